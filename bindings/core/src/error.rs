@@ -25,6 +25,9 @@ pub enum Error {
     /// SerdeJson errors.
     #[error("{0}")]
     SerdeJson(#[from] serde_json::error::Error),
+    /// The permission doesn't allow calling the method.
+    #[error("permission denied: {0}")]
+    PermissionDenied(String),
     /// Unpack errors.
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
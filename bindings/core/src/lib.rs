@@ -7,6 +7,7 @@ mod error;
 mod method;
 mod method_handler;
 mod panic;
+mod permission;
 mod response;
 
 use std::fmt::{Formatter, Result as FmtResult};
@@ -27,7 +28,11 @@ pub use self::method_handler::CallMethod;
 pub use self::{
     error::{Error, Result},
     method::{AccountMethod, ClientMethod, SecretManagerMethod, UtilsMethod, WalletMethod},
-    method_handler::{call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method},
+    method_handler::{
+        call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method,
+        call_wallet_method_with_permission,
+    },
+    permission::WalletPermission,
     response::Response,
 };
 
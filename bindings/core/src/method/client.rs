@@ -216,6 +216,12 @@ pub enum ClientMethod {
         /// Output ID
         output_id: OutputId,
     },
+    /// Get output raw
+    #[serde(rename_all = "camelCase")]
+    GetOutputRaw {
+        /// Output ID
+        output_id: OutputId,
+    },
     /// Get output metadata
     #[serde(rename_all = "camelCase")]
     GetOutputMetadata {
@@ -271,6 +277,12 @@ pub enum ClientMethod {
         /// Transaction ID
         transaction_id: TransactionId,
     },
+    /// Returns the raw included block of the transaction.
+    #[serde(rename_all = "camelCase")]
+    GetIncludedBlockRaw {
+        /// Transaction ID
+        transaction_id: TransactionId,
+    },
     /// Returns the included block metadata of the transaction.
     #[serde(rename_all = "camelCase")]
     GetIncludedBlockMetadata {
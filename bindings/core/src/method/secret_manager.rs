@@ -4,7 +4,10 @@
 use crypto::keys::bip44::Bip44;
 use derivative::Derivative;
 use iota_sdk::{
-    client::api::{GetAddressesOptions, PreparedTransactionDataDto},
+    client::{
+        api::{GetAddressesOptions, PreparedTransactionDataDto},
+        secret::eip712::{Eip712Domain, Eip712Types},
+    },
     utils::serde::bip44::Bip44Def,
 };
 use serde::{Deserialize, Serialize};
@@ -47,6 +50,15 @@ pub enum SecretManagerMethod {
         #[serde(with = "Bip44Def")]
         chain: Bip44,
     },
+    /// Signs a prefixed message with an Ed25519 private key, to prove the ownership of its address.
+    /// Expected response: [`Ed25519Signature`](crate::Response::Ed25519Signature)
+    SignMessage {
+        /// The message to sign, hex encoded String
+        message: String,
+        /// Chain to sign the message with
+        #[serde(with = "Bip44Def")]
+        chain: Bip44,
+    },
     /// Signs a message with an Secp256k1Ecdsa private key.
     SignSecp256k1Ecdsa {
         /// The message to sign, hex encoded String
@@ -55,6 +67,19 @@ pub enum SecretManagerMethod {
         #[serde(with = "Bip44Def")]
         chain: Bip44,
     },
+    /// Signs EIP-712 typed data with an Secp256k1Ecdsa private key.
+    /// Expected response: [`Secp256k1EcdsaSignature`](crate::Response::Secp256k1EcdsaSignature)
+    SignTypedData {
+        /// The domain of the typed data
+        domain: Eip712Domain,
+        /// The struct types of the message
+        types: Eip712Types,
+        /// The message to sign
+        message: serde_json::Value,
+        /// Chain to sign the typed data with
+        #[serde(with = "Bip44Def")]
+        chain: Bip44,
+    },
     /// Sign a transaction
     #[serde(rename_all = "camelCase")]
     SignTransaction {
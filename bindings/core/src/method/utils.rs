@@ -150,6 +150,12 @@ pub enum UtilsMethod {
         signature: Ed25519SignatureDto,
         message: String,
     },
+    /// Verify a signature of a message signed with `SignMessage` against an Ed25519 address.
+    VerifyMessage {
+        address: Bech32Address,
+        signature: Ed25519SignatureDto,
+        message: String,
+    },
     /// Verify a Secp256k1Ecdsa signature against a message.
     #[serde(rename_all = "camelCase")]
     VerifySecp256k1EcdsaSignature {
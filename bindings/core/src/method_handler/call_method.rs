@@ -17,6 +17,7 @@ use crate::{
         utils::call_utils_method_internal, wallet::call_wallet_method_internal,
     },
     panic::{convert_async_panics, convert_panics},
+    permission::WalletPermission,
     response::Response,
     UtilsMethod,
 };
@@ -66,6 +67,20 @@ pub async fn call_wallet_method(wallet: &Wallet, method: WalletMethod) -> Respon
     response
 }
 
+/// Call a wallet method if the permission allows it.
+pub async fn call_wallet_method_with_permission(
+    wallet: &Wallet,
+    method: WalletMethod,
+    permission: WalletPermission,
+) -> Response {
+    if let Err(e) = permission.check(&method) {
+        log::debug!("Wallet method denied: {e}");
+        return Response::Error(e);
+    }
+
+    call_wallet_method(wallet, method).await
+}
+
 /// Call a utils method.
 pub fn call_utils_method(method: UtilsMethod) -> Response {
     log::debug!("Utils method: {method:?}");
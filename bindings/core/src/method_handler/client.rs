@@ -293,6 +293,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 .await
                 .map(OutputWithMetadataResponse::from)?,
         ),
+        ClientMethod::GetOutputRaw { output_id } => Response::Raw(client.get_output_raw(&output_id).await?),
         ClientMethod::GetOutputMetadata { output_id } => {
             Response::OutputMetadata(client.get_output_metadata(&output_id).await?)
         }
@@ -322,6 +323,9 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetIncludedBlock { transaction_id } => {
             Response::Block(BlockDto::from(&client.get_included_block(&transaction_id).await?))
         }
+        ClientMethod::GetIncludedBlockRaw { transaction_id } => {
+            Response::Raw(client.get_included_block_raw(&transaction_id).await?)
+        }
         ClientMethod::GetIncludedBlockMetadata { transaction_id } => {
             Response::BlockMetadata(client.get_included_block_metadata(&transaction_id).await?)
         }
@@ -62,6 +62,11 @@ pub(crate) async fn call_secret_manager_method_internal(
             let signature = secret_manager.sign_ed25519(&msg, chain).await?;
             Response::Ed25519Signature(Ed25519SignatureDto::from(&signature))
         }
+        SecretManagerMethod::SignMessage { message, chain } => {
+            let msg: Vec<u8> = prefix_hex::decode(message)?;
+            let signature = secret_manager.sign_message(&msg, chain).await?;
+            Response::Ed25519Signature(Ed25519SignatureDto::from(&signature))
+        }
         SecretManagerMethod::SignSecp256k1Ecdsa { message, chain } => {
             let msg: Vec<u8> = prefix_hex::decode(message)?;
             let (public_key, signature) = secret_manager.sign_secp256k1_ecdsa(&msg, chain).await?;
@@ -70,6 +75,18 @@ pub(crate) async fn call_secret_manager_method_internal(
                 signature: prefix_hex::encode(signature.to_bytes()),
             }
         }
+        SecretManagerMethod::SignTypedData {
+            domain,
+            types,
+            message,
+            chain,
+        } => {
+            let (public_key, signature) = secret_manager.sign_typed_data(&domain, &types, &message, chain).await?;
+            Response::Secp256k1EcdsaSignature {
+                public_key: prefix_hex::encode(public_key.to_bytes()),
+                signature: prefix_hex::encode(signature.to_bytes()),
+            }
+        }
         #[cfg(feature = "stronghold")]
         SecretManagerMethod::StoreMnemonic { mnemonic } => {
             let mnemonic = crypto::keys::bip39::Mnemonic::from(mnemonic);
@@ -4,7 +4,9 @@
 use crypto::keys::bip39::Mnemonic;
 use iota_sdk::{
     client::{
-        api::verify_semantic, hex_public_key_to_bech32_address, hex_to_bech32, secret::types::InputSigningData,
+        api::verify_semantic,
+        hex_public_key_to_bech32_address, hex_to_bech32,
+        secret::{message::verify_message, types::InputSigningData},
         verify_mnemonic, Client,
     },
     types::{
@@ -98,6 +100,18 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let message: Vec<u8> = prefix_hex::decode(message)?;
             Response::Bool(signature.try_verify(&message).map_err(Error::from)?)
         }
+        UtilsMethod::VerifyMessage {
+            address,
+            signature,
+            message,
+        } => {
+            let signature = Ed25519Signature::try_from(signature)?;
+            let message: Vec<u8> = prefix_hex::decode(message)?;
+            Response::Bool(match address.inner() {
+                Address::Ed25519(address) => verify_message(address, &signature, &message),
+                _ => false,
+            })
+        }
         UtilsMethod::VerifySecp256k1EcdsaSignature {
             public_key,
             signature,
@@ -0,0 +1,267 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, fmt};
+
+use iota_sdk::{
+    client::{
+        api::{input_selection::ChangeStrategy, PreparedTransactionData, SignedTransactionData},
+        secret::types::InputSigningData,
+    },
+    types::{
+        block::{
+            address::Address,
+            output::{BasicOutput, Output, TokenId},
+        },
+        TryFromDto,
+    },
+    wallet::account::{RemainderValueStrategy, TransactionOptionsDto},
+    U256,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{method::AccountMethod, Error, Result, WalletMethod};
+
+/// The permission of a caller of the wallet message interface, checked before a method is dispatched.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WalletPermission {
+    /// Only methods that read the state of the wallet.
+    ReadOnly,
+    /// Read-only methods and transactions that only send base coins, at most `max_amount` per transaction.
+    ///
+    /// Transactions that are signed from prepared transaction data may only contain basic outputs and must not move
+    /// native tokens to other addresses. Sends may not set return addresses or transaction options that select the
+    /// inputs, burn or move the remainder to other addresses.
+    #[serde(rename_all = "camelCase")]
+    Send {
+        #[serde(with = "iota_sdk::utils::serde::string")]
+        max_amount: u64,
+    },
+    /// All methods.
+    Admin,
+}
+
+impl WalletPermission {
+    /// Checks if the permission allows calling the method.
+    pub fn check(&self, method: &WalletMethod) -> Result<()> {
+        let access = wallet_method_access(method)?;
+
+        let allowed = match (self, &access) {
+            (Self::Admin, _) | (_, Access::Read) => true,
+            (Self::Send { max_amount }, Access::Send(amount)) => *amount <= *max_amount as u128,
+            _ => false,
+        };
+
+        if allowed {
+            Ok(())
+        } else {
+            Err(Error::PermissionDenied(format!(
+                "{} requires {access} permission",
+                method_name(method)
+            )))
+        }
+    }
+}
+
+/// The access a method requires.
+enum Access {
+    Read,
+    /// Sending the amount of base coins to other addresses.
+    Send(u128),
+    Admin,
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "read-only"),
+            Self::Send(amount) => write!(f, "send {amount}"),
+            Self::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+fn method_name(method: &WalletMethod) -> String {
+    let value = serde_json::to_value(method).unwrap_or_default();
+    let name = value["name"].as_str().unwrap_or_default();
+
+    match value["data"]["method"]["name"].as_str() {
+        Some(account_method) if matches!(method, WalletMethod::CallAccountMethod { .. }) => {
+            format!("{name}.{account_method}")
+        }
+        _ => name.to_string(),
+    }
+}
+
+fn wallet_method_access(method: &WalletMethod) -> Result<Access> {
+    Ok(match method {
+        WalletMethod::CallAccountMethod { method, .. } => return account_method_access(method),
+        WalletMethod::GetAccount { .. }
+        | WalletMethod::GetAccountIndexes
+        | WalletMethod::GetAccounts
+        | WalletMethod::GenerateEd25519Address { .. } => Access::Read,
+        #[cfg(feature = "storage")]
+        WalletMethod::GetChrysalisData => Access::Read,
+        #[cfg(feature = "stronghold")]
+        WalletMethod::IsStrongholdPasswordAvailable => Access::Read,
+        #[cfg(feature = "ledger_nano")]
+        WalletMethod::GetLedgerNanoStatus => Access::Read,
+        _ => Access::Admin,
+    })
+}
+
+fn account_method_access(method: &AccountMethod) -> Result<Access> {
+    Ok(match method {
+        AccountMethod::Addresses
+        | AccountMethod::AddressesWithUnspentOutputs
+        | AccountMethod::ClaimableOutputs { .. }
+        | AccountMethod::GetBalance
+        | AccountMethod::GetFoundryOutput { .. }
+        | AccountMethod::GetIncomingTransaction { .. }
+        | AccountMethod::GetOutput { .. }
+        | AccountMethod::GetTransaction { .. }
+        | AccountMethod::IncomingTransactions
+        | AccountMethod::Outputs { .. }
+        | AccountMethod::PendingTransactions
+        | AccountMethod::PrepareOutput { .. }
+        | AccountMethod::Sync { .. }
+        | AccountMethod::Transactions
+        | AccountMethod::UnspentOutputs { .. } => Access::Read,
+        #[cfg(feature = "participation")]
+        AccountMethod::GetParticipationEvent { .. }
+        | AccountMethod::GetParticipationEventIds { .. }
+        | AccountMethod::GetParticipationEventStatus { .. }
+        | AccountMethod::GetParticipationEvents
+        | AccountMethod::GetParticipationOverview { .. }
+        | AccountMethod::GetVotingPower => Access::Read,
+        AccountMethod::Send { amount, options, .. } => send_access(*amount as u128, options.as_ref()),
+        AccountMethod::SendWithParams { params, options } | AccountMethod::PrepareSend { params, options } => {
+            if params.iter().any(|p| p.return_address().is_some()) {
+                Access::Admin
+            } else {
+                send_access(params.iter().map(|p| *p.amount() as u128).sum(), options.as_ref())
+            }
+        }
+        AccountMethod::SignAndSubmitTransaction {
+            prepared_transaction_data,
+        }
+        | AccountMethod::SignTransactionEssence {
+            prepared_transaction_data,
+        } => {
+            let prepared_transaction_data = PreparedTransactionData::try_from_dto(prepared_transaction_data.clone())?;
+            transaction_access(
+                &prepared_transaction_data.inputs_data,
+                prepared_transaction_data.essence.as_regular().outputs(),
+            )
+        }
+        AccountMethod::SubmitAndStoreTransaction {
+            signed_transaction_data,
+        } => {
+            let signed_transaction_data = SignedTransactionData::try_from_dto(signed_transaction_data.clone())?;
+            transaction_access(
+                &signed_transaction_data.inputs_data,
+                signed_transaction_data
+                    .transaction_payload
+                    .essence()
+                    .as_regular()
+                    .outputs(),
+            )
+        }
+        _ => Access::Admin,
+    })
+}
+
+/// Returns send access to the amount, or admin access if the options could move funds to other addresses.
+fn send_access(amount: u128, options: Option<&TransactionOptionsDto>) -> Access {
+    if options.is_none_or(is_send_scoped) {
+        Access::Send(amount)
+    } else {
+        Access::Admin
+    }
+}
+
+// Destructured, so new options have to be classified here.
+fn is_send_scoped(options: &TransactionOptionsDto) -> bool {
+    let TransactionOptionsDto {
+        remainder_value_strategy,
+        tagged_data_payload: _,
+        custom_inputs,
+        mandatory_inputs,
+        burn,
+        note: _,
+        allow_micro_amount: _,
+        coin_selection_strategy: _,
+        change_strategy,
+        native_tokens_remainder_address,
+        token_remainder_addresses,
+        expiration_safety_margin: _,
+        input_selection_seed: _,
+    } = options;
+
+    !matches!(remainder_value_strategy, RemainderValueStrategy::CustomAddress(_))
+        && custom_inputs.is_none()
+        && mandatory_inputs.is_none()
+        && burn.is_none()
+        && *change_strategy == ChangeStrategy::default()
+        && native_tokens_remainder_address.is_none()
+        && token_remainder_addresses.is_empty()
+}
+
+/// Returns the amount of base coins a transaction sends to other addresses, or admin access if it isn't a plain base
+/// coin transaction.
+fn transaction_access(inputs: &[InputSigningData], outputs: &[Output]) -> Access {
+    let mut input_addresses = Vec::with_capacity(inputs.len());
+    let mut native_tokens = HashMap::<TokenId, U256>::new();
+
+    for input in inputs {
+        let Output::Basic(output) = &input.output else {
+            return Access::Admin;
+        };
+        input_addresses.push(output.address());
+        for native_token in output.native_tokens().iter() {
+            *native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+        }
+    }
+
+    let mut sent_amount = 0u128;
+
+    for output in outputs {
+        let Output::Basic(output) = output else {
+            return Access::Admin;
+        };
+        if stays_on(output, &input_addresses) {
+            for native_token in output.native_tokens().iter() {
+                let amount = native_tokens.entry(*native_token.token_id()).or_default();
+                *amount = amount.saturating_sub(native_token.amount());
+            }
+        } else {
+            // native tokens may only be kept, not sent
+            if !output.native_tokens().is_empty() {
+                return Access::Admin;
+            }
+            sent_amount += output.amount() as u128;
+        }
+    }
+
+    // native tokens that don't end up on an input address would be burned
+    if native_tokens.values().any(|amount| !amount.is_zero()) {
+        return Access::Admin;
+    }
+
+    Access::Send(sent_amount)
+}
+
+// Whether the output can only be unlocked by the addresses, also after its expiration, and returns its storage deposit
+// to them.
+fn stays_on(output: &BasicOutput, addresses: &[&Address]) -> bool {
+    let unlock_conditions = output.unlock_conditions();
+
+    addresses.contains(&output.address())
+        && unlock_conditions
+            .storage_deposit_return()
+            .is_none_or(|sdr| addresses.contains(&sdr.return_address()))
+        && unlock_conditions
+            .expiration()
+            .is_none_or(|expiration| addresses.contains(&expiration.return_address()))
+}
@@ -96,9 +96,11 @@ pub enum Response {
     SignatureUnlock(UnlockDto),
     /// Response for:
     /// - [`SignEd25519`](crate::method::SecretManagerMethod::SignEd25519)
+    /// - [`SignMessage`](crate::method::SecretManagerMethod::SignMessage)
     Ed25519Signature(Ed25519SignatureDto),
     /// Response for:
     /// - [`SignSecp256k1Ecdsa`](crate::method::SecretManagerMethod::SignSecp256k1Ecdsa)
+    /// - [`SignTypedData`](crate::method::SecretManagerMethod::SignTypedData)
     #[serde(rename_all = "camelCase")]
     Secp256k1EcdsaSignature { public_key: String, signature: String },
     /// Response for:
@@ -131,8 +133,10 @@ pub enum Response {
     BlockMetadata(BlockMetadataResponse),
     /// Response for:
     /// - [`GetBlockRaw`](crate::method::ClientMethod::GetBlockRaw)
+    /// - [`GetOutputRaw`](crate::method::ClientMethod::GetOutputRaw)
     /// - [`GetMilestoneByIdRaw`](crate::method::ClientMethod::GetMilestoneByIdRaw)
     /// - [`GetMilestoneByIndexRaw`](crate::method::ClientMethod::GetMilestoneByIndexRaw)
+    /// - [`GetIncludedBlockRaw`](crate::method::ClientMethod::GetIncludedBlockRaw)
     /// - [`BlockBytes`](crate::method::UtilsMethod::BlockBytes)
     Raw(Vec<u8>),
     /// Response for:
@@ -275,6 +279,7 @@ pub enum Response {
     /// - [`GetHealth`](crate::method::ClientMethod::GetHealth)
     /// - [`IsAddressValid`](crate::method::UtilsMethod::IsAddressValid)
     /// - [`VerifyEd25519Signature`](crate::method::UtilsMethod::VerifyEd25519Signature)
+    /// - [`VerifyMessage`](crate::method::UtilsMethod::VerifyMessage)
     /// - [`VerifySecp256k1EcdsaSignature`](crate::method::UtilsMethod::VerifySecp256k1EcdsaSignature)
     Bool(bool),
     /// Response for:
@@ -0,0 +1,82 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_bindings_core::{Error, WalletMethod, WalletPermission};
+use pretty_assertions::assert_eq;
+
+fn account_method(method: &str) -> WalletMethod {
+    serde_json::from_str(&format!(
+        r#"{{"name":"callAccountMethod","data":{{"accountId":0,"method":{method}}}}}"#
+    ))
+    .unwrap()
+}
+
+#[test]
+fn permissions() {
+    let get_accounts = serde_json::from_str::<WalletMethod>(r#"{"name":"getAccounts"}"#).unwrap();
+    let remove_latest_account = serde_json::from_str::<WalletMethod>(r#"{"name":"removeLatestAccount"}"#).unwrap();
+    let get_balance = account_method(r#"{"name":"getBalance"}"#);
+    let send = account_method(
+        r#"{"name":"send","data":{"amount":"1000000","address":"rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"}}"#,
+    );
+
+    assert!(WalletPermission::ReadOnly.check(&get_accounts).is_ok());
+    assert!(WalletPermission::ReadOnly.check(&get_balance).is_ok());
+    assert!(WalletPermission::ReadOnly.check(&send).is_err());
+    assert!(WalletPermission::ReadOnly.check(&remove_latest_account).is_err());
+
+    assert!(WalletPermission::Send { max_amount: 1_000_000 }
+        .check(&get_balance)
+        .is_ok());
+    assert!(WalletPermission::Send { max_amount: 1_000_000 }.check(&send).is_ok());
+    assert!(WalletPermission::Send { max_amount: 999_999 }.check(&send).is_err());
+    assert!(WalletPermission::Send { max_amount: 1_000_000 }
+        .check(&remove_latest_account)
+        .is_err());
+
+    assert!(WalletPermission::Admin.check(&send).is_ok());
+    assert!(WalletPermission::Admin.check(&remove_latest_account).is_ok());
+
+    let Err(Error::PermissionDenied(message)) = WalletPermission::Send { max_amount: 0 }.check(&send) else {
+        panic!("expected permission denied");
+    };
+    assert_eq!(message, "callAccountMethod.send requires send 1000000 permission");
+}
+
+#[test]
+fn send_permission_scope() {
+    let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+    let output_id = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000";
+    let permission = WalletPermission::Send { max_amount: 1_000_000 };
+
+    let send_with_note = account_method(&format!(
+        r#"{{"name":"send","data":{{"amount":"1000","address":"{address}","options":{{"note":"coffee"}}}}}}"#
+    ));
+    let send_with_custom_inputs = account_method(&format!(
+        r#"{{"name":"send","data":{{"amount":"1000","address":"{address}","options":{{"customInputs":["{output_id}"]}}}}}}"#
+    ));
+    let send_with_change_strategy = account_method(&format!(
+        r#"{{"name":"prepareSend","data":{{"params":[{{"amount":"1000","address":"{address}"}}],"options":{{"changeStrategy":{{"strategy":"Split","value":2}}}}}}}}"#
+    ));
+    let send_with_return_address = account_method(&format!(
+        r#"{{"name":"sendWithParams","data":{{"params":[{{"amount":"1000","address":"{address}","return_address":"{address}"}}]}}}}"#
+    ));
+
+    assert!(permission.check(&send_with_note).is_ok());
+    assert!(permission.check(&send_with_custom_inputs).is_err());
+    assert!(permission.check(&send_with_change_strategy).is_err());
+    assert!(permission.check(&send_with_return_address).is_err());
+    assert!(WalletPermission::Admin.check(&send_with_custom_inputs).is_ok());
+}
+
+#[test]
+fn permission_serialization() {
+    assert_eq!(
+        serde_json::from_str::<WalletPermission>(r#"{"type":"send","maxAmount":"1000"}"#).unwrap(),
+        WalletPermission::Send { max_amount: 1000 }
+    );
+    assert_eq!(
+        serde_json::to_string(&WalletPermission::ReadOnly).unwrap(),
+        r#"{"type":"readOnly"}"#
+    );
+}
@@ -148,6 +148,23 @@ export class Client {
         return plainToInstance(OutputResponse, parsed.payload);
     }
 
+    /**
+     * Get output as raw bytes from a given output ID.
+     *
+     * @param outputId The ID of the requested output.
+     * @returns The raw bytes of the requested output.
+     */
+    async getOutputRaw(outputId: OutputId): Promise<Uint8Array> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getOutputRaw',
+            data: {
+                outputId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Fetch OutputResponse from given output IDs. Requests are sent in parallel.
      */
@@ -570,6 +587,23 @@ export class Client {
         return plainToInstance(MilestonePayload, parsed.payload);
     }
 
+    /**
+     * Get a milestone payload as raw bytes by its ID.
+     *
+     * @param milestoneId The ID of the requested milestone.
+     * @returns The raw bytes of the corresponding milestone payload.
+     */
+    async getMilestoneByIdRaw(milestoneId: MilestoneId): Promise<Uint8Array> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getMilestoneByIdRaw',
+            data: {
+                milestoneId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get all UTXO changes of a milestone by its ID.
      *
@@ -606,6 +640,23 @@ export class Client {
         return plainToInstance(MilestonePayload, parsed.payload);
     }
 
+    /**
+     * Get a milestone payload as raw bytes by its index.
+     *
+     * @param index The index of the requested milestone.
+     * @returns The raw bytes of the corresponding milestone payload.
+     */
+    async getMilestoneByIndexRaw(index: number): Promise<Uint8Array> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getMilestoneByIndexRaw',
+            data: {
+                index,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get all UTXO changes of a milestone by its index.
      *
@@ -682,6 +733,23 @@ export class Client {
         return plainToInstance(Block, parsed.payload);
     }
 
+    /**
+     * Get the included block of a given transaction as raw bytes.
+     *
+     * @param transactionId The ID of the transaction.
+     * @returns The raw bytes of the included block that contained the transaction.
+     */
+    async getIncludedBlockRaw(transactionId: TransactionId): Promise<Uint8Array> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getIncludedBlockRaw',
+            data: {
+                transactionId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the metadata of the included block of a given transaction.
      *
@@ -45,6 +45,13 @@ export interface __GetOutputMethod__ {
     };
 }
 
+export interface __GetOutputRawMethod__ {
+    name: 'getOutputRaw';
+    data: {
+        outputId: OutputId;
+    };
+}
+
 export interface __GetOutputIdsMethod__ {
     name: 'outputIds';
     data: {
@@ -221,6 +228,13 @@ export interface __GetUtxoChangesByIdMethod__ {
         milestoneId: MilestoneId;
     };
 }
+export interface __GetMilestoneByIdRawMethod__ {
+    name: 'getMilestoneByIdRaw';
+    data: {
+        milestoneId: MilestoneId;
+    };
+}
+
 export interface __GetMilestoneByIndexMethod__ {
     name: 'getMilestoneByIndex';
     data: {
@@ -228,6 +242,13 @@ export interface __GetMilestoneByIndexMethod__ {
     };
 }
 
+export interface __GetMilestoneByIndexRawMethod__ {
+    name: 'getMilestoneByIndexRaw';
+    data: {
+        index: number;
+    };
+}
+
 export interface __GetUtxoChangesByIndexMethod__ {
     name: 'getUtxoChangesByIndex';
     data: {
@@ -257,6 +278,13 @@ export interface __GetIncludedBlockMethod__ {
     };
 }
 
+export interface __GetIncludedBlockRawMethod__ {
+    name: 'getIncludedBlockRaw';
+    data: {
+        transactionId: TransactionId;
+    };
+}
+
 export interface __GetIncludedBlockMetadataMethod__ {
     name: 'getIncludedBlockMetadata';
     data: {
@@ -30,6 +30,10 @@ import type {
     __GetPeersMethod__,
     __PostBlockRawMethod__,
     __GetBlockRawMethod__,
+    __GetOutputRawMethod__,
+    __GetMilestoneByIdRawMethod__,
+    __GetMilestoneByIndexRawMethod__,
+    __GetIncludedBlockRawMethod__,
     __GetReceiptsMethod__,
     __GetReceiptsMigratedAtMethod__,
     __GetTreasuryMethod__,
@@ -100,14 +104,18 @@ export type __ClientMethods__ =
     | __GetPeersMethod__
     | __PostBlockRawMethod__
     | __GetBlockRawMethod__
+    | __GetOutputRawMethod__
     | __GetMilestoneByIdMethod__
+    | __GetMilestoneByIdRawMethod__
     | __GetUtxoChangesByIdMethod__
     | __GetMilestoneByIndexMethod__
+    | __GetMilestoneByIndexRawMethod__
     | __GetUtxoChangesByIndexMethod__
     | __GetReceiptsMethod__
     | __GetReceiptsMigratedAtMethod__
     | __GetTreasuryMethod__
     | __GetIncludedBlockMethod__
+    | __GetIncludedBlockRawMethod__
     | __GetIncludedBlockMetadataMethod__
     | __HexToBech32Method__
     | __AliasIdToBech32Method__
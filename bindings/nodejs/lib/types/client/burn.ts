@@ -13,4 +13,6 @@ export interface Burn {
     foundries?: FoundryId[];
     /** Amounts of native tokens to burn */
     nativeTokens?: Map<TokenId, bigint>;
+    /** Amounts of native tokens to melt with their foundry */
+    meltedNativeTokens?: Map<TokenId, bigint>;
 }
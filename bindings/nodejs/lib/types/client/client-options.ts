@@ -36,8 +36,74 @@ export interface IClientOptions {
     powWorkerCount?: number;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
+    /** The policy of where the PoW of blocks is done. */
+    powPolicy?: PowPolicy;
     /** The maximum parallel API requests. */
     maxParallelApiRequests?: number;
+    /** The policy for retrying failed requests. */
+    retryPolicy?: IRetryPolicy;
+    /** The template of the block explorer urls of the network, e.g. `https://explorer.shimmer.network/shimmer/{kind}/{id}`. */
+    explorerUrlTemplate?: string;
+    /** The TLS configuration of all connections to nodes. */
+    tlsConfig?: ITlsConfig;
+    /** The proxy all connections to nodes are tunneled through. */
+    proxy?: IProxyConfig;
+    /** If the client is offline and no requests are sent to nodes, the protocol parameters are used instead of the ones of the nodes. */
+    offline?: boolean;
+    /** When the protocol parameters are refreshed in the background, in addition to the node syncing. */
+    protocolParametersRefresh?: ProtocolParametersRefresh;
+}
+
+/** Proxy of the connections to nodes, e.g. a corporate proxy or Tor */
+export interface IProxyConfig {
+    /** The url of the proxy, with one of the schemes `http`, `https`, `socks5` or `socks5h`, e.g. `socks5h://127.0.0.1:9050`. MQTT connections only support HTTP(S) proxies. */
+    url: string;
+}
+
+/** TLS configuration of the connections to nodes, e.g. for self-hosted nodes with a private PKI */
+export interface ITlsConfig {
+    /** PEM encoded CA certificates to trust. */
+    caCertificates?: string[];
+    /** Whether the built-in webpki roots are trusted, defaults to true. */
+    useDefaultRoots?: boolean;
+    /** SHA-256 fingerprints of pinned certificates, as hex. A node is only trusted if it presents one of them. */
+    pinnedCertificates?: string[];
+}
+
+/** Policy for retrying failed requests to a node */
+export interface IRetryPolicy {
+    /** Max amount of retries per request and node, 0 disables retrying. */
+    maxRetries?: number;
+    /** Delay before the first retry, doubled for every following retry. */
+    baseDelay?: IDuration;
+    /** Max random delay added to every retry delay. */
+    jitter?: IDuration;
+    /** The failures that will be retried. */
+    retryOn?: IRetryOn;
+}
+
+/** Policy of where the PoW of blocks is done */
+export type PowPolicy =
+    /** Do the PoW locally or remotely, depending on `localPow`. */
+    | { type: 'static' }
+    /** Do the PoW locally and, if it doesn't complete within `localTimeout`, let a node with remote PoW do it. */
+    | { type: 'localThenRemote'; localTimeout: IDuration };
+
+/** When the protocol parameters are refreshed in the background */
+export type ProtocolParametersRefresh =
+    /** Request the node info at an interval. */
+    | { type: 'interval'; interval: IDuration }
+    /** Request the node info when a new milestone is received over MQTT. */
+    | { type: 'onMilestone' };
+
+/** Failures that are classified as retryable */
+export interface IRetryOn {
+    /** Retry when the node responds with 429 Too Many Requests. */
+    tooManyRequests?: boolean;
+    /** Retry when the node responds with a 5xx status code. */
+    serverErrors?: boolean;
+    /** Retry when the request timed out. */
+    timeouts?: boolean;
 }
 
 /** Time duration */
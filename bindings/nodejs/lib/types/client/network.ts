@@ -37,8 +37,43 @@ export interface IMqttBrokerOptions {
     port?: number;
     /** Sets the maximum number of reconnection attempts. 0 is unlimited. */
     maxReconnectionAttempts?: number;
+    /** Sets the username and password to authenticate with the MQTT broker. */
+    credentials?: IMqttCredentials;
+    /** Sets the PEM encoded CA certificates that are trusted instead of the platform certificates. */
+    caCertificate?: string;
+    /** Sets the certificate the client authenticates with to the MQTT broker. Requires a CA certificate to be set. */
+    clientCertificate?: IMqttClientCertificate;
+    /** Sets the default QoS level of subscriptions. */
+    qos?: MqttQos;
 }
 
+/**
+ * The username and password to authenticate with a MQTT broker.
+ */
+export interface IMqttCredentials {
+    /** The username. */
+    username: string;
+    /** The password. */
+    password: string;
+}
+
+/**
+ * A client certificate to authenticate with a MQTT broker over TLS.
+ */
+export interface IMqttClientCertificate {
+    /** The PEM encoded certificate chain. */
+    certificate: string;
+    /** The PEM encoded private key. */
+    key: string;
+    /** The format of the private key, a PKCS#8 key if not set. */
+    keyFormat?: 'pkcs8' | 'rsa';
+}
+
+/**
+ * The MQTT QoS level of a subscription.
+ */
+export type MqttQos = 'atMostOnce' | 'atLeastOnce' | 'exactlyOnce';
+
 /**
  * A node object for the client.
  */
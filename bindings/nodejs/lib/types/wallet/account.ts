@@ -34,6 +34,23 @@ export interface Balance {
      * TimelockUnlockCondition or ExpirationUnlockCondition this can change at any time
      */
     potentiallyLockedOutputs: { [outputId: string]: boolean };
+    /** Changes of pending transactions that are already applied to the balance */
+    provisional: ProvisionalBalance;
+}
+
+/**
+ * The changes of pending transactions, which are provisionally applied to the balance until the transactions are
+ * confirmed, or rolled back if they conflict
+ */
+export interface ProvisionalBalance {
+    /** The pending transactions */
+    transactionIds: string[];
+    /** The outputs spent by the pending transactions, which are no longer part of the total balance */
+    spentOutputs: string[];
+    /** The base coin amount of the spent outputs */
+    spent: bigint;
+    /** The base coin amount of the received outputs, like remainders, which are only available once confirmed */
+    received: bigint;
 }
 
 /** The balance of the base coin */
@@ -108,6 +125,21 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /**
+     * The number of unused addresses after the last used one that are checked for unspent outputs before syncing.
+     * Addresses are not discovered if 0 or if specific `addresses` are synced. Default: 0.
+     */
+    addressGapLimit?: number;
+    /** How the addresses within the `addressGapLimit` are checked. Default: linear. */
+    addressDiscovery?: AddressDiscoveryMode;
+}
+
+/** How unknown addresses of an account are discovered. */
+export enum AddressDiscoveryMode {
+    /** Check every address until `addressGapLimit` addresses in a row are unused. */
+    Linear = 'linear',
+    /** Only check the addresses at an offset of 1, 2, 4, 8, ... up to `addressGapLimit` after the last used one. */
+    Exponential = 'exponential',
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
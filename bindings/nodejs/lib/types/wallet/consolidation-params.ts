@@ -7,6 +7,10 @@ export interface ConsolidationParams {
     force: boolean;
     /** Consolidates if the output number is >= the output_threshold. */
     outputThreshold?: number;
+    /** The max number of inputs of the consolidation transaction. */
+    maxInputsPerTx?: number;
     /** Address to which the consolidated output should be sent. */
     targetAddress?: string;
+    /** Also consolidates outputs with native tokens if set to `true`, the default. */
+    includeNativeTokens?: boolean;
 }
@@ -51,6 +51,14 @@ enum WalletEventType {
     TransactionInclusion = 4,
     /** A progress update while submitting a transaction. */
     TransactionProgress = 5,
+    /** A pending transaction was promoted or reattached. */
+    TransactionRetry = 6,
+    /** Expiring outputs were claimed automatically. */
+    OutputsClaimed = 7,
+    /** A step of creating a native token together with its alias. */
+    NativeTokenCreationProgress = 8,
+    /** A transaction of a send that was split into multiple transactions was sent. */
+    SplitTransactionProgress = 9,
 }
 
 /**
@@ -27,8 +27,48 @@ export interface TransactionOptions {
     note?: string;
     /** Whether to allow sending a micro amount. */
     allowMicroAmount?: boolean;
+    /** The strategy to select the inputs that fulfill the amount, `smallestFirst` by default. */
+    coinSelectionStrategy?: CoinSelectionStrategy;
+    /** How the remainder is created, a single output by default. */
+    changeStrategy?: ChangeStrategy;
+    /** The address that receives the native token remainder, instead of the base coin remainder address. */
+    nativeTokensRemainderAddress?: Bech32Address;
+    /** The addresses that receive the remainder of specific native tokens, by token ID. */
+    tokenRemainderAddresses?: { [tokenId: HexEncodedString]: Bech32Address };
+    /** Inputs that expire within this many seconds after the latest milestone timestamp are not used, 60 by default. */
+    expirationSafetyMargin?: number;
 }
 
+/** The possible change strategies. */
+export type ChangeStrategy =
+    | {
+          /** Create a single remainder output. */
+          strategy: 'Single';
+      }
+    | {
+          /** Split the remainder into up to `value` outputs of rounded amounts, fewer if they would be too small. */
+          strategy: 'Split';
+          value: number;
+      }
+    | {
+          /** Merge the remainder into an existing unspent output of the remainder address. */
+          strategy: 'MergeIntoExisting';
+      };
+
+/**
+ * The possible coin selection strategies.
+ * - `largestFirst`: selects the inputs with the highest amount first, which results in the fewest inputs.
+ * - `smallestFirst`: selects the inputs with the lowest amount first, which consolidates dust outputs over time.
+ * - `branchAndBound`: selects inputs whose amounts sum up to exactly the required amount first, if any, so no
+ *   remainder is needed.
+ * - `random`: selects the inputs in a random order, so they don't reveal the other outputs of the wallet.
+ */
+export type CoinSelectionStrategy =
+    | 'largestFirst'
+    | 'smallestFirst'
+    | 'branchAndBound'
+    | 'random';
+
 /** The possible remainder value strategies. */
 export type RemainderValueStrategy =
     | ChangeAddress
@@ -36,6 +36,8 @@ export class Transaction {
     incoming!: boolean;
     /** Note that can be set when sending a transaction and is only stored locally */
     note?: string;
+    /** Free-form tags that are only stored locally */
+    tags?: string[];
     /**
      * Outputs that are used as input in the transaction.
      * May not be all, because some may have already been deleted from the node.
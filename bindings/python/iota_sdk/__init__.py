@@ -7,7 +7,7 @@ from .external import *
 from .utils import Utils
 from .wallet.wallet import Wallet, Account
 from .wallet.common import WalletError
-from .wallet.sync_options import AccountSyncOptions, NftSyncOptions, AliasSyncOptions, SyncOptions
+from .wallet.sync_options import AccountSyncOptions, AddressDiscoveryMode, NftSyncOptions, AliasSyncOptions, SyncOptions
 from .secret_manager.secret_manager import *
 from .prefix_hex import *
 from .types.address import *
@@ -30,6 +30,7 @@ from .types.output import *
 from .types.output_data import *
 from .types.output_id import *
 from .types.output_params import *
+from .types.participation import *
 from .types.payload import *
 from .types.send_params import *
 from .types.token_scheme import *
@@ -133,6 +133,15 @@ class NodeCoreAPI(metaclass=ABCMeta):
             'outputId': output_id_str
         }))
 
+    def get_output_raw(self, output_id: Union[OutputId, HexStr]) -> List[int]:
+        """Get the raw bytes of the output corresponding to the given output id.
+        """
+        output_id_str = output_id.output_id if isinstance(
+            output_id, OutputId) else output_id
+        return self._call_method('getOutputRaw', {
+            'outputId': output_id_str
+        })
+
     def get_output_metadata(
             self, output_id: Union[OutputId, HexStr]) -> OutputMetadata:
         """Get the output metadata corresponding to the given output id.
@@ -229,6 +238,13 @@ class NodeCoreAPI(metaclass=ABCMeta):
             'transactionId': transaction_id
         }))
 
+    def get_included_block_raw(self, transaction_id: HexStr) -> List[int]:
+        """Returns the raw bytes of the included block of the given transaction.
+        """
+        return self._call_method('getIncludedBlockRaw', {
+            'transactionId': transaction_id
+        })
+
     def get_included_block_metadata(
             self, transaction_id: HexStr) -> BlockMetadata:
         """Returns the metadata of the included block of the given transaction.
@@ -32,14 +32,22 @@ class MnemonicSecretManager(dict):
     This is not recommended in production. Use LedgerNano or Stronghold instead.
     """
 
-    def __init__(self, mnemonic):
+    def __init__(self, mnemonic, passphrase: Optional[str] = None):
         """Initialize a mnemonic secret manager.
 
         Args:
             mnemonic: The root secret of this type of secret manager.
+            passphrase: An optional BIP-39 passphrase, also known as the 25th word.
         """
 
-        dict.__init__(self, mnemonic=mnemonic)
+        if passphrase is None:
+            dict.__init__(self, mnemonic=mnemonic)
+        else:
+            dict.__init__(
+                self,
+                mnemonicWithPassphrase={
+                    'mnemonic': mnemonic,
+                    'passphrase': passphrase})
 
 
 class SeedSecretManager(dict):
@@ -255,6 +263,21 @@ class SecretManager():
             'chain': chain.__dict__,
         }))
 
+    def sign_message(self, message: HexStr, chain: Bip44) -> Ed25519Signature:
+        """Signs a prefixed message with an Ed25519 private key, to prove the ownership of its address.
+
+        Args:
+            message: The given message to sign.
+            chain: The chain to sign with.
+
+        Returns:
+            The Ed25519 signature.
+        """
+        return from_dict(Ed25519Signature, self._call_method('signMessage', {
+            'message': message,
+            'chain': chain.__dict__,
+        }))
+
     def sign_secp256k1_ecdsa(self, message: HexStr, chain: Bip44):
         """Signs a message with an Secp256k1Ecdsa private key.
 
@@ -267,6 +290,24 @@ class SecretManager():
             'chain': chain.__dict__,
         })
 
+    def sign_typed_data(self, domain: dict, types: dict,
+                        message: dict, chain: Bip44):
+        """Signs EIP-712 typed data with an Secp256k1Ecdsa private key.
+
+        Args:
+            domain: The domain of the typed data, with optional `name`, `version`,
+                `chainId`, `verifyingContract` and `salt`.
+            types: The struct types of the message, without the `EIP712Domain` type.
+            message: The message to sign.
+            chain: The chain to sign with.
+        """
+        return self._call_method('signTypedData', {
+            'domain': domain,
+            'types': types,
+            'message': message,
+            'chain': chain.__dict__,
+        })
+
     def sign_transaction(
             self, prepared_transaction_data: PreparedTransactionData) -> TransactionPayload:
         """Sign a transaction.
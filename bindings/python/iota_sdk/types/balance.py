@@ -51,6 +51,23 @@ class NativeTokensBalance:
     metadata: Optional[HexStr]
 
 
+@dataclass
+class ProvisionalBalance:
+    """The changes of pending transactions, which are provisionally applied to the balance until the transactions are
+    confirmed, or rolled back if they conflict.
+
+    Attributes:
+        transactionIds: The pending transactions.
+        spentOutputs: The outputs spent by the pending transactions, which are no longer part of the total balance.
+        spent: The base coin amount of the spent outputs.
+        received: The base coin amount of the received outputs, like remainders, which are only available once confirmed.
+    """
+    transactionIds: List[HexStr]
+    spentOutputs: List[HexStr]
+    spent: str
+    received: str
+
+
 @dataclass
 class Balance:
     """The balance of an account.
@@ -63,6 +80,7 @@ class Balance:
         aliases: All owned aliases.
         foundries: All owned foundries.
         potentiallyLockedOutputs: A list of potentially locked outputs.
+        provisional: Changes of pending transactions that are already applied to the balance.
     """
     baseCoin: BaseCoinBalance
     requiredStorageDeposit: RequiredStorageDeposit
@@ -71,6 +89,7 @@ class Balance:
     aliases: List[HexStr]
     foundries: List[HexStr]
     potentiallyLockedOutputs: dict[HexStr, bool]
+    provisional: ProvisionalBalance
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -81,5 +100,6 @@ class Balance:
         config['requiredStorageDeposit'] = config['requiredStorageDeposit'].__dict__
         config['nativeTokens'] = [nt.__dict__
                                   for nt in config['nativeTokens']]
+        config['provisional'] = config['provisional'].__dict__
 
         return config
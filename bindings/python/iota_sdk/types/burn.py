@@ -17,12 +17,14 @@ class Burn:
         nfts: The NFTs to burn.
         foundries: The foundries to burn.
         nativeTokens: The native tokens to burn.
+        meltedNativeTokens: The native tokens to melt with their foundry.
     """
 
     aliases: Optional[List[HexStr]] = None
     nfts: Optional[List[HexStr]] = None
     foundries: Optional[List[HexStr]] = None
     nativeTokens: Optional[List[NativeToken]] = None
+    meltedNativeTokens: Optional[List[NativeToken]] = None
 
     def add_alias(self, alias: HexStr) -> Burn:
         """Add an alias to the burn.
@@ -57,6 +59,15 @@ class Burn:
             self.nativeTokens.append(native_token)
         return self
 
+    def add_melted_native_token(self, native_token: NativeToken) -> Burn:
+        """Add a native token to melt with its foundry to the burn.
+        """
+        if self.meltedNativeTokens is None:
+            self.meltedNativeTokens = [native_token]
+        else:
+            self.meltedNativeTokens.append(native_token)
+        return self
+
     def as_dict(self) -> Dict[str, Any]:
         """Converts this object to a dict.
         """
@@ -65,4 +76,7 @@ class Burn:
         if "nativeTokens" in config:
             config["nativeTokens"] = {nativeToken.__dict__["id"]: nativeToken.__dict__[
                 "amount"] for nativeToken in config["nativeTokens"]}
+        if "meltedNativeTokens" in config:
+            config["meltedNativeTokens"] = {nativeToken.__dict__["id"]: nativeToken.__dict__[
+                "amount"] for nativeToken in config["meltedNativeTokens"]}
         return config
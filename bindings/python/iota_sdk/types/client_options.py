@@ -15,6 +15,37 @@ class Duration:
     nanos: int
 
 
+@dataclass
+class MqttCredentials:
+    """The username and password to authenticate with a MQTT broker.
+
+        Attributes:
+        username (str):
+            The username.
+        password (str):
+            The password.
+    """
+    username: str
+    password: str
+
+
+@dataclass
+class MqttClientCertificate:
+    """A client certificate to authenticate with a MQTT broker over TLS.
+
+        Attributes:
+        certificate (str):
+            The PEM encoded certificate chain.
+        key (str):
+            The PEM encoded private key.
+        keyFormat (str):
+            The format of the private key, `pkcs8` or `rsa`. A PKCS#8 key if not set.
+    """
+    certificate: str
+    key: str
+    keyFormat: Optional[str] = None
+
+
 @dataclass
 class MqttBrokerOptions:
     """Options for the MQTT broker.
@@ -30,12 +61,161 @@ class MqttBrokerOptions:
             Sets the port used for the MQTT operations.
         maxReconnectionAttempts (int):
             Sets the maximum number of reconnection attempts. 0 is unlimited.
+        credentials (MqttCredentials):
+            Sets the username and password to authenticate with the MQTT broker.
+        caCertificate (str):
+            Sets the PEM encoded CA certificates that are trusted instead of the platform certificates.
+        clientCertificate (MqttClientCertificate):
+            Sets the certificate the client authenticates with to the MQTT broker. Requires a CA certificate to be set.
+        qos (str):
+            Sets the default QoS level of subscriptions, one of `atMostOnce`, `atLeastOnce` or `exactlyOnce`.
     """
     automaticDisconnect: Optional[bool] = None
     timeout: Optional[Duration] = None
     useWs: Optional[bool] = None
     port: Optional[int] = None
     maxReconnectionAttempts: Optional[int] = None
+    credentials: Optional[MqttCredentials] = None
+    caCertificate: Optional[str] = None
+    clientCertificate: Optional[MqttClientCertificate] = None
+    qos: Optional[str] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+        if 'credentials' in config:
+            config['credentials'] = config['credentials'].__dict__
+        if 'clientCertificate' in config:
+            config['clientCertificate'] = {
+                k: v for k, v in config['clientCertificate'].__dict__.items() if v is not None}
+        return config
+
+
+@dataclass
+class RetryOn:
+    """The failures that are classified as retryable.
+
+        Attributes:
+        tooManyRequests (bool):
+            Retry when the node responds with 429 Too Many Requests.
+        serverErrors (bool):
+            Retry when the node responds with a 5xx status code.
+        timeouts (bool):
+            Retry when the request timed out.
+    """
+    tooManyRequests: Optional[bool] = None
+    serverErrors: Optional[bool] = None
+    timeouts: Optional[bool] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return {k: v for k, v in self.__dict__.items() if v is not None}
+
+
+@dataclass
+class TlsConfig:
+    """TLS configuration of the connections to nodes, e.g. for self-hosted nodes with a private PKI.
+
+        Attributes:
+        caCertificates (List[str]):
+            PEM encoded CA certificates to trust.
+        useDefaultRoots (bool):
+            Whether the built-in webpki roots are trusted, defaults to true.
+        pinnedCertificates (List[str]):
+            SHA-256 fingerprints of pinned certificates, as hex. A node is only trusted if it presents one of them.
+    """
+    caCertificates: Optional[List[str]] = None
+    useDefaultRoots: Optional[bool] = None
+    pinnedCertificates: Optional[List[str]] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return {k: v for k, v in self.__dict__.items() if v is not None}
+
+
+@dataclass
+class ProxyConfig:
+    """Proxy of the connections to nodes, e.g. a corporate proxy or Tor.
+
+        Attributes:
+        url (str):
+            The url of the proxy, with one of the schemes `http`, `https`, `socks5` or `socks5h`, e.g. `socks5h://127.0.0.1:9050`.
+            MQTT connections only support HTTP(S) proxies.
+    """
+    url: str
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return {k: v for k, v in self.__dict__.items() if v is not None}
+
+
+@dataclass
+class RetryPolicy:
+    """The policy for retrying failed requests to a node.
+
+        Attributes:
+        maxRetries (int):
+            Max amount of retries per request and node, 0 disables retrying.
+        baseDelay (Duration):
+            Delay before the first retry, doubled for every following retry.
+        jitter (Duration):
+            Max random delay added to every retry delay.
+        retryOn (RetryOn):
+            The failures that will be retried.
+    """
+    maxRetries: Optional[int] = None
+    baseDelay: Optional[Duration] = None
+    jitter: Optional[Duration] = None
+    retryOn: Optional[RetryOn] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        config = {k: v for k, v in self.__dict__.items() if v is not None}
+
+        if 'retryOn' in config:
+            config['retryOn'] = config['retryOn'].as_dict()
+
+        return config
+
+
+@dataclass
+class PowPolicy:
+    """The policy of where the PoW of blocks is done.
+
+        Attributes:
+        type (str):
+            `static` to do the PoW locally or remotely depending on `localPow`, or `localThenRemote` to fall back to
+            remote PoW if local PoW doesn't complete within `localTimeout`.
+        localTimeout (Duration):
+            The timeout for local PoW, only for `localThenRemote`.
+    """
+    type: str = 'static'
+    localTimeout: Optional[Duration] = None
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return {k: v for k, v in self.__dict__.items() if v is not None}
+
+
+@dataclass
+class ProtocolParametersRefresh:
+    """When the protocol parameters are refreshed in the background.
+
+        Attributes:
+        type (str):
+            `interval` to request the node info every `interval`, or `onMilestone` to request it when a new milestone
+            is received over MQTT.
+        interval (Duration):
+            The interval of the refreshes, only for `interval`.
+    """
+    type: str = 'interval'
+    interval: Optional[Duration] = None
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -78,6 +258,8 @@ class ClientOptions:
             Local proof of work.
         fallbackToLocalPow (bool):
             Fallback to local proof of work if the node doesn't support remote PoW.
+        powPolicy (PowPolicy):
+            The policy of where the PoW of blocks is done.
         tipsInterval (int):
             Tips request interval during PoW in seconds.
         apiTimeout (Duration):
@@ -88,6 +270,18 @@ class ClientOptions:
             The amount of threads to be used for proof of work.
         maxParallelApiRequests (int):
             The maximum parallel API requests.
+        retryPolicy (RetryPolicy):
+            The policy for retrying failed requests.
+        explorerUrlTemplate (str):
+            The template of the block explorer urls of the network, e.g. `https://explorer.shimmer.network/shimmer/{kind}/{id}`.
+        tlsConfig (TlsConfig):
+            The TLS configuration of all connections to nodes.
+        proxy (ProxyConfig):
+            The proxy all connections to nodes are tunneled through.
+        offline (bool):
+            If the client is offline and no requests are sent to nodes, the protocol parameters are used instead of the ones of the nodes.
+        protocolParametersRefresh (ProtocolParametersRefresh):
+            When the protocol parameters are refreshed in the background, in addition to the node syncing.
     """
     primaryNode: Optional[str] = None
     primaryPowNode: Optional[str] = None
@@ -103,11 +297,18 @@ class ClientOptions:
     protocolParameters: Optional[NodeInfoProtocol] = None
     localPow: Optional[bool] = None
     fallbackToLocalPow: Optional[bool] = None
+    powPolicy: Optional[PowPolicy] = None
     tipsInterval: Optional[int] = None
     apiTimeout: Optional[Duration] = None
     remotePowTimeout: Optional[Duration] = None
     powWorkerCount: Optional[int] = None
     maxParallelApiRequests: Optional[int] = None
+    retryPolicy: Optional[RetryPolicy] = None
+    explorerUrlTemplate: Optional[str] = None
+    tlsConfig: Optional[TlsConfig] = None
+    proxy: Optional[ProxyConfig] = None
+    offline: Optional[bool] = None
+    protocolParametersRefresh: Optional[ProtocolParametersRefresh] = None
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -117,4 +318,19 @@ class ClientOptions:
         if 'brokerOptions' in config:
             config['brokerOptions'] = config['brokerOptions'].as_dict()
 
+        if 'retryPolicy' in config:
+            config['retryPolicy'] = config['retryPolicy'].as_dict()
+
+        if 'powPolicy' in config:
+            config['powPolicy'] = config['powPolicy'].as_dict()
+
+        if 'tlsConfig' in config:
+            config['tlsConfig'] = config['tlsConfig'].as_dict()
+
+        if 'proxy' in config:
+            config['proxy'] = config['proxy'].as_dict()
+
+        if 'protocolParametersRefresh' in config:
+            config['protocolParametersRefresh'] = config['protocolParametersRefresh'].as_dict()
+
         return config
@@ -14,10 +14,16 @@ class ConsolidationParams:
             Ignores the output_threshold if set to `true`.
         outputThreshold (Optional[int]):
             Consolidates if the output number is >= the output_threshold.
+        maxInputsPerTx (Optional[int]):
+            The max number of inputs of the consolidation transaction.
         targetAddress (Optional[str]):
             Address to which the consolidated output should be sent.
+        includeNativeTokens (bool):
+            Also consolidates outputs with native tokens if set to `true`.
     """
 
     force: bool
     outputThreshold: Optional[int] = None
+    maxInputsPerTx: Optional[int] = None
     targetAddress: Optional[str] = None
+    includeNativeTokens: bool = True
@@ -14,6 +14,10 @@ class WalletEventType(IntEnum):
         SpentOutput (3): An output was spent.
         TransactionInclusion (4): A transaction was included into the ledger.
         TransactionProgress (5): A progress update while submitting a transaction.
+        TransactionRetry (6): A pending transaction was promoted or reattached.
+        OutputsClaimed (7): Expiring outputs were claimed automatically.
+        NativeTokenCreationProgress (8): A step of creating a native token together with its alias.
+        SplitTransactionProgress (9): A transaction of a send that was split into multiple transactions was sent.
     """
     ConsolidationRequired = 0
     LedgerAddressGeneration = 1
@@ -21,3 +25,7 @@ class WalletEventType(IntEnum):
     SpentOutput = 3
     TransactionInclusion = 4
     TransactionProgress = 5
+    TransactionRetry = 6
+    OutputsClaimed = 7
+    NativeTokenCreationProgress = 8
+    SplitTransactionProgress = 9
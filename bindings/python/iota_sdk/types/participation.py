@@ -0,0 +1,35 @@
+# Copyright 2024 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import Dict, List
+from iota_sdk.types.common import HexStr
+
+
+@dataclass
+class TrackedParticipationOverview:
+    """Holds the information for each tracked participation.
+
+    Attributes:
+        amount: Amount of tokens that were included in the output the participation was made.
+        answers: IDs of the answers to the questions of a ballot, in the same order.
+        blockId: ID of the block that included the transaction that created the output the participation was made.
+        endMilestoneIndex: Milestone index the participation ended. 0 if the participation is still active.
+        startMilestoneIndex: Milestone index the participation started.
+    """
+    amount: str
+    answers: List[int]
+    blockId: HexStr
+    endMilestoneIndex: int
+    startMilestoneIndex: int
+
+
+@dataclass
+class AccountParticipationOverview:
+    """The participations of an account.
+
+    Attributes:
+        participations: The tracked participations per output ID, per event ID.
+    """
+    participations: Dict[HexStr, Dict[HexStr, TrackedParticipationOverview]]
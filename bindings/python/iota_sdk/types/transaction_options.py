@@ -2,7 +2,8 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from enum import Enum
-from typing import Optional, List, Union
+from typing import Dict, Optional, List, Union
+from iota_sdk.types.common import HexStr
 from iota_sdk.types.burn import Burn
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.payload import TaggedDataPayload
@@ -50,6 +51,57 @@ class RemainderValueStrategy(Enum):
         return dict({"strategy": self.name, "value": self.value[0]})
 
 
+class CoinSelectionStrategy(str, Enum):
+    """The strategy to select the inputs that fulfill the amount of a transaction.
+
+    Attributes:
+        LargestFirst: Selects the inputs with the highest amount first, which results in the fewest inputs.
+        SmallestFirst: Selects the inputs with the lowest amount first, which consolidates dust outputs over time.
+        BranchAndBound: Selects inputs whose amounts sum up to exactly the required amount first, if any, so no remainder is needed.
+        Random: Selects the inputs in a random order, so they don't reveal the other outputs of the wallet.
+    """
+    LargestFirst = 'largestFirst'
+    SmallestFirst = 'smallestFirst'
+    BranchAndBound = 'branchAndBound'
+    Random = 'random'
+
+
+class ChangeStrategy():
+    """How the remainder of a transaction is created.
+
+    Attributes:
+        strategy: `Single`, `Split` or `MergeIntoExisting`.
+        value: The max number of outputs for `Split`.
+    """
+
+    def __init__(self, strategy: str, value: Optional[int] = None):
+        self.strategy = strategy
+        self.value = value
+
+    @staticmethod
+    def single():
+        """Create a single remainder output.
+        """
+        return ChangeStrategy('Single')
+
+    @staticmethod
+    def split(count: int):
+        """Split the remainder into up to `count` outputs of rounded amounts, fewer if they would be too small.
+        """
+        return ChangeStrategy('Split', count)
+
+    @staticmethod
+    def merge_into_existing():
+        """Merge the remainder into an existing unspent output of the remainder address.
+        """
+        return ChangeStrategy('MergeIntoExisting')
+
+    def as_dict(self):
+        """Converts this object to a dict.
+        """
+        return dict({"strategy": self.strategy, "value": self.value})
+
+
 class TransactionOptions():
     """Transaction options.
 
@@ -61,6 +113,11 @@ class TransactionOptions():
         burn: Specifies what needs to be burned during input selection.
         note: A string attached to the transaction.
         allow_micro_amount: Whether to allow sending a micro amount.
+        coin_selection_strategy: The strategy to select the inputs that fulfill the amount, `SmallestFirst` by default.
+        change_strategy: How the remainder is created, a single output by default.
+        native_tokens_remainder_address: The address that receives the native token remainder, instead of the base coin remainder address.
+        token_remainder_addresses: The addresses that receive the remainder of specific native tokens, by token ID.
+        expiration_safety_margin: Inputs that expire within this many seconds after the latest milestone timestamp are not used, 60 by default.
     """
 
     def __init__(self, remainder_value_strategy: Optional[Union[RemainderValueStrategy, RemainderValueStrategyCustomAddress]] = None,
@@ -69,7 +126,12 @@ class TransactionOptions():
                  mandatory_inputs: Optional[List[OutputId]] = None,
                  burn: Optional[Burn] = None,
                  note: Optional[str] = None,
-                 allow_micro_amount: Optional[bool] = None):
+                 allow_micro_amount: Optional[bool] = None,
+                 coin_selection_strategy: Optional[CoinSelectionStrategy] = None,
+                 change_strategy: Optional[ChangeStrategy] = None,
+                 native_tokens_remainder_address: Optional[str] = None,
+                 token_remainder_addresses: Optional[Dict[HexStr, str]] = None,
+                 expiration_safety_margin: Optional[int] = None):
         """Initialize transaction options.
         """
         self.remainder_value_strategy = remainder_value_strategy
@@ -79,6 +141,11 @@ class TransactionOptions():
         self.burn = burn
         self.note = note
         self.allow_micro_amount = allow_micro_amount
+        self.coin_selection_strategy = coin_selection_strategy
+        self.change_strategy = change_strategy
+        self.native_tokens_remainder_address = native_tokens_remainder_address
+        self.token_remainder_addresses = token_remainder_addresses
+        self.expiration_safety_margin = expiration_safety_margin
 
     def as_dict(self):
         """Converts this object to a dict.
@@ -88,4 +155,7 @@ class TransactionOptions():
         if 'remainder_value_strategy' in config:
             config['remainder_value_strategy'] = config['remainder_value_strategy'].as_dict()
 
+        if 'change_strategy' in config:
+            config['change_strategy'] = config['change_strategy'].as_dict()
+
         return config
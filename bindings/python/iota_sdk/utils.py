@@ -209,6 +209,17 @@ class Utils():
             'message': message,
         })
 
+    @staticmethod
+    def verify_message(address: str, signature: Ed25519Signature,
+                       message: HexStr) -> bool:
+        """Verify a signature of a message signed with `sign_message()` against a bech32 Ed25519 address.
+        """
+        return _call_method('verifyMessage', {
+            'address': address,
+            'signature': signature.__dict__,
+            'message': message,
+        })
+
     @staticmethod
     def verify_secp256k1_ecdsa_signature(
             public_key: HexStr, signature: HexStr, message: HexStr) -> bool:
@@ -17,6 +17,7 @@ from iota_sdk.types.output_data import OutputData
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import BasicOutput, NftOutput, Output, output_from_dict
 from iota_sdk.types.output_params import OutputParams
+from iota_sdk.types.participation import AccountParticipationOverview
 from iota_sdk.types.transaction_data import PreparedTransactionData, SignedTransactionData
 from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams
 from iota_sdk.types.transaction import Transaction
@@ -382,6 +383,91 @@ class Account:
             'getBalance'
         ))
 
+    def get_voting_power(self) -> int:
+        """Get the account's total voting power (voting or NOT voting).
+        """
+        return int(self._call_account_method(
+            'getVotingPower'
+        ))
+
+    def get_participation_overview(
+            self, event_ids: Optional[List[HexStr]] = None) -> AccountParticipationOverview:
+        """Calculate a participation overview for the account. If event_ids are provided, only return outputs and
+        tracked participations for them.
+        """
+        return from_dict(AccountParticipationOverview, self._call_account_method(
+            'getParticipationOverview', {
+                'eventIds': event_ids
+            }
+        ))
+
+    def increase_voting_power(self, amount: int) -> Transaction:
+        """Designate a given amount of tokens towards the account's voting power by creating a special output, which
+        is a basic output with participation metadata.
+        """
+        return self.prepare_increase_voting_power(amount).send()
+
+    def prepare_increase_voting_power(self, amount: int) -> PreparedTransaction:
+        """Prepare to increase the account's voting power.
+        """
+        prepared = self._call_account_method(
+            'prepareIncreaseVotingPower', {
+                'amount': str(amount)
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
+    def decrease_voting_power(self, amount: int) -> Transaction:
+        """Reduce the account's voting power by a given amount. This will stop voting, but the voting data isn't
+        lost and calling `vote()` without parameters will revote.
+        """
+        return self.prepare_decrease_voting_power(amount).send()
+
+    def prepare_decrease_voting_power(self, amount: int) -> PreparedTransaction:
+        """Prepare to decrease the account's voting power.
+        """
+        prepared = self._call_account_method(
+            'prepareDecreaseVotingPower', {
+                'amount': str(amount)
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
+    def vote(self, event_id: Optional[HexStr] = None,
+             answers: Optional[List[int]] = None) -> Transaction:
+        """Vote for a participation event. Without an event ID, the participations of the voting output are
+        renewed.
+        """
+        return self.prepare_vote(event_id, answers).send()
+
+    def prepare_vote(self, event_id: Optional[HexStr] = None,
+                     answers: Optional[List[int]] = None) -> PreparedTransaction:
+        """Prepare to vote for a participation event.
+        """
+        prepared = self._call_account_method(
+            'prepareVote', {
+                'eventId': event_id,
+                'answers': answers
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
+    def stop_participating(self, event_id: HexStr) -> Transaction:
+        """Stop participating for an event.
+        """
+        return self.prepare_stop_participating(event_id).send()
+
+    def prepare_stop_participating(
+            self, event_id: HexStr) -> PreparedTransaction:
+        """Prepare to stop participating for an event.
+        """
+        prepared = self._call_account_method(
+            'prepareStopParticipating', {
+                'eventId': event_id
+            }
+        )
+        return PreparedTransaction(self, prepared)
+
     def prepare_output(self, params: OutputParams,
                        transaction_options: Optional[TransactionOptions] = None) -> Union[BasicOutput, NftOutput]:
         """Prepare an output for sending.
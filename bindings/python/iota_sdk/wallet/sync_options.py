@@ -1,6 +1,7 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
+from enum import Enum
 from typing import List, Optional
 
 
@@ -67,6 +68,17 @@ class NftSyncOptions():
         self.aliasOutputs = alias_outputs
 
 
+class AddressDiscoveryMode(str, Enum):
+    """How unknown addresses of an account are discovered.
+
+    Attributes:
+        Linear: Check every address until `address_gap_limit` addresses in a row are unused.
+        Exponential: Only check the addresses at an offset of 1, 2, 4, 8, ... up to `address_gap_limit` after the last used one.
+    """
+    Linear = 'linear'
+    Exponential = 'exponential'
+
+
 class SyncOptions():
     """The synchronization options.
 
@@ -103,6 +115,11 @@ class SyncOptions():
         This will overwrite the `account`, `alias` and `nft` options.
     sync_native_token_foundries :
         Sync native token foundries, so their metadata can be returned in the balance.
+    address_gap_limit :
+        The number of unused addresses after the last used one that are checked for unspent outputs
+        before syncing. Addresses are not discovered if 0 or if specific `addresses` are synced.
+    address_discovery :
+        How the addresses within the `address_gap_limit` are checked.
     """
 
     def __init__(self,
@@ -116,7 +133,9 @@ class SyncOptions():
                  alias: Optional[AliasSyncOptions] = None,
                  nft: Optional[NftSyncOptions] = None,
                  sync_only_most_basic_outputs: Optional[bool] = None,
-                 sync_native_token_foundries: Optional[bool] = None):
+                 sync_native_token_foundries: Optional[bool] = None,
+                 address_gap_limit: Optional[int] = None,
+                 address_discovery: Optional[AddressDiscoveryMode] = None):
         """Initialize `Self`.
         """
         self.addresses = addresses
@@ -130,6 +149,8 @@ class SyncOptions():
         self.nft = nft
         self.syncOnlyMostBasicOutputs = sync_only_most_basic_outputs
         self.syncNativeTokenFoundries = sync_native_token_foundries
+        self.addressGapLimit = address_gap_limit
+        self.addressDiscovery = address_discovery
 
     def as_dict(self):
         """Converts this object to a dict.
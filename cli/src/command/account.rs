@@ -5,7 +5,7 @@ use std::str::FromStr;
 
 use clap::{CommandFactory, Parser, Subcommand};
 use iota_sdk::{
-    client::request_funds_from_faucet,
+    client::{api::PreparedTransactionData, request_funds_from_faucet},
     types::{
         api::plugins::participation::types::ParticipationEventId,
         block::{
@@ -368,8 +368,8 @@ pub async fn balance_command(account: &Account, addresses: Option<Vec<Bech32Addr
 pub async fn burn_native_token_command(account: &Account, token_id: String, amount: String) -> Result<(), Error> {
     println_log_info!("Burning native token {token_id} {amount}.");
 
-    let transaction = account
-        .burn(
+    let prepared_transaction = account
+        .prepare_burn(
             NativeToken::new(
                 TokenId::from_str(&token_id)?,
                 U256::from_dec_str(&amount).map_err(|e| Error::Miscellaneous(e.to_string()))?,
@@ -377,6 +377,7 @@ pub async fn burn_native_token_command(account: &Account, token_id: String, amou
             None,
         )
         .await?;
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, None).await?;
 
     println_log_info!(
         "Burning transaction sent:\n{:?}\n{:?}",
@@ -391,7 +392,8 @@ pub async fn burn_native_token_command(account: &Account, token_id: String, amou
 pub async fn burn_nft_command(account: &Account, nft_id: String) -> Result<(), Error> {
     println_log_info!("Burning nft {nft_id}.");
 
-    let transaction = account.burn(NftId::from_str(&nft_id)?, None).await?;
+    let prepared_transaction = account.prepare_burn(NftId::from_str(&nft_id)?, None).await?;
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, None).await?;
 
     println_log_info!(
         "Burning transaction sent:\n{:?}\n{:?}",
@@ -560,7 +562,8 @@ pub async fn create_native_token_command(
 pub async fn destroy_alias_command(account: &Account, alias_id: String) -> Result<(), Error> {
     println_log_info!("Destroying alias {alias_id}.");
 
-    let transaction = account.burn(AliasId::from_str(&alias_id)?, None).await?;
+    let prepared_transaction = account.prepare_burn(AliasId::from_str(&alias_id)?, None).await?;
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, None).await?;
 
     println_log_info!(
         "Destroying alias transaction sent:\n{:?}\n{:?}",
@@ -575,7 +578,8 @@ pub async fn destroy_alias_command(account: &Account, alias_id: String) -> Resul
 pub async fn destroy_foundry_command(account: &Account, foundry_id: String) -> Result<(), Error> {
     println_log_info!("Destroying foundry {foundry_id}.");
 
-    let transaction = account.burn(FoundryId::from_str(&foundry_id)?, None).await?;
+    let prepared_transaction = account.prepare_burn(FoundryId::from_str(&foundry_id)?, None).await?;
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, None).await?;
 
     println_log_info!(
         "Destroying foundry transaction sent:\n{:?}\n{:?}",
@@ -736,15 +740,12 @@ pub async fn send_command(
     let params = [SendParams::new(amount, address)?
         .with_return_address(return_address.map(ConvertTo::convert).transpose()?)
         .with_expiration(expiration)];
-    let transaction = account
-        .send_with_params(
-            params,
-            TransactionOptions {
-                allow_micro_amount,
-                ..Default::default()
-            },
-        )
-        .await?;
+    let options = TransactionOptions {
+        allow_micro_amount,
+        ..Default::default()
+    };
+    let prepared_transaction = account.prepare_send(params, options.clone()).await?;
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, options).await?;
 
     println_log_info!(
         "Transaction sent:\n{:?}\n{:?}",
@@ -764,7 +765,7 @@ pub async fn send_native_token_command(
     gift_storage_deposit: Option<bool>,
 ) -> Result<(), Error> {
     let address = address.convert()?;
-    let transaction = if gift_storage_deposit.unwrap_or(false) {
+    let prepared_transaction = if gift_storage_deposit.unwrap_or(false) {
         // Send native tokens together with the required storage deposit
         let rent_structure = account.client().get_rent_structure().await?;
         let token_supply = account.client().get_token_supply().await?;
@@ -779,7 +780,7 @@ pub async fn send_native_token_command(
             )?])
             .finish_output(token_supply)?];
 
-        account.send_outputs(outputs, None).await?
+        account.prepare_transaction(outputs, None).await?
     } else {
         // Send native tokens with storage deposit return and expiration
         let outputs = [SendNativeTokensParams::new(
@@ -789,8 +790,9 @@ pub async fn send_native_token_command(
                 U256::from_dec_str(&amount).map_err(|e| Error::Miscellaneous(e.to_string()))?,
             )],
         )?];
-        account.send_native_tokens(outputs, None).await?
+        account.prepare_send_native_tokens(outputs, None).await?
     };
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, None).await?;
 
     println_log_info!(
         "Native token transaction sent:\n{:?}\n{:?}",
@@ -808,7 +810,8 @@ pub async fn send_nft_command(
     nft_id: String,
 ) -> Result<(), Error> {
     let outputs = [SendNftParams::new(address.convert()?, &nft_id)?];
-    let transaction = account.send_nft(outputs, None).await?;
+    let prepared_transaction = account.prepare_send_nft(outputs, None).await?;
+    let transaction = sign_and_submit_transaction(account, prepared_transaction, None).await?;
 
     println_log_info!(
         "Nft transaction sent:\n{:?}\n{:?}",
@@ -956,6 +959,23 @@ pub async fn voting_output_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// Prints the summary of the transaction before it's signed and submitted.
+async fn sign_and_submit_transaction(
+    account: &Account,
+    prepared_transaction: PreparedTransactionData,
+    options: impl Into<Option<TransactionOptions>> + Send,
+) -> Result<Transaction, Error> {
+    let protocol_parameters = account.client().get_protocol_parameters().await?;
+    println_log_info!(
+        "Transaction summary:\n{}",
+        prepared_transaction.summary(&protocol_parameters)?
+    );
+
+    Ok(account
+        .sign_and_submit_transaction(prepared_transaction, options)
+        .await?)
+}
+
 async fn print_address(account: &Account, address: &Bech32Address) -> Result<(), Error> {
     let mut formatted_string = String::new();
 
@@ -7,10 +7,13 @@ use std::collections::{HashMap, HashSet};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{AliasId, FoundryId, NativeToken, NftId, TokenId};
+use super::{Error, InputSelection, InvalidBurnReason};
+use crate::types::block::output::{AliasId, FoundryId, NativeToken, NftId, OutputId, Rent, TokenId};
 
 /// A type to specify what needs to be burned during input selection.
 /// Nothing will be burned that has not been explicitly set with this struct.
+///
+/// Use [`Burn::builder()`] to create a burn that is checked for consistency.
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct Burn {
     /// Aliases to burn.
@@ -21,6 +24,8 @@ pub struct Burn {
     pub(crate) foundries: HashSet<FoundryId>,
     /// Amounts of native tokens to burn.
     pub(crate) native_tokens: BTreeMap<TokenId, U256>,
+    /// Amounts of native tokens to melt with their foundry.
+    pub(crate) melted_native_tokens: BTreeMap<TokenId, U256>,
 }
 
 impl Burn {
@@ -29,6 +34,11 @@ impl Burn {
         Self::default()
     }
 
+    /// Creates a new [`BurnBuilder`].
+    pub fn builder() -> BurnBuilder {
+        BurnBuilder::default()
+    }
+
     /// Adds an alias to [`Burn`].
     pub fn add_alias(mut self, alias_id: AliasId) -> Self {
         self.aliases.insert(alias_id);
@@ -99,6 +109,147 @@ impl Burn {
     pub fn native_tokens(&self) -> &BTreeMap<TokenId, U256> {
         &self.native_tokens
     }
+
+    /// Returns the native tokens to melt of [`Burn`].
+    pub fn melted_native_tokens(&self) -> &BTreeMap<TokenId, U256> {
+        &self.melted_native_tokens
+    }
+
+    /// Checks that the [`Burn`] can be done in a single transaction.
+    pub fn verify(&self) -> Result<(), Error> {
+        if let Some((token_id, _)) = self
+            .native_tokens
+            .iter()
+            .chain(self.melted_native_tokens.iter())
+            .find(|(_, amount)| amount.is_zero())
+        {
+            return Err(Error::InvalidBurn(InvalidBurnReason::ZeroAmount(*token_id)));
+        }
+
+        for token_id in self.melted_native_tokens.keys() {
+            let foundry_id = FoundryId::from(*token_id);
+
+            if self.foundries.contains(&foundry_id) {
+                return Err(Error::InvalidBurn(InvalidBurnReason::MeltAndDestroyFoundry(foundry_id)));
+            }
+        }
+
+        // A foundry can only be transitioned or destroyed with a state transition of its alias.
+        for foundry_id in self.foundries.iter().copied().chain(
+            self.melted_native_tokens
+                .keys()
+                .map(|token_id| FoundryId::from(*token_id)),
+        ) {
+            let alias_id = *foundry_id.alias_address().alias_id();
+
+            if self.aliases.contains(&alias_id) {
+                return Err(Error::InvalidBurn(InvalidBurnReason::FoundryOfDestroyedAlias {
+                    foundry_id,
+                    alias_id,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Selects the inputs of the [`Burn`] with `input_selection`, without outputs of its own, and reports the
+    /// resulting base coin and storage deposit movements.
+    pub fn dry_run(&self, input_selection: InputSelection) -> Result<BurnDryRun, Error> {
+        self.verify()?;
+
+        let rent_structure = *input_selection.protocol_parameters.rent_structure();
+        let selected = input_selection.burn(self.clone()).select()?;
+
+        Ok(BurnDryRun {
+            inputs: selected.inputs.iter().map(|input| *input.output_id()).collect(),
+            input_amount: selected.inputs.iter().map(|input| input.output.amount()).sum(),
+            output_amount: selected.outputs.iter().map(|output| output.amount()).sum(),
+            remainder_amount: selected
+                .remainder
+                .as_ref()
+                .map_or(0, |remainder| remainder.output.amount()),
+            input_storage_deposit: selected
+                .inputs
+                .iter()
+                .map(|input| input.output.rent_cost(&rent_structure))
+                .sum(),
+            output_storage_deposit: selected
+                .outputs
+                .iter()
+                .map(|output| output.rent_cost(&rent_structure))
+                .sum(),
+        })
+    }
+}
+
+/// A builder of a [`Burn`] that is checked for consistency, e.g. a foundry can't be destroyed when its tokens are
+/// melted.
+#[derive(Debug, Default, Clone)]
+#[must_use]
+pub struct BurnBuilder(Burn);
+
+impl BurnBuilder {
+    /// Burns an amount of a native token, adding to the amount that is already burned.
+    pub fn burn_native_token(mut self, token_id: TokenId, amount: impl Into<U256>) -> Self {
+        *self.0.native_tokens.entry(token_id).or_default() += amount.into();
+        self
+    }
+
+    /// Melts an amount of a native token with its foundry, adding to the amount that is already melted.
+    pub fn melt_native_token(mut self, token_id: TokenId, amount: impl Into<U256>) -> Self {
+        *self.0.melted_native_tokens.entry(token_id).or_default() += amount.into();
+        self
+    }
+
+    /// Destroys an alias.
+    pub fn destroy_alias(mut self, alias_id: AliasId) -> Self {
+        self.0.aliases.insert(alias_id);
+        self
+    }
+
+    /// Burns an NFT.
+    pub fn burn_nft(mut self, nft_id: NftId) -> Self {
+        self.0.nfts.insert(nft_id);
+        self
+    }
+
+    /// Destroys a foundry.
+    pub fn destroy_foundry(mut self, foundry_id: FoundryId) -> Self {
+        self.0.foundries.insert(foundry_id);
+        self
+    }
+
+    /// Finishes the [`BurnBuilder`] into a [`Burn`], see [`Burn::verify()`].
+    pub fn finish(self) -> Result<Burn, Error> {
+        self.0.verify()?;
+
+        Ok(self.0)
+    }
+}
+
+/// The base coin and storage deposit movements of a [`Burn`], see [`Burn::dry_run()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnDryRun {
+    /// The inputs that would be consumed.
+    pub inputs: Vec<OutputId>,
+    /// The base coin amount of the consumed inputs.
+    pub input_amount: u64,
+    /// The base coin amount of the created outputs, including the remainder.
+    pub output_amount: u64,
+    /// The base coin amount that would be sent to the remainder address.
+    pub remainder_amount: u64,
+    /// The storage deposit required by the consumed inputs.
+    pub input_storage_deposit: u64,
+    /// The storage deposit required by the created outputs.
+    pub output_storage_deposit: u64,
+}
+
+impl BurnDryRun {
+    /// Returns the storage deposit that is released by the burn.
+    pub fn released_storage_deposit(&self) -> u64 {
+        self.input_storage_deposit.saturating_sub(self.output_storage_deposit)
+    }
 }
 
 impl From<FoundryId> for Burn {
@@ -141,6 +292,9 @@ pub struct BurnDto {
     /// Amounts of native tokens to burn.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) native_tokens: Option<BTreeMap<TokenId, U256>>,
+    /// Amounts of native tokens to melt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) melted_native_tokens: Option<BTreeMap<TokenId, U256>>,
 }
 
 impl From<&Burn> for BurnDto {
@@ -155,6 +309,8 @@ impl From<&Burn> for BurnDto {
                     .iter()
                     .map(|(token_id, amount)| (*token_id, *amount)),
             )),
+            melted_native_tokens: (!value.melted_native_tokens.is_empty())
+                .then_some(value.melted_native_tokens.clone()),
         }
     }
 }
@@ -166,6 +322,7 @@ impl From<BurnDto> for Burn {
             nfts: value.nfts.unwrap_or_default(),
             foundries: value.foundries.unwrap_or_default(),
             native_tokens: value.native_tokens.unwrap_or_default(),
+            melted_native_tokens: value.melted_native_tokens.unwrap_or_default(),
         }
     }
 }
@@ -8,7 +8,7 @@ use std::fmt::Debug;
 use primitive_types::U256;
 
 use super::Requirement;
-use crate::types::block::output::{ChainId, OutputId, TokenId};
+use crate::types::block::output::{AliasId, ChainId, FoundryId, OutputId, TokenId};
 
 /// Errors related to input selection.
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -37,6 +37,9 @@ pub enum Error {
         /// The required amount.
         required: U256,
     },
+    /// The burn can't be done in a single transaction.
+    #[error("invalid burn: {0}")]
+    InvalidBurn(InvalidBurnReason),
     /// Invalid amount of inputs.
     #[error("invalid amount of inputs: {0}")]
     InvalidInputCount(usize),
@@ -55,7 +58,82 @@ pub enum Error {
     /// Required input is not available.
     #[error("required input {0} is not available")]
     RequiredInputIsNotAvailable(OutputId),
+    /// Required input is available but can't be unlocked.
+    #[error("required input {output_id} can't be used: {reason}")]
+    RequiredInputIsUnusable {
+        /// The output ID of the input.
+        output_id: OutputId,
+        /// Why the input can't be used.
+        reason: UnusableInputReason,
+    },
+    /// The transaction would contain more distinct native tokens than allowed.
+    #[error(
+        "too many native tokens: found {found}, max {max}; consolidate the outputs holding native tokens or send them in \
+         separate transactions"
+    )]
+    TooManyNativeTokens {
+        /// The number of distinct native tokens found.
+        found: usize,
+        /// The max number of distinct native tokens.
+        max: usize,
+    },
     /// Unfulfillable requirement.
     #[error("unfulfillable requirement {0:?}")]
     UnfulfillableRequirement(Requirement),
 }
+
+/// The reason why a burn is inconsistent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvalidBurnReason {
+    /// A native token amount is zero.
+    ZeroAmount(TokenId),
+    /// Native tokens of a foundry that is destroyed are melted.
+    MeltAndDestroyFoundry(FoundryId),
+    /// A foundry is destroyed or melts native tokens while its alias is destroyed.
+    FoundryOfDestroyedAlias {
+        /// The foundry ID.
+        foundry_id: FoundryId,
+        /// The alias ID.
+        alias_id: AliasId,
+    },
+}
+
+impl std::fmt::Display for InvalidBurnReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ZeroAmount(token_id) => write!(f, "zero amount of native token {token_id}"),
+            Self::MeltAndDestroyFoundry(foundry_id) => {
+                write!(f, "can't melt native tokens of foundry {foundry_id} that is destroyed")
+            }
+            Self::FoundryOfDestroyedAlias { foundry_id, alias_id } => write!(
+                f,
+                "foundry {foundry_id} requires a state transition of alias {alias_id} that is destroyed"
+            ),
+        }
+    }
+}
+
+/// The reason why an input can't be used by input selection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnusableInputReason {
+    /// The output kind can't be used as input, e.g. a treasury output.
+    UnsupportedOutputKind,
+    /// The output is time locked.
+    TimeLocked,
+    /// The output expires within the expiration safety margin, so it could return to the sender before the
+    /// transaction is confirmed.
+    ExpiresSoon,
+    /// The output isn't unlocked by the provided addresses.
+    NotControlled,
+}
+
+impl std::fmt::Display for UnusableInputReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedOutputKind => write!(f, "unsupported output kind"),
+            Self::TimeLocked => write!(f, "time locked"),
+            Self::ExpiresSoon => write!(f, "expires within the safety margin"),
+            Self::NotControlled => write!(f, "not unlocked by the provided addresses"),
+        }
+    }
+}
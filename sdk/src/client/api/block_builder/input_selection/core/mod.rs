@@ -5,6 +5,7 @@ pub(crate) mod burn;
 pub(crate) mod error;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
+pub(crate) mod strategy;
 pub(crate) mod transition;
 
 use core::ops::Deref;
@@ -12,11 +13,17 @@ use std::collections::{HashMap, HashSet};
 
 use packable::PackableExt;
 pub(crate) use requirement::is_alias_transition;
+use requirement::native_tokens::native_token_ids;
 
 pub use self::{
-    burn::{Burn, BurnDto},
-    error::Error,
+    burn::{Burn, BurnBuilder, BurnDryRun, BurnDto},
+    error::{Error, InvalidBurnReason, UnusableInputReason},
+    remainder::ChangeStrategy,
     requirement::Requirement,
+    strategy::{
+        BranchAndBound, CoinSelectionStrategy, InputSelectionStrategy, LargestFirst, RandomOrder, SeededRandomOrder,
+        SmallestFirst,
+    },
 };
 use crate::{
     client::{api::types::RemainderData, secret::types::InputSigningData},
@@ -24,8 +31,8 @@ use crate::{
         address::{Address, AliasAddress, NftAddress},
         input::INPUT_COUNT_RANGE,
         output::{
-            AliasOutput, AliasTransition, ChainId, FoundryOutput, NativeTokensBuilder, NftOutput, Output, OutputId,
-            OUTPUT_COUNT_RANGE,
+            AliasOutput, AliasTransition, ChainId, FoundryOutput, NativeTokens, NativeTokensBuilder, NftOutput, Output,
+            OutputId, TokenId, OUTPUT_COUNT_RANGE,
         },
         protocol::ProtocolParameters,
     },
@@ -44,8 +51,14 @@ pub struct InputSelection {
     remainder_address: Option<Address>,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
+    expiration_safety_margin: u32,
+    max_native_token_count: usize,
     requirements: Vec<Requirement>,
     automatically_transitioned: HashMap<ChainId, Option<AliasTransition>>,
+    strategy: Box<dyn InputSelectionStrategy>,
+    change_strategy: ChangeStrategy,
+    native_tokens_remainder_address: Option<Address>,
+    token_remainder_addresses: HashMap<TokenId, Address>,
 }
 
 /// Result of the input selection algorithm.
@@ -189,14 +202,34 @@ impl InputSelection {
             remainder_address: None,
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
+            expiration_safety_margin: 0,
+            max_native_token_count: NativeTokens::COUNT_MAX.into(),
             requirements: Vec::new(),
             automatically_transitioned: HashMap::new(),
+            strategy: Box::<CoinSelectionStrategy>::default(),
+            change_strategy: ChangeStrategy::default(),
+            native_tokens_remainder_address: None,
+            token_remainder_addresses: HashMap::new(),
         }
     }
 
-    /// Sets the required inputs of an [`InputSelection`].
+    /// Sets the required inputs of an [`InputSelection`], they have to be part of the available inputs and are always
+    /// consumed. The other available inputs are only selected if needed.
     pub fn required_inputs(mut self, inputs: impl Into<HashSet<OutputId>>) -> Self {
-        self.required_inputs = inputs.into();
+        self.required_inputs.extend(inputs.into());
+        self
+    }
+
+    /// Adds mandatory inputs to an [`InputSelection`], that are always consumed, in addition to the optional pool of
+    /// available inputs. Selection fails with [`Error::RequiredInputIsUnusable`] if one of them can't be unlocked,
+    /// rather than with [`Error::InsufficientAmount`].
+    pub fn mandatory_inputs(mut self, inputs: impl IntoIterator<Item = InputSigningData>) -> Self {
+        for input in inputs {
+            self.required_inputs.insert(*input.output_id());
+            self.available_inputs
+                .retain(|available| available.output_id() != input.output_id());
+            self.available_inputs.push(input);
+        }
         self
     }
 
@@ -218,40 +251,112 @@ impl InputSelection {
         self
     }
 
-    /// Sets the timestamp of an [`InputSelection`].
+    /// Sets the remainder address of the native tokens of an [`InputSelection`], they are sent to the base coin
+    /// remainder address by default. Native tokens with their own remainder address are sent to a separate output
+    /// with the minimum storage deposit.
+    pub fn native_tokens_remainder_address(mut self, address: impl Into<Option<Address>>) -> Self {
+        self.native_tokens_remainder_address = address.into();
+        self
+    }
+
+    /// Sets the remainder address of a native token of an [`InputSelection`], it takes precedence over the
+    /// [`InputSelection::native_tokens_remainder_address()`].
+    pub fn token_remainder_address(mut self, token_id: TokenId, address: Address) -> Self {
+        self.token_remainder_addresses.insert(token_id, address);
+        self
+    }
+
+    /// Sets the timestamp of an [`InputSelection`], usually the timestamp of the latest milestone.
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
         self
     }
 
-    fn filter_inputs(&mut self) {
-        self.available_inputs.retain(|input| {
-            // Keep alias outputs because at this point we do not know if a state or governor address will be required.
-            if input.output.is_alias() {
-                return true;
-            }
-            // Filter out non basic/foundry/nft outputs.
-            else if !input.output.is_basic() && !input.output.is_foundry() && !input.output.is_nft() {
-                return false;
-            }
+    /// Sets the expiration safety margin in seconds of an [`InputSelection`], defaults to 0. Inputs with an expiration
+    /// unlock condition that expires within the margin after the timestamp are not selected, as they could return to
+    /// the sender before the transaction is confirmed.
+    pub fn expiration_safety_margin(mut self, expiration_safety_margin: u32) -> Self {
+        self.expiration_safety_margin = expiration_safety_margin;
+        self
+    }
+
+    /// Sets the max number of distinct native tokens of the transaction of an [`InputSelection`], defaults to and
+    /// can't exceed [`NativeTokens::COUNT_MAX`].
+    pub fn max_native_token_count(mut self, max_native_token_count: u8) -> Self {
+        self.max_native_token_count = max_native_token_count.min(NativeTokens::COUNT_MAX).into();
+        self
+    }
+
+    /// Sets the strategy that orders the inputs to fulfill the amount requirement of an [`InputSelection`], defaults to
+    /// [`CoinSelectionStrategy::SmallestFirst`].
+    pub fn strategy(mut self, strategy: impl InputSelectionStrategy + 'static) -> Self {
+        self.strategy = Box::new(strategy);
+        self
+    }
+
+    // Returns why an input can't be used, `None` if it can.
+    fn unusable_reason(&self, input: &InputSigningData) -> Option<UnusableInputReason> {
+        // Keep alias outputs because at this point we do not know if a state or governor address will be required.
+        if input.output.is_alias() {
+            return None;
+        }
+        // Filter out non basic/foundry/nft outputs.
+        else if !input.output.is_basic() && !input.output.is_foundry() && !input.output.is_nft() {
+            return Some(UnusableInputReason::UnsupportedOutputKind);
+        }
 
+        // PANIC: safe to unwrap as non basic/alias/foundry/nft outputs are already filtered out.
+        let unlock_conditions = input.output.unlock_conditions().unwrap();
+
+        if unlock_conditions.is_time_locked(self.timestamp) {
+            return Some(UnusableInputReason::TimeLocked);
+        }
+
+        // The unlocking address would change if the transaction gets confirmed after the expiration.
+        if !unlock_conditions.is_expired(self.timestamp)
+            && unlock_conditions.is_expired(self.timestamp.saturating_add(self.expiration_safety_margin))
+        {
+            return Some(UnusableInputReason::ExpiresSoon);
+        }
+
+        let required_address = input
+            .output
+            // Alias transition is irrelevant here as we keep aliases anyway.
+            .required_and_unlocked_address(self.timestamp, input.output_id(), None)
             // PANIC: safe to unwrap as non basic/alias/foundry/nft outputs are already filtered out.
-            let unlock_conditions = input.output.unlock_conditions().unwrap();
+            .unwrap()
+            .0;
+
+        (!self.addresses.contains(&required_address)).then_some(UnusableInputReason::NotControlled)
+    }
 
-            if unlock_conditions.is_time_locked(self.timestamp) {
-                return false;
+    /// Sets how the remainder of an [`InputSelection`] is created, defaults to [`ChangeStrategy::Single`].
+    pub fn change_strategy(mut self, change_strategy: ChangeStrategy) -> Self {
+        self.change_strategy = change_strategy;
+        self
+    }
+
+    fn filter_inputs(&mut self) -> Result<(), Error> {
+        let mut available_inputs = std::mem::take(&mut self.available_inputs);
+        let mut result = Ok(());
+
+        available_inputs.retain(|input| match self.unusable_reason(input) {
+            None => true,
+            Some(reason) => {
+                // Required inputs can't be dropped silently.
+                if result.is_ok() && self.required_inputs.contains(input.output_id()) {
+                    result = Err(Error::RequiredInputIsUnusable {
+                        output_id: *input.output_id(),
+                        reason,
+                    });
+                }
+                false
             }
+        });
 
-            let required_address = input
-                .output
-                // Alias transition is irrelevant here as we keep aliases anyway.
-                .required_and_unlocked_address(self.timestamp, input.output_id(), None)
-                // PANIC: safe to unwrap as non basic/alias/foundry/nft outputs are already filtered out.
-                .unwrap()
-                .0;
+        self.available_inputs = available_inputs;
 
-            self.addresses.contains(&required_address)
-        })
+        result
     }
 
     // Inputs need to be sorted before signing, because the reference unlock conditions can only reference a lower index
@@ -368,7 +473,7 @@ impl InputSelection {
             }
         }
 
-        self.filter_inputs();
+        self.filter_inputs()?;
 
         if self.available_inputs.is_empty() {
             return Err(Error::NoAvailableInputsProvided);
@@ -392,13 +497,39 @@ impl InputSelection {
             return Err(Error::InvalidInputCount(self.selected_inputs.len()));
         }
 
-        let (remainder, storage_deposit_returns) = self.remainder_and_storage_deposit_return_outputs()?;
+        let native_token_count = native_token_ids(
+            self.selected_inputs
+                .iter()
+                .map(|input| &input.output)
+                .chain(self.outputs.iter()),
+        )
+        .len();
+
+        if native_token_count > self.max_native_token_count {
+            return Err(Error::TooManyNativeTokens {
+                found: native_token_count,
+                max: self.max_native_token_count,
+            });
+        }
+
+        if self.change_strategy == ChangeStrategy::MergeIntoExisting {
+            self.select_remainder_merge_input()?;
+        }
+
+        let (mut remainder, additional_outputs) = self.remainder_and_storage_deposit_return_outputs()?;
+        let split_remainder_outputs = match (&mut remainder, self.change_strategy) {
+            (Some(remainder), ChangeStrategy::Split(count)) => {
+                self.split_remainder(remainder, count, additional_outputs.len())?
+            }
+            _ => Vec::new(),
+        };
 
         if let Some(remainder) = &remainder {
             self.outputs.push(remainder.output.clone());
         }
 
-        self.outputs.extend(storage_deposit_returns);
+        self.outputs.extend(split_remainder_outputs);
+        self.outputs.extend(additional_outputs);
 
         // Check again, because more outputs may have been added.
         if !OUTPUT_COUNT_RANGE.contains(&(self.outputs.len() as u16)) {
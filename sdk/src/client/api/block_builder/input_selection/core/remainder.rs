@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use crypto::keys::bip44::Bip44;
+use serde::{Deserialize, Serialize};
 
 use super::{
     requirement::{
@@ -15,10 +18,35 @@ use crate::{
     client::api::RemainderData,
     types::block::{
         address::{Address, Ed25519Address},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokensBuilder, Output},
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            OUTPUT_COUNT_MAX,
+        },
     },
 };
 
+/// How the remainder of a transaction is created.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", content = "value")]
+pub enum ChangeStrategy {
+    /// Create a single remainder output.
+    #[default]
+    Single,
+    /// Split the remainder into up to the given number of outputs of rounded amounts, e.g. to be able to send
+    /// multiple transactions in parallel. Fewer outputs are created if they would hold less than
+    /// [`ChangeStrategy::DUST_AVOIDANCE_FACTOR`] times their minimum storage deposit.
+    Split(u8),
+    /// Merge the remainder into an existing unspent basic output of the remainder address, which is consumed as
+    /// additional input, so the transaction doesn't increase the number of outputs.
+    MergeIntoExisting,
+}
+
+impl ChangeStrategy {
+    /// Split remainder outputs hold at least this many times their minimum storage deposit.
+    pub const DUST_AVOIDANCE_FACTOR: u64 = 2;
+}
+
 impl InputSelection {
     // Gets the remainder address from configuration of finds one from the inputs.
     fn get_remainder_address(&self) -> Result<Option<(Address, Option<Bip44>)>, Error> {
@@ -63,6 +91,50 @@ impl InputSelection {
         Ok(None)
     }
 
+    // Moves the native tokens of the remainder that have their own remainder address to separate outputs with the
+    // minimum storage deposit, returning the native tokens that stay in the base coin remainder and these outputs.
+    fn native_token_remainder_outputs(
+        &self,
+        native_tokens: Option<NativeTokens>,
+    ) -> Result<(Option<NativeTokens>, Vec<Output>), Error> {
+        let Some(native_tokens) = native_tokens else {
+            return Ok((None, Vec::new()));
+        };
+
+        let mut base_native_tokens = NativeTokensBuilder::new();
+        let mut separate_native_tokens = BTreeMap::<Address, NativeTokensBuilder>::new();
+
+        for native_token in native_tokens.iter() {
+            match self
+                .token_remainder_addresses
+                .get(native_token.token_id())
+                .or(self.native_tokens_remainder_address.as_ref())
+            {
+                Some(address) => separate_native_tokens
+                    .entry(*address)
+                    .or_default()
+                    .add_native_token(*native_token)?,
+                None => base_native_tokens.add_native_token(*native_token)?,
+            }
+        }
+
+        let outputs = separate_native_tokens
+            .into_iter()
+            .map(|(address, native_tokens)| {
+                log::debug!("Adding {native_tokens:?} to native token remainder output for {address:?}");
+                Ok(
+                    BasicOutputBuilder::new_with_minimum_storage_deposit(*self.protocol_parameters.rent_structure())
+                        .add_unlock_condition(AddressUnlockCondition::new(address))
+                        .with_native_tokens(native_tokens.finish()?)
+                        .finish_output(self.protocol_parameters.token_supply())?,
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        let base_native_tokens = base_native_tokens.finish()?;
+
+        Ok(((!base_native_tokens.is_empty()).then_some(base_native_tokens), outputs))
+    }
+
     pub(crate) fn remainder_amount(&self) -> Result<(u64, bool), Error> {
         let mut input_native_tokens = get_native_tokens(self.selected_inputs.iter().map(|input| &input.output))?;
         let mut output_native_tokens = get_native_tokens(self.outputs.iter())?;
@@ -78,6 +150,8 @@ impl InputSelection {
 
         let native_tokens_diff = get_native_tokens_diff(&input_native_tokens, &output_native_tokens)?;
         let native_tokens_remainder = native_tokens_diff.is_some();
+        let (native_tokens_diff, native_token_remainder_outputs) =
+            self.native_token_remainder_outputs(native_tokens_diff)?;
 
         let mut remainder_builder =
             BasicOutputBuilder::new_with_minimum_storage_deposit(*self.protocol_parameters.rent_structure())
@@ -92,11 +166,101 @@ impl InputSelection {
         Ok((
             remainder_builder
                 .finish_output(self.protocol_parameters.token_supply())?
-                .amount(),
+                .amount()
+                + native_token_remainder_outputs.iter().map(Output::amount).sum::<u64>(),
             native_tokens_remainder,
         ))
     }
 
+    // Selects the smallest available simple deposit of the remainder address, so the remainder replaces it.
+    pub(crate) fn select_remainder_merge_input(&mut self) -> Result<(), Error> {
+        let Some(remainder) = self.remainder_and_storage_deposit_return_outputs()?.0 else {
+            log::debug!("No remainder to merge");
+            return Ok(());
+        };
+
+        if self.selected_inputs.len() >= INPUT_COUNT_MAX.into() {
+            return Ok(());
+        }
+
+        let merge_input = self
+            .available_inputs
+            .iter()
+            .enumerate()
+            .filter(|(_, input)| match &input.output {
+                Output::Basic(basic) => basic.simple_deposit_address() == Some(&remainder.address),
+                _ => false,
+            })
+            .min_by_key(|(_, input)| input.output.amount())
+            .map(|(index, _)| index);
+
+        if let Some(index) = merge_input {
+            let input = self.available_inputs.swap_remove(index);
+            log::debug!("Merging remainder into {}", input.output_id());
+            self.select_input(input, None)?;
+        }
+
+        Ok(())
+    }
+
+    // Splits the base coins of the remainder into multiple outputs of the remainder address, the native tokens stay in
+    // the first one.
+    pub(crate) fn split_remainder(
+        &self,
+        remainder: &mut RemainderData,
+        count: u8,
+        additional_output_count: usize,
+    ) -> Result<Vec<Output>, Error> {
+        let rent_structure = *self.protocol_parameters.rent_structure();
+        let token_supply = self.protocol_parameters.token_supply();
+        let min_amount = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(remainder.address))
+            .finish_output(token_supply)?
+            .amount();
+        let first_min_amount = BasicOutputBuilder::from(remainder.output.as_basic())
+            .with_minimum_storage_deposit(rent_structure)
+            .finish_output(token_supply)?
+            .amount();
+        // Round the amounts down to the highest power of ten below the minimum storage deposit.
+        let rounding = 10u64.pow(min_amount.max(1).ilog10());
+        let amount = remainder.output.amount();
+        let capacity = (OUTPUT_COUNT_MAX as usize).saturating_sub(self.outputs.len() + additional_output_count + 1);
+
+        let mut count = u64::from(count).min(capacity as u64 + 1).max(1);
+        let mut share = 0;
+
+        while count > 1 {
+            share = amount / count / rounding * rounding;
+
+            if share >= ChangeStrategy::DUST_AVOIDANCE_FACTOR * min_amount
+                && amount - (count - 1) * share >= ChangeStrategy::DUST_AVOIDANCE_FACTOR * first_min_amount
+            {
+                break;
+            }
+            count -= 1;
+        }
+
+        if count == 1 {
+            log::debug!("Remainder of {amount} is too small to be split");
+            return Ok(Vec::new());
+        }
+
+        log::debug!("Splitting remainder of {amount} into {count} outputs");
+
+        remainder.output = BasicOutputBuilder::from(remainder.output.as_basic())
+            .with_amount(amount - (count - 1) * share)
+            .finish_output(token_supply)?;
+
+        (1..count)
+            .map(|_| {
+                Ok(BasicOutputBuilder::new_with_amount(share)
+                    .add_unlock_condition(AddressUnlockCondition::new(remainder.address))
+                    .finish_output(token_supply)?)
+            })
+            .collect()
+    }
+
+    // Returns the remainder and the additional outputs, native token remainders and storage deposit returns.
     pub(crate) fn remainder_and_storage_deposit_return_outputs(
         &self,
     ) -> Result<(Option<RemainderData>, Vec<Output>), Error> {
@@ -144,7 +308,15 @@ impl InputSelection {
             return Err(Error::MissingInputWithEd25519Address);
         };
 
-        let diff = inputs_sum - outputs_sum;
+        let (native_tokens_diff, native_token_remainder_outputs) =
+            self.native_token_remainder_outputs(native_tokens_diff)?;
+        let native_token_remainders_amount = native_token_remainder_outputs.iter().map(Output::amount).sum::<u64>();
+        let diff = (inputs_sum - outputs_sum)
+            .checked_sub(native_token_remainders_amount)
+            .ok_or(Error::InsufficientAmount {
+                found: inputs_sum,
+                required: outputs_sum + native_token_remainders_amount,
+            })?;
         let mut remainder_builder = BasicOutputBuilder::new_with_amount(diff);
 
         remainder_builder = remainder_builder.add_unlock_condition(AddressUnlockCondition::new(remainder_address));
@@ -169,7 +341,10 @@ impl InputSelection {
                 chain,
                 address: remainder_address,
             }),
-            storage_deposit_returns,
+            native_token_remainder_outputs
+                .into_iter()
+                .chain(storage_deposit_returns)
+                .collect(),
         ))
     }
 }
@@ -3,15 +3,18 @@
 
 use std::collections::{HashMap, HashSet};
 
-use super::{Error, InputSelection, Requirement};
+use super::{native_tokens::native_token_ids, Error, InputSelection, Requirement};
 use crate::{
-    client::secret::types::InputSigningData,
+    client::{
+        api::input_selection::{InputSelectionStrategy, LargestFirst},
+        secret::types::InputSigningData,
+    },
     types::block::{
         address::Address,
         input::INPUT_COUNT_MAX,
         output::{
             unlock_condition::StorageDepositReturnUnlockCondition, AliasOutputBuilder, AliasTransition,
-            FoundryOutputBuilder, NativeTokens, NftOutputBuilder, Output, OutputId, Rent, TokenId,
+            FoundryOutputBuilder, NftOutputBuilder, Output, OutputId, Rent, TokenId,
         },
     },
 };
@@ -82,6 +85,7 @@ struct AmountSelection {
     native_tokens_remainder: bool,
     timestamp: u32,
     selected_native_tokens: HashSet<TokenId>,
+    max_native_token_count: usize,
 }
 
 impl AmountSelection {
@@ -91,17 +95,7 @@ impl AmountSelection {
             &input_selection.outputs,
             input_selection.timestamp,
         );
-        let selected_native_tokens = HashSet::<TokenId>::from_iter(
-            input_selection
-                .selected_inputs
-                .iter()
-                .flat_map(|i| {
-                    i.output
-                        .native_tokens()
-                        .map(|n| n.iter().copied().map(|n| *n.token_id()).collect::<Vec<TokenId>>())
-                })
-                .flatten(),
-        );
+        let selected_native_tokens = native_token_ids(input_selection.selected_inputs.iter().map(|i| &i.output));
         let (remainder_amount, native_tokens_remainder) = input_selection.remainder_amount()?;
 
         Ok(Self {
@@ -114,6 +108,7 @@ impl AmountSelection {
             native_tokens_remainder,
             timestamp: input_selection.timestamp,
             selected_native_tokens,
+            max_native_token_count: input_selection.max_native_token_count,
         })
     }
 
@@ -164,7 +159,7 @@ impl AmountSelection {
 
                 selected_native_tokens.extend(nt.iter().map(|t| t.token_id()));
                 // Don't select input if the tx would end up with more than allowed native tokens.
-                if selected_native_tokens.len() > NativeTokens::COUNT_MAX.into() {
+                if selected_native_tokens.len() > self.max_native_token_count {
                     continue;
                 } else {
                     // Update selected with NTs from this output.
@@ -320,15 +315,11 @@ impl InputSelection {
             );
         }
 
-        // TODO if consolidate strategy: sum all the lowest amount until diff is covered.
-        // TODO this would be lowest amount of input strategy.
+        // Try to select outputs first with the ordering of the strategy, if that fails, try from high to low amount.
 
-        // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
-
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        log::debug!("Ordering inputs with {:?}", self.strategy);
+        self.strategy
+            .order_inputs(&mut self.available_inputs, amount_selection.missing_amount());
 
         if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
             return Ok(r);
@@ -337,18 +328,8 @@ impl InputSelection {
         // If the available inputs have more NTs than are allowed in a single tx, we might not be able to find inputs
         // without exceeding the threshold, so in this case we also try again with the outputs ordered the other way
         // around.
-        let potentially_too_many_native_tokens = HashSet::<TokenId>::from_iter(
-            self.available_inputs
-                .iter()
-                .flat_map(|i| {
-                    i.output
-                        .native_tokens()
-                        .map(|n| n.iter().copied().map(|n| *n.token_id()).collect::<Vec<TokenId>>())
-                })
-                .flatten(),
-        )
-        .len()
-            > NativeTokens::COUNT_MAX.into();
+        let potentially_too_many_native_tokens =
+            native_token_ids(self.available_inputs.iter().map(|i| &i.output)).len() > self.max_native_token_count;
 
         if self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into()
             || potentially_too_many_native_tokens
@@ -358,9 +339,7 @@ impl InputSelection {
             amount_selection = AmountSelection::new(self)?;
 
             log::debug!("Ordering inputs from high to low amount");
-            // Sort inputs per amount, high to low.
-            self.available_inputs
-                .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+            LargestFirst.order_inputs(&mut self.available_inputs, amount_selection.missing_amount());
 
             if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
                 return Ok(r);
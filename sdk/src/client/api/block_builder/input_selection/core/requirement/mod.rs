@@ -182,6 +182,23 @@ impl InputSelection {
                 log::debug!("Adding {requirement:?} from burn");
                 self.requirements.push(requirement);
             }
+
+            // The foundry is transitioned with the melted tokens.
+            for token_id in burn.melted_native_tokens.keys() {
+                let foundry_id = FoundryId::from(*token_id);
+
+                if self
+                    .outputs
+                    .iter()
+                    .any(|output| is_foundry_with_id(output, &foundry_id))
+                {
+                    return Err(Error::BurnAndTransition(ChainId::from(foundry_id)));
+                }
+
+                let requirement = Requirement::Foundry(foundry_id);
+                log::debug!("Adding {requirement:?} from melt");
+                self.requirements.push(requirement);
+            }
         }
 
         Ok(())
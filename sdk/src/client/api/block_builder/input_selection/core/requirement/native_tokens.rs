@@ -8,9 +8,19 @@ use primitive_types::U256;
 use super::{Error, InputSelection};
 use crate::{
     client::secret::types::InputSigningData,
-    types::block::output::{AliasTransition, NativeToken, NativeTokens, NativeTokensBuilder, Output, TokenScheme},
+    types::block::output::{
+        AliasTransition, NativeToken, NativeTokens, NativeTokensBuilder, Output, TokenId, TokenScheme,
+    },
 };
 
+/// Returns the IDs of the native tokens of outputs.
+pub(crate) fn native_token_ids<'a>(outputs: impl Iterator<Item = &'a Output>) -> HashSet<TokenId> {
+    outputs
+        .filter_map(|output| output.native_tokens())
+        .flat_map(|native_tokens| native_tokens.iter().map(|native_token| *native_token.token_id()))
+        .collect()
+}
+
 pub(crate) fn get_native_tokens<'a>(outputs: impl Iterator<Item = &'a Output>) -> Result<NativeTokensBuilder, Error> {
     let mut required_native_tokens = NativeTokensBuilder::new();
 
@@ -135,17 +145,54 @@ impl InputSelection {
             let mut newly_selected_inputs = Vec::new();
             let mut newly_selected_ids = HashSet::new();
 
+            let mut token_ids = native_token_ids(
+                self.selected_inputs
+                    .iter()
+                    .map(|input| &input.output)
+                    .chain(self.outputs.iter()),
+            );
+
             for diff in diffs.iter() {
                 let mut amount = U256::zero();
-                // TODO sort ?
-                let inputs = self.available_inputs.iter().filter(|input| {
+                // The largest number of native tokens the transaction would have had with a skipped input.
+                let mut too_many_native_tokens = None;
+                let mut inputs = self
+                    .available_inputs
+                    .iter()
+                    .filter(|input| {
+                        input
+                            .output
+                            .native_tokens()
+                            .is_some_and(|native_tokens| native_tokens.contains(diff.token_id()))
+                    })
+                    .collect::<Vec<_>>();
+
+                // Prefer inputs that bring in the least native tokens that are not part of the transaction yet, to
+                // stay under the max native token count.
+                inputs.sort_by_key(|input| {
+                    // PANIC: safe to unwrap as the filter guarantees inputs with native tokens.
                     input
                         .output
                         .native_tokens()
-                        .map_or(false, |native_tokens| native_tokens.contains(diff.token_id()))
+                        .unwrap()
+                        .iter()
+                        .filter(|native_token| !token_ids.contains(native_token.token_id()))
+                        .count()
                 });
 
                 for input in inputs {
+                    if !newly_selected_ids.contains(input.output_id()) {
+                        let mut new_token_ids = token_ids.clone();
+                        new_token_ids.extend(native_token_ids(core::iter::once(&input.output)));
+
+                        // Don't select input if the tx would end up with more than allowed native tokens.
+                        if new_token_ids.len() > self.max_native_token_count {
+                            too_many_native_tokens = too_many_native_tokens.max(Some(new_token_ids.len()));
+                            continue;
+                        }
+                        token_ids = new_token_ids;
+                    }
+
                     amount += input
                         .output
                         .native_tokens()
@@ -164,6 +211,12 @@ impl InputSelection {
                 }
 
                 if amount < diff.amount() {
+                    if let Some(found) = too_many_native_tokens {
+                        return Err(Error::TooManyNativeTokens {
+                            found,
+                            max: self.max_native_token_count,
+                        });
+                    }
                     return Err(Error::InsufficientNativeTokenAmount {
                         token_id: *diff.token_id(),
                         found: amount,
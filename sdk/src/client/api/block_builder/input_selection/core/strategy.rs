@@ -0,0 +1,322 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Coin selection strategies of the amount requirement.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::types::InputSigningData,
+    types::block::{
+        input::INPUT_COUNT_MAX,
+        output::{Output, OutputId},
+    },
+};
+
+/// Orders the available inputs that are selected to fulfill the amount requirement of an
+/// [`InputSelection`](super::InputSelection).
+///
+/// Inputs are grouped by kind first, e.g. basic outputs without native tokens are preferred over ones with native
+/// tokens, and are then selected in the order of the strategy until the missing amount is covered.
+pub trait InputSelectionStrategy: core::fmt::Debug + Send + Sync {
+    /// Orders the available inputs, given the amount that is still missing.
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64);
+}
+
+/// Selects the inputs with the highest amount first, which results in the fewest inputs.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LargestFirst;
+
+impl InputSelectionStrategy for LargestFirst {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _missing_amount: u64) {
+        inputs.sort_by_key(|input| core::cmp::Reverse(input.output.amount()));
+    }
+}
+
+/// Selects the inputs with the lowest amount first, which consolidates dust outputs over time.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SmallestFirst;
+
+impl InputSelectionStrategy for SmallestFirst {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _missing_amount: u64) {
+        inputs.sort_by_key(|input| input.output.amount());
+    }
+}
+
+/// Searches for simple deposit inputs whose amounts sum up to exactly the missing amount, so no remainder output is
+/// needed, and selects them first. The other inputs are selected smallest first.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BranchAndBound {
+    /// The max number of branches that are explored before giving up.
+    pub max_tries: usize,
+}
+
+impl BranchAndBound {
+    /// The default max number of explored branches.
+    pub const DEFAULT_MAX_TRIES: usize = 100_000;
+}
+
+impl Default for BranchAndBound {
+    fn default() -> Self {
+        Self {
+            max_tries: Self::DEFAULT_MAX_TRIES,
+        }
+    }
+}
+
+impl InputSelectionStrategy for BranchAndBound {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64) {
+        // Only simple deposits are candidates, other inputs would add requirements or storage deposit returns.
+        let mut candidates = inputs
+            .iter()
+            .filter(|input| match &input.output {
+                Output::Basic(basic) => basic
+                    .simple_deposit_address()
+                    .is_some_and(|address| address.is_ed25519()),
+                _ => false,
+            })
+            .map(|input| (*input.output_id(), input.output.amount()))
+            .collect::<Vec<_>>();
+        // Exploring large amounts first finds short solutions and exceeds the target early.
+        candidates.sort_by_key(|(_, amount)| core::cmp::Reverse(*amount));
+
+        let exact_match = exact_match(&candidates, missing_amount, self.max_tries);
+
+        if let Some(exact_match) = &exact_match {
+            log::debug!("Found exact match {exact_match:?} for the missing amount {missing_amount}");
+        }
+
+        inputs.sort_by_key(|input| {
+            (
+                !exact_match
+                    .as_ref()
+                    .is_some_and(|exact_match| exact_match.contains(input.output_id())),
+                input.output.amount(),
+            )
+        });
+    }
+}
+
+// Depth-first search for a subset of the candidates, sorted by amount from high to low, that sums up to the target.
+fn exact_match(candidates: &[(OutputId, u64)], target: u64, max_tries: usize) -> Option<HashSet<OutputId>> {
+    // The sums of the remaining candidates, to prune branches that can't reach the target anymore.
+    let mut remaining_sums = vec![0u64; candidates.len() + 1];
+    for (index, (_, amount)) in candidates.iter().enumerate().rev() {
+        remaining_sums[index] = remaining_sums[index + 1].saturating_add(*amount);
+    }
+
+    let mut selected = Vec::new();
+    let mut tries = 0;
+
+    fn search(
+        candidates: &[(OutputId, u64)],
+        remaining_sums: &[u64],
+        index: usize,
+        target: u64,
+        selected: &mut Vec<usize>,
+        tries: &mut usize,
+        max_tries: usize,
+    ) -> bool {
+        if target == 0 {
+            return true;
+        }
+        *tries += 1;
+        if index == candidates.len()
+            || remaining_sums[index] < target
+            || selected.len() == INPUT_COUNT_MAX as usize
+            || *tries > max_tries
+        {
+            return false;
+        }
+
+        let amount = candidates[index].1;
+
+        // Include the candidate.
+        if amount <= target {
+            selected.push(index);
+            if search(
+                candidates,
+                remaining_sums,
+                index + 1,
+                target - amount,
+                selected,
+                tries,
+                max_tries,
+            ) {
+                return true;
+            }
+            selected.pop();
+        }
+
+        // Exclude the candidate.
+        search(
+            candidates,
+            remaining_sums,
+            index + 1,
+            target,
+            selected,
+            tries,
+            max_tries,
+        )
+    }
+
+    (target != 0
+        && search(
+            candidates,
+            &remaining_sums,
+            0,
+            target,
+            &mut selected,
+            &mut tries,
+            max_tries,
+        ))
+    .then(|| selected.into_iter().map(|index| candidates[index].0).collect())
+}
+
+/// Selects the inputs in a random order, so the selected inputs don't reveal the other outputs of the wallet.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RandomOrder;
+
+impl InputSelectionStrategy for RandomOrder {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64) {
+        // Fisher-Yates shuffle.
+        for index in (1..inputs.len()).rev() {
+            let mut bytes = [0u8; 8];
+
+            if let Err(err) = crypto::utils::rand::fill(&mut bytes) {
+                log::warn!("Failed to shuffle inputs, selecting them smallest first: {err}");
+                return SmallestFirst.order_inputs(inputs, missing_amount);
+            }
+
+            inputs.swap(index, (u64::from_le_bytes(bytes) % (index as u64 + 1)) as usize);
+        }
+    }
+}
+
+/// Selects the inputs in a pseudo-random order derived from a seed.
+///
+/// The selection is reproducible, e.g. in tests or audits. The order doesn't hide the other outputs of the wallet from
+/// anyone who knows the seed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SeededRandomOrder {
+    /// The seed of the pseudo-random order.
+    pub seed: u64,
+}
+
+impl SeededRandomOrder {
+    /// Creates a new [`SeededRandomOrder`].
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl InputSelectionStrategy for SeededRandomOrder {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], _missing_amount: u64) {
+        // The order only depends on the seed, not on the order of the given inputs.
+        inputs.sort_by_key(|input| *input.output_id());
+
+        // Fisher-Yates shuffle with a SplitMix64 generator.
+        let mut state = self.seed;
+        for index in (1..inputs.len()).rev() {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut random = state;
+            random = (random ^ (random >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            random = (random ^ (random >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            random ^= random >> 31;
+
+            inputs.swap(index, (random % (index as u64 + 1)) as usize);
+        }
+    }
+}
+
+impl<T: InputSelectionStrategy + ?Sized> InputSelectionStrategy for Box<T> {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64) {
+        (**self).order_inputs(inputs, missing_amount)
+    }
+}
+
+/// The built-in coin selection strategies, e.g. to choose one in the transaction options.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CoinSelectionStrategy {
+    /// See [`LargestFirst`].
+    LargestFirst,
+    /// See [`SmallestFirst`].
+    #[default]
+    SmallestFirst,
+    /// See [`BranchAndBound`].
+    BranchAndBound,
+    /// See [`RandomOrder`].
+    Random,
+}
+
+impl InputSelectionStrategy for CoinSelectionStrategy {
+    fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64) {
+        match self {
+            Self::LargestFirst => LargestFirst.order_inputs(inputs, missing_amount),
+            Self::SmallestFirst => SmallestFirst.order_inputs(inputs, missing_amount),
+            Self::BranchAndBound => BranchAndBound::default().order_inputs(inputs, missing_amount),
+            Self::Random => RandomOrder.order_inputs(inputs, missing_amount),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        output::BasicOutputBuilder,
+        rand::output::{rand_output_id, rand_output_metadata, unlock_condition::rand_address_unlock_condition},
+    };
+
+    #[test]
+    fn exact_match_search() {
+        let candidates = [5, 4, 3, 1]
+            .into_iter()
+            .map(|amount| (rand_output_id(), amount))
+            .collect::<Vec<_>>();
+        let amounts = |exact_match: HashSet<OutputId>| {
+            let mut amounts = candidates
+                .iter()
+                .filter(|(output_id, _)| exact_match.contains(output_id))
+                .map(|(_, amount)| *amount)
+                .collect::<Vec<_>>();
+            amounts.sort_unstable();
+            amounts
+        };
+
+        assert_eq!(amounts(exact_match(&candidates, 8, 100).unwrap()), [3, 5]);
+        assert_eq!(amounts(exact_match(&candidates, 13, 100).unwrap()), [1, 3, 4, 5]);
+        assert_eq!(exact_match(&candidates, 2, 100), None);
+        assert_eq!(exact_match(&candidates, 14, 100), None);
+        // Gives up after the max number of tries.
+        assert_eq!(exact_match(&candidates, 1, 2), None);
+    }
+
+    #[test]
+    fn seeded_random_order() {
+        let inputs = (1..=10)
+            .map(|amount| InputSigningData {
+                output: BasicOutputBuilder::new_with_amount(amount * 1_000_000)
+                    .add_unlock_condition(rand_address_unlock_condition())
+                    .finish_output(u64::MAX)
+                    .unwrap(),
+                output_metadata: rand_output_metadata(),
+                chain: None,
+            })
+            .collect::<Vec<_>>();
+        let order = |seed: u64, mut inputs: Vec<InputSigningData>| {
+            SeededRandomOrder::new(seed).order_inputs(&mut inputs, 0);
+            inputs.iter().map(|input| *input.output_id()).collect::<Vec<_>>()
+        };
+        let mut reversed = inputs.clone();
+        reversed.reverse();
+
+        // The same seed results in the same order, regardless of the order of the given inputs.
+        assert_eq!(order(42, inputs.clone()), order(42, inputs.clone()));
+        assert_eq!(order(42, inputs.clone()), order(42, reversed));
+        assert_ne!(order(42, inputs.clone()), order(43, inputs));
+    }
+}
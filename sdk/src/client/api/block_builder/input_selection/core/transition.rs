@@ -9,7 +9,7 @@ use crate::{
     client::secret::types::InputSigningData,
     types::block::output::{
         AliasOutput, AliasOutputBuilder, AliasTransition, ChainId, FoundryOutput, FoundryOutputBuilder, NftOutput,
-        NftOutputBuilder, Output, OutputId,
+        NftOutputBuilder, Output, OutputId, SimpleTokenScheme, TokenScheme,
     },
 };
 
@@ -144,7 +144,34 @@ impl InputSelection {
             return Ok(None);
         }
 
-        let output = FoundryOutputBuilder::from(input).finish_output(self.protocol_parameters.token_supply())?;
+        let mut builder = FoundryOutputBuilder::from(input);
+
+        if let Some(melted_amount) = self
+            .burn
+            .as_ref()
+            .and_then(|burn| burn.melted_native_tokens.get(&input.token_id()))
+        {
+            let TokenScheme::Simple(token_scheme) = input.token_scheme();
+
+            log::debug!("Melting {melted_amount} of {} with {foundry_id:?}", input.token_id());
+
+            // The tokens held by the foundry are released, so they can be melted and the rest goes to the remainder.
+            builder = builder
+                .with_native_tokens(
+                    input
+                        .native_tokens()
+                        .iter()
+                        .filter(|native_token| *native_token.token_id() != input.token_id())
+                        .copied(),
+                )
+                .with_token_scheme(TokenScheme::Simple(SimpleTokenScheme::new(
+                    token_scheme.minted_tokens(),
+                    token_scheme.melted_tokens() + *melted_amount,
+                    token_scheme.maximum_supply(),
+                )?));
+        }
+
+        let output = builder.finish_output(self.protocol_parameters.token_supply())?;
 
         self.automatically_transitioned.insert(ChainId::from(foundry_id), None);
 
@@ -10,4 +10,8 @@ mod sender_issuer;
 mod utxo_chains;
 
 pub(crate) use self::core::is_alias_transition;
-pub use self::core::{Burn, BurnDto, Error, InputSelection, Requirement, Selected};
+pub use self::core::{
+    BranchAndBound, Burn, BurnBuilder, BurnDryRun, BurnDto, ChangeStrategy, CoinSelectionStrategy, Error,
+    InputSelection, InputSelectionStrategy, InvalidBurnReason, LargestFirst, RandomOrder, Requirement,
+    SeededRandomOrder, Selected, SmallestFirst, UnusableInputReason,
+};
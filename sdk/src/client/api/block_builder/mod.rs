@@ -11,7 +11,12 @@ use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
 use self::input_selection::BurnDto;
-pub use self::transaction::verify_semantic;
+#[cfg(not(target_family = "wasm"))]
+pub use self::pow::BlockSubmission;
+pub use self::{
+    pow::{PowAbortHandle, PowEvent, PowPolicy, PreparedBlock},
+    transaction::verify_semantic,
+};
 use crate::{
     client::{
         api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
@@ -364,7 +369,7 @@ impl<'a> ClientBlockBuilder<'a> {
 
         let block_id = self.client.post_block_raw(&final_block).await?;
         // Get block if we use remote PoW, because the node will change parents and nonce
-        if self.client.get_local_pow().await {
+        if self.client.has_local_pow(&final_block).await? {
             Ok(final_block)
         } else {
             // Request block multiple times because the node maybe didn't process it completely in this time
@@ -3,21 +3,260 @@
 
 //! PoW functions.
 
-#[cfg(not(target_family = "wasm"))]
-use crate::pow::miner::{Miner, MinerBuilder, MinerCancel};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use packable::PackableExt;
+use serde::{Deserialize, Serialize};
+
 #[cfg(target_family = "wasm")]
-use crate::pow::wasm_miner::{SingleThreadedMiner, SingleThreadedMinerBuilder};
+use crate::pow::wasm_miner::SingleThreadedMinerBuilder;
 use crate::{
-    client::{ClientInner, Error, Result},
-    types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, Error as BlockError},
+    client::{constants::MIN_MILESTONE_INTERVAL_IN_SECONDS, Client, ClientInner, Error, Result},
+    pow::miner::MinerCancel,
+    types::{
+        api::core::response::SubmitBlockResponse,
+        block::{parent::Parents, payload::Payload, Block, BlockBuilder, BlockId, Error as BlockError},
+    },
+    utils::unix_timestamp_now,
 };
 
+/// The policy of where the PoW of blocks is done.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum PowPolicy {
+    /// Do the PoW locally or remotely, depending on `local_pow`.
+    #[default]
+    Static,
+    /// Do the PoW locally and, if it doesn't complete within `local_timeout`, let a node with remote PoW do it.
+    ///
+    /// A [`PowEvent::FallbackToRemotePow`] is emitted when switching, on wasm the PoW is always done locally.
+    #[serde(rename_all = "camelCase")]
+    LocalThenRemote { local_timeout: Duration },
+}
+
+/// Events about switching between local and remote PoW, see [`Client::subscribe_pow_events()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum PowEvent {
+    /// Local PoW didn't complete within the timeout of [`PowPolicy::LocalThenRemote`], the block is posted to a node
+    /// with remote PoW.
+    #[serde(rename_all = "camelCase")]
+    FallbackToRemotePow { local_timeout: Duration },
+    /// No node with remote PoW is available, the PoW is done locally.
+    FallbackToLocalPow,
+}
+
+/// A handle to abort the local PoW of blocks.
+///
+/// Aborting cancels an in-progress nonce search, the PoW then fails with [`Error::PowAborted`]. Blocks that are
+/// finished with the handle afterwards fail right away.
+#[derive(Clone, Default)]
+pub struct PowAbortHandle(Arc<Mutex<PowAbortState>>);
+
+#[derive(Default)]
+struct PowAbortState {
+    aborted: bool,
+    /// The cancel of the running nonce search.
+    cancel: Option<MinerCancel>,
+}
+
+impl PowAbortHandle {
+    /// Creates a new [`PowAbortHandle`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Aborts the PoW.
+    pub fn abort(&self) {
+        let mut state = self.0.lock().expect("pow abort lock poisoned");
+        state.aborted = true;
+        if let Some(cancel) = &state.cancel {
+            cancel.trigger();
+        }
+    }
+
+    /// Checks if the PoW has been aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.0.lock().expect("pow abort lock poisoned").aborted
+    }
+
+    /// Registers the cancel of a new nonce search, fails if the PoW has already been aborted.
+    fn register(&self, cancel: &MinerCancel) -> Result<()> {
+        let mut state = self.0.lock().expect("pow abort lock poisoned");
+        if state.aborted {
+            return Err(Error::PowAborted);
+        }
+        state.cancel.replace(cancel.clone());
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for PowAbortHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PowAbortHandle").field(&self.is_aborted()).finish()
+    }
+}
+
+/// A block that is finished and posted in the background, returned by [`Client::submit_block()`].
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub struct BlockSubmission {
+    abort_handle: PowAbortHandle,
+    task: tokio::task::JoinHandle<Result<(BlockId, Block)>>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl BlockSubmission {
+    /// Returns a handle to abort the local PoW of the block.
+    pub fn abort_handle(&self) -> PowAbortHandle {
+        self.abort_handle.clone()
+    }
+
+    /// Aborts the local PoW of the block, the block won't be posted if it hasn't been yet.
+    pub fn abort(&self) {
+        self.abort_handle.abort();
+    }
+
+    /// Waits until the block is posted and returns it with its id.
+    pub async fn finish(self) -> Result<(BlockId, Block)> {
+        self.task.await?
+    }
+}
+
+/// A block with its parents and PoW already done, returned by [`ClientInner::prepare_block()`] and posted with
+/// [`ClientInner::submit_prepared()`].
+///
+/// The parents of the block fall below the max depth after some milestones, the block can't be posted anymore then.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreparedBlock {
+    block: Block,
+    valid_until: u32,
+}
+
+impl PreparedBlock {
+    /// Returns the block.
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns the unix timestamp in seconds until which the block can be posted.
+    pub fn valid_until(&self) -> u32 {
+        self.valid_until
+    }
+
+    /// Checks if the block can't be posted anymore at the given unix timestamp in seconds.
+    pub fn is_expired(&self, timestamp: u32) -> bool {
+        timestamp >= self.valid_until
+    }
+
+    /// Consumes the [`PreparedBlock`] and returns the block.
+    pub fn into_block(self) -> Block {
+        self.block
+    }
+}
+
+impl Client {
+    /// Subscribes to [`PowEvent`]s, e.g. to let users know that the PoW is done by a node.
+    pub fn subscribe_pow_events(&self) -> tokio::sync::broadcast::Receiver<PowEvent> {
+        self.pow_event_sender.subscribe()
+    }
+
+    /// Finishes a block, with local PoW if needed, and posts it in the background.
+    ///
+    /// Unlike [`ClientInner::finish_block_builder()`], the returned [`BlockSubmission`] can abort the PoW.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn submit_block(&self, parents: Option<Parents>, payload: Option<Payload>) -> BlockSubmission {
+        let abort_handle = PowAbortHandle::new();
+        let client = self.clone();
+        let abort_handle_ = abort_handle.clone();
+
+        let task = tokio::spawn(async move {
+            let block = client
+                .finish_block_builder_with_abort_handle(parents, payload, &abort_handle_)
+                .await?;
+            let block_id = client.post_block_raw(&block).await?;
+
+            Ok((block_id, block))
+        });
+
+        BlockSubmission { abort_handle, task }
+    }
+}
+
 impl ClientInner {
+    /// Prepares a block with local PoW, to be posted later with [`ClientInner::submit_prepared()`] so the PoW isn't
+    /// done at the moment of submission.
+    ///
+    /// The validity deadline is estimated from the below max depth, with the shortest milestone interval of the
+    /// networks, starting when the tips are requested. Parents that are passed need to be fresh tips too.
+    pub async fn prepare_block(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<PreparedBlock> {
+        let parents = match parents {
+            Some(parents) => parents,
+            None => Parents::from_vec(self.get_tips().await?)?,
+        };
+        let valid_until = unix_timestamp_now().as_secs() as u32
+            + self.get_below_max_depth().await? as u32 * MIN_MILESTONE_INTERVAL_IN_SECONDS;
+
+        // The parents are set, so the PoW doesn't restart with new tips after the tips interval.
+        let block = self.finish_pow(Some(parents), payload).await?;
+
+        Ok(PreparedBlock { block, valid_until })
+    }
+
+    /// Posts a block prepared with [`ClientInner::prepare_block()`], fails without a request if its validity deadline
+    /// has passed.
+    pub async fn submit_prepared(&self, prepared_block: &PreparedBlock) -> Result<BlockId> {
+        if prepared_block.is_expired(unix_timestamp_now().as_secs() as u32) {
+            return Err(Error::PreparedBlockExpired {
+                valid_until: prepared_block.valid_until,
+            });
+        }
+
+        // The PoW is done, so no node with remote PoW is needed.
+        let response = self
+            .node_manager
+            .read()
+            .await
+            .post_request_bytes::<SubmitBlockResponse>(
+                "api/core/v2/blocks",
+                self.get_timeout().await,
+                &prepared_block.block.pack_to_vec(),
+                true,
+            )
+            .await?;
+
+        Ok(response.block_id)
+    }
+
     /// Finishes the block with local PoW if needed.
     /// Without local PoW, it will finish the block with a 0 nonce.
     pub async fn finish_block_builder(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
-        if self.get_local_pow().await {
-            self.finish_pow(parents, payload).await
+        self.finish_block_builder_with_abort_handle(parents, payload, &PowAbortHandle::new())
+            .await
+    }
+
+    /// Finishes the block with local PoW if needed, which can be aborted with the handle.
+    /// Without local PoW, it will finish the block with a 0 nonce.
+    pub async fn finish_block_builder_with_abort_handle(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        abort_handle: &PowAbortHandle,
+    ) -> Result<Block> {
+        #[cfg(not(target_family = "wasm"))]
+        if let PowPolicy::LocalThenRemote { local_timeout } = self.get_pow_policy().await {
+            return self
+                .finish_local_then_remote_pow(parents, payload, abort_handle, local_timeout)
+                .await;
+        }
+
+        if self.uses_local_pow().await {
+            self.finish_pow_with_abort_handle(parents, payload, abort_handle).await
         } else {
             // Finish block without doing PoW.
             let parents = match parents {
@@ -31,52 +270,122 @@ impl ClientInner {
 
     /// Calls the appropriate PoW function depending whether the compilation is for wasm or not.
     pub async fn finish_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+        self.finish_pow_with_abort_handle(parents, payload, &PowAbortHandle::new())
+            .await
+    }
+
+    /// Calls the appropriate PoW function depending whether the compilation is for wasm or not, the PoW can be
+    /// aborted with the handle.
+    pub async fn finish_pow_with_abort_handle(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        abort_handle: &PowAbortHandle,
+    ) -> Result<Block> {
         #[cfg(not(target_family = "wasm"))]
-        let block = self.finish_multi_threaded_pow(parents, payload).await?;
+        let block = self.finish_multi_threaded_pow(parents, payload, abort_handle).await?;
         #[cfg(target_family = "wasm")]
-        let block = self.finish_single_threaded_pow(parents, payload).await?;
+        let block = self.finish_single_threaded_pow(parents, payload, abort_handle).await?;
 
         Ok(block)
     }
 
-    /// Performs multi-threaded proof-of-work.
+    /// Returns whether the PoW is done locally, which is always the case with [`PowPolicy::LocalThenRemote`] until
+    /// it falls back to remote PoW.
+    pub(crate) async fn uses_local_pow(&self) -> bool {
+        #[cfg(target_family = "wasm")]
+        if matches!(self.get_pow_policy().await, PowPolicy::LocalThenRemote { .. }) {
+            return true;
+        }
+        self.get_local_pow().await
+    }
+
+    /// Returns whether a finished block gets posted with its own PoW, otherwise a node with remote PoW does it.
+    pub(crate) async fn has_local_pow(&self, block: &Block) -> Result<bool> {
+        Ok(match self.get_pow_policy().await {
+            PowPolicy::LocalThenRemote { .. } => block.verify_nonce(self.get_min_pow_score().await?).is_ok(),
+            PowPolicy::Static => self.uses_local_pow().await,
+        })
+    }
+
+    /// Performs local PoW and, if it doesn't complete within `local_timeout`, finishes the block without PoW for a
+    /// node with remote PoW.
+    #[cfg(not(target_family = "wasm"))]
+    async fn finish_local_then_remote_pow(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        abort_handle: &PowAbortHandle,
+        local_timeout: Duration,
+    ) -> Result<Block> {
+        let local_pow = self.finish_pow_with_abort_handle(parents.clone(), payload.clone(), abort_handle);
+
+        // Dropping the local PoW on timeout cancels its nonce search.
+        if let Ok(block) = tokio::time::timeout(local_timeout, local_pow).await {
+            return block;
+        }
+
+        log::debug!("[finish_block_builder] local PoW timed out, falling back to remote PoW");
+        self.emit_pow_event(PowEvent::FallbackToRemotePow { local_timeout });
+
+        let parents = match parents {
+            Some(parents) => parents,
+            None => Parents::from_vec(self.get_tips().await?)?,
+        };
+
+        Ok(BlockBuilder::new(parents).with_payload(payload).finish()?)
+    }
+
+    pub(crate) fn emit_pow_event(&self, event: PowEvent) {
+        // There may be no subscribers.
+        self.pow_event_sender.send(event).ok();
+    }
+
+    /// Performs multi-threaded proof-of-work on the worker pool of the client.
     ///
     /// Always fetches new tips after each tips interval elapses if no parents are provided.
     #[cfg(not(target_family = "wasm"))]
-    async fn finish_multi_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
-        let pow_worker_count = *self.pow_worker_count.read().await;
+    async fn finish_multi_threaded_pow(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        abort_handle: &PowAbortHandle,
+    ) -> Result<Block> {
+        let pool = self.pow_worker_pool().await;
         let min_pow_score = self.get_min_pow_score().await?;
-        let tips_interval = self.get_tips_interval().await;
+        let tips_interval = std::time::Duration::from_secs(self.get_tips_interval().await);
 
         loop {
             let cancel = MinerCancel::new();
-            let cancel_2 = cancel.clone();
-            let payload_ = payload.clone();
+            abort_handle.register(&cancel)?;
+
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
-            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel)));
-            let pow_thread = std::thread::spawn(move || {
-                let mut client_miner = MinerBuilder::new().with_cancel(cancel_2);
-                if let Some(worker_count) = pow_worker_count {
-                    client_miner = client_miner.with_num_workers(worker_count);
-                }
-                do_pow(client_miner.finish(), min_pow_score, payload_, parents).map(Some)
+            let payload = payload.clone();
+            let pool = pool.clone();
+            let cancel_ = cancel.clone();
+            let mut pow_task = tokio::task::spawn_blocking(move || {
+                do_pow(|bytes| pool.nonce(bytes, min_pow_score, &cancel_), payload, parents)
             });
 
-            for t in [pow_thread, time_thread] {
-                match t.join().expect("failed to join threads.") {
-                    Ok(block) => {
-                        if let Some(block) = block {
-                            return Ok(block);
-                        }
-                    }
-                    Err(Error::Block(BlockError::NonceNotFound)) => {}
-                    Err(err) => {
-                        return Err(err);
-                    }
+            // If we reach the tips interval we will restart the PoW with new tips, so the final block will never be
+            // lazy.
+            // Cancel the nonce search if this future is dropped.
+            let _cancel_guard = CancelOnDrop(cancel.clone());
+            let res = tokio::select! {
+                res = &mut pow_task => res,
+                _ = tokio::time::sleep(tips_interval) => {
+                    cancel.trigger();
+                    pow_task.await
                 }
+            };
+
+            match res? {
+                Ok(block) => return Ok(block),
+                Err(Error::Block(BlockError::NonceNotFound)) => {}
+                Err(err) => return Err(err),
             }
         }
     }
@@ -84,13 +393,21 @@ impl ClientInner {
     /// Single threaded proof-of-work for Wasm, which cannot generally spawn the native threads used
     /// by the `ClientMiner`.
     ///
-    /// Fetches new tips after each tips interval elapses if no parents are provided.
+    /// Fetches new tips after each tips interval elapses if no parents are provided. The abort handle is only checked
+    /// before each tips interval.
     #[cfg(target_family = "wasm")]
-    async fn finish_single_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+    async fn finish_single_threaded_pow(
+        &self,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+        abort_handle: &PowAbortHandle,
+    ) -> Result<Block> {
         let min_pow_score: u32 = self.get_min_pow_score().await?;
         let tips_interval: u64 = self.get_tips_interval().await;
 
         loop {
+            abort_handle.register(&MinerCancel::new())?;
+
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
@@ -100,7 +417,11 @@ impl ClientInner {
                 .with_timeout_in_seconds(tips_interval)
                 .finish();
 
-            match do_pow(single_threaded_miner, min_pow_score, payload.clone(), parents) {
+            match do_pow(
+                |bytes| single_threaded_miner.nonce(bytes, min_pow_score),
+                payload.clone(),
+                parents,
+            ) {
                 Ok(block) => {
                     return Ok(block);
                 }
@@ -113,25 +434,20 @@ impl ClientInner {
     }
 }
 
-/// Performs proof-of-work to construct a [`Block`].
-fn do_pow(
-    #[cfg(not(target_family = "wasm"))] miner: Miner,
-    #[cfg(target_family = "wasm")] miner: SingleThreadedMiner,
-    min_pow_score: u32,
-    payload: Option<Payload>,
-    parents: Parents,
-) -> Result<Block> {
-    Ok(BlockBuilder::new(parents)
-        .with_payload(payload)
-        .finish_nonce(|bytes| miner.nonce(bytes, min_pow_score))?)
-}
-
-// PoW timeout, if we reach this we will restart the PoW with new tips, so the final block will never be lazy.
+/// Triggers a miner cancel when dropped.
 #[cfg(not(target_family = "wasm"))]
-fn pow_timeout(after_seconds: u64, cancel: MinerCancel) -> Option<Block> {
-    std::thread::sleep(std::time::Duration::from_secs(after_seconds));
+struct CancelOnDrop(MinerCancel);
 
-    cancel.trigger();
+#[cfg(not(target_family = "wasm"))]
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.trigger();
+    }
+}
 
-    None
+/// Performs proof-of-work to construct a [`Block`].
+fn do_pow(nonce_provider: impl Fn(&[u8]) -> Option<u64>, payload: Option<Payload>, parents: Parents) -> Result<Block> {
+    Ok(BlockBuilder::new(parents)
+        .with_payload(payload)
+        .finish_nonce(nonce_provider)?)
 }
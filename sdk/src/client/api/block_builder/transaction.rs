@@ -3,25 +3,31 @@
 
 //! Transaction preparation and signing
 
+use std::collections::HashMap;
+
 use packable::PackableExt;
 
 use crate::{
     client::{
-        api::{types::PreparedTransactionData, ClientBlockBuilder},
+        api::{input_selection::is_alias_transition, types::PreparedTransactionData, ClientBlockBuilder},
         secret::{types::InputSigningData, SecretManage},
         Error, Result,
     },
     types::block::{
+        address::Address,
         input::{Input, UtxoInput},
         output::{InputsCommitment, Output, OutputId},
         payload::{
             transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
             TaggedDataPayload,
         },
+        protocol::ProtocolParameters,
         semantic::{semantic_validation, ConflictReason, ValidationContext},
-        signature::Ed25519Signature,
+        signature::{Ed25519Signature, Signature},
+        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
         Block, BlockId,
     },
+    utils::unix_timestamp_now,
 };
 
 const MAX_TX_LENGTH_FOR_BLOCK_WITH_8_PARENTS: usize = Block::LENGTH_MAX - Block::LENGTH_MIN - (7 * BlockId::LENGTH);
@@ -138,6 +144,90 @@ pub fn verify_semantic(
     Ok(semantic_validation(context, inputs.as_slice(), transaction.unlocks())?)
 }
 
+impl PreparedTransactionData {
+    /// Validates the transaction against the recorded inputs before it's signed, with the same syntactic and semantic
+    /// rules as a node at the current time. Signatures are not verified, so the transaction can still conflict with
+    /// [`ConflictReason::InvalidSignature`] once it's signed by the wrong keys.
+    pub fn validate(&self, protocol_parameters: &ProtocolParameters) -> Result<ConflictReason> {
+        let time = unix_timestamp_now().as_secs() as u32;
+
+        // Syntactic validation, with the parameters of the network.
+        TransactionEssence::unpack_verified(self.essence.pack_to_vec(), protocol_parameters)?;
+
+        let TransactionEssence::Regular(essence) = &self.essence;
+        let mut inputs = Vec::with_capacity(essence.inputs().len());
+
+        for input in essence.inputs() {
+            let Input::Utxo(utxo_input) = input else {
+                return Ok(ConflictReason::InputUtxoNotFound);
+            };
+            match self
+                .inputs_data
+                .iter()
+                .find(|input_data| input_data.output_id() == utxo_input.output_id())
+            {
+                Some(input_data) => inputs.push((input_data.output_id(), &input_data.output)),
+                None => return Ok(ConflictReason::InputUtxoNotFound),
+            }
+        }
+
+        let Some(unlocks) = placeholder_unlocks(essence, &inputs, time)? else {
+            return Ok(ConflictReason::InvalidUnlock);
+        };
+        let transaction = TransactionPayload::new(self.essence.clone(), unlocks)?;
+        let transaction_id = transaction.id();
+        let mut context = ValidationContext::new(
+            &transaction_id,
+            essence,
+            inputs.iter().map(|(id, input)| (*id, *input)),
+            transaction.unlocks(),
+            time,
+        );
+        context.verify_signatures = false;
+
+        Ok(semantic_validation(context, inputs.as_slice(), transaction.unlocks())?)
+    }
+}
+
+// Creates the unlocks the inputs would be signed with, but with empty signatures. Returns `None` if an input is owned
+// by an alias or NFT address that isn't unlocked by a previous input.
+fn placeholder_unlocks(
+    essence: &RegularTransactionEssence,
+    inputs: &[(&OutputId, &Output)],
+    time: u32,
+) -> Result<Option<Unlocks>> {
+    let mut unlocks = Vec::with_capacity(inputs.len());
+    let mut unlock_indexes = HashMap::<Address, u16>::new();
+
+    for (index, (output_id, output)) in inputs.iter().enumerate() {
+        let index = index as u16;
+        let alias_transition = is_alias_transition(output, **output_id, essence.outputs(), None);
+        let (address, _) = output.required_and_unlocked_address(time, output_id, alias_transition)?;
+
+        unlocks.push(match (unlock_indexes.get(&address), address) {
+            (Some(unlock_index), Address::Ed25519(_)) => Unlock::Reference(ReferenceUnlock::new(*unlock_index)?),
+            (Some(unlock_index), Address::Alias(_)) => Unlock::Alias(AliasUnlock::new(*unlock_index)?),
+            (Some(unlock_index), Address::Nft(_)) => Unlock::Nft(NftUnlock::new(*unlock_index)?),
+            (None, Address::Alias(_) | Address::Nft(_)) => return Ok(None),
+            (None, Address::Ed25519(_)) => {
+                unlock_indexes.insert(address, index);
+                Unlock::Signature(SignatureUnlock::new(Signature::from(Ed25519Signature::from_bytes(
+                    [0; Ed25519Signature::PUBLIC_KEY_LENGTH],
+                    [0; Ed25519Signature::SIGNATURE_LENGTH],
+                ))))
+            }
+        });
+
+        match output {
+            Output::Alias(alias) => unlock_indexes.insert(Address::Alias(alias.alias_address(output_id)), index),
+            Output::Nft(nft) => unlock_indexes.insert(Address::Nft(nft.nft_address(output_id)), index),
+            _ => None,
+        };
+    }
+
+    Ok(Some(Unlocks::new(unlocks)?))
+}
+
 /// Verifies that the transaction payload doesn't exceed the block size limit with 8 parents.
 pub fn validate_transaction_payload_length(transaction_payload: &TransactionPayload) -> Result<()> {
     let transaction_payload_bytes = transaction_payload.pack_to_vec();
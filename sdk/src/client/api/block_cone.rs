@@ -0,0 +1,155 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashSet, VecDeque};
+
+use futures::{stream, Stream};
+
+use crate::{
+    client::{Client, Result},
+    types::block::{Block, BlockId},
+};
+
+/// A block of the past cone of another block, yielded by [`Client::past_cone_iter()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PastConeBlock {
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The block.
+    pub block: Block,
+    /// The distance to the start block, 1 for its parents.
+    pub depth: usize,
+}
+
+/// A block of the future cone of another block, yielded by [`Client::children_iter()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BlockChild {
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The parent through which the block was reached.
+    pub parent: BlockId,
+    /// The distance to the start block, 1 for its children.
+    pub depth: usize,
+}
+
+struct PastConeState {
+    client: Client,
+    start: Option<BlockId>,
+    depth_limit: usize,
+    queue: VecDeque<(BlockId, usize)>,
+    visited: HashSet<BlockId>,
+}
+
+impl PastConeState {
+    fn push_parents(&mut self, block: &Block, depth: usize) {
+        for parent in block.parents().iter() {
+            // The null block id is the parent of the first blocks of a network.
+            if *parent != BlockId::null() && self.visited.insert(*parent) {
+                self.queue.push_back((*parent, depth));
+            }
+        }
+    }
+}
+
+struct ChildrenState {
+    client: Client,
+    queue: VecDeque<(BlockId, usize)>,
+    pending: VecDeque<BlockChild>,
+    visited: HashSet<BlockId>,
+}
+
+impl Client {
+    /// Returns a stream of the past cone of a block, walking the parents breadth first up to `depth_limit` parent
+    /// references away from the block.
+    ///
+    /// Each block is requested once, blocks that are reachable over multiple paths are only yielded at their smallest
+    /// depth. If a block can't be requested, e.g. because it was pruned, the error is yielded and its parents are
+    /// skipped.
+    /// ```ignore
+    /// let past_cone = client.past_cone_iter(block_id, 10);
+    /// futures::pin_mut!(past_cone);
+    /// while let Some(past_cone_block) = past_cone.next().await {
+    ///     let past_cone_block = past_cone_block?;
+    /// }
+    /// ```
+    pub fn past_cone_iter(
+        &self,
+        block_id: BlockId,
+        depth_limit: usize,
+    ) -> impl Stream<Item = Result<PastConeBlock>> + Send {
+        let state = PastConeState {
+            client: self.clone(),
+            start: Some(block_id),
+            depth_limit,
+            queue: VecDeque::new(),
+            visited: HashSet::from([block_id]),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            if let Some(start) = state.start.take() {
+                if state.depth_limit == 0 {
+                    return None;
+                }
+                match state.client.get_block(&start).await {
+                    Ok(block) => state.push_parents(&block, 1),
+                    Err(err) => return Some((Err(err), state)),
+                }
+            }
+
+            let (block_id, depth) = state.queue.pop_front()?;
+            let block = match state.client.get_block(&block_id).await {
+                Ok(block) => block,
+                Err(err) => return Some((Err(err), state)),
+            };
+
+            if depth < state.depth_limit {
+                state.push_parents(&block, depth + 1);
+            }
+
+            Some((Ok(PastConeBlock { block_id, block, depth }), state))
+        })
+    }
+
+    /// Returns a stream of the future cone of a block, walking the children breadth first until the tips are
+    /// reached. The children are requested from the permanode, see [`ClientInner::get_block_children()`].
+    ///
+    /// The children of each block are requested once, blocks that are reachable over multiple paths are only yielded
+    /// at their smallest depth. If the children of a block can't be requested, the error is yielded and the block
+    /// isn't walked further.
+    ///
+    /// [`ClientInner::get_block_children()`]: crate::client::ClientInner::get_block_children
+    pub fn children_iter(&self, block_id: BlockId) -> impl Stream<Item = Result<BlockChild>> + Send {
+        let state = ChildrenState {
+            client: self.clone(),
+            queue: VecDeque::from([(block_id, 0)]),
+            pending: VecDeque::new(),
+            visited: HashSet::from([block_id]),
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(child) = state.pending.pop_front() {
+                    state.queue.push_back((child.block_id, child.depth));
+                    return Some((Ok(child), state));
+                }
+
+                let (parent, depth) = state.queue.pop_front()?;
+
+                match state.client.get_all_block_children(&parent).await {
+                    Ok(children) => {
+                        for block_id in children {
+                            if state.visited.insert(block_id) {
+                                state.pending.push_back(BlockChild {
+                                    block_id,
+                                    parent,
+                                    depth: depth + 1,
+                                });
+                            }
+                        }
+                    }
+                    Err(err) => return Some((Err(err), state)),
+                }
+            }
+        })
+    }
+}
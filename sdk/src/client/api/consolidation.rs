@@ -1,13 +1,19 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::keys::bip44::Bip44;
+
 use super::GetAddressesOptions;
 use crate::{
     client::{node_api::indexer::query_parameters::QueryParameter, secret::SecretManager, Client, Result},
     types::block::{
         address::Bech32Address,
         input::{UtxoInput, INPUT_COUNT_MAX},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokensBuilder},
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            OutputWithMetadata, RentStructure,
+        },
+        BlockId,
     },
 };
 
@@ -19,14 +25,15 @@ impl Client {
         secret_manager: &SecretManager,
         options: GetAddressesOptions,
     ) -> Result<Bech32Address> {
-        let token_supply = self.get_token_supply().await?;
         let mut last_transfer_index = options.range.start;
         // use the start index as offset
         let offset = last_transfer_index;
+        let (coin_type, account_index) = (options.coin_type, options.account_index);
 
         let addresses = secret_manager.generate_ed25519_addresses(options).await?;
 
         let consolidation_address = addresses[0];
+        self.bech32_hrp_matches(consolidation_address.hrp()).await?;
 
         'consolidation: loop {
             let mut block_ids = Vec::new();
@@ -36,17 +43,7 @@ impl Client {
                 // add the offset so the index matches the address index also for higher start indexes
                 let index = index + offset;
 
-                // Get output ids of outputs that can be controlled by this address without further unlock constraints
-                let output_ids_response = self
-                    .basic_output_ids([
-                        QueryParameter::Address(*address),
-                        QueryParameter::HasExpiration(false),
-                        QueryParameter::HasTimelock(false),
-                        QueryParameter::HasStorageDepositReturn(false),
-                    ])
-                    .await?;
-
-                let basic_outputs_responses = self.get_outputs(&output_ids_response.items).await?;
+                let basic_outputs_responses = self.unconstrained_basic_outputs(*address).await?;
 
                 if !basic_outputs_responses.is_empty() {
                     // If we reach the same index again
@@ -59,38 +56,17 @@ impl Client {
                     }
                 }
 
-                let outputs_chunks = basic_outputs_responses.chunks(INPUT_COUNT_MAX.into());
-
-                self.bech32_hrp_matches(consolidation_address.hrp()).await?;
-
-                for chunk in outputs_chunks {
-                    let mut block_builder = self.build_block().with_secret_manager(secret_manager);
-                    let mut total_amount = 0;
-                    let mut total_native_tokens = NativeTokensBuilder::new();
-
-                    for output_with_meta in chunk {
-                        block_builder = block_builder
-                            .with_input(UtxoInput::from(output_with_meta.metadata().output_id().to_owned()))?;
-
-                        if let Some(native_tokens) = output_with_meta.output().native_tokens() {
-                            total_native_tokens.add_native_tokens(native_tokens.clone())?;
-                        }
-                        total_amount += output_with_meta.output().amount();
-                    }
-
-                    let consolidation_output = BasicOutputBuilder::new_with_amount(total_amount)
-                        .add_unlock_condition(AddressUnlockCondition::new(consolidation_address))
-                        .with_native_tokens(total_native_tokens.finish()?)
-                        .finish_output(token_supply)?;
-
-                    let block = block_builder
-                        .with_input_range(index..index + 1)
-                        .with_outputs([consolidation_output])?
-                        .with_initial_address_index(0)
-                        .finish()
-                        .await?;
-                    block_ids.push(block.id());
-                }
+                block_ids.extend(
+                    self.send_outputs_to(
+                        secret_manager,
+                        Bip44::new(coin_type)
+                            .with_account(account_index)
+                            .with_address_index(index),
+                        &basic_outputs_responses,
+                        consolidation_address,
+                    )
+                    .await?,
+                );
             }
 
             if block_ids.is_empty() {
@@ -103,4 +79,172 @@ impl Client {
         }
         Ok(consolidation_address)
     }
+
+    /// Returns the basic outputs of the address that can be controlled by it without further unlock constraints.
+    pub(crate) async fn unconstrained_basic_outputs(&self, address: Bech32Address) -> Result<Vec<OutputWithMetadata>> {
+        let output_ids_response = self
+            .basic_output_ids([
+                QueryParameter::Address(address),
+                QueryParameter::HasExpiration(false),
+                QueryParameter::HasTimelock(false),
+                QueryParameter::HasStorageDepositReturn(false),
+            ])
+            .await?;
+
+        self.get_outputs(&output_ids_response.items).await
+    }
+
+    /// Sends the base coins and native tokens of the outputs to `destination`, with up to [`INPUT_COUNT_MAX`] inputs
+    /// per block. The outputs have to be controlled by the address of the chain. Returns the IDs of the sent blocks.
+    pub(crate) async fn send_outputs_to(
+        &self,
+        secret_manager: &SecretManager,
+        chain: Bip44,
+        outputs: &[OutputWithMetadata],
+        destination: Bech32Address,
+    ) -> Result<Vec<BlockId>> {
+        let token_supply = self.get_token_supply().await?;
+        let rent_structure = self.get_rent_structure().await?;
+        let mut block_ids = Vec::new();
+
+        for chunk in outputs.chunks(INPUT_COUNT_MAX.into()) {
+            let mut block_builder = self
+                .build_block()
+                .with_secret_manager(secret_manager)
+                .with_coin_type(chain.coin_type)
+                .with_account_index(chain.account);
+            let mut total_amount = 0;
+            let mut total_native_tokens = NativeTokensBuilder::new();
+
+            for output_with_meta in chunk {
+                block_builder =
+                    block_builder.with_input(UtxoInput::from(output_with_meta.metadata().output_id().to_owned()))?;
+
+                if let Some(native_tokens) = output_with_meta.output().native_tokens() {
+                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+                total_amount += output_with_meta.output().amount();
+            }
+
+            let block = block_builder
+                .with_input_range(chain.address_index..chain.address_index + 1)
+                .with_outputs(consolidation_outputs(
+                    total_amount,
+                    total_native_tokens,
+                    destination,
+                    rent_structure,
+                    token_supply,
+                )?)?
+                .with_initial_address_index(0)
+                .finish()
+                .await?;
+            block_ids.push(block.id());
+        }
+
+        Ok(block_ids)
+    }
+}
+
+// Returns the outputs that send the amount and native tokens to the destination. The native tokens that don't fit into
+// the first output are sent with the minimum storage deposit, the remaining amount is sent with the first output.
+fn consolidation_outputs(
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
+    destination: Bech32Address,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> Result<Vec<Output>> {
+    let native_tokens = native_tokens.finish_vec()?;
+    let mut native_token_chunks = native_tokens.chunks(NativeTokens::COUNT_MAX.into());
+    let first_native_tokens = native_token_chunks.next().unwrap_or_default();
+
+    let mut outputs = Vec::new();
+    for native_tokens in native_token_chunks {
+        outputs.push(
+            BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                .add_unlock_condition(AddressUnlockCondition::new(destination))
+                .with_native_tokens(native_tokens.iter().cloned())
+                .finish_output(token_supply)?,
+        );
+    }
+    let required = outputs.iter().map(Output::amount).sum();
+    let remaining_amount =
+        amount
+            .checked_sub(required)
+            .ok_or(crate::client::api::input_selection::Error::InsufficientAmount {
+                found: amount,
+                required,
+            })?;
+
+    let output = BasicOutputBuilder::new_with_amount(remaining_amount)
+        .add_unlock_condition(AddressUnlockCondition::new(destination))
+        .with_native_tokens(first_native_tokens.iter().cloned())
+        .finish_output(token_supply)?;
+    output.verify_storage_deposit(rent_structure, token_supply)?;
+    outputs.insert(0, output);
+
+    Ok(outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Ed25519Address, ToBech32Ext},
+        output::{NativeToken, Rent, TokenId},
+        protocol::protocol_parameters,
+        Error as BlockError,
+    };
+
+    #[test]
+    fn native_tokens_split_into_outputs() {
+        let protocol_parameters = protocol_parameters();
+        let rent_structure = *protocol_parameters.rent_structure();
+        let token_supply = protocol_parameters.token_supply();
+        let destination = Ed25519Address::new([0; 32]).to_bech32(*protocol_parameters.bech32_hrp());
+        let native_tokens = |count: u8| {
+            let mut native_tokens = NativeTokensBuilder::new();
+            for index in 0..count {
+                native_tokens
+                    .add_native_token(NativeToken::new(TokenId::new([index; TokenId::LENGTH]), 1).unwrap())
+                    .unwrap();
+            }
+            native_tokens
+        };
+
+        let outputs =
+            consolidation_outputs(10_000_000, native_tokens(10), destination, rent_structure, token_supply).unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].amount(), 10_000_000);
+        assert_eq!(outputs[0].native_tokens().unwrap().len(), 10);
+
+        let outputs =
+            consolidation_outputs(10_000_000, native_tokens(70), destination, rent_structure, token_supply).unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs.iter().map(Output::amount).sum::<u64>(), 10_000_000);
+        assert_eq!(outputs[1].amount(), outputs[1].rent_cost(&rent_structure));
+        assert_eq!(
+            outputs
+                .iter()
+                .map(|output| output.native_tokens().unwrap().len())
+                .collect::<Vec<_>>(),
+            [64, 6]
+        );
+        assert!(outputs
+            .iter()
+            .all(|output| output.as_basic().address() == destination.inner()));
+
+        assert!(matches!(
+            consolidation_outputs(1_000, native_tokens(70), destination, rent_structure, token_supply),
+            Err(crate::client::Error::InputSelection(
+                crate::client::api::input_selection::Error::InsufficientAmount { found: 1_000, .. }
+            ))
+        ));
+        assert!(matches!(
+            consolidation_outputs(1_000, native_tokens(0), destination, rent_structure, token_supply),
+            Err(crate::client::Error::Block(
+                BlockError::InsufficientStorageDepositAmount { .. }
+            ))
+        ));
+    }
 }
@@ -0,0 +1,442 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Versioned envelopes of [`PreparedTransactionData`] and [`SignedTransactionData`].
+//!
+//! Envelopes exchange the data between SDK versions, e.g. with an air-gapped signer or between the parties of a
+//! multi-party transaction.
+//!
+//! An envelope contains the version of its schema, the kind of the data, the hash of the protocol parameters the
+//! data was created with and the data itself, including the inputs with their metadata. The binary encoding is
+//! `version || kind || protocol parameters hash || packed data`, the JSON encoding contains the same fields with the
+//! DTO of the data.
+
+use crypto::keys::bip44::Bip44;
+use packable::{
+    error::{UnpackError, UnpackErrorExt},
+    packer::Packer,
+    unpacker::Unpacker,
+    Packable, PackableExt,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    PreparedTransactionData, PreparedTransactionDataDto, RemainderData, SignedTransactionData, SignedTransactionDataDto,
+};
+use crate::{
+    client::secret::types::InputSigningData,
+    types::{
+        block::{
+            address::Address,
+            output::{Output, OutputId, OutputMetadata},
+            payload::transaction::{TransactionEssence, TransactionId, TransactionPayload},
+            protocol::ProtocolParameters,
+            BlockId, Error as BlockError,
+        },
+        TryFromDto,
+    },
+};
+
+/// The version of the transaction data envelope schema.
+pub const TRANSACTION_DATA_ENVELOPE_VERSION: u8 = 1;
+/// The kind of an envelope of [`PreparedTransactionData`].
+pub const PREPARED_TRANSACTION_DATA_KIND: u8 = 0;
+/// The kind of an envelope of [`SignedTransactionData`].
+pub const SIGNED_TRANSACTION_DATA_KIND: u8 = 1;
+
+// The version, the kind and the protocol parameters hash.
+const HEADER_LENGTH: usize = 1 + 1 + 32;
+
+/// Transaction data envelope errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The envelope contains another kind of transaction data
+    #[error("invalid transaction data kind {found}, expected {expected}")]
+    InvalidKind {
+        /// The expected kind.
+        expected: u8,
+        /// The kind of the envelope.
+        found: u8,
+    },
+    /// The envelope is too short to contain a header
+    #[error("invalid transaction data envelope length {0}")]
+    InvalidLength(usize),
+    /// The data was created with other protocol parameters
+    #[error("protocol parameters hash mismatch: expected {expected}, found {found}")]
+    ProtocolParametersMismatch {
+        /// The hash of the provided protocol parameters.
+        expected: String,
+        /// The hash in the envelope.
+        found: String,
+    },
+    /// The envelope has a version that isn't supported
+    #[error("unsupported transaction data envelope version {0}")]
+    UnsupportedVersion(u8),
+}
+
+/// The JSON encoding of a transaction data envelope.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDataEnvelopeDto<T> {
+    /// The version of the envelope schema.
+    pub version: u8,
+    /// The kind of the data.
+    #[serde(rename = "type")]
+    pub kind: u8,
+    /// The hash of the protocol parameters the data was created with.
+    pub protocol_parameters_hash: String,
+    /// The transaction data.
+    pub data: T,
+}
+
+impl PreparedTransactionData {
+    /// Returns the binary transaction data envelope.
+    pub fn to_envelope_bytes(&self, protocol_parameters: &ProtocolParameters) -> Vec<u8> {
+        to_envelope_bytes(self, protocol_parameters)
+    }
+
+    /// Reads a binary transaction data envelope that was created with the same protocol parameters.
+    pub fn from_envelope_bytes(bytes: &[u8], protocol_parameters: &ProtocolParameters) -> crate::client::Result<Self> {
+        from_envelope_bytes(bytes, protocol_parameters)
+    }
+
+    /// Returns the JSON transaction data envelope.
+    pub fn to_envelope_json(&self, protocol_parameters: &ProtocolParameters) -> crate::client::Result<String> {
+        to_envelope_json(self, protocol_parameters)
+    }
+
+    /// Reads a JSON transaction data envelope that was created with the same protocol parameters.
+    pub fn from_envelope_json(json: &str, protocol_parameters: &ProtocolParameters) -> crate::client::Result<Self> {
+        from_envelope_json(json, protocol_parameters)
+    }
+}
+
+impl SignedTransactionData {
+    /// Returns the binary transaction data envelope.
+    pub fn to_envelope_bytes(&self, protocol_parameters: &ProtocolParameters) -> Vec<u8> {
+        to_envelope_bytes(self, protocol_parameters)
+    }
+
+    /// Reads a binary transaction data envelope that was created with the same protocol parameters.
+    pub fn from_envelope_bytes(bytes: &[u8], protocol_parameters: &ProtocolParameters) -> crate::client::Result<Self> {
+        from_envelope_bytes(bytes, protocol_parameters)
+    }
+
+    /// Returns the JSON transaction data envelope.
+    pub fn to_envelope_json(&self, protocol_parameters: &ProtocolParameters) -> crate::client::Result<String> {
+        to_envelope_json(self, protocol_parameters)
+    }
+
+    /// Reads a JSON transaction data envelope that was created with the same protocol parameters.
+    pub fn from_envelope_json(json: &str, protocol_parameters: &ProtocolParameters) -> crate::client::Result<Self> {
+        from_envelope_json(json, protocol_parameters)
+    }
+}
+
+// The transaction data that can be put in an envelope.
+trait EnvelopeData: Clone + Sized {
+    const KIND: u8;
+
+    type Dto: Serialize + for<'de> Deserialize<'de>;
+
+    fn to_dto(&self) -> Self::Dto;
+
+    fn from_dto(dto: Self::Dto, protocol_parameters: &ProtocolParameters) -> Result<Self, BlockError>;
+}
+
+impl EnvelopeData for PreparedTransactionData {
+    const KIND: u8 = PREPARED_TRANSACTION_DATA_KIND;
+
+    type Dto = PreparedTransactionDataDto;
+
+    fn to_dto(&self) -> Self::Dto {
+        PreparedTransactionDataDto::from(self)
+    }
+
+    fn from_dto(dto: Self::Dto, protocol_parameters: &ProtocolParameters) -> Result<Self, BlockError> {
+        Self::try_from_dto_with_params(dto, protocol_parameters)
+    }
+}
+
+impl EnvelopeData for SignedTransactionData {
+    const KIND: u8 = SIGNED_TRANSACTION_DATA_KIND;
+
+    type Dto = SignedTransactionDataDto;
+
+    fn to_dto(&self) -> Self::Dto {
+        SignedTransactionDataDto::from(self)
+    }
+
+    fn from_dto(dto: Self::Dto, protocol_parameters: &ProtocolParameters) -> Result<Self, BlockError> {
+        Self::try_from_dto_with_params(dto, protocol_parameters)
+    }
+}
+
+fn verify_header<T: EnvelopeData>(
+    version: u8,
+    kind: u8,
+    protocol_parameters_hash: &[u8],
+    protocol_parameters: &ProtocolParameters,
+) -> Result<(), Error> {
+    if version != TRANSACTION_DATA_ENVELOPE_VERSION {
+        return Err(Error::UnsupportedVersion(version));
+    }
+    if kind != T::KIND {
+        return Err(Error::InvalidKind {
+            expected: T::KIND,
+            found: kind,
+        });
+    }
+
+    let expected_hash = protocol_parameters.hash();
+
+    if protocol_parameters_hash != expected_hash.as_slice() {
+        return Err(Error::ProtocolParametersMismatch {
+            expected: prefix_hex::encode(expected_hash),
+            found: prefix_hex::encode(protocol_parameters_hash),
+        });
+    }
+
+    Ok(())
+}
+
+fn to_envelope_bytes<T: EnvelopeData>(data: &T, protocol_parameters: &ProtocolParameters) -> Vec<u8>
+where
+    Body<T>: Packable,
+{
+    let mut bytes = vec![TRANSACTION_DATA_ENVELOPE_VERSION, T::KIND];
+    bytes.extend(protocol_parameters.hash());
+    bytes.extend(Body(data.clone()).pack_to_vec());
+    bytes
+}
+
+fn from_envelope_bytes<T: EnvelopeData>(
+    bytes: &[u8],
+    protocol_parameters: &ProtocolParameters,
+) -> crate::client::Result<T>
+where
+    Body<T>: Packable<UnpackError = BlockError, UnpackVisitor = ProtocolParameters>,
+{
+    if bytes.len() < HEADER_LENGTH {
+        return Err(Error::InvalidLength(bytes.len()).into());
+    }
+
+    verify_header::<T>(bytes[0], bytes[1], &bytes[2..HEADER_LENGTH], protocol_parameters)?;
+
+    Ok(Body::<T>::unpack_verified(&bytes[HEADER_LENGTH..], protocol_parameters)?.0)
+}
+
+fn to_envelope_json<T: EnvelopeData>(
+    data: &T,
+    protocol_parameters: &ProtocolParameters,
+) -> crate::client::Result<String> {
+    Ok(serde_json::to_string(&TransactionDataEnvelopeDto {
+        version: TRANSACTION_DATA_ENVELOPE_VERSION,
+        kind: T::KIND,
+        protocol_parameters_hash: prefix_hex::encode(protocol_parameters.hash()),
+        data: data.to_dto(),
+    })?)
+}
+
+fn from_envelope_json<T: EnvelopeData>(
+    json: &str,
+    protocol_parameters: &ProtocolParameters,
+) -> crate::client::Result<T> {
+    // The data is only parsed after the header was verified, it could have another schema.
+    let envelope = serde_json::from_str::<TransactionDataEnvelopeDto<serde_json::Value>>(json)?;
+
+    verify_header::<T>(
+        envelope.version,
+        envelope.kind,
+        &prefix_hex::decode::<Vec<u8>>(&envelope.protocol_parameters_hash)?,
+        protocol_parameters,
+    )?;
+
+    Ok(T::from_dto(
+        serde_json::from_value(envelope.data)?,
+        protocol_parameters,
+    )?)
+}
+
+// The packed data of a binary envelope.
+struct Body<T>(T);
+
+impl Packable for Body<PreparedTransactionData> {
+    type UnpackError = BlockError;
+    type UnpackVisitor = ProtocolParameters;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.0.essence.pack(packer)?;
+        pack_inputs(&self.0.inputs_data, packer)?;
+
+        match &self.0.remainder {
+            Some(remainder) => {
+                1u8.pack(packer)?;
+                remainder.output.pack(packer)?;
+                pack_chain(remainder.chain.as_ref(), packer)?;
+                remainder.address.pack(packer)
+            }
+            None => 0u8.pack(packer),
+        }
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let essence = TransactionEssence::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let inputs_data = unpack_inputs::<_, VERIFY>(unpacker, visitor)?;
+        let remainder = if unpack_flag::<_, VERIFY>(unpacker)? {
+            Some(RemainderData {
+                output: Output::unpack::<_, VERIFY>(unpacker, visitor).coerce()?,
+                chain: unpack_chain::<_, VERIFY>(unpacker)?,
+                address: Address::unpack::<_, VERIFY>(unpacker, &()).coerce()?,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self(PreparedTransactionData {
+            essence,
+            inputs_data,
+            remainder,
+        }))
+    }
+}
+
+impl Packable for Body<SignedTransactionData> {
+    type UnpackError = BlockError;
+    type UnpackVisitor = ProtocolParameters;
+
+    fn pack<P: Packer>(&self, packer: &mut P) -> Result<(), P::Error> {
+        self.0.transaction_payload.pack(packer)?;
+        pack_inputs(&self.0.inputs_data, packer)
+    }
+
+    fn unpack<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &Self::UnpackVisitor,
+    ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
+        let transaction_payload = TransactionPayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let inputs_data = unpack_inputs::<_, VERIFY>(unpacker, visitor)?;
+
+        Ok(Self(SignedTransactionData {
+            transaction_payload,
+            inputs_data,
+        }))
+    }
+}
+
+fn pack_inputs<P: Packer>(inputs_data: &[InputSigningData], packer: &mut P) -> Result<(), P::Error> {
+    // There are at most `INPUT_COUNT_MAX` inputs.
+    (inputs_data.len() as u16).pack(packer)?;
+
+    for input in inputs_data {
+        let metadata = &input.output_metadata;
+
+        input.output.pack(packer)?;
+        metadata.block_id().pack(packer)?;
+        metadata.output_id().pack(packer)?;
+        (metadata.is_spent() as u8).pack(packer)?;
+        pack_option(metadata.milestone_index_spent().as_ref(), packer)?;
+        pack_option(metadata.milestone_timestamp_spent().as_ref(), packer)?;
+        pack_option(metadata.transaction_id_spent(), packer)?;
+        metadata.milestone_index_booked().pack(packer)?;
+        metadata.milestone_timestamp_booked().pack(packer)?;
+        metadata.ledger_index().pack(packer)?;
+        pack_chain(input.chain.as_ref(), packer)?;
+    }
+
+    Ok(())
+}
+
+fn unpack_inputs<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+    visitor: &ProtocolParameters,
+) -> Result<Vec<InputSigningData>, UnpackError<BlockError, U::Error>> {
+    let count = u16::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+    let mut inputs_data = Vec::with_capacity(count.into());
+
+    for _ in 0..count {
+        let output = Output::unpack::<_, VERIFY>(unpacker, visitor).coerce()?;
+        let output_metadata = OutputMetadata::new(
+            BlockId::unpack::<_, VERIFY>(unpacker, &()).coerce()?,
+            OutputId::unpack::<_, VERIFY>(unpacker, &()).coerce()?,
+            unpack_flag::<_, VERIFY>(unpacker)?,
+            unpack_option::<u32, _, VERIFY>(unpacker)?,
+            unpack_option::<u32, _, VERIFY>(unpacker)?,
+            unpack_option::<TransactionId, _, VERIFY>(unpacker)?,
+            u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?,
+            u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?,
+            u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?,
+        );
+        let chain = unpack_chain::<_, VERIFY>(unpacker)?;
+
+        inputs_data.push(InputSigningData {
+            output,
+            output_metadata,
+            chain,
+        });
+    }
+
+    Ok(inputs_data)
+}
+
+fn unpack_flag<U: Unpacker, const VERIFY: bool>(unpacker: &mut U) -> Result<bool, UnpackError<BlockError, U::Error>> {
+    match u8::unpack::<_, VERIFY>(unpacker, &()).coerce()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(UnpackError::Packable(BlockError::InvalidField("flag"))),
+    }
+}
+
+fn pack_option<T: Packable, P: Packer>(value: Option<&T>, packer: &mut P) -> Result<(), P::Error> {
+    match value {
+        Some(value) => {
+            1u8.pack(packer)?;
+            value.pack(packer)
+        }
+        None => 0u8.pack(packer),
+    }
+}
+
+fn unpack_option<T: Packable<UnpackVisitor = ()>, U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+) -> Result<Option<T>, UnpackError<BlockError, U::Error>>
+where
+    BlockError: From<T::UnpackError>,
+{
+    Ok(if unpack_flag::<_, VERIFY>(unpacker)? {
+        Some(T::unpack::<_, VERIFY>(unpacker, &()).coerce()?)
+    } else {
+        None
+    })
+}
+
+fn pack_chain<P: Packer>(chain: Option<&Bip44>, packer: &mut P) -> Result<(), P::Error> {
+    match chain {
+        Some(chain) => {
+            1u8.pack(packer)?;
+            chain.coin_type.pack(packer)?;
+            chain.account.pack(packer)?;
+            chain.change.pack(packer)?;
+            chain.address_index.pack(packer)
+        }
+        None => 0u8.pack(packer),
+    }
+}
+
+fn unpack_chain<U: Unpacker, const VERIFY: bool>(
+    unpacker: &mut U,
+) -> Result<Option<Bip44>, UnpackError<BlockError, U::Error>> {
+    Ok(if unpack_flag::<_, VERIFY>(unpacker)? {
+        Some(
+            Bip44::new(u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?)
+                .with_account(u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?)
+                .with_change(u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?)
+                .with_address_index(u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?),
+        )
+    } else {
+        None
+    })
+}
@@ -7,7 +7,7 @@ use futures::{StreamExt, TryStreamExt};
 
 use crate::{
     client::{
-        api::{input_selection::Error as InputSelectionError, ClientBlockBuilder},
+        api::{input_selection::Error as InputSelectionError, ClientBlockBuilder, RetryProgressEvent},
         constants::{
             DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL, DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT, FIVE_MINUTES_IN_SECONDS,
         },
@@ -18,14 +18,18 @@ use crate::{
     types::{
         api::core::response::LedgerInclusionState,
         block::{
-            address::Bech32Address,
+            address::{Address, Bech32Address},
             input::{Input, UtxoInput, INPUT_COUNT_MAX},
-            output::OutputWithMetadata,
+            output::{
+                unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition},
+                BasicOutputBuilder, Output, OutputWithMetadata, RentStructure,
+            },
             parent::Parents,
             payload::{
                 transaction::{TransactionEssence, TransactionId},
                 Payload,
             },
+            semantic::ConflictReason,
             Block, BlockId,
         },
     },
@@ -91,10 +95,25 @@ impl Client {
         block_id: &BlockId,
         interval: Option<u64>,
         max_attempts: Option<u64>,
+    ) -> Result<Vec<(BlockId, Block)>> {
+        self.retry_until_included_with_progress(block_id, interval, max_attempts, |_| {})
+            .await
+    }
+
+    /// Like [`Client::retry_until_included()`], but calls `on_progress` for every reattachment, promotion and detected
+    /// conflict, so the activity can be shown while waiting.
+    pub async fn retry_until_included_with_progress(
+        &self,
+        block_id: &BlockId,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+        on_progress: impl Fn(RetryProgressEvent) + Send + Sync,
     ) -> Result<Vec<(BlockId, Block)>> {
         log::debug!("[retry_until_included]");
         // Attachments of the Block to check inclusion state
         let mut block_ids = vec![*block_id];
+        // Conflicting attachments, which are only reported once
+        let mut conflicting_block_ids = HashSet::new();
         // Reattached Blocks that get returned
         let mut blocks_with_id = Vec::new();
         for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
@@ -134,17 +153,28 @@ impl Client {
                         }
                         // only set it as conflicting here and don't return, because another reattached block could
                         // have the included transaction
-                        LedgerInclusionState::Conflicting => conflicting = true,
+                        LedgerInclusionState::Conflicting => {
+                            conflicting = true;
+                            if conflicting_block_ids.insert(*id) {
+                                let reason = block_metadata
+                                    .conflict_reason
+                                    .and_then(|reason| ConflictReason::try_from(reason).ok())
+                                    .unwrap_or(ConflictReason::SemanticValidationFailed);
+                                on_progress(RetryProgressEvent::ConflictDetected(reason));
+                            }
+                        }
                     };
                 }
                 // Only reattach or promote latest attachment of the block
                 if index == block_ids_len - 1 {
                     if block_metadata.should_promote.unwrap_or(false) {
                         // Safe to unwrap since we iterate over it
-                        self.promote_unchecked(block_ids.last().unwrap()).await?;
+                        let (promote_block_id, _) = self.promote_unchecked(block_ids.last().unwrap()).await?;
+                        on_progress(RetryProgressEvent::Promoted(promote_block_id));
                     } else if block_metadata.should_reattach.unwrap_or(false) {
                         // Safe to unwrap since we iterate over it
                         let reattached = self.reattach_unchecked(block_ids.last().unwrap()).await?;
+                        on_progress(RetryProgressEvent::Reattached(reattached.0));
                         block_ids.push(reattached.0);
                         blocks_with_id.push(reattached);
                     }
@@ -245,7 +275,7 @@ impl Client {
         // Post the modified
         let block_id = self.post_block_raw(&reattach_block).await?;
         // Get block if we use remote Pow, because the node will change parents and nonce
-        let block = if self.get_local_pow().await {
+        let block = if self.has_local_pow(&reattach_block).await? {
             reattach_block
         } else {
             self.get_block(&block_id).await?
@@ -276,7 +306,7 @@ impl Client {
 
         let block_id = self.post_block_raw(&promote_block).await?;
         // Get block if we use remote Pow, because the node will change parents and nonce.
-        let block = if self.get_local_pow().await {
+        let block = if self.has_local_pow(&promote_block).await? {
             promote_block
         } else {
             self.get_block(&block_id).await?
@@ -305,4 +335,92 @@ impl Client {
 
         Ok(current_time)
     }
+
+    /// Builds a basic output with `amount` for `address` that can only be unlocked after the wall-clock
+    /// `unix_timestamp` in seconds, which has to be after the latest milestone timestamp.
+    pub async fn build_timelocked_basic_output(
+        &self,
+        address: impl Into<Address> + Send,
+        amount: u64,
+        unix_timestamp: u64,
+    ) -> Result<Output> {
+        let network_info = self.get_network_info().await?;
+        let milestone_timestamp = network_info
+            .latest_milestone_timestamp
+            .unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
+
+        timelocked_basic_output(
+            address.into(),
+            amount,
+            unix_timestamp,
+            milestone_timestamp,
+            *network_info.protocol_parameters.rent_structure(),
+            network_info.protocol_parameters.token_supply(),
+        )
+    }
+}
+
+pub(crate) fn timelocked_basic_output(
+    address: Address,
+    amount: u64,
+    unix_timestamp: u64,
+    milestone_timestamp: u32,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> Result<Output> {
+    let timestamp = u32::try_from(unix_timestamp)
+        .ok()
+        .filter(|timestamp| *timestamp > milestone_timestamp)
+        .ok_or(Error::InvalidTimelock {
+            timestamp: unix_timestamp,
+            milestone_timestamp,
+        })?;
+
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .add_unlock_condition(TimelockUnlockCondition::new(timestamp)?)
+        .finish_output(token_supply)?;
+
+    output.verify_storage_deposit(rent_structure, token_supply)?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{address::Ed25519Address, protocol::protocol_parameters};
+
+    #[test]
+    fn timelocked_output() {
+        let protocol_parameters = protocol_parameters();
+        let address = Address::from(Ed25519Address::new([0; 32]));
+        let build = |unix_timestamp| {
+            timelocked_basic_output(
+                address,
+                1_000_000,
+                unix_timestamp,
+                100,
+                *protocol_parameters.rent_structure(),
+                protocol_parameters.token_supply(),
+            )
+        };
+
+        let output = build(200).unwrap();
+        assert_eq!(output.amount(), 1_000_000);
+        assert_eq!(output.as_basic().address(), &address);
+        assert_eq!(output.unlock_conditions().unwrap().timelock().unwrap().timestamp(), 200);
+
+        assert!(matches!(
+            build(100),
+            Err(Error::InvalidTimelock {
+                timestamp: 100,
+                milestone_timestamp: 100
+            })
+        ));
+        assert!(matches!(
+            build(u64::from(u32::MAX) + 1),
+            Err(Error::InvalidTimelock { .. })
+        ));
+    }
 }
@@ -5,10 +5,15 @@
 
 mod address;
 mod block_builder;
+mod block_cone;
 mod consolidation;
+pub mod envelope;
 mod high_level;
+mod summary;
 mod types;
+#[cfg(feature = "mqtt")]
+mod utxo_changes;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{address::*, block_builder::*, block_cone::*, summary::*, types::*};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
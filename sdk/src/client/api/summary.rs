@@ -0,0 +1,282 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use super::PreparedTransactionData;
+use crate::types::block::{
+    address::{Bech32Address, ToBech32Ext},
+    output::{AliasId, FoundryId, NativeToken, NftId, Output, Rent, TokenId},
+    payload::transaction::TransactionEssence,
+    protocol::ProtocolParameters,
+    Error,
+};
+
+/// A human-readable summary of a [`PreparedTransactionData`], that should be shown to the user before it's signed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSummary {
+    /// The created outputs, without the remainder.
+    pub recipients: Vec<OutputSummary>,
+    /// The remainder output that's sent back to the wallet.
+    pub remainder: Option<OutputSummary>,
+    /// The base coin amount of the inputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub input_amount: u64,
+    /// The base coin amount of the outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub output_amount: u64,
+    /// The native tokens that are minted.
+    pub minted_native_tokens: Vec<NativeToken>,
+    /// The native tokens that are burned or melted.
+    pub burned_native_tokens: Vec<NativeToken>,
+    /// The aliases that are destroyed.
+    pub burned_aliases: Vec<AliasId>,
+    /// The NFTs that are burned.
+    pub burned_nfts: Vec<NftId>,
+    /// The foundries that are destroyed.
+    pub burned_foundries: Vec<FoundryId>,
+    /// The storage deposit required by the inputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub input_storage_deposit: u64,
+    /// The storage deposit required by the outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub output_storage_deposit: u64,
+}
+
+/// A human-readable summary of an output of a [`TransactionSummary`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputSummary {
+    /// The kind of the output, e.g. `Basic`.
+    pub kind: String,
+    /// The address that owns the output, the state controller for an alias output.
+    pub address: Option<Bech32Address>,
+    /// The base coin amount.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The native tokens.
+    pub native_tokens: Vec<NativeToken>,
+    /// The alias ID, `None` for new aliases.
+    pub alias_id: Option<AliasId>,
+    /// The NFT ID, `None` for new NFTs.
+    pub nft_id: Option<NftId>,
+    /// The foundry ID.
+    pub foundry_id: Option<FoundryId>,
+    /// The amount that has to be returned to the sender to claim the output.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub storage_deposit_return: Option<u64>,
+    /// The time after which the output returns to the sender, if it isn't claimed.
+    pub expiration: Option<u32>,
+    /// The time until the output can't be unlocked.
+    pub timelock: Option<u32>,
+}
+
+impl OutputSummary {
+    fn new(output: &Output, protocol_parameters: &ProtocolParameters) -> Self {
+        let hrp = *protocol_parameters.bech32_hrp();
+        let unlock_conditions = output.unlock_conditions();
+        let address = unlock_conditions.and_then(|unlock_conditions| {
+            unlock_conditions
+                .address()
+                .map(|uc| *uc.address())
+                .or_else(|| unlock_conditions.state_controller_address().map(|uc| *uc.address()))
+                .or_else(|| unlock_conditions.immutable_alias_address().map(|uc| *uc.address()))
+        });
+
+        Self {
+            kind: output.kind_str().to_owned(),
+            address: address.map(|address| address.to_bech32(hrp)),
+            amount: output.amount(),
+            native_tokens: output
+                .native_tokens()
+                .map(|native_tokens| native_tokens.iter().cloned().collect())
+                .unwrap_or_default(),
+            alias_id: match output {
+                Output::Alias(alias) if !alias.alias_id().is_null() => Some(*alias.alias_id()),
+                _ => None,
+            },
+            nft_id: match output {
+                Output::Nft(nft) if !nft.nft_id().is_null() => Some(*nft.nft_id()),
+                _ => None,
+            },
+            foundry_id: match output {
+                Output::Foundry(foundry) => Some(foundry.id()),
+                _ => None,
+            },
+            storage_deposit_return: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                .map(|sdruc| sdruc.amount()),
+            expiration: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.expiration())
+                .map(|expiration| expiration.timestamp()),
+            timelock: unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.timelock())
+                .map(|timelock| timelock.timestamp()),
+        }
+    }
+}
+
+impl PreparedTransactionData {
+    /// Returns a human-readable summary of the transaction, that should be shown to the user before it's signed.
+    pub fn summary(&self, protocol_parameters: &ProtocolParameters) -> Result<TransactionSummary, Error> {
+        let TransactionEssence::Regular(essence) = &self.essence;
+        let rent_structure = protocol_parameters.rent_structure();
+
+        let mut native_token_balances = BTreeMap::<TokenId, (U256, U256)>::new();
+        let mut input_aliases = BTreeSet::new();
+        let mut input_nfts = BTreeSet::new();
+        let mut input_foundries = BTreeSet::new();
+
+        for input in &self.inputs_data {
+            for native_token in input.output.native_tokens().into_iter().flat_map(|n| n.iter()) {
+                native_token_balances.entry(*native_token.token_id()).or_default().0 += native_token.amount();
+            }
+            match &input.output {
+                Output::Alias(alias) => {
+                    input_aliases.insert(alias.alias_id_non_null(input.output_id()));
+                }
+                Output::Nft(nft) => {
+                    input_nfts.insert(nft.nft_id_non_null(input.output_id()));
+                }
+                Output::Foundry(foundry) => {
+                    input_foundries.insert(foundry.id());
+                }
+                _ => {}
+            }
+        }
+
+        for output in essence.outputs() {
+            for native_token in output.native_tokens().into_iter().flat_map(|n| n.iter()) {
+                native_token_balances.entry(*native_token.token_id()).or_default().1 += native_token.amount();
+            }
+            match output {
+                Output::Alias(alias) => {
+                    input_aliases.remove(alias.alias_id());
+                }
+                Output::Nft(nft) => {
+                    input_nfts.remove(nft.nft_id());
+                }
+                Output::Foundry(foundry) => {
+                    input_foundries.remove(&foundry.id());
+                }
+                _ => {}
+            }
+        }
+
+        let mut minted_native_tokens = Vec::new();
+        let mut burned_native_tokens = Vec::new();
+
+        for (token_id, (input_amount, output_amount)) in native_token_balances {
+            if output_amount > input_amount {
+                minted_native_tokens.push(NativeToken::new(token_id, output_amount - input_amount)?);
+            } else if input_amount > output_amount {
+                burned_native_tokens.push(NativeToken::new(token_id, input_amount - output_amount)?);
+            }
+        }
+
+        let remainder_output = self.remainder.as_ref().map(|remainder| &remainder.output);
+        let (remainder, recipients) = essence
+            .outputs()
+            .iter()
+            .partition::<Vec<_>, _>(|output| Some(*output) == remainder_output);
+
+        Ok(TransactionSummary {
+            recipients: recipients
+                .into_iter()
+                .map(|output| OutputSummary::new(output, protocol_parameters))
+                .collect(),
+            remainder: remainder
+                .first()
+                .map(|output| OutputSummary::new(output, protocol_parameters)),
+            input_amount: self.inputs_data.iter().map(|input| input.output.amount()).sum(),
+            output_amount: essence.outputs().iter().map(Output::amount).sum(),
+            minted_native_tokens,
+            burned_native_tokens,
+            burned_aliases: input_aliases.into_iter().collect(),
+            burned_nfts: input_nfts.into_iter().collect(),
+            burned_foundries: input_foundries.into_iter().collect(),
+            input_storage_deposit: self
+                .inputs_data
+                .iter()
+                .map(|input| input.output.rent_cost(rent_structure))
+                .sum(),
+            output_storage_deposit: essence
+                .outputs()
+                .iter()
+                .map(|output| output.rent_cost(rent_structure))
+                .sum(),
+        })
+    }
+}
+
+impl core::fmt::Display for OutputSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {} output", self.amount, self.kind)?;
+        if let Some(address) = &self.address {
+            write!(f, " to {address}")?;
+        }
+        if let Some(alias_id) = &self.alias_id {
+            write!(f, ", alias {alias_id}")?;
+        }
+        if let Some(nft_id) = &self.nft_id {
+            write!(f, ", NFT {nft_id}")?;
+        }
+        if let Some(foundry_id) = &self.foundry_id {
+            write!(f, ", foundry {foundry_id}")?;
+        }
+        for native_token in &self.native_tokens {
+            write!(f, ", {} of {}", native_token.amount(), native_token.token_id())?;
+        }
+        if let Some(amount) = self.storage_deposit_return {
+            write!(f, ", {amount} storage deposit to return")?;
+        }
+        if let Some(expiration) = self.expiration {
+            write!(f, ", expires at {expiration}")?;
+        }
+        if let Some(timelock) = self.timelock {
+            write!(f, ", timelocked until {timelock}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for TransactionSummary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Inputs: {}, outputs: {}", self.input_amount, self.output_amount)?;
+        for recipient in &self.recipients {
+            writeln!(f, "Send {recipient}")?;
+        }
+        if let Some(remainder) = &self.remainder {
+            writeln!(f, "Remainder {remainder}")?;
+        }
+        for native_token in &self.minted_native_tokens {
+            writeln!(f, "Mint {} of {}", native_token.amount(), native_token.token_id())?;
+        }
+        for native_token in &self.burned_native_tokens {
+            writeln!(
+                f,
+                "Burn or melt {} of {}",
+                native_token.amount(),
+                native_token.token_id()
+            )?;
+        }
+        for alias_id in &self.burned_aliases {
+            writeln!(f, "Destroy alias {alias_id}")?;
+        }
+        for nft_id in &self.burned_nfts {
+            writeln!(f, "Burn NFT {nft_id}")?;
+        }
+        for foundry_id in &self.burned_foundries {
+            writeln!(f, "Destroy foundry {foundry_id}")?;
+        }
+        write!(
+            f,
+            "Storage deposit: {} -> {}",
+            self.input_storage_deposit, self.output_storage_deposit
+        )
+    }
+}
@@ -17,7 +17,8 @@ use crate::{
                 },
                 TransactionPayload,
             },
-            Error,
+            semantic::ConflictReason,
+            BlockId, Error,
         },
         TryFromDto, ValidationParams,
     },
@@ -172,3 +173,16 @@ impl From<&RemainderData> for RemainderDataDto {
         }
     }
 }
+
+/// Progress of retrying a block until it's included, see
+/// [`Client::retry_until_included_with_progress()`](crate::client::Client::retry_until_included_with_progress).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum RetryProgressEvent {
+    /// The block was reattached with the new block id.
+    Reattached(BlockId),
+    /// The block was promoted by the new block id.
+    Promoted(BlockId),
+    /// An attachment of the block conflicts with the ledger, another attachment could still get included.
+    ConflictDetected(ConflictReason),
+}
@@ -0,0 +1,90 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use futures::{stream, Stream};
+use tokio::sync::mpsc;
+
+use crate::{
+    client::{
+        mqtt::{MqttPayload, Topic},
+        Client, Result,
+    },
+    types::api::core::response::UtxoChangesResponse,
+};
+
+/// How long to wait for a confirmed milestone event before asking the node, in case MQTT events got lost.
+const CONFIRMED_MILESTONE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+struct UtxoChangesState {
+    client: Client,
+    receiver: mpsc::UnboundedReceiver<u32>,
+    next_index: u32,
+    confirmed_index: u32,
+}
+
+impl Client {
+    /// Returns a stream of the UTXO changes of all confirmed milestones, starting at `start_index`.
+    ///
+    /// New milestones are picked up from the confirmed milestone MQTT topic, past and missed milestones are backfilled
+    /// from the REST API, so the changes of every milestone are yielded once and in order. If the changes of a
+    /// milestone can't be requested, the error is yielded and the same milestone is requested again on the next poll.
+    /// The stream ends when the `milestone-info/confirmed` topic is unsubscribed.
+    /// ```ignore
+    /// let stream = client.utxo_changes_stream(start_index).await?;
+    /// futures::pin_mut!(stream);
+    /// while let Some(utxo_changes) = stream.next().await {
+    ///     let utxo_changes = utxo_changes?;
+    /// }
+    /// ```
+    pub async fn utxo_changes_stream(
+        &self,
+        start_index: u32,
+    ) -> Result<impl Stream<Item = Result<UtxoChangesResponse>> + Send> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        self.subscribe([Topic::new_unchecked("milestone-info/confirmed")], move |event| {
+            if let MqttPayload::Json(milestone_info) = &event.payload {
+                if let Some(index) = milestone_info["index"].as_u64() {
+                    // The stream may have been dropped already.
+                    sender.send(index as u32).ok();
+                }
+            }
+        })
+        .await?;
+
+        let confirmed_index = self.get_info().await?.node_info.status.confirmed_milestone.index;
+        let state = UtxoChangesState {
+            client: self.clone(),
+            receiver,
+            next_index: start_index,
+            confirmed_index,
+        };
+
+        Ok(stream::unfold(state, |mut state| async move {
+            while state.next_index > state.confirmed_index {
+                match tokio::time::timeout(CONFIRMED_MILESTONE_POLL_INTERVAL, state.receiver.recv()).await {
+                    Ok(Some(index)) => state.confirmed_index = state.confirmed_index.max(index),
+                    Ok(None) => return None,
+                    Err(_) => match state.client.get_info().await {
+                        Ok(info) => {
+                            state.confirmed_index = state
+                                .confirmed_index
+                                .max(info.node_info.status.confirmed_milestone.index)
+                        }
+                        Err(err) => return Some((Err(err), state)),
+                    },
+                }
+            }
+
+            let utxo_changes = state.client.get_utxo_changes_by_index(state.next_index).await;
+
+            if utxo_changes.is_ok() {
+                state.next_index += 1;
+            }
+
+            Some((utxo_changes, state))
+        }))
+    }
+}
@@ -9,14 +9,31 @@ use serde::{Deserialize, Serialize};
 use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
+#[cfg(not(target_family = "wasm"))]
+use crate::client::node_manager::dns::DnsResolver;
+#[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+use crate::client::node_manager::proxy::ProxyConfig;
+#[cfg(all(feature = "tls", not(target_family = "wasm")))]
+use crate::client::node_manager::tls::TlsConfig;
 use crate::{
     client::{
-        constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
+        api::PowPolicy,
+        cache::ResponseCache,
+        constants::{
+            DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL, NODE_EVENT_CHANNEL_CAPACITY,
+            POW_EVENT_CHANNEL_CAPACITY, PROTOCOL_PARAMETERS_CHANNEL_CAPACITY,
+        },
         error::Result,
+        explorer::ExplorerUrlTemplate,
         node_manager::{
             builder::validate_url,
+            interceptor::RequestInterceptor,
+            metrics::MetricsSink,
             node::{Node, NodeAuth},
+            retry::RetryPolicy,
         },
+        protocol_parameters::ProtocolParametersRefresh,
+        tag_protocol::{TagProtocol, TagProtocols},
         Client,
     },
     types::block::protocol::ProtocolParameters,
@@ -52,6 +69,12 @@ pub struct ClientBuilder {
     #[cfg(not(target_family = "wasm"))]
     #[serde(default = "default_max_parallel_api_requests")]
     pub max_parallel_api_requests: usize,
+    /// The max number of immutable responses cached by the client, 0 disables the cache
+    #[serde(default)]
+    pub response_cache_size: usize,
+    /// The registered tag protocols
+    #[serde(skip)]
+    pub(crate) tag_protocols: TagProtocols,
 }
 
 fn default_api_timeout() -> Duration {
@@ -74,7 +97,10 @@ impl Default for NetworkInfo {
             protocol_parameters: ProtocolParameters::default(),
             local_pow: default_local_pow(),
             fallback_to_local_pow: true,
+            pow_policy: PowPolicy::default(),
             tips_interval: DEFAULT_TIPS_INTERVAL,
+            explorer_url_template: None,
+            protocol_parameters_refresh: None,
             latest_milestone_timestamp: None,
         }
     }
@@ -93,6 +119,8 @@ impl Default for ClientBuilder {
             pow_worker_count: None,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: super::constants::MAX_PARALLEL_API_REQUESTS,
+            response_cache_size: 0,
+            tag_protocols: TagProtocols::default(),
         }
     }
 }
@@ -177,6 +205,17 @@ impl ClientBuilder {
         self
     }
 
+    /// Use the client without a network, with the given protocol parameters instead of the ones of the nodes.
+    ///
+    /// Transactions can still be prepared and signed, and storage deposits and bech32 addresses computed, but no
+    /// requests are sent to nodes and calls that need them return
+    /// [`Error::OfflineMode`](crate::client::Error::OfflineMode).
+    pub fn with_offline_mode(mut self, protocol_parameters: impl Into<ProtocolParameters>) -> Self {
+        self.network_info.protocol_parameters = protocol_parameters.into();
+        self.node_manager_builder = self.node_manager_builder.with_offline(true);
+        self
+    }
+
     /// Set if quorum should be used or not
     pub fn with_quorum(mut self, quorum: bool) -> Self {
         self.node_manager_builder = self.node_manager_builder.with_quorum(quorum);
@@ -210,7 +249,17 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the policy of where the PoW of blocks is done, e.g. to fall back to remote PoW if local PoW takes too
+    /// long.
+    pub fn with_pow_policy(mut self, pow_policy: PowPolicy) -> Self {
+        self.network_info.pow_policy = pow_policy;
+        self
+    }
+
     /// Sets the amount of workers that should be used for PoW, default is num_cpus::get().
+    ///
+    /// The workers are threads of a pool that is reused for all blocks of the client, so concurrent PoW doesn't
+    /// occupy more threads.
     #[cfg(not(target_family = "wasm"))]
     pub fn with_pow_worker_count(mut self, worker_count: impl Into<Option<usize>>) -> Self {
         self.pow_worker_count = worker_count.into();
@@ -229,6 +278,22 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the template of the block explorer urls of the network.
+    pub fn with_explorer_url_template(mut self, explorer_url_template: impl Into<Option<ExplorerUrlTemplate>>) -> Self {
+        self.network_info.explorer_url_template = explorer_url_template.into();
+        self
+    }
+
+    /// Refreshes the protocol parameters in the background, in addition to the node syncing, so pending protocol
+    /// parameters are used as soon as they activate.
+    pub fn with_protocol_parameters_refresh(
+        mut self,
+        protocol_parameters_refresh: impl Into<Option<ProtocolParametersRefresh>>,
+    ) -> Self {
+        self.network_info.protocol_parameters_refresh = protocol_parameters_refresh.into();
+        self
+    }
+
     /// Sets the default request timeout.
     pub fn with_api_timeout(mut self, timeout: Duration) -> Self {
         self.api_timeout = timeout;
@@ -248,6 +313,70 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the policy for retrying requests that failed with a transient error like 429 Too Many Requests, a 5xx status
+    /// code or a timeout, before the next node is tried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Set if TLS is required for all node connections. Nodes with plaintext `http://` URLs, apart from localhost, will
+    /// be rejected when building the client and skipped during node syncing and requests.
+    pub fn with_require_tls(mut self, require_tls: bool) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_require_tls(require_tls);
+        self
+    }
+
+    /// Set the TLS configuration of all HTTP and MQTT connections to nodes, to trust custom CA certificates or pin
+    /// certificates, e.g. for self-hosted nodes with a private PKI.
+    #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+    pub fn with_tls_config(mut self, tls_config: impl Into<Option<TlsConfig>>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_tls_config(tls_config);
+        self
+    }
+
+    /// Set a proxy that all HTTP and MQTT connections to nodes are tunneled through, e.g. a corporate proxy or Tor.
+    #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+    pub fn with_proxy(mut self, proxy: impl Into<Option<ProxyConfig>>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_proxy(proxy);
+        self
+    }
+
+    /// Set a [`DnsResolver`] that resolves the host names of nodes instead of the system resolver.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_dns_resolver(mut self, dns_resolver: impl DnsResolver + 'static) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_dns_resolver(dns_resolver);
+        self
+    }
+
+    /// Add a [`RequestInterceptor`] that can mutate every request sent to a node, e.g. to add custom auth headers, and
+    /// observe the responses. Interceptors are called in the order they were added.
+    pub fn with_request_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_request_interceptor(interceptor);
+        self
+    }
+
+    /// Add a [`MetricsSink`] that receives the route, node, status, latency and retries of every request sent to a
+    /// node, e.g. to export them to Prometheus or OpenTelemetry.
+    pub fn with_metrics_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_metrics_sink(sink);
+        self
+    }
+
+    /// Register a [`TagProtocol`] to decode the payloads whose tag starts with its prefix, replacing a protocol with
+    /// the same prefix. If the prefixes of multiple protocols match a tag, the longest one is used.
+    pub fn with_tag_protocol(mut self, protocol: impl TagProtocol + 'static) -> Self {
+        self.tag_protocols.push(Arc::new(protocol));
+        self
+    }
+
+    /// Cache up to `size` responses that can't change anymore, i.e. blocks and milestones by ID and spent outputs and
+    /// their metadata, so they are only requested once. A size of 0 disables the cache.
+    pub fn with_response_cache_size(mut self, size: usize) -> Self {
+        self.response_cache_size = size;
+        self
+    }
+
     /// Set maximum parallel API requests.
     #[cfg(not(target_family = "wasm"))]
     pub fn with_max_parallel_api_requests(mut self, max_parallel_api_requests: usize) -> Self {
@@ -260,8 +389,12 @@ impl ClientBuilder {
     pub async fn finish(self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        self.node_manager_builder.validate_tls()?;
+
         let node_sync_interval = self.node_manager_builder.node_sync_interval;
         let ignore_node_health = self.node_manager_builder.ignore_node_health;
+        let offline = self.node_manager_builder.offline;
+        let protocol_parameters_refresh = self.network_info.protocol_parameters_refresh;
         let nodes = self
             .node_manager_builder
             .primary_node
@@ -274,34 +407,59 @@ impl ClientBuilder {
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
         let client_inner = Arc::new(ClientInner {
-            node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
+            node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())?),
             network_info: RwLock::new(self.network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
             pow_worker_count: RwLock::new(self.pow_worker_count),
+            pow_worker_pool: Default::default(),
+            pow_event_sender: tokio::sync::broadcast::channel(POW_EVENT_CHANNEL_CAPACITY).0,
+            protocol_parameters_sender: tokio::sync::broadcast::channel(PROTOCOL_PARAMETERS_CHANNEL_CAPACITY).0,
+            node_event_sender: tokio::sync::broadcast::channel(NODE_EVENT_CHANNEL_CAPACITY).0,
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
                 client: Default::default(),
                 topic_handlers: Default::default(),
+                topic_qos: Default::default(),
                 broker_options: RwLock::new(self.broker_options),
                 sender: RwLock::new(mqtt_event_tx),
                 receiver: RwLock::new(mqtt_event_rx),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
+            tag_protocols: RwLock::new(self.tag_protocols),
+            response_cache: std::sync::Mutex::new(ResponseCache::new(self.response_cache_size)),
         });
 
-        client_inner.sync_nodes(&nodes, ignore_node_health).await?;
-        let client_clone = client_inner.clone();
+        // Without a network there are no nodes to sync, the protocol parameters are the injected ones.
+        let sync_handle = if offline {
+            None
+        } else {
+            client_inner.sync_nodes(&nodes, ignore_node_health).await?;
+            let client_clone = client_inner.clone();
+
+            Some(tokio::spawn(async move {
+                client_clone
+                    .start_sync_process(nodes, node_sync_interval, ignore_node_health)
+                    .await
+            }))
+        };
 
-        let sync_handle = tokio::spawn(async move {
-            client_clone
-                .start_sync_process(nodes, node_sync_interval, ignore_node_health)
-                .await
+        // The task holds a client without handles, so the task is aborted when the last client is dropped.
+        let protocol_parameters_refresh_handle = protocol_parameters_refresh.filter(|_| !offline).map(|refresh| {
+            let client = Client {
+                inner: client_inner.clone(),
+                _sync_handle: Default::default(),
+                _protocol_parameters_refresh_handle: Default::default(),
+            };
+            tokio::spawn(client.start_protocol_parameters_refresh(refresh))
         });
 
         let client = Client {
             inner: client_inner,
-            _sync_handle: Arc::new(RwLock::new(super::SyncHandle(Some(sync_handle)))),
+            _sync_handle: Arc::new(RwLock::new(super::SyncHandle(sync_handle))),
+            _protocol_parameters_refresh_handle: Arc::new(RwLock::new(super::SyncHandle(
+                protocol_parameters_refresh_handle,
+            ))),
         };
 
         Ok(client)
@@ -312,24 +470,32 @@ impl ClientBuilder {
     pub async fn finish(self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        self.node_manager_builder.validate_tls()?;
+
         #[cfg(feature = "mqtt")]
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
 
         let client = Client {
             inner: Arc::new(ClientInner {
-                node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
+                node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())?),
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                pow_event_sender: tokio::sync::broadcast::channel(POW_EVENT_CHANNEL_CAPACITY).0,
+                protocol_parameters_sender: tokio::sync::broadcast::channel(PROTOCOL_PARAMETERS_CHANNEL_CAPACITY).0,
+                node_event_sender: tokio::sync::broadcast::channel(NODE_EVENT_CHANNEL_CAPACITY).0,
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
                     client: Default::default(),
                     topic_handlers: Default::default(),
+                    topic_qos: Default::default(),
                     broker_options: RwLock::new(self.broker_options),
                     sender: RwLock::new(mqtt_event_tx),
                     receiver: RwLock::new(mqtt_event_rx),
                 },
                 last_sync: tokio::sync::Mutex::new(None),
+                tag_protocols: RwLock::new(self.tag_protocols),
+                response_cache: std::sync::Mutex::new(ResponseCache::new(self.response_cache_size)),
             }),
         };
 
@@ -340,7 +506,7 @@ impl ClientBuilder {
         Self {
             node_manager_builder: NodeManagerBuilder::from(&*client.node_manager.read().await),
             #[cfg(feature = "mqtt")]
-            broker_options: *client.mqtt.broker_options.read().await,
+            broker_options: client.mqtt.broker_options.read().await.clone(),
             network_info: client.network_info.read().await.clone(),
             api_timeout: client.get_timeout().await,
             remote_pow_timeout: client.get_remote_pow_timeout().await,
@@ -348,6 +514,8 @@ impl ClientBuilder {
             pow_worker_count: *client.pow_worker_count.read().await,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests: client.request_pool.size().await,
+            response_cache_size: client.response_cache_size(),
+            tag_protocols: client.tag_protocols.read().await.clone(),
         }
     }
 }
@@ -365,9 +533,18 @@ pub struct NetworkInfo {
     /// Fallback to local proof of work if the node doesn't support remote PoW.
     #[serde(default = "default_fallback_to_local_pow")]
     pub fallback_to_local_pow: bool,
+    /// The policy of where the PoW of blocks is done.
+    #[serde(default)]
+    pub pow_policy: PowPolicy,
     /// Tips request interval during PoW in seconds.
     #[serde(default = "default_tips_interval")]
     pub tips_interval: u64,
+    /// The template of the block explorer urls of the network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explorer_url_template: Option<ExplorerUrlTemplate>,
+    /// When the protocol parameters are refreshed in the background, in addition to the node syncing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol_parameters_refresh: Option<ProtocolParametersRefresh>,
     /// The latest cached milestone timestamp.
     #[serde(skip)]
     pub latest_milestone_timestamp: Option<u32>,
@@ -389,11 +566,29 @@ impl NetworkInfo {
         self
     }
 
+    pub fn with_pow_policy(mut self, pow_policy: PowPolicy) -> Self {
+        self.pow_policy = pow_policy;
+        self
+    }
+
     pub fn with_tips_interval(mut self, tips_interval: u64) -> Self {
         self.tips_interval = tips_interval;
         self
     }
 
+    pub fn with_explorer_url_template(mut self, explorer_url_template: impl Into<Option<ExplorerUrlTemplate>>) -> Self {
+        self.explorer_url_template = explorer_url_template.into();
+        self
+    }
+
+    pub fn with_protocol_parameters_refresh(
+        mut self,
+        protocol_parameters_refresh: impl Into<Option<ProtocolParametersRefresh>>,
+    ) -> Self {
+        self.protocol_parameters_refresh = protocol_parameters_refresh.into();
+        self
+    }
+
     pub fn with_latest_milestone_timestamp(mut self, latest_milestone_timestamp: impl Into<Option<u32>>) -> Self {
         self.latest_milestone_timestamp = latest_milestone_timestamp.into();
         self
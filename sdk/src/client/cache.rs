@@ -0,0 +1,199 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A least recently used cache of node responses that can't change anymore, see
+//! [`ClientBuilder::with_response_cache_size()`](crate::client::ClientBuilder::with_response_cache_size).
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    client::ClientInner,
+    types::block::{
+        output::{OutputId, OutputMetadata, OutputWithMetadata},
+        payload::milestone::{MilestoneId, MilestonePayload},
+        Block, BlockId,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) enum CacheKey {
+    Block(BlockId),
+    Milestone(MilestoneId),
+    Output(OutputId),
+    OutputMetadata(OutputId),
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum CachedResponse {
+    Block(Block),
+    Milestone(MilestonePayload),
+    Output(OutputWithMetadata),
+    OutputMetadata(OutputMetadata),
+}
+
+/// Blocks and milestones never change once they exist and outputs don't anymore once they are spent, so they can be
+/// cached without invalidation.
+#[derive(Debug, Default)]
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    // The last access of each entry, the smallest one is evicted first.
+    entries: HashMap<CacheKey, (u64, CachedResponse)>,
+    accesses: BTreeMap<u64, CacheKey>,
+    next_access: u64,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > self.capacity {
+            let Some((_, key)) = self.accesses.pop_first() else {
+                break;
+            };
+            self.entries.remove(&key);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.accesses.clear();
+    }
+
+    pub(crate) fn get(&mut self, key: &CacheKey) -> Option<CachedResponse> {
+        let access = self.next_access();
+        let (last_access, response) = self.entries.get_mut(key)?;
+
+        self.accesses.remove(last_access);
+        self.accesses.insert(access, *key);
+        *last_access = access;
+
+        Some(response.clone())
+    }
+
+    pub(crate) fn insert(&mut self, key: CacheKey, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let access = self.next_access();
+        if let Some((last_access, _)) = self.entries.insert(key, (access, response)) {
+            self.accesses.remove(&last_access);
+        }
+        self.accesses.insert(access, key);
+        self.set_capacity(self.capacity);
+    }
+
+    pub(crate) fn get_block(&mut self, block_id: &BlockId) -> Option<Block> {
+        match self.get(&CacheKey::Block(*block_id))? {
+            CachedResponse::Block(block) => Some(block),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_milestone(&mut self, milestone_id: &MilestoneId) -> Option<MilestonePayload> {
+        match self.get(&CacheKey::Milestone(*milestone_id))? {
+            CachedResponse::Milestone(milestone) => Some(milestone),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_output(&mut self, output_id: &OutputId) -> Option<OutputWithMetadata> {
+        match self.get(&CacheKey::Output(*output_id))? {
+            CachedResponse::Output(output) => Some(output),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_output_metadata(&mut self, output_id: &OutputId) -> Option<OutputMetadata> {
+        match self.get(&CacheKey::OutputMetadata(*output_id))? {
+            CachedResponse::OutputMetadata(metadata) => Some(metadata),
+            _ => None,
+        }
+    }
+
+    fn next_access(&mut self) -> u64 {
+        self.next_access += 1;
+        self.next_access
+    }
+}
+
+impl ClientInner {
+    /// Returns the max number of cached responses, 0 if the cache is disabled.
+    pub fn response_cache_size(&self) -> usize {
+        self.with_response_cache(|cache| cache.capacity()).unwrap_or_default()
+    }
+
+    /// Sets the max number of cached responses, evicting the least recently used ones if there are more.
+    pub fn set_response_cache_size(&self, size: usize) {
+        self.with_response_cache(|cache| cache.set_capacity(size));
+    }
+
+    /// Removes all cached responses.
+    pub fn clear_response_cache(&self) {
+        self.with_response_cache(ResponseCache::clear);
+    }
+
+    // A poisoned cache is skipped, the responses are requested from the nodes instead.
+    pub(crate) fn with_response_cache<R>(&self, f: impl FnOnce(&mut ResponseCache) -> R) -> Option<R> {
+        self.response_cache.lock().ok().map(|mut cache| f(&mut cache))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::rand::{block::rand_block, output::rand_output_id};
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = ResponseCache::new(2);
+        let blocks = [rand_block(), rand_block(), rand_block()];
+
+        cache.insert(
+            CacheKey::Block(blocks[0].id()),
+            CachedResponse::Block(blocks[0].clone()),
+        );
+        cache.insert(
+            CacheKey::Block(blocks[1].id()),
+            CachedResponse::Block(blocks[1].clone()),
+        );
+        // The first block is now used more recently than the second one.
+        assert_eq!(cache.get_block(&blocks[0].id()), Some(blocks[0].clone()));
+        cache.insert(
+            CacheKey::Block(blocks[2].id()),
+            CachedResponse::Block(blocks[2].clone()),
+        );
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get_block(&blocks[0].id()), Some(blocks[0].clone()));
+        assert_eq!(cache.get_block(&blocks[1].id()), None);
+        assert_eq!(cache.get_block(&blocks[2].id()), Some(blocks[2].clone()));
+    }
+
+    #[test]
+    fn disabled() {
+        let mut cache = ResponseCache::new(0);
+        let block = rand_block();
+
+        cache.insert(CacheKey::Block(block.id()), CachedResponse::Block(block.clone()));
+
+        assert_eq!(cache.len(), 0);
+        assert_eq!(cache.get_block(&block.id()), None);
+        assert!(cache.get_output(&rand_output_id()).is_none());
+    }
+}
@@ -15,10 +15,22 @@ pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
 /// Interval in seconds when new tips will be requested during PoW, so the final block always will be attached to a
 /// new part of the Tangle
 pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
+/// The shortest interval of the milestones of the IOTA networks, in seconds
+pub(crate) const MIN_MILESTONE_INTERVAL_IN_SECONDS: u32 = 5;
+/// Capacity of the channel for PoW events, older events are dropped for slow subscribers
+pub(crate) const POW_EVENT_CHANNEL_CAPACITY: usize = 16;
+/// Capacity of the channel for protocol parameters updates, older updates are dropped for slow subscribers
+pub(crate) const PROTOCOL_PARAMETERS_CHANNEL_CAPACITY: usize = 16;
+/// Capacity of the channel for node events, older events are dropped for slow subscribers
+pub(crate) const NODE_EVENT_CHANNEL_CAPACITY: usize = 16;
 /// Interval in which the node info will be requested and healthy nodes will be added to the healthy node pool
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
 pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
+/// Default delay before the first retry of a failed request
+pub(crate) const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+/// Default max random delay added to the delay before retrying a failed request
+pub(crate) const DEFAULT_RETRY_JITTER: Duration = Duration::from_millis(100);
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 #[cfg(not(target_family = "wasm"))]
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
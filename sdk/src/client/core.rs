@@ -8,7 +8,7 @@ use std::{sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 #[cfg(feature = "mqtt")]
 use {
-    crate::client::node_api::mqtt::{BrokerOptions, MqttEvent, TopicHandlerMap},
+    crate::client::node_api::mqtt::{BrokerOptions, MqttEvent, TopicHandlerMap, TopicQosMap},
     rumqttc::AsyncClient as MqttClient,
     tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
@@ -17,11 +17,18 @@ use {
 use super::request_pool::RequestPool;
 #[cfg(target_family = "wasm")]
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
+#[cfg(not(target_family = "wasm"))]
+use crate::pow::pool::PowWorkerPool;
 use crate::{
     client::{
+        api::{PowEvent, PowPolicy},
         builder::{ClientBuilder, NetworkInfo},
+        cache::ResponseCache,
         error::Result,
-        node_manager::NodeManager,
+        explorer::ExplorerUrlTemplate,
+        node_manager::{events::NodeEvent, NodeManager},
+        protocol_parameters::ProtocolParametersUpdate,
+        tag_protocol::TagProtocols,
         Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
@@ -33,6 +40,8 @@ pub struct Client {
     pub(crate) inner: Arc<ClientInner>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) _sync_handle: Arc<RwLock<SyncHandle>>,
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) _protocol_parameters_refresh_handle: Arc<RwLock<SyncHandle>>,
 }
 
 impl core::ops::Deref for Client {
@@ -54,12 +63,20 @@ pub struct ClientInner {
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
+    /// The worker pool for local PoW, created on first use.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) pow_worker_pool: tokio::sync::Mutex<Option<Arc<PowWorkerPool>>>,
+    pub(crate) pow_event_sender: tokio::sync::broadcast::Sender<PowEvent>,
+    pub(crate) protocol_parameters_sender: tokio::sync::broadcast::Sender<ProtocolParametersUpdate>,
+    pub(crate) node_event_sender: tokio::sync::broadcast::Sender<NodeEvent>,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt: MqttInner,
     #[cfg(target_family = "wasm")]
     pub(crate) last_sync: tokio::sync::Mutex<Option<u32>>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) request_pool: RequestPool,
+    pub(crate) tag_protocols: RwLock<TagProtocols>,
+    pub(crate) response_cache: std::sync::Mutex<ResponseCache>,
 }
 
 #[derive(Default)]
@@ -79,6 +96,8 @@ pub(crate) struct MqttInner {
     /// A MQTT client to subscribe/unsubscribe to topics.
     pub(crate) client: RwLock<Option<MqttClient>>,
     pub(crate) topic_handlers: RwLock<TopicHandlerMap>,
+    /// The QoS levels of the subscribed topics, to restore them on reconnection.
+    pub(crate) topic_qos: RwLock<TopicQosMap>,
     pub(crate) broker_options: RwLock<BrokerOptions>,
     pub(crate) sender: RwLock<WatchSender<MqttEvent>>,
     pub(crate) receiver: RwLock<WatchReceiver<MqttEvent>>,
@@ -112,7 +131,7 @@ impl ClientInner {
         // difficulty or the byte cost could change via a milestone, so we request the node info every time, so we don't
         // create invalid transactions/blocks.
         #[cfg(target_family = "wasm")]
-        {
+        if !self.is_offline().await {
             let current_time = crate::utils::unix_timestamp_now().as_secs() as u32;
             if let Some(last_sync) = *self.last_sync.lock().await {
                 if current_time < last_sync {
@@ -128,6 +147,12 @@ impl ClientInner {
         Ok(self.network_info.read().await.clone())
     }
 
+    /// Returns whether the client is in offline mode, see
+    /// [`ClientBuilder::with_offline_mode()`](crate::client::ClientBuilder::with_offline_mode).
+    pub async fn is_offline(&self) -> bool {
+        self.node_manager.read().await.offline
+    }
+
     /// Gets the protocol parameters of the node we're connecting to.
     pub async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
         Ok(self.get_network_info().await?.protocol_parameters)
@@ -183,6 +208,18 @@ impl ClientInner {
         self.network_info.read().await.local_pow
     }
 
+    /// Returns the worker pool for local PoW, (re)creating it if the pow worker count changed.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn pow_worker_pool(&self) -> Arc<PowWorkerPool> {
+        let num_workers = self.pow_worker_count.read().await.unwrap_or_else(num_cpus::get).max(1);
+        let mut pool = self.pow_worker_pool.lock().await;
+
+        match &*pool {
+            Some(pool) if pool.num_workers() == num_workers => pool.clone(),
+            _ => pool.insert(Arc::new(PowWorkerPool::new(num_workers))).clone(),
+        }
+    }
+
     pub(crate) async fn get_timeout(&self) -> Duration {
         *self.api_timeout.read().await
     }
@@ -191,6 +228,16 @@ impl ClientInner {
         *self.remote_pow_timeout.read().await
     }
 
+    /// Returns the policy of where the PoW of blocks is done.
+    pub async fn get_pow_policy(&self) -> PowPolicy {
+        self.network_info.read().await.pow_policy
+    }
+
+    /// Returns the template of the block explorer urls of the network, if configured.
+    pub async fn get_explorer_url_template(&self) -> Option<ExplorerUrlTemplate> {
+        self.network_info.read().await.explorer_url_template.clone()
+    }
+
     /// returns the fallback_to_local_pow
     pub async fn get_fallback_to_local_pow(&self) -> bool {
         self.network_info.read().await.fallback_to_local_pow
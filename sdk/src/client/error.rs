@@ -3,7 +3,7 @@
 
 //! Error handling in iota-client crate.
 
-use std::fmt::Debug;
+use std::{fmt::Debug, str::FromStr};
 
 use packable::error::UnexpectedEOF;
 use serde::{
@@ -13,7 +13,7 @@ use serde::{
 
 use crate::{
     client::{api::input_selection::Error as InputSelectionError, node_api::indexer::QueryParameter},
-    types::block::semantic::ConflictReason,
+    types::block::{address::Hrp, output::OutputId, semantic::ConflictReason},
 };
 
 /// Type alias of `Result` in iota-client
@@ -26,7 +26,10 @@ pub enum Error {
     #[error("{0}")]
     ApiTypes(#[from] crate::types::api::core::error::Error),
     /// Invalid bech32 HRP, should match the one from the used network
-    #[error("invalid bech32 hrp for the connected network: {provided}, expected: {expected}")]
+    #[error(
+        "invalid bech32 hrp for the connected network: {provided}, expected: {expected}{}",
+        network_hint(provided)
+    )]
     Bech32HrpMismatch {
         /// The bech32 human readable part from the provided address.
         provided: String,
@@ -45,6 +48,12 @@ pub enum Error {
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
+    /// EIP-712 typed data error
+    #[error("{0}")]
+    Eip712(#[from] crate::client::secret::eip712::Error),
+    /// The faucet didn't provide the requested funds
+    #[error("faucet error: {0}")]
+    Faucet(String),
     /// Address not found
     #[error("address: {address} not found in range: {range}")]
     InputAddressNotFound {
@@ -59,6 +68,14 @@ pub enum Error {
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// The timelock is not after the latest milestone timestamp or doesn't fit a milestone timestamp
+    #[error("invalid timelock {timestamp}, it has to be after the latest milestone timestamp {milestone_timestamp}")]
+    InvalidTimelock {
+        /// The timestamp of the timelock.
+        timestamp: u64,
+        /// The timestamp of the latest milestone.
+        milestone_timestamp: u32,
+    },
     /// The transaction essence is too large
     #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
     InvalidRegularTransactionEssenceLength {
@@ -81,6 +98,12 @@ pub enum Error {
     /// Missing required parameters
     #[error("must provide required parameter: {0}")]
     MissingParameter(&'static str),
+    /// The request can only be served by a permanode, but none is configured
+    #[error("no permanode configured")]
+    MissingPermanode,
+    /// The client is in offline mode, so requests to nodes aren't possible
+    #[error("the client is in offline mode, no requests are sent to nodes")]
+    OfflineMode,
     /// Error on API request
     #[error("node error: {0}")]
     Node(#[from] crate::client::node_api::error::Error),
@@ -90,12 +113,29 @@ pub enum Error {
     /// Requested output id not found for this type
     #[error("No output found for {0}")]
     NoOutput(String),
+    /// Requesting outputs failed for some output ids
+    #[error("failed to get {} outputs: {}", .0.len(), display_failed_outputs(.0))]
+    OutputRequestsFailed(Vec<(OutputId, Self)>),
+    /// No secret manager of a router secret manager is routed to the coin type and account index
+    #[error("no secret manager route for coin type {coin_type} and account index {account_index}")]
+    SecretManagerRouteNotFound {
+        /// The coin type.
+        coin_type: u32,
+        /// The account index.
+        account_index: u32,
+    },
     /// PlaceholderSecretManager can't be used for address generation or signing
     #[error("placeholderSecretManager can't be used for address generation or signing")]
     PlaceholderSecretManager,
     /// Rw lock failed.
     #[error("rw lock failed")]
     PoisonError,
+    /// The validity deadline of a prepared block has passed
+    #[error("the prepared block was only valid until {valid_until}, the parents are below max depth")]
+    PreparedBlockExpired { valid_until: u32 },
+    /// The local PoW of a block was aborted
+    #[error("local PoW was aborted")]
+    PowAborted,
     /// Prefix hex string convert error
     #[error("{0}")]
     PrefixHex(#[from] prefix_hex::Error),
@@ -115,18 +155,42 @@ pub enum Error {
         /// The minimum quorum threshold.
         minimum_threshold: usize,
     },
+    /// A remote signer used by a callback secret manager failed
+    #[error("remote signer error: {0}")]
+    RemoteSigner(String),
     /// Specifically used for `TryInfo` implementations for `SecretManager`.
     #[error("cannot unwrap a SecretManager: type mismatch!")]
     SecretManagerMismatch,
+    /// The secret manager is locked
+    #[error("secret manager is locked")]
+    SecretManagerLocked,
+    /// The secret doesn't match the one the secret manager was created from
+    #[error("the secret doesn't match the locked secret manager")]
+    SecretManagerUnlockMismatch,
     /// No node available in the healthy node pool
     #[error("no healthy node available")]
     HealthyNodePoolEmpty,
     /// Error when building tagged_data blocks
     #[error("error when building tagged_data block: {0}")]
     TaggedData(String),
+    /// A tag protocol failed to decode a payload
+    #[error("failed to decode payload of tag protocol `{protocol}`: {error}")]
+    TagProtocolDecode {
+        /// The name of the tag protocol.
+        protocol: String,
+        /// The decode error.
+        error: String,
+    },
     /// The block cannot be included into the Tangle
     #[error("block ID `{0}` couldn't get included into the Tangle")]
     TangleInclusion(String),
+    /// TLS is required, but a node doesn't use it
+    #[error("TLS is required, refusing plaintext connection to {0}")]
+    TlsRequired(String),
+    /// Invalid TLS configuration
+    #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+    #[error("invalid TLS configuration: {0}")]
+    TlsConfig(#[from] crate::client::node_manager::tls::TlsConfigError),
     #[cfg(not(target_family = "wasm"))]
     /// Tokio task join error
     #[error("{0}")]
@@ -141,6 +205,9 @@ pub enum Error {
         /// The timestamp of the latest milestone.
         milestone_timestamp: u32,
     },
+    /// Transaction data envelope error
+    #[error("{0}")]
+    TransactionDataEnvelope(#[from] crate::client::api::envelope::Error),
     /// The semantic validation of a transaction failed.
     #[error("the semantic validation of a transaction failed with conflict reason: {} - {0:?}", *.0 as u8)]
     TransactionSemantic(ConflictReason),
@@ -172,11 +239,29 @@ pub enum Error {
     #[error("{0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
 
+    /// Encrypted mnemonic error
+    #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_mnemonic_secret_manager")))]
+    #[error("{0}")]
+    EncryptedMnemonic(#[from] crate::client::secret::encrypted_mnemonic::Error),
+
+    /// Encrypted metadata error
+    #[cfg(feature = "encrypted_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_metadata")))]
+    #[error("{0}")]
+    EncryptedMetadata(#[from] crate::client::secret::encrypted_metadata::Error),
+
+    /// FROST error
+    #[cfg(feature = "frost")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "frost")))]
+    #[error("{0}")]
+    Frost(#[from] crate::client::secret::frost::Error),
+
     /// Ledger error
-    #[cfg(feature = "ledger_nano")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    #[cfg(any(feature = "ledger_nano", feature = "ledger_nano_simulator"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "ledger_nano", feature = "ledger_nano_simulator"))))]
     #[error("{0}")]
-    Ledger(#[from] crate::client::secret::ledger_nano::Error),
+    Ledger(#[from] crate::client::secret::ledger_common::Error),
 
     /// MQTT error
     #[cfg(feature = "mqtt")]
@@ -184,6 +269,12 @@ pub enum Error {
     #[error("{0}")]
     Mqtt(#[from] crate::client::node_api::mqtt::Error),
 
+    /// PKCS#11 error
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    #[error("{0}")]
+    Pkcs11(#[from] crate::client::secret::pkcs11::Error),
+
     /// Stronghold error
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -191,6 +282,23 @@ pub enum Error {
     Stronghold(#[from] crate::client::stronghold::Error),
 }
 
+fn display_failed_outputs(failed: &[(OutputId, Error)]) -> String {
+    failed
+        .iter()
+        .map(|(output_id, error)| format!("{output_id}: {error}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Returns a hint which network an address with the HRP likely belongs to, for error messages.
+fn network_hint(hrp: &str) -> String {
+    Hrp::from_str(hrp)
+        .ok()
+        .and_then(|hrp| hrp.guess_network())
+        .map(|network| format!(", this looks like a {network} address"))
+        .unwrap_or_default()
+}
+
 // Serialize type with Display error
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
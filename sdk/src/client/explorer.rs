@@ -0,0 +1,124 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Links to a block explorer, configured per network with
+//! [`ClientBuilder::with_explorer_url_template()`](crate::client::ClientBuilder::with_explorer_url_template).
+
+use core::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Error, Result},
+    types::block::{
+        address::{Address, Bech32Address, Hrp, ToBech32Ext},
+        payload::transaction::TransactionId,
+        Block, BlockId,
+    },
+};
+
+/// A template of block explorer urls, e.g. `https://explorer.shimmer.network/shimmer/{kind}/{id}`.
+///
+/// `{kind}` is replaced by `block`, `transaction` or `addr`, the paths of the IOTA explorers, and `{id}` by the id of
+/// the block or transaction or the bech32 address. A template without `{kind}` can only link to a single kind.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ExplorerUrlTemplate(String);
+
+impl ExplorerUrlTemplate {
+    /// The placeholder for the kind of the linked item.
+    pub const KIND_PLACEHOLDER: &'static str = "{kind}";
+    /// The placeholder for the id of the linked item.
+    pub const ID_PLACEHOLDER: &'static str = "{id}";
+
+    /// Creates a new [`ExplorerUrlTemplate`], which needs to contain the `{id}` placeholder and be a valid url.
+    pub fn new(template: impl Into<String>) -> Result<Self> {
+        let template = Self(template.into());
+
+        if !template.0.contains(Self::ID_PLACEHOLDER) {
+            return Err(Error::UrlValidation(format!(
+                "explorer url template `{}` doesn't contain {}",
+                template.0,
+                Self::ID_PLACEHOLDER
+            )));
+        }
+        url::Url::parse(&template.url("block", "0x00"))?;
+
+        Ok(template)
+    }
+
+    /// Returns the template as str.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn url(&self, kind: &str, id: impl Display) -> String {
+        self.0
+            .replace(Self::KIND_PLACEHOLDER, kind)
+            .replace(Self::ID_PLACEHOLDER, &id.to_string())
+    }
+
+    /// Returns the explorer url of a block.
+    pub fn block_url(&self, block_id: &BlockId) -> String {
+        self.url("block", block_id)
+    }
+
+    /// Returns the explorer url of a transaction.
+    pub fn transaction_url(&self, transaction_id: &TransactionId) -> String {
+        self.url("transaction", transaction_id)
+    }
+
+    /// Returns the explorer url of an address.
+    pub fn address_url(&self, address: &Bech32Address) -> String {
+        self.url("addr", address)
+    }
+}
+
+impl TryFrom<String> for ExplorerUrlTemplate {
+    type Error = Error;
+
+    fn try_from(template: String) -> Result<Self> {
+        Self::new(template)
+    }
+}
+
+impl From<ExplorerUrlTemplate> for String {
+    fn from(template: ExplorerUrlTemplate) -> Self {
+        template.0
+    }
+}
+
+impl core::str::FromStr for ExplorerUrlTemplate {
+    type Err = Error;
+
+    fn from_str(template: &str) -> Result<Self> {
+        Self::new(template)
+    }
+}
+
+impl Display for ExplorerUrlTemplate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Block {
+    /// Returns the explorer url of the block.
+    pub fn explorer_url(&self, template: &ExplorerUrlTemplate) -> String {
+        template.block_url(&self.id())
+    }
+}
+
+impl Bech32Address {
+    /// Returns the explorer url of the address.
+    pub fn explorer_url(&self, template: &ExplorerUrlTemplate) -> String {
+        template.address_url(self)
+    }
+}
+
+impl Address {
+    /// Returns the explorer url of the address, bech32 encoded with the given HRP.
+    pub fn explorer_url(&self, template: &ExplorerUrlTemplate, bech32_hrp: Hrp) -> String {
+        template.address_url(&self.to_bech32(bech32_hrp))
+    }
+}
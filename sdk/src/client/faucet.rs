@@ -0,0 +1,209 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Client of the faucet of a test network.
+//! <https://github.com/iotaledger/inx-faucet>
+
+use std::time::Duration;
+
+use reqwest::{header::RETRY_AFTER, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::indexer::QueryParameter, Client, Error, Result},
+    types::block::{address::Bech32Address, output::OutputId},
+};
+
+/// The default number of times a rate limited request is retried.
+pub const DEFAULT_FAUCET_MAX_RETRIES: usize = 3;
+/// The default time to wait before retrying a rate limited request, if the faucet doesn't send a `Retry-After` header.
+pub const DEFAULT_FAUCET_RETRY_AFTER: Duration = Duration::from_secs(10);
+/// The default interval in which the indexer is polled while waiting for the funds.
+pub const DEFAULT_FAUCET_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A request that the faucet accepted, the funds are sent once the requests before it are processed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaucetRequestAccepted {
+    /// The address the funds are sent to.
+    pub address: Bech32Address,
+    /// The number of requests in the queue of the faucet.
+    pub waiting_requests: usize,
+}
+
+/// The response of a faucet to a funds request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FaucetResponse {
+    /// The request was queued.
+    Accepted(FaucetRequestAccepted),
+    /// Too many requests were sent, the request can be retried after some time.
+    RateLimited {
+        /// The time to wait before retrying, if the faucet provided it.
+        retry_after: Option<Duration>,
+        /// The message of the faucet.
+        message: String,
+    },
+    /// The request was rejected, e.g. because the address already has enough funds.
+    Rejected {
+        /// The HTTP status code.
+        status: u16,
+        /// The message of the faucet.
+        message: String,
+    },
+}
+
+#[derive(Deserialize)]
+struct FaucetErrorResponse {
+    error: FaucetError,
+}
+
+#[derive(Deserialize)]
+struct FaucetError {
+    message: String,
+}
+
+/// Requests funds from the faucet of a test network.
+#[derive(Clone, Debug)]
+pub struct FaucetClient {
+    url: String,
+    http_client: reqwest::Client,
+    max_retries: usize,
+    default_retry_after: Duration,
+    poll_interval: Duration,
+}
+
+impl FaucetClient {
+    /// Creates a new [`FaucetClient`] for the enqueue URL of a faucet, e.g.
+    /// `https://faucet.testnet.shimmer.network/api/enqueue`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            http_client: reqwest::Client::new(),
+            max_retries: DEFAULT_FAUCET_MAX_RETRIES,
+            default_retry_after: DEFAULT_FAUCET_RETRY_AFTER,
+            poll_interval: DEFAULT_FAUCET_POLL_INTERVAL,
+        }
+    }
+
+    /// Sets how many times a rate limited request is retried.
+    pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets how long to wait before retrying a rate limited request, if the faucet doesn't send a `Retry-After` header.
+    pub fn with_default_retry_after(mut self, default_retry_after: Duration) -> Self {
+        self.default_retry_after = default_retry_after;
+        self
+    }
+
+    /// Sets the interval in which the indexer is polled while waiting for the funds.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sends a single funds request.
+    pub async fn request_funds(&self, address: &Bech32Address) -> Result<FaucetResponse> {
+        let response = self
+            .http_client
+            .post(&self.url)
+            .json(&serde_json::json!({ "address": address }))
+            .send()
+            .await
+            .map_err(|err| Error::Node(err.into()))?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .map(Duration::from_secs);
+        let text = response.text().await.map_err(|err| Error::Node(err.into()))?;
+
+        if status.is_success() {
+            return Ok(FaucetResponse::Accepted(serde_json::from_str(&text)?));
+        }
+
+        let message = serde_json::from_str::<FaucetErrorResponse>(&text)
+            .map(|response| response.error.message)
+            .unwrap_or(text);
+
+        Ok(if status == StatusCode::TOO_MANY_REQUESTS {
+            FaucetResponse::RateLimited { retry_after, message }
+        } else {
+            FaucetResponse::Rejected {
+                status: status.as_u16(),
+                message,
+            }
+        })
+    }
+
+    /// Requests funds, waiting and retrying while the faucet rate limits the requests.
+    pub async fn request_funds_with_retry(&self, address: &Bech32Address) -> Result<FaucetRequestAccepted> {
+        let mut retries = 0;
+        loop {
+            match self.request_funds(address).await? {
+                FaucetResponse::Accepted(accepted) => return Ok(accepted),
+                FaucetResponse::RateLimited { retry_after, message } => {
+                    if retries >= self.max_retries {
+                        return Err(Error::Faucet(message));
+                    }
+                    let delay = retry_after.unwrap_or(self.default_retry_after);
+                    log::debug!("faucet rate limited the request, retrying in {delay:?}: {message}");
+                    sleep(delay).await;
+                    retries += 1;
+                }
+                FaucetResponse::Rejected { status, message } => {
+                    return Err(Error::Faucet(format!("{status}: {message}")));
+                }
+            }
+        }
+    }
+
+    /// Requests funds and waits until the indexer of the client returns new basic outputs for the address, or the
+    /// timeout elapsed. Returns the IDs of the new outputs.
+    pub async fn request_funds_and_wait(
+        &self,
+        client: &Client,
+        address: &Bech32Address,
+        timeout: Duration,
+    ) -> Result<Vec<OutputId>> {
+        let known_output_ids = address_output_ids(client, address).await?;
+
+        self.request_funds_with_retry(address).await?;
+
+        let start_time = instant::Instant::now();
+        loop {
+            let new_output_ids = address_output_ids(client, address)
+                .await?
+                .into_iter()
+                .filter(|output_id| !known_output_ids.contains(output_id))
+                .collect::<Vec<_>>();
+
+            if !new_output_ids.is_empty() {
+                return Ok(new_output_ids);
+            }
+            if start_time.elapsed() >= timeout {
+                return Err(Error::Faucet(format!("funds didn't arrive at {address} within {timeout:?}")));
+            }
+
+            sleep(self.poll_interval).await;
+        }
+    }
+}
+
+async fn address_output_ids(client: &Client, address: &Bech32Address) -> Result<Vec<OutputId>> {
+    Ok(client
+        .basic_output_ids([QueryParameter::Address(*address)])
+        .await?
+        .items)
+}
+
+async fn sleep(duration: Duration) {
+    #[cfg(target_family = "wasm")]
+    gloo_timers::future::TimeoutFuture::new(duration.as_millis().try_into().unwrap_or(u32::MAX)).await;
+    #[cfg(not(target_family = "wasm"))]
+    tokio::time::sleep(duration).await;
+}
@@ -37,18 +37,26 @@ macro_rules! lazy_static {
 
 pub mod api;
 pub mod builder;
+pub(crate) mod cache;
 pub mod constants;
 pub mod core;
 pub mod error;
+pub mod explorer;
+pub mod faucet;
 pub mod node_api;
 pub mod node_manager;
+pub mod protocol_parameters;
 #[cfg(not(target_family = "wasm"))]
 pub(crate) mod request_pool;
 pub mod secret;
+#[cfg(all(feature = "service", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "service")))]
+pub mod service;
 pub mod storage;
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
 pub mod stronghold;
+pub mod tag_protocol;
 pub mod utils;
 
 #[cfg(feature = "mqtt")]
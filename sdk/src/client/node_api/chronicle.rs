@@ -0,0 +1,193 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Chronicle permanode explorer routes, which serve the history that regular nodes have pruned.
+//!
+//! Requests are only sent if a permanode is configured with [`ClientBuilder::with_permanode()`].
+//! <https://github.com/iotaledger/inx-chronicle/blob/main/src/bin/inx-chronicle/api/explorer/routes.rs>
+//!
+//! [`ClientBuilder::with_permanode()`]: crate::client::ClientBuilder::with_permanode
+
+use core::ops::RangeInclusive;
+
+use crate::{
+    client::{ClientInner, Result},
+    types::{
+        api::chronicle::{
+            BlockChildrenResponse, LedgerUpdateByAddress, LedgerUpdatesByAddressResponse,
+            LedgerUpdatesByMilestoneResponse, SortOrder,
+        },
+        block::{
+            address::Bech32Address,
+            payload::milestone::{dto::MilestonePayloadDto, MilestoneId, MilestonePayload},
+            BlockId,
+        },
+        TryFromDto,
+    },
+};
+
+/// Query parameters of paginated ledger updates.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LedgerUpdatesQuery {
+    /// The max number of updates per page.
+    pub page_size: Option<usize>,
+    /// The cursor of the page to request, from a previous response.
+    pub cursor: Option<String>,
+    /// The order of the updates.
+    pub sort: Option<SortOrder>,
+    /// Only return updates from this milestone index on.
+    pub start_milestone_index: Option<u32>,
+}
+
+impl LedgerUpdatesQuery {
+    fn to_query_string(&self) -> Option<String> {
+        let mut query = Vec::new();
+
+        if let Some(page_size) = self.page_size {
+            query.push(format!("pageSize={page_size}"));
+        }
+        if let Some(cursor) = &self.cursor {
+            query.push(format!("cursor={cursor}"));
+        }
+        if let Some(sort) = self.sort {
+            query.push(format!("sort={sort}"));
+        }
+        if let Some(start_milestone_index) = self.start_milestone_index {
+            query.push(format!("startMilestoneIndex={start_milestone_index}"));
+        }
+
+        (!query.is_empty()).then(|| query.join("&"))
+    }
+}
+
+impl ClientInner {
+    /// Get a page of the outputs of an address that were created or spent.
+    /// GET /api/explorer/v2/ledger/updates/by-address/{address}
+    pub async fn get_ledger_updates_by_address(
+        &self,
+        address: &Bech32Address,
+        query: &LedgerUpdatesQuery,
+    ) -> Result<LedgerUpdatesByAddressResponse> {
+        let route = format!("api/explorer/v2/ledger/updates/by-address/{address}");
+
+        self.get_permanode_request(&route, query.to_query_string().as_deref())
+            .await
+    }
+
+    /// Get the complete history of created and spent outputs of an address, following the cursor until all pages
+    /// have been requested.
+    pub async fn get_address_history(
+        &self,
+        address: &Bech32Address,
+        sort: SortOrder,
+    ) -> Result<Vec<LedgerUpdateByAddress>> {
+        let mut query = LedgerUpdatesQuery {
+            sort: Some(sort),
+            ..Default::default()
+        };
+        let mut history = Vec::new();
+
+        loop {
+            let response = self.get_ledger_updates_by_address(address, &query).await?;
+            history.extend(response.items);
+
+            match response.cursor {
+                Some(cursor) => query.cursor.replace(cursor),
+                None => break,
+            };
+        }
+
+        Ok(history)
+    }
+
+    /// Get a page of the outputs that were created or spent in a milestone.
+    /// GET /api/explorer/v2/ledger/updates/by-milestone/{milestoneId}
+    pub async fn get_ledger_updates_by_milestone(
+        &self,
+        milestone_id: &MilestoneId,
+        page_size: Option<usize>,
+        cursor: Option<&str>,
+    ) -> Result<LedgerUpdatesByMilestoneResponse> {
+        let route = format!("api/explorer/v2/ledger/updates/by-milestone/{milestone_id}");
+        let query = [
+            page_size.map(|page_size| format!("pageSize={page_size}")),
+            cursor.map(|cursor| format!("cursor={cursor}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("&");
+
+        self.get_permanode_request(&route, (!query.is_empty()).then_some(query.as_str()))
+            .await
+    }
+
+    /// Get the ledger updates of each milestone of a range of milestone indexes, with all pages of a milestone merged
+    /// into one response.
+    pub async fn get_ledger_updates_by_milestone_range(
+        &self,
+        milestone_indexes: RangeInclusive<u32>,
+    ) -> Result<Vec<LedgerUpdatesByMilestoneResponse>> {
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        let mut ledger_updates = Vec::new();
+
+        for index in milestone_indexes {
+            // Regular nodes may have pruned the milestone, so it's also requested from the permanode.
+            let milestone = self
+                .get_permanode_request::<MilestonePayloadDto>(&format!("api/core/v2/milestones/by-index/{index}"), None)
+                .await?;
+            let milestone_id = MilestonePayload::try_from_dto_with_params(milestone, &protocol_parameters)?.id();
+
+            let mut response = self.get_ledger_updates_by_milestone(&milestone_id, None, None).await?;
+
+            while let Some(cursor) = response.cursor.take() {
+                let page = self
+                    .get_ledger_updates_by_milestone(&milestone_id, None, Some(&cursor))
+                    .await?;
+                response.items.extend(page.items);
+                response.cursor = page.cursor;
+            }
+
+            ledger_updates.push(response);
+        }
+
+        Ok(ledger_updates)
+    }
+
+    /// Get a page of the blocks that reference a block as parent.
+    /// GET /api/explorer/v2/blocks/{blockId}/children
+    pub async fn get_block_children(
+        &self,
+        block_id: &BlockId,
+        page_size: Option<usize>,
+        page: Option<usize>,
+    ) -> Result<BlockChildrenResponse> {
+        let route = format!("api/explorer/v2/blocks/{block_id}/children");
+        let query = [
+            page_size.map(|page_size| format!("pageSize={page_size}")),
+            page.map(|page| format!("page={page}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("&");
+
+        self.get_permanode_request(&route, (!query.is_empty()).then_some(query.as_str()))
+            .await
+    }
+
+    /// Get all children of a block, requesting pages until one isn't full.
+    pub async fn get_all_block_children(&self, block_id: &BlockId) -> Result<Vec<BlockId>> {
+        let mut response = self.get_block_children(block_id, None, None).await?;
+        let mut children = core::mem::take(&mut response.children);
+        let mut page = 0;
+
+        while response.count > 0 && response.count >= response.max_results {
+            page += 1;
+            response = self.get_block_children(block_id, None, Some(page)).await?;
+            children.append(&mut response.children);
+        }
+
+        Ok(children)
+    }
+}
@@ -5,17 +5,69 @@
 
 pub mod routes;
 
+use futures::StreamExt;
+
 use crate::{
-    client::{Client, Result},
-    types::block::output::{OutputId, OutputMetadata, OutputWithMetadata},
+    client::{Client, Error, Result},
+    types::{
+        api::core::response::BlockMetadataResponse,
+        block::{
+            output::{OutputId, OutputMetadata, OutputWithMetadata},
+            payload::transaction::TransactionId,
+            Block,
+        },
+    },
 };
 
 impl Client {
+    /// Request the block that was included in the ledger for a given transaction ID together with its metadata.
+    /// Both requests are sent at the same time instead of one after the other.
+    pub async fn get_included_block_with_metadata(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> Result<(Block, BlockMetadataResponse)> {
+        futures::future::try_join(
+            self.get_included_block(transaction_id),
+            self.get_included_block_metadata(transaction_id),
+        )
+        .await
+    }
+
     /// Request outputs by their output ID in parallel
     pub async fn get_outputs(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
         futures::future::try_join_all(output_ids.iter().map(|id| self.get_output(id))).await
     }
 
+    /// Request outputs by their output ID, with at most `max_in_flight` requests at the same time.
+    /// All outputs are requested even if some requests fail, the failures are returned together with their output IDs.
+    pub async fn get_outputs_with_concurrency(
+        &self,
+        output_ids: &[OutputId],
+        max_in_flight: usize,
+    ) -> Result<Vec<OutputWithMetadata>> {
+        let mut outputs = Vec::with_capacity(output_ids.len());
+        let mut failed = Vec::new();
+
+        let requests = output_ids
+            .iter()
+            .map(|output_id| async move { (*output_id, self.get_output(output_id).await) })
+            .collect::<Vec<_>>();
+        let mut responses = futures::stream::iter(requests).buffered(max_in_flight.max(1));
+
+        while let Some((output_id, response)) = responses.next().await {
+            match response {
+                Ok(output) => outputs.push(output),
+                Err(e) => failed.push((output_id, e)),
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(outputs)
+        } else {
+            Err(Error::OutputRequestsFailed(failed))
+        }
+    }
+
     /// Request outputs by their output ID in parallel, ignoring failed requests
     /// Useful to get data about spent outputs, that might not be pruned yet
     pub async fn get_outputs_ignore_errors(&self, output_ids: &[OutputId]) -> Result<Vec<OutputWithMetadata>> {
@@ -4,13 +4,18 @@
 //! Node core API routes.
 
 use packable::PackableExt;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use url::Url;
 
 use crate::{
     client::{
+        api::PowEvent,
+        cache::{CacheKey, CachedResponse},
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
-        node_manager::node::{Node, NodeAuth},
+        node_manager::{
+            http_client::HttpClient,
+            node::{Node, NodeAuth},
+        },
         Client, ClientInner, Error, Result,
     },
     types::{
@@ -53,7 +58,7 @@ impl ClientInner {
 
         let mut url = Url::parse(url)?;
         url.set_path(path);
-        let status = crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
+        let status = default_http_client()
             .get(
                 Node {
                     url,
@@ -103,7 +108,7 @@ impl ClientInner {
     /// POST JSON to /api/core/v2/blocks
     pub async fn post_block(&self, block: &Block) -> Result<BlockId> {
         let path = "api/core/v2/blocks";
-        let local_pow = self.get_local_pow().await;
+        let local_pow = self.has_local_pow(block).await?;
         let timeout = if local_pow {
             self.get_timeout().await
         } else {
@@ -125,22 +130,9 @@ impl ClientInner {
                     return Err(Error::Node(crate::client::node_api::error::Error::UnavailablePow));
                 }
                 log::debug!("[post_block] falling back to local PoW");
+                self.emit_pow_event(PowEvent::FallbackToLocalPow);
 
-                self.network_info.write().await.local_pow = true;
-
-                let block_res = self.finish_block_builder(None, block.payload().cloned()).await;
-                let block_with_local_pow = match block_res {
-                    Ok(block) => {
-                        // reset local PoW state
-                        self.network_info.write().await.local_pow = false;
-                        block
-                    }
-                    Err(e) => {
-                        // reset local PoW state
-                        self.network_info.write().await.local_pow = false;
-                        return Err(e);
-                    }
-                };
+                let block_with_local_pow = self.finish_pow(None, block.payload().cloned()).await?;
                 let block_dto = BlockDto::from(&block_with_local_pow);
 
                 self.node_manager
@@ -159,7 +151,7 @@ impl ClientInner {
     /// POST /api/core/v2/blocks
     pub async fn post_block_raw(&self, block: &Block) -> Result<BlockId> {
         let path = "api/core/v2/blocks";
-        let local_pow = self.get_local_pow().await;
+        let local_pow = self.has_local_pow(block).await?;
         let timeout = if local_pow {
             self.get_timeout().await
         } else {
@@ -180,21 +172,10 @@ impl ClientInner {
                     return Err(Error::Node(crate::client::node_api::error::Error::UnavailablePow));
                 }
 
-                self.network_info.write().await.local_pow = true;
-
-                let block_res = self.finish_block_builder(None, block.payload().cloned()).await;
-                let block_with_local_pow = match block_res {
-                    Ok(block) => {
-                        // reset local PoW state
-                        self.network_info.write().await.local_pow = false;
-                        block
-                    }
-                    Err(e) => {
-                        // reset local PoW state
-                        self.network_info.write().await.local_pow = false;
-                        return Err(e);
-                    }
-                };
+                log::debug!("[post_block_raw] falling back to local PoW");
+                self.emit_pow_event(PowEvent::FallbackToLocalPow);
+
+                let block_with_local_pow = self.finish_pow(None, block.payload().cloned()).await?;
                 self.node_manager
                     .read()
                     .await
@@ -210,14 +191,20 @@ impl ClientInner {
     /// Finds a block by its BlockId. This method returns the given block object.
     /// GET /api/core/v2/blocks/{BlockId}
     pub async fn get_block(&self, block_id: &BlockId) -> Result<Block> {
+        if let Some(block) = self.with_response_cache(|cache| cache.get_block(block_id)).flatten() {
+            return Ok(block);
+        }
+
         let path = &format!("api/core/v2/blocks/{block_id}");
 
         let dto = self.get_request::<BlockDto>(path, None, false, true).await?;
+        let block = Block::try_from_dto_with_params(dto, self.get_protocol_parameters().await?)?;
 
-        Ok(Block::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        self.with_response_cache(|cache| {
+            cache.insert(CacheKey::Block(*block_id), CachedResponse::Block(block.clone()))
+        });
+
+        Ok(block)
     }
 
     /// Finds a block by its BlockId. This method returns the given block raw data.
@@ -241,14 +228,30 @@ impl ClientInner {
     /// Finds an output, as JSON, by its OutputId (TransactionId + output_index).
     /// GET /api/core/v2/outputs/{outputId}
     pub async fn get_output(&self, output_id: &OutputId) -> Result<OutputWithMetadata> {
+        if let Some(output) = self.with_response_cache(|cache| cache.get_output(output_id)).flatten() {
+            return Ok(output);
+        }
+
         let path = &format!("api/core/v2/outputs/{output_id}");
 
         let response: OutputWithMetadataResponse = self.get_request(path, None, false, true).await?;
 
         let token_supply = self.get_token_supply().await?;
         let output = Output::try_from_dto_with_params(response.output, token_supply)?;
+        let output = OutputWithMetadata::new(output, response.metadata);
+
+        // Unspent outputs still change once they are spent.
+        if output.metadata().is_spent() {
+            self.with_response_cache(|cache| {
+                cache.insert(
+                    CacheKey::OutputMetadata(*output_id),
+                    CachedResponse::OutputMetadata(*output.metadata()),
+                );
+                cache.insert(CacheKey::Output(*output_id), CachedResponse::Output(output.clone()));
+            });
+        }
 
-        Ok(OutputWithMetadata::new(output, response.metadata))
+        Ok(output)
     }
 
     /// Finds an output, as raw bytes, by its OutputId (TransactionId + output_index).
@@ -262,9 +265,28 @@ impl ClientInner {
     /// Get the metadata for a given `OutputId` (TransactionId + output_index).
     /// GET /api/core/v2/outputs/{outputId}/metadata
     pub async fn get_output_metadata(&self, output_id: &OutputId) -> Result<OutputMetadata> {
+        if let Some(metadata) = self
+            .with_response_cache(|cache| cache.get_output_metadata(output_id))
+            .flatten()
+        {
+            return Ok(metadata);
+        }
+
         let path = &format!("api/core/v2/outputs/{output_id}/metadata");
 
-        self.get_request::<OutputMetadata>(path, None, false, true).await
+        let metadata = self.get_request::<OutputMetadata>(path, None, false, true).await?;
+
+        // Unspent outputs still change once they are spent.
+        if metadata.is_spent() {
+            self.with_response_cache(|cache| {
+                cache.insert(
+                    CacheKey::OutputMetadata(*output_id),
+                    CachedResponse::OutputMetadata(metadata),
+                )
+            });
+        }
+
+        Ok(metadata)
     }
 
     /// Gets all stored receipts.
@@ -330,14 +352,26 @@ impl ClientInner {
     /// Gets the milestone by the given milestone id.
     /// GET /api/core/v2/milestones/{milestoneId}
     pub async fn get_milestone_by_id(&self, milestone_id: &MilestoneId) -> Result<MilestonePayload> {
+        if let Some(milestone) = self
+            .with_response_cache(|cache| cache.get_milestone(milestone_id))
+            .flatten()
+        {
+            return Ok(milestone);
+        }
+
         let path = &format!("api/core/v2/milestones/{milestone_id}");
 
         let dto = self.get_request::<MilestonePayloadDto>(path, None, false, true).await?;
+        let milestone = MilestonePayload::try_from_dto_with_params(dto, self.get_protocol_parameters().await?)?;
 
-        Ok(MilestonePayload::try_from_dto_with_params(
-            dto,
-            self.get_protocol_parameters().await?,
-        )?)
+        self.with_response_cache(|cache| {
+            cache.insert(
+                CacheKey::Milestone(*milestone_id),
+                CachedResponse::Milestone(milestone.clone()),
+            )
+        });
+
+        Ok(milestone)
     }
 
     /// Gets the milestone by the given milestone id.
@@ -420,6 +454,21 @@ impl ClientInner {
 impl Client {
     /// GET /api/core/v2/info endpoint
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
+        Self::get_node_request(&default_http_client(), url, auth, INFO_PATH).await
+    }
+
+    /// GET /api/routes endpoint
+    pub async fn get_node_routes(url: &str, auth: Option<NodeAuth>) -> Result<RoutesResponse> {
+        Self::get_node_request(&default_http_client(), url, auth, "api/routes").await
+    }
+
+    /// Sends a GET request to a node that isn't necessarily part of the node pool.
+    pub(crate) async fn get_node_request<T: DeserializeOwned>(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+        path: &str,
+    ) -> Result<T> {
         let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
         if let Some(auth) = &auth {
             if let Some((name, password)) = &auth.basic_auth_name_pwd {
@@ -429,27 +478,34 @@ impl Client {
                     .map_err(|_| crate::client::Error::UrlAuth("password"))?;
             }
         }
-        let path = "api/core/v2/info";
         if url.path().ends_with('/') {
             url.set_path(&format!("{}{}", url.path(), path));
         } else {
             url.set_path(&format!("{}/{}", url.path(), path));
         }
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+        let resp: T = http_client
+            .get(
+                Node {
+                    url,
+                    auth,
+                    disabled: false,
+                },
+                DEFAULT_API_TIMEOUT,
+            )
+            .await?
+            .into_json()
+            .await?;
 
         Ok(resp)
     }
 }
+
+fn default_http_client() -> HttpClient {
+    HttpClient::new(
+        DEFAULT_USER_AGENT.to_string(),
+        Default::default(),
+        Default::default(),
+        Default::default(),
+    )
+}
@@ -23,6 +23,14 @@ pub enum Error {
         /// The url of the API.
         url: String,
     },
+    /// The node responded with a different content type than the requested binary encoding
+    #[error("Unexpected content type {content_type}, expected application/vnd.iota.serializer-v1, URL: {url}")]
+    UnexpectedContentType {
+        /// The content type of the response.
+        content_type: String,
+        /// The url of the API.
+        url: String,
+    },
     /// None of our nodes have remote Pow enabled
     #[error("No node available for remote Pow")]
     UnavailablePow,
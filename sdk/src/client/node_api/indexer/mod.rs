@@ -6,10 +6,12 @@
 pub mod query_parameters;
 pub mod routes;
 
+use futures::{Stream, StreamExt, TryStreamExt};
+
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
     client::{ClientInner, Result},
-    types::api::plugins::indexer::OutputIdsResponse,
+    types::{api::plugins::indexer::OutputIdsResponse, block::output::OutputId},
 };
 
 impl ClientInner {
@@ -56,4 +58,56 @@ impl ClientInner {
 
         Ok(merged_output_ids_response)
     }
+
+    /// Get a stream of all output ids for a provided URL route and query parameters.
+    /// The pages are lazily requested, following the cursor returned by the indexer until there are no more results.
+    /// If a `QueryParameter::Cursor(_)` is provided, the stream starts at this cursor.
+    pub(crate) fn get_output_ids_stream<'a>(
+        &'a self,
+        route: &'a str,
+        query_parameters: Result<QueryParameters>,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> impl Stream<Item = Result<OutputId>> + Send + 'a {
+        futures::stream::once(async move { query_parameters })
+            .map_ok(move |query_parameters| {
+                futures::stream::try_unfold(Some(query_parameters), move |query_parameters| {
+                    self.get_output_ids_page(route, query_parameters, need_quorum, prefer_permanode)
+                })
+                .map_ok(|output_ids| futures::stream::iter(output_ids.into_iter().map(Ok)))
+                .try_flatten()
+            })
+            .try_flatten()
+            .boxed()
+    }
+
+    // Requests a single page of output ids and returns it together with the query parameters for the next page, if
+    // there is one.
+    async fn get_output_ids_page(
+        &self,
+        route: &str,
+        query_parameters: Option<QueryParameters>,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<Option<(Vec<OutputId>, Option<QueryParameters>)>> {
+        let Some(mut query_parameters) = query_parameters else {
+            return Ok(None);
+        };
+
+        let output_ids_response = self
+            .get_request::<OutputIdsResponse>(
+                route,
+                query_parameters.to_query_string().as_deref(),
+                need_quorum,
+                prefer_permanode,
+            )
+            .await?;
+
+        let next_query_parameters = output_ids_response.cursor.map(|cursor| {
+            query_parameters.replace(QueryParameter::Cursor(cursor));
+            query_parameters
+        });
+
+        Ok(Some((output_ids_response.items, next_query_parameters)))
+    }
 }
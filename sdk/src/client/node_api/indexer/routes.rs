@@ -3,6 +3,8 @@
 
 //! IOTA node indexer routes
 
+use futures::Stream;
+
 use crate::{
     client::{
         node_api::indexer::{
@@ -41,6 +43,21 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of the ids of basic, alias, nft and foundry outputs filtered by the given parameters, transparently
+    /// following the indexer cursor until all pages have been requested.
+    /// Accepts the same query parameters as [`Self::output_ids()`].
+    /// api/indexer/v1/outputs
+    pub fn output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> impl Stream<Item = Result<OutputId>> + Send + '_ {
+        let route = "api/indexer/v1/outputs";
+
+        let query_parameters = verify_query_parameters_outputs(query_parameters.into());
+
+        self.get_output_ids_stream(route, query_parameters, true, false)
+    }
+
     /// Get basic outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "address", "hasStorageDepositReturn", "storageDepositReturnAddress",
@@ -59,6 +76,21 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of the ids of basic outputs filtered by the given parameters, transparently following the indexer
+    /// cursor until all pages have been requested.
+    /// Accepts the same query parameters as [`Self::basic_output_ids()`].
+    /// api/indexer/v1/outputs/basic
+    pub fn basic_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> impl Stream<Item = Result<OutputId>> + Send + '_ {
+        let route = "api/indexer/v1/outputs/basic";
+
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into());
+
+        self.get_output_ids_stream(route, query_parameters, true, false)
+    }
+
     /// Get alias outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "stateController", "governor", "issuer", "sender", "createdBefore", "createdAfter"
@@ -75,6 +107,21 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of the ids of alias outputs filtered by the given parameters, transparently following the indexer
+    /// cursor until all pages have been requested.
+    /// Accepts the same query parameters as [`Self::alias_output_ids()`].
+    /// api/indexer/v1/outputs/alias
+    pub fn alias_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> impl Stream<Item = Result<OutputId>> + Send + '_ {
+        let route = "api/indexer/v1/outputs/alias";
+
+        let query_parameters = verify_query_parameters_alias_outputs(query_parameters.into());
+
+        self.get_output_ids_stream(route, query_parameters, true, false)
+    }
+
     /// Get alias output by its aliasID.
     /// api/indexer/v1/outputs/alias/:{AliasId}
     pub async fn alias_output_id(&self, alias_id: AliasId) -> Result<OutputId> {
@@ -103,6 +150,21 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of the ids of foundry outputs filtered by the given parameters, transparently following the indexer
+    /// cursor until all pages have been requested.
+    /// Accepts the same query parameters as [`Self::foundry_output_ids()`].
+    /// api/indexer/v1/outputs/foundry
+    pub fn foundry_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> impl Stream<Item = Result<OutputId>> + Send + '_ {
+        let route = "api/indexer/v1/outputs/foundry";
+
+        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters.into());
+
+        self.get_output_ids_stream(route, query_parameters, true, false)
+    }
+
     /// Get foundry output by its foundryID.
     /// api/indexer/v1/outputs/foundry/:{FoundryID}
     pub async fn foundry_output_id(&self, foundry_id: FoundryId) -> Result<OutputId> {
@@ -132,6 +194,21 @@ impl ClientInner {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of the ids of NFT outputs filtered by the given parameters, transparently following the indexer
+    /// cursor until all pages have been requested.
+    /// Accepts the same query parameters as [`Self::nft_output_ids()`].
+    /// api/indexer/v1/outputs/nft
+    pub fn nft_output_ids_stream(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> impl Stream<Item = Result<OutputId>> + Send + '_ {
+        let route = "api/indexer/v1/outputs/nft";
+
+        let query_parameters = verify_query_parameters_nft_outputs(query_parameters.into());
+
+        self.get_output_ids_stream(route, query_parameters, true, false)
+    }
+
     /// Get NFT output by its nftID.
     /// api/indexer/v1/outputs/nft/:{NftId}
     pub async fn nft_output_id(&self, nft_id: NftId) -> Result<OutputId> {
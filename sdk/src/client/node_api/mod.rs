@@ -3,6 +3,7 @@
 
 //! node API modules
 
+pub mod chronicle;
 pub mod core;
 pub mod error;
 pub mod indexer;
@@ -13,3 +14,5 @@ pub mod mqtt;
 #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
 pub mod participation;
 pub mod plugin;
+pub mod poi;
+pub mod provenance;
@@ -13,6 +13,18 @@ pub enum Error {
     /// Crypto error.
     #[error("crypto error {0}")]
     Crypto(#[from] crypto::Error),
+    /// A client certificate was set without a CA certificate.
+    #[error("a client certificate requires a CA certificate")]
+    MissingCaCertificate,
+    /// The client is in offline mode.
+    #[error("the client is in offline mode, no MQTT connection is created")]
+    OfflineMode,
+    /// Invalid TLS configuration.
+    #[error("invalid TLS configuration: {0}")]
+    TlsConfig(#[from] crate::client::node_manager::tls::TlsConfigError),
+    /// The proxy is not supported for MQTT connections.
+    #[error("unsupported MQTT proxy scheme `{0}`, only HTTP(S) proxies are supported")]
+    UnsupportedProxy(String),
     /// Invalid topic.
     #[error("invalid topic {0}")]
     InvalidTopic(String),
@@ -11,12 +11,22 @@ use std::{sync::Arc, time::Instant};
 use crypto::utils;
 use log::warn;
 use packable::PackableExt;
-use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
+use rumqttc::{
+    AsyncClient, Event, EventLoop, Incoming, Key, MqttOptions, NetworkOptions, SubscribeFilter, TlsConfiguration,
+    Transport,
+};
+#[cfg(feature = "proxy")]
+use rumqttc::{Proxy, ProxyAuth, ProxyType};
 use tokio::sync::watch::Receiver as WatchReceiver;
 
 pub use self::{error::Error, types::*};
+#[cfg(feature = "proxy")]
+use crate::client::node_manager::proxy::ProxyConfig;
 use crate::{
-    client::{Client, ClientInner},
+    client::{
+        node_manager::tls::{parse_certificates, TlsConfig, TlsConfigError},
+        Client, ClientInner,
+    },
     types::block::{
         payload::{milestone::ReceiptMilestoneOption, Payload},
         Block,
@@ -60,6 +70,9 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
 
     if !exists {
         let node_manager = client.node_manager.read().await;
+        if node_manager.offline {
+            return Err(Error::OfflineMode);
+        }
         let nodes = if !node_manager.ignore_node_health {
             #[cfg(not(target_family = "wasm"))]
             {
@@ -77,15 +90,22 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
         } else {
             node_manager.nodes.clone()
         };
+        let client_tls_config = node_manager.tls_config.clone();
+        #[cfg(feature = "proxy")]
+        let proxy = node_manager
+            .proxy
+            .as_ref()
+            .map(|proxy| mqtt_proxy(proxy, client_tls_config.as_ref()))
+            .transpose()?;
+        let broker_options = client.mqtt.broker_options.read().await.clone();
         for node in &nodes {
             let host = node.url.host_str().expect("can't get host from URL");
             let mut entropy = [0u8; 8];
             utils::rand::fill(&mut entropy)?;
             let id = format!("iotasdk{}", prefix_hex::encode(entropy));
-            let broker_options = client.mqtt.broker_options.read().await;
             let port = broker_options.port;
             let secure = node.url.scheme() == "https";
-            let mqtt_options = if broker_options.use_ws {
+            let mut mqtt_options = if broker_options.use_ws {
                 let path = if node.url.path().ends_with('/') {
                     &node.url.path()[0..node.url.path().len() - 1]
                 } else {
@@ -98,7 +118,10 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                 );
                 let mut mqtt_options = MqttOptions::new(id, uri, port);
                 if secure {
-                    mqtt_options.set_transport(Transport::wss_with_default_config());
+                    mqtt_options.set_transport(Transport::wss_with_config(tls_config(
+                        &broker_options,
+                        client_tls_config.as_ref(),
+                    )?));
                 } else {
                     mqtt_options.set_transport(Transport::ws());
                 }
@@ -107,10 +130,20 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                 let uri = host.to_string();
                 let mut mqtt_options = MqttOptions::new(id, uri, port);
                 if secure {
-                    mqtt_options.set_transport(Transport::tls_with_default_config());
+                    mqtt_options.set_transport(Transport::tls_with_config(tls_config(
+                        &broker_options,
+                        client_tls_config.as_ref(),
+                    )?));
                 }
                 mqtt_options
             };
+            #[cfg(feature = "proxy")]
+            if let Some(proxy) = &proxy {
+                mqtt_options.set_proxy(proxy.clone());
+            }
+            if let Some(credentials) = &broker_options.credentials {
+                mqtt_options.set_credentials(&credentials.username, &credentials.password);
+            }
             let (_, mut connection) = AsyncClient::new(mqtt_options.clone(), 10);
             let mut network_options = NetworkOptions::new();
             network_options.set_connection_timeout(broker_options.timeout.as_secs());
@@ -131,12 +164,95 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                 let (mqtt_client, connection) = AsyncClient::new(mqtt_options, 10);
                 client.mqtt.client.write().await.replace(mqtt_client);
                 poll_mqtt(client, connection);
+                break;
             }
         }
     }
     Ok(())
 }
 
+/// Returns the TLS configuration for secure connections, the platform certificates are trusted unless a CA certificate
+/// or the [`TlsConfig`] of the client is set.
+fn tls_config(
+    broker_options: &BrokerOptions,
+    client_tls_config: Option<&TlsConfig>,
+) -> Result<TlsConfiguration, Error> {
+    if let Some(client_tls_config) = client_tls_config {
+        let mut client_tls_config = client_tls_config.clone();
+        if let Some(ca_certificate) = &broker_options.ca_certificate {
+            client_tls_config = client_tls_config.with_ca_certificate(ca_certificate);
+        }
+        let builder = client_tls_config.client_config_builder()?;
+
+        let config = match &broker_options.client_certificate {
+            Some(client_certificate) => {
+                let certificates = parse_certificates(&client_certificate.certificate)?;
+                let mut key = client_certificate.key.as_bytes();
+                let key = match client_certificate.key_format {
+                    MqttKeyFormat::Pkcs8 => rustls_pemfile::pkcs8_private_keys(&mut key),
+                    MqttKeyFormat::Rsa => rustls_pemfile::rsa_private_keys(&mut key),
+                }
+                .map_err(TlsConfigError::from)?
+                .into_iter()
+                .next()
+                .ok_or(TlsConfigError::MissingPrivateKey)?;
+
+                builder
+                    .with_client_auth_cert(certificates, rustls::PrivateKey(key))
+                    .map_err(TlsConfigError::from)?
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        return Ok(TlsConfiguration::Rustls(Arc::new(config)));
+    }
+
+    let Some(ca_certificate) = &broker_options.ca_certificate else {
+        return if broker_options.client_certificate.is_some() {
+            Err(Error::MissingCaCertificate)
+        } else {
+            Ok(TlsConfiguration::default())
+        };
+    };
+
+    let client_auth = broker_options.client_certificate.as_ref().map(|client_certificate| {
+        let key = client_certificate.key.as_bytes().to_vec();
+        let key = match client_certificate.key_format {
+            MqttKeyFormat::Pkcs8 => Key::ECC(key),
+            MqttKeyFormat::Rsa => Key::RSA(key),
+        };
+        (client_certificate.certificate.as_bytes().to_vec(), key)
+    });
+
+    Ok(TlsConfiguration::Simple {
+        ca: ca_certificate.as_bytes().to_vec(),
+        alpn: None,
+        client_auth,
+    })
+}
+
+/// Returns the MQTT proxy of the client, only HTTP(S) proxies are supported.
+#[cfg(feature = "proxy")]
+fn mqtt_proxy(proxy: &ProxyConfig, client_tls_config: Option<&TlsConfig>) -> Result<Proxy, Error> {
+    let url = proxy.url();
+    let ty = match url.scheme() {
+        "http" => ProxyType::Http,
+        "https" => ProxyType::Https(tls_config(&BrokerOptions::new(), client_tls_config)?),
+        scheme => return Err(Error::UnsupportedProxy(scheme.to_string())),
+    };
+    let auth = match proxy.credentials() {
+        Some((username, password)) => ProxyAuth::Basic { username, password },
+        None => ProxyAuth::None,
+    };
+
+    Ok(Proxy {
+        ty,
+        auth,
+        addr: url.host_str().expect("the proxy url has a host").to_string(),
+        port: url.port_or_known_default().unwrap_or(80),
+    })
+}
+
 fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
     let client = client.clone();
     std::thread::spawn(move || {
@@ -147,10 +263,10 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
         runtime.block_on(async move {
             // rumqttc performs automatic reconnection since we keep running the event loop
             // but the subscriptions are lost on reconnection, so we need to resubscribe
-            // the `is_subscribed` flag is set to false on event error, so the ConnAck event
-            // can perform the re-subscriptions and reset `is_subscribed` to true.
+            // the `reconnecting` flag is set on event error, so the ConnAck event
+            // can perform the re-subscriptions and reset `reconnecting`.
             // we need the flag since the first ConnAck must be ignored.
-            let mut is_subscribed = true;
+            let mut reconnecting = false;
             let mut error_instant = Instant::now();
             let mut connection_failure_count = 0;
 
@@ -158,33 +274,43 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                 let event = event_loop.poll().await;
 
                 match event {
-                    Ok(Event::Incoming(Incoming::ConnAck(_))) => {
-                        let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
-                        if !is_subscribed {
-                            is_subscribed = true;
+                    Ok(Event::Incoming(Incoming::ConnAck(conn_ack))) => {
+                        let mut mqtt_event = MqttEvent::Connected;
+                        if reconnecting {
+                            reconnecting = false;
                             // resubscribe topics
-                            let topics = client
-                                .inner
-                                .mqtt
-                                .topic_handlers
-                                .read()
-                                .await
-                                .keys()
-                                .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce))
-                                .collect::<Vec<SubscribeFilter>>();
-                            if !topics.is_empty() {
-                                let _ = client
-                                    .inner
+                            let topics = {
+                                let topic_qos = client.mqtt.topic_qos.read().await;
+                                client
                                     .mqtt
-                                    .client
-                                    .write()
+                                    .topic_handlers
+                                    .read()
                                     .await
-                                    .as_mut()
-                                    .unwrap()
-                                    .subscribe_many(topics)
-                                    .await;
+                                    .keys()
+                                    .map(|t| {
+                                        let qos = topic_qos.get(t).copied().unwrap_or_default();
+                                        SubscribeFilter::new(t.as_str().to_owned(), qos.into())
+                                    })
+                                    .collect::<Vec<SubscribeFilter>>()
+                            };
+                            if !topics.is_empty() {
+                                // with a present session the broker kept the subscriptions and queued the missed events
+                                if conn_ack.session_present {
+                                    mqtt_event = MqttEvent::Resubscribed { possibly_missed: false };
+                                } else if let Some(mqtt_client) = &*client.mqtt.client.read().await {
+                                    // don't wait for space in the request queue, it's only drained by this loop
+                                    match mqtt_client.try_subscribe_many(topics) {
+                                        Ok(()) => mqtt_event = MqttEvent::Resubscribed { possibly_missed: true },
+                                        Err(e) => {
+                                            warn!("Resubscribing topics failed: {e}");
+                                            // try again with the next ConnAck
+                                            reconnecting = true;
+                                        }
+                                    }
+                                }
                             }
                         }
+                        let _ = client.mqtt.sender.read().await.send(mqtt_event);
                     }
                     Ok(Event::Incoming(Incoming::Publish(p))) => {
                         let client = client.clone();
@@ -276,7 +402,7 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                             break;
                         }
                         error_instant = Instant::now();
-                        is_subscribed = false;
+                        reconnecting = true;
                     }
                     _ => {}
                 }
@@ -317,6 +443,7 @@ impl<'a> MqttManager<'a> {
         if let Some(client) = &*self.client.mqtt.client.write().await {
             client.disconnect().await?;
             self.client.mqtt.topic_handlers.write().await.clear();
+            self.client.mqtt.topic_qos.write().await.clear();
         }
 
         *self.client.mqtt.client.write().await = None;
@@ -330,6 +457,7 @@ impl<'a> MqttManager<'a> {
 pub struct MqttTopicManager<'a> {
     client: &'a Client,
     topics: Vec<Topic>,
+    qos: Option<MqttQos>,
 }
 
 impl<'a> MqttTopicManager<'a> {
@@ -338,6 +466,7 @@ impl<'a> MqttTopicManager<'a> {
         Self {
             client,
             topics: Vec::new(),
+            qos: None,
         }
     }
 
@@ -353,6 +482,12 @@ impl<'a> MqttTopicManager<'a> {
         self
     }
 
+    /// Set the QoS level of the subscription, instead of the default one of the broker options.
+    pub fn with_qos(mut self, qos: MqttQos) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
     /// Subscribe to the given topics with the callback.
     pub async fn subscribe<C: Fn(&crate::client::node_api::mqtt::TopicEvent) + Send + Sync + 'static>(
         self,
@@ -364,6 +499,10 @@ impl<'a> MqttTopicManager<'a> {
                     dyn Fn(&crate::client::node_api::mqtt::TopicEvent) + Send + Sync + 'static,
                 >);
         set_mqtt_client(self.client).await?;
+        let qos = match self.qos {
+            Some(qos) => qos,
+            None => self.client.mqtt.broker_options.read().await.qos,
+        };
         self.client
             .inner
             .mqtt
@@ -375,12 +514,15 @@ impl<'a> MqttTopicManager<'a> {
             .subscribe_many(
                 self.topics
                     .iter()
-                    .map(|t| SubscribeFilter::new(t.as_str().to_owned(), QoS::AtLeastOnce)),
+                    .map(|t| SubscribeFilter::new(t.as_str().to_owned(), qos.into())),
             )
             .await?;
         {
             let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
+            let mut mqtt_topic_qos = self.client.mqtt.topic_qos.write().await;
             for topic in self.topics {
+                // subscribing again replaces the QoS level of the topic
+                mqtt_topic_qos.insert(topic.clone(), qos);
                 mqtt_topic_handlers.entry(topic).or_default().push(cb.clone());
             }
         }
@@ -407,8 +549,10 @@ impl<'a> MqttTopicManager<'a> {
 
         let empty_topic_handlers = {
             let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
+            let mut mqtt_topic_qos = self.client.mqtt.topic_qos.write().await;
             for topic in topics {
                 mqtt_topic_handlers.remove(&topic);
+                mqtt_topic_qos.remove(&topic);
             }
             mqtt_topic_handlers.is_empty()
         };
@@ -19,6 +19,8 @@ type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
 
 pub(crate) type TopicHandlerMap = HashMap<Topic, Vec<Arc<TopicHandler>>>;
 
+pub(crate) type TopicQosMap = HashMap<Topic, MqttQos>;
+
 /// An event from a MQTT topic.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TopicEvent {
@@ -49,10 +51,16 @@ pub enum MqttEvent {
     Connected,
     /// Client was disconnected.
     Disconnected,
+    /// Client was reconnected and all topic subscriptions were restored.
+    Resubscribed {
+        /// Whether events could have been published while the connection was lost, e.g. a wallet should sync to catch
+        /// up on missed confirmations.
+        possibly_missed: bool,
+    },
 }
 
 /// The MQTT broker options.
-#[derive(Copy, Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[must_use]
 pub struct BrokerOptions {
@@ -66,6 +74,14 @@ pub struct BrokerOptions {
     pub(crate) port: u16,
     #[serde(default = "default_max_reconnection_attempts")]
     pub(crate) max_reconnection_attempts: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) credentials: Option<MqttCredentials>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ca_certificate: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) client_certificate: Option<MqttClientCertificate>,
+    #[serde(default)]
+    pub(crate) qos: MqttQos,
 }
 
 fn default_broker_automatic_disconnect() -> bool {
@@ -96,6 +112,10 @@ impl Default for BrokerOptions {
             use_ws: default_broker_use_ws(),
             port: default_broker_port(),
             max_reconnection_attempts: default_max_reconnection_attempts(),
+            credentials: None,
+            ca_certificate: None,
+            client_certificate: None,
+            qos: MqttQos::default(),
         }
     }
 }
@@ -135,6 +155,125 @@ impl BrokerOptions {
         self.max_reconnection_attempts = max_reconnection_attempts;
         self
     }
+
+    /// Sets the username and password to authenticate with the MQTT broker.
+    pub fn credentials(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.credentials = Some(MqttCredentials {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Sets the PEM encoded CA certificates that are trusted instead of the platform certificates, for brokers with a
+    /// private CA.
+    pub fn ca_certificate(mut self, ca_certificate: impl Into<String>) -> Self {
+        self.ca_certificate = Some(ca_certificate.into());
+        self
+    }
+
+    /// Sets the certificate the client authenticates with to the MQTT broker. Requires a CA certificate to be set.
+    pub fn client_certificate(mut self, client_certificate: MqttClientCertificate) -> Self {
+        self.client_certificate = Some(client_certificate);
+        self
+    }
+
+    /// Sets the default QoS level of subscriptions.
+    pub fn qos(mut self, qos: MqttQos) -> Self {
+        self.qos = qos;
+        self
+    }
+}
+
+/// The username and password to authenticate with a MQTT broker.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttCredentials {
+    /// The username.
+    pub username: String,
+    /// The password.
+    pub password: String,
+}
+
+impl std::fmt::Debug for MqttCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MqttCredentials")
+            .field("username", &self.username)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A client certificate to authenticate with a MQTT broker over TLS.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MqttClientCertificate {
+    /// The PEM encoded certificate chain.
+    pub certificate: String,
+    /// The PEM encoded private key.
+    pub key: String,
+    /// The format of the private key.
+    #[serde(default)]
+    pub key_format: MqttKeyFormat,
+}
+
+impl MqttClientCertificate {
+    /// Creates a new client certificate with a PKCS#8 private key.
+    pub fn new(certificate: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            certificate: certificate.into(),
+            key: key.into(),
+            key_format: MqttKeyFormat::default(),
+        }
+    }
+
+    /// Sets the format of the private key.
+    pub fn with_key_format(mut self, key_format: MqttKeyFormat) -> Self {
+        self.key_format = key_format;
+        self
+    }
+}
+
+impl std::fmt::Debug for MqttClientCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MqttClientCertificate")
+            .field("certificate", &self.certificate)
+            .field("key_format", &self.key_format)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The format of the private key of a [`MqttClientCertificate`].
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MqttKeyFormat {
+    /// A PKCS#8 key (`BEGIN PRIVATE KEY`), e.g. an EC key.
+    #[default]
+    Pkcs8,
+    /// A PKCS#1 RSA key (`BEGIN RSA PRIVATE KEY`).
+    Rsa,
+}
+
+/// The MQTT QoS level of a subscription.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MqttQos {
+    /// Events are delivered at most once, events published while the connection was lost are dropped.
+    AtMostOnce,
+    /// Events are delivered at least once.
+    #[default]
+    AtLeastOnce,
+    /// Events are delivered exactly once.
+    ExactlyOnce,
+}
+
+impl From<MqttQos> for rumqttc::QoS {
+    fn from(qos: MqttQos) -> Self {
+        match qos {
+            MqttQos::AtMostOnce => Self::AtMostOnce,
+            MqttQos::AtLeastOnce => Self::AtLeastOnce,
+            MqttQos::ExactlyOnce => Self::ExactlyOnce,
+        }
+    }
 }
 
 /// A MQTT topic.
@@ -9,7 +9,7 @@ use crate::{
     client::{ClientInner, Result},
     types::{
         api::plugins::participation::{
-            responses::{AddressOutputsResponse, EventsResponse, OutputStatusResponse},
+            responses::{AddressOutputsResponse, EventsResponse, OutputStatusResponse, RewardsResponse},
             types::{
                 AddressStakingStatus, ParticipationEventData, ParticipationEventId, ParticipationEventStatus,
                 ParticipationEventType,
@@ -84,4 +84,24 @@ impl ClientInner {
 
         self.get_request(&route, None, false, false).await
     }
+
+    /// RouteAdminRewards is the route to get the rewards of all addresses for a staking event. Requires the node to
+    /// authorize the admin routes, e.g. with a JWT set in the node auth.
+    pub async fn event_rewards(
+        &self,
+        event_id: &ParticipationEventId,
+        milestone_index: Option<u32>,
+    ) -> Result<RewardsResponse> {
+        let route = format!("api/participation/v1/admin/events/{event_id}/rewards");
+
+        self.get_request(
+            &route,
+            milestone_index
+                .map(|index| format!("milestoneIndex={index}"))
+                .as_deref(),
+            false,
+            false,
+        )
+        .await
+    }
 }
@@ -6,35 +6,103 @@
 use core::str::FromStr;
 
 use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 
-use crate::client::{ClientInner, Result};
+use crate::{
+    client::{node_api::error::Error as NodeApiError, ClientInner, Error, Result},
+    types::{api::core::response::WhiteFlagResponse, block::BlockId},
+};
+
+/// Base path of the participation plugin routes.
+pub const PARTICIPATION_PLUGIN_PATH: &str = "api/participation/v1/";
+/// Base path of the proof of inclusion plugin routes.
+pub const POI_PLUGIN_PATH: &str = "api/poi/v1/";
+/// Base path of the debug plugin routes.
+pub const DEBUG_PLUGIN_PATH: &str = "api/debug/v1/";
 
 impl ClientInner {
     /// Extension method which provides request methods for plugins.
-    pub async fn call_plugin_route<T>(
+    ///
+    /// The `query_params` are joined with `&`, e.g. `vec!["pageSize=10", "cursor=abc"]`, and the `request_object` of a
+    /// POST request is sent as JSON.
+    pub async fn call_plugin_route<T: DeserializeOwned>(
         &self,
         base_plugin_path: &str,
         method: &str,
         endpoint: &str,
         query_params: Vec<String>,
         request_object: Option<String>,
-    ) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize,
-    {
+    ) -> Result<T> {
         let mut method = method.to_string();
         method.make_ascii_uppercase();
 
-        let req_method = reqwest::Method::from_str(&method);
+        let path = format!("{base_plugin_path}{endpoint}");
+        let query = query_params.join("&");
+        let query = (!query.is_empty()).then_some(query.as_str());
+
+        let value = match Method::from_str(&method) {
+            Ok(Method::GET) => self.get_request::<Value>(&path, query, false, false).await?,
+            Ok(Method::POST) => {
+                let path = match query {
+                    Some(query) => format!("{path}?{query}"),
+                    None => path,
+                };
+                let json = request_object
+                    .as_deref()
+                    .map(serde_json::from_str)
+                    .transpose()?
+                    .unwrap_or(Value::Null);
+                self.post_request_json(&path, json, true).await?
+            }
+            _ => return Err(Error::Node(NodeApiError::NotSupported(method))),
+        };
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Calls a route of the participation plugin, see [`ClientInner::call_plugin_route()`].
+    pub async fn call_participation_plugin_route<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query_params: Vec<String>,
+        request_object: Option<String>,
+    ) -> Result<T> {
+        self.call_plugin_route(PARTICIPATION_PLUGIN_PATH, method, endpoint, query_params, request_object)
+            .await
+    }
+
+    /// Calls a route of the proof of inclusion plugin, see [`ClientInner::call_plugin_route()`].
+    pub async fn call_poi_plugin_route<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query_params: Vec<String>,
+        request_object: Option<String>,
+    ) -> Result<T> {
+        self.call_plugin_route(POI_PLUGIN_PATH, method, endpoint, query_params, request_object)
+            .await
+    }
+
+    /// Calls a route of the debug plugin, see [`ClientInner::call_plugin_route()`].
+    pub async fn call_debug_plugin_route<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query_params: Vec<String>,
+        request_object: Option<String>,
+    ) -> Result<T> {
+        self.call_plugin_route(DEBUG_PLUGIN_PATH, method, endpoint, query_params, request_object)
+            .await
+    }
 
-        let path = format!("{}{}{}", base_plugin_path, endpoint, query_params.join("&"));
+    /// Computes the merkle tree hash of the white flag traversal of the given parents with the debug plugin.
+    /// POST /api/debug/v1/whiteflag
+    pub async fn compute_white_flag(&self, index: u32, parents: &[BlockId]) -> Result<WhiteFlagResponse> {
+        let request_object = serde_json::json!({ "index": index, "parents": parents }).to_string();
 
-        match req_method {
-            Ok(Method::GET) => self.get_request(&path, None, false, false).await,
-            Ok(Method::POST) => self.post_request_json(&path, request_object.into(), true).await,
-            _ => Err(crate::client::Error::Node(
-                crate::client::node_api::error::Error::NotSupported(method.to_string()),
-            )),
-        }
+        self.call_debug_plugin_route("POST", "whiteflag", Vec::new(), Some(request_object))
+            .await
     }
 }
@@ -0,0 +1,32 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! IOTA node proof of inclusion routes.
+//! <https://github.com/iotaledger/inx-poi/blob/develop/core/poi/routes.go>
+
+use crate::{
+    client::{ClientInner, Result},
+    types::{
+        api::plugins::poi::{ProofOfInclusion, ProofOfInclusionResponse},
+        block::{payload::milestone::MilestonePayload, Block, BlockId},
+        TryFromDto,
+    },
+};
+
+impl ClientInner {
+    /// Returns a proof that a block was referenced by a milestone, which can be verified locally with
+    /// [`ProofOfInclusion::verify()`].
+    /// GET /api/poi/v1/create/{blockId}
+    pub async fn get_poi(&self, block_id: &BlockId) -> Result<ProofOfInclusion> {
+        let endpoint = format!("create/{block_id}");
+
+        let response: ProofOfInclusionResponse = self.call_poi_plugin_route("GET", &endpoint, Vec::new(), None).await?;
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        Ok(ProofOfInclusion {
+            milestone: MilestonePayload::try_from_dto_with_params(response.milestone, &protocol_parameters)?,
+            block: Block::try_from_dto_with_params(response.block, &protocol_parameters)?,
+            proof: response.proof,
+        })
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Query results together with where they came from, for applications that reconcile data from multiple nodes.
+//!
+//! Each result is requested from a single node, without quorum, so the provenance is the one of the returned data.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    client::{
+        node_api::indexer::query_parameters::{verify_query_parameters_basic_outputs, QueryParameter},
+        ClientInner, Result,
+    },
+    types::{
+        api::{
+            core::response::{BlockMetadataResponse, InfoResponse, OutputWithMetadataResponse},
+            plugins::indexer::OutputIdsResponse,
+        },
+        block::{
+            output::{Output, OutputId, OutputMetadata, OutputWithMetadata},
+            Block, BlockDto, BlockId,
+        },
+        TryFromDto,
+    },
+};
+
+/// Where a query result came from.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Provenance {
+    /// The url of the request, without credentials.
+    pub url: Url,
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// The time until the response was received, including retries.
+    pub latency: Duration,
+    /// The ledger index of the response or, if it doesn't contain one, the confirmed milestone index of the node at
+    /// its last sync.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger_index: Option<u32>,
+}
+
+impl Provenance {
+    pub(crate) fn ledger_index_of(response: &Value) -> Option<u32> {
+        response
+            .get("ledgerIndex")
+            .or_else(|| response.get("metadata")?.get("ledgerIndex"))
+            .and_then(Value::as_u64)
+            .map(|ledger_index| ledger_index as u32)
+    }
+}
+
+/// A query result with its [`Provenance`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithProvenance<T> {
+    /// The result.
+    pub data: T,
+    /// Where the result came from.
+    pub provenance: Provenance,
+}
+
+impl<T> WithProvenance<T> {
+    /// Converts the result, keeping its provenance.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WithProvenance<U> {
+        WithProvenance {
+            data: f(self.data),
+            provenance: self.provenance,
+        }
+    }
+
+    /// Converts the result with a fallible function, keeping its provenance.
+    pub fn try_map<U, E>(
+        self,
+        f: impl FnOnce(T) -> core::result::Result<U, E>,
+    ) -> core::result::Result<WithProvenance<U>, E> {
+        Ok(WithProvenance {
+            data: f(self.data)?,
+            provenance: self.provenance,
+        })
+    }
+
+    /// Returns the result without its provenance.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl ClientInner {
+    /// Returns the node info, see [`ClientInner::get_info()`].
+    /// GET /api/core/v2/info
+    pub async fn get_info_with_provenance(&self) -> Result<WithProvenance<InfoResponse>> {
+        self.get_request_with_provenance("api/core/v2/info", None, false).await
+    }
+
+    /// Finds a block by its BlockId, see [`ClientInner::get_block()`].
+    /// GET /api/core/v2/blocks/{BlockId}
+    pub async fn get_block_with_provenance(&self, block_id: &BlockId) -> Result<WithProvenance<Block>> {
+        let path = &format!("api/core/v2/blocks/{block_id}");
+        let protocol_parameters = self.get_protocol_parameters().await?;
+
+        self.get_request_with_provenance::<BlockDto>(path, None, true)
+            .await?
+            .try_map(|dto| Block::try_from_dto_with_params(dto, protocol_parameters))
+            .map_err(Into::into)
+    }
+
+    /// Returns the metadata of a block, see [`ClientInner::get_block_metadata()`].
+    /// GET /api/core/v2/blocks/{blockId}/metadata
+    pub async fn get_block_metadata_with_provenance(
+        &self,
+        block_id: &BlockId,
+    ) -> Result<WithProvenance<BlockMetadataResponse>> {
+        let path = &format!("api/core/v2/blocks/{block_id}/metadata");
+
+        self.get_request_with_provenance(path, None, true).await
+    }
+
+    /// Finds an output by its OutputId, see [`ClientInner::get_output()`].
+    /// GET /api/core/v2/outputs/{outputId}
+    pub async fn get_output_with_provenance(&self, output_id: &OutputId) -> Result<WithProvenance<OutputWithMetadata>> {
+        let path = &format!("api/core/v2/outputs/{output_id}");
+        let token_supply = self.get_token_supply().await?;
+
+        self.get_request_with_provenance::<OutputWithMetadataResponse>(path, None, true)
+            .await?
+            .try_map(|response| {
+                Output::try_from_dto_with_params(response.output, token_supply)
+                    .map(|output| OutputWithMetadata::new(output, response.metadata))
+            })
+            .map_err(Into::into)
+    }
+
+    /// Returns the metadata of an output, see [`ClientInner::get_output_metadata()`].
+    /// GET /api/core/v2/outputs/{outputId}/metadata
+    pub async fn get_output_metadata_with_provenance(
+        &self,
+        output_id: &OutputId,
+    ) -> Result<WithProvenance<OutputMetadata>> {
+        let path = &format!("api/core/v2/outputs/{output_id}/metadata");
+
+        self.get_request_with_provenance(path, None, true).await
+    }
+
+    /// Returns a single page of the basic output ids filtered by the given parameters, see
+    /// [`ClientInner::basic_output_ids()`]. The next page is requested with the cursor of the response.
+    /// api/indexer/v1/outputs/basic
+    pub async fn basic_output_ids_with_provenance(
+        &self,
+        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+    ) -> Result<WithProvenance<OutputIdsResponse>> {
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
+
+        self.get_request_with_provenance(
+            "api/indexer/v1/outputs/basic",
+            query_parameters.to_query_string().as_deref(),
+            false,
+        )
+        .await
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// The versions of the core API a node can be requested with.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum CoreApiVersion {
+    /// The stardust core API, `/api/core/v2`.
+    #[default]
+    V2,
+    /// The experimental next-gen core API, `/api/core/v3`.
+    #[cfg(feature = "core_v3")]
+    V3,
+}
+
+impl CoreApiVersion {
+    /// The versions this build can use, in ascending order.
+    pub const SUPPORTED: &'static [Self] = &[
+        Self::V2,
+        #[cfg(feature = "core_v3")]
+        Self::V3,
+    ];
+
+    /// Returns the route group of the version, as listed by the `/api/routes` endpoint of a node.
+    pub fn route_group(&self) -> &'static str {
+        match self {
+            Self::V2 => "core/v2",
+            #[cfg(feature = "core_v3")]
+            Self::V3 => "core/v3",
+        }
+    }
+
+    /// Returns the highest supported version that is listed in the route groups of a node.
+    pub fn negotiate<S: AsRef<str>>(routes: &[S]) -> Option<Self> {
+        Self::SUPPORTED
+            .iter()
+            .rev()
+            .find(|version| routes.iter().any(|route| route.as_ref() == version.route_group()))
+            .copied()
+    }
+
+    /// Rewrites a core API path, which are all defined for v2, to this version.
+    pub(crate) fn route_path<'a>(&self, path: &'a str) -> Cow<'a, str> {
+        let v2_prefix = Self::V2.path_prefix();
+
+        match path.strip_prefix(v2_prefix) {
+            Some(route) if *self != Self::V2 => Cow::Owned(format!("{}{route}", self.path_prefix())),
+            _ => Cow::Borrowed(path),
+        }
+    }
+
+    fn path_prefix(&self) -> &'static str {
+        match self {
+            Self::V2 => "api/core/v2/",
+            #[cfg(feature = "core_v3")]
+            Self::V3 => "api/core/v3/",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn negotiate() {
+        assert_eq!(
+            CoreApiVersion::negotiate(&["core/v2", "indexer/v1"]),
+            Some(CoreApiVersion::V2)
+        );
+        assert_eq!(CoreApiVersion::negotiate(&["indexer/v1"]), None);
+        assert_eq!(CoreApiVersion::negotiate::<&str>(&[]), None);
+
+        #[cfg(feature = "core_v3")]
+        assert_eq!(
+            CoreApiVersion::negotiate(&["core/v2", "core/v3"]),
+            Some(CoreApiVersion::V3)
+        );
+        #[cfg(not(feature = "core_v3"))]
+        assert_eq!(
+            CoreApiVersion::negotiate(&["core/v2", "core/v3"]),
+            Some(CoreApiVersion::V2)
+        );
+    }
+
+    #[test]
+    fn route_path() {
+        assert_eq!(
+            CoreApiVersion::V2.route_path("api/core/v2/blocks"),
+            "api/core/v2/blocks"
+        );
+        assert_eq!(CoreApiVersion::V2.route_path("api/routes"), "api/routes");
+
+        #[cfg(feature = "core_v3")]
+        {
+            assert_eq!(
+                CoreApiVersion::V3.route_path("api/core/v2/blocks"),
+                "api/core/v3/blocks"
+            );
+            assert_eq!(
+                CoreApiVersion::V3.route_path("api/indexer/v1/outputs"),
+                "api/indexer/v1/outputs"
+            );
+        }
+    }
+}
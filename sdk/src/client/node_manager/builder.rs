@@ -5,20 +5,32 @@
 
 use std::{
     collections::{HashMap, HashSet},
-    sync::RwLock,
+    sync::{Arc, RwLock},
     time::Duration,
 };
 
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+#[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+use crate::client::node_manager::proxy::ProxyConfig;
+#[cfg(all(feature = "tls", not(target_family = "wasm")))]
+use crate::client::node_manager::tls::TlsConfig;
+#[cfg(not(target_family = "wasm"))]
+use crate::client::{
+    node_api::error::Error as NodeApiError,
+    node_manager::dns::{CustomDnsResolver, DnsResolver},
+};
 use crate::{
     client::{
         constants::{DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT, NODE_SYNC_INTERVAL},
         error::{Error, Result},
         node_manager::{
             http_client::HttpClient,
+            interceptor::{RequestInterceptor, RequestInterceptors},
+            metrics::{MetricsSink, MetricsSinks},
             node::{Node, NodeAuth, NodeDto},
+            retry::RetryPolicy,
             NodeManager,
         },
     },
@@ -61,6 +73,33 @@ pub struct NodeManagerBuilder {
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// The policy for retrying failed requests
+    #[serde(default)]
+    pub retry_policy: RetryPolicy,
+    /// If only nodes using TLS should be used, plaintext connections are only allowed to localhost
+    #[serde(default)]
+    pub require_tls: bool,
+    /// The TLS configuration of the node connections
+    #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls_config: Option<TlsConfig>,
+    /// The proxy of the node connections
+    #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxyConfig>,
+    /// If the client is offline and no requests are sent to nodes
+    #[serde(default)]
+    pub offline: bool,
+    /// The resolver of the node host names, the system resolver is used if not set
+    #[cfg(not(target_family = "wasm"))]
+    #[serde(skip)]
+    pub(crate) dns_resolver: Option<CustomDnsResolver>,
+    /// The interceptors called with every request and response
+    #[serde(skip)]
+    pub(crate) request_interceptors: RequestInterceptors,
+    /// The sinks of the request metrics
+    #[serde(skip)]
+    pub(crate) metrics_sinks: MetricsSinks,
 }
 
 fn default_user_agent() -> String {
@@ -211,8 +250,100 @@ impl NodeManagerBuilder {
         self
     }
 
-    pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> NodeManager {
-        NodeManager {
+    pub(crate) fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub(crate) fn with_require_tls(mut self, require_tls: bool) -> Self {
+        self.require_tls = require_tls;
+        self
+    }
+
+    #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+    pub(crate) fn with_tls_config(mut self, tls_config: impl Into<Option<TlsConfig>>) -> Self {
+        self.tls_config = tls_config.into();
+        self
+    }
+
+    #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+    pub(crate) fn with_proxy(mut self, proxy: impl Into<Option<ProxyConfig>>) -> Self {
+        self.proxy = proxy.into();
+        self
+    }
+
+    pub(crate) fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn with_dns_resolver(mut self, dns_resolver: impl DnsResolver + 'static) -> Self {
+        self.dns_resolver = Some(CustomDnsResolver::new(Arc::new(dns_resolver)));
+        self
+    }
+
+    pub(crate) fn with_request_interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.request_interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    pub(crate) fn with_metrics_sink(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.metrics_sinks.push(Arc::new(sink));
+        self
+    }
+
+    /// Validates that all nodes use TLS if it's required.
+    pub(crate) fn validate_tls(&self) -> Result<()> {
+        if self.require_tls {
+            for node_dto in self
+                .primary_node
+                .iter()
+                .chain(self.primary_pow_node.iter())
+                .chain(self.nodes.iter())
+                .chain(self.permanodes.iter())
+            {
+                let node: Node = node_dto.into();
+                validate_tls(&node.url)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the reqwest client with the TLS configuration, proxy and DNS resolver.
+    #[cfg(not(target_family = "wasm"))]
+    fn reqwest_client(&self) -> Result<reqwest::Client> {
+        #[allow(unused_mut)]
+        let mut builder = reqwest::Client::builder();
+
+        #[cfg(feature = "tls")]
+        if let Some(tls_config) = &self.tls_config {
+            builder = builder.use_preconfigured_tls(tls_config.client_config_builder()?.with_no_client_auth());
+        }
+        #[cfg(feature = "proxy")]
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.to_reqwest_proxy().map_err(NodeApiError::from)?);
+        }
+        if let Some(dns_resolver) = &self.dns_resolver {
+            builder = builder.dns_resolver(Arc::new(dns_resolver.clone()));
+        }
+
+        Ok(builder.build().map_err(NodeApiError::from)?)
+    }
+
+    pub(crate) fn build(self, healthy_nodes: HashMap<Node, InfoResponse>) -> Result<NodeManager> {
+        #[cfg(not(target_family = "wasm"))]
+        let reqwest_client = self.reqwest_client()?;
+        let http_client = HttpClient::new(
+            self.user_agent,
+            self.retry_policy,
+            self.request_interceptors,
+            self.metrics_sinks,
+        );
+        #[cfg(not(target_family = "wasm"))]
+        let http_client = http_client.with_client(reqwest_client);
+
+        Ok(NodeManager {
             primary_node: self.primary_node.map(Into::into),
             primary_pow_node: self.primary_pow_node.map(Into::into),
             nodes: self.nodes.into_iter().map(Into::into).collect(),
@@ -223,8 +354,17 @@ impl NodeManagerBuilder {
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
-        }
+            http_client,
+            require_tls: self.require_tls,
+            #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+            tls_config: self.tls_config,
+            #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+            proxy: self.proxy,
+            offline: self.offline,
+            #[cfg(not(target_family = "wasm"))]
+            dns_resolver: self.dns_resolver,
+            api_versions: Default::default(),
+        })
     }
 }
 
@@ -241,6 +381,17 @@ impl Default for NodeManagerBuilder {
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            retry_policy: RetryPolicy::default(),
+            require_tls: false,
+            #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+            tls_config: None,
+            #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+            proxy: None,
+            offline: false,
+            #[cfg(not(target_family = "wasm"))]
+            dns_resolver: None,
+            request_interceptors: RequestInterceptors::default(),
+            metrics_sinks: MetricsSinks::default(),
         }
     }
 }
@@ -253,6 +404,24 @@ pub fn validate_url(url: Url) -> Result<Url> {
     Ok(url)
 }
 
+/// Validates if the url uses https, plaintext http is only accepted for localhost
+pub fn validate_tls(url: &Url) -> Result<()> {
+    if !is_tls_or_localhost(url) {
+        return Err(Error::TlsRequired(url.origin().ascii_serialization()));
+    }
+    Ok(())
+}
+
+pub(crate) fn is_tls_or_localhost(url: &Url) -> bool {
+    url.scheme() == "https"
+        || match url.host() {
+            Some(url::Host::Domain(domain)) => domain == "localhost",
+            Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+            Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+            None => false,
+        }
+}
+
 impl From<&NodeManager> for NodeManagerBuilder {
     fn from(value: &NodeManager) -> Self {
         Self {
@@ -266,6 +435,17 @@ impl From<&NodeManager> for NodeManagerBuilder {
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
             user_agent: value.http_client.user_agent.clone(),
+            retry_policy: value.http_client.retry_policy.clone(),
+            require_tls: value.require_tls,
+            #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+            tls_config: value.tls_config.clone(),
+            #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+            proxy: value.proxy.clone(),
+            offline: value.offline,
+            #[cfg(not(target_family = "wasm"))]
+            dns_resolver: value.dns_resolver.clone(),
+            request_interceptors: value.http_client.interceptors.clone(),
+            metrics_sinks: value.http_client.metrics_sinks.clone(),
         }
     }
 }
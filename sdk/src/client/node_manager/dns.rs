@@ -0,0 +1,68 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fmt::Debug,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+
+/// The error of a [`DnsResolver`].
+pub type DnsResolverError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Resolves the host names of nodes instead of the system resolver, e.g. with DNS over HTTPS or a static host map.
+///
+/// The resolver is used by the HTTP client. MQTT connections resolve hosts with the system resolver, or with the
+/// proxy if one is set.
+#[async_trait::async_trait]
+pub trait DnsResolver: Send + Sync {
+    /// Resolves a host name to the IP addresses to try in order.
+    async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>, DnsResolverError>;
+}
+
+#[async_trait::async_trait]
+impl<T: DnsResolver + ?Sized> DnsResolver for Arc<T> {
+    async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>, DnsResolverError> {
+        (**self).resolve(host).await
+    }
+}
+
+/// The custom DNS resolver of the client.
+#[derive(Clone)]
+pub(crate) struct CustomDnsResolver(Arc<dyn DnsResolver>);
+
+impl CustomDnsResolver {
+    pub(crate) fn new(resolver: Arc<dyn DnsResolver>) -> Self {
+        Self(resolver)
+    }
+}
+
+impl Resolve for CustomDnsResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let resolver = self.0.clone();
+
+        Box::pin(async move {
+            let ips = resolver.resolve(name.as_str()).await?;
+            // The port is replaced by the one of the URL.
+            let addrs: Addrs = Box::new(ips.into_iter().map(|ip| SocketAddr::new(ip, 0)));
+            Ok(addrs)
+        })
+    }
+}
+
+impl Debug for CustomDnsResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CustomDnsResolver")
+    }
+}
+
+impl PartialEq for CustomDnsResolver {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CustomDnsResolver {}
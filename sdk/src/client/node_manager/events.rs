@@ -0,0 +1,58 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::node::Node;
+use crate::client::ClientInner;
+
+/// Changes of the nodes requests are sent to, see [`ClientInner::subscribe_node_events()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum NodeEvent {
+    /// The node sync found a node healthy that wasn't before, it is added to the healthy node pool.
+    Healthy { node: Node },
+    /// The node sync found a previously healthy node unhealthy or unreachable, it is removed from the healthy node
+    /// pool.
+    Unhealthy { node: Node },
+    /// The primary node was replaced, e.g. by
+    /// [`Client::update_node_manager()`](crate::client::Client::update_node_manager).
+    PrimaryNodeChanged {
+        previous: Option<Node>,
+        current: Option<Node>,
+    },
+}
+
+impl ClientInner {
+    /// Subscribes to [`NodeEvent`]s, e.g. to alert operators when nodes become unhealthy.
+    pub fn subscribe_node_events(&self) -> tokio::sync::broadcast::Receiver<NodeEvent> {
+        self.node_event_sender.subscribe()
+    }
+
+    /// Calls the callback with every [`NodeEvent`] until the client is dropped or the returned task is aborted.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn on_node_event<F>(&self, callback: F) -> tokio::task::JoinHandle<()>
+    where
+        F: Fn(NodeEvent) + Send + Sync + 'static,
+    {
+        let mut receiver = self.subscribe_node_events();
+
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => callback(event),
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::warn!("Skipped {skipped} node events, the callback is too slow");
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
+    pub(crate) fn send_node_event(&self, event: NodeEvent) {
+        log::debug!("[send_node_event] {event:?}");
+        // There may be no subscribers.
+        self.node_event_sender.send(event).ok();
+    }
+}
@@ -11,8 +11,17 @@ use serde_json::Value;
 
 use crate::client::{
     node_api::error::{Error, Result},
-    node_manager::node::Node,
+    node_manager::{
+        interceptor::{InterceptedResponse, RequestInterceptors},
+        metrics::{route_template, MetricsSinks, RequestMetrics},
+        node::Node,
+        retry::RetryPolicy,
+    },
 };
+
+/// The content type of binary encoded (packed) API responses and requests
+pub(crate) const BINARY_CONTENT_TYPE: &str = "application/vnd.iota.serializer-v1";
+
 pub(crate) struct Response(reqwest::Response);
 
 impl Response {
@@ -38,16 +47,34 @@ impl Response {
 pub(crate) struct HttpClient {
     client: reqwest::Client,
     pub(crate) user_agent: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) interceptors: RequestInterceptors,
+    pub(crate) metrics_sinks: MetricsSinks,
 }
 
 impl HttpClient {
-    pub(crate) fn new(user_agent: String) -> Self {
+    pub(crate) fn new(
+        user_agent: String,
+        retry_policy: RetryPolicy,
+        interceptors: RequestInterceptors,
+        metrics_sinks: MetricsSinks,
+    ) -> Self {
         Self {
             client: reqwest::Client::new(),
             user_agent,
+            retry_policy,
+            interceptors,
+            metrics_sinks,
         }
     }
 
+    /// Uses a client that is configured with the connection options of the node manager.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
     async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response> {
         let status = response.status();
         if status.is_success() {
@@ -88,39 +115,143 @@ impl HttpClient {
         request_builder
     }
 
+    // Sends the request once, passing it and the response to the interceptors
+    async fn execute(&self, request_builder: RequestBuilder, method: &str, url: &url::Url) -> Result<Response> {
+        let mut request = request_builder.build()?;
+        self.interceptors.on_request(&mut request);
+        let request_method = request.method().clone();
+
+        let start_time = instant::Instant::now();
+        let resp = self.client.execute(request).await?;
+        let elapsed = start_time.elapsed();
+        log::debug!("{method}: {:?} ms for {} {url}", elapsed.as_millis(), resp.status());
+        self.interceptors.on_response(&InterceptedResponse {
+            method: &request_method,
+            url: resp.url(),
+            status: resp.status().as_u16(),
+            headers: resp.headers(),
+            elapsed,
+        });
+
+        Self::parse_response(resp, url).await
+    }
+
+    // Sends the request with retries, in a tracing span and reporting the metrics to the sinks
+    async fn send(&self, request_builder: RequestBuilder, method: &str, url: &url::Url) -> Result<Response> {
+        let route = route_template(url.path());
+        let node = url.origin().ascii_serialization();
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "node_request",
+            method,
+            route,
+            node,
+            status = tracing::field::Empty,
+            retries = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+        );
+
+        let start_time = instant::Instant::now();
+        let send = self.send_with_retries(request_builder, method, url);
+        #[cfg(feature = "tracing")]
+        let send = tracing::Instrument::instrument(send, span.clone());
+        let (result, retries) = send.await;
+        let latency = start_time.elapsed();
+
+        let status = match &result {
+            Ok(response) => Some(response.status()),
+            Err(Error::ResponseError { code, .. }) => Some(*code),
+            Err(Error::NotFound(_)) => Some(404),
+            Err(_) => None,
+        };
+        #[cfg(feature = "tracing")]
+        {
+            span.record("status", status);
+            span.record("retries", retries);
+            span.record("latency_ms", latency.as_millis() as u64);
+            if let Err(e) = &result {
+                tracing::debug!(parent: &span, error = %e, "node request failed");
+            }
+        }
+        if !self.metrics_sinks.is_empty() {
+            self.metrics_sinks.on_request(&RequestMetrics {
+                method,
+                route: &route,
+                node: &node,
+                status,
+                latency,
+                retries,
+                error: result.as_ref().err(),
+            });
+        }
+
+        result
+    }
+
+    // Sends the request and retries it if it failed with an error that is retryable according to the retry policy,
+    // returns the result and the number of retries
+    async fn send_with_retries(
+        &self,
+        request_builder: RequestBuilder,
+        method: &str,
+        url: &url::Url,
+    ) -> (Result<Response>, u32) {
+        let mut retries = 0;
+        loop {
+            // Requests with a body that can't be cloned are only sent once
+            let Some(request) = request_builder.try_clone() else {
+                return (self.execute(request_builder, method, url).await, retries);
+            };
+            match self.execute(request, method, url).await {
+                Err(e) if self.retry_policy.should_retry(&e, retries) => {
+                    let delay = self.retry_policy.delay(retries);
+                    log::debug!("{method}: retrying {url} in {delay:?} after: {e}");
+                    #[cfg(target_family = "wasm")]
+                    gloo_timers::future::TimeoutFuture::new(delay.as_millis().try_into().unwrap_or(u32::MAX)).await;
+                    #[cfg(not(target_family = "wasm"))]
+                    tokio::time::sleep(delay).await;
+                    retries += 1;
+                }
+                result => return (result, retries),
+            }
+        }
+    }
+
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
         let mut request_builder = self.client.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
-        log::debug!(
-            "GET: {:?} ms for {} {}",
-            start_time.elapsed().as_millis(),
-            resp.status(),
-            node.url
-        );
-        Self::parse_response(resp, &node.url).await
+        self.send(request_builder, "GET", &node.url).await
     }
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
     pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response> {
         let mut request_builder = self.client.get(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
-        let resp = request_builder.send().await?;
-        Self::parse_response(resp, &node.url).await
+        request_builder = request_builder.header(reqwest::header::ACCEPT, BINARY_CONTENT_TYPE);
+        let resp = self.send(request_builder, "GET", &node.url).await?;
+        // Nodes that don't support the binary encoding for a route respond with JSON instead
+        if let Some(content_type) = resp.0.headers().get(reqwest::header::CONTENT_TYPE) {
+            let content_type = content_type.to_str().unwrap_or_default();
+            if !content_type.starts_with(BINARY_CONTENT_TYPE) {
+                return Err(Error::UnexpectedContentType {
+                    content_type: content_type.to_string(),
+                    url: node.url.to_string(),
+                });
+            }
+        }
+        Ok(resp)
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+        self.send(request_builder.json(&json), "POST", &node.url).await
     }
 
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+        request_builder = request_builder.header(reqwest::header::CONTENT_TYPE, BINARY_CONTENT_TYPE);
+        self.send(request_builder.body(body.to_vec()), "POST", &node.url).await
     }
 }
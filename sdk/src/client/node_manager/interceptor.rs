@@ -0,0 +1,82 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+pub use reqwest::{header::HeaderMap, Method, Request};
+
+use crate::Url;
+
+/// Hook into the requests sent to nodes, to mutate them before they're sent (custom auth headers, request signing,
+/// tracing IDs, ...) and to observe the responses.
+///
+/// Interceptors are called for every attempt of a request, so also for retries and every tried node.
+pub trait RequestInterceptor: Send + Sync {
+    /// Called with every request right before it's sent.
+    fn on_request(&self, _request: &mut Request) {}
+
+    /// Called with every received response, before its status is checked.
+    fn on_response(&self, _response: &InterceptedResponse<'_>) {}
+}
+
+impl<T: RequestInterceptor + ?Sized> RequestInterceptor for Arc<T> {
+    fn on_request(&self, request: &mut Request) {
+        (**self).on_request(request)
+    }
+
+    fn on_response(&self, response: &InterceptedResponse<'_>) {
+        (**self).on_response(response)
+    }
+}
+
+/// A response received from a node, passed to [`RequestInterceptor::on_response`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct InterceptedResponse<'a> {
+    /// The method of the request.
+    pub method: &'a Method,
+    /// The url of the response.
+    pub url: &'a Url,
+    /// The status code of the response.
+    pub status: u16,
+    /// The headers of the response.
+    pub headers: &'a HeaderMap,
+    /// The time it took from sending the request until the response was received.
+    pub elapsed: Duration,
+}
+
+/// The registered request interceptors, called in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct RequestInterceptors(Vec<Arc<dyn RequestInterceptor>>);
+
+impl RequestInterceptors {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn RequestInterceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub(crate) fn on_request(&self, request: &mut Request) {
+        for interceptor in &self.0 {
+            interceptor.on_request(request);
+        }
+    }
+
+    pub(crate) fn on_response(&self, response: &InterceptedResponse<'_>) {
+        for interceptor in &self.0 {
+            interceptor.on_response(response);
+        }
+    }
+}
+
+impl Debug for RequestInterceptors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RequestInterceptors({})", self.0.len())
+    }
+}
+
+impl PartialEq for RequestInterceptors {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl Eq for RequestInterceptors {}
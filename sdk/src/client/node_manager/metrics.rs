@@ -0,0 +1,89 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use crate::{client::node_api::error::Error, types::block::address::Bech32Address};
+
+/// Receives the metrics of the requests sent to nodes, e.g. to export them to Prometheus or OpenTelemetry.
+pub trait MetricsSink: Send + Sync {
+    /// Called once a request to a node completed, successfully or not, after all its retries.
+    fn on_request(&self, metrics: &RequestMetrics<'_>);
+}
+
+impl<T: MetricsSink + ?Sized> MetricsSink for Arc<T> {
+    fn on_request(&self, metrics: &RequestMetrics<'_>) {
+        (**self).on_request(metrics)
+    }
+}
+
+/// The metrics of a request to a node.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RequestMetrics<'a> {
+    /// The HTTP method.
+    pub method: &'a str,
+    /// The route, with ids, indexes and addresses replaced by `{id}` to keep the number of distinct routes low, e.g.
+    /// `/api/core/v2/blocks/{id}`.
+    pub route: &'a str,
+    /// The origin of the node, e.g. `https://api.testnet.shimmer.network`.
+    pub node: &'a str,
+    /// The status code of the last response, if one was received.
+    pub status: Option<u16>,
+    /// The time from sending the first attempt until the last response.
+    pub latency: Duration,
+    /// The number of retries.
+    pub retries: u32,
+    /// The error if the request failed.
+    pub error: Option<&'a Error>,
+}
+
+/// Returns the route of a path with ids, indexes and addresses replaced by `{id}`.
+pub(crate) fn route_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            let is_id = segment.starts_with("0x")
+                || (!segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()))
+                || Bech32Address::try_from_str(segment).is_ok();
+            if is_id {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct MetricsSinks(Vec<Arc<dyn MetricsSink>>);
+
+impl MetricsSinks {
+    pub(crate) fn push(&mut self, sink: Arc<dyn MetricsSink>) {
+        self.0.push(sink);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn on_request(&self, metrics: &RequestMetrics<'_>) {
+        for sink in &self.0 {
+            sink.on_request(metrics);
+        }
+    }
+}
+
+impl Debug for MetricsSinks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MetricsSinks({})", self.0.len())
+    }
+}
+
+impl PartialEq for MetricsSinks {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl Eq for MetricsSinks {}
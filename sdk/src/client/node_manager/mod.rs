@@ -3,11 +3,30 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
+/// The core API versions of nodes
+pub mod api_version;
 pub mod builder;
+/// Custom DNS resolution of the node host names
+#[cfg(not(target_family = "wasm"))]
+pub mod dns;
+/// Events about the nodes requests are sent to
+pub mod events;
 pub(crate) mod http_client;
+/// Hooks into the requests sent to nodes
+pub mod interceptor;
+/// Metrics of the requests sent to nodes
+pub mod metrics;
 /// Structs for nodes
 pub mod node;
+/// Proxy configuration of the node connections
+#[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+pub mod proxy;
+/// The retry policy for failed requests
+pub mod retry;
 pub(crate) mod syncing;
+/// TLS configuration of the node connections
+#[cfg(all(feature = "tls", not(target_family = "wasm")))]
+pub mod tls;
 
 use std::{
     collections::{HashMap, HashSet},
@@ -19,14 +38,15 @@ use std::{
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{api_version::CoreApiVersion, http_client::HttpClient, node::Node};
 use super::ClientInner;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::request_pool::RateLimitExt;
 use crate::{
     client::{
         error::{Error, Result},
-        node_manager::builder::NodeManagerBuilder,
+        node_api::provenance::{Provenance, WithProvenance},
+        node_manager::builder::{is_tls_or_localhost, NodeManagerBuilder},
     },
     types::api::core::response::InfoResponse,
 };
@@ -45,6 +65,15 @@ pub struct NodeManager {
     min_quorum_size: usize,
     quorum_threshold: usize,
     pub(crate) http_client: HttpClient,
+    pub(crate) require_tls: bool,
+    #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+    pub(crate) tls_config: Option<self::tls::TlsConfig>,
+    #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+    pub(crate) proxy: Option<self::proxy::ProxyConfig>,
+    pub(crate) offline: bool,
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) dns_resolver: Option<self::dns::CustomDnsResolver>,
+    pub(crate) api_versions: RwLock<HashMap<Node, CoreApiVersion>>,
 }
 
 impl Debug for NodeManager {
@@ -59,7 +88,16 @@ impl Debug for NodeManager {
         d.field("healthy_nodes", &self.healthy_nodes);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
-        d.field("quorum_threshold", &self.quorum_threshold).finish()
+        d.field("quorum_threshold", &self.quorum_threshold);
+        d.field("require_tls", &self.require_tls);
+        #[cfg(all(feature = "tls", not(target_family = "wasm")))]
+        d.field("tls_config", &self.tls_config);
+        #[cfg(all(feature = "proxy", not(target_family = "wasm")))]
+        d.field("proxy", &self.proxy);
+        d.field("offline", &self.offline);
+        #[cfg(not(target_family = "wasm"))]
+        d.field("dns_resolver", &self.dns_resolver);
+        d.field("api_versions", &self.api_versions).finish()
     }
 }
 
@@ -78,6 +116,38 @@ impl ClientInner {
         request.await
     }
 
+    /// Sends a GET request to the permanodes, for routes that regular nodes don't serve.
+    pub(crate) async fn get_permanode_request<T: DeserializeOwned + Debug + Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+    ) -> Result<T> {
+        let node_manager = self.node_manager.read().await;
+        if node_manager.offline {
+            return Err(Error::OfflineMode);
+        }
+        if node_manager.permanodes.is_empty() {
+            return Err(Error::MissingPermanode);
+        }
+        drop(node_manager);
+
+        self.get_request(path, query, false, true).await
+    }
+
+    /// Sends a GET request to a single node and returns the response with where it came from.
+    pub(crate) async fn get_request_with_provenance<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        prefer_permanode: bool,
+    ) -> Result<WithProvenance<T>> {
+        let node_manager = self.node_manager.read().await;
+        let request = node_manager.get_request_with_provenance(path, query, self.get_timeout().await, prefer_permanode);
+        #[cfg(not(target_family = "wasm"))]
+        let request = request.rate_limit(&self.request_pool);
+        request.await
+    }
+
     pub(crate) async fn get_request_bytes(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
         let node_manager = self.node_manager.read().await;
         let request = node_manager.get_request_bytes(path, query, self.get_timeout().await);
@@ -112,6 +182,10 @@ impl NodeManager {
         use_pow_nodes: bool,
         prefer_permanode: bool,
     ) -> Result<Vec<Node>> {
+        if self.offline {
+            return Err(Error::OfflineMode);
+        }
+
         let mut nodes_with_modified_url: Vec<Node> = Vec::new();
 
         if prefer_permanode || (path == "api/core/v2/blocks" && query.is_some()) {
@@ -178,6 +252,21 @@ impl NodeManager {
         // remove disabled nodes
         nodes_with_modified_url.retain(|n| !n.disabled);
 
+        // remove plaintext nodes if TLS is required
+        if self.require_tls {
+            let nodes_len = nodes_with_modified_url.len();
+            nodes_with_modified_url.retain(|n| {
+                let tls = is_tls_or_localhost(&n.url);
+                if !tls {
+                    log::warn!("skipping {}, TLS is required", n.url.origin().ascii_serialization());
+                }
+                tls
+            });
+            if nodes_len > 0 && nodes_with_modified_url.is_empty() {
+                return Err(Error::TlsRequired("all available nodes".to_string()));
+            }
+        }
+
         if nodes_with_modified_url.is_empty() {
             if use_pow_nodes {
                 return Err(crate::client::Error::Node(
@@ -187,8 +276,14 @@ impl NodeManager {
             return Err(crate::client::Error::HealthyNodePoolEmpty);
         }
 
-        // Set path and query parameters
+        let api_versions = self
+            .api_versions
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?;
+
+        // Set path, routed to the negotiated API version of the node, and query parameters
         for node in &mut nodes_with_modified_url {
+            let path = api_versions.get(node).copied().unwrap_or_default().route_path(path);
             if node.url.path().ends_with('/') {
                 node.url.set_path(&format!("{}{}", node.url.path(), path));
             } else {
@@ -328,6 +423,66 @@ impl NodeManager {
         }
     }
 
+    // Without quorum, so the provenance is the one of the returned data
+    pub(crate) async fn get_request_with_provenance<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+        prefer_permanode: bool,
+    ) -> Result<WithProvenance<T>> {
+        let nodes = self.get_nodes(path, query, false, prefer_permanode)?;
+        let mut error = None;
+        // Send requests
+        for node in nodes {
+            let start_time = instant::Instant::now();
+            match self.http_client.get(node.clone(), timeout).await {
+                Ok(res) => {
+                    let status = res.status();
+                    match res.into_json::<Value>().await {
+                        Ok(response) => {
+                            let latency = start_time.elapsed();
+                            let ledger_index = Provenance::ledger_index_of(&response)
+                                .or_else(|| self.confirmed_milestone_index(&node));
+                            let mut url = node.url;
+                            url.set_username("").ok();
+                            url.set_password(None).ok();
+
+                            return Ok(WithProvenance {
+                                data: serde_json::from_value(response)?,
+                                provenance: Provenance {
+                                    url,
+                                    status,
+                                    latency,
+                                    ledger_index,
+                                },
+                            });
+                        }
+                        Err(e) => {
+                            error.replace(e.into());
+                        }
+                    }
+                }
+                Err(err) => {
+                    error.replace(err.into());
+                }
+            }
+        }
+        // Safe unwrap, there are nodes because we throw on empty nodepool.
+        // Each node will throw an error or return Ok()
+        Err(error.unwrap())
+    }
+
+    // Returns the confirmed milestone index of a node at its last sync, the url of the node may contain a path.
+    fn confirmed_milestone_index(&self, node: &Node) -> Option<u32> {
+        let healthy_nodes = self.healthy_nodes.read().ok()?;
+
+        healthy_nodes
+            .iter()
+            .find(|(healthy_node, _)| healthy_node.url.origin() == node.url.origin())
+            .map(|(_, info)| info.status.confirmed_milestone.index)
+    }
+
     // Only used for api/core/v2/blocks/{blockID}, that's why we don't need the quorum stuff
     pub(crate) async fn get_request_bytes(
         &self,
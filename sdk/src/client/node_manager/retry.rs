@@ -0,0 +1,186 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{
+    constants::{DEFAULT_RETRY_BASE_DELAY, DEFAULT_RETRY_JITTER},
+    node_api::error::Error,
+};
+
+/// The policy for retrying failed HTTP requests to a node, before the next node is tried.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryPolicy {
+    /// Max amount of retries per request and node, 0 disables retrying.
+    #[serde(default)]
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every following retry.
+    #[serde(default = "default_base_delay")]
+    pub base_delay: Duration,
+    /// Max random delay added to every retry delay, so multiple clients don't retry at the same time.
+    #[serde(default = "default_jitter")]
+    pub jitter: Duration,
+    /// The failures that will be retried.
+    #[serde(default)]
+    pub retry_on: RetryOn,
+}
+
+fn default_base_delay() -> Duration {
+    DEFAULT_RETRY_BASE_DELAY
+}
+
+fn default_jitter() -> Duration {
+    DEFAULT_RETRY_JITTER
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            base_delay: DEFAULT_RETRY_BASE_DELAY,
+            jitter: DEFAULT_RETRY_JITTER,
+            retry_on: RetryOn::default(),
+        }
+    }
+}
+
+/// The failures that are classified as retryable.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryOn {
+    /// Retry when the node responds with 429 Too Many Requests.
+    #[serde(default = "default_true")]
+    pub too_many_requests: bool,
+    /// Retry when the node responds with a 5xx status code.
+    #[serde(default = "default_true")]
+    pub server_errors: bool,
+    /// Retry when the request timed out.
+    #[serde(default = "default_true")]
+    pub timeouts: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self {
+            too_many_requests: true,
+            server_errors: true,
+            timeouts: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Set the max amount of retries.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Set the delay before the first retry.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the max random delay added to every retry delay.
+    pub fn with_jitter(mut self, jitter: Duration) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the failures that will be retried.
+    pub fn with_retry_on(mut self, retry_on: RetryOn) -> Self {
+        self.retry_on = retry_on;
+        self
+    }
+
+    /// Returns if a request that failed with the error should be retried after `retries` retries.
+    pub(crate) fn should_retry(&self, error: &Error, retries: u32) -> bool {
+        retries < self.max_retries
+            && match error {
+                Error::ResponseError { code, .. } => {
+                    (self.retry_on.too_many_requests && *code == 429)
+                        || (self.retry_on.server_errors && (500..600).contains(code))
+                }
+                Error::Reqwest(e) => self.retry_on.timeouts && e.is_timeout(),
+                _ => false,
+            }
+    }
+
+    /// Returns the delay before the retry with the index `retry`, the base delay doubled for every previous retry plus
+    /// a random jitter.
+    pub(crate) fn delay(&self, retry: u32) -> Duration {
+        let backoff = self.base_delay.saturating_mul(2u32.saturating_pow(retry));
+        let jitter_nanos = self.jitter.as_nanos() as u64;
+        let jitter = if jitter_nanos == 0 {
+            Duration::ZERO
+        } else {
+            // Randomly seeded hasher as cheap source of randomness
+            Duration::from_nanos(RandomState::new().build_hasher().finish() % (jitter_nanos + 1))
+        };
+        backoff.saturating_add(jitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn response_error(code: u16) -> Error {
+        Error::ResponseError {
+            code,
+            text: String::new(),
+            url: String::new(),
+        }
+    }
+
+    #[test]
+    fn should_retry() {
+        let policy = RetryPolicy::default().with_max_retries(2);
+
+        assert!(policy.should_retry(&response_error(429), 0));
+        assert!(policy.should_retry(&response_error(503), 1));
+        assert!(!policy.should_retry(&response_error(503), 2));
+        assert!(!policy.should_retry(&response_error(400), 0));
+        assert!(!policy.should_retry(&Error::NotFound(String::new()), 0));
+        assert!(!RetryPolicy::default().should_retry(&response_error(503), 0));
+
+        let policy = policy.with_retry_on(RetryOn {
+            server_errors: false,
+            ..Default::default()
+        });
+        assert!(policy.should_retry(&response_error(429), 0));
+        assert!(!policy.should_retry(&response_error(503), 0));
+    }
+
+    #[test]
+    fn delay() {
+        let policy = RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(100))
+            .with_jitter(Duration::ZERO);
+
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(800));
+
+        let policy = policy.with_jitter(Duration::from_millis(50));
+        for retry in 0..3 {
+            let delay = policy.delay(retry);
+            let backoff = Duration::from_millis(100 * 2u64.pow(retry));
+            assert!(delay >= backoff && delay <= backoff + Duration::from_millis(50));
+        }
+    }
+}
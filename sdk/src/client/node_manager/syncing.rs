@@ -3,12 +3,21 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
-    crate::types::api::core::response::InfoResponse,
-    std::{collections::HashSet, time::Duration},
+    super::{api_version::CoreApiVersion, http_client::HttpClient},
+    crate::{
+        client::node_api::core::routes::INFO_PATH,
+        types::api::core::response::{InfoResponse, RoutesResponse},
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    },
     tokio::time::sleep,
 };
 
-use super::{Node, NodeManager};
+#[cfg(not(target_family = "wasm"))]
+use super::builder::is_tls_or_localhost;
+use super::{events::NodeEvent, Node, NodeManager};
 use crate::client::{Client, ClientInner, Error, Result};
 
 impl ClientInner {
@@ -23,6 +32,30 @@ impl ClientInner {
         pool.into_iter().next().ok_or(Error::HealthyNodePoolEmpty)
     }
 
+    /// Returns the confirmed milestone index of the healthy nodes at their last sync, the node info is only requested
+    /// if no node has been synced yet.
+    #[cfg(feature = "wallet")]
+    pub(crate) async fn confirmed_milestone_index(&self) -> Result<u32> {
+        let cached_index = self
+            .node_manager
+            .read()
+            .await
+            .healthy_nodes
+            .read()
+            .ok()
+            .and_then(|healthy_nodes| {
+                healthy_nodes
+                    .values()
+                    .map(|info| info.status.confirmed_milestone.index)
+                    .max()
+            });
+
+        match cached_index {
+            Some(index) => Ok(index),
+            None => Ok(self.get_info().await?.node_info.status.confirmed_milestone.index),
+        }
+    }
+
     /// returns the unhealthy nodes.
     #[cfg(not(target_family = "wasm"))]
     pub async fn unhealthy_nodes(&self) -> HashSet<Node> {
@@ -40,6 +73,19 @@ impl ClientInner {
                     .collect()
             })
     }
+
+    /// Returns the core API version negotiated with each node, nodes without one are requested with the default
+    /// version.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn node_api_versions(&self) -> HashMap<Node, CoreApiVersion> {
+        self.node_manager
+            .read()
+            .await
+            .api_versions
+            .read()
+            .map(|api_versions| api_versions.clone())
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -62,15 +108,33 @@ impl ClientInner {
     }
 
     pub(crate) async fn sync_nodes(&self, nodes: &HashSet<Node>, ignore_node_health: bool) -> Result<()> {
-        use std::collections::HashMap;
-
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
+        let mut api_versions = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        let (require_tls, http_client) = {
+            let node_manager = self.node_manager.read().await;
+            (node_manager.require_tls, node_manager.http_client.clone())
+        };
 
         for node in nodes {
+            if require_tls && !is_tls_or_localhost(&node.url) {
+                log::warn!(
+                    "not syncing {}, TLS is required",
+                    node.url.origin().ascii_serialization()
+                );
+                continue;
+            }
+
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            match Client::get_node_request::<InfoResponse>(
+                &http_client,
+                node.url.as_ref(),
+                node.auth.clone(),
+                INFO_PATH,
+            )
+            .await
+            {
                 Ok(info) => {
                     if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(info.protocol.network_name()) {
@@ -103,43 +167,95 @@ impl ClientInner {
 
         if let Some(nodes) = network_nodes.get(most_nodes.0) {
             if let Some((info, _node_url)) = nodes.first() {
-                let mut network_info = self.network_info.write().await;
-
-                network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
-                network_info.protocol_parameters = info.protocol.clone();
+                self.update_protocol_parameters(info.protocol.clone(), info.status.latest_milestone.timestamp)
+                    .await;
             }
 
             for (info, node_url) in nodes {
                 healthy_nodes.insert(node_url.clone(), info.clone());
+                api_versions.insert(node_url.clone(), negotiate_api_version(&http_client, node_url).await);
             }
         }
 
+        let node_manager = self.node_manager.read().await;
         // Update the sync list.
-        *self
-            .node_manager
-            .read()
-            .await
-            .healthy_nodes
+        let previous_healthy_nodes = core::mem::replace(
+            &mut *node_manager
+                .healthy_nodes
+                .write()
+                .map_err(|_| crate::client::Error::PoisonError)?,
+            healthy_nodes.clone(),
+        );
+        *node_manager
+            .api_versions
             .write()
-            .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
+            .map_err(|_| crate::client::Error::PoisonError)? = api_versions;
+        drop(node_manager);
+
+        for node in nodes {
+            match (
+                previous_healthy_nodes.contains_key(node),
+                healthy_nodes.contains_key(node),
+            ) {
+                (false, true) => self.send_node_event(NodeEvent::Healthy { node: node.clone() }),
+                (true, false) => self.send_node_event(NodeEvent::Unhealthy { node: node.clone() }),
+                _ => {}
+            }
+        }
 
         Ok(())
     }
 }
 
+/// Negotiates the highest core API version supported by both the node and this build.
+#[cfg(not(target_family = "wasm"))]
+async fn negotiate_api_version(http_client: &HttpClient, node: &Node) -> CoreApiVersion {
+    // With a single supported version there is nothing to negotiate, the node already answered the info request.
+    if CoreApiVersion::SUPPORTED.len() == 1 {
+        return CoreApiVersion::default();
+    }
+
+    match Client::get_node_request::<RoutesResponse>(http_client, node.url.as_ref(), node.auth.clone(), "api/routes")
+        .await
+    {
+        Ok(routes) => CoreApiVersion::negotiate(&routes.routes).unwrap_or_default(),
+        Err(err) => {
+            log::debug!(
+                "Couldn't get routes of {}, using the default API version: {err}",
+                node.url
+            );
+            CoreApiVersion::default()
+        }
+    }
+}
+
 impl Client {
     #[cfg(not(target_family = "wasm"))]
     pub async fn update_node_manager(&self, node_manager: NodeManager) -> Result<()> {
         let node_sync_interval = node_manager.node_sync_interval;
         let ignore_node_health = node_manager.ignore_node_health;
+        let offline = node_manager.offline;
         let nodes = node_manager
             .primary_node
             .iter()
             .chain(node_manager.nodes.iter())
             .cloned()
             .collect();
+        let primary_node = node_manager.primary_node.clone();
 
-        *self.node_manager.write().await = node_manager;
+        let previous_primary_node =
+            core::mem::replace(&mut *self.node_manager.write().await, node_manager).primary_node;
+        if previous_primary_node != primary_node {
+            self.send_node_event(NodeEvent::PrimaryNodeChanged {
+                previous: previous_primary_node,
+                current: primary_node,
+            });
+        }
+
+        if offline {
+            *self._sync_handle.write().await = crate::client::SyncHandle(None);
+            return Ok(());
+        }
 
         self.sync_nodes(&nodes, ignore_node_health).await?;
         let client = self.clone();
@@ -156,7 +272,16 @@ impl Client {
 
     #[cfg(target_family = "wasm")]
     pub async fn update_node_manager(&self, node_manager: NodeManager) -> Result<()> {
-        *self.node_manager.write().await = node_manager;
+        let primary_node = node_manager.primary_node.clone();
+
+        let previous_primary_node =
+            core::mem::replace(&mut *self.node_manager.write().await, node_manager).primary_node;
+        if previous_primary_node != primary_node {
+            self.send_node_event(NodeEvent::PrimaryNodeChanged {
+                previous: previous_primary_node,
+                current: primary_node,
+            });
+        }
         Ok(())
     }
 }
@@ -0,0 +1,268 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! TLS configuration of the HTTP and MQTT connections to nodes, e.g. for self-hosted nodes with a private PKI.
+
+use std::{sync::Arc, time::SystemTime};
+
+use crypto::hashes::{sha::Sha256, Digest};
+use rustls::{
+    client::{ServerCertVerified, ServerCertVerifier, WantsClientCert, WebPkiVerifier},
+    Certificate, ClientConfig, ConfigBuilder, OwnedTrustAnchor, RootCertStore, ServerName,
+};
+use serde::{Deserialize, Serialize};
+
+/// TLS configuration errors.
+#[derive(Debug, thiserror::Error)]
+pub enum TlsConfigError {
+    /// Invalid certificate fingerprint.
+    #[error("invalid certificate fingerprint `{0}`, expected the hex encoded SHA-256 hash of the certificate")]
+    InvalidFingerprint(String),
+    /// Invalid PEM.
+    #[error("invalid PEM: {0}")]
+    InvalidPem(#[from] std::io::Error),
+    /// A PEM doesn't contain a certificate.
+    #[error("no certificate found in PEM")]
+    MissingCertificate,
+    /// A PEM doesn't contain a private key.
+    #[error("no private key found in PEM")]
+    MissingPrivateKey,
+    /// No certificate would be trusted.
+    #[error("no trusted certificates, the default roots are disabled and no CA or pinned certificate is set")]
+    NoTrustedCertificates,
+    /// Rustls error.
+    #[error("{0}")]
+    Rustls(#[from] rustls::Error),
+}
+
+/// The SHA-256 hash of a DER encoded certificate, as shown by e.g. `openssl x509 -noout -fingerprint -sha256`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CertificateFingerprint([u8; Self::LENGTH]);
+
+impl CertificateFingerprint {
+    /// The length of a fingerprint in bytes.
+    pub const LENGTH: usize = 32;
+
+    /// Creates a new [`CertificateFingerprint`] from its bytes.
+    pub fn new(bytes: [u8; Self::LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Computes the fingerprint of a DER encoded certificate.
+    pub fn from_der(certificate: &[u8]) -> Self {
+        Self(Sha256::digest(certificate).into())
+    }
+
+    /// Returns the bytes of the fingerprint.
+    pub fn as_bytes(&self) -> &[u8; Self::LENGTH] {
+        &self.0
+    }
+}
+
+impl core::str::FromStr for CertificateFingerprint {
+    type Err = TlsConfigError;
+
+    /// Parses prefix hex or colon separated hex, like `AB:CD:...` in the output of openssl.
+    fn from_str(fingerprint: &str) -> Result<Self, Self::Err> {
+        let hex = fingerprint.replace(':', "").to_ascii_lowercase();
+        let hex = hex.strip_prefix("0x").unwrap_or(&hex);
+        let mut bytes = [0; Self::LENGTH];
+
+        hex::decode_to_slice(hex, &mut bytes)
+            .map_err(|_| TlsConfigError::InvalidFingerprint(fingerprint.to_string()))?;
+
+        Ok(Self(bytes))
+    }
+}
+
+impl TryFrom<String> for CertificateFingerprint {
+    type Error = TlsConfigError;
+
+    fn try_from(fingerprint: String) -> Result<Self, Self::Error> {
+        fingerprint.parse()
+    }
+}
+
+impl From<CertificateFingerprint> for String {
+    fn from(fingerprint: CertificateFingerprint) -> Self {
+        fingerprint.to_string()
+    }
+}
+
+impl core::fmt::Display for CertificateFingerprint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", prefix_hex::encode(self.0))
+    }
+}
+
+/// The TLS configuration of all connections to nodes, set with
+/// [`ClientBuilder::with_tls_config()`](crate::client::ClientBuilder::with_tls_config).
+///
+/// By default the certificates of nodes are verified with the built-in webpki roots. Custom CA certificates are
+/// trusted in addition to them, or instead of them if the default roots are disabled.
+///
+/// If certificates are pinned, a node is only trusted if it presents one of them: a pinned end-entity certificate,
+/// which can also be self-signed, is trusted directly, a pinned intermediate certificate only in a chain that is valid
+/// for the trusted roots.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsConfig {
+    /// PEM encoded CA certificates to trust.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) ca_certificates: Vec<String>,
+    /// Whether the built-in webpki roots are trusted.
+    #[serde(default = "default_use_default_roots")]
+    pub(crate) use_default_roots: bool,
+    /// Fingerprints of pinned certificates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) pinned_certificates: Vec<CertificateFingerprint>,
+}
+
+fn default_use_default_roots() -> bool {
+    true
+}
+
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self {
+            ca_certificates: Vec::new(),
+            use_default_roots: default_use_default_roots(),
+            pinned_certificates: Vec::new(),
+        }
+    }
+}
+
+impl TlsConfig {
+    /// Creates the default TLS configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trusts the CA certificates of a PEM bundle.
+    pub fn with_ca_certificate(mut self, ca_certificate: impl Into<String>) -> Self {
+        self.ca_certificates.push(ca_certificate.into());
+        self
+    }
+
+    /// Sets whether the built-in webpki roots are trusted.
+    pub fn with_default_roots(mut self, use_default_roots: bool) -> Self {
+        self.use_default_roots = use_default_roots;
+        self
+    }
+
+    /// Pins a certificate by its fingerprint.
+    pub fn with_pinned_certificate(mut self, fingerprint: CertificateFingerprint) -> Self {
+        self.pinned_certificates.push(fingerprint);
+        self
+    }
+
+    /// Returns the PEM encoded CA certificates.
+    pub fn ca_certificates(&self) -> &[String] {
+        &self.ca_certificates
+    }
+
+    /// Returns whether the built-in webpki roots are trusted.
+    pub fn use_default_roots(&self) -> bool {
+        self.use_default_roots
+    }
+
+    /// Returns the fingerprints of the pinned certificates.
+    pub fn pinned_certificates(&self) -> &[CertificateFingerprint] {
+        &self.pinned_certificates
+    }
+
+    /// Validates the configuration, which is otherwise only done when the client is built.
+    pub fn validate(&self) -> Result<(), TlsConfigError> {
+        self.client_config_builder().map(|_| ())
+    }
+
+    /// Returns a rustls config builder that verifies the server certificates, to which the client authentication
+    /// still needs to be added.
+    pub(crate) fn client_config_builder(&self) -> Result<ConfigBuilder<ClientConfig, WantsClientCert>, TlsConfigError> {
+        let mut roots = RootCertStore::empty();
+
+        if self.use_default_roots {
+            roots.add_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.iter().map(|trust_anchor| {
+                OwnedTrustAnchor::from_subject_spki_name_constraints(
+                    trust_anchor.subject,
+                    trust_anchor.spki,
+                    trust_anchor.name_constraints,
+                )
+            }));
+        }
+        for ca_certificate in &self.ca_certificates {
+            for certificate in parse_certificates(ca_certificate)? {
+                roots.add(&certificate)?;
+            }
+        }
+
+        if roots.is_empty() && self.pinned_certificates.is_empty() {
+            return Err(TlsConfigError::NoTrustedCertificates);
+        }
+
+        let verifier = WebPkiVerifier::new(roots, None);
+        let verifier: Arc<dyn ServerCertVerifier> = if self.pinned_certificates.is_empty() {
+            Arc::new(verifier)
+        } else {
+            Arc::new(PinnedCertificateVerifier {
+                pinned_certificates: self.pinned_certificates.clone(),
+                verifier,
+            })
+        };
+
+        Ok(ClientConfig::builder()
+            .with_safe_defaults()
+            .with_custom_certificate_verifier(verifier))
+    }
+}
+
+/// Parses the certificates of a PEM bundle.
+pub(crate) fn parse_certificates(pem: &str) -> Result<Vec<Certificate>, TlsConfigError> {
+    let certificates = rustls_pemfile::certs(&mut pem.as_bytes())?;
+
+    if certificates.is_empty() {
+        return Err(TlsConfigError::MissingCertificate);
+    }
+
+    Ok(certificates.into_iter().map(Certificate).collect())
+}
+
+struct PinnedCertificateVerifier {
+    pinned_certificates: Vec<CertificateFingerprint>,
+    verifier: WebPkiVerifier,
+}
+
+impl PinnedCertificateVerifier {
+    fn is_pinned(&self, certificate: &Certificate) -> bool {
+        self.pinned_certificates
+            .contains(&CertificateFingerprint::from_der(&certificate.0))
+    }
+}
+
+impl ServerCertVerifier for PinnedCertificateVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        server_name: &ServerName,
+        scts: &mut dyn Iterator<Item = &[u8]>,
+        ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if self.is_pinned(end_entity) {
+            return Ok(ServerCertVerified::assertion());
+        }
+
+        self.verifier
+            .verify_server_cert(end_entity, intermediates, server_name, scts, ocsp_response, now)?;
+
+        if intermediates.iter().any(|certificate| self.is_pinned(certificate)) {
+            Ok(ServerCertVerified::assertion())
+        } else {
+            Err(rustls::Error::General(
+                "the certificate chain doesn't contain a pinned certificate".to_string(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Refreshing of the cached protocol parameters, so long-running clients keep working across protocol upgrades.
+//!
+//! The node syncing already updates the protocol parameters every node sync interval, a refresh configured with
+//! [`ClientBuilder::with_protocol_parameters_refresh()`] does it more often or on every milestone.
+//!
+//! [`ClientBuilder::with_protocol_parameters_refresh()`]: crate::client::ClientBuilder::with_protocol_parameters_refresh
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{ClientInner, Result},
+    types::block::protocol::ProtocolParameters,
+};
+
+/// When the protocol parameters are refreshed in the background.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ProtocolParametersRefresh {
+    /// Request the node info at an interval.
+    #[serde(rename_all = "camelCase")]
+    Interval { interval: Duration },
+    /// Request the node info when a new milestone is received over MQTT.
+    #[cfg(feature = "mqtt")]
+    OnMilestone,
+}
+
+/// The cached protocol parameters changed, e.g. because pending protocol parameters activated at their target
+/// milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParametersUpdate {
+    /// The protocol parameters before the update.
+    pub previous: ProtocolParameters,
+    /// The protocol parameters after the update.
+    pub current: ProtocolParameters,
+}
+
+impl ClientInner {
+    /// Subscribes to [`ProtocolParametersUpdate`]s, sent whenever the cached protocol parameters change.
+    pub fn subscribe_protocol_parameters_updates(&self) -> tokio::sync::broadcast::Receiver<ProtocolParametersUpdate> {
+        self.protocol_parameters_sender.subscribe()
+    }
+
+    /// Requests the node info and updates the cached protocol parameters with the ones of the node.
+    pub async fn refresh_protocol_parameters(&self) -> Result<ProtocolParameters> {
+        let info = self.get_info().await?.node_info;
+
+        self.update_protocol_parameters(info.protocol.clone(), info.status.latest_milestone.timestamp)
+            .await;
+
+        Ok(info.protocol)
+    }
+
+    /// Swaps the cached protocol parameters and sends an update if they changed.
+    pub(crate) async fn update_protocol_parameters(
+        &self,
+        protocol_parameters: ProtocolParameters,
+        latest_milestone_timestamp: Option<u32>,
+    ) {
+        let mut network_info = self.network_info.write().await;
+
+        network_info.latest_milestone_timestamp = latest_milestone_timestamp;
+        if network_info.protocol_parameters != protocol_parameters {
+            let previous = core::mem::replace(&mut network_info.protocol_parameters, protocol_parameters.clone());
+            log::debug!(
+                "[update_protocol_parameters] protocol version {} replaced by {}",
+                previous.protocol_version(),
+                protocol_parameters.protocol_version()
+            );
+            // There may be no subscribers.
+            self.protocol_parameters_sender
+                .send(ProtocolParametersUpdate {
+                    previous,
+                    current: protocol_parameters,
+                })
+                .ok();
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl crate::client::Client {
+    /// Refreshes the protocol parameters until the client is dropped.
+    pub(crate) async fn start_protocol_parameters_refresh(self, refresh: ProtocolParametersRefresh) {
+        match refresh {
+            ProtocolParametersRefresh::Interval { interval } => loop {
+                tokio::time::sleep(interval).await;
+                self.try_refresh_protocol_parameters().await;
+            },
+            #[cfg(feature = "mqtt")]
+            ProtocolParametersRefresh::OnMilestone => {
+                use std::sync::Arc;
+
+                use crate::client::mqtt::Topic;
+
+                let milestone = Arc::new(tokio::sync::Notify::new());
+                let milestone_ = milestone.clone();
+                let topic = Topic::new("milestone-info/latest").expect("valid topic");
+
+                if let Err(err) = self.subscribe([topic], move |_| milestone_.notify_one()).await {
+                    log::warn!("Subscribing to milestones for the protocol parameters refresh failed: {err}");
+                    return;
+                }
+                loop {
+                    milestone.notified().await;
+                    self.try_refresh_protocol_parameters().await;
+                }
+            }
+        }
+    }
+
+    async fn try_refresh_protocol_parameters(&self) {
+        if let Err(err) = self.refresh_protocol_parameters().await {
+            log::warn!("Refreshing the protocol parameters failed: {err}");
+        }
+    }
+}
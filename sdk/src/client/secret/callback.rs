@@ -0,0 +1,272 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`CallbackSecretManager`].
+
+use std::{future::Future, ops::Range, sync::Arc};
+
+use async_trait::async_trait;
+use crypto::{
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+use futures::future::BoxFuture;
+
+use super::{GenerateAddressOptions, SecretManage};
+use crate::{
+    client::{api::PreparedTransactionData, Error},
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+
+type GenerateAddressesCallback = Arc<
+    dyn Fn(
+            u32,
+            u32,
+            Range<u32>,
+            GenerateAddressOptions,
+        ) -> BoxFuture<'static, crate::client::Result<Vec<Ed25519Address>>>
+        + Send
+        + Sync,
+>;
+type SignEd25519Callback =
+    Arc<dyn Fn(Vec<u8>, Bip44) -> BoxFuture<'static, crate::client::Result<Ed25519Signature>> + Send + Sync>;
+
+/// Secret manager that delegates address generation and Ed25519 signing to async callbacks, e.g. to integrate a remote
+/// KMS or HSM. Callbacks can report failures with [`Error::RemoteSigner`].
+#[derive(Clone)]
+pub struct CallbackSecretManager {
+    generate_ed25519_addresses: GenerateAddressesCallback,
+    sign_ed25519: SignEd25519Callback,
+}
+
+impl std::fmt::Debug for CallbackSecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("CallbackSecretManager").finish()
+    }
+}
+
+impl CallbackSecretManager {
+    /// Creates a new [`CallbackSecretManager`].
+    ///
+    /// `generate_ed25519_addresses` is called with the coin type, account index, address indexes and options and must
+    /// return one address per index. `sign_ed25519` is called with the message and the [`Bip44`] chain of the address
+    /// that needs to sign it, the signature is rejected if its public key doesn't belong to that address.
+    pub fn new<G, GF, S, SF>(generate_ed25519_addresses: G, sign_ed25519: S) -> Self
+    where
+        G: Fn(u32, u32, Range<u32>, GenerateAddressOptions) -> GF + Send + Sync + 'static,
+        GF: Future<Output = crate::client::Result<Vec<Ed25519Address>>> + Send + 'static,
+        S: Fn(Vec<u8>, Bip44) -> SF + Send + Sync + 'static,
+        SF: Future<Output = crate::client::Result<Ed25519Signature>> + Send + 'static,
+    {
+        Self {
+            generate_ed25519_addresses: Arc::new(move |coin_type, account_index, address_indexes, options| {
+                Box::pin(generate_ed25519_addresses(
+                    coin_type,
+                    account_index,
+                    address_indexes,
+                    options,
+                ))
+            }),
+            sign_ed25519: Arc::new(move |msg, chain| Box::pin(sign_ed25519(msg, chain))),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretManage for CallbackSecretManager {
+    type Error = Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        let expected = address_indexes.len();
+        let addresses = (self.generate_ed25519_addresses)(
+            coin_type,
+            account_index,
+            address_indexes,
+            options.into().unwrap_or_default(),
+        )
+        .await?;
+
+        if addresses.len() != expected {
+            return Err(Error::RemoteSigner(format!(
+                "expected {expected} addresses, got {}",
+                addresses.len()
+            )));
+        }
+
+        Ok(addresses)
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::SecretManagerMismatch)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        let signature = (self.sign_ed25519)(msg.to_vec(), chain).await?;
+
+        // Don't trust the remote signer, an invalid signature or one of another key would only be noticed by the node
+        let address = self
+            .generate_ed25519_addresses(
+                chain.coin_type,
+                chain.account,
+                chain.address_index..chain.address_index + 1,
+                GenerateAddressOptions {
+                    internal: chain.change == 1,
+                    ..Default::default()
+                },
+            )
+            .await?[0];
+        signature
+            .is_valid(msg, &address)
+            .map_err(|err| Error::RemoteSigner(format!("invalid Ed25519 signature: {err}")))?;
+
+        Ok(signature)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        Err(Error::SecretManagerMismatch)
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        super::default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{constants::IOTA_COIN_TYPE, secret::mnemonic::MnemonicSecretManager};
+
+    #[tokio::test]
+    async fn delegates_to_callbacks() {
+        let mnemonic = Arc::new(
+            MnemonicSecretManager::try_from_mnemonic(
+                "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned(),
+            )
+            .unwrap(),
+        );
+
+        let generator = mnemonic.clone();
+        let signer = mnemonic.clone();
+        let secret_manager = CallbackSecretManager::new(
+            move |coin_type, account_index, address_indexes, options| {
+                let generator = generator.clone();
+                async move {
+                    generator
+                        .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                        .await
+                }
+            },
+            move |msg, chain| {
+                let signer = signer.clone();
+                async move { signer.sign_ed25519(&msg, chain).await }
+            },
+        );
+
+        let addresses = secret_manager
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            addresses,
+            mnemonic
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, None)
+                .await
+                .unwrap()
+        );
+
+        let signature = secret_manager
+            .sign_ed25519(b"message", Bip44::new(IOTA_COIN_TYPE))
+            .await
+            .unwrap();
+        assert!(signature.try_verify(b"message").unwrap());
+    }
+
+    #[tokio::test]
+    async fn rejects_signatures_of_other_keys() {
+        let mnemonic = Arc::new(
+            MnemonicSecretManager::try_from_mnemonic(
+                "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_owned(),
+            )
+            .unwrap(),
+        );
+
+        let generator = mnemonic.clone();
+        let signer = mnemonic.clone();
+        let secret_manager = CallbackSecretManager::new(
+            move |coin_type, account_index, address_indexes, options| {
+                let generator = generator.clone();
+                async move {
+                    generator
+                        .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                        .await
+                }
+            },
+            // Signs with the key of the next address.
+            move |msg, chain| {
+                let signer = signer.clone();
+                async move {
+                    signer
+                        .sign_ed25519(&msg, chain.with_address_index(chain.address_index + 1))
+                        .await
+                }
+            },
+        );
+
+        assert!(matches!(
+            secret_manager
+                .sign_ed25519(b"message", Bip44::new(IOTA_COIN_TYPE))
+                .await,
+            Err(Error::RemoteSigner(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_remote_results() {
+        let secret_manager = CallbackSecretManager::new(
+            |_, _, _, _| async { Ok(Vec::new()) },
+            |_, _| async { Err(Error::RemoteSigner("unavailable".to_string())) },
+        );
+
+        assert!(matches!(
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await,
+            Err(Error::RemoteSigner(_))
+        ));
+        assert!(matches!(
+            secret_manager
+                .sign_ed25519(b"message", Bip44::new(IOTA_COIN_TYPE))
+                .await,
+            Err(Error::RemoteSigner(_))
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encoding of [EIP-712] typed structured data, to be signed with
+//! [`SecretManage::sign_typed_data()`](super::SecretManage::sign_typed_data()).
+//!
+//! [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crypto::hashes::keccak::{keccak256 as keccak256_into, KECCAK256_LEN};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// EIP-712 errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// A type that is neither atomic, dynamic, an array nor defined in the types
+    #[error("invalid EIP-712 type `{0}`")]
+    InvalidType(String),
+    /// A value that doesn't match its type
+    #[error("invalid EIP-712 value for `{name}` of type `{ty}`")]
+    InvalidValue {
+        /// The name of the field.
+        name: String,
+        /// The type of the field.
+        ty: String,
+    },
+    /// A field of a struct without value
+    #[error("missing EIP-712 value for `{0}`")]
+    MissingValue(String),
+    /// The types don't have exactly one type that isn't referenced by another type
+    #[error("EIP-712 types need exactly one primary type, found {0:?}")]
+    PrimaryType(Vec<String>),
+}
+
+/// The domain of EIP-712 typed data, only the set fields are part of the domain separator.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip712Domain {
+    /// The name of the signing domain, e.g. the name of the dApp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The version of the signing domain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// The EIP-155 chain id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain_id: Option<u64>,
+    /// The hex encoded address of the contract that verifies the signature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verifying_contract: Option<String>,
+    /// A hex encoded 32 bytes salt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<String>,
+}
+
+impl Eip712Domain {
+    fn fields(&self) -> Vec<Eip712Field> {
+        [
+            (self.name.is_some(), "name", "string"),
+            (self.version.is_some(), "version", "string"),
+            (self.chain_id.is_some(), "chainId", "uint256"),
+            (self.verifying_contract.is_some(), "verifyingContract", "address"),
+            (self.salt.is_some(), "salt", "bytes32"),
+        ]
+        .into_iter()
+        .filter(|(is_set, ..)| *is_set)
+        .map(|(_, name, ty)| Eip712Field {
+            name: name.to_owned(),
+            ty: ty.to_owned(),
+        })
+        .collect()
+    }
+}
+
+/// A field of an EIP-712 struct type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Eip712Field {
+    /// The name of the field.
+    pub name: String,
+    /// The type of the field, e.g. `address`, `uint256`, `string[]` or the name of another struct type.
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// The struct types of EIP-712 typed data by name. An `EIP712Domain` type is ignored, as it's derived from the
+/// [`Eip712Domain`].
+pub type Eip712Types = BTreeMap<String, Vec<Eip712Field>>;
+
+/// Returns the bytes that are hashed with Keccak-256 and signed, `0x1901 || domainSeparator || hashStruct(message)`.
+pub fn signing_payload(domain: &Eip712Domain, types: &Eip712Types, message: &Value) -> Result<Vec<u8>, Error> {
+    let mut types = types.clone();
+    types.insert(DOMAIN_TYPE.to_owned(), domain.fields());
+    let domain = serde_json::to_value(domain).expect("domain serialization can't fail");
+
+    let primary_type = primary_type(&types)?;
+    let encoder = Encoder { types: &types };
+
+    let mut payload = vec![0x19, 0x01];
+    payload.extend(encoder.hash_struct(DOMAIN_TYPE, &domain)?);
+    payload.extend(encoder.hash_struct(&primary_type, message)?);
+
+    Ok(payload)
+}
+
+/// Returns the EIP-712 digest of the typed data, which is what gets signed.
+pub fn hash_typed_data(domain: &Eip712Domain, types: &Eip712Types, message: &Value) -> Result<[u8; 32], Error> {
+    Ok(keccak256(signing_payload(domain, types, message)?))
+}
+
+/// Returns the Keccak-256 hash of the data.
+pub(crate) fn keccak256(data: impl AsRef<[u8]>) -> [u8; KECCAK256_LEN] {
+    let mut digest = [0; KECCAK256_LEN];
+    keccak256_into(data.as_ref(), &mut digest);
+    digest
+}
+
+// The primary type is the only type that isn't referenced by another one.
+fn primary_type(types: &Eip712Types) -> Result<String, Error> {
+    let referenced = types
+        .values()
+        .flatten()
+        .map(|field| base_type(&field.ty))
+        .collect::<BTreeSet<_>>();
+    let candidates = types
+        .keys()
+        .filter(|name| name.as_str() != DOMAIN_TYPE && !referenced.contains(name.as_str()))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    match <[String; 1]>::try_from(candidates) {
+        Ok([primary_type]) => Ok(primary_type),
+        Err(candidates) => Err(Error::PrimaryType(candidates)),
+    }
+}
+
+// Strips the array suffixes of a type, `Person[][2]` -> `Person`.
+fn base_type(ty: &str) -> &str {
+    ty.split('[').next().unwrap_or(ty)
+}
+
+struct Encoder<'a> {
+    types: &'a Eip712Types,
+}
+
+impl Encoder<'_> {
+    fn fields(&self, ty: &str) -> Result<&[Eip712Field], Error> {
+        self.types
+            .get(ty)
+            .map(Vec::as_slice)
+            .ok_or_else(|| Error::InvalidType(ty.to_owned()))
+    }
+
+    fn collect_dependencies<'b>(&'b self, ty: &'b str, dependencies: &mut BTreeSet<&'b str>) -> Result<(), Error> {
+        if dependencies.insert(ty) {
+            for field in self.fields(ty)? {
+                let field_type = base_type(&field.ty);
+                if self.types.contains_key(field_type) {
+                    self.collect_dependencies(field_type, dependencies)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // `Mail(Person from,Person to,string contents)Person(string name,address wallet)`
+    fn encode_type(&self, ty: &str) -> Result<String, Error> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(ty, &mut dependencies)?;
+        dependencies.remove(ty);
+
+        let mut encoded = String::new();
+        for dependency in std::iter::once(ty).chain(dependencies) {
+            let fields = self
+                .fields(dependency)?
+                .iter()
+                .map(|field| format!("{} {}", field.ty, field.name))
+                .collect::<Vec<_>>();
+            encoded.push_str(&format!("{dependency}({})", fields.join(",")));
+        }
+
+        Ok(encoded)
+    }
+
+    fn hash_struct(&self, ty: &str, value: &Value) -> Result<[u8; 32], Error> {
+        let mut encoded = keccak256(self.encode_type(ty)?).to_vec();
+
+        for field in self.fields(ty)? {
+            let field_value = value
+                .get(&field.name)
+                .ok_or_else(|| Error::MissingValue(format!("{ty}.{}", field.name)))?;
+            encoded.extend(self.encode_value(&field.name, &field.ty, field_value)?);
+        }
+
+        Ok(keccak256(encoded))
+    }
+
+    fn encode_value(&self, name: &str, ty: &str, value: &Value) -> Result<[u8; 32], Error> {
+        let invalid_value = || Error::InvalidValue {
+            name: name.to_owned(),
+            ty: ty.to_owned(),
+        };
+
+        // Arrays are encoded as the hash of the concatenated encodings of their items.
+        if let Some((item_type, length)) = ty.strip_suffix(']').and_then(|ty| ty.rsplit_once('[')) {
+            let items = value.as_array().ok_or_else(invalid_value)?;
+            if !length.is_empty() && length.parse::<usize>().ok() != Some(items.len()) {
+                return Err(invalid_value());
+            }
+
+            let mut encoded = Vec::with_capacity(items.len() * 32);
+            for item in items {
+                encoded.extend(self.encode_value(name, item_type, item)?);
+            }
+            return Ok(keccak256(encoded));
+        }
+
+        if self.types.contains_key(ty) {
+            return self.hash_struct(ty, value);
+        }
+
+        let mut word = [0; 32];
+        match ty {
+            "string" => {
+                word = keccak256(value.as_str().ok_or_else(invalid_value)?);
+            }
+            "bytes" => {
+                word = keccak256(decode_hex(value).ok_or_else(invalid_value)?);
+            }
+            "bool" => {
+                word[31] = value.as_bool().ok_or_else(invalid_value)? as u8;
+            }
+            "address" => {
+                let address = decode_hex(value)
+                    .filter(|bytes| bytes.len() == 20)
+                    .ok_or_else(invalid_value)?;
+                word[12..].copy_from_slice(&address);
+            }
+            _ => {
+                if let Some(size) = ty.strip_prefix("bytes") {
+                    let size = parse_size(size, 1, 32, 1).ok_or_else(|| Error::InvalidType(ty.to_owned()))?;
+                    let bytes = decode_hex(value)
+                        .filter(|bytes| bytes.len() == size)
+                        .ok_or_else(invalid_value)?;
+                    word[..size].copy_from_slice(&bytes);
+                } else if let Some(bits) = ty.strip_prefix("uint") {
+                    let bits = parse_size(bits, 8, 256, 8).ok_or_else(|| Error::InvalidType(ty.to_owned()))?;
+                    let (negative, number) = parse_integer(value).ok_or_else(invalid_value)?;
+                    if negative || number.bits() > bits {
+                        return Err(invalid_value());
+                    }
+                    number.to_big_endian(&mut word);
+                } else if let Some(bits) = ty.strip_prefix("int") {
+                    let bits = parse_size(bits, 8, 256, 8).ok_or_else(|| Error::InvalidType(ty.to_owned()))?;
+                    let (negative, number) = parse_integer(value).ok_or_else(invalid_value)?;
+                    // The magnitude of a negative number can be one more than the max positive one.
+                    let limit = U256::one() << (bits - 1);
+                    if (!negative && number >= limit) || (negative && number > limit) {
+                        return Err(invalid_value());
+                    }
+                    // Two's complement, sign extended to 256 bits.
+                    let number = if negative {
+                        (!number).overflowing_add(U256::one()).0
+                    } else {
+                        number
+                    };
+                    number.to_big_endian(&mut word);
+                } else {
+                    return Err(Error::InvalidType(ty.to_owned()));
+                }
+            }
+        }
+
+        Ok(word)
+    }
+}
+
+// Parses the size of a `bytesN`, `uintN` or `intN` type, `uint` and `int` are aliases of `uint256` and `int256`.
+fn parse_size(size: &str, min: usize, max: usize, step: usize) -> Option<usize> {
+    if size.is_empty() && max == 256 {
+        return Some(max);
+    }
+    size.parse::<usize>()
+        .ok()
+        .filter(|size| (min..=max).contains(size) && size % step == 0)
+}
+
+fn decode_hex(value: &Value) -> Option<Vec<u8>> {
+    prefix_hex::decode::<Vec<u8>>(value.as_str()?).ok()
+}
+
+// Integers can be JSON numbers, decimal strings or hex strings, returns the sign and the magnitude.
+fn parse_integer(value: &Value) -> Option<(bool, U256)> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .map(|number| (false, U256::from(number)))
+            .or_else(|| number.as_i64().map(|number| (true, U256::from(number.unsigned_abs())))),
+        Value::String(string) => {
+            let (negative, magnitude) = string
+                .strip_prefix('-')
+                .map_or((false, string.as_str()), |magnitude| (true, magnitude));
+            let number = match magnitude.strip_prefix("0x") {
+                Some(hex) => U256::from_str_radix(hex, 16).ok()?,
+                None => U256::from_dec_str(magnitude).ok()?,
+            };
+            Some((negative && !number.is_zero(), number))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    // The example of the EIP.
+    fn mail() -> (Eip712Domain, Eip712Types, Value) {
+        let domain = Eip712Domain {
+            name: Some("Ether Mail".to_owned()),
+            version: Some("1".to_owned()),
+            chain_id: Some(1),
+            verifying_contract: Some("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC".to_owned()),
+            salt: None,
+        };
+        let types = serde_json::from_value(json!({
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        }))
+        .unwrap();
+        let message = json!({
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        });
+
+        (domain, types, message)
+    }
+
+    #[test]
+    fn eip_example() {
+        let (domain, types, message) = mail();
+
+        let payload = signing_payload(&domain, &types, &message).unwrap();
+        assert_eq!(
+            prefix_hex::encode(&payload[2..34]),
+            "0xf2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+        assert_eq!(
+            prefix_hex::encode(&payload[34..]),
+            "0xc52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            prefix_hex::encode(hash_typed_data(&domain, &types, &message).unwrap()),
+            "0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+
+    #[test]
+    fn encode_type() {
+        let (_, types, _) = mail();
+
+        assert_eq!(
+            Encoder { types: &types }.encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+    }
+
+    #[test]
+    fn invalid_values() {
+        let (domain, types, mut message) = mail();
+
+        message["from"]["wallet"] = json!("0x1234");
+        assert!(matches!(
+            signing_payload(&domain, &types, &message),
+            Err(Error::InvalidValue { name, .. }) if name == "wallet"
+        ));
+
+        message.as_object_mut().unwrap().remove("from");
+        assert!(matches!(
+            signing_payload(&domain, &types, &message),
+            Err(Error::MissingValue(field)) if field == "Mail.from"
+        ));
+    }
+
+    #[test]
+    fn integers() {
+        let types = Eip712Types::new();
+        let encoder = Encoder { types: &types };
+
+        assert_eq!(encoder.encode_value("n", "int8", &json!(-1)).unwrap(), [0xff; 32]);
+        assert_eq!(encoder.encode_value("n", "int256", &json!("-0x1")).unwrap(), [0xff; 32]);
+        let mut word = [0; 32];
+        word[31] = 200;
+        assert_eq!(encoder.encode_value("n", "uint8", &json!("200")).unwrap(), word);
+
+        assert!(encoder.encode_value("n", "uint8", &json!(256)).is_err());
+        assert!(encoder.encode_value("n", "int8", &json!(128)).is_err());
+        assert!(encoder.encode_value("n", "int8", &json!(-129)).is_err());
+        assert!(encoder.encode_value("n", "uint", &json!(-1)).is_err());
+        assert!(encoder.encode_value("n", "uint7", &json!(1)).is_err());
+    }
+}
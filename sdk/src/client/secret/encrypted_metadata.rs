@@ -0,0 +1,157 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encryption of [`MetadataFeature`] payloads to the X25519 key of a recipient, to attach private messages to outputs.
+//!
+//! The X25519 key of a chain is derived from its Ed25519 key, see
+//! [`SecretManage::derive_x25519_public_key()`](super::SecretManage::derive_x25519_public_key()), so the recipient
+//! has to share its X25519 public key with the sender once. The payload is encrypted ECIES-style: a new ephemeral key
+//! is generated for every payload and the key of the XChaCha20-Poly1305 cipher is derived from the shared secret with
+//! the recipient key. The encrypted payload is `version || ephemeral public key || nonce || tag || ciphertext`.
+//!
+//! X25519 keys are supported by the [`MnemonicSecretManager`](super::mnemonic::MnemonicSecretManager), the
+//! `EncryptedMnemonicSecretManager` and the [`RouterSecretManager`](super::router::RouterSecretManager) if the
+//! routed secret manager supports them. Stronghold and Ledger Nano don't expose the Ed25519 secret key, so they and
+//! all other secret managers return [`Error::Unsupported`].
+
+use crypto::{
+    ciphers::chacha,
+    hashes::{blake2b::Blake2b256, sha::Sha512, Digest},
+    keys::{bip44::Bip44, x25519},
+    signatures::ed25519,
+};
+use zeroize::{Zeroize, Zeroizing};
+
+use super::SecretManage;
+use crate::types::block::output::feature::MetadataFeature;
+
+/// The version of the encrypted payload.
+pub const ENCRYPTED_METADATA_VERSION: u8 = 1;
+
+// Domain separation of the key derivation.
+const KEY_DERIVATION_CONTEXT: &[u8] = b"IOTA encrypted metadata";
+// The version byte and the ephemeral public key.
+const HEADER_LENGTH: usize = 1 + x25519::PUBLIC_KEY_LENGTH;
+
+/// Encrypted metadata errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The payload can't be decrypted with the key of the chain
+    #[error("encrypted metadata can't be decrypted")]
+    Decryption,
+    /// The payload is too short to be encrypted metadata
+    #[error("invalid encrypted metadata length {0}")]
+    InvalidLength(usize),
+    /// The secret manager can't derive X25519 keys
+    #[error("X25519 keys are not supported by the secret manager")]
+    Unsupported,
+    /// The payload has a version that isn't supported
+    #[error("unsupported encrypted metadata version {0}")]
+    UnsupportedVersion(u8),
+}
+
+/// Converts an Ed25519 secret key to the X25519 secret key of the same curve point, like libsodium does.
+pub(crate) fn x25519_secret_key(secret_key: &ed25519::SecretKey) -> x25519::SecretKey {
+    let mut hash = Sha512::digest(secret_key.as_slice());
+    let mut bytes = Zeroizing::new([0u8; x25519::SECRET_KEY_LENGTH]);
+    bytes.copy_from_slice(&hash[..x25519::SECRET_KEY_LENGTH]);
+    hash.as_mut_slice().zeroize();
+
+    // The scalar is clamped by the Diffie-Hellman function.
+    x25519::SecretKey::from_bytes(*bytes)
+}
+
+fn cipher_key(shared_secret: &[u8; 32], ephemeral_public_key: &x25519::PublicKey) -> Zeroizing<[u8; 32]> {
+    Zeroizing::new(
+        Blake2b256::new()
+            .chain_update(KEY_DERIVATION_CONTEXT)
+            .chain_update(shared_secret)
+            .chain_update(ephemeral_public_key.to_bytes())
+            .finalize()
+            .into(),
+    )
+}
+
+/// Encrypts `plaintext` to the X25519 public key of the recipient, returning the [`MetadataFeature`] to add to an
+/// output.
+pub fn encrypt_metadata(
+    recipient_public_key: &x25519::PublicKey,
+    plaintext: &[u8],
+) -> Result<MetadataFeature, crate::client::Error> {
+    let ephemeral_secret_key = x25519::SecretKey::generate()?;
+    let ephemeral_public_key = ephemeral_secret_key.public_key();
+    let shared_secret = Zeroizing::new(ephemeral_secret_key.diffie_hellman(recipient_public_key).to_bytes());
+    let key = cipher_key(&shared_secret, &ephemeral_public_key);
+
+    let mut payload = Vec::with_capacity(HEADER_LENGTH);
+    payload.push(ENCRYPTED_METADATA_VERSION);
+    payload.extend_from_slice(&ephemeral_public_key.to_bytes());
+    payload.extend(chacha::aead_encrypt(key.as_ref(), plaintext)?);
+
+    Ok(MetadataFeature::new(payload)?)
+}
+
+/// Decrypts a [`MetadataFeature`] that was encrypted with [`encrypt_metadata()`] to the X25519 key of `chain`.
+pub async fn decrypt_metadata<S: SecretManage>(
+    secret_manager: &S,
+    metadata: &MetadataFeature,
+    chain: Bip44,
+) -> Result<Zeroizing<Vec<u8>>, S::Error>
+where
+    S::Error: From<crate::client::Error>,
+{
+    let data = metadata.data();
+
+    if data.len() <= HEADER_LENGTH {
+        return Err(crate::client::Error::from(Error::InvalidLength(data.len())).into());
+    }
+    if data[0] != ENCRYPTED_METADATA_VERSION {
+        return Err(crate::client::Error::from(Error::UnsupportedVersion(data[0])).into());
+    }
+
+    let (ephemeral_public_key, ciphertext) = data[1..].split_at(x25519::PUBLIC_KEY_LENGTH);
+    let ephemeral_public_key =
+        x25519::PublicKey::try_from_slice(ephemeral_public_key).map_err(crate::client::Error::from)?;
+    let shared_secret = secret_manager
+        .x25519_diffie_hellman(&ephemeral_public_key, chain)
+        .await?;
+    let key = cipher_key(&shared_secret, &ephemeral_public_key);
+
+    Ok(Zeroizing::new(
+        chacha::aead_decrypt(key.as_ref(), ciphertext).map_err(|_| crate::client::Error::from(Error::Decryption))?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{constants::IOTA_COIN_TYPE, secret::mnemonic::MnemonicSecretManager};
+
+    const MNEMONIC: &str = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+
+    #[tokio::test]
+    async fn encrypt_and_decrypt_metadata() {
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(MNEMONIC.to_owned()).unwrap();
+        let chain = Bip44::new(IOTA_COIN_TYPE);
+        let other_chain = chain.with_address_index(1);
+        let public_key = secret_manager.derive_x25519_public_key(chain).await.unwrap();
+        let message = b"private message";
+
+        let metadata = encrypt_metadata(&public_key, message).unwrap();
+        assert_eq!(metadata.data()[0], ENCRYPTED_METADATA_VERSION);
+        // A new ephemeral key is used every time.
+        assert_ne!(metadata, encrypt_metadata(&public_key, message).unwrap());
+
+        let decrypted = decrypt_metadata(&secret_manager, &metadata, chain).await.unwrap();
+        assert_eq!(decrypted.as_slice(), message);
+
+        assert!(matches!(
+            decrypt_metadata(&secret_manager, &metadata, other_chain).await,
+            Err(crate::client::Error::EncryptedMetadata(Error::Decryption))
+        ));
+        assert!(matches!(
+            decrypt_metadata(&secret_manager, &MetadataFeature::new([1, 2, 3]).unwrap(), chain).await,
+            Err(crate::client::Error::EncryptedMetadata(Error::InvalidLength(3)))
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`EncryptedMnemonicSecretManager`].
+//!
+//! The mnemonic is encrypted with XChaCha20-Poly1305 under a key that's derived from the password with Argon2id, into
+//! an [`EncryptedMnemonic`] which the caller stores, e.g. in a file or in the wallet storage, so the secret manager
+//! doesn't need a file system and also works in wasm. The version, KDF parameters and salt are authenticated as
+//! associated data, so they can't be changed without the decryption failing. It's a lightweight alternative to
+//! Stronghold, but the seed is kept in plain memory while the secret manager is unlocked. The mnemonic, the password
+//! and the derived key are zeroized after use.
+
+use std::ops::Range;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use async_trait::async_trait;
+use crypto::{
+    ciphers::{chacha::XChaCha20Poly1305, traits::Aead},
+    keys::{bip39::Mnemonic, bip44::Bip44},
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::{
+    mnemonic::MnemonicSecretManager, types::EncryptedMnemonicDto, GenerateAddressOptions, SecretManage,
+    SecretManagerConfig,
+};
+use crate::{
+    client::{api::PreparedTransactionData, Password},
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+
+/// The version of the encrypted mnemonic format.
+pub const ENCRYPTED_MNEMONIC_VERSION: u8 = 1;
+
+const KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 16;
+
+/// Encrypted mnemonic errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Argon2 error
+    #[error("{0}")]
+    Argon2(#[from] argon2::Error),
+    /// The encrypted mnemonic can't be decrypted with the password
+    #[error("invalid password")]
+    InvalidPassword,
+    /// JSON error
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    /// The password wasn't provided
+    #[error("missing password")]
+    MissingPassword,
+    /// The encrypted mnemonic has an unsupported version
+    #[error("unsupported version {0}")]
+    UnsupportedVersion(u8),
+}
+
+/// The Argon2id parameters to derive the encryption key from the password.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Argon2idParams {
+    /// The memory cost in KiB.
+    pub memory_cost: u32,
+    /// The number of iterations.
+    pub time_cost: u32,
+    /// The degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for Argon2idParams {
+    fn default() -> Self {
+        Self {
+            memory_cost: Params::DEFAULT_M_COST,
+            time_cost: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl Argon2idParams {
+    fn derive_key(&self, password: &Password, salt: &[u8]) -> Result<Zeroizing<[u8; KEY_LENGTH]>, Error> {
+        let params = Params::new(self.memory_cost, self.time_cost, self.parallelism, Some(KEY_LENGTH))?;
+        let mut key = Zeroizing::new([0; KEY_LENGTH]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params).hash_password_into(
+            password.as_bytes(),
+            salt,
+            key.as_mut(),
+        )?;
+
+        Ok(key)
+    }
+}
+
+/// A mnemonic encrypted with a password. It's serialized as JSON to be stored by the caller, e.g. in a file.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedMnemonic {
+    version: u8,
+    kdf: Argon2idParams,
+    #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+    salt: Vec<u8>,
+    /// The nonce, tag and ciphertext of the mnemonic.
+    #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+    ciphertext: Vec<u8>,
+}
+
+impl EncryptedMnemonic {
+    /// Encrypts the mnemonic with the password, the key is derived with the given parameters and a new random salt.
+    pub fn encrypt(
+        mnemonic: &Mnemonic,
+        password: &Password,
+        params: Argon2idParams,
+    ) -> Result<Self, crate::client::Error> {
+        let mut encrypted = Self {
+            version: ENCRYPTED_MNEMONIC_VERSION,
+            kdf: params,
+            salt: rand::thread_rng().gen::<[u8; SALT_LENGTH]>().to_vec(),
+            ciphertext: Vec::new(),
+        };
+        let key = params.derive_key(password, &encrypted.salt)?;
+
+        let nonce = rand::thread_rng().gen::<[u8; XChaCha20Poly1305::NONCE_LENGTH]>();
+        let mut tag = [0; XChaCha20Poly1305::TAG_LENGTH];
+        let mut ciphertext = vec![0; mnemonic.len()];
+        XChaCha20Poly1305::try_encrypt(
+            key.as_ref(),
+            &nonce,
+            &encrypted.associated_data(),
+            mnemonic.as_bytes(),
+            &mut ciphertext,
+            &mut tag,
+        )?;
+        encrypted.ciphertext = [nonce.as_slice(), &tag, &ciphertext].concat();
+
+        Ok(encrypted)
+    }
+
+    /// Decrypts the mnemonic with the password.
+    pub fn decrypt(&self, password: &Password) -> Result<Mnemonic, crate::client::Error> {
+        if self.version != ENCRYPTED_MNEMONIC_VERSION {
+            return Err(Error::UnsupportedVersion(self.version).into());
+        }
+
+        let key = self.kdf.derive_key(password, &self.salt)?;
+
+        if self.ciphertext.len() < XChaCha20Poly1305::NONCE_LENGTH + XChaCha20Poly1305::TAG_LENGTH {
+            return Err(Error::InvalidPassword.into());
+        }
+        let (nonce, tag_and_ciphertext) = self.ciphertext.split_at(XChaCha20Poly1305::NONCE_LENGTH);
+        let (tag, ciphertext) = tag_and_ciphertext.split_at(XChaCha20Poly1305::TAG_LENGTH);
+        let mut mnemonic = Zeroizing::new(vec![0; ciphertext.len()]);
+        XChaCha20Poly1305::try_decrypt(
+            key.as_ref(),
+            nonce,
+            &self.associated_data(),
+            &mut mnemonic,
+            ciphertext,
+            tag,
+        )
+        .map_err(|_| Error::InvalidPassword)?;
+
+        Ok(Mnemonic::from(
+            std::str::from_utf8(&mnemonic)
+                .map_err(|e| crate::client::Error::InvalidMnemonic(e.to_string()))?
+                .to_owned(),
+        ))
+    }
+
+    /// Deserializes an encrypted mnemonic from JSON bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, crate::client::Error> {
+        Ok(serde_json::from_slice(bytes).map_err(Error::from)?)
+    }
+
+    /// Serializes the encrypted mnemonic to JSON bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, crate::client::Error> {
+        Ok(serde_json::to_vec(self).map_err(Error::from)?)
+    }
+
+    // The version, KDF parameters and salt, which are authenticated together with the ciphertext.
+    fn associated_data(&self) -> Vec<u8> {
+        let mut associated_data = vec![self.version];
+        associated_data.extend(self.kdf.memory_cost.to_le_bytes());
+        associated_data.extend(self.kdf.time_cost.to_le_bytes());
+        associated_data.extend(self.kdf.parallelism.to_le_bytes());
+        associated_data.extend(&self.salt);
+        associated_data
+    }
+}
+
+/// Secret manager that keeps the mnemonic encrypted with a password, see [`EncryptedMnemonic`].
+pub struct EncryptedMnemonicSecretManager {
+    encrypted_mnemonic: EncryptedMnemonic,
+    secret_manager: MnemonicSecretManager,
+}
+
+impl std::fmt::Debug for EncryptedMnemonicSecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EncryptedMnemonicSecretManager")
+            .field("encrypted_mnemonic", &self.encrypted_mnemonic)
+            .finish()
+    }
+}
+
+impl EncryptedMnemonicSecretManager {
+    /// Encrypts the mnemonic with the password. The caller has to store the
+    /// [`encrypted_mnemonic()`](Self::encrypted_mnemonic) to load it again.
+    pub fn create(
+        mnemonic: impl Into<Mnemonic>,
+        password: &Password,
+        params: Argon2idParams,
+    ) -> Result<Self, crate::client::Error> {
+        // Trim the mnemonic, in case it hasn't been, as otherwise the restored seed would be wrong.
+        let mnemonic = Mnemonic::from(mnemonic.into().trim().to_owned());
+        let encrypted_mnemonic = EncryptedMnemonic::encrypt(&mnemonic, password, params)?;
+
+        Ok(Self {
+            encrypted_mnemonic,
+            secret_manager: MnemonicSecretManager::try_from_mnemonic(mnemonic)?,
+        })
+    }
+
+    /// Decrypts the encrypted mnemonic with the password.
+    pub fn load(encrypted_mnemonic: EncryptedMnemonic, password: &Password) -> Result<Self, crate::client::Error> {
+        Ok(Self {
+            secret_manager: MnemonicSecretManager::try_from_mnemonic(encrypted_mnemonic.decrypt(password)?)?,
+            encrypted_mnemonic,
+        })
+    }
+
+    /// Clears the seed from memory, until the secret manager is unlocked again with the password.
+    pub fn lock(&self) {
+        self.secret_manager.lock()
+    }
+
+    /// Returns whether the seed is cleared from memory.
+    pub fn is_locked(&self) -> bool {
+        self.secret_manager.is_locked()
+    }
+
+    /// Decrypts the encrypted mnemonic with the password to unlock the secret manager.
+    pub fn unlock(&self, password: &Password) -> Result<(), crate::client::Error> {
+        self.secret_manager.unlock(self.encrypted_mnemonic.decrypt(password)?)
+    }
+
+    /// Encrypts the mnemonic with a new password, the current password is needed to decrypt it. The caller has to
+    /// replace the stored [`encrypted_mnemonic()`](Self::encrypted_mnemonic) afterwards.
+    pub fn change_password(
+        &mut self,
+        current_password: &Password,
+        new_password: &Password,
+        params: Argon2idParams,
+    ) -> Result<(), crate::client::Error> {
+        let mnemonic = self.encrypted_mnemonic.decrypt(current_password)?;
+        self.encrypted_mnemonic = EncryptedMnemonic::encrypt(&mnemonic, new_password, params)?;
+
+        Ok(())
+    }
+
+    /// Returns the encrypted mnemonic.
+    pub fn encrypted_mnemonic(&self) -> &EncryptedMnemonic {
+        &self.encrypted_mnemonic
+    }
+}
+
+#[async_trait]
+impl SecretManage for EncryptedMnemonicSecretManager {
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        self.secret_manager
+            .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+            .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        self.secret_manager
+            .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+            .await
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        self.secret_manager.sign_ed25519(msg, chain).await
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn derive_x25519_public_key(&self, chain: Bip44) -> Result<crypto::keys::x25519::PublicKey, Self::Error> {
+        self.secret_manager.derive_x25519_public_key(chain).await
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn x25519_diffie_hellman(
+        &self,
+        public_key: &crypto::keys::x25519::PublicKey,
+        chain: Bip44,
+    ) -> Result<Zeroizing<[u8; 32]>, Self::Error> {
+        self.secret_manager.x25519_diffie_hellman(public_key, chain).await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        self.secret_manager.sign_secp256k1_ecdsa(msg, chain).await
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        super::default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+impl SecretManagerConfig for EncryptedMnemonicSecretManager {
+    type Config = EncryptedMnemonicDto;
+
+    fn to_config(&self) -> Option<Self::Config> {
+        Some(Self::Config {
+            password: None,
+            encrypted_mnemonic: self.encrypted_mnemonic.clone(),
+        })
+    }
+
+    fn from_config(config: &Self::Config) -> Result<Self, Self::Error> {
+        Self::load(
+            config.encrypted_mnemonic.clone(),
+            config.password.as_ref().ok_or(Error::MissingPassword)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::client::constants::IOTA_COIN_TYPE;
+
+    const MNEMONIC: &str = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+
+    // Weak parameters to keep the tests fast.
+    const PARAMS: Argon2idParams = Argon2idParams {
+        memory_cost: 64,
+        time_cost: 1,
+        parallelism: 1,
+    };
+
+    #[tokio::test]
+    async fn create_and_load() {
+        let password = Password::from("drowssap".to_owned());
+
+        let created = EncryptedMnemonicSecretManager::create(MNEMONIC.to_owned(), &password, PARAMS).unwrap();
+        let bytes = created.encrypted_mnemonic().to_bytes().unwrap();
+        assert!(!String::from_utf8(bytes.clone()).unwrap().contains("giant"));
+
+        let loaded =
+            EncryptedMnemonicSecretManager::load(EncryptedMnemonic::from_bytes(&bytes).unwrap(), &password).unwrap();
+        assert_eq!(
+            loaded
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, None)
+                .await
+                .unwrap(),
+            created
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, None)
+                .await
+                .unwrap()
+        );
+
+        assert!(matches!(
+            EncryptedMnemonicSecretManager::load(
+                created.encrypted_mnemonic().clone(),
+                &Password::from("password".to_owned())
+            ),
+            Err(crate::client::Error::EncryptedMnemonic(Error::InvalidPassword))
+        ));
+    }
+
+    #[test]
+    fn authenticated_header() {
+        let password = Password::from("drowssap".to_owned());
+        let encrypted = EncryptedMnemonic::encrypt(&Mnemonic::from(MNEMONIC.to_owned()), &password, PARAMS).unwrap();
+
+        // Changing the KDF parameters or the salt makes the decryption fail, even though the key could be derived
+        let mut weakened = encrypted.clone();
+        weakened.kdf.time_cost += 1;
+        assert!(matches!(
+            weakened.decrypt(&password),
+            Err(crate::client::Error::EncryptedMnemonic(Error::InvalidPassword))
+        ));
+
+        let mut salted = encrypted.clone();
+        salted.salt[0] ^= 1;
+        assert!(matches!(
+            salted.decrypt(&password),
+            Err(crate::client::Error::EncryptedMnemonic(Error::InvalidPassword))
+        ));
+
+        assert_eq!(encrypted.decrypt(&password).unwrap().as_ref(), MNEMONIC);
+    }
+
+    #[tokio::test]
+    async fn change_password() {
+        let password = Password::from("drowssap".to_owned());
+        let new_password = Password::from("password".to_owned());
+
+        let mut secret_manager =
+            EncryptedMnemonicSecretManager::create(MNEMONIC.to_owned(), &password, PARAMS).unwrap();
+        let address = secret_manager
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+            .await
+            .unwrap();
+        secret_manager
+            .change_password(&password, &new_password, PARAMS)
+            .unwrap();
+
+        assert!(matches!(
+            secret_manager.unlock(&password),
+            Err(crate::client::Error::EncryptedMnemonic(Error::InvalidPassword))
+        ));
+        let loaded =
+            EncryptedMnemonicSecretManager::load(secret_manager.encrypted_mnemonic().clone(), &new_password).unwrap();
+        assert_eq!(
+            loaded
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap(),
+            address
+        );
+    }
+}
@@ -0,0 +1,344 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Building of legacy ([EIP-155]) and [EIP-1559] Ethereum transactions, e.g. for ShimmerEVM, to be signed with
+//! [`SecretManage::sign_evm_transaction()`](super::SecretManage::sign_evm_transaction()).
+//!
+//! [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+//! [EIP-1559]: https://eips.ethereum.org/EIPS/eip-1559
+
+use crypto::signatures::secp256k1_ecdsa::{self, EvmAddress};
+use primitive_types::U256;
+
+use super::eip712::keccak256;
+
+const EIP1559_TRANSACTION_TYPE: u8 = 0x02;
+
+/// A legacy Ethereum transaction with [EIP-155](https://eips.ethereum.org/EIPS/eip-155) replay protection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LegacyTransaction {
+    /// The id of the chain the transaction is valid on.
+    pub chain_id: u64,
+    /// The nonce of the sender.
+    pub nonce: u64,
+    /// The price per gas, in wei.
+    pub gas_price: U256,
+    /// The max gas the transaction can use.
+    pub gas_limit: u64,
+    /// The receiver, `None` to deploy a contract.
+    pub to: Option<[u8; 20]>,
+    /// The amount to send, in wei.
+    pub value: U256,
+    /// The call data or the contract code.
+    pub data: Vec<u8>,
+}
+
+/// An address and the storage keys of it that an [`Eip1559Transaction`] accesses.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccessListItem {
+    /// The accessed address.
+    pub address: [u8; 20],
+    /// The accessed storage keys.
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// An [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) Ethereum transaction.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Eip1559Transaction {
+    /// The id of the chain the transaction is valid on.
+    pub chain_id: u64,
+    /// The nonce of the sender.
+    pub nonce: u64,
+    /// The max tip per gas for the block producer, in wei.
+    pub max_priority_fee_per_gas: U256,
+    /// The max total fee per gas, in wei.
+    pub max_fee_per_gas: U256,
+    /// The max gas the transaction can use.
+    pub gas_limit: u64,
+    /// The receiver, `None` to deploy a contract.
+    pub to: Option<[u8; 20]>,
+    /// The amount to send, in wei.
+    pub value: U256,
+    /// The call data or the contract code.
+    pub data: Vec<u8>,
+    /// The addresses and storage keys the transaction accesses.
+    pub access_list: Vec<AccessListItem>,
+}
+
+/// An Ethereum transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvmTransaction {
+    /// A legacy transaction.
+    Legacy(LegacyTransaction),
+    /// An EIP-1559 transaction.
+    Eip1559(Eip1559Transaction),
+}
+
+impl From<LegacyTransaction> for EvmTransaction {
+    fn from(transaction: LegacyTransaction) -> Self {
+        Self::Legacy(transaction)
+    }
+}
+
+impl From<Eip1559Transaction> for EvmTransaction {
+    fn from(transaction: Eip1559Transaction) -> Self {
+        Self::Eip1559(transaction)
+    }
+}
+
+/// A signed Ethereum transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedEvmTransaction {
+    /// The RLP encoded transaction, as sent with `eth_sendRawTransaction`.
+    pub raw: Vec<u8>,
+    /// The transaction hash.
+    pub hash: [u8; 32],
+    /// The address of the signer.
+    pub from: EvmAddress,
+}
+
+impl EvmTransaction {
+    /// Returns the chain id of the transaction.
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Self::Legacy(transaction) => transaction.chain_id,
+            Self::Eip1559(transaction) => transaction.chain_id,
+        }
+    }
+
+    // The RLP encoded fields, without the signature.
+    fn fields(&self) -> Vec<Vec<u8>> {
+        match self {
+            Self::Legacy(transaction) => vec![
+                rlp::encode_u64(transaction.nonce),
+                rlp::encode_u256(transaction.gas_price),
+                rlp::encode_u64(transaction.gas_limit),
+                rlp::encode_bytes(transaction.to.as_ref().map_or(&[][..], |to| to.as_slice())),
+                rlp::encode_u256(transaction.value),
+                rlp::encode_bytes(&transaction.data),
+            ],
+            Self::Eip1559(transaction) => vec![
+                rlp::encode_u64(transaction.chain_id),
+                rlp::encode_u64(transaction.nonce),
+                rlp::encode_u256(transaction.max_priority_fee_per_gas),
+                rlp::encode_u256(transaction.max_fee_per_gas),
+                rlp::encode_u64(transaction.gas_limit),
+                rlp::encode_bytes(transaction.to.as_ref().map_or(&[][..], |to| to.as_slice())),
+                rlp::encode_u256(transaction.value),
+                rlp::encode_bytes(&transaction.data),
+                rlp::encode_list(transaction.access_list.iter().map(|item| {
+                    rlp::encode_list([
+                        rlp::encode_bytes(&item.address),
+                        rlp::encode_list(item.storage_keys.iter().map(|key| rlp::encode_bytes(key))),
+                    ])
+                })),
+            ],
+        }
+    }
+
+    // Prepends the transaction type of typed transactions.
+    fn envelope(&self, payload: Vec<u8>) -> Vec<u8> {
+        match self {
+            Self::Legacy(_) => payload,
+            Self::Eip1559(_) => [vec![EIP1559_TRANSACTION_TYPE], payload].concat(),
+        }
+    }
+
+    /// Returns the bytes that are hashed with Keccak-256 and signed.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        let mut fields = self.fields();
+        // EIP-155 replaces the signature with the chain id and two empty values.
+        if let Self::Legacy(transaction) = self {
+            fields.extend([
+                rlp::encode_u64(transaction.chain_id),
+                rlp::encode_u64(0),
+                rlp::encode_u64(0),
+            ]);
+        }
+
+        self.envelope(rlp::encode_list(fields))
+    }
+
+    /// Returns the RLP encoded transaction with the signature of its [signing payload](Self::signing_payload()).
+    pub fn encode_signed(&self, signature: &secp256k1_ecdsa::RecoverableSignature) -> Vec<u8> {
+        let signature = signature.to_bytes();
+        let (r, s, recovery_id) = (&signature[..32], &signature[32..64], signature[64] as u64);
+        let v = match self {
+            Self::Legacy(transaction) => recovery_id + transaction.chain_id * 2 + 35,
+            Self::Eip1559(_) => recovery_id,
+        };
+
+        let mut fields = self.fields();
+        fields.extend([
+            rlp::encode_u64(v),
+            rlp::encode_u256(U256::from_big_endian(r)),
+            rlp::encode_u256(U256::from_big_endian(s)),
+        ]);
+
+        self.envelope(rlp::encode_list(fields))
+    }
+
+    /// Returns the signed transaction with its hash and sender.
+    pub fn signed(
+        &self,
+        public_key: &secp256k1_ecdsa::PublicKey,
+        signature: &secp256k1_ecdsa::RecoverableSignature,
+    ) -> SignedEvmTransaction {
+        let raw = self.encode_signed(signature);
+
+        SignedEvmTransaction {
+            hash: keccak256(&raw),
+            raw,
+            from: public_key.evm_address(),
+        }
+    }
+}
+
+/// The subset of the [RLP](https://ethereum.org/en/developers/docs/data-structures-and-encoding/rlp/) encoding needed
+/// for transactions.
+mod rlp {
+    use primitive_types::U256;
+
+    fn encode_length(length: usize, offset: u8) -> Vec<u8> {
+        if length <= 55 {
+            vec![offset + length as u8]
+        } else {
+            let length = trim_leading_zeros(&length.to_be_bytes()).to_vec();
+            [vec![offset + 55 + length.len() as u8], length].concat()
+        }
+    }
+
+    fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+        let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+        &bytes[start..]
+    }
+
+    pub(super) fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+        match bytes {
+            [byte] if *byte < 0x80 => vec![*byte],
+            _ => [encode_length(bytes.len(), 0x80), bytes.to_vec()].concat(),
+        }
+    }
+
+    pub(super) fn encode_u64(value: u64) -> Vec<u8> {
+        encode_bytes(trim_leading_zeros(&value.to_be_bytes()))
+    }
+
+    pub(super) fn encode_u256(value: U256) -> Vec<u8> {
+        let mut bytes = [0; 32];
+        value.to_big_endian(&mut bytes);
+        encode_bytes(trim_leading_zeros(&bytes))
+    }
+
+    pub(super) fn encode_list(items: impl IntoIterator<Item = Vec<u8>>) -> Vec<u8> {
+        let payload = items.into_iter().flatten().collect::<Vec<_>>();
+        [encode_length(payload.len(), 0xc0), payload].concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    // The example of EIP-155.
+    fn eip155_example() -> EvmTransaction {
+        LegacyTransaction {
+            chain_id: 1,
+            nonce: 9,
+            gas_price: U256::from(20_000_000_000u64),
+            gas_limit: 21_000,
+            to: Some([0x35; 20]),
+            value: U256::from(1_000_000_000_000_000_000u64),
+            data: Vec::new(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn legacy_transaction() {
+        let transaction = eip155_example();
+        assert_eq!(
+            prefix_hex::encode(transaction.signing_payload()),
+            "0xec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080"
+        );
+
+        let mut signature = [0; 65];
+        signature[..32].copy_from_slice(
+            &prefix_hex::decode::<[u8; 32]>("0x28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276")
+                .unwrap(),
+        );
+        signature[32..64].copy_from_slice(
+            &prefix_hex::decode::<[u8; 32]>("0x67cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83")
+                .unwrap(),
+        );
+        let signature = secp256k1_ecdsa::RecoverableSignature::try_from_bytes(&signature).unwrap();
+
+        assert_eq!(
+            prefix_hex::encode(transaction.encode_signed(&signature)),
+            "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn eip1559_transaction() {
+        let transaction = EvmTransaction::from(Eip1559Transaction {
+            chain_id: 1,
+            max_priority_fee_per_gas: U256::one(),
+            max_fee_per_gas: U256::from(2),
+            gas_limit: 21_000,
+            to: Some([0x35; 20]),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            prefix_hex::encode(transaction.signing_payload()),
+            "0x02df018001028252089435353535353535353535353535353535353535358080c0"
+        );
+    }
+
+    #[tokio::test]
+    async fn sign_with_secret_manager() {
+        use crypto::keys::bip44::Bip44;
+
+        use crate::client::{
+            constants::ETHER_COIN_TYPE,
+            secret::{mnemonic::MnemonicSecretManager, SecretManage},
+        };
+
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(
+            "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river".to_owned(),
+        )
+        .unwrap();
+        let transaction = EvmTransaction::from(Eip1559Transaction {
+            chain_id: 1074,
+            gas_limit: 21_000,
+            to: Some([0x35; 20]),
+            value: U256::from(1_000_000_000_000u64),
+            ..Default::default()
+        });
+
+        let signed = secret_manager
+            .sign_evm_transaction(&transaction, Bip44::new(ETHER_COIN_TYPE))
+            .await
+            .unwrap();
+
+        assert_eq!(signed.raw[0], EIP1559_TRANSACTION_TYPE);
+        assert_eq!(keccak256(&signed.raw), signed.hash);
+        assert_eq!(
+            prefix_hex::encode(signed.from.as_ref()),
+            "0xcaefde2b487ded55688765964320ff390cd87828"
+        );
+    }
+
+    #[test]
+    fn rlp_lengths() {
+        assert_eq!(rlp::encode_bytes(&[]), [0x80]);
+        assert_eq!(rlp::encode_bytes(&[0x7f]), [0x7f]);
+        assert_eq!(rlp::encode_bytes(&[0x80]), [0x81, 0x80]);
+        assert_eq!(rlp::encode_bytes(&[0; 56])[..2], [0xb8, 56]);
+        assert_eq!(rlp::encode_list([]), [0xc0]);
+        assert_eq!(rlp::encode_list([vec![0; 56]])[..2], [0xf8, 56]);
+    }
+}
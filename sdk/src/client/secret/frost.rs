@@ -0,0 +1,469 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`FrostSecretManager`], experimental.
+//!
+//! A FROST group of `n` participants shares a single Ed25519 key, any `t` of them can sign together without the key
+//! ever existing in one place. Signatures are plain Ed25519 signatures of the group key, so the group address can be
+//! unlocked like any other Ed25519 address.
+//!
+//! Keys are created in a key generation ceremony, either by a trusted dealer with [`generate_with_dealer()`] or with a
+//! distributed key generation between the participants, [`dkg_part1()`], [`dkg_part2()`] and [`dkg_part3()`]. Signing
+//! takes two rounds: every signer commits to nonces, then every signer creates a signature share of the
+//! [`SigningPackage`] with all commitments, which are aggregated into the group signature with [`aggregate()`].
+//! [`FrostSecretManager`] coordinates both rounds between [`FrostSigner`]s, which can be local or remote.
+
+use std::{collections::BTreeMap, ops::Range, sync::Mutex};
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+    signatures::{
+        ed25519,
+        secp256k1_ecdsa::{self, EvmAddress},
+    },
+};
+pub use frost_ed25519::{
+    keys::{
+        dkg::{part2 as dkg_part2, part3 as dkg_part3, round1 as dkg_round1, round2 as dkg_round2},
+        KeyPackage, PublicKeyPackage, SecretShare,
+    },
+    round1::{SigningCommitments, SigningNonces},
+    round2::SignatureShare,
+    Identifier, SigningPackage,
+};
+
+use super::{GenerateAddressOptions, SecretManage};
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+
+/// FROST errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// FROST protocol error
+    #[error("{0}")]
+    Frost(#[from] frost_ed25519::Error),
+    /// The aggregated signature isn't a valid Ed25519 signature of the group key
+    #[error("invalid group signature")]
+    InvalidSignature,
+    /// A signer was asked for a signature share without committing to nonces first
+    #[error("no nonces committed by signer {0:?}")]
+    MissingNonces(Identifier),
+    /// Not enough signers committed to nonces
+    #[error("{signers} signers committed, {min_signers} are required")]
+    NotEnoughSigners {
+        /// The number of signers that committed.
+        signers: usize,
+        /// The threshold of the group.
+        min_signers: u16,
+    },
+}
+
+/// Generates the key shares of a `min_signers`-of-`max_signers` group with a trusted dealer.
+///
+/// The dealer knows the group key, so the shares need to be distributed over secure channels and the dealer has to
+/// forget them afterwards.
+pub fn generate_with_dealer(
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<(BTreeMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+    Ok(frost_ed25519::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost_ed25519::keys::IdentifierList::Default,
+        rand::thread_rng(),
+    )?)
+}
+
+/// Starts a distributed key generation for a participant. The secret package is kept, the package is broadcast to all
+/// other participants, who pass the received packages to [`dkg_part2()`].
+pub fn dkg_part1(
+    identifier: Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<(dkg_round1::SecretPackage, dkg_round1::Package), Error> {
+    Ok(frost_ed25519::keys::dkg::part1(
+        identifier,
+        max_signers,
+        min_signers,
+        rand::thread_rng(),
+    )?)
+}
+
+/// Aggregates the signature shares of the signers of the signing package into the Ed25519 signature of the group.
+pub fn aggregate(
+    signing_package: &SigningPackage,
+    signature_shares: &BTreeMap<Identifier, SignatureShare>,
+    public_key_package: &PublicKeyPackage,
+) -> Result<Ed25519Signature, Error> {
+    let signature = frost_ed25519::aggregate(signing_package, signature_shares, public_key_package)?;
+    let public_key = group_public_key(public_key_package)?;
+    let signature = ed25519::Signature::from_bytes(signature.serialize());
+
+    if !public_key.verify(&signature, signing_package.message()) {
+        return Err(Error::InvalidSignature);
+    }
+
+    Ok(Ed25519Signature::new(public_key, signature))
+}
+
+fn group_public_key(public_key_package: &PublicKeyPackage) -> Result<ed25519::PublicKey, Error> {
+    ed25519::PublicKey::try_from_bytes(public_key_package.verifying_key().serialize())
+        .map_err(|_| Error::Frost(frost_ed25519::Error::MalformedVerifyingKey))
+}
+
+/// A participant of a FROST group that takes part in signing, e.g. a local key share or a remote co-signer.
+#[async_trait]
+pub trait FrostSigner: Send + Sync {
+    /// The identifier of the participant.
+    fn identifier(&self) -> Identifier;
+
+    /// Commits to new nonces for the next signature share.
+    async fn commit(&self) -> crate::client::Result<SigningCommitments>;
+
+    /// Creates a signature share of the signing package with the nonces of the last commitment. Nonces must only be
+    /// used once, so they are discarded afterwards.
+    async fn sign(&self, signing_package: &SigningPackage) -> crate::client::Result<SignatureShare>;
+}
+
+/// A [`FrostSigner`] with its key share in memory.
+pub struct LocalFrostSigner {
+    key_package: KeyPackage,
+    nonces: Mutex<Option<SigningNonces>>,
+}
+
+impl std::fmt::Debug for LocalFrostSigner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LocalFrostSigner")
+            .field("identifier", self.key_package.identifier())
+            .finish()
+    }
+}
+
+impl LocalFrostSigner {
+    /// Creates a [`LocalFrostSigner`] from the key package of a distributed key generation.
+    pub fn new(key_package: KeyPackage) -> Self {
+        Self {
+            key_package,
+            nonces: Mutex::new(None),
+        }
+    }
+
+    /// Creates a [`LocalFrostSigner`] from the secret share of a trusted dealer, after verifying it.
+    pub fn try_from_secret_share(secret_share: SecretShare) -> Result<Self, Error> {
+        Ok(Self::new(KeyPackage::try_from(secret_share)?))
+    }
+}
+
+#[async_trait]
+impl FrostSigner for LocalFrostSigner {
+    fn identifier(&self) -> Identifier {
+        *self.key_package.identifier()
+    }
+
+    async fn commit(&self) -> crate::client::Result<SigningCommitments> {
+        let (nonces, commitments) =
+            frost_ed25519::round1::commit(self.key_package.signing_share(), &mut rand::thread_rng());
+        *self.nonces.lock().map_err(|_| crate::client::Error::PoisonError)? = Some(nonces);
+
+        Ok(commitments)
+    }
+
+    async fn sign(&self, signing_package: &SigningPackage) -> crate::client::Result<SignatureShare> {
+        let nonces = self
+            .nonces
+            .lock()
+            .map_err(|_| crate::client::Error::PoisonError)?
+            .take()
+            .ok_or_else(|| Error::MissingNonces(self.identifier()))?;
+
+        Ok(frost_ed25519::round2::sign(signing_package, &nonces, &self.key_package).map_err(Error::from)?)
+    }
+}
+
+/// Experimental secret manager that signs with the Ed25519 key of a FROST group, by coordinating a threshold of its
+/// signers. The group has a single key, so every chain has the same address.
+pub struct FrostSecretManager {
+    public_key_package: PublicKeyPackage,
+    min_signers: u16,
+    signers: Vec<Box<dyn FrostSigner>>,
+    // Signers keep the nonces of a single signing session, so sessions can't run concurrently.
+    session: tokio::sync::Mutex<()>,
+}
+
+impl std::fmt::Debug for FrostSecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FrostSecretManager")
+            .field("min_signers", &self.min_signers)
+            .field(
+                "signers",
+                &self
+                    .signers
+                    .iter()
+                    .map(|signer| signer.identifier())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl FrostSecretManager {
+    /// Creates a [`FrostSecretManager`] of the group with the public key package and threshold, that signs with the
+    /// signers. Signers are asked in order until `min_signers` of them committed, so unavailable signers are skipped.
+    pub fn new(
+        public_key_package: PublicKeyPackage,
+        min_signers: u16,
+        signers: impl IntoIterator<Item = Box<dyn FrostSigner>>,
+    ) -> Self {
+        Self {
+            public_key_package,
+            min_signers,
+            signers: signers.into_iter().collect(),
+            session: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Returns the public key package of the group.
+    pub fn public_key_package(&self) -> &PublicKeyPackage {
+        &self.public_key_package
+    }
+
+    /// Returns the Ed25519 public key of the group.
+    pub fn public_key(&self) -> Result<ed25519::PublicKey, Error> {
+        group_public_key(&self.public_key_package)
+    }
+
+    /// Returns the address of the group.
+    pub fn address(&self) -> Result<Ed25519Address, Error> {
+        Ok(Ed25519Address::new(
+            Blake2b256::digest(self.public_key()?.to_bytes()).into(),
+        ))
+    }
+}
+
+#[async_trait]
+impl SecretManage for FrostSecretManager {
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        Ok(vec![self.address()?])
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(crate::client::Error::SecretManagerMismatch)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], _chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        let _session = self.session.lock().await;
+
+        // Round one, collect the nonce commitments of the first available signers.
+        let mut signers = Vec::new();
+        let mut commitments = BTreeMap::new();
+        for signer in &self.signers {
+            if commitments.len() == self.min_signers as usize {
+                break;
+            }
+            match signer.commit().await {
+                Ok(signer_commitments) => {
+                    commitments.insert(signer.identifier(), signer_commitments);
+                    signers.push(signer);
+                }
+                Err(err) => log::warn!("[FROST] signer {:?} didn't commit: {err}", signer.identifier()),
+            }
+        }
+        if commitments.len() < self.min_signers as usize {
+            return Err(Error::NotEnoughSigners {
+                signers: commitments.len(),
+                min_signers: self.min_signers,
+            }
+            .into());
+        }
+
+        // Round two, collect the signature shares of the committed signers.
+        let signing_package = SigningPackage::new(commitments, msg);
+        let mut signature_shares = BTreeMap::new();
+        for signer in signers {
+            signature_shares.insert(signer.identifier(), signer.sign(&signing_package).await?);
+        }
+
+        Ok(aggregate(
+            &signing_package,
+            &signature_shares,
+            &self.public_key_package,
+        )?)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        Err(crate::client::Error::SecretManagerMismatch)
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        super::default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::client::constants::IOTA_COIN_TYPE;
+
+    struct OfflineSigner(Identifier);
+
+    #[async_trait]
+    impl FrostSigner for OfflineSigner {
+        fn identifier(&self) -> Identifier {
+            self.0
+        }
+
+        async fn commit(&self) -> crate::client::Result<SigningCommitments> {
+            Err(crate::client::Error::RemoteSigner("offline".to_owned()))
+        }
+
+        async fn sign(&self, _signing_package: &SigningPackage) -> crate::client::Result<SignatureShare> {
+            Err(crate::client::Error::RemoteSigner("offline".to_owned()))
+        }
+    }
+
+    fn signers(secret_shares: BTreeMap<Identifier, SecretShare>) -> Vec<Box<dyn FrostSigner>> {
+        secret_shares
+            .into_values()
+            .map(|secret_share| {
+                Box::new(LocalFrostSigner::try_from_secret_share(secret_share).unwrap()) as Box<dyn FrostSigner>
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn threshold_signature() {
+        let (secret_shares, public_key_package) = generate_with_dealer(3, 2).unwrap();
+        let mut signers = signers(secret_shares);
+        // Only two of the three signers are available.
+        signers[0] = Box::new(OfflineSigner(signers[0].identifier()));
+        let secret_manager = FrostSecretManager::new(public_key_package, 2, signers);
+
+        let chain = Bip44::new(IOTA_COIN_TYPE);
+        let signature = secret_manager.sign_ed25519(b"message", chain).await.unwrap();
+
+        signature
+            .is_valid(b"message", &secret_manager.address().unwrap())
+            .unwrap();
+        assert_eq!(
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap(),
+            [secret_manager.address().unwrap()]
+        );
+    }
+
+    #[tokio::test]
+    async fn not_enough_signers() {
+        let (secret_shares, public_key_package) = generate_with_dealer(3, 3).unwrap();
+        let mut signers = signers(secret_shares);
+        signers[2] = Box::new(OfflineSigner(signers[2].identifier()));
+        let secret_manager = FrostSecretManager::new(public_key_package, 3, signers);
+
+        assert!(matches!(
+            secret_manager
+                .sign_ed25519(b"message", Bip44::new(IOTA_COIN_TYPE))
+                .await,
+            Err(crate::client::Error::Frost(Error::NotEnoughSigners {
+                signers: 2,
+                min_signers: 3
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn distributed_key_generation() {
+        let identifiers = (1..=3u16).map(|i| Identifier::try_from(i).unwrap()).collect::<Vec<_>>();
+
+        let mut round1_secret_packages = BTreeMap::new();
+        let mut round1_packages = BTreeMap::new();
+        for identifier in &identifiers {
+            let (secret_package, package) = dkg_part1(*identifier, 3, 2).unwrap();
+            round1_secret_packages.insert(*identifier, secret_package);
+            round1_packages.insert(*identifier, package);
+        }
+
+        let received_round1_packages = |identifier: &Identifier| {
+            round1_packages
+                .iter()
+                .filter(|(sender, _)| *sender != identifier)
+                .map(|(sender, package)| (*sender, package.clone()))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut round2_secret_packages = BTreeMap::new();
+        let mut round2_packages = BTreeMap::<Identifier, BTreeMap<Identifier, dkg_round2::Package>>::new();
+        for (identifier, secret_package) in round1_secret_packages {
+            let (round2_secret_package, packages) =
+                dkg_part2(secret_package, &received_round1_packages(&identifier)).unwrap();
+            round2_secret_packages.insert(identifier, round2_secret_package);
+            for (receiver, package) in packages {
+                round2_packages.entry(receiver).or_default().insert(identifier, package);
+            }
+        }
+
+        let mut public_key_packages = Vec::new();
+        let mut signers = Vec::<Box<dyn FrostSigner>>::new();
+        for identifier in &identifiers {
+            let (key_package, public_key_package) = dkg_part3(
+                &round2_secret_packages[identifier],
+                &received_round1_packages(identifier),
+                &round2_packages[identifier],
+            )
+            .unwrap();
+            signers.push(Box::new(LocalFrostSigner::new(key_package)));
+            public_key_packages.push(public_key_package);
+        }
+
+        // All participants derived the same group key.
+        assert!(public_key_packages
+            .windows(2)
+            .all(|packages| packages[0] == packages[1]));
+
+        let secret_manager = FrostSecretManager::new(public_key_packages.remove(0), 2, signers);
+        let signature = secret_manager
+            .sign_ed25519(b"message", Bip44::new(IOTA_COIN_TYPE))
+            .await
+            .unwrap();
+        signature
+            .is_valid(b"message", &secret_manager.address().unwrap())
+            .unwrap();
+    }
+}
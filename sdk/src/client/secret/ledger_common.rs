@@ -0,0 +1,224 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Errors and transaction checks shared by the ledger nano secret manager and its simulator, without the Ledger
+//! transport.
+
+use std::collections::HashMap;
+
+use packable::{error::UnexpectedEOF, PackableExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::{is_alias_transition, PreparedTransactionData},
+    types::block::{
+        address::{Address, AliasAddress, NftAddress},
+        output::Output,
+        payload::transaction::TransactionEssence,
+        signature::Signature,
+        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, Unlock},
+    },
+    utils::unix_timestamp_now,
+};
+
+// Packed length of the BIP32 index and change of an input that the app expects after the essence.
+const BIP32_INDEX_LENGTH: usize = 2 * core::mem::size_of::<u32>();
+
+/// Ledger nano errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Denied by User
+    #[error("denied by user")]
+    DeniedByUser,
+    /// Dongle Locked
+    #[error("ledger locked")]
+    DongleLocked,
+    /// Ledger Device not found
+    #[error("ledger device not found")]
+    DeviceNotFound,
+    /// Ledger Essence Too Large
+    #[error("ledger essence too large")]
+    EssenceTooLarge,
+    /// Ledger transport error
+    #[error("ledger transport error")]
+    MiscError,
+    /// Unsupported operation
+    #[error("unsupported operation")]
+    UnsupportedOperation,
+    /// Block error
+    #[error("{0}")]
+    Block(Box<crate::types::block::Error>),
+    /// Missing input with ed25519 address
+    #[error("missing input with ed25519 address")]
+    MissingInputWithEd25519Address,
+    /// Missing bip32 chain
+    #[error("missing bip32 chain")]
+    MissingBip32Chain,
+    /// Bip32 chain mismatch
+    #[error("Bip32 chain mismatch")]
+    Bip32ChainMismatch,
+    /// Unpack error
+    #[error("{0}")]
+    Unpack(#[from] packable::error::UnpackError<crate::types::block::Error, UnexpectedEOF>),
+    /// No available inputs provided
+    #[error("No available inputs provided")]
+    NoAvailableInputsProvided,
+    /// Blind signing is needed, but not allowed
+    #[error(
+        "blind signing required because {0}, allow it for the secret manager and enable it in the settings of the Ledger app"
+    )]
+    BlindSigningRequired(BlindSigningReason),
+}
+
+/// The reason why the Ledger device can't display what gets signed, so blind signing is needed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BlindSigningReason {
+    /// An output isn't a basic output with only an Ed25519 address unlock condition.
+    #[serde(rename_all = "camelCase")]
+    UnsupportedOutput {
+        /// The index of the output in the transaction.
+        index: usize,
+    },
+    /// The essence with the BIP32 indices of the inputs doesn't fit into the buffer of the device.
+    #[serde(rename_all = "camelCase")]
+    EssenceTooLarge {
+        /// The size of the essence with the BIP32 indices of the inputs.
+        size: usize,
+        /// The buffer size of the device.
+        buffer_size: usize,
+    },
+    /// A hash is signed instead of a transaction essence.
+    HashSigning,
+}
+
+impl core::fmt::Display for BlindSigningReason {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedOutput { index } => write!(f, "output {index} can't be displayed by the device"),
+            Self::EssenceTooLarge { size, buffer_size } => write!(
+                f,
+                "the essence size of {size} bytes exceeds the device buffer size of {buffer_size} bytes"
+            ),
+            Self::HashSigning => write!(f, "a hash is signed"),
+        }
+    }
+}
+
+impl From<crate::types::block::Error> for Error {
+    fn from(error: crate::types::block::Error) -> Self {
+        Self::Block(Box::new(error))
+    }
+}
+
+/// Checks whether a transaction needs blind signing.
+///
+/// The Ledger Nano S(+)/X app can present the user a detailed view of the transaction before it
+/// is signed but only with BasicOutputs, without extra-features and if the Essence is not too large.
+/// If criteria are not met, blind signing is needed.
+/// This method finds out if we have to switch to blind signing mode.
+pub fn needs_blind_signing(prepared_transaction: &PreparedTransactionData, buffer_size: usize) -> bool {
+    blind_signing_reason(prepared_transaction, buffer_size).is_some()
+}
+
+/// Returns why blind signing is needed for a transaction, see [`needs_blind_signing()`].
+pub fn blind_signing_reason(
+    prepared_transaction: &PreparedTransactionData,
+    buffer_size: usize,
+) -> Option<BlindSigningReason> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
+
+    if let Some(index) = essence.outputs().iter().position(
+        |output| !matches!(output, Output::Basic(o) if o.simple_deposit_address().is_some() && o.address().is_ed25519()),
+    ) {
+        return Some(BlindSigningReason::UnsupportedOutput { index });
+    }
+
+    // check if essence + bip32 indices fit into the buffer of the device
+    let total_size =
+        BIP32_INDEX_LENGTH * prepared_transaction.inputs_data.len() + prepared_transaction.essence.packed_len();
+
+    (total_size > buffer_size).then_some(BlindSigningReason::EssenceTooLarge {
+        size: total_size,
+        buffer_size,
+    })
+}
+
+// Merge signature unlocks with Alias/Nft/Reference unlocks
+pub(crate) fn merge_unlocks(
+    prepared_transaction_data: &PreparedTransactionData,
+    mut unlocks: impl Iterator<Item = Unlock>,
+    time: Option<u32>,
+) -> Result<Vec<Unlock>, Error> {
+    // The hashed_essence gets signed
+    let hashed_essence = prepared_transaction_data.essence.hash();
+
+    let time = time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
+
+    let mut merged_unlocks = Vec::new();
+    let mut block_indexes = HashMap::<Address, usize>::new();
+
+    // Assuming inputs_data is ordered by address type
+    for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
+        // Get the address that is required to unlock the input
+        let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
+        let alias_transition = is_alias_transition(&input.output, *input.output_id(), regular.outputs(), None);
+        let (input_address, _) =
+            input
+                .output
+                .required_and_unlocked_address(time, input.output_metadata.output_id(), alias_transition)?;
+
+        // Check if we already added an [Unlock] for this address
+        match block_indexes.get(&input_address) {
+            // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
+            Some(block_index) => match input_address {
+                Address::Alias(_alias) => merged_unlocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
+                Address::Ed25519(_ed25519) => {
+                    merged_unlocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
+                }
+                Address::Nft(_nft) => merged_unlocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
+            },
+            None => {
+                // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
+                // address already at this point, because the reference index needs to be lower
+                // than the current block index
+                if !input_address.is_ed25519() {
+                    return Err(Error::MissingInputWithEd25519Address);
+                }
+
+                let unlock = unlocks.next().ok_or(Error::MissingInputWithEd25519Address)?;
+
+                if let Unlock::Signature(signature_unlock) = &unlock {
+                    let Signature::Ed25519(ed25519_signature) = signature_unlock.signature();
+                    let ed25519_address = match input_address {
+                        Address::Ed25519(ed25519_address) => ed25519_address,
+                        _ => return Err(Error::MissingInputWithEd25519Address)?,
+                    };
+                    ed25519_signature.is_valid(&hashed_essence, &ed25519_address)?;
+                }
+
+                merged_unlocks.push(unlock);
+
+                // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
+                // the same address in their unlock condition
+                block_indexes.insert(input_address, current_block_index);
+            }
+        }
+
+        // When we have an alias or Nft output, we will add their alias or nft address to block_indexes,
+        // because they can be used to unlock outputs via [Unlock::Alias] or [Unlock::Nft],
+        // that have the corresponding alias or nft address in their unlock condition
+        match &input.output {
+            Output::Alias(alias_output) => block_indexes.insert(
+                Address::Alias(AliasAddress::new(alias_output.alias_id_non_null(input.output_id()))),
+                current_block_index,
+            ),
+            Output::Nft(nft_output) => block_indexes.insert(
+                Address::Nft(NftAddress::new(nft_output.nft_id_non_null(input.output_id()))),
+                current_block_index,
+            ),
+            _ => None,
+        };
+    }
+    Ok(merged_unlocks)
+}
@@ -5,7 +5,7 @@
 //!
 //! Ledger status codes: <https://github.com/iotaledger/ledger-iota-app/blob/53c1f96d15f8b014ba8ba31a85f0401bb4d33e18/src/iota_io.h#L54>.
 
-use std::{collections::HashMap, ops::Range};
+use std::ops::Range;
 
 use async_trait::async_trait;
 use crypto::{
@@ -16,73 +16,24 @@ use iota_ledger_nano::{
     api::errors::APIError, get_app_config, get_buffer_size, get_ledger, get_opened_app, LedgerBIP32Index,
     Packable as LedgerNanoPackable, TransportTypes,
 };
-use packable::{error::UnexpectedEOF, unpacker::SliceUnpacker, Packable, PackableExt};
+use packable::{unpacker::SliceUnpacker, Packable, PackableExt};
 use tokio::sync::Mutex;
 
-use super::{GenerateAddressOptions, SecretManage, SecretManagerConfig};
+pub use super::ledger_common::{blind_signing_reason, needs_blind_signing, BlindSigningReason, Error};
+use super::{ledger_common::merge_unlocks, GenerateAddressOptions, SecretManage, SecretManagerConfig};
 use crate::{
     client::secret::{
-        is_alias_transition,
         types::{LedgerApp, LedgerDeviceType},
         LedgerNanoStatus, PreparedTransactionData,
     },
     types::block::{
-        address::{Address, AliasAddress, Ed25519Address, NftAddress},
-        output::Output,
+        address::Ed25519Address,
         payload::transaction::{TransactionEssence, TransactionPayload},
         signature::{Ed25519Signature, Signature},
-        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
     },
-    utils::unix_timestamp_now,
 };
 
-/// Ledger nano errors.
-#[derive(Debug, thiserror::Error)]
-pub enum Error {
-    /// Denied by User
-    #[error("denied by user")]
-    DeniedByUser,
-    /// Dongle Locked
-    #[error("ledger locked")]
-    DongleLocked,
-    /// Ledger Device not found
-    #[error("ledger device not found")]
-    DeviceNotFound,
-    /// Ledger Essence Too Large
-    #[error("ledger essence too large")]
-    EssenceTooLarge,
-    /// Ledger transport error
-    #[error("ledger transport error")]
-    MiscError,
-    /// Unsupported operation
-    #[error("unsupported operation")]
-    UnsupportedOperation,
-    /// Block error
-    #[error("{0}")]
-    Block(Box<crate::types::block::Error>),
-    /// Missing input with ed25519 address
-    #[error("missing input with ed25519 address")]
-    MissingInputWithEd25519Address,
-    /// Missing bip32 chain
-    #[error("missing bip32 chain")]
-    MissingBip32Chain,
-    /// Bip32 chain mismatch
-    #[error("Bip32 chain mismatch")]
-    Bip32ChainMismatch,
-    /// Unpack error
-    #[error("{0}")]
-    Unpack(#[from] packable::error::UnpackError<crate::types::block::Error, UnexpectedEOF>),
-    /// No available inputs provided
-    #[error("No available inputs provided")]
-    NoAvailableInputsProvided,
-}
-
-impl From<crate::types::block::Error> for Error {
-    fn from(error: crate::types::block::Error) -> Self {
-        Self::Block(Box::new(error))
-    }
-}
-
 // map most errors to a single error but there are some errors that
 // need special care.
 // LedgerDongleLocked: Ask the user to unlock the dongle
@@ -104,12 +55,15 @@ impl From<APIError> for Error {
 }
 
 /// Secret manager that uses a Ledger hardware wallet.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct LedgerSecretManager {
     /// Specifies if a real Ledger hardware is used or only a simulator is used.
     pub is_simulator: bool,
     /// Specifies whether the wallet should be in non-interactive mode.
     pub non_interactive: bool,
+    /// Specifies whether transactions and hashes that can't be displayed on the device can be blind signed, `true` by
+    /// default. If not allowed, signing them fails with [`Error::BlindSigningRequired`].
+    pub allow_blind_signing: bool,
     /// Mutex to prevent multiple simultaneous requests to a ledger.
     mutex: Mutex<()>,
 }
@@ -184,6 +138,10 @@ impl SecretManage for LedgerSecretManager {
             return Err(Error::UnsupportedOperation.into());
         }
 
+        if !self.allow_blind_signing {
+            return Err(Error::BlindSigningRequired(BlindSigningReason::HashSigning).into());
+        }
+
         let msg = msg.to_vec();
 
         let coin_type = chain.coin_type;
@@ -282,11 +240,16 @@ impl SecretManage for LedgerSecretManager {
                 .set_non_interactive_mode(self.non_interactive)
                 .map_err(Error::from)?;
         }
-        let blind_signing = needs_blind_signing(prepared_transaction, ledger.get_buffer_size());
+        let blind_signing_reason = blind_signing_reason(prepared_transaction, ledger.get_buffer_size());
+        let blind_signing = blind_signing_reason.is_some();
 
         // if essence + bip32 input indices are larger than the buffer size or the essence contains
         // features / types that are not supported blind signing will be needed
-        if blind_signing {
+        if let Some(reason) = blind_signing_reason {
+            if !self.allow_blind_signing {
+                return Err(Error::BlindSigningRequired(reason).into());
+            }
+
             // prepare signing
             log::debug!("[LEDGER] prepare_blind_signing");
             log::debug!("[LEDGER] {:?} {:?}", input_bip32_indices, essence_hash);
@@ -419,25 +382,10 @@ impl SecretManagerConfig for LedgerSecretManager {
     }
 }
 
-/// the Ledger Nano S(+)/X app can present the user a detailed view of the transaction before it
-/// is signed but only with BasicOutputs, without extra-features and if the Essence is not too large.
-/// If criteria are not met, blind signing is needed.
-/// This method finds out if we have to switch to blind signing mode.
-pub fn needs_blind_signing(prepared_transaction: &PreparedTransactionData, buffer_size: usize) -> bool {
-    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
-
-    if !essence.outputs().iter().all(
-        |output| matches!(output, Output::Basic(o) if o.simple_deposit_address().is_some() && o.address().is_ed25519()),
-    ) {
-        return true;
+impl Default for LedgerSecretManager {
+    fn default() -> Self {
+        Self::new(false)
     }
-
-    // check if essence + bip32 indices fit into the buffer of the device
-    let total_size = LedgerBIP32Index::default().packed_len() * prepared_transaction.inputs_data.len()
-        + prepared_transaction.essence.packed_len();
-
-    // return true if too large
-    total_size > buffer_size
 }
 
 impl LedgerSecretManager {
@@ -448,10 +396,38 @@ impl LedgerSecretManager {
         Self {
             is_simulator,
             non_interactive: false,
+            allow_blind_signing: true,
             mutex: Mutex::new(()),
         }
     }
 
+    /// Displays the address of the chain on the device, so the user can verify it. Returns whether the user approved
+    /// the address.
+    pub async fn verify_address(&self, chain: Bip44) -> Result<bool, crate::client::Error> {
+        let bip32 = LedgerBIP32Index {
+            bip32_index: chain.address_index.harden().into(),
+            bip32_change: chain.change.harden().into(),
+        };
+
+        // lock the mutex to prevent multiple simultaneous requests to a ledger
+        let _lock = self.mutex.lock().await;
+
+        let ledger =
+            get_ledger(chain.coin_type, chain.account.harden().into(), self.is_simulator).map_err(Error::from)?;
+        if ledger.is_debug_app() {
+            ledger
+                .set_non_interactive_mode(self.non_interactive)
+                .map_err(Error::from)?;
+        }
+
+        log::debug!("[LEDGER] verify address {:?}", bip32);
+        match ledger.get_addresses(true, bip32, 1) {
+            Ok(_) => Ok(true),
+            Err(APIError::ConditionsOfUseNotSatisfied) => Ok(false),
+            Err(error) => Err(Error::from(error).into()),
+        }
+    }
+
     /// Get Ledger hardware status.
     pub async fn get_ledger_nano_status(&self) -> LedgerNanoStatus {
         log::debug!("get_ledger_nano_status");
@@ -504,85 +480,6 @@ impl LedgerSecretManager {
     }
 }
 
-// Merge signature unlocks with Alias/Nft/Reference unlocks
-fn merge_unlocks(
-    prepared_transaction_data: &PreparedTransactionData,
-    mut unlocks: impl Iterator<Item = Unlock>,
-    time: Option<u32>,
-) -> Result<Vec<Unlock>, Error> {
-    // The hashed_essence gets signed
-    let hashed_essence = prepared_transaction_data.essence.hash();
-
-    let time = time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
-
-    let mut merged_unlocks = Vec::new();
-    let mut block_indexes = HashMap::<Address, usize>::new();
-
-    // Assuming inputs_data is ordered by address type
-    for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
-        // Get the address that is required to unlock the input
-        let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
-        let alias_transition = is_alias_transition(&input.output, *input.output_id(), regular.outputs(), None);
-        let (input_address, _) =
-            input
-                .output
-                .required_and_unlocked_address(time, input.output_metadata.output_id(), alias_transition)?;
-
-        // Check if we already added an [Unlock] for this address
-        match block_indexes.get(&input_address) {
-            // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
-            Some(block_index) => match input_address {
-                Address::Alias(_alias) => merged_unlocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
-                Address::Ed25519(_ed25519) => {
-                    merged_unlocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
-                }
-                Address::Nft(_nft) => merged_unlocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
-            },
-            None => {
-                // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
-                // address already at this point, because the reference index needs to be lower
-                // than the current block index
-                if !input_address.is_ed25519() {
-                    return Err(Error::MissingInputWithEd25519Address);
-                }
-
-                let unlock = unlocks.next().ok_or(Error::MissingInputWithEd25519Address)?;
-
-                if let Unlock::Signature(signature_unlock) = &unlock {
-                    let Signature::Ed25519(ed25519_signature) = signature_unlock.signature();
-                    let ed25519_address = match input_address {
-                        Address::Ed25519(ed25519_address) => ed25519_address,
-                        _ => return Err(Error::MissingInputWithEd25519Address)?,
-                    };
-                    ed25519_signature.is_valid(&hashed_essence, &ed25519_address)?;
-                }
-
-                merged_unlocks.push(unlock);
-
-                // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
-                // the same address in their unlock condition
-                block_indexes.insert(input_address, current_block_index);
-            }
-        }
-
-        // When we have an alias or Nft output, we will add their alias or nft address to block_indexes,
-        // because they can be used to unlock outputs via [Unlock::Alias] or [Unlock::Nft],
-        // that have the corresponding alias or nft address in their unlock condition
-        match &input.output {
-            Output::Alias(alias_output) => block_indexes.insert(
-                Address::Alias(AliasAddress::new(alias_output.alias_id_non_null(input.output_id()))),
-                current_block_index,
-            ),
-            Output::Nft(nft_output) => block_indexes.insert(
-                Address::Nft(NftAddress::new(nft_output.nft_id_non_null(input.output_id()))),
-                current_block_index,
-            ),
-            _ => None,
-        };
-    }
-    Ok(merged_unlocks)
-}
-
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -0,0 +1,381 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`LedgerSimulatorSecretManager`].
+//!
+//! The simulator behaves like the IOTA and Shimmer Ledger apps, without a device or Speculos, so the Ledger code paths
+//! of an application can run in CI. The keys are derived from a mnemonic, everything else follows the app: only the
+//! coin type of the opened app and the testnet coin type are accepted, a locked device and denied confirmations fail
+//! with the same errors, only a single address can be displayed at a time and transactions that can't be displayed
+//! need blind signing, which has to be allowed for the secret manager and enabled in the settings of the app.
+
+use std::{ops::Range, sync::Mutex};
+
+use async_trait::async_trait;
+use crypto::{
+    keys::{bip39::Mnemonic, bip44::Bip44},
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+
+use super::{
+    ledger_common::{blind_signing_reason, merge_unlocks, BlindSigningReason, Error},
+    mnemonic::MnemonicSecretManager,
+    types::{LedgerApp, LedgerDeviceType},
+    GenerateAddressOptions, LedgerNanoStatus, PreparedTransactionData, SecretManage,
+};
+use crate::{
+    client::constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+    types::block::{
+        address::Ed25519Address,
+        payload::transaction::TransactionPayload,
+        signature::{Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+    },
+};
+
+/// The buffer size the simulator reports by default.
+pub const DEFAULT_BUFFER_SIZE: usize = 2048;
+
+/// The app version the simulator reports.
+pub const SIMULATOR_APP_VERSION: &str = "1.0.0";
+
+// The IOTA and Shimmer apps both also accept the testnet coin type.
+const TESTNET_COIN_TYPE: u32 = 1;
+
+/// Secret manager that simulates a Ledger Nano with the IOTA or Shimmer app in software.
+pub struct LedgerSimulatorSecretManager {
+    /// The coin type of the opened app, [`IOTA_COIN_TYPE`] or [`SHIMMER_COIN_TYPE`].
+    pub app_coin_type: u32,
+    /// The simulated device.
+    pub device: LedgerDeviceType,
+    /// The buffer size of the device, essences with the BIP32 indices of the inputs that don't fit need blind signing.
+    pub buffer_size: usize,
+    /// Whether the device is locked.
+    pub locked: bool,
+    /// Whether blind signing is enabled in the settings of the app, `false` by default like on a device.
+    pub blind_signing_enabled: bool,
+    /// Specifies whether transactions and hashes that can't be displayed on the device can be blind signed, `true` by
+    /// default. If not allowed, signing them fails with [`Error::BlindSigningRequired`].
+    pub allow_blind_signing: bool,
+    /// Whether the simulated user approves what's displayed on the device, `true` by default.
+    pub user_approves: bool,
+    displayed_addresses: Mutex<Vec<Bip44>>,
+    secret_manager: MnemonicSecretManager,
+}
+
+impl std::fmt::Debug for LedgerSimulatorSecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LedgerSimulatorSecretManager")
+            .field("app_coin_type", &self.app_coin_type)
+            .field("device", &self.device)
+            .field("buffer_size", &self.buffer_size)
+            .field("locked", &self.locked)
+            .field("blind_signing_enabled", &self.blind_signing_enabled)
+            .field("allow_blind_signing", &self.allow_blind_signing)
+            .field("user_approves", &self.user_approves)
+            .finish()
+    }
+}
+
+impl LedgerSimulatorSecretManager {
+    /// Creates a [`LedgerSimulatorSecretManager`] of an unlocked Ledger Nano S Plus with the IOTA app opened, with the
+    /// keys derived from the mnemonic.
+    pub fn try_from_mnemonic(mnemonic: impl Into<Mnemonic>) -> Result<Self, crate::client::Error> {
+        Ok(Self {
+            app_coin_type: IOTA_COIN_TYPE,
+            device: LedgerDeviceType::LedgerNanoSPlus,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            locked: false,
+            blind_signing_enabled: false,
+            allow_blind_signing: true,
+            user_approves: true,
+            displayed_addresses: Mutex::new(Vec::new()),
+            secret_manager: MnemonicSecretManager::try_from_mnemonic(mnemonic)?,
+        })
+    }
+
+    /// Returns the chains of the addresses that were displayed on the device, in the order they were displayed.
+    pub fn displayed_addresses(&self) -> Vec<Bip44> {
+        self.displayed_addresses.lock().expect("poisoned lock").clone()
+    }
+
+    /// Displays the address of the chain on the device. Returns whether the user approved the address.
+    pub async fn verify_address(&self, chain: Bip44) -> Result<bool, crate::client::Error> {
+        self.open_app(chain.coin_type)?;
+
+        match self.display_address(chain) {
+            Ok(()) => Ok(true),
+            Err(Error::DeniedByUser) => Ok(false),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Get the simulated Ledger hardware status.
+    pub async fn get_ledger_nano_status(&self) -> LedgerNanoStatus {
+        LedgerNanoStatus {
+            connected: true,
+            locked: Some(self.locked),
+            blind_signing_enabled: self.blind_signing_enabled,
+            app: Some(LedgerApp {
+                name: if self.app_coin_type == SHIMMER_COIN_TYPE {
+                    "Shimmer"
+                } else {
+                    "IOTA"
+                }
+                .to_owned(),
+                version: SIMULATOR_APP_VERSION.to_owned(),
+            }),
+            device: Some(self.device),
+            buffer_size: Some(self.buffer_size),
+        }
+    }
+
+    // Checks that the app can be used for the coin type, like opening a connection to the device does.
+    fn open_app(&self, coin_type: u32) -> Result<(), Error> {
+        if self.locked {
+            return Err(Error::DongleLocked);
+        }
+        if coin_type != self.app_coin_type && coin_type != TESTNET_COIN_TYPE {
+            log::debug!("[LEDGER SIMULATOR] coin type {coin_type} isn't supported by the opened app");
+            return Err(Error::MiscError);
+        }
+
+        Ok(())
+    }
+
+    fn user_confirm(&self) -> Result<(), Error> {
+        if self.user_approves {
+            Ok(())
+        } else {
+            Err(Error::DeniedByUser)
+        }
+    }
+
+    fn display_address(&self, chain: Bip44) -> Result<(), Error> {
+        self.displayed_addresses.lock().expect("poisoned lock").push(chain);
+        self.user_confirm()
+    }
+
+    fn check_blind_signing(&self, reason: BlindSigningReason) -> Result<(), Error> {
+        if self.allow_blind_signing && self.blind_signing_enabled {
+            Ok(())
+        } else {
+            Err(Error::BlindSigningRequired(reason))
+        }
+    }
+}
+
+#[async_trait]
+impl SecretManage for LedgerSimulatorSecretManager {
+    type Error = crate::client::Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        let options = options.into().unwrap_or_default();
+        self.open_app(coin_type)?;
+
+        if options.ledger_nano_prompt {
+            // The device can only display a single address at a time.
+            if address_indexes.len() != 1 {
+                return Err(Error::UnsupportedOperation.into());
+            }
+            self.display_address(
+                Bip44::new(coin_type)
+                    .with_account(account_index)
+                    .with_change(options.internal as _)
+                    .with_address_index(address_indexes.start),
+            )?;
+        }
+
+        self.secret_manager
+            .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+            .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    /// Like the Ledger, only messages of 32 bytes can be signed and only with blind signing.
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        if msg.len() != 32 {
+            return Err(Error::UnsupportedOperation.into());
+        }
+
+        self.open_app(chain.coin_type)?;
+        self.check_blind_signing(BlindSigningReason::HashSigning)?;
+        self.user_confirm()?;
+
+        self.secret_manager.sign_ed25519(msg, chain).await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        Err(Error::UnsupportedOperation.into())
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, <Self as SecretManage>::Error> {
+        let mut chains = Vec::new();
+
+        for input in &prepared_transaction.inputs_data {
+            let chain = input.chain.ok_or(Error::MissingBip32Chain)?;
+
+            // coin_type and account_index should be the same in each input
+            if chains
+                .first()
+                .is_some_and(|first: &Bip44| first.coin_type != chain.coin_type || first.account != chain.account)
+            {
+                return Err(Error::Bip32ChainMismatch.into());
+            }
+
+            chains.push(chain);
+        }
+
+        let first_chain = chains.first().ok_or(Error::NoAvailableInputsProvided)?;
+        self.open_app(first_chain.coin_type)?;
+
+        if let Some(reason) = blind_signing_reason(prepared_transaction, self.buffer_size) {
+            self.check_blind_signing(reason)?;
+        } else if let Some(remainder) = &prepared_transaction.remainder {
+            // The device validates that the remainder is an output of the transaction.
+            if remainder.chain.is_some()
+                && !prepared_transaction
+                    .essence
+                    .as_regular()
+                    .outputs()
+                    .iter()
+                    .any(|output| output == &remainder.output)
+            {
+                log::debug!("[LEDGER SIMULATOR] remainder_index not found");
+                return Err(Error::MiscError.into());
+            }
+        }
+
+        self.user_confirm()?;
+
+        // Like the device, sign every chain once, the other unlocks reference the signatures.
+        let hashed_essence = prepared_transaction.essence.hash();
+        let mut signed_chains = Vec::new();
+        let mut unlocks = Vec::new();
+        for chain in chains {
+            if !signed_chains.contains(&chain) {
+                let signature = self.secret_manager.sign_ed25519(&hashed_essence, chain).await?;
+                unlocks.push(Unlock::Signature(SignatureUnlock::new(Signature::from(signature))));
+                signed_chains.push(chain);
+            }
+        }
+
+        Ok(Unlocks::new(merge_unlocks(
+            prepared_transaction,
+            unlocks.into_iter(),
+            time,
+        )?)?)
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const MNEMONIC: &str = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+
+    #[tokio::test]
+    async fn addresses() {
+        let simulator = LedgerSimulatorSecretManager::try_from_mnemonic(MNEMONIC.to_owned()).unwrap();
+        let mnemonic = MnemonicSecretManager::try_from_mnemonic(MNEMONIC.to_owned()).unwrap();
+
+        assert_eq!(
+            simulator
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..3, None)
+                .await
+                .unwrap(),
+            mnemonic
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..3, None)
+                .await
+                .unwrap()
+        );
+        assert!(simulator.displayed_addresses().is_empty());
+    }
+
+    #[tokio::test]
+    async fn address_display() {
+        let mut simulator = LedgerSimulatorSecretManager::try_from_mnemonic(MNEMONIC.to_owned()).unwrap();
+        let options = GenerateAddressOptions {
+            ledger_nano_prompt: true,
+            ..Default::default()
+        };
+
+        simulator
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 1..2, options)
+            .await
+            .unwrap();
+        assert_eq!(
+            simulator.displayed_addresses(),
+            [Bip44::new(IOTA_COIN_TYPE).with_address_index(1)]
+        );
+
+        assert!(matches!(
+            simulator
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, options)
+                .await,
+            Err(crate::client::Error::Ledger(Error::UnsupportedOperation))
+        ));
+
+        simulator.user_approves = false;
+        assert!(!simulator.verify_address(Bip44::new(IOTA_COIN_TYPE)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn device_constraints() {
+        let mut simulator = LedgerSimulatorSecretManager::try_from_mnemonic(MNEMONIC.to_owned()).unwrap();
+        let chain = Bip44::new(IOTA_COIN_TYPE);
+
+        assert!(matches!(
+            simulator.sign_ed25519(&[0; 32], chain).await,
+            Err(crate::client::Error::Ledger(Error::BlindSigningRequired(
+                BlindSigningReason::HashSigning
+            )))
+        ));
+
+        simulator.blind_signing_enabled = true;
+        simulator.sign_ed25519(&[0; 32], chain).await.unwrap();
+
+        assert!(matches!(
+            simulator.sign_ed25519(&[0; 32], Bip44::new(SHIMMER_COIN_TYPE)).await,
+            Err(crate::client::Error::Ledger(Error::MiscError))
+        ));
+
+        simulator.locked = true;
+        assert!(matches!(
+            simulator.sign_ed25519(&[0; 32], chain).await,
+            Err(crate::client::Error::Ledger(Error::DongleLocked))
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signing of arbitrary messages with [`SecretManage::sign_message()`](super::SecretManage::sign_message()), to prove
+//! the ownership of an address without creating a transaction.
+//!
+//! The message is prefixed and hashed before it's signed, so a signed message can never be used as a signature of a
+//! transaction essence or block.
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+
+use crate::types::block::{address::Ed25519Address, signature::Ed25519Signature};
+
+/// The prefix of signed messages, it starts with a byte that's not a valid transaction essence kind.
+pub const MESSAGE_PREFIX: &[u8] = b"\x19IOTA Signed Message:\n";
+
+/// Returns the bytes that are signed for a message: the BLAKE2b-256 hash of the prefix, the decimal length of the
+/// message and the message.
+pub fn message_signing_payload(message: &[u8]) -> [u8; 32] {
+    Blake2b256::new()
+        .chain_update(MESSAGE_PREFIX)
+        .chain_update(message.len().to_string())
+        .chain_update(message)
+        .finalize()
+        .into()
+}
+
+/// Verifies that a message was signed with [`SecretManage::sign_message()`](super::SecretManage::sign_message()) by
+/// the key of the address.
+pub fn verify_message(address: &Ed25519Address, signature: &Ed25519Signature, message: &[u8]) -> bool {
+    signature.is_valid(&message_signing_payload(message), address).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto::keys::bip44::Bip44;
+
+    use super::*;
+    use crate::client::{
+        constants::IOTA_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManage},
+    };
+
+    const MNEMONIC: &str = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+
+    #[tokio::test]
+    async fn sign_and_verify_message() {
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(MNEMONIC.to_owned()).unwrap();
+        let addresses = secret_manager
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..2, None)
+            .await
+            .unwrap();
+        let message = b"I own this address";
+
+        let signature = secret_manager
+            .sign_message(message, Bip44::new(IOTA_COIN_TYPE))
+            .await
+            .unwrap();
+
+        assert!(verify_message(&addresses[0], &signature, message));
+        assert!(!verify_message(&addresses[1], &signature, message));
+        assert!(!verify_message(&addresses[0], &signature, b"I own this address too"));
+        // The message itself isn't signed.
+        assert!(signature.is_valid(message, &addresses[0]).is_err());
+    }
+}
@@ -3,12 +3,18 @@
 
 //! Implementation of [`MnemonicSecretManager`].
 
-use std::ops::Range;
+use std::{ops::Range, sync::RwLock};
 
 use async_trait::async_trait;
+#[cfg(feature = "encrypted_metadata")]
+use crypto::keys::x25519;
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::{bip39::Mnemonic, bip44::Bip44, slip10::Seed},
+    keys::{
+        bip39::{Mnemonic, Passphrase},
+        bip44::Bip44,
+        slip10::Seed,
+    },
     signatures::{
         ed25519,
         secp256k1_ecdsa::{self, EvmAddress},
@@ -16,7 +22,7 @@ use crypto::{
 };
 use zeroize::Zeroizing;
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{types::MnemonicDto, GenerateAddressOptions, SecretManage};
 use crate::{
     client::{api::PreparedTransactionData, Client, Error},
     types::block::{
@@ -27,11 +33,19 @@ use crate::{
 /// Secret manager that uses only a mnemonic.
 ///
 /// Computation are done in-memory. A mnemonic needs to be supplied upon the creation of [`MnemonicSecretManager`].
-pub struct MnemonicSecretManager(Seed);
+/// The seed can be cleared from memory with [`MnemonicSecretManager::lock()`], e.g. during idle periods, it's zeroized
+/// when dropped.
+pub struct MnemonicSecretManager {
+    seed: RwLock<Option<Seed>>,
+    // The hash of the seed, to check that the secret manager is unlocked with the same seed.
+    seed_hash: [u8; 32],
+}
 
 impl std::fmt::Debug for MnemonicSecretManager {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.debug_tuple("MnemonicSecretManager").finish()
+        f.debug_struct("MnemonicSecretManager")
+            .field("locked", &self.is_locked())
+            .finish()
     }
 }
 
@@ -55,11 +69,13 @@ impl SecretManage for MnemonicSecretManager {
                     .with_change(internal as _)
                     .with_address_index(address_index);
 
-                let public_key = chain
-                    .derive(&self.0.to_master_key::<ed25519::SecretKey>())
-                    .secret_key()
-                    .public_key()
-                    .to_bytes();
+                let public_key = self.with_seed(|seed| {
+                    chain
+                        .derive(&seed.to_master_key::<ed25519::SecretKey>())
+                        .secret_key()
+                        .public_key()
+                        .to_bytes()
+                })?;
 
                 // Hash the public key to get the address
                 let result = Blake2b256::digest(public_key).into();
@@ -85,10 +101,12 @@ impl SecretManage for MnemonicSecretManager {
                     .with_change(internal as _)
                     .with_address_index(address_index);
 
-                let public_key = chain
-                    .derive(&self.0.to_master_key::<secp256k1_ecdsa::SecretKey>())
-                    .secret_key()
-                    .public_key();
+                let public_key = self.with_seed(|seed| {
+                    chain
+                        .derive(&seed.to_master_key::<secp256k1_ecdsa::SecretKey>())
+                        .secret_key()
+                        .public_key()
+                })?;
 
                 crate::client::Result::Ok(public_key.evm_address())
             })
@@ -97,22 +115,41 @@ impl SecretManage for MnemonicSecretManager {
 
     async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
         // Get the private and public key for this Ed25519 address
-        let private_key = chain.derive(&self.0.to_master_key::<ed25519::SecretKey>()).secret_key();
+        let private_key =
+            self.with_seed(|seed| chain.derive(&seed.to_master_key::<ed25519::SecretKey>()).secret_key())?;
         let public_key = private_key.public_key();
         let signature = private_key.sign(msg);
 
         Ok(Ed25519Signature::new(public_key, signature))
     }
 
+    #[cfg(feature = "encrypted_metadata")]
+    async fn derive_x25519_public_key(&self, chain: Bip44) -> Result<x25519::PublicKey, Self::Error> {
+        Ok(self.x25519_secret_key(chain)?.public_key())
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn x25519_diffie_hellman(
+        &self,
+        public_key: &x25519::PublicKey,
+        chain: Bip44,
+    ) -> Result<Zeroizing<[u8; 32]>, Self::Error> {
+        Ok(Zeroizing::new(
+            self.x25519_secret_key(chain)?.diffie_hellman(public_key).to_bytes(),
+        ))
+    }
+
     async fn sign_secp256k1_ecdsa(
         &self,
         msg: &[u8],
         chain: Bip44,
     ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
         // Get the private and public key for this secp256k1_ecdsa key
-        let private_key = chain
-            .derive(&self.0.to_master_key::<secp256k1_ecdsa::SecretKey>())
-            .secret_key();
+        let private_key = self.with_seed(|seed| {
+            chain
+                .derive(&seed.to_master_key::<secp256k1_ecdsa::SecretKey>())
+                .secret_key()
+        })?;
         let public_key = private_key.public_key();
         let signature = private_key.try_sign_keccak256(msg)?;
 
@@ -136,11 +173,70 @@ impl SecretManage for MnemonicSecretManager {
 }
 
 impl MnemonicSecretManager {
+    fn new(seed: Seed) -> Self {
+        Self {
+            seed_hash: Blake2b256::digest(seed.as_ref()).into(),
+            seed: RwLock::new(Some(seed)),
+        }
+    }
+
+    // Calls `f` with the seed, if the secret manager isn't locked.
+    fn with_seed<T>(&self, f: impl FnOnce(&Seed) -> T) -> Result<T, Error> {
+        // PANIC: the lock can't be poisoned, as nothing panics while it's held.
+        self.seed
+            .read()
+            .expect("seed lock poisoned")
+            .as_ref()
+            .map(f)
+            .ok_or(Error::SecretManagerLocked)
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    fn x25519_secret_key(&self, chain: Bip44) -> Result<x25519::SecretKey, Error> {
+        self.with_seed(|seed| {
+            super::encrypted_metadata::x25519_secret_key(
+                &chain.derive(&seed.to_master_key::<ed25519::SecretKey>()).secret_key(),
+            )
+        })
+    }
+
+    fn unlock_with_seed(&self, seed: Seed) -> Result<(), Error> {
+        if <[u8; 32]>::from(Blake2b256::digest(seed.as_ref())) != self.seed_hash {
+            return Err(Error::SecretManagerUnlockMismatch);
+        }
+        // PANIC: the lock can't be poisoned, as nothing panics while it's held.
+        *self.seed.write().expect("seed lock poisoned") = Some(seed);
+
+        Ok(())
+    }
+
     /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English.
     ///
     /// For more information, see <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>.
     pub fn try_from_mnemonic(mnemonic: impl Into<Mnemonic>) -> Result<Self, Error> {
-        Ok(Self(Client::mnemonic_to_seed(mnemonic.into())?.into()))
+        Ok(Self::new(Client::mnemonic_to_seed(mnemonic.into())?.into()))
+    }
+
+    /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English and a BIP-39 passphrase, also known as
+    /// the 25th word. A different passphrase results in a different seed, so in different addresses.
+    pub fn try_from_mnemonic_with_passphrase(
+        mnemonic: impl Into<Mnemonic>,
+        passphrase: impl Into<Passphrase>,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(
+            Client::mnemonic_with_passphrase_to_seed(mnemonic.into(), passphrase.into())?.into(),
+        ))
+    }
+
+    /// Create a new [`MnemonicSecretManager`] from a [`MnemonicDto`], the passphrase is optional.
+    pub fn try_from_dto(dto: &MnemonicDto) -> Result<Self, Error> {
+        let mnemonic = Mnemonic::from(dto.mnemonic.as_str().to_owned());
+        match &dto.passphrase {
+            Some(passphrase) => {
+                Self::try_from_mnemonic_with_passphrase(mnemonic, Passphrase::from(passphrase.as_str().to_owned()))
+            }
+            None => Self::try_from_mnemonic(mnemonic),
+        }
     }
 
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
@@ -148,7 +244,45 @@ impl MnemonicSecretManager {
         let hex = hex.into();
         let bytes = Zeroizing::new(prefix_hex::decode::<Vec<u8>>(hex.as_str())?);
         let seed = Seed::from_bytes(bytes.as_ref());
-        Ok(Self(seed))
+        Ok(Self::new(seed))
+    }
+
+    /// Clears the seed from memory, until the secret manager is unlocked again. Address generation and signing fail
+    /// with [`Error::SecretManagerLocked`] in the meantime.
+    pub fn lock(&self) {
+        // PANIC: the lock can't be poisoned, as nothing panics while it's held.
+        // The seed is zeroized when it's dropped.
+        self.seed.write().expect("seed lock poisoned").take();
+    }
+
+    /// Returns whether the seed is cleared from memory.
+    pub fn is_locked(&self) -> bool {
+        // PANIC: the lock can't be poisoned, as nothing panics while it's held.
+        self.seed.read().expect("seed lock poisoned").is_none()
+    }
+
+    /// Unlocks the secret manager with the mnemonic it was created from.
+    pub fn unlock(&self, mnemonic: impl Into<Mnemonic>) -> Result<(), Error> {
+        self.unlock_with_seed(Client::mnemonic_to_seed(mnemonic.into())?.into())
+    }
+
+    /// Unlocks the secret manager with the [`MnemonicDto`] it was created from, the passphrase is optional.
+    pub fn unlock_with_dto(&self, dto: &MnemonicDto) -> Result<(), Error> {
+        let mnemonic = Mnemonic::from(dto.mnemonic.as_str().to_owned());
+        match &dto.passphrase {
+            Some(passphrase) => self.unlock_with_seed(
+                Client::mnemonic_with_passphrase_to_seed(mnemonic, Passphrase::from(passphrase.as_str().to_owned()))?
+                    .into(),
+            ),
+            None => self.unlock(mnemonic),
+        }
+    }
+
+    /// Unlocks the secret manager with the hex-encoded raw seed it was created from.
+    pub fn unlock_with_hex_seed(&self, hex: impl Into<Zeroizing<String>>) -> Result<(), Error> {
+        let hex = hex.into();
+        let bytes = Zeroizing::new(prefix_hex::decode::<Vec<u8>>(hex.as_str())?);
+        self.unlock_with_seed(Seed::from_bytes(bytes.as_ref()))
     }
 }
 
@@ -177,6 +311,73 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn passphrase_address() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let without_passphrase = MnemonicSecretManager::try_from_dto(&MnemonicDto {
+            mnemonic: mnemonic.to_owned().into(),
+            passphrase: None,
+        })
+        .unwrap();
+        let with_passphrase =
+            MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic.to_owned(), "passphrase".to_owned())
+                .unwrap();
+
+        let address = |secret_manager: MnemonicSecretManager| async move {
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap()[0]
+                .to_bech32_unchecked("atoi")
+        };
+
+        assert_eq!(
+            address(without_passphrase).await,
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e"
+        );
+        assert_ne!(
+            address(with_passphrase).await,
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e"
+        );
+    }
+
+    #[tokio::test]
+    async fn lock_unlock() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(mnemonic.to_owned()).unwrap();
+
+        secret_manager.lock();
+        assert!(secret_manager.is_locked());
+        assert!(matches!(
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await,
+            Err(Error::SecretManagerLocked)
+        ));
+
+        let other_mnemonic = "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river";
+        assert!(matches!(
+            secret_manager.unlock(other_mnemonic.to_owned()),
+            Err(Error::SecretManagerUnlockMismatch)
+        ));
+        assert!(secret_manager.is_locked());
+
+        secret_manager.unlock(mnemonic.to_owned()).unwrap();
+        assert!(!secret_manager.is_locked());
+        assert_eq!(
+            secret_manager
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap()[0]
+                .to_bech32_unchecked("atoi"),
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e"
+        );
+    }
+
     #[tokio::test]
     async fn seed_address() {
         use crate::client::constants::IOTA_COIN_TYPE;
@@ -3,16 +3,50 @@
 
 //! Secret manager module enabling address generation and transaction essence signing.
 
+/// Module for callback based secret management.
+pub mod callback;
+/// Module for EIP-712 typed data encoding.
+pub mod eip712;
+/// Module for encrypting metadata to the X25519 key of a recipient.
+#[cfg(feature = "encrypted_metadata")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encrypted_metadata")))]
+pub mod encrypted_metadata;
+/// Module for encrypted mnemonic file based secret management.
+#[cfg(feature = "encrypted_mnemonic_secret_manager")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encrypted_mnemonic_secret_manager")))]
+pub mod encrypted_mnemonic;
+/// Module for Ethereum transaction encoding.
+pub mod evm;
+/// Module for experimental FROST threshold signing.
+#[cfg(feature = "frost")]
+#[cfg_attr(docsrs, doc(cfg(feature = "frost")))]
+pub mod frost;
+/// Module for the errors and transaction checks of the ledger nano secret managers.
+#[cfg(any(feature = "ledger_nano", feature = "ledger_nano_simulator"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "ledger_nano", feature = "ledger_nano_simulator"))))]
+pub mod ledger_common;
 /// Module for ledger nano based secret management.
 #[cfg(feature = "ledger_nano")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
 pub mod ledger_nano;
+/// Module for a software simulation of a ledger nano.
+#[cfg(feature = "ledger_nano_simulator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano_simulator")))]
+pub mod ledger_nano_simulator;
+/// Module for signing arbitrary messages.
+pub mod message;
 /// Module for mnemonic based secret management.
 pub mod mnemonic;
+/// Module for PKCS#11 token based secret management.
+#[cfg(feature = "pkcs11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+pub mod pkcs11;
 /// Module for single private key based secret management.
 #[cfg(feature = "private_key_secret_manager")]
 #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
 pub mod private_key;
+/// Module for routing to other secret managers by account.
+pub mod router;
 /// Module for stronghold based secret management.
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -25,6 +59,8 @@ use std::time::Duration;
 use std::{collections::HashMap, fmt, ops::Range, str::FromStr};
 
 use async_trait::async_trait;
+#[cfg(feature = "encrypted_metadata")]
+use crypto::keys::x25519;
 use crypto::{
     keys::{bip39::Mnemonic, bip44::Bip44},
     signatures::secp256k1_ecdsa::{self, EvmAddress},
@@ -32,14 +68,32 @@ use crypto::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use zeroize::Zeroizing;
 
+#[cfg(feature = "encrypted_mnemonic_secret_manager")]
+use self::encrypted_mnemonic::EncryptedMnemonicSecretManager;
+#[cfg(feature = "frost")]
+use self::frost::FrostSecretManager;
 #[cfg(feature = "ledger_nano")]
 use self::ledger_nano::LedgerSecretManager;
-use self::mnemonic::MnemonicSecretManager;
+#[cfg(feature = "ledger_nano_simulator")]
+use self::ledger_nano_simulator::LedgerSimulatorSecretManager;
+#[cfg(feature = "pkcs11")]
+use self::pkcs11::Pkcs11SecretManager;
 #[cfg(feature = "private_key_secret_manager")]
 use self::private_key::PrivateKeySecretManager;
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
-pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
+pub use self::types::{GenerateAddressOptions, LedgerNanoStatus, UnlockSecret};
+use self::{
+    callback::CallbackSecretManager,
+    eip712::{Eip712Domain, Eip712Types},
+    evm::{EvmTransaction, SignedEvmTransaction},
+    mnemonic::MnemonicSecretManager,
+    router::RouterSecretManager,
+};
+#[cfg(feature = "encrypted_mnemonic_secret_manager")]
+use crate::client::secret::types::EncryptedMnemonicDto;
+#[cfg(feature = "pkcs11")]
+use crate::client::secret::types::Pkcs11Dto;
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
 use crate::{
@@ -49,6 +103,7 @@ use crate::{
             transaction::validate_transaction_payload_length,
             verify_semantic, PreparedTransactionData,
         },
+        secret::types::MnemonicDto,
         Error,
     },
     types::block::{
@@ -96,6 +151,75 @@ pub trait SecretManage: Send + Sync {
         chain: Bip44,
     ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error>;
 
+    /// Signs a message using the given [`Bip44`] using Ed25519, to prove the ownership of its address. The message is
+    /// prefixed and hashed before it's signed, see [`message::message_signing_payload()`], so the signature can be
+    /// verified with [`message::verify_message()`], but can't be used for a transaction.
+    async fn sign_message(&self, message: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        self.sign_ed25519(&message::message_signing_payload(message), chain)
+            .await
+    }
+
+    /// Derives the X25519 public key of the given [`Bip44`] from its Ed25519 key, which other parties can use to
+    /// encrypt metadata to it with [`encrypted_metadata::encrypt_metadata()`].
+    #[cfg(feature = "encrypted_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_metadata")))]
+    async fn derive_x25519_public_key(&self, chain: Bip44) -> Result<x25519::PublicKey, Self::Error>
+    where
+        Self::Error: From<crate::client::Error>,
+    {
+        let _ = chain;
+        Err(crate::client::Error::from(encrypted_metadata::Error::Unsupported).into())
+    }
+
+    /// Computes the X25519 shared secret of the key of the given [`Bip44`] and `public_key`, used to decrypt metadata
+    /// with [`encrypted_metadata::decrypt_metadata()`].
+    #[cfg(feature = "encrypted_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_metadata")))]
+    async fn x25519_diffie_hellman(
+        &self,
+        public_key: &x25519::PublicKey,
+        chain: Bip44,
+    ) -> Result<Zeroizing<[u8; 32]>, Self::Error>
+    where
+        Self::Error: From<crate::client::Error>,
+    {
+        let _ = (public_key, chain);
+        Err(crate::client::Error::from(encrypted_metadata::Error::Unsupported).into())
+    }
+
+    /// Signs [EIP-712] typed data using the given [`Bip44`] using Secp256k1, like `eth_signTypedData_v4`. The primary
+    /// type of `message` is the only type in `types` that isn't referenced by another type. The signature recovers to
+    /// the EVM address of the chain, its recovery id plus 27 is the `v` of the Ethereum signature.
+    ///
+    /// [EIP-712]: https://eips.ethereum.org/EIPS/eip-712
+    async fn sign_typed_data(
+        &self,
+        domain: &Eip712Domain,
+        types: &Eip712Types,
+        message: &serde_json::Value,
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error>
+    where
+        Self::Error: From<crate::client::Error>,
+    {
+        let payload = eip712::signing_payload(domain, types, message).map_err(crate::client::Error::from)?;
+
+        // The payload is hashed with Keccak-256 before it's signed, which results in the EIP-712 digest.
+        self.sign_secp256k1_ecdsa(&payload, chain).await
+    }
+
+    /// Signs a legacy or EIP-1559 Ethereum transaction using the given [`Bip44`] using Secp256k1, returning the RLP
+    /// encoded transaction that can be sent with `eth_sendRawTransaction`.
+    async fn sign_evm_transaction(
+        &self,
+        transaction: &EvmTransaction,
+        chain: Bip44,
+    ) -> Result<SignedEvmTransaction, Self::Error> {
+        let (public_key, signature) = self.sign_secp256k1_ecdsa(&transaction.signing_payload(), chain).await?;
+
+        Ok(transaction.signed(&public_key, &signature))
+    }
+
     /// Signs `essence_hash` using the given `chain`, returning an [`Unlock`].
     async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: Bip44) -> Result<Unlock, Self::Error> {
         Ok(Unlock::Signature(SignatureUnlock::new(Signature::from(
@@ -139,15 +263,41 @@ pub enum SecretManager {
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerNano(LedgerSecretManager),
 
+    /// Secret manager that simulates a Ledger Nano in software, for tests.
+    #[cfg(feature = "ledger_nano_simulator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano_simulator")))]
+    LedgerSimulator(Box<LedgerSimulatorSecretManager>),
+
     /// Secret manager that uses a mnemonic in plain memory. It's not recommended for production use. Use
     /// LedgerNano or Stronghold instead.
     Mnemonic(MnemonicSecretManager),
 
+    /// Secret manager that uses a mnemonic stored in a password encrypted file.
+    #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_mnemonic_secret_manager")))]
+    EncryptedMnemonic(Box<EncryptedMnemonicSecretManager>),
+
     /// Secret manager that uses a single private key.
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
     PrivateKey(Box<PrivateKeySecretManager>),
 
+    /// Secret manager that uses Ed25519 keys stored on a PKCS#11 token.
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    Pkcs11(Box<Pkcs11SecretManager>),
+
+    /// Experimental secret manager that signs with a threshold of the signers of a FROST group.
+    #[cfg(feature = "frost")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "frost")))]
+    Frost(Box<FrostSecretManager>),
+
+    /// Secret manager that delegates address generation and signing to async callbacks, e.g. for a remote signer.
+    Callback(Box<CallbackSecretManager>),
+
+    /// Secret manager that routes to other secret managers by the coin type and account index.
+    Router(Box<RouterSecretManager>),
+
     /// Secret manager that's just a placeholder, so it can be provided to an online wallet, but can't be used for
     /// signing.
     Placeholder,
@@ -167,12 +317,26 @@ impl From<LedgerSecretManager> for SecretManager {
     }
 }
 
+#[cfg(feature = "ledger_nano_simulator")]
+impl From<LedgerSimulatorSecretManager> for SecretManager {
+    fn from(secret_manager: LedgerSimulatorSecretManager) -> Self {
+        Self::LedgerSimulator(Box::new(secret_manager))
+    }
+}
+
 impl From<MnemonicSecretManager> for SecretManager {
     fn from(secret_manager: MnemonicSecretManager) -> Self {
         Self::Mnemonic(secret_manager)
     }
 }
 
+#[cfg(feature = "encrypted_mnemonic_secret_manager")]
+impl From<EncryptedMnemonicSecretManager> for SecretManager {
+    fn from(secret_manager: EncryptedMnemonicSecretManager) -> Self {
+        Self::EncryptedMnemonic(Box::new(secret_manager))
+    }
+}
+
 #[cfg(feature = "private_key_secret_manager")]
 impl From<PrivateKeySecretManager> for SecretManager {
     fn from(secret_manager: PrivateKeySecretManager) -> Self {
@@ -180,6 +344,32 @@ impl From<PrivateKeySecretManager> for SecretManager {
     }
 }
 
+#[cfg(feature = "pkcs11")]
+impl From<Pkcs11SecretManager> for SecretManager {
+    fn from(secret_manager: Pkcs11SecretManager) -> Self {
+        Self::Pkcs11(Box::new(secret_manager))
+    }
+}
+
+#[cfg(feature = "frost")]
+impl From<FrostSecretManager> for SecretManager {
+    fn from(secret_manager: FrostSecretManager) -> Self {
+        Self::Frost(Box::new(secret_manager))
+    }
+}
+
+impl From<CallbackSecretManager> for SecretManager {
+    fn from(secret_manager: CallbackSecretManager) -> Self {
+        Self::Callback(Box::new(secret_manager))
+    }
+}
+
+impl From<RouterSecretManager> for SecretManager {
+    fn from(secret_manager: RouterSecretManager) -> Self {
+        Self::Router(Box::new(secret_manager))
+    }
+}
+
 impl fmt::Debug for SecretManager {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -187,9 +377,21 @@ impl fmt::Debug for SecretManager {
             Self::Stronghold(_) => f.debug_tuple("Stronghold").field(&"...").finish(),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(_) => f.debug_tuple("LedgerNano").field(&"...").finish(),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(_) => f.debug_tuple("LedgerSimulator").field(&"...").finish(),
             Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => {
+                f.debug_tuple("EncryptedMnemonic").field(secret_manager).finish()
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => f.debug_tuple("PrivateKey").field(&"...").finish(),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(_) => f.debug_tuple("Pkcs11").field(&"...").finish(),
+            #[cfg(feature = "frost")]
+            Self::Frost(_) => f.debug_tuple("Frost").field(&"...").finish(),
+            Self::Callback(_) => f.debug_tuple("Callback").field(&"...").finish(),
+            Self::Router(secret_manager) => f.debug_tuple("Router").field(secret_manager).finish(),
             Self::Placeholder => f.debug_struct("Placeholder").finish(),
         }
     }
@@ -208,9 +410,19 @@ impl fmt::Display for SecretManager {
                     write!(f, "LedgerNano")
                 }
             }
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(_) => write!(f, "LedgerSimulator"),
             Self::Mnemonic(_) => write!(f, "Mnemonic"),
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(_) => write!(f, "EncryptedMnemonic"),
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => write!(f, "PrivateKey"),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(_) => write!(f, "Pkcs11"),
+            #[cfg(feature = "frost")]
+            Self::Frost(_) => write!(f, "Frost"),
+            Self::Callback(_) => write!(f, "Callback"),
+            Self::Router(_) => write!(f, "Router"),
             Self::Placeholder => write!(f, "Placeholder"),
         }
     }
@@ -241,11 +453,24 @@ pub enum SecretManagerDto {
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(Zeroizing<String>),
+    /// Mnemonic with a BIP-39 passphrase
+    #[serde(alias = "mnemonicWithPassphrase")]
+    MnemonicWithPassphrase(MnemonicDto),
+    /// Encrypted mnemonic
+    #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_mnemonic_secret_manager")))]
+    #[serde(alias = "encryptedMnemonic")]
+    EncryptedMnemonic(EncryptedMnemonicDto),
     /// Private Key
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
     #[serde(alias = "privateKey")]
     PrivateKey(Zeroizing<String>),
+    /// PKCS#11
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    #[serde(alias = "pkcs11")]
+    Pkcs11(Pkcs11Dto),
     /// Hex seed
     #[serde(alias = "hexSeed")]
     HexSeed(Zeroizing<String>),
@@ -281,11 +506,23 @@ impl TryFrom<SecretManagerDto> for SecretManager {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
 
+            SecretManagerDto::MnemonicWithPassphrase(mnemonic_dto) => {
+                Self::Mnemonic(MnemonicSecretManager::try_from_dto(&mnemonic_dto)?)
+            }
+
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            SecretManagerDto::EncryptedMnemonic(encrypted_mnemonic_dto) => Self::EncryptedMnemonic(Box::new(
+                EncryptedMnemonicSecretManager::from_config(&encrypted_mnemonic_dto)?,
+            )),
+
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key)?))
             }
 
+            #[cfg(feature = "pkcs11")]
+            SecretManagerDto::Pkcs11(pkcs11_dto) => Self::Pkcs11(Box::new(Pkcs11SecretManager::new(pkcs11_dto)?)),
+
             SecretManagerDto::HexSeed(hex_seed) => {
                 // `SecretManagerDto` is `ZeroizeOnDrop` so it will take care of zeroizing the original.
                 Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(hex_seed)?)
@@ -319,9 +556,32 @@ impl From<&SecretManager> for SecretManagerDto {
             // to know the type
             SecretManager::Mnemonic(_mnemonic) => Self::Mnemonic("...".to_string().into()),
 
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            SecretManager::EncryptedMnemonic(encrypted_mnemonic) => Self::EncryptedMnemonic(EncryptedMnemonicDto {
+                password: None,
+                encrypted_mnemonic: encrypted_mnemonic.encrypted_mnemonic().clone(),
+            }),
+
+            // The simulator is only used in tests, it needs to be provided again when the secret manager is restored
+            #[cfg(feature = "ledger_nano_simulator")]
+            SecretManager::LedgerSimulator(_simulator) => Self::Placeholder,
+
             #[cfg(feature = "private_key_secret_manager")]
             SecretManager::PrivateKey(_private_key) => Self::PrivateKey("...".to_string().into()),
 
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => Self::Pkcs11(pkcs11.config()),
+
+            // The signers can't be serialized, they need to be provided again when the secret manager is restored
+            #[cfg(feature = "frost")]
+            SecretManager::Frost(_frost) => Self::Placeholder,
+
+            // Callbacks can't be serialized, they need to be provided again when the secret manager is restored
+            SecretManager::Callback(_callback) => Self::Placeholder,
+
+            // The routed secret managers can't all be serialized, so the routes need to be provided again
+            SecretManager::Router(_router) => Self::Placeholder,
+
             SecretManager::Placeholder => Self::Placeholder,
         }
     }
@@ -347,17 +607,51 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager
                 .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                 .await?),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Mnemonic(secret_manager) => {
                 secret_manager
                     .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => {
                 secret_manager
                     .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            #[cfg(feature = "frost")]
+            Self::Frost(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            Self::Callback(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            Self::Router(secret_manager) => {
+                secret_manager
+                    .generate_ed25519_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -378,17 +672,51 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager
                 .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                 .await?),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Mnemonic(secret_manager) => {
                 secret_manager
                     .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => {
                 secret_manager
                     .generate_evm_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            #[cfg(feature = "frost")]
+            Self::Frost(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            Self::Callback(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            Self::Router(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -399,10 +727,48 @@ impl SecretManage for SecretManager {
             Self::Stronghold(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             Self::Mnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            #[cfg(feature = "frost")]
+            Self::Frost(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::Callback(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::Router(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::Placeholder => Err(Error::PlaceholderSecretManager),
+        }
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn derive_x25519_public_key(&self, chain: Bip44) -> Result<x25519::PublicKey, Self::Error> {
+        match self {
+            Self::Mnemonic(secret_manager) => secret_manager.derive_x25519_public_key(chain).await,
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => secret_manager.derive_x25519_public_key(chain).await,
+            Self::Router(secret_manager) => secret_manager.derive_x25519_public_key(chain).await,
+            Self::Placeholder => Err(Error::PlaceholderSecretManager),
+            _ => Err(encrypted_metadata::Error::Unsupported.into()),
+        }
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn x25519_diffie_hellman(
+        &self,
+        public_key: &x25519::PublicKey,
+        chain: Bip44,
+    ) -> Result<Zeroizing<[u8; 32]>, Self::Error> {
+        match self {
+            Self::Mnemonic(secret_manager) => secret_manager.x25519_diffie_hellman(public_key, chain).await,
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => secret_manager.x25519_diffie_hellman(public_key, chain).await,
+            Self::Router(secret_manager) => secret_manager.x25519_diffie_hellman(public_key, chain).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
+            _ => Err(encrypted_metadata::Error::Unsupported.into()),
         }
     }
 
@@ -416,9 +782,19 @@ impl SecretManage for SecretManager {
             Self::Stronghold(secret_manager) => Ok(secret_manager.sign_secp256k1_ecdsa(msg, chain).await?),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_secp256k1_ecdsa(msg, chain).await?),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
             Self::Mnemonic(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            #[cfg(feature = "frost")]
+            Self::Frost(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            Self::Callback(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
+            Self::Router(secret_manager) => secret_manager.sign_secp256k1_ecdsa(msg, chain).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -437,17 +813,51 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager
                 .sign_transaction_essence(prepared_transaction_data, time)
                 .await?),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             Self::Mnemonic(secret_manager) => {
                 secret_manager
                     .sign_transaction_essence(prepared_transaction_data, time)
                     .await
             }
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => {
                 secret_manager
                     .sign_transaction_essence(prepared_transaction_data, time)
                     .await
             }
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
+            #[cfg(feature = "frost")]
+            Self::Frost(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
+            Self::Callback(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
+            Self::Router(secret_manager) => {
+                secret_manager
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -461,9 +871,19 @@ impl SecretManage for SecretManager {
             Self::Stronghold(secret_manager) => Ok(secret_manager.sign_transaction(prepared_transaction_data).await?),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_transaction(prepared_transaction_data).await?),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             Self::Mnemonic(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            #[cfg(feature = "frost")]
+            Self::Frost(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            Self::Callback(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
+            Self::Router(secret_manager) => secret_manager.sign_transaction(prepared_transaction_data).await,
             Self::Placeholder => Err(Error::PlaceholderSecretManager),
         }
     }
@@ -488,9 +908,19 @@ impl SecretManagerConfig for SecretManager {
             Self::Stronghold(s) => s.to_config().map(Self::Config::Stronghold),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(s) => s.to_config().map(Self::Config::LedgerNano),
+            #[cfg(feature = "ledger_nano_simulator")]
+            Self::LedgerSimulator(_) => None,
             Self::Mnemonic(_) => None,
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(s) => s.to_config().map(Self::Config::EncryptedMnemonic),
             #[cfg(feature = "private_key_secret_manager")]
             Self::PrivateKey(_) => None,
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(s) => s.to_config().map(Self::Config::Pkcs11),
+            #[cfg(feature = "frost")]
+            Self::Frost(_) => None,
+            Self::Callback(_) => None,
+            Self::Router(_) => None,
             Self::Placeholder => None,
         }
     }
@@ -507,10 +937,19 @@ impl SecretManagerConfig for SecretManager {
             SecretManagerDto::Mnemonic(mnemonic) => {
                 Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic.as_str().to_owned())?)
             }
+            SecretManagerDto::MnemonicWithPassphrase(mnemonic_dto) => {
+                Self::Mnemonic(MnemonicSecretManager::try_from_dto(mnemonic_dto)?)
+            }
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            SecretManagerDto::EncryptedMnemonic(config) => {
+                Self::EncryptedMnemonic(Box::new(EncryptedMnemonicSecretManager::from_config(config)?))
+            }
             #[cfg(feature = "private_key_secret_manager")]
             SecretManagerDto::PrivateKey(private_key) => {
                 Self::PrivateKey(Box::new(PrivateKeySecretManager::try_from_hex(private_key.to_owned())?))
             }
+            #[cfg(feature = "pkcs11")]
+            SecretManagerDto::Pkcs11(config) => Self::Pkcs11(Box::new(Pkcs11SecretManager::from_config(config)?)),
             SecretManagerDto::Placeholder => Self::Placeholder,
         })
     }
@@ -526,6 +965,51 @@ impl SecretManager {
     pub fn try_from_hex_seed(seed: impl Into<Zeroizing<String>>) -> crate::client::Result<Self> {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
     }
+
+    /// Clears the secrets of a Stronghold or mnemonic based secret manager from memory, e.g. during idle periods,
+    /// until it's unlocked again with [`SecretManager::unlock()`]. Secret managers that don't keep secrets in memory
+    /// are left as they are.
+    pub async fn lock(&self) -> crate::client::Result<()> {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => secret_manager.clear_key().await,
+            Self::Mnemonic(secret_manager) => secret_manager.lock(),
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            Self::EncryptedMnemonic(secret_manager) => secret_manager.lock(),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(_) => {}
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(_) => {}
+            Self::Callback(_) | Self::Placeholder => {}
+            _ => return Err(Error::SecretManagerMismatch),
+        }
+
+        Ok(())
+    }
+
+    /// Unlocks a secret manager that was locked with [`SecretManager::lock()`], the secret has to match the one it
+    /// was created from.
+    pub async fn unlock(&self, secret: UnlockSecret) -> crate::client::Result<()> {
+        match (self, secret) {
+            #[cfg(feature = "stronghold")]
+            (Self::Stronghold(secret_manager), UnlockSecret::Password(password)) => {
+                secret_manager.set_password(password).await?
+            }
+            (Self::Mnemonic(secret_manager), UnlockSecret::Mnemonic(mnemonic_dto)) => {
+                secret_manager.unlock_with_dto(&mnemonic_dto)?
+            }
+            (Self::Mnemonic(secret_manager), UnlockSecret::HexSeed(hex_seed)) => {
+                secret_manager.unlock_with_hex_seed(hex_seed)?
+            }
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            (Self::EncryptedMnemonic(secret_manager), UnlockSecret::Password(password)) => {
+                secret_manager.unlock(&password)?
+            }
+            _ => return Err(Error::SecretManagerMismatch),
+        }
+
+        Ok(())
+    }
 }
 
 pub(crate) async fn default_sign_transaction_essence<M: SecretManage>(
@@ -0,0 +1,342 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`Pkcs11SecretManager`].
+//!
+//! PKCS#11 tokens can't derive SLIP-10 keys, so every BIP-44 chain gets its own Ed25519 key pair that's generated on
+//! the token the first time an address of the chain is requested. The keys are found by their label, which is
+//! `{key_label_prefix}/{coin_type}'/{account}'/{change}'/{address_index}'`.
+//!
+//! Generating addresses therefore provisions persistent key pairs on the token, signing only uses existing keys.
+//! The PKCS#11 calls block until the token responds, so they run on the blocking thread pool of tokio.
+
+use std::{
+    ops::Range,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip44::Bip44,
+    signatures::{
+        ed25519,
+        secp256k1_ecdsa::{self, EvmAddress},
+    },
+};
+use cryptoki::{
+    context::{CInitializeArgs, Pkcs11},
+    mechanism::Mechanism,
+    object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle},
+    session::{Session, UserType},
+    types::AuthPin,
+};
+
+use super::{types::Pkcs11Dto, GenerateAddressOptions, SecretManage, SecretManagerConfig};
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+
+/// The default prefix of the labels of the keys on the token.
+pub const DEFAULT_KEY_LABEL_PREFIX: &str = "iota";
+
+// DER encoded OID of Ed25519 (1.3.101.112), used as CKA_EC_PARAMS
+const ED25519_EC_PARAMS: [u8; 5] = [0x06, 0x03, 0x2b, 0x65, 0x70];
+
+/// PKCS#11 errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// PKCS#11 library error
+    #[error("{0}")]
+    Cryptoki(#[from] cryptoki::error::Error),
+    /// The token returned an invalid public key
+    #[error("invalid public key for key `{0}`")]
+    InvalidPublicKey(String),
+    /// The token returned an invalid signature
+    #[error("invalid signature for key `{0}`")]
+    InvalidSignature(String),
+    /// No private key found for the label
+    #[error("no key found with label `{0}`")]
+    KeyNotFound(String),
+    /// The session lock was poisoned
+    #[error("PKCS#11 session lock poisoned")]
+    PoisonError,
+    /// No token in the configured slot
+    #[error("no token found in slot {0}")]
+    SlotNotFound(u64),
+}
+
+/// Secret manager that uses Ed25519 keys stored on a PKCS#11 token, e.g. SoftHSM, YubiHSM or a cloud HSM.
+pub struct Pkcs11SecretManager {
+    session: Arc<Mutex<Session>>,
+    config: Pkcs11Dto,
+}
+
+impl std::fmt::Debug for Pkcs11SecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pkcs11SecretManager")
+            .field("module_path", &self.config.module_path)
+            .field("slot", &self.config.slot)
+            .finish()
+    }
+}
+
+impl Pkcs11SecretManager {
+    /// Loads the PKCS#11 module, opens a session with the token in the configured slot and logs in with the pin, if
+    /// one is configured.
+    pub fn new(config: Pkcs11Dto) -> Result<Self, Error> {
+        let context = Pkcs11::new(&config.module_path)?;
+        context.initialize(CInitializeArgs::OsThreads)?;
+
+        let slot = context
+            .get_slots_with_token()?
+            .into_iter()
+            .find(|slot| slot.id() == config.slot)
+            .ok_or(Error::SlotNotFound(config.slot))?;
+        let session = context.open_rw_session(slot)?;
+        if let Some(pin) = &config.pin {
+            session.login(UserType::User, Some(&AuthPin::new(pin.as_str().to_owned())))?;
+        }
+
+        Ok(Self {
+            session: Arc::new(Mutex::new(session)),
+            config,
+        })
+    }
+
+    /// Returns the configuration of the secret manager, without the pin.
+    pub fn config(&self) -> Pkcs11Dto {
+        Pkcs11Dto {
+            pin: None,
+            ..self.config.clone()
+        }
+    }
+
+    /// Runs `f` with the session on the blocking thread pool and passes it the prefix of the key labels.
+    async fn with_session<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&Session, &str) -> Result<T, Error> + Send + 'static,
+    ) -> Result<T, crate::client::Error> {
+        let session = self.session.clone();
+        let key_label_prefix = self
+            .config
+            .key_label_prefix
+            .clone()
+            .unwrap_or_else(|| DEFAULT_KEY_LABEL_PREFIX.to_owned());
+
+        Ok(tokio::task::spawn_blocking(move || {
+            let session = session.lock().map_err(|_| Error::PoisonError)?;
+            f(&session, &key_label_prefix)
+        })
+        .await??)
+    }
+}
+
+fn key_label(prefix: &str, chain: Bip44) -> String {
+    format!(
+        "{prefix}/{}'/{}'/{}'/{}'",
+        chain.coin_type, chain.account, chain.change, chain.address_index
+    )
+}
+
+// Returns the public key of the key pair with the label, if it exists.
+fn public_key(session: &Session, label: &str) -> Result<Option<ed25519::PublicKey>, Error> {
+    find_key(session, label, ObjectClass::PUBLIC_KEY)?
+        .map(|public_key| read_public_key(session, label, public_key))
+        .transpose()
+}
+
+// Returns the public key of the key pair with the label, the key pair is generated on the token if it doesn't exist
+// yet.
+fn provision_public_key(session: &Session, label: &str) -> Result<ed25519::PublicKey, Error> {
+    let public_key = match find_key(session, label, ObjectClass::PUBLIC_KEY)? {
+        Some(public_key) => public_key,
+        None => generate_key_pair(session, label)?,
+    };
+
+    read_public_key(session, label, public_key)
+}
+
+fn read_public_key(session: &Session, label: &str, public_key: ObjectHandle) -> Result<ed25519::PublicKey, Error> {
+    let ec_point = session
+        .get_attributes(public_key, &[AttributeType::EcPoint])?
+        .into_iter()
+        .find_map(|attribute| match attribute {
+            Attribute::EcPoint(ec_point) => Some(ec_point),
+            _ => None,
+        })
+        .ok_or_else(|| Error::InvalidPublicKey(label.to_owned()))?;
+
+    decode_ec_point(&ec_point)
+        .and_then(|bytes| ed25519::PublicKey::try_from_bytes(bytes).ok())
+        .ok_or_else(|| Error::InvalidPublicKey(label.to_owned()))
+}
+
+fn sign(session: &Session, label: &str, msg: &[u8]) -> Result<Ed25519Signature, Error> {
+    let private_key =
+        find_key(session, label, ObjectClass::PRIVATE_KEY)?.ok_or_else(|| Error::KeyNotFound(label.to_owned()))?;
+    let public_key = public_key(session, label)?.ok_or_else(|| Error::KeyNotFound(label.to_owned()))?;
+    let signature: [u8; ed25519::Signature::LENGTH] = session
+        .sign(&Mechanism::Eddsa, private_key, msg)?
+        .try_into()
+        .map_err(|_| Error::InvalidSignature(label.to_owned()))?;
+    let signature = ed25519::Signature::from_bytes(signature);
+
+    if !public_key.verify(&signature, msg) {
+        return Err(Error::InvalidSignature(label.to_owned()));
+    }
+
+    Ok(Ed25519Signature::new(public_key, signature))
+}
+
+fn find_key(session: &Session, label: &str, class: ObjectClass) -> Result<Option<ObjectHandle>, Error> {
+    Ok(session
+        .find_objects(&[
+            Attribute::Class(class),
+            Attribute::KeyType(KeyType::EC_EDWARDS),
+            Attribute::Label(label.as_bytes().to_vec()),
+        ])?
+        .into_iter()
+        .next())
+}
+
+// Returns the handle of the public key, the private key stays on the token and can't be extracted.
+fn generate_key_pair(session: &Session, label: &str) -> Result<ObjectHandle, Error> {
+    log::debug!("[PKCS#11] generating key pair {label}");
+
+    let label = Attribute::Label(label.as_bytes().to_vec());
+    let (public_key, _private_key) = session.generate_key_pair(
+        &Mechanism::EccEdwardsKeyPairGen,
+        &[
+            Attribute::Token(true),
+            Attribute::Verify(true),
+            Attribute::EcParams(ED25519_EC_PARAMS.to_vec()),
+            label.clone(),
+        ],
+        &[
+            Attribute::Token(true),
+            Attribute::Private(true),
+            Attribute::Sensitive(true),
+            Attribute::Extractable(false),
+            Attribute::Sign(true),
+            label,
+        ],
+    )?;
+
+    Ok(public_key)
+}
+
+// Tokens either return the raw public key or a DER encoded octet string as CKA_EC_POINT.
+fn decode_ec_point(ec_point: &[u8]) -> Option<[u8; ed25519::PublicKey::LENGTH]> {
+    match ec_point {
+        [0x04, 0x20, public_key @ ..] => public_key.try_into().ok(),
+        public_key => public_key.try_into().ok(),
+    }
+}
+
+#[async_trait]
+impl SecretManage for Pkcs11SecretManager {
+    type Error = crate::client::Error;
+
+    /// Generates a persistent key pair on the token for every chain that doesn't have one yet.
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        let internal = options.into().unwrap_or_default().internal;
+
+        self.with_session(move |session, key_label_prefix| {
+            address_indexes
+                .map(|address_index| {
+                    let chain = Bip44::new(coin_type)
+                        .with_account(account_index)
+                        .with_change(internal as _)
+                        .with_address_index(address_index);
+                    let public_key = provision_public_key(session, &key_label(key_label_prefix, chain))?;
+
+                    Ok(Ed25519Address::new(Blake2b256::digest(public_key.to_bytes()).into()))
+                })
+                .collect()
+        })
+        .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(crate::client::Error::SecretManagerMismatch)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        let msg = msg.to_vec();
+
+        self.with_session(move |session, key_label_prefix| sign(session, &key_label(key_label_prefix, chain), &msg))
+            .await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _msg: &[u8],
+        _chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        Err(crate::client::Error::SecretManagerMismatch)
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        super::default_sign_transaction_essence(self, prepared_transaction_data, time).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+impl SecretManagerConfig for Pkcs11SecretManager {
+    type Config = Pkcs11Dto;
+
+    fn to_config(&self) -> Option<Self::Config> {
+        Some(self.config())
+    }
+
+    fn from_config(config: &Self::Config) -> Result<Self, Self::Error> {
+        Ok(Self::new(config.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_labels() {
+        let chain = Bip44::new(4218).with_account(1).with_change(1).with_address_index(5);
+        assert_eq!(key_label(DEFAULT_KEY_LABEL_PREFIX, chain), "iota/4218'/1'/1'/5'");
+    }
+
+    #[test]
+    fn ec_point_encodings() {
+        let mut der = vec![0x04, 0x20];
+        der.extend([7; 32]);
+
+        assert_eq!(decode_ec_point(&der), Some([7; 32]));
+        assert_eq!(decode_ec_point(&[7; 32]), Some([7; 32]));
+        assert_eq!(decode_ec_point(&[7; 31]), None);
+    }
+}
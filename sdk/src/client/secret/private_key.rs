@@ -98,11 +98,11 @@ impl SecretManage for PrivateKeySecretManager {
 impl PrivateKeySecretManager {
     /// Create a new [`PrivateKeySecretManager`] from a base 58 encoded private key.
     pub fn try_from_b58<T: AsRef<[u8]>>(b58: T) -> Result<Self, Error> {
-        let mut bytes = [0u8; ed25519::SecretKey::LENGTH];
+        let mut bytes = Zeroizing::new([0u8; ed25519::SecretKey::LENGTH]);
 
         // TODO replace with a more fitting variant.
         if bs58::decode(b58.as_ref())
-            .onto(&mut bytes)
+            .onto(&mut *bytes)
             .map_err(|_| crypto::Error::PrivateKeyError)?
             != ed25519::SecretKey::LENGTH
         {
@@ -110,11 +110,7 @@ impl PrivateKeySecretManager {
             return Err(crypto::Error::PrivateKeyError.into());
         }
 
-        let private_key = Self(ed25519::SecretKey::from_bytes(&bytes));
-
-        bytes.zeroize();
-
-        Ok(private_key)
+        Ok(Self(ed25519::SecretKey::from_bytes(&bytes)))
     }
 
     /// Create a new [`PrivateKeySecretManager`] from an hex encoded private key.
@@ -0,0 +1,304 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`RouterSecretManager`].
+
+use std::ops::Range;
+
+use async_trait::async_trait;
+#[cfg(feature = "encrypted_metadata")]
+use crypto::keys::x25519;
+use crypto::{
+    keys::bip44::Bip44,
+    signatures::secp256k1_ecdsa::{self, EvmAddress},
+};
+
+use super::{GenerateAddressOptions, SecretManage, SecretManager};
+use crate::{
+    client::{api::PreparedTransactionData, Error},
+    types::block::{
+        address::Ed25519Address, payload::transaction::TransactionPayload, signature::Ed25519Signature, unlock::Unlocks,
+    },
+};
+
+/// The BIP-44 chains that are routed to a secret manager of a [`RouterSecretManager`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretManagerRoute {
+    /// The coin type, any if `None`.
+    pub coin_type: Option<u32>,
+    /// The account indexes.
+    pub account_indexes: Range<u32>,
+}
+
+impl SecretManagerRoute {
+    /// Creates a route for the account indexes of any coin type.
+    pub fn new(account_indexes: Range<u32>) -> Self {
+        Self {
+            coin_type: None,
+            account_indexes,
+        }
+    }
+
+    /// Only routes chains of the coin type.
+    pub fn with_coin_type(mut self, coin_type: impl Into<Option<u32>>) -> Self {
+        self.coin_type = coin_type.into();
+        self
+    }
+
+    /// Returns whether the coin type and account index are routed.
+    pub fn contains(&self, coin_type: u32, account_index: u32) -> bool {
+        self.coin_type
+            .is_none_or(|route_coin_type| route_coin_type == coin_type)
+            && self.account_indexes.contains(&account_index)
+    }
+}
+
+/// Secret manager that routes address generation and signing to other secret managers.
+///
+/// Routes match the coin type and account index, e.g. accounts 0 to 9 to a Ledger Nano and the others to Stronghold.
+/// The first matching route is used,
+/// otherwise the default secret manager, if one is set.
+///
+/// Transaction essences are signed by a single secret manager if all inputs are routed to it, so hardware wallets can
+/// display the whole transaction. Otherwise every input is signed separately by the secret manager it's routed to.
+#[derive(Default)]
+pub struct RouterSecretManager {
+    routes: Vec<(SecretManagerRoute, SecretManager)>,
+    default: Option<SecretManager>,
+}
+
+impl std::fmt::Debug for RouterSecretManager {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RouterSecretManager")
+            .field("routes", &self.routes)
+            .field("default", &self.default)
+            .finish()
+    }
+}
+
+impl RouterSecretManager {
+    /// Creates a new [`RouterSecretManager`] without routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a route, routes are matched in the order they were added.
+    pub fn with_route(mut self, route: SecretManagerRoute, secret_manager: impl Into<SecretManager>) -> Self {
+        self.routes.push((route, secret_manager.into()));
+        self
+    }
+
+    /// Sets the secret manager for chains that don't match any route.
+    pub fn with_default(mut self, secret_manager: impl Into<SecretManager>) -> Self {
+        self.default = Some(secret_manager.into());
+        self
+    }
+
+    /// Returns the routes and their secret managers.
+    pub fn routes(&self) -> impl Iterator<Item = (&SecretManagerRoute, &SecretManager)> {
+        self.routes
+            .iter()
+            .map(|(route, secret_manager)| (route, secret_manager))
+    }
+
+    // The index of the matching route, `None` for the default secret manager.
+    fn route_index(&self, coin_type: u32, account_index: u32) -> Result<Option<usize>, Error> {
+        match self
+            .routes
+            .iter()
+            .position(|(route, _)| route.contains(coin_type, account_index))
+        {
+            Some(index) => Ok(Some(index)),
+            None if self.default.is_some() => Ok(None),
+            None => Err(Error::SecretManagerRouteNotFound {
+                coin_type,
+                account_index,
+            }),
+        }
+    }
+
+    fn secret_manager(&self, route_index: Option<usize>) -> &SecretManager {
+        route_index.map_or_else(
+            // PANIC: `route_index()` only returns `None` if there is a default secret manager.
+            || self.default.as_ref().expect("missing default secret manager"),
+            |index| &self.routes[index].1,
+        )
+    }
+
+    /// Returns the secret manager the coin type and account index are routed to.
+    pub fn route(&self, coin_type: u32, account_index: u32) -> Result<&SecretManager, Error> {
+        Ok(self.secret_manager(self.route_index(coin_type, account_index)?))
+    }
+}
+
+#[async_trait]
+impl SecretManage for RouterSecretManager {
+    type Error = Error;
+
+    async fn generate_ed25519_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Self::Error> {
+        SecretManage::generate_ed25519_addresses(
+            self.route(coin_type, account_index)?,
+            coin_type,
+            account_index,
+            address_indexes,
+            options,
+        )
+        .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        SecretManage::generate_evm_addresses(
+            self.route(coin_type, account_index)?,
+            coin_type,
+            account_index,
+            address_indexes,
+            options,
+        )
+        .await
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: Bip44) -> Result<Ed25519Signature, Self::Error> {
+        self.route(chain.coin_type, chain.account)?
+            .sign_ed25519(msg, chain)
+            .await
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn derive_x25519_public_key(&self, chain: Bip44) -> Result<x25519::PublicKey, Self::Error> {
+        self.route(chain.coin_type, chain.account)?
+            .derive_x25519_public_key(chain)
+            .await
+    }
+
+    #[cfg(feature = "encrypted_metadata")]
+    async fn x25519_diffie_hellman(
+        &self,
+        public_key: &x25519::PublicKey,
+        chain: Bip44,
+    ) -> Result<zeroize::Zeroizing<[u8; 32]>, Self::Error> {
+        self.route(chain.coin_type, chain.account)?
+            .x25519_diffie_hellman(public_key, chain)
+            .await
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        msg: &[u8],
+        chain: Bip44,
+    ) -> Result<(secp256k1_ecdsa::PublicKey, secp256k1_ecdsa::RecoverableSignature), Self::Error> {
+        self.route(chain.coin_type, chain.account)?
+            .sign_secp256k1_ecdsa(msg, chain)
+            .await
+    }
+
+    async fn sign_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        time: Option<u32>,
+    ) -> Result<Unlocks, Self::Error> {
+        let mut route_indexes = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .filter_map(|input| input.chain)
+            .map(|chain| self.route_index(chain.coin_type, chain.account))
+            .collect::<Result<Vec<_>, _>>()?;
+        route_indexes.sort_unstable();
+        route_indexes.dedup();
+
+        match route_indexes.as_slice() {
+            [route_index] => {
+                self.secret_manager(*route_index)
+                    .sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
+            _ => super::default_sign_transaction_essence(self, prepared_transaction_data, time).await,
+        }
+    }
+
+    async fn sign_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> Result<TransactionPayload, Self::Error> {
+        super::default_sign_transaction(self, prepared_transaction_data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::client::{
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        secret::mnemonic::MnemonicSecretManager,
+    };
+
+    const FIRST_MNEMONIC: &str = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+    const SECOND_MNEMONIC: &str = "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river";
+
+    async fn address(
+        secret_manager: &impl SecretManage<Error = Error>,
+        coin_type: u32,
+        account_index: u32,
+    ) -> Ed25519Address {
+        secret_manager
+            .generate_ed25519_addresses(coin_type, account_index, 0..1, None)
+            .await
+            .unwrap()[0]
+    }
+
+    #[tokio::test]
+    async fn routes_by_account_index() {
+        let first = MnemonicSecretManager::try_from_mnemonic(FIRST_MNEMONIC.to_owned()).unwrap();
+        let second = MnemonicSecretManager::try_from_mnemonic(SECOND_MNEMONIC.to_owned()).unwrap();
+        let router = RouterSecretManager::new()
+            .with_route(
+                SecretManagerRoute::new(0..10).with_coin_type(IOTA_COIN_TYPE),
+                MnemonicSecretManager::try_from_mnemonic(FIRST_MNEMONIC.to_owned()).unwrap(),
+            )
+            .with_default(MnemonicSecretManager::try_from_mnemonic(SECOND_MNEMONIC.to_owned()).unwrap());
+
+        assert_eq!(
+            address(&router, IOTA_COIN_TYPE, 9).await,
+            address(&first, IOTA_COIN_TYPE, 9).await
+        );
+        assert_eq!(
+            address(&router, IOTA_COIN_TYPE, 10).await,
+            address(&second, IOTA_COIN_TYPE, 10).await
+        );
+        assert_eq!(
+            address(&router, SHIMMER_COIN_TYPE, 0).await,
+            address(&second, SHIMMER_COIN_TYPE, 0).await
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_route() {
+        let router = RouterSecretManager::new().with_route(
+            SecretManagerRoute::new(0..10),
+            MnemonicSecretManager::try_from_mnemonic(FIRST_MNEMONIC.to_owned()).unwrap(),
+        );
+
+        assert!(matches!(
+            router
+                .sign_ed25519(b"message", Bip44::new(IOTA_COIN_TYPE).with_account(10))
+                .await,
+            Err(Error::SecretManagerRouteNotFound {
+                coin_type: IOTA_COIN_TYPE,
+                account_index: 10
+            })
+        ));
+    }
+}
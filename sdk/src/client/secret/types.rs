@@ -5,6 +5,7 @@
 
 use crypto::keys::bip44::Bip44;
 use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
 
 use crate::{
     types::{
@@ -41,6 +42,92 @@ impl core::fmt::Debug for StrongholdDto {
     }
 }
 
+/// Mnemonic DTO to allow the creation of a mnemonic secret manager with a BIP-39 passphrase from bindings
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MnemonicDto {
+    /// The mnemonic
+    pub mnemonic: Zeroizing<String>,
+    /// The BIP-39 passphrase, also known as the 25th word
+    pub passphrase: Option<crate::client::Password>,
+}
+
+impl core::fmt::Debug for MnemonicDto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MnemonicDto").finish_non_exhaustive()
+    }
+}
+
+/// The secret to unlock a locked secret manager with.
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum UnlockSecret {
+    /// The password of a Stronghold or encrypted mnemonic secret manager
+    Password(crate::client::Password),
+    /// The mnemonic, and optional BIP-39 passphrase, a mnemonic secret manager was created from
+    Mnemonic(MnemonicDto),
+    /// The hex-encoded raw seed a mnemonic secret manager was created from
+    HexSeed(Zeroizing<String>),
+}
+
+impl core::fmt::Debug for UnlockSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Password(_) => f.debug_tuple("Password").field(&"...").finish(),
+            Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
+            Self::HexSeed(_) => f.debug_tuple("HexSeed").field(&"...").finish(),
+        }
+    }
+}
+
+/// Encrypted mnemonic DTO to allow the creation of an encrypted mnemonic secret manager from bindings
+#[cfg(feature = "encrypted_mnemonic_secret_manager")]
+#[cfg_attr(docsrs, doc(cfg(feature = "encrypted_mnemonic_secret_manager")))]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedMnemonicDto {
+    /// The password to decrypt the mnemonic
+    pub password: Option<crate::client::Password>,
+    /// The encrypted mnemonic
+    pub encrypted_mnemonic: super::encrypted_mnemonic::EncryptedMnemonic,
+}
+
+#[cfg(feature = "encrypted_mnemonic_secret_manager")]
+impl core::fmt::Debug for EncryptedMnemonicDto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EncryptedMnemonicDto")
+            .field("encrypted_mnemonic", &self.encrypted_mnemonic)
+            .finish()
+    }
+}
+
+/// PKCS#11 DTO to allow the creation of a PKCS#11 secret manager from bindings
+#[cfg(feature = "pkcs11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pkcs11Dto {
+    /// The path of the PKCS#11 module of the token
+    pub module_path: String,
+    /// The id of the slot of the token
+    pub slot: u64,
+    /// The user pin of the token
+    pub pin: Option<crate::client::Password>,
+    /// The prefix of the labels of the keys on the token, `iota` if not set
+    pub key_label_prefix: Option<String>,
+}
+
+#[cfg(feature = "pkcs11")]
+impl core::fmt::Debug for Pkcs11Dto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pkcs11Dto")
+            .field("module_path", &self.module_path)
+            .field("slot", &self.slot)
+            .field("key_label_prefix", &self.key_label_prefix)
+            .finish()
+    }
+}
+
 /// An account address.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AccountAddress {
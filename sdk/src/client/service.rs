@@ -0,0 +1,156 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A [`Client`] wrapper for backend services, with liveness and readiness checks that can be plugged into the health
+//! endpoints of web frameworks like axum or actix.
+//!
+//! ```no_run
+//! # use iota_sdk::client::{service::ClientService, Client};
+//! # async fn example(client: Client) {
+//! let service = ClientService::new(client).with_max_milestone_lag(5);
+//!
+//! // In the readiness endpoint handler of the web framework.
+//! let report = service.readiness().await;
+//! let (status_code, body) = (report.status_code(), serde_json::to_string(&report).unwrap());
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, NodeInfoWrapper};
+
+/// The default max difference between the latest and the confirmed milestone index of a ready node.
+pub const DEFAULT_MAX_MILESTONE_LAG: u32 = 2;
+
+/// A [`Client`] that is shared between the handlers of a service, cloning it is cheap.
+#[derive(Clone, Debug)]
+pub struct ClientService {
+    client: Client,
+    max_milestone_lag: u32,
+}
+
+impl ClientService {
+    /// Creates a new [`ClientService`].
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            max_milestone_lag: DEFAULT_MAX_MILESTONE_LAG,
+        }
+    }
+
+    /// Sets the max difference between the latest and the confirmed milestone index for the node to be ready.
+    pub fn with_max_milestone_lag(mut self, max_milestone_lag: u32) -> Self {
+        self.max_milestone_lag = max_milestone_lag;
+        self
+    }
+
+    /// Returns the client.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Checks that the client is functional, without requesting a node.
+    ///
+    /// The client is live as long as its background node syncing is running, an unreachable node shouldn't restart
+    /// the service.
+    pub async fn liveness(&self) -> HealthReport {
+        let sync_running = self
+            .client
+            ._sync_handle
+            .read()
+            .await
+            .0
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished());
+
+        HealthReport {
+            healthy: sync_running,
+            error: (!sync_running).then(|| "node syncing stopped".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Checks that a node is reachable, synced and its confirmed milestone is within the max milestone lag.
+    pub async fn readiness(&self) -> HealthReport {
+        match self.client.get_info().await {
+            Ok(info) => HealthReport::from_node_info(&info, self.max_milestone_lag),
+            Err(err) => HealthReport {
+                error: Some(err.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl core::ops::Deref for ClientService {
+    type Target = Client;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl From<Client> for ClientService {
+    fn from(client: Client) -> Self {
+        Self::new(client)
+    }
+}
+
+/// The result of a health check, serializable as the body of a health endpoint.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthReport {
+    /// Whether the check passed.
+    pub healthy: bool,
+    /// The url of the node that was checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_url: Option<String>,
+    /// Whether the node reports itself as healthy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synced: Option<bool>,
+    /// The difference between the latest and the confirmed milestone index of the node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone_lag: Option<u32>,
+    /// Why the check failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HealthReport {
+    /// Evaluates the readiness of a node from its info.
+    pub fn from_node_info(info: &NodeInfoWrapper, max_milestone_lag: u32) -> Self {
+        let status = &info.node_info.status;
+        let synced = status.is_healthy;
+        let milestone_lag = status
+            .latest_milestone
+            .index
+            .saturating_sub(status.confirmed_milestone.index);
+
+        let error = if !synced {
+            Some("node is not synced".to_string())
+        } else if milestone_lag > max_milestone_lag {
+            Some(format!(
+                "milestone lag {milestone_lag} exceeds the max of {max_milestone_lag}"
+            ))
+        } else {
+            None
+        };
+
+        Self {
+            healthy: error.is_none(),
+            node_url: Some(info.url.clone()),
+            synced: Some(synced),
+            milestone_lag: Some(milestone_lag),
+            error,
+        }
+    }
+
+    /// Returns the HTTP status code for a health endpoint, 200 if healthy, otherwise 503.
+    pub fn status_code(&self) -> u16 {
+        if self.healthy {
+            200
+        } else {
+            503
+        }
+    }
+}
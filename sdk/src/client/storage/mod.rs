@@ -50,4 +50,9 @@ pub trait StorageAdapter: std::fmt::Debug + Send + Sync {
 
     /// Removes a record from the storage.
     async fn delete(&self, key: &str) -> Result<(), Self::Error>;
+
+    /// Returns the keys of all records in the storage, or `None` if the storage can't list them.
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        Ok(None)
+    }
 }
@@ -0,0 +1,87 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Persistent cache of the Ed25519 addresses derived from the seed of a snapshot.
+//!
+//! The addresses are stored by their BIP-44 chain in `{snapshot_path}.addresses`, so they can be returned again without
+//! the key and without deriving them in the vault. Addresses aren't secret, but the cache links the addresses of a
+//! wallet, so it's only used if enabled.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crypto::keys::bip44::Bip44;
+
+use super::Error;
+use crate::types::block::address::Ed25519Address;
+
+/// The address cache of a snapshot.
+#[derive(Debug)]
+pub(super) struct AddressCache {
+    path: PathBuf,
+    addresses: BTreeMap<String, Ed25519Address>,
+}
+
+impl AddressCache {
+    /// Loads the address cache of the snapshot, an invalid cache is ignored, as it's derived again.
+    pub(super) fn load(snapshot_path: &Path) -> Result<Self, Error> {
+        let path = address_cache_path(snapshot_path);
+        let addresses = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).unwrap_or_else(|err| {
+                log::warn!("[STRONGHOLD] ignoring invalid address cache: {err}");
+                BTreeMap::new()
+            }),
+            Err(err) if err.kind() == ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self { path, addresses })
+    }
+
+    pub(super) fn get(&self, chain: Bip44) -> Option<Ed25519Address> {
+        self.addresses.get(&chain_key(chain)).copied()
+    }
+
+    /// Adds the addresses and writes the cache.
+    pub(super) fn extend(&mut self, addresses: impl IntoIterator<Item = (Bip44, Ed25519Address)>) -> Result<(), Error> {
+        self.addresses.extend(
+            addresses
+                .into_iter()
+                .map(|(chain, address)| (chain_key(chain), address)),
+        );
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        fs::write(&tmp_path, serde_json::to_vec(&self.addresses)?)?;
+        fs::rename(&tmp_path, &self.path)?;
+
+        Ok(())
+    }
+
+    /// Removes all addresses, e.g. because the seed changed.
+    pub(super) fn clear(&mut self) -> Result<(), Error> {
+        self.addresses.clear();
+
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn address_cache_path(snapshot_path: &Path) -> PathBuf {
+    let mut path = snapshot_path.as_os_str().to_owned();
+    path.push(".addresses");
+    PathBuf::from(path)
+}
+
+fn chain_key(chain: Bip44) -> String {
+    format!(
+        "{}'/{}'/{}'/{}'",
+        chain.coin_type, chain.account, chain.change, chain.address_index
+    )
+}
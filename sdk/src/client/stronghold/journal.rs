@@ -0,0 +1,135 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Write-ahead journal of the Stronghold store.
+//!
+//! Every change to the store is appended to `{snapshot_path}.journal` before it's applied in memory, so changes that
+//! happened after the last snapshot was written can be replayed when the snapshot is loaded again after a crash. The
+//! journal only contains the encrypted values, the same as the snapshot, and is removed once a snapshot is written.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use iota_stronghold::Stronghold;
+use serde::{Deserialize, Serialize};
+
+use super::{common::PRIVATE_DATA_CLIENT_PATH, Error};
+
+/// A change to the Stronghold store.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub(super) enum JournalEntry {
+    /// A value was inserted, already encrypted.
+    Insert {
+        #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+        key: Vec<u8>,
+        #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+        value: Vec<u8>,
+    },
+    /// A value was deleted.
+    Delete {
+        #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+        key: Vec<u8>,
+    },
+}
+
+/// The journal file of a snapshot.
+#[derive(Debug)]
+pub(super) struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    pub(super) fn new(snapshot_path: &Path) -> Self {
+        Self {
+            path: journal_path(snapshot_path),
+        }
+    }
+
+    /// Appends an entry and syncs it to the disk.
+    pub(super) fn append(&self, entry: &JournalEntry) -> Result<(), Error> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        file.write_all(&line)?;
+        file.sync_data()?;
+
+        Ok(())
+    }
+}
+
+fn journal_path(snapshot_path: &Path) -> PathBuf {
+    let mut path = snapshot_path.as_os_str().to_owned();
+    path.push(".journal");
+    PathBuf::from(path)
+}
+
+/// Removes the journal of the snapshot, after its changes were written to the snapshot.
+pub(super) fn remove(snapshot_path: &Path) -> Result<(), Error> {
+    match fs::remove_file(journal_path(snapshot_path)) {
+        Err(err) if err.kind() != ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Applies the journal of the snapshot to the loaded store and returns the number of replayed entries.
+///
+/// Replaying is idempotent, as the journal only grows until the next snapshot is written. A partially written last
+/// line, from a crash during an append, is skipped.
+pub(super) fn replay(stronghold: &Stronghold, snapshot_path: &Path) -> Result<usize, Error> {
+    let file = match File::open(journal_path(snapshot_path)) {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(err.into()),
+    };
+
+    let store = stronghold.get_client(PRIVATE_DATA_CLIENT_PATH)?.store();
+    let mut replayed = 0;
+
+    for line in BufReader::new(file).lines() {
+        let entry = match serde_json::from_str::<JournalEntry>(&line?) {
+            Ok(entry) => entry,
+            Err(err) => {
+                log::warn!("[STRONGHOLD] skipping invalid journal entry: {err}");
+                break;
+            }
+        };
+
+        match entry {
+            JournalEntry::Insert { key, value } => {
+                store.insert(key, value, None)?;
+            }
+            JournalEntry::Delete { key } => {
+                store.delete(&key)?;
+            }
+        }
+        replayed += 1;
+    }
+
+    if replayed > 0 {
+        log::debug!("[STRONGHOLD] replayed {replayed} journal entries");
+    }
+
+    Ok(replayed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn journal_entry_serde() {
+        let entry = JournalEntry::Insert {
+            key: b"key".to_vec(),
+            value: vec![1, 2, 3],
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+
+        assert_eq!(json, r#"{"op":"insert","key":"0x6b6579","value":"0x010203"}"#);
+        assert_eq!(serde_json::from_str::<JournalEntry>(&json).unwrap(), entry);
+    }
+}
@@ -37,6 +37,16 @@
 //! [`read_stronghold_snapshot()`] or [`write_stronghold_snapshot()`]. The latter can be used to create a snapshot file
 //! after creating a [`StrongholdAdapter`] with a non-existent snapshot path.
 //!
+//! Changes made through the [`StorageAdapter`] interface are kept in memory until the snapshot is written. To not lose
+//! them on a crash, the snapshot can be written periodically when there are changes with
+//! [`StrongholdAdapterBuilder::auto_save_interval()`], and every change can be appended to a write-ahead journal next
+//! to the snapshot with [`StrongholdAdapterBuilder::journal()`], which is replayed when the snapshot is loaded.
+//! [`flush()`] writes the snapshot immediately.
+//!
+//! Addresses are derived in a single vault session with [`derive_addresses()`]. With
+//! [`StrongholdAdapterBuilder::address_cache()`], derived addresses are also cached next to the snapshot, so they don't
+//! need the key or the vault again, e.g. during a wallet sync.
+//!
 //! [Stronghold]: iota_stronghold
 //! [`StorageAdapter`]: crate::client::storage::StorageAdapter
 //! [`SecretManage`]: crate::client::secret::SecretManage
@@ -45,9 +55,13 @@
 //! [`set_timeout()`]: self::StrongholdAdapter::set_timeout()
 //! [`read_stronghold_snapshot()`]: self::StrongholdAdapter::read_stronghold_snapshot()
 //! [`write_stronghold_snapshot()`]: self::StrongholdAdapter::write_stronghold_snapshot()
+//! [`flush()`]: self::StrongholdAdapter::flush()
+//! [`derive_addresses()`]: self::StrongholdAdapter::derive_addresses()
 
+mod address_cache;
 mod common;
 mod error;
+mod journal;
 mod migration;
 mod secret;
 mod storage;
@@ -55,7 +69,10 @@ mod storage;
 use alloc::sync::Weak;
 use std::{
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     time::Duration,
 };
 
@@ -69,6 +86,7 @@ use zeroize::Zeroizing;
 
 pub(crate) use self::common::PRIVATE_DATA_CLIENT_PATH;
 pub use self::error::Error;
+use self::{address_cache::AddressCache, journal::Journal};
 use super::{storage::StorageAdapter, utils::Password};
 
 /// A wrapper on [Stronghold].
@@ -99,6 +117,18 @@ pub struct StrongholdAdapter {
     /// A handle to the timeout task.
     timeout_task: Arc<Mutex<Option<TaskHandle>>>,
 
+    /// An interval of time, after which the snapshot is written if the store has been changed.
+    auto_save_interval: Option<Duration>,
+
+    /// Whether the store has been changed since the snapshot was last written.
+    dirty: Arc<AtomicBool>,
+
+    /// The write-ahead journal of the store, if enabled.
+    journal: Option<Arc<Mutex<Journal>>>,
+
+    /// The cache of the derived addresses, if enabled.
+    address_cache: Option<Arc<Mutex<AddressCache>>>,
+
     /// The path to the corresponding Stronghold snapshot file.
     pub(crate) snapshot_path: PathBuf,
 }
@@ -141,6 +171,9 @@ pub struct StrongholdAdapterBuilder {
     stronghold: Option<Stronghold>,
     key_provider: Option<KeyProvider>,
     timeout: Option<Duration>,
+    auto_save_interval: Option<Duration>,
+    journal: bool,
+    address_cache: bool,
 }
 
 /// Extra / custom builder method implementations.
@@ -160,6 +193,26 @@ impl StrongholdAdapterBuilder {
         self
     }
 
+    /// Write the snapshot every `interval`, if the store has been changed since it was last written.
+    pub fn auto_save_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.auto_save_interval = interval.into();
+        self
+    }
+
+    /// Append every change of the store to a write-ahead journal next to the snapshot, which is replayed when the
+    /// snapshot is loaded, so no change is lost if the process crashes before the snapshot is written.
+    pub fn journal(mut self, journal: bool) -> Self {
+        self.journal = journal;
+        self
+    }
+
+    /// Cache the derived addresses next to the snapshot, so they can be returned without deriving them again, even
+    /// when the key is cleared.
+    pub fn address_cache(mut self, address_cache: bool) -> Self {
+        self.address_cache = address_cache;
+        self
+    }
+
     /// Use an user-input password string to derive a key to use Stronghold.
     pub fn password(mut self, password: impl Into<Password>) -> Self {
         self.key_provider
@@ -179,8 +232,11 @@ impl StrongholdAdapterBuilder {
     /// Otherwise, the function would panic. If this is not desired, one needs to avoid calling [`password()`] and
     /// [`timeout()`] during the building process.
     ///
+    /// The same applies to the auto-save task, which is spawned if [`auto_save_interval()`] is set.
+    ///
     /// [`password()`]: Self::password()
     /// [`timeout()`]: Self::timeout()
+    /// [`auto_save_interval()`]: Self::auto_save_interval()
     pub fn build<P: AsRef<Path>>(self, snapshot_path: P) -> Result<StrongholdAdapter, Error> {
         if snapshot_path.as_ref().is_dir() {
             // TODO: Add Error in 2.0 as its breaking.
@@ -200,6 +256,7 @@ impl StrongholdAdapterBuilder {
 
         if let Some(key_provider) = &self.key_provider {
             check_or_create_snapshot(&stronghold, key_provider, &SnapshotPath::from_path(&snapshot_path))?;
+            journal::replay(&stronghold, snapshot_path.as_ref())?;
         }
 
         let has_key_provider = self.key_provider.is_some();
@@ -218,12 +275,37 @@ impl StrongholdAdapterBuilder {
             )));
         }
 
+        let dirty = Arc::new(AtomicBool::new(false));
+        let journal = self
+            .journal
+            .then(|| Arc::new(Mutex::new(Journal::new(snapshot_path.as_ref()))));
+        let address_cache = if self.address_cache {
+            Some(Arc::new(Mutex::new(AddressCache::load(snapshot_path.as_ref())?)))
+        } else {
+            None
+        };
+
+        if let Some(interval) = self.auto_save_interval {
+            tokio::spawn(task_auto_save(
+                Arc::downgrade(&stronghold),
+                key_provider.clone(),
+                journal.clone(),
+                dirty.clone(),
+                snapshot_path.as_ref().to_path_buf(),
+                interval,
+            ));
+        }
+
         // Create the adapter as per configuration and return it.
         Ok(StrongholdAdapter {
             stronghold,
             key_provider,
             timeout: self.timeout,
             timeout_task,
+            auto_save_interval: self.auto_save_interval,
+            dirty,
+            journal,
+            address_cache,
             snapshot_path: snapshot_path.as_ref().to_path_buf(),
         })
     }
@@ -268,6 +350,8 @@ impl StrongholdAdapter {
         let stronghold = self.stronghold.lock().await;
 
         check_or_create_snapshot(&stronghold, &key_provider, &snapshot_path)?;
+        journal::replay(&stronghold, &self.snapshot_path)?;
+        drop(stronghold);
 
         *key_provider_guard = Some(key_provider);
         drop(key_provider_guard);
@@ -410,6 +494,20 @@ impl StrongholdAdapter {
         Ok(())
     }
 
+    /// Change the password of the Stronghold after verifying the current one, see
+    /// [`change_password()`](Self::change_password()).
+    ///
+    /// Snapshots are written to a temporary file that replaces the snapshot afterwards, so if the process gets
+    /// interrupted, the snapshot is either encrypted with the current or with the new password.
+    pub async fn rotate_password(
+        &self,
+        current_password: impl Into<Password> + Send,
+        new_password: impl Into<Password> + Send,
+    ) -> Result<(), Error> {
+        self.set_password(current_password).await?;
+        self.change_password(new_password).await
+    }
+
     /// Immediately clear ([zeroize]) the stored key.
     ///
     /// If a key clearing thread has been spawned, then it'll be stopped too.
@@ -483,11 +581,13 @@ impl StrongholdAdapter {
             return Err(Error::KeyCleared);
         };
 
-        self.stronghold.lock().await.load_client_from_snapshot(
+        let stronghold = self.stronghold.lock().await;
+        stronghold.load_client_from_snapshot(
             PRIVATE_DATA_CLIENT_PATH,
             key_provider,
             &SnapshotPath::from_path(&self.snapshot_path),
         )?;
+        journal::replay(&stronghold, &self.snapshot_path)?;
 
         Ok(())
     }
@@ -495,34 +595,51 @@ impl StrongholdAdapter {
     /// Persist Stronghold to a snapshot at a provided `snapshot_path` or at the Stronghold's own `snapshot_path` if
     /// None.
     ///
-    /// It doesn't unload the snapshot; see also [`unload_stronghold_snapshot()`].
+    /// The snapshot is written to a temporary file first, which then replaces the snapshot, so it doesn't get corrupted
+    /// if the process is interrupted. Writing the own snapshot removes the journal. It doesn't unload the snapshot; see
+    /// also [`unload_stronghold_snapshot()`].
     ///
     /// [`unload_stronghold_snapshot()`]: Self::unload_stronghold_snapshot()
     pub async fn write_stronghold_snapshot(&self, snapshot_path: Option<&Path>) -> Result<(), Error> {
-        if let Some(p) = snapshot_path {
-            if p.is_dir() {
+        match snapshot_path {
+            Some(p) if p.is_dir() => {
                 // TODO: Add Error in 2.0 as its breaking.
                 // Issue #1197
-                return Err(
-                    std::io::Error::new(std::io::ErrorKind::Other, format!("Path is not a file: {:?}", p)).into(),
-                );
+                Err(std::io::Error::new(std::io::ErrorKind::Other, format!("Path is not a file: {:?}", p)).into())
+            }
+            Some(p) if p != self.snapshot_path => {
+                let locked_key_provider = self.key_provider.lock().await;
+                let key_provider = locked_key_provider.as_ref().ok_or(Error::KeyCleared)?;
+
+                commit_snapshot(&*self.stronghold.lock().await, key_provider, p)
+            }
+            _ => {
+                persist_snapshot(
+                    &self.stronghold,
+                    &self.key_provider,
+                    self.journal.as_deref(),
+                    &self.dirty,
+                    &self.snapshot_path,
+                )
+                .await
             }
         }
+    }
 
-        // The key needs to be supplied first.
-        let locked_key_provider = self.key_provider.lock().await;
-        let key_provider = if let Some(key_provider) = &*locked_key_provider {
-            key_provider
-        } else {
-            return Err(Error::KeyCleared);
-        };
+    /// Write the snapshot and remove the journal, e.g. before shutting down, as changes of the store are otherwise
+    /// only written by the auto-save task or when the key is cleared.
+    pub async fn flush(&self) -> Result<(), Error> {
+        self.write_stronghold_snapshot(None).await
+    }
 
-        self.stronghold.lock().await.commit_with_keyprovider(
-            &SnapshotPath::from_path(snapshot_path.unwrap_or(&self.snapshot_path)),
-            key_provider,
-        )?;
+    /// Get the interval of the auto-save task.
+    pub fn get_auto_save_interval(&self) -> Option<Duration> {
+        self.auto_save_interval
+    }
 
-        Ok(())
+    /// Whether the store has been changed since the snapshot was last written.
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.dirty.load(Ordering::Acquire)
     }
 
     /// Unload Stronghold from memory.
@@ -552,6 +669,85 @@ impl StrongholdAdapter {
 
 type TaskHandle = JoinHandle<()>;
 
+/// Writes the snapshot to a temporary file first, which then replaces the snapshot.
+fn commit_snapshot(stronghold: &Stronghold, key_provider: &KeyProvider, snapshot_path: &Path) -> Result<(), Error> {
+    let mut temporary_path = snapshot_path.as_os_str().to_owned();
+    temporary_path.push(".tmp");
+    let temporary_path = PathBuf::from(temporary_path);
+
+    stronghold.commit_with_keyprovider(&SnapshotPath::from_path(&temporary_path), key_provider)?;
+    // The temporary file has to be on disk before it replaces the snapshot, and the rename has to be on disk before
+    // the journal is removed.
+    std::fs::File::open(&temporary_path)?.sync_all()?;
+    std::fs::rename(&temporary_path, snapshot_path)?;
+    #[cfg(unix)]
+    {
+        let directory = snapshot_path
+            .parent()
+            .filter(|directory| !directory.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new("."));
+        std::fs::File::open(directory)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Writes the own snapshot and removes the journal, whose lock is held so no change gets lost in between.
+async fn persist_snapshot(
+    stronghold: &Mutex<Stronghold>,
+    key_provider: &Mutex<Option<KeyProvider>>,
+    journal: Option<&Mutex<Journal>>,
+    dirty: &AtomicBool,
+    snapshot_path: &Path,
+) -> Result<(), Error> {
+    let _journal = match journal {
+        Some(journal) => Some(journal.lock().await),
+        None => None,
+    };
+
+    // The key needs to be supplied first.
+    let locked_key_provider = key_provider.lock().await;
+    let key_provider = locked_key_provider.as_ref().ok_or(Error::KeyCleared)?;
+
+    dirty.store(false, Ordering::Release);
+    if let Err(err) = commit_snapshot(&*stronghold.lock().await, key_provider, snapshot_path) {
+        dirty.store(true, Ordering::Release);
+        return Err(err);
+    }
+
+    journal::remove(snapshot_path)
+}
+
+/// The asynchronous task writing the snapshot every `interval` if the store has been changed, until the
+/// [`StrongholdAdapter`] is dropped.
+async fn task_auto_save(
+    stronghold: Weak<Mutex<Stronghold>>,
+    key_provider: Arc<Mutex<Option<KeyProvider>>>,
+    journal: Option<Arc<Mutex<Journal>>>,
+    dirty: Arc<AtomicBool>,
+    snapshot_path: PathBuf,
+    interval: Duration,
+) {
+    loop {
+        tokio::time::sleep(interval).await;
+
+        let Some(stronghold) = stronghold.upgrade() else {
+            break;
+        };
+
+        // Without the key, Stronghold has been unloaded after writing the snapshot.
+        if !dirty.load(Ordering::Acquire) || key_provider.lock().await.is_none() {
+            continue;
+        }
+
+        debug!("StrongholdAdapter is auto-saving the snapshot");
+        if let Err(err) = persist_snapshot(&stronghold, &key_provider, journal.as_deref(), &dirty, &snapshot_path).await
+        {
+            warn!("failed to auto-save the Stronghold snapshot: {err}");
+        }
+    }
+}
+
 /// The asynchronous key clearing task purging `key` after `timeout` spent in Tokio.
 async fn task_key_clear(
     task: Weak<Mutex<Option<TaskHandle>>>,
@@ -652,4 +848,63 @@ mod tests {
 
         fs::remove_file(stronghold_path).unwrap();
     }
+
+    #[tokio::test]
+    async fn stronghold_auto_save() {
+        iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+        let stronghold_path = "stronghold_auto_save.stronghold";
+        let adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .auto_save_interval(Duration::from_millis(50))
+            .build(stronghold_path)
+            .unwrap();
+        assert_eq!(adapter.get_auto_save_interval(), Some(Duration::from_millis(50)));
+
+        adapter.set("key", &"value").await.unwrap();
+        assert!(adapter.has_unsaved_changes());
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(!adapter.has_unsaved_changes());
+
+        // The change has been written to the snapshot without unloading Stronghold
+        drop(adapter);
+        let adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        assert_eq!(adapter.get::<String>("key").await.unwrap().as_deref(), Some("value"));
+
+        fs::remove_file(stronghold_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn stronghold_rotate_password() {
+        iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+        let stronghold_path = "stronghold_rotate_password.stronghold";
+        let adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        adapter.set("key", &"value").await.unwrap();
+
+        assert!(matches!(
+            adapter
+                .rotate_password("wrong_password".to_owned(), "new_password".to_owned())
+                .await,
+            Err(Error::InvalidPassword)
+        ));
+        adapter
+            .rotate_password("drowssap".to_owned(), "new_password".to_owned())
+            .await
+            .unwrap();
+        assert!(!Path::new("stronghold_rotate_password.stronghold.tmp").exists());
+
+        // The snapshot can only be loaded with the new password
+        adapter.clear_key().await;
+        assert!(adapter.set_password("drowssap".to_owned()).await.is_err());
+        adapter.set_password("new_password".to_owned()).await.unwrap();
+        assert_eq!(adapter.get::<String>("key").await.unwrap().as_deref(), Some("value"));
+
+        fs::remove_file(stronghold_path).unwrap();
+    }
 }
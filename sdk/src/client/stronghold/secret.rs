@@ -51,69 +51,9 @@ impl SecretManage for StrongholdAdapter {
         address_indexes: Range<u32>,
         options: impl Into<Option<GenerateAddressOptions>> + Send,
     ) -> Result<Vec<Ed25519Address>, Self::Error> {
-        // Prevent the method from being invoked when the key has been cleared from the memory. Do note that Stronghold
-        // only asks for a key for reading / writing a snapshot, so without our cached key this method is invocable, but
-        // it doesn't make sense when it comes to our user (signing transactions / generating addresses without a key).
-        // Thus, we put an extra guard here to prevent this methods from being invoked when our cached key has
-        // been cleared.
-        if !self.is_key_available().await {
-            return Err(Error::KeyCleared.into());
-        }
-
-        // Stronghold arguments.
-        let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
-
-        // Addresses to return.
-        let mut addresses = Vec::new();
-        let internal = options.into().map(|o| o.internal).unwrap_or_default();
-
-        for address_index in address_indexes {
-            let chain = Bip44::new(coin_type)
-                .with_account(account_index)
-                .with_change(internal as _)
-                .with_address_index(address_index);
-
-            let derive_location = Location::generic(
-                SECRET_VAULT_PATH,
-                [
-                    DERIVE_OUTPUT_RECORD_PATH,
-                    &chain
-                        .to_chain::<ed25519::SecretKey>()
-                        .into_iter()
-                        .flat_map(|seg| seg.ser32())
-                        .collect::<Vec<u8>>(),
-                ]
-                .concat(),
-            );
-
-            // Derive a SLIP-10 private key in the vault.
-            self.slip10_derive(Curve::Ed25519, chain, seed_location.clone(), derive_location.clone())
-                .await?;
-
-            // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
-            let public_key = self.ed25519_public_key(derive_location.clone()).await?;
-
-            // Cleanup location afterwards
-            self.stronghold
-                .lock()
-                .await
-                .get_client(PRIVATE_DATA_CLIENT_PATH)
-                .map_err(Error::from)?
-                .vault(SECRET_VAULT_PATH)
-                .delete_secret(derive_location.record_path())
-                .map_err(Error::from)?;
-
-            // Hash the public key to get the address.
-            let hash = Blake2b256::digest(public_key);
-
-            // Convert the hash into [Address].
-            let address = Ed25519Address::new(hash.into());
-
-            // Collect it.
-            addresses.push(address);
-        }
-
-        Ok(addresses)
+        Ok(self
+            .derive_addresses(coin_type, account_index, address_indexes, options)
+            .await?)
     }
 
     async fn generate_evm_addresses(
@@ -369,20 +309,7 @@ impl StrongholdAdapter {
                 output,
             })
         {
-            match err {
-                iota_stronghold::procedures::ProcedureError::Engine(ref e) => {
-                    // Custom error for missing vault error: https://github.com/iotaledger/stronghold.rs/blob/7f0a2e0637394595e953f9071fa74b1d160f51ec/client/src/types/error.rs#L170
-                    if e.to_string().contains("does not exist") {
-                        // Actually the seed, derived from the mnemonic, is not stored.
-                        return Err(Error::MnemonicMissing);
-                    } else {
-                        return Err(err.into());
-                    }
-                }
-                _ => {
-                    return Err(err.into());
-                }
-            }
+            return Err(slip10_derive_error(err));
         };
 
         Ok(())
@@ -456,6 +383,113 @@ impl StrongholdAdapter {
         Ok(secp256k1_ecdsa::PublicKey::try_from_slice(&bytes)?)
     }
 
+    /// Derives the Ed25519 addresses of the address indexes in a single vault session: the key of the account is
+    /// derived from the seed once and the key of every address is derived from it, instead of deriving every key
+    /// from the seed.
+    ///
+    /// If the address cache is enabled, the addresses are returned from it when all of them are cached, even if the key
+    /// has been cleared, and newly derived addresses are added to it.
+    pub async fn derive_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: impl Into<Option<GenerateAddressOptions>> + Send,
+    ) -> Result<Vec<Ed25519Address>, Error> {
+        let internal = options.into().map(|o| o.internal).unwrap_or_default();
+        let chain = |address_index| {
+            Bip44::new(coin_type)
+                .with_account(account_index)
+                .with_change(internal as _)
+                .with_address_index(address_index)
+        };
+
+        if let Some(address_cache) = &self.address_cache {
+            let address_cache = address_cache.lock().await;
+            if let Some(addresses) = address_indexes
+                .clone()
+                .map(|address_index| address_cache.get(chain(address_index)))
+                .collect::<Option<Vec<_>>>()
+            {
+                return Ok(addresses);
+            }
+        }
+
+        // Prevent the method from being invoked when the key has been cleared from the memory, as addresses can't be
+        // derived without it for our user.
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        // The chain of the account and change, without the address index.
+        let account_chain = chain(0).to_chain::<ed25519::SecretKey>()[..4].to_vec();
+        let account_chain_bytes = account_chain.iter().flat_map(|seg| seg.ser32()).collect::<Vec<u8>>();
+        let account_location = Location::generic(
+            SECRET_VAULT_PATH,
+            [DERIVE_OUTPUT_RECORD_PATH, &account_chain_bytes].concat(),
+        );
+        let derive_location = Location::generic(
+            SECRET_VAULT_PATH,
+            [DERIVE_OUTPUT_RECORD_PATH, &account_chain_bytes, b"/address"].concat(),
+        );
+
+        let stronghold = self.stronghold.lock().await;
+        let client = stronghold.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+
+        let derive = || -> Result<Vec<Ed25519Address>, Error> {
+            client
+                .execute_procedure(procedures::Slip10Derive {
+                    curve: Curve::Ed25519,
+                    chain: account_chain.iter().copied().map(Into::into).collect(),
+                    input: Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH)),
+                    output: account_location.clone(),
+                })
+                .map_err(slip10_derive_error)?;
+
+            address_indexes
+                .clone()
+                .map(|address_index| {
+                    client.execute_procedure(procedures::Slip10Derive {
+                        curve: Curve::Ed25519,
+                        chain: vec![address_index.harden().into()],
+                        input: Slip10DeriveInput::Key(account_location.clone()),
+                        output: derive_location.clone(),
+                    })?;
+                    let public_key = ed25519::PublicKey::try_from_bytes(
+                        client
+                            .execute_procedure(procedures::PublicKey {
+                                ty: KeyType::Ed25519,
+                                private_key: derive_location.clone(),
+                            })?
+                            .try_into()
+                            .unwrap(),
+                    )?;
+
+                    // Hash the public key to get the address.
+                    Ok(Ed25519Address::new(Blake2b256::digest(public_key).into()))
+                })
+                .collect()
+        };
+        let addresses = derive();
+
+        // Cleanup the derived keys afterwards, also if the derivation failed.
+        let vault = client.vault(SECRET_VAULT_PATH);
+        vault.delete_secret(derive_location.record_path()).ok();
+        vault.delete_secret(account_location.record_path()).ok();
+        drop(stronghold);
+
+        let addresses = addresses?;
+
+        if let Some(address_cache) = &self.address_cache {
+            address_cache
+                .lock()
+                .await
+                .extend(address_indexes.map(chain).zip(addresses.iter().copied()))?;
+        }
+
+        Ok(addresses)
+    }
+
     /// Store a mnemonic into the Stronghold vault.
     pub async fn store_mnemonic(&self, mnemonic: impl Borrow<MnemonicRef> + Send) -> Result<(), Error> {
         // The key needs to be supplied first.
@@ -488,6 +522,11 @@ impl StrongholdAdapter {
         self.bip39_recover(trimmed_mnemonic, Passphrase::default(), output)
             .await?;
 
+        // Addresses cached for a previous seed of the snapshot are invalid now.
+        if let Some(address_cache) = &self.address_cache {
+            address_cache.lock().await.clear()?;
+        }
+
         // Persist Stronghold to the disk
         self.write_stronghold_snapshot(None).await?;
 
@@ -495,6 +534,21 @@ impl StrongholdAdapter {
     }
 }
 
+fn slip10_derive_error(err: procedures::ProcedureError) -> Error {
+    match err {
+        procedures::ProcedureError::Engine(ref e) => {
+            // Custom error for missing vault error: https://github.com/iotaledger/stronghold.rs/blob/7f0a2e0637394595e953f9071fa74b1d160f51ec/client/src/types/error.rs#L170
+            if e.to_string().contains("does not exist") {
+                // Actually the seed, derived from the mnemonic, is not stored.
+                Error::MnemonicMissing
+            } else {
+                err.into()
+            }
+        }
+        _ => err.into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -571,6 +625,61 @@ mod tests {
         std::fs::remove_file(stronghold_path).ok();
     }
 
+    #[tokio::test]
+    async fn test_sign_typed_data() {
+        use crate::client::secret::{
+            eip712::{Eip712Domain, Eip712Types},
+            mnemonic::MnemonicSecretManager,
+        };
+
+        let stronghold_path = "test_sign_typed_data.stronghold";
+        // Remove potential old stronghold file
+        std::fs::remove_file(stronghold_path).ok();
+        let mnemonic = "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river";
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+        stronghold_adapter
+            .store_mnemonic(Mnemonic::from(mnemonic.to_owned()))
+            .await
+            .unwrap();
+        let mnemonic_secret_manager = MnemonicSecretManager::try_from_mnemonic(mnemonic.to_owned()).unwrap();
+
+        let domain = Eip712Domain {
+            name: Some("Ether Mail".to_owned()),
+            version: Some("1".to_owned()),
+            chain_id: Some(1),
+            ..Default::default()
+        };
+        let types: Eip712Types = serde_json::from_value(serde_json::json!({
+            "Mail": [{ "name": "contents", "type": "string" }]
+        }))
+        .unwrap();
+        let message = serde_json::json!({ "contents": "Hello, Bob!" });
+        let chain = Bip44::new(ETHER_COIN_TYPE);
+
+        let (public_key, signature) = stronghold_adapter
+            .sign_typed_data(&domain, &types, &message, chain)
+            .await
+            .unwrap();
+        let (expected_public_key, expected_signature) = mnemonic_secret_manager
+            .sign_typed_data(&domain, &types, &message, chain)
+            .await
+            .unwrap();
+
+        // Deterministic signatures of the same key have to be the same, and belong to the EVM address of the chain.
+        assert_eq!(public_key.to_bytes(), expected_public_key.to_bytes());
+        assert_eq!(signature.to_bytes(), expected_signature.to_bytes());
+        assert_eq!(
+            prefix_hex::encode(public_key.evm_address().as_ref()),
+            "0xcaefde2b487ded55688765964320ff390cd87828"
+        );
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+    }
+
     #[tokio::test]
     async fn test_key_cleared() {
         let stronghold_path = "test_key_cleared.stronghold";
@@ -592,12 +701,10 @@ mod tests {
         stronghold_adapter.clear_key().await;
 
         // Address generation returns an error when the key is cleared.
-        assert!(
-            stronghold_adapter
-                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None,)
-                .await
-                .is_err()
-        );
+        assert!(stronghold_adapter
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..1, None,)
+            .await
+            .is_err());
 
         stronghold_adapter.set_password("drowssap".to_owned()).await.unwrap();
 
@@ -615,4 +722,103 @@ mod tests {
         // Remove garbage after test, but don't care about the result
         std::fs::remove_file(stronghold_path).ok();
     }
+
+    #[tokio::test]
+    async fn test_derive_addresses() {
+        let stronghold_path = "test_derive_addresses.stronghold";
+        // Remove potential old stronghold file
+        std::fs::remove_file(stronghold_path).ok();
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .build(stronghold_path)
+            .unwrap();
+
+        stronghold_adapter
+            .store_mnemonic(Mnemonic::from(mnemonic.to_owned()))
+            .await
+            .unwrap();
+
+        let mnemonic_secret_manager =
+            crate::client::secret::mnemonic::MnemonicSecretManager::try_from_mnemonic(mnemonic.to_owned()).unwrap();
+        for internal in [false, true] {
+            let options = GenerateAddressOptions {
+                internal,
+                ..Default::default()
+            };
+            assert_eq!(
+                stronghold_adapter
+                    .derive_addresses(IOTA_COIN_TYPE, 1, 3..8, options)
+                    .await
+                    .unwrap(),
+                mnemonic_secret_manager
+                    .generate_ed25519_addresses(IOTA_COIN_TYPE, 1, 3..8, options)
+                    .await
+                    .unwrap()
+            );
+        }
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_address_cache() {
+        let stronghold_path = "test_address_cache.stronghold";
+        let address_cache_path = "test_address_cache.stronghold.addresses";
+        // Remove potential old stronghold files
+        std::fs::remove_file(stronghold_path).ok();
+        std::fs::remove_file(address_cache_path).ok();
+        let mnemonic = Mnemonic::from(
+            "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally".to_owned(),
+        );
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .address_cache(true)
+            .build(stronghold_path)
+            .unwrap();
+
+        stronghold_adapter.store_mnemonic(mnemonic).await.unwrap();
+
+        let addresses = stronghold_adapter
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..3, None)
+            .await
+            .unwrap();
+        assert!(Path::new(address_cache_path).exists());
+
+        // Cached addresses are returned without the key.
+        stronghold_adapter.clear_key().await;
+        assert_eq!(
+            stronghold_adapter
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 1..3, None)
+                .await
+                .unwrap(),
+            addresses[1..]
+        );
+        assert!(stronghold_adapter
+            .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..4, None)
+            .await
+            .is_err());
+
+        // The cache is loaded again with the snapshot.
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .address_cache(true)
+            .build(stronghold_path)
+            .unwrap();
+        assert_eq!(
+            stronghold_adapter
+                .generate_ed25519_addresses(IOTA_COIN_TYPE, 0, 0..3, None)
+                .await
+                .unwrap(),
+            addresses
+        );
+        assert_eq!(
+            addresses[0].to_bech32_unchecked("atoi"),
+            "atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e"
+        );
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+        std::fs::remove_file(address_cache_path).ok();
+    }
 }
@@ -3,16 +3,19 @@
 
 //! The `StorageAdapter` implementation for `StrongholdAdapter`.
 
+use std::sync::atomic::Ordering;
+
 use async_trait::async_trait;
 use crypto::ciphers::{chacha::XChaCha20Poly1305, traits::Aead};
 use iota_stronghold::{
     procedures::{self, AeadCipher},
-    Location,
+    Client, Location,
 };
 use zeroize::Zeroizing;
 
 use super::{
     common::{PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, USERDATA_STORE_KEY_RECORD_PATH},
+    journal::JournalEntry,
     StrongholdAdapter,
 };
 use crate::client::{storage::StorageAdapter, stronghold::Error};
@@ -54,6 +57,11 @@ impl StorageAdapter for StrongholdAdapter {
             let vault_path = store_key_location.vault_path();
             let vault = stronghold_client.vault(vault_path);
             vault.write_secret(store_key_location.clone(), key)?;
+
+            // Journaled values can only be decrypted with the key of the snapshot.
+            if self.journal.is_some() {
+                self.write_stronghold_snapshot(None).await?;
+            }
         }
 
         let mut nonce = [0; XChaCha20Poly1305::NONCE_LENGTH];
@@ -70,19 +78,50 @@ impl StorageAdapter for StrongholdAdapter {
         // The value is assumed to be `nonce || tag || ciphertext`
         let final_data = [nonce.to_vec(), encrypted_value].concat();
 
-        stronghold_client
-            .store()
-            .insert(key.as_bytes().to_vec(), final_data, None)?;
-        Ok(())
+        self.apply(
+            &stronghold_client,
+            JournalEntry::Insert {
+                key: key.as_bytes().to_vec(),
+                value: final_data,
+            },
+        )
+        .await
     }
 
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
-        self.stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .store()
-            .delete(key.as_bytes())?;
+        let stronghold_client = self.stronghold.lock().await.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+
+        self.apply(
+            &stronghold_client,
+            JournalEntry::Delete {
+                key: key.as_bytes().to_vec(),
+            },
+        )
+        .await
+    }
+}
+
+impl StrongholdAdapter {
+    /// Applies a change to the store, after appending it to the journal if it's enabled.
+    async fn apply(&self, stronghold_client: &Client, entry: JournalEntry) -> Result<(), Error> {
+        let journal = match &self.journal {
+            Some(journal) => Some(journal.lock().await),
+            None => None,
+        };
+        if let Some(journal) = &journal {
+            journal.append(&entry)?;
+        }
+
+        match entry {
+            JournalEntry::Insert { key, value } => {
+                stronghold_client.store().insert(key, value, None)?;
+            }
+            JournalEntry::Delete { key } => {
+                stronghold_client.store().delete(&key)?;
+            }
+        }
+        self.dirty.store(true, Ordering::Release);
+
         Ok(())
     }
 }
@@ -128,4 +167,46 @@ mod tests {
 
         fs::remove_file(snapshot_path).unwrap();
     }
+
+    #[tokio::test]
+    async fn test_stronghold_db_journal() {
+        use std::{fs, path::Path};
+
+        use super::StrongholdAdapter;
+        use crate::client::storage::StorageAdapter;
+
+        let snapshot_path = "test_stronghold_db_journal.stronghold";
+        let journal_path = "test_stronghold_db_journal.stronghold.journal";
+
+        fs::remove_file(snapshot_path).unwrap_or(());
+        fs::remove_file(journal_path).unwrap_or(());
+
+        let stronghold = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .journal(true)
+            .build(snapshot_path)
+            .unwrap();
+        stronghold.set("test-0", "test-0").await.unwrap();
+        stronghold.set("test-1", "test-1").await.unwrap();
+        stronghold.delete("test-1").await.unwrap();
+        assert!(stronghold.has_unsaved_changes());
+
+        // Dropping it without writing the snapshot, as if the process crashed
+        drop(stronghold);
+        assert!(Path::new(journal_path).exists());
+
+        let stronghold = StrongholdAdapter::builder()
+            .password("drowssap".to_owned())
+            .journal(true)
+            .build(snapshot_path)
+            .unwrap();
+        assert!(matches!(stronghold.get::<String>("test-0").await, Ok(Some(s)) if s == "test-0"));
+        assert!(matches!(stronghold.get::<String>("test-1").await, Ok(None)));
+
+        stronghold.flush().await.unwrap();
+        assert!(!stronghold.has_unsaved_changes());
+        assert!(!Path::new(journal_path).exists());
+
+        fs::remove_file(snapshot_path).unwrap();
+    }
 }
@@ -0,0 +1,152 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Application protocols that are identified by a tag prefix, e.g. for chat messages, sensor data or attestations.
+//!
+//! Protocols are registered with [`ClientBuilder::with_tag_protocol()`], the client and wallet then decode tagged data
+//! and the features of outputs at one dispatch point.
+//!
+//! [`ClientBuilder::with_tag_protocol()`]: crate::client::ClientBuilder::with_tag_protocol
+
+use std::{fmt::Debug, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::{ClientInner, Error, Result},
+    types::block::{
+        output::Output,
+        payload::{transaction::TransactionEssence, Payload, TaggedDataPayload},
+    },
+};
+
+/// An application protocol whose payloads are tagged with a common prefix.
+pub trait TagProtocol: Send + Sync {
+    /// The name of the protocol, e.g. `chat`.
+    fn name(&self) -> &str;
+
+    /// The prefix of the tags of the protocol.
+    fn tag_prefix(&self) -> &[u8];
+
+    /// Decodes the data of a payload whose tag starts with the prefix of the protocol.
+    fn decode(&self, tag: &[u8], data: &[u8]) -> core::result::Result<Value, String>;
+}
+
+impl<T: TagProtocol + ?Sized> TagProtocol for Arc<T> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn tag_prefix(&self) -> &[u8] {
+        (**self).tag_prefix()
+    }
+
+    fn decode(&self, tag: &[u8], data: &[u8]) -> core::result::Result<Value, String> {
+        (**self).decode(tag, data)
+    }
+}
+
+/// A payload decoded by a [`TagProtocol`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationPayload {
+    /// The name of the protocol.
+    pub protocol: String,
+    /// The tag of the payload.
+    #[serde(with = "crate::utils::serde::prefix_hex_bytes")]
+    pub tag: Vec<u8>,
+    /// The decoded data.
+    pub data: Value,
+}
+
+/// The registered tag protocols.
+#[derive(Clone, Default)]
+pub(crate) struct TagProtocols(Vec<Arc<dyn TagProtocol>>);
+
+impl TagProtocols {
+    /// Registers a protocol, replacing a protocol with the same tag prefix.
+    pub(crate) fn push(&mut self, protocol: Arc<dyn TagProtocol>) {
+        self.0
+            .retain(|registered| registered.tag_prefix() != protocol.tag_prefix());
+        self.0.push(protocol);
+    }
+
+    /// Decodes a payload with the protocol with the longest matching tag prefix.
+    pub(crate) fn decode(&self, tag: &[u8], data: &[u8]) -> Result<Option<ApplicationPayload>> {
+        let Some(protocol) = self
+            .0
+            .iter()
+            .filter(|protocol| tag.starts_with(protocol.tag_prefix()))
+            .max_by_key(|protocol| protocol.tag_prefix().len())
+        else {
+            return Ok(None);
+        };
+
+        let data = protocol.decode(tag, data).map_err(|error| Error::TagProtocolDecode {
+            protocol: protocol.name().to_string(),
+            error,
+        })?;
+
+        Ok(Some(ApplicationPayload {
+            protocol: protocol.name().to_string(),
+            tag: tag.to_vec(),
+            data,
+        }))
+    }
+}
+
+impl Debug for TagProtocols {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|protocol| protocol.name()))
+            .finish()
+    }
+}
+
+impl PartialEq for TagProtocols {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| Arc::ptr_eq(a, b))
+    }
+}
+
+impl Eq for TagProtocols {}
+
+impl ClientInner {
+    /// Decodes tagged data with the registered tag protocols. Returns `None` if no protocol matches the tag.
+    pub async fn decode_tagged_data(&self, tagged_data: &TaggedDataPayload) -> Result<Option<ApplicationPayload>> {
+        self.tag_protocols
+            .read()
+            .await
+            .decode(tagged_data.tag(), tagged_data.data())
+    }
+
+    /// Decodes the tagged data of a block payload, also the one of a transaction, with the registered tag protocols.
+    pub async fn decode_payload(&self, payload: &Payload) -> Result<Option<ApplicationPayload>> {
+        let tagged_data = match payload {
+            Payload::TaggedData(tagged_data) => tagged_data,
+            Payload::Transaction(transaction) => {
+                let TransactionEssence::Regular(essence) = transaction.essence();
+                match essence.payload() {
+                    Some(Payload::TaggedData(tagged_data)) => tagged_data,
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+
+        self.decode_tagged_data(tagged_data).await
+    }
+
+    /// Decodes the metadata feature of an output with the tag protocol matching its tag feature.
+    pub async fn decode_output(&self, output: &Output) -> Result<Option<ApplicationPayload>> {
+        let Some(features) = output.features() else {
+            return Ok(None);
+        };
+        let Some(tag) = features.tag() else {
+            return Ok(None);
+        };
+        let data = features.metadata().map(|metadata| metadata.data()).unwrap_or_default();
+
+        self.tag_protocols.read().await.decode(tag.tag(), data)
+    }
+}
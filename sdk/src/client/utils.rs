@@ -8,7 +8,7 @@ use std::collections::HashMap;
 
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
-    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, Seed},
+    keys::bip39::{wordlist, Mnemonic, MnemonicRef, Passphrase, PassphraseRef, Seed},
     utils,
 };
 use serde::{Deserialize, Serialize};
@@ -65,11 +65,19 @@ pub fn mnemonic_to_hex_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<String
 
 /// Returns a seed for a mnemonic.
 pub fn mnemonic_to_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<Seed> {
+    mnemonic_with_passphrase_to_seed(mnemonic, Passphrase::default())
+}
+
+/// Returns a seed for a mnemonic and a BIP-39 passphrase.
+pub fn mnemonic_with_passphrase_to_seed(
+    mnemonic: impl Borrow<MnemonicRef>,
+    passphrase: impl Borrow<PassphraseRef>,
+) -> Result<Seed> {
     // first we check if the mnemonic is valid to give meaningful errors
     verify_mnemonic(mnemonic.borrow())?;
     Ok(crypto::keys::bip39::mnemonic_to_seed(
         mnemonic.borrow(),
-        &Passphrase::default(),
+        passphrase.borrow(),
     ))
 }
 
@@ -164,6 +172,14 @@ impl Client {
         mnemonic_to_seed(mnemonic)
     }
 
+    /// Returns a seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_with_passphrase_to_seed(
+        mnemonic: impl Borrow<MnemonicRef>,
+        passphrase: impl Borrow<PassphraseRef>,
+    ) -> Result<Seed> {
+        mnemonic_with_passphrase_to_seed(mnemonic, passphrase)
+    }
+
     /// Returns a hex encoded seed for a mnemonic.
     pub fn mnemonic_to_hex_seed(mnemonic: impl Borrow<MnemonicRef>) -> Result<String> {
         mnemonic_to_hex_seed(mnemonic)
@@ -193,4 +209,8 @@ impl Password {
     pub fn as_bytes(&self) -> &[u8] {
         self.0.as_bytes()
     }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
 }
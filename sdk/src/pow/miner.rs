@@ -93,54 +93,16 @@ pub struct Miner {
 }
 
 impl Miner {
-    fn worker(cancel: MinerCancel, pow_digest: TritBuf<T1B1Buf>, start_nonce: u64, target_zeros: usize) -> Option<u64> {
-        let mut nonce = start_nonce;
-        let mut hasher = CurlPBatchHasher::<T1B1Buf>::new(HASH_LENGTH);
-        let mut buffers = Vec::<TritBuf<T1B1Buf>>::with_capacity(BATCH_SIZE);
-
-        for _ in 0..BATCH_SIZE {
-            let mut buffer = TritBuf::<T1B1Buf>::zeros(HASH_LENGTH);
-            buffer[..pow_digest.len()].copy_from(&pow_digest);
-            buffers.push(buffer);
-        }
-
-        while !cancel.is_cancelled() {
-            for (i, buffer) in buffers.iter_mut().enumerate() {
-                let nonce_trits = b1t6::encode::<T1B1Buf>(&(nonce + i as u64).to_le_bytes());
-                buffer[pow_digest.len()..pow_digest.len() + nonce_trits.len()].copy_from(&nonce_trits);
-                hasher.add(buffer.clone());
-            }
-
-            for (i, hash) in hasher.hash().enumerate() {
-                if count_trailing_zeros(&hash) >= target_zeros {
-                    cancel.trigger();
-                    return Some(nonce + i as u64);
-                }
-            }
-
-            nonce += BATCH_SIZE as u64;
-        }
-
-        None
-    }
-
     /// Mines a nonce for provided bytes.
     pub fn nonce(&self, bytes: &[u8], target_score: u32) -> Option<u64> {
         self.cancel.reset();
 
         let mut nonce = None;
-        let mut pow_digest = TritBuf::<T1B1Buf>::new();
-        // This should not be more than HASH_LENGTH but given the types of `bytes` and `target_score`, its maximum value
-        // depending on user input is ceil(ln(usize::MAX * u32::MAX) / ln(3)) = 61.
-        let target_zeros = ((((bytes.len() + std::mem::size_of::<u64>()) as f64).ln() + (target_score as f64).ln())
-            / LN_3)
-            .ceil() as usize;
+        let pow_digest = pow_digest(bytes);
+        let target_zeros = target_zeros(bytes.len(), target_score);
 
         let worker_width = u64::MAX / self.num_workers as u64;
         let mut workers = Vec::with_capacity(self.num_workers);
-        let hash = Blake2b256::digest(bytes);
-
-        b1t6::encode::<T1B1Buf>(&hash).iter().for_each(|t| pow_digest.push(t));
 
         for i in 0..self.num_workers {
             let start_nonce = i as u64 * worker_width;
@@ -148,7 +110,7 @@ impl Miner {
             let _pow_digest = pow_digest.clone();
 
             workers.push(thread::spawn(move || {
-                Self::worker(_cancel, _pow_digest, start_nonce, target_zeros)
+                mine(&_cancel, &_pow_digest, start_nonce, target_zeros)
             }));
         }
 
@@ -162,6 +124,62 @@ impl Miner {
     }
 }
 
+/// Returns the number of trailing zeros the PoW hash of bytes and a nonce needs to reach the target score.
+pub(crate) fn target_zeros(bytes_len: usize, target_score: u32) -> usize {
+    // This should not be more than HASH_LENGTH but given the types of `bytes` and `target_score`, its maximum value
+    // depending on user input is ceil(ln(usize::MAX * u32::MAX) / ln(3)) = 61.
+    ((((bytes_len + std::mem::size_of::<u64>()) as f64).ln() + (target_score as f64).ln()) / LN_3).ceil() as usize
+}
+
+/// Returns the trit encoded Blake2b-256 hash of bytes, which the nonce is appended to.
+pub(crate) fn pow_digest(bytes: &[u8]) -> TritBuf<T1B1Buf> {
+    let mut pow_digest = TritBuf::<T1B1Buf>::new();
+    let hash = Blake2b256::digest(bytes);
+
+    b1t6::encode::<T1B1Buf>(&hash).iter().for_each(|t| pow_digest.push(t));
+
+    pow_digest
+}
+
+/// Searches a nonce, starting at `start_nonce`, until one is found or the search is cancelled.
+///
+/// Other searches sharing the same cancel are cancelled when a nonce is found.
+pub(crate) fn mine(
+    cancel: &MinerCancel,
+    pow_digest: &TritBuf<T1B1Buf>,
+    start_nonce: u64,
+    target_zeros: usize,
+) -> Option<u64> {
+    let mut nonce = start_nonce;
+    let mut hasher = CurlPBatchHasher::<T1B1Buf>::new(HASH_LENGTH);
+    let mut buffers = Vec::<TritBuf<T1B1Buf>>::with_capacity(BATCH_SIZE);
+
+    for _ in 0..BATCH_SIZE {
+        let mut buffer = TritBuf::<T1B1Buf>::zeros(HASH_LENGTH);
+        buffer[..pow_digest.len()].copy_from(pow_digest);
+        buffers.push(buffer);
+    }
+
+    while !cancel.is_cancelled() {
+        for (i, buffer) in buffers.iter_mut().enumerate() {
+            let nonce_trits = b1t6::encode::<T1B1Buf>(&(nonce + i as u64).to_le_bytes());
+            buffer[pow_digest.len()..pow_digest.len() + nonce_trits.len()].copy_from(&nonce_trits);
+            hasher.add(buffer.clone());
+        }
+
+        for (i, hash) in hasher.hash().enumerate() {
+            if count_trailing_zeros(&hash) >= target_zeros {
+                cancel.trigger();
+                return Some(nonce + i as u64);
+            }
+        }
+
+        nonce += BATCH_SIZE as u64;
+    }
+
+    None
+}
+
 fn _get_miner(bytes: &[u8], min_pow_score: u32, num_workers: usize) -> Option<u64> {
     MinerBuilder::new()
         .with_num_workers(num_workers)
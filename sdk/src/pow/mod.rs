@@ -5,6 +5,8 @@
 //! TIP <https://github.com/iotaledger/tips/blob/main/tips/TIP-0012/tip-0012.md>.
 
 pub mod miner;
+#[cfg(not(target_family = "wasm"))]
+pub mod pool;
 pub mod score;
 #[cfg(target_family = "wasm")]
 pub mod wasm_miner;
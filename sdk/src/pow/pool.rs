@@ -0,0 +1,119 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A pool of PoW worker threads that is reused for multiple nonce searches.
+
+use std::{
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use crypto::encoding::ternary::{T1B1Buf, TritBuf};
+
+use crate::pow::miner::{mine, pow_digest, target_zeros, MinerCancel};
+
+/// A chunk of the nonce space of a nonce search.
+struct Job {
+    cancel: MinerCancel,
+    pow_digest: Arc<TritBuf<T1B1Buf>>,
+    start_nonce: u64,
+    target_zeros: usize,
+    result: Sender<Option<u64>>,
+}
+
+/// A fixed number of worker threads that search nonces.
+///
+/// Unlike the [`Miner`](crate::pow::miner::Miner), the threads are only spawned once, and concurrent nonce searches
+/// share them instead of each occupying all cores. The threads stop when the pool is dropped.
+pub struct PowWorkerPool {
+    num_workers: usize,
+    sender: Mutex<Sender<Job>>,
+}
+
+impl PowWorkerPool {
+    /// Creates a new [`PowWorkerPool`] with `num_workers` threads, at least one.
+    pub fn new(num_workers: usize) -> Self {
+        let num_workers = num_workers.max(1);
+        let (sender, receiver) = channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..num_workers {
+            let receiver = receiver.clone();
+            thread::spawn(move || worker(&receiver));
+        }
+
+        Self {
+            num_workers,
+            sender: Mutex::new(sender),
+        }
+    }
+
+    /// Returns the number of worker threads.
+    pub fn num_workers(&self) -> usize {
+        self.num_workers
+    }
+
+    /// Searches a nonce for provided bytes, until one reaching the target score is found or `cancel` is triggered.
+    ///
+    /// Blocks the calling thread.
+    pub fn nonce(&self, bytes: &[u8], target_score: u32, cancel: &MinerCancel) -> Option<u64> {
+        let pow_digest = Arc::new(pow_digest(bytes));
+        let target_zeros = target_zeros(bytes.len(), target_score);
+        let worker_width = u64::MAX / self.num_workers as u64;
+        let (result_sender, result_receiver) = channel();
+
+        {
+            let sender = self.sender.lock().expect("pow worker pool lock poisoned");
+            for i in 0..self.num_workers {
+                let job = Job {
+                    cancel: cancel.clone(),
+                    pow_digest: pow_digest.clone(),
+                    start_nonce: i as u64 * worker_width,
+                    target_zeros,
+                    result: result_sender.clone(),
+                };
+                // Workers only stop when the pool is dropped, so this can't fail.
+                sender.send(job).ok();
+            }
+        }
+        drop(result_sender);
+
+        // Every job reports exactly one result, the first found nonce cancels the other jobs.
+        let nonce = result_receiver.iter().flatten().next();
+        // Wait for the remaining jobs so they don't occupy workers after returning.
+        result_receiver.iter().for_each(drop);
+
+        nonce
+    }
+}
+
+impl core::fmt::Debug for PowWorkerPool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PowWorkerPool")
+            .field("num_workers", &self.num_workers)
+            .finish()
+    }
+}
+
+fn worker(receiver: &Mutex<Receiver<Job>>) {
+    loop {
+        let job = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        // The sender was dropped with the pool.
+        let Ok(job) = job else {
+            return;
+        };
+
+        let nonce = if job.cancel.is_cancelled() {
+            None
+        } else {
+            mine(&job.cancel, &job.pow_digest, job.start_nonce, job.target_zeros)
+        };
+        job.result.send(nonce).ok();
+    }
+}
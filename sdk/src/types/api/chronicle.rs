@@ -0,0 +1,135 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Chronicle permanode explorer responses.
+//! <https://github.com/iotaledger/inx-chronicle/blob/main/src/bin/inx-chronicle/api/explorer/responses.rs>
+
+use alloc::{string::String, vec::Vec};
+
+use crate::types::block::{address::Address, output::OutputId, BlockId};
+
+/// The order of paginated Chronicle responses.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum SortOrder {
+    /// The newest entries first.
+    #[default]
+    Newest,
+    /// The oldest entries first.
+    Oldest,
+}
+
+impl core::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Newest => write!(f, "newest"),
+            Self::Oldest => write!(f, "oldest"),
+        }
+    }
+}
+
+/// Response of GET /api/explorer/v2/ledger/updates/by-address/{address}
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct LedgerUpdatesByAddressResponse {
+    /// The bech32 address the updates belong to.
+    pub address: String,
+    /// The ledger updates.
+    pub items: Vec<LedgerUpdateByAddress>,
+    /// Cursor to request the next page.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub cursor: Option<String>,
+}
+
+/// An output of an address that was created or spent.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct LedgerUpdateByAddress {
+    /// The index of the milestone that confirmed the update.
+    pub milestone_index: u32,
+    /// The timestamp of the milestone that confirmed the update.
+    pub milestone_timestamp: u32,
+    /// The output that was created or spent.
+    pub output_id: OutputId,
+    /// Whether the output was spent, otherwise it was created.
+    pub is_spent: bool,
+}
+
+/// Response of GET /api/explorer/v2/ledger/updates/by-milestone/{milestoneId}
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct LedgerUpdatesByMilestoneResponse {
+    /// The index of the milestone.
+    pub milestone_index: u32,
+    /// The ledger updates.
+    pub items: Vec<LedgerUpdateByMilestone>,
+    /// Cursor to request the next page.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub cursor: Option<String>,
+}
+
+/// An output that was created or spent in a milestone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct LedgerUpdateByMilestone {
+    /// The address that owns the output.
+    #[cfg_attr(feature = "serde", serde(with = "address_dto"))]
+    pub address: Address,
+    /// The output that was created or spent.
+    pub output_id: OutputId,
+    /// Whether the output was spent, otherwise it was created.
+    pub is_spent: bool,
+}
+
+/// Response of GET /api/explorer/v2/blocks/{blockId}/children
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct BlockChildrenResponse {
+    /// The block the children belong to.
+    pub block_id: BlockId,
+    /// The max number of children per page.
+    pub max_results: usize,
+    /// The number of children in this page.
+    pub count: usize,
+    /// The children.
+    pub children: Vec<BlockId>,
+}
+
+#[cfg(feature = "serde")]
+mod address_dto {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::types::block::address::{dto::AddressDto, Address};
+
+    pub(super) fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+        AddressDto::from(address).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+        Address::try_from(AddressDto::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
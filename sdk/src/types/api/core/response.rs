@@ -3,13 +3,14 @@
 
 use alloc::{string::String, vec::Vec};
 
+use packable::{error::UnpackError, PackableExt};
 use serde::{Deserialize, Serialize};
 
 use crate::types::block::{
     output::{dto::OutputDto, OutputId, OutputMetadata, OutputWithMetadata},
     payload::milestone::{option::dto::ReceiptMilestoneOptionDto, MilestoneId},
     protocol::ProtocolParameters,
-    BlockId,
+    BlockId, Error,
 };
 
 /// Response of GET /api/core/v2/info.
@@ -81,6 +82,27 @@ pub struct PendingProtocolParameter {
     pub params: String,
 }
 
+impl PendingProtocolParameter {
+    /// Decodes the hex encoded binary parameters, e.g. to validate outputs against them before the target milestone.
+    pub fn try_into_parameters(&self) -> Result<ProtocolParameters, Error> {
+        let bytes = prefix_hex::decode::<Vec<u8>>(&self.params).map_err(Error::Hex)?;
+        let protocol_parameters =
+            ProtocolParameters::unpack_verified(bytes.as_slice(), &()).map_err(|err| match err {
+                UnpackError::Packable(err) => err,
+                UnpackError::Unpacker(_) => Error::InvalidField("params"),
+            })?;
+
+        if protocol_parameters.protocol_version() != self.protocol_version {
+            return Err(Error::ProtocolVersionMismatch {
+                expected: self.protocol_version,
+                actual: protocol_parameters.protocol_version(),
+            });
+        }
+
+        Ok(protocol_parameters)
+    }
+}
+
 /// Returned in [`InfoResponse`].
 /// Information about the base token.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -274,7 +296,7 @@ pub struct PeerResponse {
     pub gossip: Option<Gossip>,
 }
 
-/// Response of GET /api/plugins/debug/whiteflag.
+/// Response of POST /api/debug/v1/whiteflag.
 /// Returns the computed merkle tree hash for the given white flag traversal.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
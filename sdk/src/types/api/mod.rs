@@ -5,5 +5,6 @@
 
 #![allow(missing_docs)]
 
+pub mod chronicle;
 pub mod core;
 pub mod plugins;
@@ -5,3 +5,4 @@
 
 pub mod indexer;
 pub mod participation;
+pub mod poi;
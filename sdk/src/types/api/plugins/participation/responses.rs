@@ -4,7 +4,7 @@
 //! Response types for the public participation endpoints.
 //! Types from <https://github.com/iotaledger/inx-participation/blob/d3b994f74a8bb948b18a89b04ed6c9bb271c7166/core/participation/types.go>
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use hashbrown::HashMap;
 
@@ -61,6 +61,26 @@ pub struct AddressOutputsResponse {
     pub outputs: HashMap<OutputId, OutputStatusResponse>,
 }
 
+/// RewardsResponse defines the response of a GET RouteAdminRewards REST API call.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct RewardsResponse {
+    /// Symbol of the rewarded tokens.
+    pub symbol: String,
+    /// Milestone index the rewards were calculated for.
+    pub milestone_index: u32,
+    /// Sum of all rewards.
+    pub total_rewards: u64,
+    /// SHA256 checksum of the rewards.
+    pub checksum: String,
+    /// Rewards per bech32 address.
+    pub rewards: HashMap<String, u64>,
+}
+
 #[cfg(test)]
 impl OutputStatusResponse {
     pub fn mock() -> Self {
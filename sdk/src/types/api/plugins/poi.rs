@@ -0,0 +1,106 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Proof of inclusion types.
+//! <https://github.com/iotaledger/inx-poi>
+
+use alloc::boxed::Box;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        payload::milestone::{dto::MilestonePayloadDto, MilestonePayload},
+        Block, BlockDto, BlockId,
+    },
+    utils::serde::prefix_hex_bytes,
+};
+
+const LEAF_HASH_PREFIX: u8 = 0;
+const NODE_HASH_PREFIX: u8 = 1;
+
+/// A merkle audit path from a block ID to the inclusion merkle root of a milestone.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MerkleProof {
+    /// A node of the path, the block ID is in one of its subtrees.
+    Node {
+        #[serde(rename = "l")]
+        left: Box<Self>,
+        #[serde(rename = "r")]
+        right: Box<Self>,
+    },
+    /// The leaf of the proven block ID.
+    Value { value: BlockId },
+    /// The hash of a subtree that doesn't contain the proven block ID.
+    Hash {
+        #[serde(rename = "h", with = "prefix_hex_bytes")]
+        hash: [u8; 32],
+    },
+}
+
+impl MerkleProof {
+    /// Computes the merkle root the proof leads to.
+    pub fn hash(&self) -> [u8; 32] {
+        match self {
+            Self::Node { left, right } => Blake2b256::new()
+                .chain_update([NODE_HASH_PREFIX])
+                .chain_update(left.hash())
+                .chain_update(right.hash())
+                .finalize()
+                .into(),
+            Self::Value { value } => Blake2b256::new()
+                .chain_update([LEAF_HASH_PREFIX])
+                .chain_update(value)
+                .finalize()
+                .into(),
+            Self::Hash { hash } => *hash,
+        }
+    }
+
+    /// Checks whether the proof contains the leaf of a block ID.
+    pub fn contains_value(&self, block_id: &BlockId) -> bool {
+        match self {
+            Self::Node { left, right } => left.contains_value(block_id) || right.contains_value(block_id),
+            Self::Value { value } => value == block_id,
+            Self::Hash { .. } => false,
+        }
+    }
+}
+
+/// Response of GET /api/poi/v1/create/{blockId}.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofOfInclusionResponse {
+    pub milestone: MilestonePayloadDto,
+    pub block: BlockDto,
+    pub proof: MerkleProof,
+}
+
+/// A proof that a block was referenced by a milestone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProofOfInclusion {
+    /// The milestone that referenced the block.
+    pub milestone: MilestonePayload,
+    /// The proven block.
+    pub block: Block,
+    /// The merkle audit path from the block to the inclusion merkle root of the milestone.
+    pub proof: MerkleProof,
+}
+
+impl ProofOfInclusion {
+    /// Verifies the proof, see [`verify_poi()`].
+    pub fn verify(&self) -> bool {
+        verify_poi(&self.proof, &self.block, &self.milestone)
+    }
+}
+
+/// Verifies that a block was referenced by a milestone, by recomputing the inclusion merkle root of the milestone from
+/// the block ID and the merkle proof.
+///
+/// This doesn't verify the milestone itself, its signatures need to be checked against the applicable public keys with
+/// [`MilestonePayload::validate()`] to not trust the node that served it.
+pub fn verify_poi(proof: &MerkleProof, block: &Block, milestone: &MilestonePayload) -> bool {
+    proof.contains_value(&block.id()) && proof.hash() == **milestone.essence().inclusion_merkle_root()
+}
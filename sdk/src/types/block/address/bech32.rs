@@ -40,6 +40,11 @@ impl Hrp {
             len: len as _,
         }
     }
+
+    /// Returns the well-known network that uses this HRP, if any.
+    pub fn guess_network(&self) -> Option<Bech32Network> {
+        Bech32Network::from_hrp(self)
+    }
 }
 
 impl FromStr for Hrp {
@@ -136,6 +141,56 @@ impl<T: AsRef<str> + Send> ConvertTo<Hrp> for T {
     }
 }
 
+/// The well-known networks, identified by the HRP of their bech32 addresses.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[non_exhaustive]
+pub enum Bech32Network {
+    /// The IOTA mainnet, `iota`.
+    Iota,
+    /// The IOTA testnet, `atoi`.
+    IotaTestnet,
+    /// The Shimmer mainnet, `smr`.
+    Shimmer,
+    /// The Shimmer testnet, `rms`.
+    ShimmerTestnet,
+}
+
+impl Bech32Network {
+    /// Returns the network that uses the HRP, if any.
+    pub fn from_hrp(hrp: &Hrp) -> Option<Self> {
+        Some(match hrp.to_string().as_str() {
+            "iota" => Self::Iota,
+            "atoi" => Self::IotaTestnet,
+            "smr" => Self::Shimmer,
+            "rms" => Self::ShimmerTestnet,
+            _ => return None,
+        })
+    }
+
+    /// Returns the HRP of the network.
+    pub const fn hrp(&self) -> Hrp {
+        Hrp::from_str_unchecked(match self {
+            Self::Iota => "iota",
+            Self::IotaTestnet => "atoi",
+            Self::Shimmer => "smr",
+            Self::ShimmerTestnet => "rms",
+        })
+    }
+}
+
+impl core::fmt::Display for Bech32Network {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Iota => "IOTA",
+            Self::IotaTestnet => "IOTA testnet",
+            Self::Shimmer => "Shimmer",
+            Self::ShimmerTestnet => "Shimmer testnet",
+        })
+    }
+}
+
 /// An address and its network type.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, AsRef, Deref, Ord, PartialOrd)]
 pub struct Bech32Address {
@@ -184,6 +239,11 @@ impl Bech32Address {
         &self.hrp
     }
 
+    /// Returns the well-known network the address belongs to, if any.
+    pub fn guess_network(&self) -> Option<Bech32Network> {
+        self.hrp.guess_network()
+    }
+
     /// Gets the address part.
     pub fn inner(&self) -> &Address {
         &self.inner
@@ -198,6 +258,27 @@ impl Bech32Address {
     pub fn try_from_str(address: impl AsRef<str>) -> Result<Self, Error> {
         Self::from_str(address.as_ref())
     }
+
+    /// Parses a bech32 address string and checks that it has the expected HRP.
+    pub fn try_from_str_with_hrp(address: impl AsRef<str>, expected: impl ConvertTo<Hrp>) -> Result<Self, Error> {
+        let address = Self::from_str(address.as_ref())?;
+        address.check_hrp(expected)?;
+        Ok(address)
+    }
+
+    /// Checks that the address has the expected HRP.
+    pub fn check_hrp(&self, expected: impl ConvertTo<Hrp>) -> Result<(), Error> {
+        let expected = expected.convert()?;
+
+        if self.hrp != expected {
+            return Err(Error::Bech32HrpMismatch {
+                expected: expected.to_string(),
+                actual: self.hrp.to_string(),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl core::fmt::Display for Bech32Address {
@@ -10,7 +10,7 @@ use derive_more::{Display, From};
 
 pub use self::{
     alias::AliasAddress,
-    bech32::{Bech32Address, Hrp},
+    bech32::{Bech32Address, Bech32Network, Hrp},
     ed25519::Ed25519Address,
     nft::NftAddress,
 };
@@ -138,7 +138,7 @@ impl Address {
 
                 let Signature::Ed25519(signature) = unlock.signature();
 
-                if signature.is_valid(&context.essence_hash, ed25519_address).is_err() {
+                if context.verify_signatures && signature.is_valid(&context.essence_hash, ed25519_address).is_err() {
                     return Err(ConflictReason::InvalidSignature);
                 }
 
@@ -182,6 +182,27 @@ impl Block {
         let block_bytes = self.pack_to_vec();
         Blake2b256::digest(&block_bytes[..block_bytes.len() - core::mem::size_of::<u64>()]).into()
     }
+
+    /// Computes the Proof of Work score of the block, as defined by TIP-12.
+    #[cfg(feature = "pow")]
+    pub fn pow_score(&self) -> f64 {
+        crate::pow::score::PowScorer::new().score(&self.pack_to_vec())
+    }
+
+    /// Verifies that the nonce of the block results in a Proof of Work score of at least `min_pow_score`.
+    #[cfg(feature = "pow")]
+    pub fn verify_nonce(&self, min_pow_score: u32) -> Result<(), Error> {
+        let score = self.pow_score();
+
+        if score < min_pow_score as f64 {
+            return Err(Error::InsufficientPowScore {
+                min_pow_score,
+                score: score as u32,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl Packable for Block {
@@ -2,13 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use alloc::string::{FromUtf8Error, String};
-use core::{convert::Infallible, fmt};
+use core::{convert::Infallible, fmt, str::FromStr};
 
 use crypto::Error as CryptoError;
 use prefix_hex::Error as HexError;
 use primitive_types::U256;
 
 use crate::types::block::{
+    address::Hrp,
     input::UtxoInput,
     output::{
         feature::FeatureCount, unlock_condition::UnlockConditionCount, AliasId, ChainId, MetadataFeatureLength,
@@ -16,8 +17,9 @@ use crate::types::block::{
     },
     parent::ParentCount,
     payload::{
-        milestone::BinaryParametersLength, InputCount, MilestoneMetadataLength, MilestoneOptionCount, OutputCount,
-        ReceiptFundsCount, SignatureCount, TagLength, TaggedDataLength,
+        milestone::{BinaryParametersLength, MilestoneId},
+        InputCount, MilestoneMetadataLength, MilestoneOptionCount, OutputCount, ReceiptFundsCount, SignatureCount,
+        TagLength, TaggedDataLength,
     },
     unlock::{UnlockCount, UnlockIndex},
 };
@@ -44,6 +46,7 @@ pub enum Error {
     InsufficientStorageDepositAmount { amount: u64, required: u64 },
     StorageDepositReturnExceedsOutputAmount { deposit: u64, amount: u64 },
     InsufficientStorageDepositReturnAmount { deposit: u64, required: u64 },
+    InsufficientTreasuryAmount { available: u64, required: u128 },
     InvalidBinaryParametersLength(<BinaryParametersLength as TryFrom<usize>>::Error),
     InvalidEssenceKind(u8),
     InvalidFeatureCount(<FeatureCount as TryFrom<usize>>::Error),
@@ -54,6 +57,7 @@ pub enum Error {
     InvalidInputCount(<InputCount as TryFrom<usize>>::Error),
     InvalidInputOutputIndex(<OutputIndex as TryFrom<u16>>::Error),
     InvalidBech32Hrp(String),
+    Bech32HrpMismatch { expected: String, actual: String },
     InvalidBlockLength(usize),
     InvalidStateMetadataLength(<StateMetadataLength as TryFrom<usize>>::Error),
     InvalidMetadataFeatureLength(<MetadataFeatureLength as TryFrom<usize>>::Error),
@@ -107,6 +111,7 @@ pub enum Error {
     ParentsNotUniqueSorted,
     ProtocolVersionMismatch { expected: u8, actual: u8 },
     NonceNotFound,
+    InsufficientPowScore { min_pow_score: u32, score: u32 },
     ReceiptFundsNotUniqueSorted,
     RemainingBytesAfterBlock,
     SelfControlledAliasOutput(AliasId),
@@ -115,6 +120,8 @@ pub enum Error {
     StorageDepositReturnOverflow,
     TailTransactionHashNotUnique { previous: usize, current: usize },
     TimelockUnlockConditionZero,
+    TreasuryAmountMismatch { input: u64, output: u64, spent: u64 },
+    TreasuryInputMismatch { expected: MilestoneId, actual: MilestoneId },
     UnallowedFeature { index: usize, kind: u8 },
     UnallowedUnlockCondition { index: usize, kind: u8 },
     UnlockConditionsNotUniqueSorted,
@@ -158,6 +165,13 @@ impl fmt::Display for Error {
             Self::InvalidAddressKind(k) => write!(f, "invalid address kind: {k}"),
             Self::InvalidAliasIndex(index) => write!(f, "invalid alias index: {index}"),
             Self::InvalidBech32Hrp(err) => write!(f, "invalid bech32 hrp: {err}"),
+            Self::Bech32HrpMismatch { expected, actual } => {
+                write!(f, "bech32 hrp mismatch: expected {expected} but got {actual}")?;
+                if let Some(network) = Hrp::from_str(actual).ok().and_then(|hrp| hrp.guess_network()) {
+                    write!(f, ", this looks like a {network} address")?;
+                }
+                Ok(())
+            }
             Self::InvalidBinaryParametersLength(length) => {
                 write!(f, "invalid binary parameters length: {length}")
             }
@@ -176,6 +190,12 @@ impl fmt::Display for Error {
                     "the return deposit ({deposit}) must be greater than the minimum storage deposit ({required})"
                 )
             }
+            Self::InsufficientTreasuryAmount { available, required } => {
+                write!(
+                    f,
+                    "insufficient treasury amount: {required} required but only {available} available"
+                )
+            }
             Self::StorageDepositReturnExceedsOutputAmount { deposit, amount } => write!(
                 f,
                 "storage deposit return of {deposit} exceeds the original output amount of {amount}"
@@ -289,6 +309,12 @@ impl fmt::Display for Error {
             Self::NonceNotFound => {
                 write!(f, "nonce miner could not find a nonce")
             }
+            Self::InsufficientPowScore { min_pow_score, score } => {
+                write!(
+                    f,
+                    "insufficient pow score: expected at least {min_pow_score} but got {score}"
+                )
+            }
             Self::ReceiptFundsNotUniqueSorted => {
                 write!(f, "receipt funds are not unique and/or sorted")
             }
@@ -319,6 +345,15 @@ impl fmt::Display for Error {
                     "timelock unlock condition with milestone index and timestamp set to 0",
                 )
             }
+            Self::TreasuryAmountMismatch { input, output, spent } => {
+                write!(
+                    f,
+                    "treasury amount mismatch: input {input} is not the output {output} plus the spent amount {spent}"
+                )
+            }
+            Self::TreasuryInputMismatch { expected, actual } => {
+                write!(f, "treasury input mismatch: expected {expected} but got {actual}")
+            }
             Self::UnallowedFeature { index, kind } => {
                 write!(f, "unallowed feature at index {index} with kind {kind}")
             }
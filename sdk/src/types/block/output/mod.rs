@@ -63,7 +63,7 @@ pub use self::{
     state_transition::{StateTransitionError, StateTransitionVerifier},
     token_id::TokenId,
     token_scheme::{SimpleTokenScheme, TokenScheme},
-    treasury::TreasuryOutput,
+    treasury::{TreasuryOutput, TreasuryOutputBuilder},
     unlock_condition::{UnlockCondition, UnlockConditions},
 };
 use super::protocol::ProtocolParameters;
@@ -1,7 +1,45 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::types::block::{protocol::ProtocolParameters, Error};
+use crate::types::block::{output::Output, protocol::ProtocolParameters, Error};
+
+/// Builder for a [`TreasuryOutput`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct TreasuryOutputBuilder {
+    amount: u64,
+}
+
+impl TreasuryOutputBuilder {
+    /// Creates a [`TreasuryOutputBuilder`] with a provided amount.
+    #[inline(always)]
+    pub fn new_with_amount(amount: u64) -> Self {
+        Self { amount }
+    }
+
+    /// Sets the amount to the provided value.
+    #[inline(always)]
+    pub fn with_amount(mut self, amount: u64) -> Self {
+        self.amount = amount;
+        self
+    }
+
+    /// Finishes the [`TreasuryOutputBuilder`] into a [`TreasuryOutput`].
+    pub fn finish(self, token_supply: u64) -> Result<TreasuryOutput, Error> {
+        TreasuryOutput::new(self.amount, token_supply)
+    }
+
+    /// Finishes the [`TreasuryOutputBuilder`] into an [`Output`].
+    pub fn finish_output(self, token_supply: u64) -> Result<Output, Error> {
+        Ok(Output::Treasury(self.finish(token_supply)?))
+    }
+}
+
+impl From<&TreasuryOutput> for TreasuryOutputBuilder {
+    fn from(output: &TreasuryOutput) -> Self {
+        Self { amount: output.amount }
+    }
+}
 
 /// [`TreasuryOutput`] is an output which holds the treasury of a network.
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, packable::Packable)]
@@ -24,6 +62,12 @@ impl TreasuryOutput {
         Ok(Self { amount })
     }
 
+    /// Creates a new [`TreasuryOutputBuilder`] with a provided amount.
+    #[inline(always)]
+    pub fn build_with_amount(amount: u64) -> TreasuryOutputBuilder {
+        TreasuryOutputBuilder::new_with_amount(amount)
+    }
+
     /// Returns the amount of a [`TreasuryOutput`].
     #[inline(always)]
     pub fn amount(&self) -> u64 {
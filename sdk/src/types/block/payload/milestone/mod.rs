@@ -24,7 +24,10 @@ pub use self::{
     index::MilestoneIndex,
     merkle::MerkleRoot,
     milestone_id::MilestoneId,
-    option::{MilestoneOption, MilestoneOptions, ParametersMilestoneOption, ReceiptMilestoneOption},
+    option::{
+        MilestoneOption, MilestoneOptions, ParametersMilestoneOption, ReceiptMilestoneOption,
+        ReceiptMilestoneOptionBuilder,
+    },
 };
 pub(crate) use self::{essence::MilestoneMetadataLength, option::BinaryParametersLength};
 use crate::types::block::{protocol::ProtocolParameters, signature::Signature, Error};
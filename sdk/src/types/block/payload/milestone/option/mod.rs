@@ -13,7 +13,7 @@ use packable::{bounded::BoundedU8, prefix::BoxedSlicePrefix, Packable};
 pub(crate) use self::{parameters::BinaryParametersLength, receipt::ReceiptFundsCount};
 pub use self::{
     parameters::ParametersMilestoneOption,
-    receipt::{MigratedFundsEntry, ReceiptMilestoneOption, TailTransactionHash},
+    receipt::{MigratedFundsEntry, ReceiptMilestoneOption, ReceiptMilestoneOptionBuilder, TailTransactionHash},
 };
 use crate::types::block::{protocol::ProtocolParameters, Error};
 
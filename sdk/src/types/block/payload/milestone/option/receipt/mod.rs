@@ -15,8 +15,9 @@ use packable::{bounded::BoundedU16, prefix::VecPrefix, Packable, PackableExt};
 
 pub use self::{migrated_funds_entry::MigratedFundsEntry, tail_transaction_hash::TailTransactionHash};
 use crate::types::block::{
+    input::TreasuryInput,
     output::OUTPUT_COUNT_RANGE,
-    payload::{milestone::MilestoneIndex, Payload, TreasuryTransactionPayload},
+    payload::{milestone::MilestoneIndex, Payload, TreasuryTransactionPayload, TreasuryTransactionPayloadBuilder},
     protocol::ProtocolParameters,
     Error,
 };
@@ -26,6 +27,71 @@ const MIGRATED_FUNDS_ENTRY_RANGE: RangeInclusive<u16> = OUTPUT_COUNT_RANGE;
 pub(crate) type ReceiptFundsCount =
     BoundedU16<{ *MIGRATED_FUNDS_ENTRY_RANGE.start() }, { *MIGRATED_FUNDS_ENTRY_RANGE.end() }>;
 
+/// Builder for a [`ReceiptMilestoneOption`] and the [`TreasuryTransactionPayload`] funding its migrated funds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct ReceiptMilestoneOptionBuilder {
+    migrated_at: MilestoneIndex,
+    last: bool,
+    funds: Vec<MigratedFundsEntry>,
+    treasury_input: TreasuryInput,
+    treasury_amount: u64,
+}
+
+impl ReceiptMilestoneOptionBuilder {
+    /// Creates a [`ReceiptMilestoneOptionBuilder`] funding the migrated funds from the treasury with the provided
+    /// amount created by the milestone referenced by `treasury_input`.
+    pub fn new(migrated_at: MilestoneIndex, treasury_input: TreasuryInput, treasury_amount: u64) -> Self {
+        Self {
+            migrated_at,
+            last: false,
+            funds: Vec::new(),
+            treasury_input,
+            treasury_amount,
+        }
+    }
+
+    /// Sets whether the receipt is the final one for the migrated at index.
+    #[inline(always)]
+    pub fn with_last(mut self, last: bool) -> Self {
+        self.last = last;
+        self
+    }
+
+    /// Adds a [`MigratedFundsEntry`] to the builder.
+    #[inline(always)]
+    pub fn add_funds(mut self, funds: MigratedFundsEntry) -> Self {
+        self.funds.push(funds);
+        self
+    }
+
+    /// Sets the [`MigratedFundsEntry`]s to the provided values.
+    #[inline(always)]
+    pub fn with_funds(mut self, funds: impl IntoIterator<Item = MigratedFundsEntry>) -> Self {
+        self.funds = funds.into_iter().collect();
+        self
+    }
+
+    /// Finishes the [`ReceiptMilestoneOptionBuilder`] into a [`ReceiptMilestoneOption`].
+    ///
+    /// The funds are sorted as required and the treasury transaction moves the remaining treasury amount to a new
+    /// treasury output.
+    pub fn finish(mut self, token_supply: u64) -> Result<ReceiptMilestoneOption, Error> {
+        self.funds.sort_by_cached_key(PackableExt::pack_to_vec);
+
+        let transaction = self
+            .funds
+            .iter()
+            .fold(
+                TreasuryTransactionPayloadBuilder::new(self.treasury_input, self.treasury_amount),
+                |builder, funds| builder.add_spent_amount(funds.amount()),
+            )
+            .finish(token_supply)?;
+
+        ReceiptMilestoneOption::new(self.migrated_at, self.last, self.funds, transaction, token_supply)
+    }
+}
+
 /// Receipt is a listing of migrated funds.
 #[derive(Clone, Debug, Eq, PartialEq, Packable)]
 #[packable(unpack_error = Error)]
@@ -96,6 +162,13 @@ impl ReceiptMilestoneOption {
     pub fn amount(&self) -> u64 {
         self.funds.iter().map(|f| f.amount()).sum()
     }
+
+    /// Verifies that the [`TreasuryTransactionPayload`] of a [`ReceiptMilestoneOption`] consumes the treasury with the
+    /// provided amount created by the milestone referenced by `treasury_input` and only spends the migrated funds.
+    pub fn verify_treasury(&self, treasury_input: &TreasuryInput, treasury_amount: u64) -> Result<(), Error> {
+        self.transaction()
+            .verify_transition(treasury_input, treasury_amount, self.amount())
+    }
 }
 
 fn verify_funds<const VERIFY: bool>(funds: &[MigratedFundsEntry], token_supply: &u64) -> Result<(), Error> {
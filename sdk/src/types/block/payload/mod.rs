@@ -27,7 +27,7 @@ pub use self::{
     milestone::{MilestoneOptions, MilestonePayload},
     tagged_data::TaggedDataPayload,
     transaction::TransactionPayload,
-    treasury_transaction::TreasuryTransactionPayload,
+    treasury_transaction::{TreasuryTransactionPayload, TreasuryTransactionPayloadBuilder},
 };
 use super::protocol::ProtocolParameters;
 use crate::types::block::Error;
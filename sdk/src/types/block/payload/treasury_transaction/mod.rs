@@ -10,6 +10,47 @@ use crate::types::block::{
     Error,
 };
 
+/// Builder for a [`TreasuryTransactionPayload`], spending funds from the treasury created by a previous milestone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[must_use]
+pub struct TreasuryTransactionPayloadBuilder {
+    input: TreasuryInput,
+    input_amount: u64,
+    spent_amount: u128,
+}
+
+impl TreasuryTransactionPayloadBuilder {
+    /// Creates a [`TreasuryTransactionPayloadBuilder`] consuming the treasury with the provided amount created by the
+    /// milestone referenced by the input.
+    pub fn new(input: TreasuryInput, input_amount: u64) -> Self {
+        Self {
+            input,
+            input_amount,
+            spent_amount: 0,
+        }
+    }
+
+    /// Adds an amount that is spent from the treasury, like the funds migrated by a receipt.
+    pub fn add_spent_amount(mut self, amount: u64) -> Self {
+        self.spent_amount += amount as u128;
+        self
+    }
+
+    /// Finishes the [`TreasuryTransactionPayloadBuilder`] into a [`TreasuryTransactionPayload`] with the remaining
+    /// amount in the new treasury output.
+    pub fn finish(self, token_supply: u64) -> Result<TreasuryTransactionPayload, Error> {
+        let output_amount =
+            (self.input_amount as u128)
+                .checked_sub(self.spent_amount)
+                .ok_or(Error::InsufficientTreasuryAmount {
+                    available: self.input_amount,
+                    required: self.spent_amount,
+                })? as u64;
+
+        TreasuryTransactionPayload::new(self.input, TreasuryOutput::new(output_amount, token_supply)?)
+    }
+}
+
 /// [`TreasuryTransactionPayload`] represents a transaction which moves funds from the treasury.
 #[derive(Clone, Debug, Eq, PartialEq, packable::Packable)]
 #[packable(unpack_visitor = ProtocolParameters)]
@@ -47,6 +88,27 @@ impl TreasuryTransactionPayload {
         // It has already been validated at construction that `output` is a `TreasuryOutput`.
         self.output.as_treasury()
     }
+
+    /// Verifies that the [`TreasuryTransactionPayload`] consumes the treasury with the provided amount created by the
+    /// milestone referenced by `input` and only spends `spent_amount` of it.
+    pub fn verify_transition(&self, input: &TreasuryInput, input_amount: u64, spent_amount: u64) -> Result<(), Error> {
+        if self.input() != input {
+            return Err(Error::TreasuryInputMismatch {
+                expected: *input.milestone_id(),
+                actual: *self.input().milestone_id(),
+            });
+        }
+
+        if self.output().amount() as u128 + spent_amount as u128 != input_amount as u128 {
+            return Err(Error::TreasuryAmountMismatch {
+                input: input_amount,
+                output: self.output().amount(),
+                spent: spent_amount,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 fn verify_input<const VERIFY: bool>(input: &Input, _: &ProtocolParameters) -> Result<(), Error> {
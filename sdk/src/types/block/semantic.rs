@@ -168,6 +168,9 @@ pub struct ValidationContext<'a> {
     pub storage_deposit_returns: HashMap<Address, u64>,
     ///
     pub simple_deposits: HashMap<Address, u64>,
+    /// Whether the signatures of the signature unlocks are verified, disabled to validate a transaction before it's
+    /// signed.
+    pub verify_signatures: bool,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -212,6 +215,7 @@ impl<'a> ValidationContext<'a> {
             unlocked_addresses: HashSet::new(),
             storage_deposit_returns: HashMap::new(),
             simple_deposits: HashMap::new(),
+            verify_signatures: true,
         }
     }
 }
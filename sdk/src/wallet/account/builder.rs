@@ -10,6 +10,7 @@ use crate::{
     types::block::address::{Address, Bech32Address, Ed25519Address, Hrp},
     wallet::{
         account::{types::AccountAddress, Account, AccountDetails},
+        label::{account_aliases_match, normalize_account_alias},
         Error, Wallet,
     },
 };
@@ -19,6 +20,8 @@ pub struct AccountBuilder<S: SecretManage = SecretManager> {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<Hrp>,
+    watch_only: bool,
+    return_existing: bool,
     wallet: Wallet<S>,
 }
 
@@ -32,6 +35,8 @@ where
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            watch_only: false,
+            return_existing: false,
             wallet,
         }
     }
@@ -43,6 +48,30 @@ where
         self
     }
 
+    /// Create a watch-only account from imported addresses. The account can be synced and provides its balance and
+    /// transaction history, but it doesn't use the secret manager, so generating addresses and signing transactions
+    /// fail with [`Error::WatchOnlyAccount`]. Transactions can still be prepared and signed with an offline
+    /// counterpart account.
+    ///
+    /// Ed25519 addresses are derived with hardened indexes only, so they can't be derived from an extended public key
+    /// and need to be imported one by one.
+    pub fn with_watch_only_addresses(mut self, addresses: impl IntoIterator<Item = Bech32Address>) -> Self {
+        self.addresses = Some(
+            addresses
+                .into_iter()
+                .enumerate()
+                .map(|(key_index, address)| AccountAddress {
+                    address,
+                    key_index: key_index as u32,
+                    internal: false,
+                    used: false,
+                })
+                .collect(),
+        );
+        self.watch_only = true;
+        self
+    }
+
     /// Set the alias
     pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
         self.alias = Some(alias.into());
@@ -55,6 +84,13 @@ where
         self
     }
 
+    // Return the account with the same alias instead of failing with `Error::AccountAliasAlreadyExists`, the check
+    // happens while the accounts are locked, so concurrent calls don't create duplicate accounts.
+    pub(crate) fn with_return_existing(mut self, return_existing: bool) -> Self {
+        self.return_existing = return_existing;
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
@@ -62,7 +98,10 @@ where
         let mut accounts = self.wallet.accounts.write().await;
         let account_index = accounts.len() as u32;
         // If no alias is provided, the account index will be set as alias
-        let account_alias = self.alias.clone().unwrap_or_else(|| account_index.to_string());
+        let account_alias = match &self.alias {
+            Some(alias) => normalize_account_alias(alias)?,
+            None => account_index.to_string(),
+        };
         log::debug!(
             "[ACCOUNT BUILDER] creating new account {} with index {}",
             account_alias,
@@ -71,27 +110,48 @@ where
 
         // Check that the alias isn't already used for another account
         for account in accounts.iter() {
-            let account = account.details().await;
-            if account.alias().to_lowercase() == account_alias.to_lowercase() {
+            if account_aliases_match(account.details().await.alias(), &account_alias) {
+                if self.return_existing {
+                    return Ok(account.clone());
+                }
                 return Err(Error::AccountAliasAlreadyExists(account_alias));
             }
         }
 
         let coin_type = self.wallet.coin_type.load(core::sync::atomic::Ordering::Relaxed);
 
+        if self.watch_only && self.addresses.as_ref().is_none_or(Vec::is_empty) {
+            return Err(Error::MissingParameter("addresses"));
+        }
+
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
         let addresses = match &self.addresses {
             Some(addresses) => addresses.clone(),
             None => {
                 let mut bech32_hrp = self.bech32_hrp;
-                if let Some(first_account) = accounts.first() {
-                    let first_account_coin_type = *first_account.details().await.coin_type();
+                // Watch-only accounts don't belong to the seed, so they can't be used for the check
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !*account.details().await.watch_only() {
+                        first_account = Some(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
+                    let (first_account_index, first_account_coin_type) = {
+                        let details = first_account.details().await;
+                        (*details.index(), *details.coin_type())
+                    };
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
                     // seeds
-                    let first_account_public_address =
-                        get_first_public_address(&self.wallet.secret_manager, first_account_coin_type, 0).await?;
+                    let first_account_public_address = get_first_public_address(
+                        &self.wallet.secret_manager,
+                        first_account_coin_type,
+                        first_account_index,
+                    )
+                    .await?;
                     let first_account_addresses = first_account.public_addresses().await;
 
                     if Address::Ed25519(first_account_public_address)
@@ -151,6 +211,7 @@ where
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: self.watch_only,
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
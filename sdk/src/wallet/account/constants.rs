@@ -15,5 +15,12 @@ pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
+/// Max amount of milestones an unfinished sync can be behind the confirmed milestone to be resumed from its checkpoint
+pub(crate) const SYNC_CHECKPOINT_MAX_AGE: u32 = 360;
+
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+// Default margin in seconds before the expiration of an input, in which it's not used anymore because the transaction
+// might only get confirmed after the expiration
+pub(crate) const DEFAULT_EXPIRATION_SAFETY_MARGIN: u32 = 60;
@@ -13,7 +13,7 @@ pub mod types;
 pub(crate) mod update;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     ops::Deref,
     sync::Arc,
 };
@@ -30,26 +30,42 @@ use self::types::{
 };
 pub use self::{
     operations::{
+        application_payloads::{ApplicationPayloadSource, ReceivedApplicationPayload},
+        native_token_supply::NativeTokenSupply,
         output_claiming::OutputsToClaim,
         output_consolidation::ConsolidationParams,
         syncing::{
-            options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
-            SyncOptions,
+            options::{AccountSyncOptions, AddressDiscoveryMode, AliasSyncOptions, NftSyncOptions},
+            SyncCheckpoint, SyncOptions, SyncProgress, SyncProgressCallback,
         },
+        token_filter::TokenFilter,
         transaction::{
+            estimate::TransactionEstimate,
+            external_signing::ExternalSigningRequest,
             high_level::{
+                burning_melting::destroy_alias::{
+                    AliasDestructionBlocker, AliasDestructionPlan, AliasDestructionStep, DestroyAliasOptions,
+                },
+                conditional_transfer::{ConditionalTransferAsset, ConditionalTransferParams},
                 create_alias::CreateAliasParams,
                 minting::{
                     create_native_token::{
                         CreateNativeTokenParams, CreateNativeTokenTransactionDto,
-                        PreparedCreateNativeTokenTransactionDto,
+                        CreateNativeTokenWithAliasTransactions, PreparedCreateNativeTokenTransactionDto,
+                    },
+                    mint_nft_collection::{
+                        NftCollectionItemStatus, NftCollectionMintingResult, NFT_COLLECTION_ITEMS_PER_TRANSACTION,
                     },
                     mint_nfts::MintNftParams,
                 },
             },
             prepare_output::{Assets, Features, OutputParams, ReturnStrategy, StorageDeposit, Unlocks},
+            split::{SplitTransaction, SplitTransactionPlan},
             RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
         },
+        transaction_export::{
+            ExportFormat, TransactionDirection, TransactionExportRecord, TRANSACTION_EXPORT_CSV_HEADER,
+        },
     },
     types::OutputDataDto,
 };
@@ -135,6 +151,8 @@ pub struct AccountDetails {
     inaccessible_incoming_transactions: HashSet<TransactionId>,
     /// Foundries for native tokens in outputs
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Whether the account was created from imported addresses and can't generate addresses or sign transactions.
+    watch_only: bool,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -167,6 +185,8 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    pub(crate) sync_checkpoint: Mutex<SyncCheckpoint>,
+    pub(crate) token_filter: Mutex<TokenFilter>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -194,6 +214,26 @@ where
             .unwrap_or_default();
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
+        #[cfg(feature = "storage")]
+        let sync_checkpoint = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_sync_checkpoint(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let sync_checkpoint = Default::default();
+        #[cfg(feature = "storage")]
+        let token_filter = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_token_filter(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let token_filter = Default::default();
 
         Ok(Self {
             wallet,
@@ -201,6 +241,8 @@ where
                 details: RwLock::new(details),
                 last_synced: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                sync_checkpoint: Mutex::new(sync_checkpoint),
+                token_filter: Mutex::new(token_filter),
             }),
         })
     }
@@ -470,6 +512,7 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         network_id: tx_essence.network_id(),
         incoming: true,
         note: None,
+        tags: BTreeSet::new(),
         inputs,
     })
 }
@@ -505,6 +548,9 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Whether the account is watch-only
+    #[serde(default)]
+    pub watch_only: bool,
 }
 
 impl TryFromDto for AccountDetails {
@@ -550,6 +596,7 @@ impl TryFromDto for AccountDetails {
                 .into_iter()
                 .map(|(id, o)| Ok((id, FoundryOutput::try_from_dto_with_params(o, &params)?)))
                 .collect::<crate::wallet::Result<_>>()?,
+            watch_only: dto.watch_only,
         })
     }
 }
@@ -590,6 +637,7 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            watch_only: *value.watch_only(),
         }
     }
 }
@@ -670,6 +718,7 @@ mod test {
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: None,
+            tags: BTreeSet::new(),
             inputs: Vec::new(),
         };
 
@@ -694,6 +743,7 @@ mod test {
             incoming_transactions,
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: false,
         };
 
         let deser_account = AccountDetails::try_from_dto(
@@ -737,6 +787,7 @@ mod test {
                 incoming_transactions: HashMap::new(),
                 inaccessible_incoming_transactions: HashSet::new(),
                 native_token_foundries: HashMap::new(),
+                watch_only: false,
             }
         }
     }
@@ -1,6 +1,9 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "ledger_nano")]
+use crypto::keys::bip44::Bip44;
+
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::{ledger_nano::LedgerSecretManager, DowncastSecretManager};
 use crate::{
@@ -48,6 +51,10 @@ where
 
         let account_details = self.details().await;
 
+        if account_details.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount(account_details.alias.clone()));
+        }
+
         // get the highest index for the public or internal addresses
         let highest_current_index_plus_one = if options.internal {
             account_details.internal_addresses.len() as u32
@@ -72,66 +79,52 @@ where
         let addresses = {
             use crate::wallet::account::SecretManager;
             let secret_manager = self.wallet.secret_manager.read().await;
-            if secret_manager
-                .downcast::<LedgerSecretManager>()
-                .or_else(|| {
-                    secret_manager.downcast::<SecretManager>().and_then(|s| {
-                        if let SecretManager::LedgerNano(n) = s {
-                            Some(n)
-                        } else {
-                            None
-                        }
-                    })
+            if let Some(ledger) = secret_manager.downcast::<LedgerSecretManager>().or_else(|| {
+                secret_manager.downcast::<SecretManager>().and_then(|s| {
+                    if let SecretManager::LedgerNano(n) = s {
+                        Some(n)
+                    } else {
+                        None
+                    }
                 })
-                .is_some()
-            {
-                #[cfg(feature = "events")]
-                let changed_options = {
-                    // Change options so ledger will not show the prompt the first time
-                    let mut changed_options = options;
-                    changed_options.ledger_nano_prompt = false;
-                    changed_options
-                };
+            }) {
+                // Change options so ledger will not show the prompt the first time
+                let mut changed_options = options;
+                changed_options.ledger_nano_prompt = false;
                 let mut addresses = Vec::new();
 
                 for address_index in address_range {
-                    #[cfg(feature = "events")]
-                    {
-                        // Generate without prompt to be able to display it
-                        let address = self
-                            .wallet
-                            .secret_manager
-                            .read()
-                            .await
-                            .generate_ed25519_addresses(
-                                account_details.coin_type,
-                                account_details.index,
-                                address_index..address_index + 1,
-                                Some(changed_options),
-                            )
-                            .await?;
-                        self.emit(
-                            account_details.index,
-                            WalletEvent::LedgerAddressGeneration(AddressData {
-                                address: address[0].to_bech32(bech32_hrp),
-                            }),
-                        )
-                        .await;
-                    }
-                    // Generate with prompt so the user can verify
-                    let address = self
-                        .wallet
-                        .secret_manager
-                        .read()
-                        .await
+                    // Generate without prompt to be able to display it
+                    let address = ledger
                         .generate_ed25519_addresses(
                             account_details.coin_type,
                             account_details.index,
                             address_index..address_index + 1,
-                            Some(options),
+                            Some(changed_options),
                         )
-                        .await?;
-                    addresses.push(address[0]);
+                        .await?[0];
+                    #[cfg(feature = "events")]
+                    self.emit(
+                        account_details.index,
+                        WalletEvent::LedgerAddressGeneration(AddressData {
+                            address: address.to_bech32(bech32_hrp),
+                        }),
+                    )
+                    .await;
+
+                    // Display it on the ledger so the user can verify it
+                    if options.ledger_nano_prompt {
+                        let chain = Bip44::new(account_details.coin_type)
+                            .with_account(account_details.index)
+                            .with_change(options.internal as _)
+                            .with_address_index(address_index);
+                        if !ledger.verify_address(chain).await? {
+                            return Err(crate::wallet::Error::AddressNotConfirmed(Bech32Address::new(
+                                bech32_hrp, address,
+                            )));
+                        }
+                    }
+                    addresses.push(address);
                 }
                 addresses
             } else {
@@ -182,6 +175,44 @@ where
         Ok(generate_addresses)
     }
 
+    /// Displays an address of the account on the ledger nano, so the user can verify it. Returns whether the user
+    /// approved the address.
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    pub async fn verify_address_on_ledger(&self, address: &Bech32Address) -> crate::wallet::Result<bool> {
+        use crate::wallet::account::SecretManager;
+
+        let chain = {
+            let account_details = self.details().await;
+            let account_address = account_details
+                .public_addresses
+                .iter()
+                .chain(&account_details.internal_addresses)
+                .find(|account_address| account_address.address == *address)
+                .ok_or(crate::wallet::Error::AddressNotFoundInAccount(*address))?;
+            Bip44::new(account_details.coin_type)
+                .with_account(account_details.index)
+                .with_change(account_address.internal as _)
+                .with_address_index(account_address.key_index)
+        };
+
+        let secret_manager = self.wallet.secret_manager.read().await;
+        let ledger = secret_manager
+            .downcast::<LedgerSecretManager>()
+            .or_else(|| {
+                secret_manager.downcast::<SecretManager>().and_then(|s| {
+                    if let SecretManager::LedgerNano(n) = s {
+                        Some(n)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .ok_or(crate::client::Error::SecretManagerMismatch)?;
+
+        Ok(ledger.verify_address(chain).await?)
+    }
+
     /// Generate an internal address and store in the account, internal addresses are used for remainder outputs
     pub(crate) async fn generate_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
         let result = self
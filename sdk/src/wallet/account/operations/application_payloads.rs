@@ -0,0 +1,85 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{secret::SecretManage, tag_protocol::ApplicationPayload},
+    types::block::{
+        output::OutputId,
+        payload::{transaction::TransactionId, Payload},
+    },
+    wallet::account::Account,
+};
+
+/// Where a received application payload was found.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "camelCase")]
+pub enum ApplicationPayloadSource {
+    /// The tagged data of an incoming transaction.
+    Transaction(TransactionId),
+    /// The tag and metadata features of an unspent output.
+    Output(OutputId),
+}
+
+/// An application payload received by the account, decoded by a registered
+/// [`TagProtocol`](crate::client::tag_protocol::TagProtocol).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivedApplicationPayload {
+    /// Where the payload was found.
+    pub source: ApplicationPayloadSource,
+    /// The decoded payload.
+    pub payload: ApplicationPayload,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the payloads of the incoming transactions and unspent outputs that are decoded by the registered tag
+    /// protocols. Payloads that fail to decode are skipped.
+    pub async fn application_payloads(&self) -> Vec<ReceivedApplicationPayload> {
+        let (incoming_transactions, unspent_outputs) = {
+            let account_details = self.details().await;
+            (
+                account_details
+                    .incoming_transactions
+                    .values()
+                    .map(|transaction| (transaction.transaction_id, transaction.payload.clone()))
+                    .collect::<Vec<_>>(),
+                account_details
+                    .unspent_outputs
+                    .values()
+                    .map(|output_data| (output_data.output_id, output_data.output.clone()))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let client = self.client();
+        let mut payloads = Vec::new();
+
+        for (transaction_id, transaction) in incoming_transactions {
+            match client.decode_payload(&Payload::from(transaction)).await {
+                Ok(Some(payload)) => payloads.push(ReceivedApplicationPayload {
+                    source: ApplicationPayloadSource::Transaction(transaction_id),
+                    payload,
+                }),
+                Ok(None) => {}
+                Err(error) => log::debug!("[application_payloads] skipping transaction {transaction_id}: {error}"),
+            }
+        }
+
+        for (output_id, output) in unspent_outputs {
+            match client.decode_output(&output).await {
+                Ok(Some(payload)) => payloads.push(ReceivedApplicationPayload {
+                    source: ApplicationPayloadSource::Output(output_id),
+                    payload,
+                }),
+                Ok(None) => {}
+                Err(error) => log::debug!("[application_payloads] skipping output {output_id}: {error}"),
+            }
+        }
+
+        payloads
+    }
+}
@@ -1,20 +1,28 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{HashMap, HashSet};
+
 use primitive_types::U256;
 
 use crate::{
     client::secret::SecretManage,
     types::block::{
-        address::Bech32Address,
-        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+        address::{Address, Bech32Address},
+        output::{
+            unlock_condition::UnlockCondition, FoundryId, FoundryOutput, NativeTokensBuilder, Output, OutputId, Rent,
+            TokenId,
+        },
+        payload::transaction::TransactionEssence,
         ConvertTo,
     },
     wallet::{
         account::{
             operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-            types::{AddressWithUnspentOutputs, Balance, NativeTokensBalance},
-            Account, AccountDetails, OutputsToClaim,
+            types::{
+                AddressBalance, AddressWithUnspentOutputs, Balance, InclusionState, NativeTokensBalance, SpamIndicator,
+            },
+            Account, AccountDetails, OutputsToClaim, TokenFilter,
         },
         Error, Result,
     },
@@ -29,9 +37,18 @@ where
         log::debug!("[BALANCE] balance");
 
         let account_details = self.details().await;
+        let addresses = account_details
+            .addresses()
+            .into_iter()
+            .map(|address| address.address.inner)
+            .collect();
 
-        self.balance_inner(account_details.addresses_with_unspent_outputs.iter(), &account_details)
-            .await
+        self.balance_inner(
+            account_details.addresses_with_unspent_outputs.iter(),
+            &addresses,
+            &account_details,
+        )
+        .await
     }
 
     /// Get the balance of the given addresses.
@@ -51,14 +68,48 @@ where
                     .ok_or(Error::AddressNotFoundInAccount(address))
             })
             .collect::<Result<Vec<&_>>>()?;
+        let addresses = addresses_with_unspent_outputs
+            .iter()
+            .map(|address| address.address.inner)
+            .collect();
 
-        self.balance_inner(addresses_with_unspent_outputs.into_iter(), &account_details)
+        self.balance_inner(addresses_with_unspent_outputs.into_iter(), &addresses, &account_details)
             .await
     }
 
+    /// Get the base coin and native token balances of each address of the account, also of the ones without outputs.
+    pub async fn balance_per_address(&self) -> Result<HashMap<Bech32Address, AddressBalance>> {
+        log::debug!("[BALANCE] balance_per_address");
+
+        let account_details = self.details().await;
+        let mut balances = HashMap::new();
+
+        for account_address in account_details.addresses() {
+            let address = account_address.address;
+            let addresses_with_unspent_outputs = account_details
+                .addresses_with_unspent_outputs
+                .iter()
+                .filter(|a| a.address == address);
+
+            let balance = self
+                .balance_inner(
+                    addresses_with_unspent_outputs,
+                    &HashSet::from([address.inner]),
+                    &account_details,
+                )
+                .await?;
+            balances.insert(address, AddressBalance::from(balance));
+        }
+
+        Ok(balances)
+    }
+
+    /// Computes the balance of the unspent outputs of `addresses_with_unspent_outputs`, with the pending transactions
+    /// provisionally applied, of which only the outputs to `addresses` are added.
     async fn balance_inner(
         &self,
         addresses_with_unspent_outputs: impl Iterator<Item = &AddressWithUnspentOutputs> + Send,
+        addresses: &HashSet<Address>,
         account_details: &AccountDetails,
     ) -> Result<Balance> {
         let network_id = self.client().get_network_id().await?;
@@ -67,6 +118,8 @@ where
         let mut balance = Balance::default();
         let mut total_rent_amount = 0;
         let mut total_native_tokens = NativeTokensBuilder::default();
+        // Native tokens that were received together with more than the minimum storage deposit
+        let mut native_tokens_with_value = HashSet::new();
 
         #[cfg(feature = "participation")]
         let voting_output = account_details.get_voting_output()?;
@@ -75,6 +128,22 @@ where
 
         let claimable_outputs = account_details.claimable_outputs(OutputsToClaim::All, local_time)?;
 
+        let pending_transactions = account_details
+            .pending_transactions
+            .iter()
+            .filter_map(|transaction_id| account_details.transactions.get(transaction_id))
+            .filter(|transaction| {
+                transaction.network_id == network_id && transaction.inclusion_state == InclusionState::Pending
+            })
+            .collect::<Vec<_>>();
+        let pending_inputs = pending_transactions
+            .iter()
+            .flat_map(|transaction| {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                essence.inputs().iter().map(|input| *input.as_utxo().output_id())
+            })
+            .collect::<HashSet<_>>();
+
         for address_with_unspent_outputs in addresses_with_unspent_outputs {
             #[cfg(feature = "participation")]
             {
@@ -93,8 +162,22 @@ where
                     }
 
                     let output = &data.output;
+
+                    // Provisionally spent, even though the transaction isn't confirmed yet
+                    if pending_inputs.contains(output_id) {
+                        balance.provisional.spent_outputs.push(*output_id);
+                        balance.provisional.spent += output.amount();
+                        continue;
+                    }
+
                     let rent = output.rent_cost(&rent_structure);
 
+                    if output.amount() > rent {
+                        if let Some(native_tokens) = output.native_tokens() {
+                            native_tokens_with_value.extend(native_tokens.iter().map(|t| *t.token_id()));
+                        }
+                    }
+
                     // Add alias and foundry outputs here because they can't have a
                     // [`StorageDepositReturnUnlockCondition`] or time related unlock conditions
                     match output {
@@ -270,15 +353,75 @@ where
             }
         }
 
+        // Native tokens received with pending transactions, which aren't available until they're confirmed
+        let mut provisional_native_tokens = NativeTokensBuilder::default();
+
+        for transaction in pending_transactions {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            // Foundries last, so the aliases controlling them are known
+            let outputs = essence
+                .outputs()
+                .iter()
+                .enumerate()
+                .filter(|(_, output)| !output.is_foundry())
+                .chain(
+                    essence
+                        .outputs()
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, output)| output.is_foundry()),
+                );
+
+            for (index, output) in outputs {
+                let output_id = OutputId::new(transaction.transaction_id, index as u16)?;
+                let is_received = match output {
+                    Output::Alias(output) => addresses.contains(output.state_controller_address()),
+                    Output::Foundry(output) => balance.aliases.contains(output.alias_address().alias_id()),
+                    _ => matches!(
+                        output
+                            .unlock_conditions()
+                            .expect("output needs to have unlock conditions")
+                            .as_ref(),
+                        [UnlockCondition::Address(unlock_condition)] if addresses.contains(unlock_condition.address())
+                    ),
+                };
+                if !is_received {
+                    continue;
+                }
+
+                balance.base_coin.total += output.amount();
+                balance.provisional.received += output.amount();
+                if let Some(native_tokens) = output.native_tokens() {
+                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                    provisional_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+
+                match output {
+                    Output::Alias(output) => balance.aliases.push(output.alias_id_non_null(&output_id)),
+                    Output::Foundry(output) => balance.foundries.push(output.id()),
+                    Output::Nft(output) => balance.nfts.push(output.nft_id_non_null(&output_id)),
+                    _ => {}
+                }
+            }
+
+            balance.provisional.transaction_ids.push(transaction.transaction_id);
+        }
+
+        let token_filter = self.token_filter().await;
+
         self.finish(
             balance,
             account_details,
             network_id,
             total_rent_amount,
             total_native_tokens,
+            provisional_native_tokens,
+            &native_tokens_with_value,
+            &token_filter,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn finish(
         &self,
         mut balance: Balance,
@@ -286,16 +429,22 @@ where
         network_id: u64,
         total_rent_amount: u64,
         total_native_tokens: NativeTokensBuilder,
+        mut locked_native_tokens: NativeTokensBuilder,
+        native_tokens_with_value: &HashSet<TokenId>,
+        token_filter: &TokenFilter,
     ) -> Result<Balance> {
         // for `available` get locked_outputs, sum outputs amount and subtract from total_amount
         log::debug!("[BALANCE] locked outputs: {:#?}", account_details.locked_outputs);
 
-        let mut locked_amount = 0;
-        let mut locked_native_tokens = NativeTokensBuilder::default();
+        // The received outputs of pending transactions can't be spent before they're confirmed
+        let mut locked_amount = balance.provisional.received;
 
         for locked_output in &account_details.locked_outputs {
-            // Skip potentially_locked_outputs, as their amounts aren't added to the balance
-            if balance.potentially_locked_outputs.contains_key(locked_output) {
+            // Skip potentially_locked_outputs and provisionally spent outputs, as their amounts aren't added to the
+            // balance
+            if balance.potentially_locked_outputs.contains_key(locked_output)
+                || balance.provisional.spent_outputs.contains(locked_output)
+            {
                 continue;
             }
             if let Some(output_data) = account_details.unspent_outputs.get(locked_output) {
@@ -328,20 +477,35 @@ where
                 }
             });
 
-            let metadata = account_details
+            let foundry = account_details
                 .native_token_foundries
-                .get(&FoundryId::from(*native_token.token_id()))
+                .get(&FoundryId::from(*native_token.token_id()));
+            let metadata = foundry
                 .and_then(|foundry| foundry.immutable_features().metadata())
                 .cloned();
 
+            let spam_indicators = if token_filter.is_listed(native_token.token_id()) {
+                Vec::new()
+            } else {
+                spam_indicators(foundry, native_tokens_with_value.contains(native_token.token_id()))
+            };
+
             balance.native_tokens.push(NativeTokensBalance {
                 token_id: *native_token.token_id(),
                 total: native_token.amount(),
                 available: native_token.amount() - *locked_native_token_amount.unwrap_or(&U256::from(0u8)),
                 metadata,
+                spam_indicators,
             })
         }
 
+        balance.filtered_native_tokens = balance
+            .native_tokens
+            .iter()
+            .filter(|native_token| token_filter.is_shown(native_token.token_id()))
+            .cloned()
+            .collect();
+
         #[cfg(not(feature = "participation"))]
         {
             balance.base_coin.available = balance.base_coin.total.saturating_sub(locked_amount);
@@ -358,3 +522,25 @@ where
         Ok(balance)
     }
 }
+
+/// Returns the heuristic indicators that a native token is spam.
+fn spam_indicators(foundry: Option<&FoundryOutput>, received_with_value: bool) -> Vec<SpamIndicator> {
+    let mut spam_indicators = Vec::new();
+
+    // The metadata can only be checked if the foundry was synced
+    if let Some(foundry) = foundry {
+        let is_irc_30 = foundry
+            .immutable_features()
+            .metadata()
+            .and_then(|metadata| serde_json::from_slice::<serde_json::Value>(metadata.data()).ok())
+            .is_some_and(|metadata| metadata["standard"] == "IRC30");
+        if !is_irc_30 {
+            spam_indicators.push(SpamIndicator::MissingIrc30Metadata);
+        }
+    }
+    if !received_with_value {
+        spam_indicators.push(SpamIndicator::ZeroValue);
+    }
+
+    spam_indicators
+}
@@ -3,10 +3,14 @@
 
 /// The module for the address generation
 pub(crate) mod address_generation;
+/// The module to decode application payloads with the registered tag protocols
+pub(crate) mod application_payloads;
 /// The module to get the accounts balance
 pub(crate) mod balance;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module to compute the supply of native tokens from their foundries
+pub(crate) mod native_token_supply;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](crate::types::block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition)
@@ -22,5 +26,11 @@ pub(crate) mod participation;
 pub(crate) mod retry;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
+/// The module for the native token allow and deny lists
+pub(crate) mod token_filter;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module to export the transaction history
+pub(crate) mod transaction_export;
+/// The module for local transaction notes and tags
+pub(crate) mod transaction_notes;
@@ -0,0 +1,115 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::output::{FoundryId, FoundryOutput, Output, OutputId, TokenId, TokenScheme},
+    wallet::account::Account,
+};
+
+/// The supply of a native token, computed from the token scheme of its foundry.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokenSupply {
+    /// The token id.
+    pub token_id: TokenId,
+    /// The output id of the foundry output the supply was computed from.
+    pub foundry_output_id: OutputId,
+    /// The amount of tokens that were minted.
+    pub minted_tokens: U256,
+    /// The amount of tokens that were melted.
+    pub melted_tokens: U256,
+    /// The max amount of tokens that can be in circulation.
+    pub maximum_supply: U256,
+    /// The amount of tokens in circulation, the minted tokens minus the melted tokens.
+    pub circulating_supply: U256,
+}
+
+impl NativeTokenSupply {
+    fn new(foundry_output_id: OutputId, foundry_output: &FoundryOutput) -> Self {
+        let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
+        Self {
+            token_id: foundry_output.token_id(),
+            foundry_output_id,
+            minted_tokens: token_scheme.minted_tokens(),
+            melted_tokens: token_scheme.melted_tokens(),
+            maximum_supply: token_scheme.maximum_supply(),
+            circulating_supply: token_scheme.circulating_supply(),
+        }
+    }
+
+    /// Returns the amount of tokens that can still be minted.
+    pub fn mintable_tokens(&self) -> U256 {
+        self.maximum_supply - self.circulating_supply
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the supply of a native token whose foundry is controlled by the account, from the last synced state of
+    /// the foundry output.
+    pub async fn native_token_supply(&self, token_id: TokenId) -> crate::wallet::Result<NativeTokenSupply> {
+        let foundry_id = FoundryId::from(token_id);
+        let output_data = self
+            .unspent_foundry_output(&foundry_id)
+            .await?
+            .ok_or(crate::wallet::Error::FoundryNotControlled(foundry_id))?;
+
+        match &output_data.output {
+            Output::Foundry(foundry_output) => Ok(NativeTokenSupply::new(output_data.output_id, foundry_output)),
+            _ => Err(crate::wallet::Error::FoundryNotControlled(foundry_id)),
+        }
+    }
+
+    /// Returns the supplies of the native tokens of all foundries controlled by the account.
+    pub async fn native_token_supplies(&self) -> Vec<NativeTokenSupply> {
+        let mut supplies = self
+            .details()
+            .await
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Foundry(foundry_output) => Some(NativeTokenSupply::new(output_data.output_id, foundry_output)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        supplies.sort_by_key(|supply| supply.token_id);
+        supplies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::AliasAddress,
+        output::{unlock_condition::ImmutableAliasAddressUnlockCondition, FoundryOutputBuilder, SimpleTokenScheme},
+        rand::output::{rand_alias_id, rand_output_id},
+    };
+
+    #[test]
+    fn supply_from_token_scheme() {
+        let token_scheme = SimpleTokenScheme::new(100u32, 30u32, 1000u32).unwrap();
+        let foundry_output = FoundryOutputBuilder::new_with_amount(1_000_000, 1, TokenScheme::Simple(token_scheme))
+            .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::new(
+                rand_alias_id(),
+            )))
+            .finish()
+            .unwrap();
+        let output_id = rand_output_id();
+
+        let supply = NativeTokenSupply::new(output_id, &foundry_output);
+        assert_eq!(supply.token_id, foundry_output.token_id());
+        assert_eq!(supply.foundry_output_id, output_id);
+        assert_eq!(supply.minted_tokens, U256::from(100u32));
+        assert_eq!(supply.melted_tokens, U256::from(30u32));
+        assert_eq!(supply.maximum_supply, U256::from(1000u32));
+        assert_eq!(supply.circulating_supply, U256::from(70u32));
+        assert_eq!(supply.mintable_tokens(), U256::from(930u32));
+    }
+}
@@ -1,10 +1,15 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
 
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{OutputsClaimedEvent, WalletEvent};
 use crate::{
     client::{api::PreparedTransactionData, secret::SecretManage},
     types::block::{
@@ -141,6 +146,57 @@ where
         account_details.claimable_outputs(outputs_to_claim, local_time)
     }
 
+    /// Get claimable outputs with an
+    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) that
+    /// expires within the `safety_margin`, after which they would return to the sender. Expirations are unix
+    /// timestamps, so the margin has to account for the time between milestones.
+    pub async fn expiring_outputs(&self, safety_margin: Duration) -> crate::wallet::Result<Vec<OutputId>> {
+        let local_time = self.client().get_time_checked().await?;
+        let expiration_limit = local_time.saturating_add(safety_margin.as_secs().try_into().unwrap_or(u32::MAX));
+        let account_details = self.details().await;
+
+        Ok(account_details
+            .claimable_outputs(OutputsToClaim::All, local_time)?
+            .into_iter()
+            .filter(|output_id| {
+                account_details
+                    .unspent_outputs
+                    .get(output_id)
+                    .and_then(|output_data| output_data.output.unlock_conditions())
+                    .is_some_and(|unlock_conditions| {
+                        // Expired outputs can only be claimable because they returned to the account
+                        !unlock_conditions.is_expired(local_time)
+                            && unlock_conditions
+                                .expiration()
+                                .is_some_and(|expiration| expiration.timestamp() <= expiration_limit)
+                    })
+            })
+            .collect())
+    }
+
+    /// Claims the outputs from [`Account::expiring_outputs()`], if there are any.
+    pub async fn claim_expiring_outputs(&self, safety_margin: Duration) -> crate::wallet::Result<Option<Transaction>> {
+        log::debug!("[OUTPUT_CLAIMING] claim_expiring_outputs");
+        let output_ids = self.expiring_outputs(safety_margin).await?;
+        if output_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let transaction = self.claim_outputs(output_ids.clone()).await?;
+
+        #[cfg(feature = "events")]
+        self.emit(
+            self.details().await.index,
+            WalletEvent::OutputsClaimed(OutputsClaimedEvent {
+                transaction_id: transaction.transaction_id,
+                output_ids,
+            }),
+        )
+        .await;
+
+        Ok(Some(transaction))
+    }
+
     /// Get basic outputs that have only one unlock condition which is [AddressUnlockCondition], so they can be used as
     /// additional inputs
     pub(crate) async fn get_basic_outputs_for_additional_inputs(&self) -> crate::wallet::Result<Vec<OutputData>> {
@@ -25,6 +25,8 @@ const INPUT_SIZE: usize = 43;
 #[cfg(feature = "ledger_nano")]
 const MIN_OUTPUT_SIZE_IN_ESSENCE: usize = 46;
 
+const DEFAULT_INCLUDE_NATIVE_TOKENS: bool = true;
+
 #[cfg(feature = "ledger_nano")]
 use crate::wallet::account::constants::DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD;
 use crate::wallet::{
@@ -37,15 +39,38 @@ use crate::wallet::{
     Result,
 };
 
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConsolidationParams {
     /// Ignores the output_threshold if set to `true`.
     force: bool,
     /// Consolidates if the output number is >= the output_threshold.
     output_threshold: Option<usize>,
+    /// The max number of inputs of the consolidation transaction, capped by the protocol and, if used, the ledger
+    /// nano buffer size.
+    #[serde(default)]
+    max_inputs_per_tx: Option<u16>,
     /// Address to which the consolidated output should be sent.
     target_address: Option<Bech32Address>,
+    /// Also consolidates outputs with native tokens if set to `true`, the default.
+    #[serde(default = "default_include_native_tokens")]
+    include_native_tokens: bool,
+}
+
+fn default_include_native_tokens() -> bool {
+    DEFAULT_INCLUDE_NATIVE_TOKENS
+}
+
+impl Default for ConsolidationParams {
+    fn default() -> Self {
+        Self {
+            force: false,
+            output_threshold: None,
+            max_inputs_per_tx: None,
+            target_address: None,
+            include_native_tokens: default_include_native_tokens(),
+        }
+    }
 }
 
 impl ConsolidationParams {
@@ -63,10 +88,20 @@ impl ConsolidationParams {
         self
     }
 
+    pub fn with_max_inputs_per_tx(mut self, max_inputs_per_tx: impl Into<Option<u16>>) -> Self {
+        self.max_inputs_per_tx = max_inputs_per_tx.into();
+        self
+    }
+
     pub fn with_target_address(mut self, target_address: impl Into<Option<Bech32Address>>) -> Self {
         self.target_address = target_address.into();
         self
     }
+
+    pub fn with_include_native_tokens(mut self, include_native_tokens: bool) -> Self {
+        self.include_native_tokens = include_native_tokens;
+        self
+    }
 }
 
 impl<S: 'static + SecretManage> Account<S>
@@ -105,7 +140,8 @@ where
     /// Consolidates basic outputs with only an [AddressUnlockCondition] from an account by sending them to a provided
     /// address or to an own address again if the output amount is >= the output_threshold. When `force`
     /// is set to `true`, the threshold is ignored. Only consolidates the amount of outputs that fit into a single
-    /// transaction.
+    /// transaction, or `max_inputs_per_tx` if it's lower. Outputs with native tokens are skipped if
+    /// `include_native_tokens` is `false`.
     pub async fn consolidate_outputs(&self, params: ConsolidationParams) -> Result<Transaction> {
         let prepared_transaction = self.prepare_consolidate_outputs(params).await?;
         let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction, None).await?;
@@ -139,6 +175,9 @@ where
                     continue;
                 }
             }
+            if !params.include_native_tokens && output_data.output.native_tokens().is_some_and(|nt| !nt.is_empty()) {
+                continue;
+            }
             let is_locked_output = account_details.locked_outputs.contains(output_id);
             let should_consolidate_output =
                 self.should_consolidate_output(output_data, current_time, account_addresses)?;
@@ -228,6 +267,9 @@ where
         };
         #[cfg(not(feature = "ledger_nano"))]
         let max_inputs = INPUT_COUNT_MAX;
+        let max_inputs = params
+            .max_inputs_per_tx
+            .map_or(max_inputs, |max_inputs_per_tx| max_inputs_per_tx.min(max_inputs).max(1));
 
         let mut total_amount = 0;
         let mut custom_inputs = Vec::with_capacity(max_inputs.into());
@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionRetryEvent, TransactionRetryKind, WalletEvent};
 use crate::{
     client::{secret::SecretManage, Error as ClientError},
     types::{
@@ -136,4 +138,76 @@ where
             Err(Error::TransactionNotFound(*transaction_id))
         }
     }
+
+    /// Checks the pending transactions of the account once: updates their inclusion state like syncing does and
+    /// promotes or reattaches the latest block of the ones that aren't included yet, if the node suggests it. Emits
+    /// [`WalletEvent::TransactionInclusion`](crate::wallet::events::types::WalletEvent::TransactionInclusion) and
+    /// [`WalletEvent::TransactionRetry`](crate::wallet::events::types::WalletEvent::TransactionRetry) events.
+    pub async fn retry_pending_transactions(&self) -> crate::wallet::Result<()> {
+        log::debug!("[retry_pending_transactions]");
+
+        self.sync_pending_transactions().await?;
+
+        let network_id = self.client().get_network_id().await?;
+        let mut reattached_transactions = Vec::new();
+
+        for mut transaction in self.pending_transactions().await {
+            // Transactions without block were already reattached when syncing them
+            let Some(block_id) = transaction.block_id else {
+                continue;
+            };
+            if transaction.network_id != network_id || transaction.inclusion_state != InclusionState::Pending {
+                continue;
+            }
+
+            let block_metadata = match self.client().get_block_metadata(&block_id).await {
+                Ok(block_metadata) => block_metadata,
+                // Reattached the next time the pending transactions are synced
+                Err(ClientError::Node(crate::client::node_api::error::Error::NotFound(_))) => continue,
+                Err(e) => return Err(e.into()),
+            };
+            // The inclusion state is updated the next time the pending transactions are synced
+            if block_metadata.ledger_inclusion_state.is_some() {
+                continue;
+            }
+
+            let (retry_block_id, promoted) = if block_metadata.should_promote.unwrap_or(false) {
+                let (promote_block_id, _) = self.client().promote_unchecked(&block_id).await?;
+                (promote_block_id, true)
+            } else if block_metadata.should_reattach.unwrap_or(false) {
+                let reattached_block_id = self.submit_transaction_payload(transaction.payload.clone()).await?;
+                transaction.block_id = Some(reattached_block_id);
+                reattached_transactions.push(transaction.clone());
+                (reattached_block_id, false)
+            } else {
+                continue;
+            };
+            log::debug!(
+                "[retry_pending_transactions] retried transaction {} in block {retry_block_id}, promoted: {promoted}",
+                transaction.transaction_id
+            );
+
+            #[cfg(feature = "events")]
+            self.emit(
+                self.details().await.index,
+                WalletEvent::TransactionRetry(TransactionRetryEvent {
+                    transaction_id: transaction.transaction_id,
+                    block_id: retry_block_id,
+                    kind: if promoted {
+                        TransactionRetryKind::Promoted
+                    } else {
+                        TransactionRetryKind::Reattached
+                    },
+                }),
+            )
+            .await;
+        }
+
+        if !reattached_transactions.is_empty() {
+            self.update_account_with_transactions(reattached_transactions, Vec::new(), Vec::new())
+                .await?;
+        }
+
+        Ok(())
+    }
 }
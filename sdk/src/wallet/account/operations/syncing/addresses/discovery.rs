@@ -0,0 +1,138 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::Range;
+
+use crate::{
+    client::{
+        node_api::indexer::QueryParameter,
+        secret::{GenerateAddressOptions, SecretManage},
+    },
+    types::block::address::{Address, Bech32Address, Hrp},
+    wallet::account::{operations::syncing::SyncOptions, Account, AddressDiscoveryMode},
+};
+
+// The address indexes that are checked in a round, starting with the first index after the last used address.
+fn address_indexes_to_check(mode: AddressDiscoveryMode, start_index: u32, gap_limit: u32) -> Vec<Range<u32>> {
+    match mode {
+        AddressDiscoveryMode::Linear => core::iter::once(start_index..start_index.saturating_add(gap_limit)).collect(),
+        AddressDiscoveryMode::Exponential => core::iter::successors(Some(1u32), |offset| offset.checked_mul(2))
+            .take_while(|offset| *offset <= gap_limit)
+            .map(|offset| {
+                let index = start_index.saturating_add(offset - 1);
+                index..index + 1
+            })
+            .collect(),
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Generates the addresses up to the last used one that was found with the `address_gap_limit` and
+    /// `address_discovery` of the options, so they get synced.
+    pub(crate) async fn discover_addresses(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+        if options.address_gap_limit == 0 || !options.addresses.is_empty() {
+            return Ok(());
+        }
+
+        let (account_index, coin_type, public_addresses, internal_addresses) = {
+            let account_details = self.details().await;
+            // Watch-only accounts can't generate addresses
+            if account_details.watch_only {
+                return Ok(());
+            }
+            (
+                account_details.index,
+                account_details.coin_type,
+                account_details.public_addresses.len() as u32,
+                account_details.internal_addresses.len() as u32,
+            )
+        };
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+
+        for (internal, known_addresses) in [(false, public_addresses), (true, internal_addresses)] {
+            let mut start_index = known_addresses;
+            let mut last_used_index = None;
+
+            loop {
+                let mut used_index = None;
+                for range in address_indexes_to_check(options.address_discovery, start_index, options.address_gap_limit)
+                {
+                    let addresses = self
+                        .wallet
+                        .secret_manager
+                        .read()
+                        .await
+                        .generate_ed25519_addresses(
+                            coin_type,
+                            account_index,
+                            range.clone(),
+                            GenerateAddressOptions {
+                                internal,
+                                ..Default::default()
+                            },
+                        )
+                        .await?;
+
+                    for (index, address) in range.zip(addresses) {
+                        if self.address_has_outputs(Address::Ed25519(address), bech32_hrp).await? {
+                            used_index = Some(index);
+                        }
+                    }
+                }
+
+                match used_index {
+                    Some(index) => {
+                        log::debug!("[SYNC] discovered used address with index {index}, internal: {internal}");
+                        last_used_index = Some(index);
+                        start_index = index + 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if let Some(last_used_index) = last_used_index {
+                self.generate_ed25519_addresses(
+                    last_used_index + 1 - known_addresses,
+                    GenerateAddressOptions {
+                        internal,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn address_has_outputs(&self, address: Address, bech32_hrp: Hrp) -> crate::wallet::Result<bool> {
+        Ok(!self
+            .client()
+            .output_ids([QueryParameter::UnlockableByAddress(Bech32Address::new(
+                bech32_hrp, address,
+            ))])
+            .await?
+            .items
+            .is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discovery_address_indexes() {
+        let linear = address_indexes_to_check(AddressDiscoveryMode::Linear, 3, 5);
+        assert_eq!(linear.len(), 1);
+        assert_eq!(linear[0], 3..8);
+        assert_eq!(
+            address_indexes_to_check(AddressDiscoveryMode::Exponential, 3, 20),
+            vec![3..4, 4..5, 6..7, 10..11, 18..19]
+        );
+        assert!(address_indexes_to_check(AddressDiscoveryMode::Exponential, 3, 0).is_empty());
+    }
+}
@@ -18,8 +18,10 @@ use crate::{
         output::OutputId,
     },
     wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+        constants::PARALLEL_REQUESTS_AMOUNT,
+        operations::syncing::{SyncOptions, SyncProgress},
+        types::address::AddressWithUnspentOutputs,
+        Account,
     },
 };
 
@@ -196,15 +198,39 @@ where
         &self,
         options: &SyncOptions,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        scanned_addresses: Vec<AddressWithUnspentOutputs>,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputId>)> {
         log::debug!("[SYNC] start get_output_ids_for_addresses");
         let address_output_ids_start_time = Instant::now();
 
+        let total_addresses = addresses_with_unspent_outputs.len();
+        let mut synced_addresses = 0;
+        let mut output_ids_count = 0;
         let mut addresses_with_outputs = Vec::new();
         // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
         let mut spent_or_not_anymore_synced_outputs = Vec::new();
+
+        // Addresses that were already scanned by an unfinished sync don't need to be requested again
+        let mut addresses_to_scan = Vec::new();
+        for address in addresses_with_unspent_outputs {
+            match scanned_addresses.iter().find(|a| a.address == address.address) {
+                Some(scanned_address) => {
+                    let output_ids = scanned_address.output_ids.clone();
+                    synced_addresses += 1;
+                    output_ids_count += output_ids.len();
+                    add_output_ids(
+                        address,
+                        output_ids,
+                        &mut addresses_with_outputs,
+                        &mut spent_or_not_anymore_synced_outputs,
+                    );
+                }
+                None => addresses_to_scan.push(address),
+            }
+        }
+
         // We split the addresses into chunks so we don't get timeouts if we have thousands
-        for addresses_chunk in &mut addresses_with_unspent_outputs
+        for addresses_chunk in &mut addresses_to_scan
             .chunks(PARALLEL_REQUESTS_AMOUNT)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
@@ -239,24 +265,32 @@ where
                 results = futures::future::try_join_all(tasks).await?;
             }
 
-            for res in results {
-                let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
-                // only return addresses with outputs
-                if !output_ids.is_empty() {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    for output_id in address.output_ids {
-                        if !output_ids.contains(&output_id) {
-                            spent_or_not_anymore_synced_outputs.push(output_id);
-                        }
-                    }
-                    address.output_ids = output_ids;
-                    addresses_with_outputs.push(address);
-                } else {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    spent_or_not_anymore_synced_outputs.extend(address.output_ids);
-                }
+            let results = results
+                .into_iter()
+                .collect::<crate::wallet::Result<Vec<(AddressWithUnspentOutputs, Vec<OutputId>)>>>()?;
+
+            // Store the progress, so the scan can be resumed if syncing gets interrupted
+            self.update_sync_checkpoint(results.iter().map(|(address, output_ids)| AddressWithUnspentOutputs {
+                output_ids: output_ids.clone(),
+                ..address.clone()
+            }))
+            .await?;
+
+            synced_addresses += results.len();
+            output_ids_count += results.iter().map(|(_, output_ids)| output_ids.len()).sum::<usize>();
+            options.progress.report(|| SyncProgress::OutputIdsFetched {
+                synced_addresses,
+                total_addresses,
+                output_ids: output_ids_count,
+            });
+
+            for (address, output_ids) in results {
+                add_output_ids(
+                    address,
+                    output_ids,
+                    &mut addresses_with_outputs,
+                    &mut spent_or_not_anymore_synced_outputs,
+                );
             }
         }
 
@@ -271,3 +305,28 @@ where
         Ok((addresses_with_outputs, spent_or_not_anymore_synced_outputs))
     }
 }
+
+// Only adds addresses with outputs and collects the output ids that are not unspent anymore
+fn add_output_ids(
+    mut address: AddressWithUnspentOutputs,
+    output_ids: Vec<OutputId>,
+    addresses_with_outputs: &mut Vec<AddressWithUnspentOutputs>,
+    spent_or_not_anymore_synced_outputs: &mut Vec<OutputId>,
+) {
+    // only return addresses with outputs
+    if !output_ids.is_empty() {
+        // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+        // synced anymore because of other sync options
+        for output_id in address.output_ids {
+            if !output_ids.contains(&output_id) {
+                spent_or_not_anymore_synced_outputs.push(output_id);
+            }
+        }
+        address.output_ids = output_ids;
+        addresses_with_outputs.push(address);
+    } else {
+        // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+        // synced anymore because of other sync options
+        spent_or_not_anymore_synced_outputs.extend(address.output_ids);
+    }
+}
@@ -7,7 +7,10 @@ use crate::{
     client::secret::SecretManage,
     wallet::{
         account::{
-            constants::PARALLEL_REQUESTS_AMOUNT, types::address::AddressWithUnspentOutputs, Account, OutputData,
+            constants::PARALLEL_REQUESTS_AMOUNT,
+            operations::syncing::{SyncOptions, SyncProgress},
+            types::address::AddressWithUnspentOutputs,
+            Account, OutputData,
         },
         task,
     },
@@ -21,10 +24,12 @@ where
     pub(crate) async fn get_outputs_from_address_output_ids(
         &self,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+        options: &SyncOptions,
     ) -> crate::wallet::Result<(Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         log::debug!("[SYNC] start get_outputs_from_address_output_ids");
         let address_outputs_start_time = Instant::now();
 
+        let total_addresses = addresses_with_unspent_outputs.len();
         let mut addresses_with_outputs = Vec::new();
         let mut outputs_data = Vec::new();
 
@@ -54,6 +59,11 @@ where
                 addresses_with_outputs.push(address);
                 outputs_data.extend(outputs);
             }
+            options.progress.report(|| SyncProgress::OutputsFetched {
+                synced_addresses: addresses_with_outputs.len(),
+                total_addresses,
+                outputs: outputs_data.len(),
+            });
         }
         log::debug!(
             "[SYNC] finished get_outputs_from_address_output_ids in {:.2?}",
@@ -0,0 +1,196 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::account::{
+        constants::SYNC_CHECKPOINT_MAX_AGE, operations::syncing::SyncOptions,
+        types::address::AddressWithUnspentOutputs, Account,
+    },
+};
+
+/// Checkpoint of the account syncing.
+///
+/// Contains the ledger index up to which addresses have been fully synced and the progress of an unfinished sync, so
+/// it can be resumed instead of scanning all addresses again.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCheckpoint {
+    /// The ledger index up to which all addresses of the account have been fully synced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ledger_index: Option<u32>,
+    /// The ledger index up to which each address has been fully synced.
+    #[serde(default)]
+    pub(crate) addresses: HashMap<Bech32Address, u32>,
+    /// The progress of an unfinished address scan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pending: Option<PendingScan>,
+}
+
+/// The progress of an unfinished address scan.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PendingScan {
+    /// The confirmed milestone index when the scan started.
+    pub(crate) ledger_index: u32,
+    /// The options of the scan, a scan can only be resumed with the same options.
+    pub(crate) options: SyncOptions,
+    /// The already scanned addresses with their unspent output ids.
+    pub(crate) addresses: Vec<AddressWithUnspentOutputs>,
+}
+
+impl SyncCheckpoint {
+    /// Returns the ledger index up to which all addresses of the account have been fully synced.
+    pub fn ledger_index(&self) -> Option<u32> {
+        self.ledger_index
+    }
+
+    /// Returns the ledger index up to which the address has been fully synced.
+    pub fn address_ledger_index(&self, address: &Bech32Address) -> Option<u32> {
+        self.addresses.get(address).copied()
+    }
+
+    /// Returns the amount of addresses that were already scanned by an unfinished sync.
+    pub fn pending_addresses(&self) -> usize {
+        self.pending.as_ref().map_or(0, |pending| pending.addresses.len())
+    }
+
+    /// Returns the pending scan if it was started with the same options and isn't too old to be resumed.
+    pub(crate) fn resumable_scan(&self, options: &SyncOptions, ledger_index: u32) -> Option<&PendingScan> {
+        self.pending.as_ref().filter(|pending| {
+            &pending.options == options && ledger_index.saturating_sub(pending.ledger_index) <= SYNC_CHECKPOINT_MAX_AGE
+        })
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the sync checkpoint of the account.
+    pub async fn sync_checkpoint(&self) -> SyncCheckpoint {
+        self.sync_checkpoint.lock().await.clone()
+    }
+
+    /// Set the sync checkpoint, if storage is enabled, it will persist during restarts.
+    pub(crate) async fn set_sync_checkpoint(&self, checkpoint: SyncCheckpoint) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_sync_checkpoint(index, &checkpoint).await?;
+        }
+
+        *self.sync_checkpoint.lock().await = checkpoint;
+        Ok(())
+    }
+
+    /// Start a new address scan or resume an unfinished one.
+    ///
+    /// Returns the ledger index from which on the scan is valid and the addresses that were already scanned.
+    pub(crate) async fn start_sync_checkpoint(
+        &self,
+        options: &SyncOptions,
+    ) -> crate::wallet::Result<(u32, Vec<AddressWithUnspentOutputs>)> {
+        let ledger_index = self.client().confirmed_milestone_index().await?;
+
+        let mut checkpoint = self.sync_checkpoint().await;
+        if let Some(pending) = checkpoint.resumable_scan(options, ledger_index) {
+            log::debug!(
+                "[SYNC] resuming scan from ledger index {} with {} scanned addresses",
+                pending.ledger_index,
+                pending.addresses.len()
+            );
+            return Ok((pending.ledger_index, pending.addresses.clone()));
+        }
+
+        checkpoint.pending = Some(PendingScan {
+            ledger_index,
+            options: options.clone(),
+            addresses: Vec::new(),
+        });
+        self.set_sync_checkpoint(checkpoint).await?;
+
+        Ok((ledger_index, Vec::new()))
+    }
+
+    /// Add scanned addresses to the pending scan.
+    pub(crate) async fn update_sync_checkpoint(
+        &self,
+        scanned_addresses: impl IntoIterator<Item = AddressWithUnspentOutputs> + Send,
+    ) -> crate::wallet::Result<()> {
+        let mut checkpoint = self.sync_checkpoint().await;
+        if let Some(pending) = &mut checkpoint.pending {
+            pending.addresses.extend(scanned_addresses);
+            self.set_sync_checkpoint(checkpoint).await?;
+        }
+        Ok(())
+    }
+
+    /// Finish the pending scan and set the ledger index for the synced addresses.
+    pub(crate) async fn finish_sync_checkpoint(
+        &self,
+        options: &SyncOptions,
+        ledger_index: u32,
+        synced_addresses: impl IntoIterator<Item = Bech32Address> + Send,
+    ) -> crate::wallet::Result<()> {
+        let mut checkpoint = self.sync_checkpoint().await;
+        checkpoint.pending = None;
+        checkpoint
+            .addresses
+            .extend(synced_addresses.into_iter().map(|address| (address, ledger_index)));
+        // Only a sync of all addresses updates the ledger index of the account
+        if options.addresses.is_empty() && options.address_start_index == 0 && options.address_start_index_internal == 0
+        {
+            checkpoint.ledger_index = Some(ledger_index);
+        }
+        self.set_sync_checkpoint(checkpoint).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn resumable_scan() {
+        let options = SyncOptions::default();
+        let checkpoint = SyncCheckpoint {
+            pending: Some(PendingScan {
+                ledger_index: 100,
+                options: options.clone(),
+                addresses: Vec::new(),
+            }),
+            ..Default::default()
+        };
+
+        assert!(checkpoint.resumable_scan(&options, 100).is_some());
+        assert!(
+            checkpoint
+                .resumable_scan(&options, 100 + SYNC_CHECKPOINT_MAX_AGE)
+                .is_some()
+        );
+        assert!(
+            checkpoint
+                .resumable_scan(&options, 101 + SYNC_CHECKPOINT_MAX_AGE)
+                .is_none()
+        );
+
+        let other_options = SyncOptions {
+            sync_incoming_transactions: true,
+            ..Default::default()
+        };
+        assert!(checkpoint.resumable_scan(&other_options, 100).is_none());
+
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        assert_eq!(serde_json::from_str::<SyncCheckpoint>(&json).unwrap(), checkpoint);
+        assert_eq!(SyncCheckpoint::default().pending_addresses(), 0);
+    }
+}
@@ -2,19 +2,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod checkpoint;
 pub(crate) mod foundries;
 pub(crate) mod options;
 pub(crate) mod outputs;
+pub(crate) mod progress;
 pub(crate) mod transactions;
 
 use std::collections::{HashMap, HashSet};
 
-pub use self::options::SyncOptions;
+pub use self::{
+    checkpoint::SyncCheckpoint,
+    options::SyncOptions,
+    progress::{SyncProgress, SyncProgressCallback},
+};
 use crate::{
     client::secret::SecretManage,
     types::block::{
         address::{Address, ToBech32Ext},
-        output::{FoundryId, Output, OutputId, OutputMetadata},
+        output::{AliasOutput, BasicOutput, FoundryId, FoundryOutput, NftOutput, Output, OutputId, OutputMetadata},
     },
     wallet::account::{
         constants::MIN_SYNC_INTERVAL,
@@ -71,6 +77,7 @@ where
             return self.balance().await;
         }
 
+        self.discover_addresses(&options).await?;
         self.sync_internal(&options).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
@@ -97,12 +104,20 @@ where
 
         let addresses_to_sync = self.get_addresses_to_sync(options).await?;
         log::debug!("[SYNC] addresses_to_sync {}", addresses_to_sync.len());
+        options.progress.report(|| SyncProgress::AddressDiscovery {
+            addresses: addresses_to_sync.len(),
+        });
+        let synced_addresses = addresses_to_sync.iter().map(|a| a.address).collect::<Vec<_>>();
+
+        let (checkpoint_ledger_index, scanned_addresses) = self.start_sync_checkpoint(options).await?;
 
         let (spent_or_not_synced_output_ids, addresses_with_unspent_outputs, outputs_data): (
             Vec<OutputId>,
             Vec<AddressWithUnspentOutputs>,
             Vec<OutputData>,
-        ) = self.request_outputs_recursively(addresses_to_sync, options).await?;
+        ) = self
+            .request_outputs_recursively(addresses_to_sync, scanned_addresses, options)
+            .await?;
 
         // Request possible spent outputs
         log::debug!("[SYNC] spent_or_not_synced_outputs: {spent_or_not_synced_output_ids:?}");
@@ -141,9 +156,26 @@ where
                 .collect::<HashSet<_>>();
 
             // Request and store foundry outputs
+            let foundries = native_token_foundry_ids.len();
             self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
+            options.progress.report(|| SyncProgress::FoundriesFetched { foundries });
         }
 
+        options.progress.report(|| {
+            let count = |kind| {
+                outputs_data
+                    .iter()
+                    .filter(|output| output.output.kind() == kind)
+                    .count()
+            };
+            SyncProgress::UpdatingAccount {
+                basic_outputs: count(BasicOutput::KIND),
+                alias_outputs: count(AliasOutput::KIND),
+                nft_outputs: count(NftOutput::KIND),
+                foundry_outputs: count(FoundryOutput::KIND),
+            }
+        });
+
         // Updates account with balances, output ids, outputs
         self.update_account(
             addresses_with_unspent_outputs,
@@ -151,7 +183,10 @@ where
             spent_or_unsynced_output_metadata_map,
             options,
         )
-        .await
+        .await?;
+
+        self.finish_sync_checkpoint(options, checkpoint_ledger_index, synced_addresses)
+            .await
     }
 
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
@@ -160,15 +195,16 @@ where
     async fn request_outputs_recursively(
         &self,
         addresses_to_sync: Vec<AddressWithUnspentOutputs>,
+        scanned_addresses: Vec<AddressWithUnspentOutputs>,
         options: &SyncOptions,
     ) -> crate::wallet::Result<(Vec<OutputId>, Vec<AddressWithUnspentOutputs>, Vec<OutputData>)> {
         // Get outputs for addresses and add them also to the `addresses_with_unspent_outputs`
         let (addresses_with_output_ids, mut spent_or_not_synced_output_ids) = self
-            .get_output_ids_for_addresses(options, addresses_to_sync.clone())
+            .get_output_ids_for_addresses(options, addresses_to_sync.clone(), scanned_addresses)
             .await?;
 
         let (mut addresses_with_unspent_outputs, mut outputs_data) = self
-            .get_outputs_from_address_output_ids(addresses_with_output_ids)
+            .get_outputs_from_address_output_ids(addresses_with_output_ids, options)
             .await?;
 
         // Cache the alias and nft address with the related ed2559 address, so we can update the account address with
@@ -201,6 +237,8 @@ where
                 break;
             }
 
+            let synced_alias_and_nft_addresses = new_alias_and_nft_addresses.len();
+            let outputs_before = outputs_data.len();
             for (alias_or_nft_address, ed25519_address) in new_alias_and_nft_addresses.drain() {
                 let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
 
@@ -221,6 +259,10 @@ where
                 outputs_data.extend(outputs_data_inner.clone());
                 new_outputs_data.extend(outputs_data_inner);
             }
+            options.progress.report(|| SyncProgress::AliasAndNftOutputsFetched {
+                addresses: synced_alias_and_nft_addresses,
+                outputs: outputs_data.len() - outputs_before,
+            });
         }
 
         // get_output_ids_for_addresses() will return recursively owned outputs not anymore, sine they will only get
@@ -3,6 +3,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::progress::{SyncProgress, SyncProgressCallback};
 use crate::types::block::address::Bech32Address;
 
 const DEFAULT_ADDRESS_START_INDEX: u32 = 0;
@@ -56,6 +57,17 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// The number of unused addresses after the last used one that are checked for unspent outputs before syncing, so
+    /// funds on addresses the account doesn't know yet are found. Addresses are not discovered if 0 or if specific
+    /// `addresses` are synced.
+    #[serde(default)]
+    pub address_gap_limit: u32,
+    /// How the addresses within the `address_gap_limit` are checked.
+    #[serde(default)]
+    pub address_discovery: AddressDiscoveryMode,
+    /// Called with the stages of the sync, e.g. to show a progress bar. Not serialized.
+    #[serde(skip)]
+    pub progress: SyncProgressCallback,
 }
 
 fn default_address_start_index() -> u32 {
@@ -96,10 +108,33 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            address_gap_limit: 0,
+            address_discovery: AddressDiscoveryMode::default(),
+            progress: SyncProgressCallback::default(),
         }
     }
 }
 
+impl SyncOptions {
+    /// Sets a callback that gets called with the stages of the sync.
+    pub fn with_progress(mut self, progress: impl Fn(SyncProgress) + Send + Sync + 'static) -> Self {
+        self.progress = SyncProgressCallback::new(progress);
+        self
+    }
+}
+
+/// How unknown addresses of an account are discovered, see [`SyncOptions::address_gap_limit`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AddressDiscoveryMode {
+    /// Check every address until `address_gap_limit` addresses in a row are unused.
+    #[default]
+    Linear,
+    /// Only check the addresses at an offset of 1, 2, 4, 8, ... up to `address_gap_limit` after the last used one.
+    /// Needs much fewer requests for large gaps, but can miss used addresses in between the checked ones.
+    Exponential,
+}
+
 /// Sync options for Ed25519 addresses from the account
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
@@ -18,7 +18,10 @@ use crate::{
         },
     },
     wallet::{
-        account::{build_transaction_from_payload_and_inputs, types::OutputData, Account, AddressWithUnspentOutputs},
+        account::{
+            build_transaction_from_payload_and_inputs, constants::PARALLEL_REQUESTS_AMOUNT, types::OutputData, Account,
+            AddressWithUnspentOutputs,
+        },
         task,
     },
 };
@@ -104,7 +107,11 @@ where
         drop(account_details);
 
         if !unknown_outputs.is_empty() {
-            outputs.extend(self.client().get_outputs(&unknown_outputs).await?);
+            outputs.extend(
+                self.client()
+                    .get_outputs_with_concurrency(&unknown_outputs, PARALLEL_REQUESTS_AMOUNT)
+                    .await?,
+            );
         }
 
         log::debug!(
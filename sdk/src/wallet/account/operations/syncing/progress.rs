@@ -0,0 +1,146 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A stage of an account sync, reported to the [`SyncOptions::progress`](super::SyncOptions::progress) callback.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "stage", rename_all = "camelCase")]
+pub enum SyncProgress {
+    /// The addresses that will be synced were determined.
+    #[serde(rename_all = "camelCase")]
+    AddressDiscovery {
+        /// The amount of addresses that will be synced.
+        addresses: usize,
+    },
+    /// The output ids of a chunk of addresses were fetched.
+    #[serde(rename_all = "camelCase")]
+    OutputIdsFetched {
+        /// The amount of addresses for which the output ids were fetched.
+        synced_addresses: usize,
+        /// The amount of addresses that will be synced.
+        total_addresses: usize,
+        /// The amount of output ids fetched so far.
+        output_ids: usize,
+    },
+    /// The outputs of a chunk of addresses with output ids were fetched.
+    #[serde(rename_all = "camelCase")]
+    OutputsFetched {
+        /// The amount of addresses for which the outputs were fetched.
+        synced_addresses: usize,
+        /// The amount of addresses that have output ids.
+        total_addresses: usize,
+        /// The amount of outputs fetched so far.
+        outputs: usize,
+    },
+    /// The outputs owned by the addresses of alias and nft outputs were fetched, this is repeated until no new alias
+    /// or nft outputs are found.
+    #[serde(rename_all = "camelCase")]
+    AliasAndNftOutputsFetched {
+        /// The amount of alias and nft addresses that were synced in this round.
+        addresses: usize,
+        /// The amount of outputs fetched for them.
+        outputs: usize,
+    },
+    /// The foundries of the native tokens were fetched, only if
+    /// [`SyncOptions::sync_native_token_foundries`](super::SyncOptions::sync_native_token_foundries) is set.
+    #[serde(rename_all = "camelCase")]
+    FoundriesFetched {
+        /// The amount of foundries.
+        foundries: usize,
+    },
+    /// The fetched outputs are stored in the account.
+    #[serde(rename_all = "camelCase")]
+    UpdatingAccount {
+        /// The amount of basic outputs.
+        basic_outputs: usize,
+        /// The amount of alias outputs.
+        alias_outputs: usize,
+        /// The amount of nft outputs.
+        nft_outputs: usize,
+        /// The amount of foundry outputs.
+        foundry_outputs: usize,
+    },
+}
+
+/// An optional callback that gets called with each [`SyncProgress`] of an account sync.
+#[derive(Clone, Default)]
+pub struct SyncProgressCallback(Option<Arc<dyn Fn(SyncProgress) + Send + Sync>>);
+
+impl SyncProgressCallback {
+    /// Creates a new [`SyncProgressCallback`].
+    pub fn new(callback: impl Fn(SyncProgress) + Send + Sync + 'static) -> Self {
+        Self(Some(Arc::new(callback)))
+    }
+
+    /// Returns whether a callback is set.
+    pub fn is_set(&self) -> bool {
+        self.0.is_some()
+    }
+
+    pub(crate) fn report(&self, progress: impl FnOnce() -> SyncProgress) {
+        if let Some(callback) = &self.0 {
+            callback(progress())
+        }
+    }
+}
+
+impl fmt::Debug for SyncProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SyncProgressCallback").field(&self.is_set()).finish()
+    }
+}
+
+// The callback doesn't change what gets synced, so options that only differ in it are equal, e.g. to resume a sync
+// checkpoint.
+impl PartialEq for SyncProgressCallback {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SyncProgressCallback {}
+
+impl Hash for SyncProgressCallback {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::account::SyncOptions;
+
+    #[test]
+    fn progress_callback() {
+        let progress = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let reported = progress.clone();
+        let options = SyncOptions::default().with_progress(move |stage| reported.lock().unwrap().push(stage));
+
+        // The callback is ignored when comparing and serializing options
+        assert_eq!(options, SyncOptions::default());
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(serde_json::from_str::<SyncOptions>(&json).unwrap(), options);
+
+        options
+            .progress
+            .report(|| SyncProgress::AddressDiscovery { addresses: 2 });
+        SyncOptions::default()
+            .progress
+            .report(|| unreachable!("no callback is set"));
+
+        assert_eq!(
+            *progress.lock().unwrap(),
+            vec![SyncProgress::AddressDiscovery { addresses: 2 }]
+        );
+        assert_eq!(
+            serde_json::to_value(SyncProgress::FoundriesFetched { foundries: 1 }).unwrap(),
+            serde_json::json!({ "stage": "foundriesFetched", "foundries": 1 })
+        );
+    }
+}
@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{TransactionRetryEvent, TransactionRetryKind, WalletEvent};
 use crate::{
     client::secret::SecretManage,
     types::{
@@ -135,12 +137,11 @@ where
                                         );
                                     } else {
                                         log::debug!("[SYNC] conflicting transaction {transaction_id}");
-                                        updated_transaction_and_outputs(
+                                        process_conflicting_transaction(
+                                            &account_details,
                                             transaction,
-                                            None,
-                                            InclusionState::Conflicting,
                                             &mut updated_transactions,
-                                            &mut spent_output_ids,
+                                            &mut output_ids_to_unlock,
                                         );
                                     }
                                 }
@@ -204,6 +205,16 @@ where
         for mut transaction in transactions_to_reattach {
             log::debug!("[SYNC] reattach transaction");
             let reattached_block = self.submit_transaction_payload(transaction.payload.clone()).await?;
+            #[cfg(feature = "events")]
+            self.emit(
+                self.details().await.index,
+                WalletEvent::TransactionRetry(TransactionRetryEvent {
+                    transaction_id: transaction.transaction_id,
+                    block_id: reattached_block,
+                    kind: TransactionRetryKind::Reattached,
+                }),
+            )
+            .await;
             transaction.block_id.replace(reattached_block);
             updated_transactions.push(transaction);
         }
@@ -236,6 +247,31 @@ fn updated_transaction_and_outputs(
     updated_transactions.push(transaction);
 }
 
+// Roll back a conflicting transaction, its inputs that didn't get spent by another transaction are available again
+fn process_conflicting_transaction(
+    account: &AccountDetails,
+    mut transaction: Transaction,
+    updated_transactions: &mut Vec<Transaction>,
+    output_ids_to_unlock: &mut Vec<OutputId>,
+) {
+    transaction.block_id = None;
+    transaction.inclusion_state = InclusionState::Conflicting;
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    for input in essence.inputs() {
+        if let Input::Utxo(input) = input {
+            // Outputs are synced before the pending transactions, so spent inputs are already known
+            if !account
+                .outputs
+                .get(input.output_id())
+                .is_some_and(|output_data| output_data.is_spent)
+            {
+                output_ids_to_unlock.push(*input.output_id());
+            }
+        }
+    }
+    updated_transactions.push(transaction);
+}
+
 // When a transaction got pruned, the inputs and outputs are also not available, then this could mean that it was
 // confirmed and the created outputs got also already spent and pruned or the inputs got spent in another transaction
 fn process_transaction_with_unknown_state(
@@ -0,0 +1,113 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{client::secret::SecretManage, types::block::output::TokenId, wallet::account::Account};
+
+/// Allow and deny lists of native tokens, to hide unwanted tokens, e.g. airdropped scam tokens.
+///
+/// Denied tokens are hidden from
+/// [`Balance::filtered_native_tokens()`](crate::wallet::account::types::Balance::filtered_native_tokens). Tokens that
+/// are on neither list are shown, but can get spam indicators to suggest hiding them.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenFilter {
+    /// Tokens that the user marked as wanted.
+    #[serde(default)]
+    pub(crate) allowed: HashSet<TokenId>,
+    /// Tokens that the user marked as unwanted.
+    #[serde(default)]
+    pub(crate) denied: HashSet<TokenId>,
+}
+
+impl TokenFilter {
+    /// Returns the tokens that the user marked as wanted.
+    pub fn allowed(&self) -> &HashSet<TokenId> {
+        &self.allowed
+    }
+
+    /// Returns the tokens that the user marked as unwanted.
+    pub fn denied(&self) -> &HashSet<TokenId> {
+        &self.denied
+    }
+
+    /// Marks a token as wanted.
+    pub fn allow(&mut self, token_id: TokenId) {
+        self.denied.remove(&token_id);
+        self.allowed.insert(token_id);
+    }
+
+    /// Marks a token as unwanted.
+    pub fn deny(&mut self, token_id: TokenId) {
+        self.allowed.remove(&token_id);
+        self.denied.insert(token_id);
+    }
+
+    /// Removes a token from both lists.
+    pub fn reset(&mut self, token_id: &TokenId) {
+        self.allowed.remove(token_id);
+        self.denied.remove(token_id);
+    }
+
+    /// Returns whether the user marked the token as wanted or unwanted.
+    pub fn is_listed(&self, token_id: &TokenId) -> bool {
+        self.allowed.contains(token_id) || self.denied.contains(token_id)
+    }
+
+    /// Returns whether the token should be shown in balances.
+    pub fn is_shown(&self, token_id: &TokenId) -> bool {
+        !self.denied.contains(token_id)
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the token filter of the account.
+    pub async fn token_filter(&self) -> TokenFilter {
+        self.token_filter.lock().await.clone()
+    }
+
+    /// Set the token filter, if storage is enabled, it will persist during restarts.
+    pub async fn set_token_filter(&self, token_filter: TokenFilter) -> crate::wallet::Result<()> {
+        self.update_token_filter(|current| *current = token_filter).await
+    }
+
+    /// Marks a native token as wanted, so it's shown in balances and no spam indicators are computed for it.
+    pub async fn allow_native_token(&self, token_id: TokenId) -> crate::wallet::Result<()> {
+        self.update_token_filter(|token_filter| token_filter.allow(token_id))
+            .await
+    }
+
+    /// Marks a native token as unwanted, so it's hidden from the filtered native tokens of balances.
+    pub async fn deny_native_token(&self, token_id: TokenId) -> crate::wallet::Result<()> {
+        self.update_token_filter(|token_filter| token_filter.deny(token_id))
+            .await
+    }
+
+    /// Removes a native token from the allow and deny lists.
+    pub async fn reset_native_token(&self, token_id: &TokenId) -> crate::wallet::Result<()> {
+        self.update_token_filter(|token_filter| token_filter.reset(token_id))
+            .await
+    }
+
+    async fn update_token_filter(&self, f: impl FnOnce(&mut TokenFilter) + Send) -> crate::wallet::Result<()> {
+        let mut token_filter = self.token_filter.lock().await;
+        let mut updated = token_filter.clone();
+        f(&mut updated);
+
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_token_filter(index, &updated).await?;
+        }
+
+        *token_filter = updated;
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{api::input_selection::Error as InputSelectionError, secret::SecretManage},
+    types::block::output::{Output, Rent},
+    wallet::account::{
+        operations::transaction::{high_level::send::SendParams, RemainderValueStrategy, TransactionOptions},
+        Account,
+    },
+};
+
+/// The estimated costs of a transaction, see [`Account::estimate_transaction()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEstimate {
+    /// The storage deposit required by the outputs, including the remainder.
+    #[serde(with = "crate::utils::serde::string")]
+    pub required_storage_deposit: u64,
+    /// The part of the output amounts that has to be returned to the sender with storage deposit return unlock
+    /// conditions.
+    #[serde(with = "crate::utils::serde::string")]
+    pub returned_storage_deposit: u64,
+    /// The number of inputs that would be consumed.
+    pub input_count: usize,
+    /// Whether the funds are spread over more outputs than a transaction can consume, so they have to be
+    /// consolidated first. The storage deposit doesn't include the remainder then.
+    pub consolidation_required: bool,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Estimates the costs of [`Account::send_with_params()`], without building the transaction or locking any
+    /// outputs, so they can be shown before the user confirms the transaction.
+    pub async fn estimate_transaction<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionEstimate>
+    where
+        I::IntoIter: Send,
+    {
+        let options = options.into();
+        let outputs = self.build_send_outputs(params, options.as_ref()).await?;
+
+        self.estimate_outputs(outputs, options).await
+    }
+
+    /// Estimates the costs of [`Account::send_outputs()`], without building the transaction or locking any outputs.
+    pub async fn estimate_outputs(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionEstimate> {
+        log::debug!("[TRANSACTION] estimate_outputs");
+        let options = options.into();
+        let outputs = outputs.into();
+        let rent_structure = self.client().get_rent_structure().await?;
+
+        let required_storage_deposit =
+            |outputs: &[Output]| -> u64 { outputs.iter().map(|output| output.rent_cost(&rent_structure)).sum() };
+        let returned_storage_deposit = |outputs: &[Output]| -> u64 {
+            outputs
+                .iter()
+                .filter_map(|output| output.unlock_conditions()?.storage_deposit_return())
+                .map(|sdruc| sdruc.amount())
+                .sum()
+        };
+
+        // A new remainder address requires the same storage deposit as an existing one, so none is generated.
+        let remainder_address = options
+            .as_ref()
+            .and_then(|options| match &options.remainder_value_strategy {
+                RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                _ => None,
+            });
+
+        match self
+            .select_inputs_with_options(outputs.clone(), options.as_ref(), remainder_address, false)
+            .await
+        {
+            Ok(selected) => Ok(TransactionEstimate {
+                required_storage_deposit: required_storage_deposit(&selected.outputs),
+                returned_storage_deposit: returned_storage_deposit(&selected.outputs),
+                input_count: selected.inputs.len(),
+                consolidation_required: false,
+            }),
+            Err(crate::wallet::Error::Client(error)) => match *error {
+                crate::client::Error::InputSelection(InputSelectionError::InvalidInputCount(input_count)) => {
+                    Ok(TransactionEstimate {
+                        required_storage_deposit: required_storage_deposit(&outputs),
+                        returned_storage_deposit: returned_storage_deposit(&outputs),
+                        input_count,
+                        consolidation_required: true,
+                    })
+                }
+                error => Err(error.into()),
+            },
+            Err(error) => Err(error),
+        }
+    }
+}
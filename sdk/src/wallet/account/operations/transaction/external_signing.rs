@@ -0,0 +1,194 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{
+            input_selection::{is_alias_transition, Error as InputSelectionError},
+            transaction::validate_transaction_payload_length,
+            PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
+        },
+        secret::SecretManage,
+    },
+    types::block::{
+        address::{Address, Bech32Address, Ed25519Address},
+        output::Output,
+        payload::transaction::{TransactionEssence, TransactionPayload},
+        signature::{Ed25519Signature, Signature},
+        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+    },
+    wallet::account::{operations::transaction::TransactionOptions, types::Transaction, Account},
+};
+
+/// A prepared transaction that is signed outside of the wallet, see [`Account::export_for_external_signing()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalSigningRequest {
+    /// The prepared transaction, the inputs contain the BIP44 chains of the keys that need to sign.
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// The hex encoded hash of the transaction essence that needs to be signed.
+    pub essence_hash: String,
+    /// The addresses that need to sign the essence hash, in the order of their signature unlocks.
+    pub required_signers: Vec<Bech32Address>,
+}
+
+// How an input gets unlocked, in the same order as the unlocks of `SecretManage::sign_transaction_essence()`.
+enum InputUnlock {
+    Signature(Ed25519Address),
+    Reference(Unlock),
+}
+
+fn input_unlocks(
+    prepared_transaction_data: &PreparedTransactionData,
+    time: u32,
+) -> crate::wallet::Result<Vec<InputUnlock>> {
+    let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
+    let mut input_unlocks = Vec::with_capacity(prepared_transaction_data.inputs_data.len());
+    let mut block_indexes = HashMap::<Address, usize>::new();
+
+    for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
+        let alias_transition = is_alias_transition(&input.output, *input.output_id(), regular.outputs(), None);
+        let (input_address, _) =
+            input
+                .output
+                .required_and_unlocked_address(time, input.output_metadata.output_id(), alias_transition)?;
+
+        match (block_indexes.get(&input_address), input_address) {
+            (Some(block_index), Address::Alias(_)) => input_unlocks.push(InputUnlock::Reference(Unlock::Alias(
+                AliasUnlock::new(*block_index as u16)?,
+            ))),
+            (Some(block_index), Address::Ed25519(_)) => input_unlocks.push(InputUnlock::Reference(Unlock::Reference(
+                ReferenceUnlock::new(*block_index as u16)?,
+            ))),
+            (Some(block_index), Address::Nft(_)) => input_unlocks.push(InputUnlock::Reference(Unlock::Nft(
+                NftUnlock::new(*block_index as u16)?,
+            ))),
+            (None, Address::Ed25519(ed25519_address)) => {
+                input_unlocks.push(InputUnlock::Signature(ed25519_address));
+                block_indexes.insert(input_address, current_block_index);
+            }
+            // The alias or nft output needs to be unlocked by an earlier input
+            (None, _) => Err(crate::client::Error::from(
+                InputSelectionError::MissingInputWithEd25519Address,
+            ))?,
+        }
+
+        match &input.output {
+            Output::Alias(alias_output) => block_indexes.insert(
+                Address::Alias(alias_output.alias_address(input.output_id())),
+                current_block_index,
+            ),
+            Output::Nft(nft_output) => block_indexes.insert(
+                Address::Nft(nft_output.nft_address(input.output_id())),
+                current_block_index,
+            ),
+            _ => None,
+        };
+    }
+
+    Ok(input_unlocks)
+}
+
+fn signature_address(signature: &Ed25519Signature) -> Ed25519Address {
+    Ed25519Address::new(Blake2b256::digest(signature.public_key_bytes()).into())
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Exports a prepared transaction to be signed outside of the wallet, e.g. on a machine that holds the keys of a
+    /// watch-only account. The signatures can then be imported with
+    /// [`Account::submit_externally_signed_transaction()`].
+    pub async fn export_for_external_signing(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<ExternalSigningRequest> {
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let time = self.client().get_time_checked().await?;
+
+        let required_signers = input_unlocks(prepared_transaction_data, time)?
+            .into_iter()
+            .filter_map(|input_unlock| match input_unlock {
+                InputUnlock::Signature(address) => Some(Bech32Address::new(bech32_hrp, address)),
+                InputUnlock::Reference(_) => None,
+            })
+            .collect();
+
+        Ok(ExternalSigningRequest {
+            prepared_transaction_data: PreparedTransactionDataDto::from(prepared_transaction_data),
+            essence_hash: prefix_hex::encode(prepared_transaction_data.essence.hash()),
+            required_signers,
+        })
+    }
+
+    /// Combines externally produced signatures into the unlocks of a prepared transaction. The `unlocks` can come from
+    /// multiple parties, only their signature unlocks are used and each needs to be valid for one of the required
+    /// signers.
+    pub async fn combine_external_signatures(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        unlocks: Vec<Unlock>,
+    ) -> crate::wallet::Result<SignedTransactionData> {
+        log::debug!("[TRANSACTION] combine_external_signatures");
+
+        let essence_hash = prepared_transaction_data.essence.hash();
+        let mut signatures = HashMap::new();
+        for unlock in unlocks {
+            if let Unlock::Signature(signature_unlock) = unlock {
+                let Signature::Ed25519(signature) = signature_unlock.signature();
+                let address = signature_address(signature);
+                signature.is_valid(&essence_hash, &address)?;
+                signatures.insert(address, signature_unlock);
+            }
+        }
+
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let time = self.client().get_time_checked().await?;
+        let mut combined_unlocks = Vec::with_capacity(prepared_transaction_data.inputs_data.len());
+        for input_unlock in input_unlocks(prepared_transaction_data, time)? {
+            combined_unlocks.push(match input_unlock {
+                InputUnlock::Signature(address) => {
+                    let signature_unlock: SignatureUnlock = signatures.get(&address).cloned().ok_or_else(|| {
+                        crate::wallet::Error::MissingSignature(Bech32Address::new(bech32_hrp, address))
+                    })?;
+                    Unlock::Signature(signature_unlock)
+                }
+                InputUnlock::Reference(unlock) => unlock,
+            });
+        }
+
+        let transaction_payload = TransactionPayload::new(
+            prepared_transaction_data.essence.clone(),
+            Unlocks::new(combined_unlocks)?,
+        )?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data: prepared_transaction_data.inputs_data.clone(),
+        })
+    }
+
+    /// Combines externally produced signatures with [`Account::combine_external_signatures()`], submits the
+    /// transaction to a node and stores it in the account.
+    pub async fn submit_externally_signed_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        unlocks: Vec<Unlock>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let signed_transaction_data = self
+            .combine_external_signatures(prepared_transaction_data, unlocks)
+            .await?;
+
+        self.submit_and_store_transaction(signed_transaction_data, options)
+            .await
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::{input_selection::Burn, PreparedTransactionData},
+    types::block::output::{AliasId, FoundryId, Output, TokenId, TokenScheme},
+    wallet::{
+        account::{types::Transaction, Account, TransactionOptions},
+        Error,
+    },
+};
+
+/// Options for [`Account::plan_alias_destruction()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DestroyAliasOptions {
+    /// Whether to melt the circulating native tokens of the foundries of the alias that are held by the account, so
+    /// the foundries can be destroyed. Without it, circulating native tokens block the destruction.
+    #[serde(default)]
+    pub melt_native_tokens: bool,
+}
+
+/// A transaction of an [`AliasDestructionPlan`], in the order they are sent.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AliasDestructionStep {
+    /// Melt native tokens with their foundry.
+    #[serde(rename_all = "camelCase")]
+    MeltNativeToken {
+        /// The token to melt.
+        token_id: TokenId,
+        /// The amount to melt.
+        amount: U256,
+    },
+    /// Destroy the foundries of the alias, which requires a state transition of the alias.
+    #[serde(rename_all = "camelCase")]
+    DestroyFoundries {
+        /// The foundries to destroy.
+        foundry_ids: Vec<FoundryId>,
+    },
+    /// Destroy the alias, which requires a governance transition.
+    #[serde(rename_all = "camelCase")]
+    DestroyAlias {
+        /// The alias to destroy.
+        alias_id: AliasId,
+    },
+}
+
+/// A reason why an alias can't be destroyed by the account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AliasDestructionBlocker {
+    /// A foundry of the alias has native tokens in circulation that won't be melted, because they aren't held by the
+    /// account or melting isn't enabled in the [`DestroyAliasOptions`].
+    #[serde(rename_all = "camelCase")]
+    CirculatingNativeTokens {
+        /// The token in circulation.
+        token_id: TokenId,
+        /// The minted minus the melted tokens.
+        circulating_supply: U256,
+        /// The available amount of the token held by the account.
+        held_by_account: U256,
+    },
+    /// The state controller of the alias isn't an address of the account, so its foundries can't be changed.
+    StateControllerNotOwned,
+    /// The governor of the alias isn't an address of the account, so it can't be destroyed.
+    GovernorNotOwned,
+}
+
+/// The transactions needed to destroy an alias with its foundries, see [`Account::plan_alias_destruction()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasDestructionPlan {
+    /// The alias to destroy.
+    pub alias_id: AliasId,
+    /// The transactions that will be sent, in order.
+    pub steps: Vec<AliasDestructionStep>,
+    /// The reasons the plan can't be executed, empty if it can.
+    pub blockers: Vec<AliasDestructionBlocker>,
+}
+
+impl AliasDestructionPlan {
+    /// Returns whether the plan can be executed with [`Account::destroy_alias_with_dependencies()`].
+    pub fn is_executable(&self) -> bool {
+        self.blockers.is_empty()
+    }
+}
+
+impl Account {
+    /// Plans the destruction of an alias without sending anything, as a dry-run preview. Foundries controlled by the
+    /// alias are destroyed first, after their circulating native tokens held by the account are melted if
+    /// [`DestroyAliasOptions::melt_native_tokens`] is set. Anything that prevents the destruction is reported in
+    /// [`AliasDestructionPlan::blockers`].
+    ///
+    /// The plan uses the last synced state of the account, which includes the foundries of its aliases.
+    pub async fn plan_alias_destruction(
+        &self,
+        alias_id: AliasId,
+        options: &DestroyAliasOptions,
+    ) -> crate::wallet::Result<AliasDestructionPlan> {
+        log::debug!("[TRANSACTION] plan_alias_destruction {alias_id}");
+
+        let balance = self.balance().await?;
+        let account_details = self.details().await;
+        let account_addresses = account_details
+            .addresses()
+            .into_iter()
+            .map(|address| address.address.inner)
+            .collect::<Vec<_>>();
+
+        let alias_output = account_details
+            .unspent_outputs()
+            .iter()
+            .find_map(|(output_id, output_data)| match &output_data.output {
+                Output::Alias(alias_output) if alias_output.alias_id_non_null(output_id) == alias_id => {
+                    Some(alias_output)
+                }
+                _ => None,
+            })
+            .ok_or(Error::AliasNotControlled(alias_id))?;
+
+        let mut foundries = account_details
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Foundry(foundry_output) if *foundry_output.alias_address().alias_id() == alias_id => {
+                    Some(foundry_output)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        foundries.sort_by_key(|foundry_output| foundry_output.id());
+
+        let mut steps = Vec::new();
+        let mut blockers = Vec::new();
+
+        if !foundries.is_empty() && !account_addresses.contains(alias_output.state_controller_address()) {
+            blockers.push(AliasDestructionBlocker::StateControllerNotOwned);
+        }
+
+        for foundry_output in &foundries {
+            let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
+            let circulating_supply = token_scheme.circulating_supply();
+            if circulating_supply.is_zero() {
+                continue;
+            }
+
+            let token_id = foundry_output.token_id();
+            let held_by_account = balance
+                .native_tokens()
+                .iter()
+                .find(|native_token| *native_token.token_id() == token_id)
+                .map(|native_token| native_token.available())
+                .unwrap_or_default();
+
+            if options.melt_native_tokens && held_by_account >= circulating_supply {
+                steps.push(AliasDestructionStep::MeltNativeToken {
+                    token_id,
+                    amount: circulating_supply,
+                });
+            } else {
+                blockers.push(AliasDestructionBlocker::CirculatingNativeTokens {
+                    token_id,
+                    circulating_supply,
+                    held_by_account,
+                });
+            }
+        }
+
+        if !foundries.is_empty() {
+            steps.push(AliasDestructionStep::DestroyFoundries {
+                foundry_ids: foundries.iter().map(|foundry_output| foundry_output.id()).collect(),
+            });
+        }
+
+        if !account_addresses.contains(alias_output.governor_address()) {
+            blockers.push(AliasDestructionBlocker::GovernorNotOwned);
+        }
+        steps.push(AliasDestructionStep::DestroyAlias { alias_id });
+
+        Ok(AliasDestructionPlan {
+            alias_id,
+            steps,
+            blockers,
+        })
+    }
+
+    /// Destroys an alias after melting the native tokens of its foundries and destroying them, as planned by
+    /// [`Account::plan_alias_destruction()`]. Every transaction is awaited until it's included and the account is
+    /// synced before the next one is sent. Fails without sending anything if the plan has blockers.
+    pub async fn destroy_alias_with_dependencies(
+        &self,
+        alias_id: AliasId,
+        options: &DestroyAliasOptions,
+        transaction_options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        let transaction_options = transaction_options.into();
+        let plan = self.plan_alias_destruction(alias_id, options).await?;
+        if !plan.is_executable() {
+            return Err(Error::BurningOrMeltingFailed(format!(
+                "alias {alias_id} can't be destroyed: {}",
+                serde_json::to_string(&plan.blockers)?
+            )));
+        }
+
+        let mut transactions = Vec::with_capacity(plan.steps.len());
+        for step in plan.steps {
+            let prepared = self
+                .prepare_alias_destruction_step(step, transaction_options.clone())
+                .await?;
+            let transaction = self
+                .sign_and_submit_transaction(prepared, transaction_options.clone())
+                .await?;
+
+            self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+                .await?;
+            self.sync(None).await?;
+            transactions.push(transaction);
+        }
+
+        Ok(transactions)
+    }
+
+    async fn prepare_alias_destruction_step(
+        &self,
+        step: AliasDestructionStep,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        match step {
+            AliasDestructionStep::MeltNativeToken { token_id, amount } => {
+                self.prepare_melt_native_token(token_id, amount, options).await
+            }
+            AliasDestructionStep::DestroyFoundries { foundry_ids } => {
+                self.prepare_burn(Burn::new().set_foundries(foundry_ids.into_iter().collect()), options)
+                    .await
+            }
+            AliasDestructionStep::DestroyAlias { alias_id } => {
+                self.prepare_burn(Burn::new().add_alias(alias_id), options).await
+            }
+        }
+    }
+}
@@ -51,13 +51,19 @@ where
         let alias_id = *foundry_id.alias_address().alias_id();
         let token_supply = self.client().get_token_supply().await?;
 
-        let (existing_alias_output_data, existing_foundry_output) = self
-            .find_alias_and_foundry_output_data(alias_id, foundry_id)
-            .await
-            .map(|(alias_data, foundry_data)| match foundry_data.output {
-                Output::Foundry(foundry_output) => (alias_data, foundry_output),
-                _ => unreachable!("We already checked it's a foundry output"),
-            })?;
+        let (existing_alias_output_data, existing_foundry_output_data) =
+            self.find_alias_and_foundry_output_data(alias_id, foundry_id).await?;
+        let existing_foundry_output_data = match existing_foundry_output_data {
+            Some(foundry_output_data) => foundry_output_data,
+            None => {
+                self.request_and_store_foundry_output_data(foundry_id, &existing_alias_output_data)
+                    .await?
+            }
+        };
+        let existing_foundry_output = match existing_foundry_output_data.output {
+            Output::Foundry(foundry_output) => foundry_output,
+            _ => unreachable!("We already checked it's a foundry output"),
+        };
 
         if let Output::Alias(alias_output) = &existing_alias_output_data.output {
             // Create the new alias output with updated amount and state_index
@@ -84,16 +90,18 @@ where
         }
     }
 
-    /// Find and return unspent `OutputData` for given `alias_id` and `foundry_id`
+    /// Find and return unspent `OutputData` for given `alias_id` and `foundry_id`, the foundry output might not be
+    /// known by the account yet. Fails if the account doesn't have state control over the alias.
     async fn find_alias_and_foundry_output_data(
         &self,
         alias_id: AliasId,
         foundry_id: FoundryId,
-    ) -> crate::wallet::Result<(OutputData, OutputData)> {
+    ) -> crate::wallet::Result<(OutputData, Option<OutputData>)> {
         let mut existing_alias_output_data = None;
         let mut existing_foundry_output = None;
+        let account_details = self.details().await;
 
-        for (output_id, output_data) in self.details().await.unspent_outputs().iter() {
+        for (output_id, output_data) in account_details.unspent_outputs().iter() {
             match &output_data.output {
                 Output::Alias(output) => {
                     if output.alias_id_non_null(output_id) == alias_id {
@@ -114,12 +122,61 @@ where
             }
         }
 
+        // The account also tracks alias outputs it's only the governor of, but melting requires a state transition
         let existing_alias_output_data = existing_alias_output_data
-            .ok_or_else(|| Error::BurningOrMeltingFailed("required alias output for foundry not found".to_string()))?;
+            .filter(|output_data| match &output_data.output {
+                Output::Alias(output) => account_details
+                    .addresses()
+                    .iter()
+                    .any(|address| address.address.inner == *output.state_controller_address()),
+                _ => false,
+            })
+            .ok_or(Error::AliasNotControlled(alias_id))?;
+
+        Ok((existing_alias_output_data, existing_foundry_output))
+    }
 
-        let existing_foundry_output_data = existing_foundry_output
-            .ok_or_else(|| Error::BurningOrMeltingFailed("required foundry output not found".to_string()))?;
+    /// Requests a foundry output that isn't known by the account yet from the indexer and stores it, so it can be
+    /// used as input. The foundry is controlled by the alias, so it's associated with the address of the alias output.
+    async fn request_and_store_foundry_output_data(
+        &self,
+        foundry_id: FoundryId,
+        alias_output_data: &OutputData,
+    ) -> crate::wallet::Result<OutputData> {
+        log::debug!("[TRANSACTION] request foundry output {foundry_id}");
+        let output_id = match self.client().foundry_output_id(foundry_id).await {
+            Ok(output_id) => output_id,
+            Err(crate::client::Error::NoOutput(_)) => {
+                return Err(Error::BurningOrMeltingFailed(
+                    "required foundry output not found".to_string(),
+                ));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let output_with_metadata = self.client().get_output(&output_id).await?;
+
+        let output_data = OutputData {
+            output_id,
+            metadata: *output_with_metadata.metadata(),
+            output: output_with_metadata.output().clone(),
+            is_spent: output_with_metadata.metadata().is_spent(),
+            address: alias_output_data.address,
+            network_id: self.client().get_network_id().await?,
+            remainder: false,
+            chain: alias_output_data.chain,
+        };
+
+        let mut account_details = self.details_mut().await;
+        account_details.outputs.insert(output_id, output_data.clone());
+        account_details.unspent_outputs.insert(output_id, output_data.clone());
+        if let Output::Foundry(foundry_output) = &output_data.output {
+            account_details
+                .native_token_foundries
+                .insert(foundry_id, foundry_output.clone());
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
 
-        Ok((existing_alias_output_data, existing_foundry_output_data))
+        Ok(output_data)
     }
 }
@@ -9,6 +9,7 @@ use crate::{
     },
 };
 
+pub(crate) mod destroy_alias;
 pub(crate) mod melt_native_token;
 
 impl Account {
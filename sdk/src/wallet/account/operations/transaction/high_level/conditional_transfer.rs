@@ -0,0 +1,349 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use getset::Getters;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
+            },
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, NativeToken, NftId, NftOutputBuilder, Output,
+            OutputId, RentStructure,
+        },
+        ConvertTo,
+    },
+    wallet::{
+        account::{
+            constants::DEFAULT_EXPIRATION_TIME, operations::transaction::Transaction, Account, TransactionOptions,
+        },
+        Error, Result,
+    },
+};
+
+/// The asset of a conditional transfer.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConditionalTransferAsset {
+    /// Native tokens, sent in a basic output.
+    NativeTokens(Vec<NativeToken>),
+    /// An NFT of the account.
+    Nft(NftId),
+}
+
+/// Params for `conditional_transfer()`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionalTransferParams {
+    /// Bech32 encoded address of the recipient
+    #[getset(get = "pub")]
+    address: Bech32Address,
+    /// The asset that is transferred
+    #[getset(get = "pub")]
+    asset: ConditionalTransferAsset,
+    /// Bech32 encoded return address, to which the storage deposit has to be returned and which gets the asset back
+    /// after the expiration. Default will use the first address of the account
+    #[getset(get = "pub")]
+    return_address: Option<Bech32Address>,
+    /// Expiration in seconds, after which the output will be available for the sender again, if not claimed by the
+    /// recipient before. Default is 1 day
+    #[getset(get = "pub")]
+    expiration: Option<u32>,
+}
+
+impl ConditionalTransferParams {
+    /// Creates a new instance of [`ConditionalTransferParams`]
+    pub fn new(address: impl ConvertTo<Bech32Address>, asset: ConditionalTransferAsset) -> Result<Self> {
+        Ok(Self {
+            address: address.convert()?,
+            asset,
+            return_address: None,
+            expiration: None,
+        })
+    }
+
+    /// Set the return address and try convert to [`Bech32Address`]
+    pub fn try_with_return_address(mut self, return_address: impl ConvertTo<Bech32Address>) -> Result<Self> {
+        self.return_address = Some(return_address.convert()?);
+        Ok(self)
+    }
+
+    /// Set the return address
+    pub fn with_return_address(mut self, return_address: impl Into<Option<Bech32Address>>) -> Self {
+        self.return_address = return_address.into();
+        self
+    }
+
+    /// Set the expiration in seconds
+    pub fn with_expiration(mut self, expiration_secs: impl Into<Option<u32>>) -> Self {
+        self.expiration = expiration_secs.into();
+        self
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends native tokens or NFTs in outputs with a [`StorageDepositReturnUnlockCondition`] and an
+    /// [`ExpirationUnlockCondition`]. The recipient can claim an output with
+    /// [Account::claim_conditional_transfer()](crate::wallet::Account::claim_conditional_transfer) before it expires
+    /// by returning the storage deposit, afterwards the asset is available for the sender again.
+    ///
+    /// For a swap, both parties send their asset to the other one and claim the received output once both outputs
+    /// are confirmed. The party that sends first should use the longer expiration, so it can't expire before the
+    /// counterpart is claimed.
+    /// ```ignore
+    /// let params = [ConditionalTransferParams::new(
+    ///     "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+    ///     ConditionalTransferAsset::Nft(nft_id),
+    /// )?
+    /// .with_expiration(2 * 86400)];
+    ///
+    /// let transaction = account.conditional_transfer(params, None).await?;
+    /// println!("Transaction sent: {}", transaction.transaction_id);
+    /// ```
+    pub async fn conditional_transfer<I: IntoIterator<Item = ConditionalTransferParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction>
+    where
+        I::IntoIter: Send,
+    {
+        let options = options.into();
+        let prepared_transaction = self.prepare_conditional_transfer(params, options.clone()).await?;
+
+        self.sign_and_submit_transaction(prepared_transaction, options).await
+    }
+
+    /// Prepares the transaction for
+    /// [Account::conditional_transfer()](crate::wallet::Account::conditional_transfer).
+    pub async fn prepare_conditional_transfer<I: IntoIterator<Item = ConditionalTransferParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData>
+    where
+        I::IntoIter: Send,
+    {
+        log::debug!("[TRANSACTION] prepare_conditional_transfer");
+        let rent_structure = self.client().get_rent_structure().await?;
+        let token_supply = self.client().get_token_supply().await?;
+
+        let account_addresses = self.addresses().await?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+
+        let local_time = self.client().get_time_checked().await?;
+
+        let mut outputs = Vec::new();
+        for ConditionalTransferParams {
+            address,
+            asset,
+            return_address,
+            expiration,
+        } in params
+        {
+            self.client().bech32_hrp_matches(address.hrp()).await?;
+            let return_address = return_address
+                .map(|addr| {
+                    if address.hrp() != addr.hrp() {
+                        Err(crate::client::Error::Bech32HrpMismatch {
+                            provided: addr.hrp().to_string(),
+                            expected: address.hrp().to_string(),
+                        })?;
+                    }
+                    Ok::<_, Error>(addr)
+                })
+                .transpose()?
+                .unwrap_or(default_return_address.address);
+
+            let asset_output = match asset {
+                ConditionalTransferAsset::NativeTokens(native_tokens) => {
+                    BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                        .with_native_tokens(native_tokens)
+                        .add_unlock_condition(AddressUnlockCondition::new(address))
+                        .finish_output(token_supply)?
+                }
+                ConditionalTransferAsset::Nft(nft_id) => {
+                    let nft_output_data = self
+                        .unspent_nft_output(&nft_id)
+                        .await?
+                        .ok_or(Error::NftNotFoundInUnspentOutputs)?;
+
+                    NftOutputBuilder::from(nft_output_data.output.as_nft())
+                        .with_nft_id(nft_id)
+                        .with_unlock_conditions([AddressUnlockCondition::new(address)])
+                        .finish_output(token_supply)?
+                }
+            };
+
+            let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
+                local_time + expiration_time
+            });
+
+            outputs.push(conditional_transfer_output(
+                &asset_output,
+                return_address.into(),
+                expiration_time,
+                rent_structure,
+                token_supply,
+            )?);
+        }
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Claims an output that was sent with
+    /// [Account::conditional_transfer()](crate::wallet::Account::conditional_transfer) to this account, by returning
+    /// the storage deposit to the sender.
+    pub async fn claim_conditional_transfer(&self, output_id: OutputId) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_claim_conditional_transfer(output_id).await?;
+
+        self.sign_and_submit_transaction(prepared_transaction, None).await
+    }
+
+    /// Prepares the transaction for
+    /// [Account::claim_conditional_transfer()](crate::wallet::Account::claim_conditional_transfer). Fails if the
+    /// output isn't a conditional transfer to this account or if it's already expired.
+    pub async fn prepare_claim_conditional_transfer(
+        &self,
+        output_id: OutputId,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_claim_conditional_transfer");
+        let local_time = self.client().get_time_checked().await?;
+        let account_addresses = self.addresses().await?;
+        let output_data = self.details().await.unspent_outputs.get(&output_id).cloned();
+
+        let claimable = output_data
+            .as_ref()
+            .and_then(|output_data| output_data.output.unlock_conditions())
+            .is_some_and(|unlock_conditions| {
+                unlock_conditions.storage_deposit_return().is_some()
+                    && unlock_conditions.expiration().is_some()
+                    && !unlock_conditions.is_expired(local_time)
+                    && unlock_conditions.address().is_some_and(|address_unlock_condition| {
+                        account_addresses.iter().any(|account_address| {
+                            account_address.address.inner() == address_unlock_condition.address()
+                        })
+                    })
+            });
+
+        if !claimable {
+            return Err(Error::ConditionalTransferNotClaimable(output_id));
+        }
+
+        self.prepare_claim_outputs([output_id]).await
+    }
+}
+
+/// Builds the output of a conditional transfer from the `asset_output`, which is owned by the recipient: it requires
+/// the minimum storage deposit, which has to be returned to `return_address` when claiming it, and it's available
+/// for `return_address` again after `expiration_time`.
+pub(crate) fn conditional_transfer_output(
+    asset_output: &Output,
+    return_address: Address,
+    expiration_time: u32,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> Result<Output> {
+    // The storage deposit doesn't depend on the return amount, so it's computed with a placeholder first.
+    let placeholder_return = StorageDepositReturnUnlockCondition::new(
+        return_address,
+        MinimumStorageDepositBasicOutput::new(rent_structure, token_supply).finish()?,
+        token_supply,
+    )?;
+    let expiration = ExpirationUnlockCondition::new(return_address, expiration_time)?;
+
+    let output = match asset_output {
+        Output::Basic(basic_output) => {
+            let builder = BasicOutputBuilder::from(basic_output)
+                .with_minimum_storage_deposit(rent_structure)
+                .add_unlock_condition(placeholder_return)
+                .add_unlock_condition(expiration);
+            let amount = builder.clone().finish_output(token_supply)?.amount();
+
+            builder
+                .replace_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                    return_address,
+                    amount,
+                    token_supply,
+                )?)
+                .finish_output(token_supply)?
+        }
+        Output::Nft(nft_output) => {
+            let builder = NftOutputBuilder::from(nft_output)
+                .with_minimum_storage_deposit(rent_structure)
+                .add_unlock_condition(placeholder_return)
+                .add_unlock_condition(expiration);
+            let amount = builder.clone().finish_output(token_supply)?.amount();
+
+            builder
+                .replace_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                    return_address,
+                    amount,
+                    token_supply,
+                )?)
+                .finish_output(token_supply)?
+        }
+        _ => return Err(Error::InvalidOutputKind(asset_output.kind_str().to_owned())),
+    };
+
+    output.verify_storage_deposit(rent_structure, token_supply)?;
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{Rent, TokenId},
+        protocol::protocol_parameters,
+        rand::{bytes::rand_bytes_array, output::rand_nft_output},
+    };
+
+    #[test]
+    fn conditional_transfer_outputs() {
+        let protocol_parameters = protocol_parameters();
+        let rent_structure = *protocol_parameters.rent_structure();
+        let token_supply = protocol_parameters.token_supply();
+        let recipient = Address::from(Ed25519Address::new([1; 32]));
+        let return_address = Address::from(Ed25519Address::new([2; 32]));
+
+        let native_tokens_output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_native_token(NativeToken::new(TokenId::from(rand_bytes_array()), U256::from(100)).unwrap())
+            .add_unlock_condition(AddressUnlockCondition::new(recipient))
+            .finish_output(token_supply)
+            .unwrap();
+        let nft_output = NftOutputBuilder::from(&rand_nft_output(token_supply))
+            .with_unlock_conditions([AddressUnlockCondition::new(recipient)])
+            .finish_output(token_supply)
+            .unwrap();
+
+        for asset_output in [native_tokens_output, nft_output] {
+            let output =
+                conditional_transfer_output(&asset_output, return_address, 100, rent_structure, token_supply).unwrap();
+            let unlock_conditions = output.unlock_conditions().unwrap();
+
+            assert_eq!(output.kind(), asset_output.kind());
+            assert_eq!(output.native_tokens(), asset_output.native_tokens());
+            // Only the storage deposit is locked and all of it is returned.
+            assert_eq!(output.amount(), output.rent_cost(&rent_structure));
+            assert_eq!(unlock_conditions.address().unwrap().address(), &recipient);
+            let storage_deposit_return = unlock_conditions.storage_deposit_return().unwrap();
+            assert_eq!(storage_deposit_return.return_address(), &return_address);
+            assert_eq!(storage_deposit_return.amount(), output.amount());
+            let expiration = unlock_conditions.expiration().unwrap();
+            assert_eq!(expiration.return_address(), &return_address);
+            assert_eq!(expiration.timestamp(), 100);
+        }
+    }
+}
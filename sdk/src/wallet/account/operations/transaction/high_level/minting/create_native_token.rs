@@ -4,6 +4,8 @@
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{NativeTokenCreationProgressEvent, WalletEvent};
 use crate::{
     client::{
         api::{PreparedTransactionData, PreparedTransactionDataDto},
@@ -13,8 +15,10 @@ use crate::{
         address::AliasAddress,
         output::{
             feature::MetadataFeature, unlock_condition::ImmutableAliasAddressUnlockCondition, AliasId,
-            AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme,
+            AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, OutputId, SimpleTokenScheme, TokenId,
+            TokenScheme,
         },
+        payload::transaction::TransactionEssence,
     },
     wallet::account::{
         types::{Transaction, TransactionDto},
@@ -61,6 +65,17 @@ impl From<&CreateNativeTokenTransaction> for CreateNativeTokenTransactionDto {
     }
 }
 
+/// The result of [Account::create_native_token_with_alias()](crate::wallet::Account::create_native_token_with_alias)
+#[derive(Debug)]
+pub struct CreateNativeTokenWithAliasTransactions {
+    pub alias_id: AliasId,
+    pub token_id: TokenId,
+    /// The transaction that created the alias, if no suitable alias existed.
+    pub alias_transaction: Option<Transaction>,
+    /// The transaction that created the foundry and minted the native tokens.
+    pub transaction: Transaction,
+}
+
 /// The result of preparing a transaction to create a native token
 #[derive(Debug)]
 pub struct PreparedCreateNativeTokenTransaction {
@@ -123,6 +138,85 @@ where
             })
     }
 
+    /// Creates a new foundry output with minted native tokens like
+    /// [Account::create_native_token()](crate::wallet::Account::create_native_token), but first creates an alias if
+    /// no alias id is provided and the account doesn't own one yet.
+    ///
+    /// The transactions depend on each other, so each one is awaited to be included and the account is synced before
+    /// continuing. Emits [`WalletEvent::NativeTokenCreationProgress`](crate::wallet::events::types::WalletEvent)
+    /// events for each step.
+    pub async fn create_native_token_with_alias(
+        &self,
+        params: CreateNativeTokenParams,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<CreateNativeTokenWithAliasTransactions> {
+        log::debug!("[TRANSACTION] create_native_token_with_alias");
+        let options = options.into();
+
+        let (alias_id, alias_transaction) = match self.get_alias_output(params.alias_id).await {
+            Some((alias_id, _)) => (alias_id, None),
+            None if params.alias_id.is_some() => {
+                return Err(crate::wallet::Error::MintingFailed("Missing alias output".to_string()));
+            }
+            None => {
+                let transaction = self.create_alias_output(None, options.clone()).await?;
+                #[cfg(feature = "events")]
+                self.emit_native_token_creation_progress(NativeTokenCreationProgressEvent::CreatingAlias {
+                    transaction_id: transaction.transaction_id,
+                })
+                .await;
+                self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+                    .await?;
+                self.sync(None).await?;
+
+                let alias_id = created_alias_id(&transaction)
+                    .ok_or_else(|| crate::wallet::Error::MintingFailed("Missing created alias output".to_string()))?;
+                #[cfg(feature = "events")]
+                self.emit_native_token_creation_progress(NativeTokenCreationProgressEvent::AliasCreated { alias_id })
+                    .await;
+                (alias_id, Some(transaction))
+            }
+        };
+
+        let CreateNativeTokenTransaction { token_id, transaction } = self
+            .create_native_token(
+                CreateNativeTokenParams {
+                    alias_id: Some(alias_id),
+                    ..params
+                },
+                options,
+            )
+            .await?;
+        #[cfg(feature = "events")]
+        self.emit_native_token_creation_progress(NativeTokenCreationProgressEvent::CreatingFoundry {
+            transaction_id: transaction.transaction_id,
+            token_id,
+        })
+        .await;
+        self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+        self.sync(None).await?;
+        #[cfg(feature = "events")]
+        self.emit_native_token_creation_progress(NativeTokenCreationProgressEvent::FoundryCreated { token_id })
+            .await;
+
+        Ok(CreateNativeTokenWithAliasTransactions {
+            alias_id,
+            token_id,
+            alias_transaction,
+            transaction,
+        })
+    }
+
+    #[cfg(feature = "events")]
+    async fn emit_native_token_creation_progress(&self, event: NativeTokenCreationProgressEvent) {
+        self.emit(
+            self.details().await.index,
+            WalletEvent::NativeTokenCreationProgress(event),
+        )
+        .await;
+    }
+
     /// Prepares the transaction for
     /// [Account::create_native_token()](crate::wallet::Account::create_native_token).
     pub async fn prepare_create_native_token(
@@ -184,3 +278,20 @@ where
         }
     }
 }
+
+// The new alias has a null alias ID in the transaction, its ID is derived from the output ID.
+fn created_alias_id(transaction: &Transaction) -> Option<AliasId> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    essence
+        .outputs()
+        .iter()
+        .enumerate()
+        .find_map(|(index, output)| match output {
+            Output::Alias(alias_output) if alias_output.alias_id().is_null() => {
+                OutputId::new(transaction.transaction_id, index as u16)
+                    .ok()
+                    .map(|output_id| AliasId::from(&output_id))
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,189 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::mint_nfts::MintNftParams;
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Bech32Address, NftAddress},
+        output::{NftId, Output, OutputId},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{operations::transaction::Transaction, Account, TransactionOptions},
+        Error as WalletError,
+    },
+};
+
+/// The number of NFTs that are minted in a single transaction by
+/// [Account::mint_nft_collection()](crate::wallet::Account::mint_nft_collection).
+///
+/// The collection NFT and a remainder also need to fit into the transaction.
+pub const NFT_COLLECTION_ITEMS_PER_TRANSACTION: usize = 50;
+
+/// The status of an NFT of a collection minted with
+/// [Account::mint_nft_collection()](crate::wallet::Account::mint_nft_collection).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum NftCollectionItemStatus {
+    /// The NFT was minted and the transaction got included.
+    #[serde(rename_all = "camelCase")]
+    Minted {
+        transaction_id: TransactionId,
+        nft_id: NftId,
+    },
+    /// The transaction that should have minted the NFT failed or didn't get included.
+    Failed { error: String },
+    /// The NFT wasn't minted, because an earlier transaction failed.
+    Skipped,
+}
+
+/// The result of [Account::mint_nft_collection()](crate::wallet::Account::mint_nft_collection), with the status of
+/// each NFT in the order of the params.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftCollectionMintingResult {
+    /// The status of each NFT.
+    pub items: Vec<NftCollectionItemStatus>,
+}
+
+impl NftCollectionMintingResult {
+    /// Returns the IDs of all minted NFTs.
+    pub fn nft_ids(&self) -> Vec<NftId> {
+        self.items
+            .iter()
+            .filter_map(|status| match status {
+                NftCollectionItemStatus::Minted { nft_id, .. } => Some(*nft_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns whether all NFTs were minted.
+    pub fn is_complete(&self) -> bool {
+        self.items
+            .iter()
+            .all(|status| matches!(status, NftCollectionItemStatus::Minted { .. }))
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Mints NFTs of a collection, issued by the collection NFT which needs to be owned by the account.
+    ///
+    /// The NFTs are minted in chunks of [`NFT_COLLECTION_ITEMS_PER_TRANSACTION`], each transaction is awaited to be
+    /// included before the next one is sent, since the collection NFT needs to be unlocked in all of them. The issuer
+    /// feature of the params is set to the collection NFT if it's not set already. If a transaction fails, the
+    /// remaining NFTs are skipped and the result contains the status of each NFT, so the failed and skipped ones can be
+    /// minted again.
+    /// ```ignore
+    /// let params = (0..150)
+    ///     .map(|index| MintNftParams::new().with_immutable_metadata(format!("item {index}").into_bytes()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let result = account.mint_nft_collection(collection_nft_id, params, None).await?;
+    /// println!("Minted NFTs: {:?}", result.nft_ids());
+    /// ```
+    pub async fn mint_nft_collection(
+        &self,
+        collection_nft_id: NftId,
+        params: Vec<MintNftParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<NftCollectionMintingResult> {
+        log::debug!("[TRANSACTION] mint_nft_collection");
+        let options = options.into();
+
+        if self.unspent_nft_output(&collection_nft_id).await?.is_none() {
+            return Err(WalletError::NftNotFoundInUnspentOutputs);
+        }
+
+        let issuer = Bech32Address::new(
+            self.client().get_bech32_hrp().await?,
+            NftAddress::new(collection_nft_id),
+        );
+        let mut items = Vec::with_capacity(params.len());
+        let mut failed = false;
+
+        for chunk in params.chunks(NFT_COLLECTION_ITEMS_PER_TRANSACTION) {
+            if failed {
+                items.extend(chunk.iter().map(|_| NftCollectionItemStatus::Skipped));
+                continue;
+            }
+
+            let chunk_params = chunk
+                .iter()
+                .cloned()
+                .map(|params| {
+                    if params.issuer().is_some() {
+                        params
+                    } else {
+                        params.with_issuer(issuer)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            match self.mint_nft_collection_chunk(chunk_params, options.clone()).await {
+                Ok(transaction) => {
+                    let nft_ids = minted_nft_ids(&transaction);
+                    log::debug!(
+                        "[TRANSACTION] minted {} NFTs of collection {collection_nft_id} in {}",
+                        nft_ids.len(),
+                        transaction.transaction_id
+                    );
+                    items.extend(nft_ids.into_iter().map(|nft_id| NftCollectionItemStatus::Minted {
+                        transaction_id: transaction.transaction_id,
+                        nft_id,
+                    }));
+                }
+                Err(error) => {
+                    log::debug!("[TRANSACTION] minting NFTs of collection {collection_nft_id} failed: {error}");
+                    let error = error.to_string();
+                    items.extend(
+                        chunk
+                            .iter()
+                            .map(|_| NftCollectionItemStatus::Failed { error: error.clone() }),
+                    );
+                    failed = true;
+                }
+            }
+        }
+
+        Ok(NftCollectionMintingResult { items })
+    }
+
+    // Mints the NFTs, waits for the transaction to get included and syncs, so the new collection NFT output can be used
+    // for the next chunk.
+    async fn mint_nft_collection_chunk(
+        &self,
+        params: Vec<MintNftParams>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let transaction = self.mint_nfts(params, options).await?;
+        self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+        self.sync(None).await?;
+        Ok(transaction)
+    }
+}
+
+// The new NFTs have a null NFT ID in the transaction, their ID is derived from the output ID.
+fn minted_nft_ids(transaction: &Transaction) -> Vec<NftId> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    essence
+        .outputs()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, output)| match output {
+            Output::Nft(nft_output) if nft_output.nft_id().is_null() => {
+                OutputId::new(transaction.transaction_id, index as u16)
+                    .ok()
+                    .map(|output_id| NftId::from(&output_id))
+            }
+            _ => None,
+        })
+        .collect()
+}
@@ -3,4 +3,5 @@
 
 pub(crate) mod create_native_token;
 pub(crate) mod mint_native_token;
+pub(crate) mod mint_nft_collection;
 pub(crate) mod mint_nfts;
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod burning_melting;
+pub(crate) mod conditional_transfer;
 pub(crate) mod create_alias;
 pub(crate) mod minting;
 pub(crate) mod send;
@@ -12,7 +12,7 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, MinimumStorageDepositBasicOutput,
+            BasicOutputBuilder, MinimumStorageDepositBasicOutput, Output,
         },
         ConvertTo,
     },
@@ -99,6 +99,8 @@ where
     /// Calls [Account::send_outputs()](crate::wallet::Account::send_outputs) internally.
     /// The options may define the remainder value strategy or custom inputs.
     /// Addresses provided with [`SendParams`] need to be bech32-encoded.
+    /// Fails if the outputs exceed the max output count or block size, use
+    /// [Account::send_split()](crate::wallet::Account::send_split) to send them in multiple transactions.
     /// ```ignore
     /// let params = [SendParams::new(
     ///     "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
@@ -137,6 +139,20 @@ where
     {
         log::debug!("[TRANSACTION] prepare_send");
         let options = options.into();
+        let outputs = self.build_send_outputs(params, options.as_ref()).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    // Builds the outputs for the send params.
+    pub(crate) async fn build_send_outputs<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>>
+    where
+        I::IntoIter: Send,
+    {
         let rent_structure = self.client().get_rent_structure().await?;
         let token_supply = self.client().get_token_supply().await?;
 
@@ -189,7 +205,7 @@ where
                     .with_expiration()?
                     .finish()?;
 
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
+                if !options.map(|o| o.allow_micro_amount).unwrap_or_default() {
                     return Err(Error::InsufficientFunds {
                         available: amount,
                         required: amount + storage_deposit_amount,
@@ -216,6 +232,47 @@ where
             }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
+    }
+
+    /// Sends a certain amount of base coins to a single address, which can only be unlocked after the wall-clock
+    /// `unix_timestamp` in seconds.
+    ///
+    /// The timestamp has to be after the timestamp of the latest milestone, otherwise the output could be unlocked
+    /// immediately.
+    pub async fn send_with_timelock(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        amount: u64,
+        unix_timestamp: u64,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let options = options.into();
+        let prepared_transaction = self
+            .prepare_send_with_timelock(address, amount, unix_timestamp, options.clone())
+            .await?;
+
+        self.sign_and_submit_transaction(prepared_transaction, options).await
+    }
+
+    /// Prepares the transaction for
+    /// [Account::send_with_timelock()](crate::wallet::Account::send_with_timelock).
+    pub async fn prepare_send_with_timelock(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+        amount: u64,
+        unix_timestamp: u64,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_with_timelock");
+        let address = address.convert()?;
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+
+        let output = self
+            .client()
+            .build_timelocked_basic_output(address, amount, unix_timestamp)
+            .await?;
+
+        self.prepare_transaction([output], options).await
     }
 }
@@ -1,21 +1,26 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{hash_map::Values, HashSet};
+use std::collections::{hash_map::Values, BTreeMap, HashSet};
 
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::input_selection::{is_alias_transition, Burn, InputSelection, Selected},
+        api::input_selection::{
+            is_alias_transition, Burn, ChangeStrategy, CoinSelectionStrategy, InputSelection, InputSelectionStrategy,
+            SeededRandomOrder, Selected,
+        },
         secret::{types::InputSigningData, SecretManage},
     },
     types::block::{
-        address::Address,
-        output::{Output, OutputId},
+        address::{Address, Bech32Address},
+        output::{Output, OutputId, TokenId},
     },
     wallet::account::{
+        constants::DEFAULT_EXPIRATION_SAFETY_MARGIN,
         operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
+        TransactionOptions,
     },
 };
 
@@ -23,7 +28,46 @@ impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    /// Selects inputs for a transaction with the [`TransactionOptions`], see [`Account::select_inputs()`].
+    pub(crate) async fn select_inputs_with_options(
+        &self,
+        outputs: Vec<Output>,
+        options: Option<&TransactionOptions>,
+        remainder_address: Option<Address>,
+        lock_inputs: bool,
+    ) -> crate::wallet::Result<Selected> {
+        self.select_inputs(
+            outputs,
+            options
+                .and_then(|options| options.custom_inputs.as_ref())
+                .map(|inputs| HashSet::from_iter(inputs.clone())),
+            options
+                .and_then(|options| options.mandatory_inputs.as_ref())
+                .map(|inputs| HashSet::from_iter(inputs.clone())),
+            remainder_address,
+            options.and_then(|options| options.burn.as_ref()),
+            options
+                .map(|options| options.coin_selection_strategy)
+                .unwrap_or_default(),
+            options.map(|options| options.change_strategy).unwrap_or_default(),
+            options
+                .and_then(|options| options.native_tokens_remainder_address.as_ref())
+                .map(|address| *address.inner()),
+            options
+                .map(|options| &options.token_remainder_addresses)
+                .unwrap_or(&BTreeMap::new()),
+            options
+                .and_then(|options| options.expiration_safety_margin)
+                .unwrap_or(DEFAULT_EXPIRATION_SAFETY_MARGIN),
+            options.and_then(|options| options.input_selection_seed),
+            lock_inputs,
+        )
+        .await
+    }
+
+    /// Selects inputs for a transaction and, if `lock_inputs` is set, locks them in the account, so they don't get
+    /// used again
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
@@ -31,6 +75,13 @@ where
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        coin_selection_strategy: CoinSelectionStrategy,
+        change_strategy: ChangeStrategy,
+        native_tokens_remainder_address: Option<Address>,
+        token_remainder_addresses: &BTreeMap<TokenId, Bech32Address>,
+        expiration_safety_margin: u32,
+        input_selection_seed: Option<u64>,
+        lock_inputs: bool,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -71,7 +122,7 @@ where
 
         // Filter inputs to not include inputs that require additional outputs for storage deposit return or could be
         // still locked.
-        let available_outputs_signing_data = filter_inputs(
+        let mut available_outputs_signing_data = filter_inputs(
             &account_details,
             account_details.unspent_outputs.values(),
             current_time,
@@ -81,6 +132,18 @@ where
             mandatory_inputs.as_ref(),
         )?;
 
+        // The unspent outputs are unordered, a seeded selection starts from the same order every time.
+        let strategy: Box<dyn InputSelectionStrategy> = match input_selection_seed {
+            Some(seed) => {
+                available_outputs_signing_data.sort_unstable_by_key(|input| *input.output_id());
+                match coin_selection_strategy {
+                    CoinSelectionStrategy::Random => Box::new(SeededRandomOrder::new(seed)),
+                    strategy => Box::new(strategy),
+                }
+            }
+            None => Box::new(coin_selection_strategy),
+        };
+
         // if custom inputs are provided we should only use them (validate if we have the outputs in this account and
         // that the amount is enough)
         if let Some(custom_inputs) = custom_inputs {
@@ -100,12 +163,21 @@ where
                 protocol_parameters.clone(),
             )
             .required_inputs(custom_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .strategy(strategy)
+            .change_strategy(change_strategy)
+            .timestamp(current_time)
+            .expiration_safety_margin(expiration_safety_margin);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
             }
 
+            input_selection = input_selection.native_tokens_remainder_address(native_tokens_remainder_address);
+            for (token_id, address) in token_remainder_addresses {
+                input_selection = input_selection.token_remainder_address(*token_id, *address.inner());
+            }
+
             if let Some(burn) = burn {
                 input_selection = input_selection.burn(burn.clone());
             }
@@ -113,8 +185,10 @@ where
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -135,12 +209,21 @@ where
                 protocol_parameters.clone(),
             )
             .required_inputs(mandatory_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .strategy(strategy)
+            .change_strategy(change_strategy)
+            .timestamp(current_time)
+            .expiration_safety_margin(expiration_safety_margin);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
             }
 
+            input_selection = input_selection.native_tokens_remainder_address(native_tokens_remainder_address);
+            for (token_id, address) in token_remainder_addresses {
+                input_selection = input_selection.token_remainder_address(*token_id, *address.inner());
+            }
+
             if let Some(burn) = burn {
                 input_selection = input_selection.burn(burn.clone());
             }
@@ -148,13 +231,10 @@ where
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
-            }
-
-            // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -166,12 +246,21 @@ where
             addresses,
             protocol_parameters.clone(),
         )
-        .forbidden_inputs(forbidden_inputs);
+        .forbidden_inputs(forbidden_inputs)
+        .strategy(strategy)
+        .change_strategy(change_strategy)
+        .timestamp(current_time)
+        .expiration_safety_margin(expiration_safety_margin);
 
         if let Some(address) = remainder_address {
             input_selection = input_selection.remainder_address(address);
         }
 
+        input_selection = input_selection.native_tokens_remainder_address(native_tokens_remainder_address);
+        for (token_id, address) in token_remainder_addresses {
+            input_selection = input_selection.token_remainder_address(*token_id, *address.inner());
+        }
+
         if let Some(burn) = burn {
             input_selection = input_selection.burn(burn.clone());
         }
@@ -194,9 +283,11 @@ where
         };
 
         // lock outputs so they don't get used by another transaction
-        for output in &selected_transaction_data.inputs {
-            log::debug!("[TRANSACTION] locking: {}", output.output_id());
-            account_details.locked_outputs.insert(*output.output_id());
+        if lock_inputs {
+            for output in &selected_transaction_data.inputs {
+                log::debug!("[TRANSACTION] locking: {}", output.output_id());
+                account_details.locked_outputs.insert(*output.output_id());
+            }
         }
 
         Ok(selected_transaction_data)
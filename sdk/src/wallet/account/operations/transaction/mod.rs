@@ -2,14 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod build_transaction;
+pub(crate) mod estimate;
+pub(crate) mod external_signing;
 pub(crate) mod high_level;
 mod input_selection;
 mod options;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
 mod sign_transaction;
+pub(crate) mod split;
 pub(crate) mod submit_transaction;
 
+use std::collections::BTreeSet;
+
 pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
 use crate::{
     client::{
@@ -111,6 +116,7 @@ where
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
                 self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                self.release_pending_spend(&prepared_transaction_data.essence).await;
                 return Err(err);
             }
         };
@@ -131,6 +137,17 @@ where
         );
         let options = options.into();
 
+        if let Err(err) = self
+            .verify_spend_policy(
+                signed_transaction_data.transaction_payload.essence(),
+                &signed_transaction_data.inputs_data,
+            )
+            .await
+        {
+            self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
+
         // Validate transaction before sending and storing it
         let local_time = self.client().get_time_checked().await?;
 
@@ -147,6 +164,8 @@ where
             );
             // unlock outputs so they are available for a new transaction
             self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+            self.release_pending_spend(signed_transaction_data.transaction_payload.essence())
+                .await;
             return Err(Error::TransactionSemantic(conflict).into());
         }
 
@@ -185,6 +204,7 @@ where
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: options.and_then(|o| o.note),
+            tags: BTreeSet::new(),
             inputs,
         };
 
@@ -197,6 +217,11 @@ where
             log::debug!("[TRANSACTION] storing account {}", account_details.index());
             self.save(Some(&account_details)).await?;
         }
+        // The spend policy reads the account details while the pending spends are locked
+        drop(account_details);
+
+        // The transaction is counted by the spend policy as a transaction of the account now
+        self.release_pending_spend(transaction.payload.essence()).await;
 
         Ok(transaction)
     }
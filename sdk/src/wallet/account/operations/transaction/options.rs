@@ -1,12 +1,15 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::input_selection::{Burn, BurnDto, ChangeStrategy, CoinSelectionStrategy},
     types::block::{
-        output::OutputId,
+        address::Bech32Address,
+        output::{OutputId, TokenId},
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
         Error,
     },
@@ -25,6 +28,20 @@ pub struct TransactionOptions {
     pub burn: Option<Burn>,
     pub note: Option<String>,
     pub allow_micro_amount: bool,
+    /// The strategy to select the inputs that fulfill the amount requirement.
+    pub coin_selection_strategy: CoinSelectionStrategy,
+    /// How the remainder is created, e.g. split into multiple outputs.
+    pub change_strategy: ChangeStrategy,
+    /// The address that receives the native token remainder, instead of the base coin remainder address.
+    pub native_tokens_remainder_address: Option<Bech32Address>,
+    /// The addresses that receive the remainder of specific native tokens, they take precedence over
+    /// `native_tokens_remainder_address`.
+    pub token_remainder_addresses: BTreeMap<TokenId, Bech32Address>,
+    /// Inputs that expire within this many seconds after the latest milestone timestamp are not used, 60 by default.
+    pub expiration_safety_margin: Option<u32>,
+    /// Makes the input selection reproducible: the available inputs are ordered by output id before they are
+    /// selected and the [`CoinSelectionStrategy::Random`] order is derived from this seed.
+    pub input_selection_seed: Option<u64>,
 }
 
 impl TransactionOptions {
@@ -38,6 +55,12 @@ impl TransactionOptions {
             burn: value.burn.map(Burn::try_from).transpose()?,
             note: value.note,
             allow_micro_amount: value.allow_micro_amount,
+            coin_selection_strategy: value.coin_selection_strategy,
+            change_strategy: value.change_strategy,
+            native_tokens_remainder_address: value.native_tokens_remainder_address,
+            token_remainder_addresses: value.token_remainder_addresses,
+            expiration_safety_margin: value.expiration_safety_margin,
+            input_selection_seed: value.input_selection_seed,
         })
     }
 }
@@ -60,6 +83,18 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
+    #[serde(default)]
+    pub change_strategy: ChangeStrategy,
+    #[serde(default)]
+    pub native_tokens_remainder_address: Option<Bech32Address>,
+    #[serde(default)]
+    pub token_remainder_addresses: BTreeMap<TokenId, Bech32Address>,
+    #[serde(default)]
+    pub expiration_safety_margin: Option<u32>,
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub input_selection_seed: Option<u64>,
 }
 
 #[allow(clippy::enum_variant_names)]
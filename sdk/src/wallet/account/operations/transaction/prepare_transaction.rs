@@ -1,8 +1,6 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
-
 use instant::Instant;
 use packable::bounded::TryIntoBoundedU16Error;
 
@@ -101,19 +99,7 @@ where
         };
 
         let selected_transaction_data = self
-            .select_inputs(
-                outputs,
-                options
-                    .as_ref()
-                    .and_then(|options| options.custom_inputs.as_ref())
-                    .map(|inputs| HashSet::from_iter(inputs.clone())),
-                options
-                    .as_ref()
-                    .and_then(|options| options.mandatory_inputs.as_ref())
-                    .map(|inputs| HashSet::from_iter(inputs.clone())),
-                remainder_address,
-                options.as_ref().and_then(|options| options.burn.as_ref()),
-            )
+            .select_inputs_with_options(outputs, options.as_ref(), remainder_address, true)
             .await?;
 
         let prepared_transaction_data = match self
@@ -128,6 +114,17 @@ where
             }
         };
 
+        if let Err(err) = self
+            .verify_spend_policy(
+                &prepared_transaction_data.essence,
+                &prepared_transaction_data.inputs_data,
+            )
+            .await
+        {
+            self.unlock_inputs(&selected_transaction_data.inputs).await?;
+            return Err(err);
+        }
+
         log::debug!(
             "[TRANSACTION] finished prepare_transaction in {:.2?}",
             prepare_transaction_start_time.elapsed()
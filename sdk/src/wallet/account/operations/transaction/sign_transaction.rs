@@ -31,6 +31,19 @@ where
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+
+        {
+            let account_details = self.details().await;
+            if account_details.watch_only {
+                return Err(crate::wallet::Error::WatchOnlyAccount(account_details.alias.clone()));
+            }
+        }
+        self.verify_spend_policy(
+            &prepared_transaction_data.essence,
+            &prepared_transaction_data.inputs_data,
+        )
+        .await?;
+
         #[cfg(feature = "events")]
         self.emit(
             self.details().await.index,
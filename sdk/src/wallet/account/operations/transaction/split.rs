@@ -0,0 +1,425 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::PackableExt;
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SplitTransactionProgressEvent, WalletEvent};
+use crate::{
+    client::{
+        api::input_selection::{ChangeStrategy, Error as InputSelectionError},
+        secret::SecretManage,
+    },
+    types::block::{
+        address::Ed25519Address,
+        input::INPUT_COUNT_MAX,
+        output::{InputsCommitment, Output, OutputId, OUTPUT_COUNT_MAX},
+        parent::Parents,
+        payload::Payload,
+        signature::Ed25519Signature,
+        Block, BlockId,
+    },
+    wallet::{
+        account::{
+            operations::transaction::{high_level::send::SendParams, Transaction},
+            Account, ConsolidationParams, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+// The length of the block and transaction fields besides the inputs, unlocks, outputs and tagged data payload:
+// protocol version, max parents, payload length, nonce, payload kind, essence kind, network ID, input count, inputs
+// commitment, output count, payload length and unlock count.
+const TRANSACTION_BLOCK_OVERHEAD_LENGTH: usize = 1
+    + (1 + *Parents::COUNT_RANGE.end() as usize * BlockId::LENGTH)
+    + 4
+    + 8
+    + 4
+    + 1
+    + 8
+    + 2
+    + InputsCommitment::LENGTH
+    + 2
+    + 4
+    + 2;
+// The length of a UTXO input: input kind and output ID.
+const INPUT_LENGTH: usize = 1 + OutputId::LENGTH;
+// The length of a signature unlock: unlock kind, signature kind, public key and signature.
+const SIGNATURE_UNLOCK_LENGTH: usize = 1 + 1 + Ed25519Signature::PUBLIC_KEY_LENGTH + Ed25519Signature::SIGNATURE_LENGTH;
+// The length of a remainder, a basic output to an Ed25519 address without native tokens: output kind, amount, native
+// token count, unlock condition count, address unlock condition with its address and feature count.
+const REMAINDER_OUTPUT_LENGTH: usize = 1 + 8 + 1 + 1 + (1 + 1 + Ed25519Address::LENGTH) + 1;
+// The max length of the outputs of a transaction, leaving room for the max number of inputs, each unlocked by its own
+// signature.
+const OUTPUTS_LENGTH_MAX: usize = Block::LENGTH_MAX
+    - TRANSACTION_BLOCK_OVERHEAD_LENGTH
+    - INPUT_COUNT_MAX as usize * (INPUT_LENGTH + SIGNATURE_UNLOCK_LENGTH);
+
+/// A transaction sent by [`Account::send_split_plan()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitTransaction {
+    /// The sent transaction.
+    pub transaction: Transaction,
+    /// Whether the transaction only consolidates outputs of the account, so the next transaction of the plan can
+    /// consume them, instead of sending outputs of the plan.
+    pub consolidation: bool,
+}
+
+/// The transactions that a send is split into, see [`Account::plan_split_send()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitTransactionPlan {
+    /// The outputs of each transaction, in the order they are sent.
+    pub transactions: Vec<Vec<Output>>,
+    /// Whether the funds are spread over more outputs than the first transaction can consume, so they have to be
+    /// consolidated before.
+    pub consolidation_required: bool,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sends to many recipients like [`Account::send_with_params()`] and returns all sent transactions.
+    ///
+    /// The outputs are split into multiple transactions if they exceed the max output count or block size, see
+    /// [`Account::send_split_plan()`].
+    /// ```ignore
+    /// let params = recipients
+    ///     .into_iter()
+    ///     .map(|address| SendParams::new(1_000_000, address))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// for split_transaction in account.send_split(params, None).await? {
+    ///     println!("Transaction sent: {}", split_transaction.transaction.transaction_id);
+    /// }
+    /// ```
+    pub async fn send_split<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<SplitTransaction>>
+    where
+        I::IntoIter: Send,
+    {
+        let options = options.into();
+        let plan = self.plan_split_send(params, options.clone()).await?;
+
+        self.send_split_plan(plan, options).await
+    }
+
+    /// Plans how the outputs of [`Account::send_split()`] are split into transactions, without sending them or
+    /// locking any outputs.
+    pub async fn plan_split_send<I: IntoIterator<Item = SendParams> + Send>(
+        &self,
+        params: I,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<SplitTransactionPlan>
+    where
+        I::IntoIter: Send,
+    {
+        let options = options.into();
+        let outputs = self.build_send_outputs(params, options.as_ref()).await?;
+
+        self.plan_split_outputs(outputs, options).await
+    }
+
+    /// Plans how the outputs are split into transactions that don't exceed the max output count and block size,
+    /// leaving room for the remainder outputs of the `options`.
+    pub async fn plan_split_outputs(
+        &self,
+        outputs: impl Into<Vec<Output>> + Send,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<SplitTransactionPlan> {
+        log::debug!("[TRANSACTION] plan_split_outputs");
+        let options = options.into();
+        let transactions = split_outputs(
+            outputs.into(),
+            reserved_output_count(options.as_ref()),
+            outputs_length_max(options.as_ref()),
+        );
+
+        let consolidation_required = match transactions.first() {
+            Some(outputs) => {
+                self.estimate_outputs(outputs.clone(), options)
+                    .await?
+                    .consolidation_required
+            }
+            None => false,
+        };
+
+        Ok(SplitTransactionPlan {
+            transactions,
+            consolidation_required,
+        })
+    }
+
+    /// Sends the transactions of a [`SplitTransactionPlan`] and returns them in the order they were sent.
+    ///
+    /// The transactions can depend on the remainder of the previous one, so each one is awaited to be included and
+    /// the account is synced before the next one is sent. If the funds are spread over more outputs than a
+    /// transaction can consume, they are consolidated first, these transactions are returned as
+    /// [`SplitTransaction::consolidation`]. Custom and mandatory inputs and burns of the `options`
+    /// only apply to the first transaction. Emits
+    /// [`WalletEvent::SplitTransactionProgress`](crate::wallet::events::types::WalletEvent) events for each sent
+    /// transaction.
+    ///
+    /// If a transaction fails after others were already sent, [`Error::SplitTransactionFailed`] is returned with the
+    /// IDs of the sent transactions, so only the remaining outputs need to be sent again.
+    pub async fn send_split_plan(
+        &self,
+        plan: SplitTransactionPlan,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<SplitTransaction>> {
+        log::debug!("[TRANSACTION] send_split_plan");
+        let mut transactions = Vec::with_capacity(plan.transactions.len());
+
+        match self
+            .send_split_plan_transactions(plan, options.into(), &mut transactions)
+            .await
+        {
+            Ok(()) => Ok(transactions),
+            Err(error) if transactions.is_empty() => Err(error),
+            Err(error) => {
+                log::debug!(
+                    "[TRANSACTION] split transaction failed after sending {} transactions: {error}",
+                    transactions.len()
+                );
+                let (consolidations, transactions): (Vec<_>, Vec<_>) =
+                    transactions.iter().partition(|transaction| transaction.consolidation);
+                Err(Error::SplitTransactionFailed {
+                    transaction_ids: transactions
+                        .iter()
+                        .map(|split_transaction| split_transaction.transaction.transaction_id)
+                        .collect(),
+                    consolidation_ids: consolidations
+                        .iter()
+                        .map(|split_transaction| split_transaction.transaction.transaction_id)
+                        .collect(),
+                    error: Box::new(error),
+                })
+            }
+        }
+    }
+
+    // Sends the transactions of the plan and adds them to `transactions` once they are sent.
+    async fn send_split_plan_transactions(
+        &self,
+        plan: SplitTransactionPlan,
+        options: Option<TransactionOptions>,
+        transactions: &mut Vec<SplitTransaction>,
+    ) -> crate::wallet::Result<()> {
+        let count = plan.transactions.len();
+
+        for (index, outputs) in plan.transactions.into_iter().enumerate() {
+            let options = if index == 0 {
+                options.clone()
+            } else {
+                options.clone().map(|options| TransactionOptions {
+                    custom_inputs: None,
+                    mandatory_inputs: None,
+                    burn: None,
+                    ..options
+                })
+            };
+
+            let transaction = loop {
+                let error = match self.prepare_transaction(outputs.clone(), options.clone()).await {
+                    Ok(prepared) => break self.sign_and_submit_transaction(prepared, options.clone()).await?,
+                    Err(crate::wallet::Error::Client(error))
+                        if matches!(
+                            *error,
+                            crate::client::Error::InputSelection(InputSelectionError::InvalidInputCount(_))
+                        ) =>
+                    {
+                        crate::wallet::Error::Client(error)
+                    }
+                    Err(error) => return Err(error),
+                };
+
+                let consolidation = self
+                    .consolidate_outputs(ConsolidationParams::new().with_force(true))
+                    .await?;
+                // Consolidating a single input doesn't reduce the input count, so the send would never fit.
+                if consolidation.inputs.len() < 2 {
+                    return Err(error);
+                }
+                log::debug!(
+                    "[TRANSACTION] consolidated {} inputs for split transaction {index} in {}",
+                    consolidation.inputs.len(),
+                    consolidation.transaction_id
+                );
+                #[cfg(feature = "events")]
+                self.emit_split_transaction_progress(SplitTransactionProgressEvent::Consolidating {
+                    transaction_id: consolidation.transaction_id,
+                })
+                .await;
+                self.retry_transaction_until_included(&consolidation.transaction_id, None, None)
+                    .await?;
+                self.sync(None).await?;
+                transactions.push(SplitTransaction {
+                    transaction: consolidation,
+                    consolidation: true,
+                });
+            };
+
+            #[cfg(feature = "events")]
+            self.emit_split_transaction_progress(SplitTransactionProgressEvent::Sending {
+                transaction_id: transaction.transaction_id,
+                index,
+                count,
+            })
+            .await;
+            if index + 1 < count {
+                self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+                    .await?;
+                self.sync(None).await?;
+            }
+            transactions.push(SplitTransaction {
+                transaction,
+                consolidation: false,
+            });
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "events")]
+    async fn emit_split_transaction_progress(&self, event: SplitTransactionProgressEvent) {
+        self.emit(self.details().await.index, WalletEvent::SplitTransactionProgress(event))
+            .await;
+    }
+}
+
+// The number of remainder outputs that can be created by input selection with the options.
+fn reserved_output_count(options: Option<&TransactionOptions>) -> usize {
+    options.map_or(1, |options| {
+        let remainders = match options.change_strategy {
+            ChangeStrategy::Split(count) => usize::from(count).max(1),
+            ChangeStrategy::Single | ChangeStrategy::MergeIntoExisting => 1,
+        };
+        remainders
+            + usize::from(options.native_tokens_remainder_address.is_some())
+            + options.token_remainder_addresses.len()
+    })
+}
+
+// The max length of the outputs of a transaction with the options, leaving room for their remainder outputs and tagged
+// data payload.
+fn outputs_length_max(options: Option<&TransactionOptions>) -> usize {
+    let payload_length = options
+        .and_then(|options| options.tagged_data_payload.clone())
+        .map_or(0, |payload| Payload::from(payload).packed_len());
+
+    OUTPUTS_LENGTH_MAX.saturating_sub(reserved_output_count(options) * REMAINDER_OUTPUT_LENGTH + payload_length)
+}
+
+// Splits the outputs into chunks that fit into a single transaction together with `reserved_output_count` remainder
+// outputs and don't exceed `outputs_length_max`, keeping their order.
+fn split_outputs(outputs: Vec<Output>, reserved_output_count: usize, outputs_length_max: usize) -> Vec<Vec<Output>> {
+    let max_output_count = usize::from(OUTPUT_COUNT_MAX)
+        .saturating_sub(reserved_output_count)
+        .max(1);
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_length = 0;
+
+    for output in outputs {
+        let length = output.packed_len();
+
+        if !chunk.is_empty() && (chunk.len() == max_output_count || chunk_length + length > outputs_length_max) {
+            chunks.push(core::mem::take(&mut chunk));
+            chunk_length = 0;
+        }
+
+        chunk_length += length;
+        chunk.push(output);
+    }
+
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        input::{Input, UtxoInput},
+        output::{feature::MetadataFeature, BasicOutputBuilder},
+        payload::TaggedDataPayload,
+        rand::output::unlock_condition::rand_address_unlock_condition,
+        signature::Signature,
+        unlock::{SignatureUnlock, Unlock},
+    };
+
+    fn output(metadata_length: usize) -> Output {
+        let mut builder =
+            BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(rand_address_unlock_condition());
+        if metadata_length > 0 {
+            builder = builder.add_feature(MetadataFeature::new(vec![0; metadata_length]).unwrap());
+        }
+        builder.finish_output(u64::MAX).unwrap()
+    }
+
+    #[test]
+    fn split_by_output_count() {
+        let outputs = (0..300).map(|_| output(0)).collect::<Vec<_>>();
+
+        let chunks = split_outputs(outputs.clone(), 1, OUTPUTS_LENGTH_MAX);
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [127, 127, 46]);
+        assert_eq!(chunks.concat(), outputs);
+
+        let chunks = split_outputs(outputs, 3, OUTPUTS_LENGTH_MAX);
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), [125, 125, 50]);
+
+        assert!(split_outputs(Vec::new(), 1, OUTPUTS_LENGTH_MAX).is_empty());
+    }
+
+    #[test]
+    fn split_by_length() {
+        let outputs = (0..10).map(|_| output(4000)).collect::<Vec<_>>();
+
+        let outputs_length_max = outputs_length_max(None);
+        let chunks = split_outputs(outputs.clone(), 1, outputs_length_max);
+        assert!(chunks.len() > 1);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.iter().map(Output::packed_len).sum::<usize>() <= outputs_length_max));
+        assert_eq!(chunks.concat(), outputs);
+    }
+
+    #[test]
+    fn lengths() {
+        let input = Input::from(UtxoInput::from(OutputId::null()));
+        assert_eq!(input.packed_len(), INPUT_LENGTH);
+
+        let unlock = Unlock::from(SignatureUnlock::new(Signature::from(Ed25519Signature::from_bytes(
+            [0; Ed25519Signature::PUBLIC_KEY_LENGTH],
+            [0; Ed25519Signature::SIGNATURE_LENGTH],
+        ))));
+        assert_eq!(unlock.packed_len(), SIGNATURE_UNLOCK_LENGTH);
+
+        assert_eq!(output(0).packed_len(), REMAINDER_OUTPUT_LENGTH);
+
+        let options = TransactionOptions {
+            tagged_data_payload: Some(TaggedDataPayload::new(b"tag".to_vec(), vec![0; 100]).unwrap()),
+            ..Default::default()
+        };
+        assert!(outputs_length_max(Some(&options)) < outputs_length_max(None) - 100);
+    }
+
+    #[test]
+    fn reserved_outputs() {
+        assert_eq!(reserved_output_count(None), 1);
+        assert_eq!(
+            reserved_output_count(Some(&TransactionOptions {
+                change_strategy: ChangeStrategy::Split(4),
+                ..Default::default()
+            })),
+            4
+        );
+    }
+}
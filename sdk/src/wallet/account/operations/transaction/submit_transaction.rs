@@ -22,7 +22,7 @@ where
         #[cfg(feature = "events")]
         let account_index = self.details().await.index;
 
-        let local_pow = self.client().get_local_pow().await;
+        let local_pow = self.client().uses_local_pow().await;
         if local_pow {
             log::debug!("[TRANSACTION] doing local pow");
             #[cfg(feature = "events")]
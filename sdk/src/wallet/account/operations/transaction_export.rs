@@ -0,0 +1,290 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::ops::RangeBounds;
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::{
+        block::{
+            address::Address,
+            output::{NativeToken, NativeTokensBuilder, Output},
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
+            BlockId,
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account,
+        },
+        core::AddressBook,
+    },
+};
+
+/// The CSV header of the exported transactions, in the order of the fields of [`TransactionExportRecord`].
+pub const TRANSACTION_EXPORT_CSV_HEADER: &str =
+    "transactionId,blockId,timestamp,inclusionState,direction,amount,remainder,nativeTokens,tag,note,counterpartLabel";
+
+/// The format of exported transactions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportFormat {
+    /// Comma separated values with the [`TRANSACTION_EXPORT_CSV_HEADER`]. Native tokens are formatted as
+    /// `tokenId:amount` with the decimal amount and separated by `;`.
+    Csv,
+    /// A JSON array of [`TransactionExportRecord`]s.
+    Json,
+}
+
+/// Whether a transaction was sent or received by the account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionDirection {
+    /// The transaction was received from someone else.
+    Incoming,
+    /// The transaction was sent by the account.
+    Outgoing,
+}
+
+impl TransactionDirection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Incoming => "incoming",
+            Self::Outgoing => "outgoing",
+        }
+    }
+}
+
+/// A transaction of the account as exported by [`Account::export_transactions()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionExportRecord {
+    /// The transaction id.
+    pub transaction_id: TransactionId,
+    /// The id of the block that contains the transaction, if known.
+    pub block_id: Option<BlockId>,
+    /// The unix timestamp of the transaction in milliseconds.
+    pub timestamp: u128,
+    /// The inclusion state of the transaction.
+    pub inclusion_state: InclusionState,
+    /// Whether the transaction was sent or received by the account.
+    pub direction: TransactionDirection,
+    /// The base coin amount that was sent to other addresses for outgoing transactions, or that was received for
+    /// incoming transactions.
+    pub amount: u64,
+    /// The base coin amount of outgoing transactions that returned to addresses of the account, like remainders.
+    pub remainder: u64,
+    /// The native tokens that were sent or received, like `amount`.
+    pub native_tokens: Vec<NativeToken>,
+    /// The hex encoded tag of the tagged data payload of the transaction.
+    pub tag: Option<String>,
+    /// The note of the transaction.
+    pub note: Option<String>,
+    /// The address book label of the first address the transaction was sent to, or received from, that has an
+    /// address book entry.
+    #[serde(default)]
+    pub counterpart_label: Option<String>,
+}
+
+impl TransactionExportRecord {
+    fn from_transaction(
+        transaction: &Transaction,
+        account_addresses: &HashSet<Address>,
+        address_book: &AddressBook,
+    ) -> crate::wallet::Result<Self> {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let direction = if transaction.incoming {
+            TransactionDirection::Incoming
+        } else {
+            TransactionDirection::Outgoing
+        };
+
+        let mut amount = 0;
+        let mut remainder = 0;
+        let mut native_tokens = NativeTokensBuilder::new();
+        let mut counterparts = Vec::new();
+
+        for output in essence.outputs() {
+            let address = output_address(output);
+            let is_own_output = address.is_some_and(|address| account_addresses.contains(address));
+            if direction == TransactionDirection::Outgoing && !is_own_output {
+                counterparts.extend(address.copied());
+            }
+
+            match (direction, is_own_output) {
+                (TransactionDirection::Outgoing, true) => remainder += output.amount(),
+                (TransactionDirection::Incoming, false) => {}
+                _ => {
+                    amount += output.amount();
+                    if let Some(output_native_tokens) = output.native_tokens() {
+                        native_tokens.add_native_tokens(output_native_tokens.clone())?;
+                    }
+                }
+            }
+        }
+
+        if direction == TransactionDirection::Incoming {
+            // The outputs of incoming transactions were sent from the addresses of the inputs
+            counterparts.extend(transaction.inputs.iter().filter_map(|input| {
+                Output::try_from_dto(input.output.clone())
+                    .ok()
+                    .and_then(|output| output_address(&output).copied())
+                    .filter(|address| !account_addresses.contains(address))
+            }));
+        }
+        let counterpart_label = counterparts
+            .iter()
+            .find_map(|address| address_book.find(address))
+            .map(|entry| entry.label.clone());
+
+        let tag = match essence.payload() {
+            Some(Payload::TaggedData(tagged_data)) => Some(prefix_hex::encode(tagged_data.tag())),
+            _ => None,
+        };
+
+        Ok(Self {
+            transaction_id: transaction.transaction_id,
+            block_id: transaction.block_id,
+            timestamp: transaction.timestamp,
+            inclusion_state: transaction.inclusion_state,
+            direction,
+            amount,
+            remainder,
+            native_tokens: native_tokens.finish_vec()?,
+            tag,
+            note: transaction.note.clone(),
+            counterpart_label,
+        })
+    }
+
+    fn to_csv_row(&self) -> String {
+        let native_tokens = self
+            .native_tokens
+            .iter()
+            .map(|native_token| format!("{}:{}", native_token.token_id(), native_token.amount()))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        [
+            self.transaction_id.to_string(),
+            self.block_id.map(|block_id| block_id.to_string()).unwrap_or_default(),
+            self.timestamp.to_string(),
+            format!("{:?}", self.inclusion_state),
+            self.direction.as_str().to_string(),
+            self.amount.to_string(),
+            self.remainder.to_string(),
+            native_tokens,
+            self.tag.clone().unwrap_or_default(),
+            self.note.as_deref().map(escape_csv_field).unwrap_or_default(),
+            self.counterpart_label
+                .as_deref()
+                .map(escape_csv_field)
+                .unwrap_or_default(),
+        ]
+        .join(",")
+    }
+}
+
+fn output_address(output: &Output) -> Option<&Address> {
+    output
+        .unlock_conditions()
+        .and_then(|unlock_conditions| unlock_conditions.address())
+        .map(|unlock_condition| unlock_condition.address())
+}
+
+// Quotes a field if it contains a separator, a quote or a line break.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Exports the sent and received transactions of the account with a timestamp, in milliseconds, in the `range`,
+    /// sorted by their timestamp.
+    ///
+    /// The base coin amount of outgoing transactions only includes outputs to other addresses, the outputs that return
+    /// to the account are exported as remainder. Only outputs with an
+    /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition) are
+    /// attributed to the account.
+    pub async fn export_transactions(
+        &self,
+        format: ExportFormat,
+        range: impl RangeBounds<u128> + Send,
+    ) -> crate::wallet::Result<String> {
+        let records = self.transaction_export_records(range).await?;
+
+        Ok(match format {
+            ExportFormat::Csv => core::iter::once(TRANSACTION_EXPORT_CSV_HEADER.to_string())
+                .chain(records.iter().map(TransactionExportRecord::to_csv_row))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ExportFormat::Json => serde_json::to_string(&records)?,
+        })
+    }
+
+    /// Returns the records that are exported by [`Account::export_transactions()`]. Transactions with addresses in the
+    /// [address book](crate::wallet::Wallet::address_book) are labeled with the label of the entry.
+    pub async fn transaction_export_records(
+        &self,
+        range: impl RangeBounds<u128> + Send,
+    ) -> crate::wallet::Result<Vec<TransactionExportRecord>> {
+        let account_details = self.details().await;
+        let account_addresses = account_details
+            .addresses()
+            .into_iter()
+            .map(|address| address.address.inner)
+            .collect::<HashSet<_>>();
+        let address_book = self.wallet.address_book.lock().await.clone();
+
+        let mut records = account_details
+            .transactions()
+            .values()
+            .chain(
+                account_details
+                    .incoming_transactions()
+                    .iter()
+                    // Transactions between addresses of the account are already exported as outgoing
+                    .filter(|(transaction_id, _)| !account_details.transactions().contains_key(transaction_id))
+                    .map(|(_, transaction)| transaction),
+            )
+            .filter(|transaction| range.contains(&transaction.timestamp))
+            .map(|transaction| {
+                TransactionExportRecord::from_transaction(transaction, &account_addresses, &address_book)
+            })
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+
+        records.sort_by(|a, b| {
+            a.timestamp
+                .cmp(&b.timestamp)
+                .then_with(|| a.transaction_id.cmp(&b.transaction_id))
+        });
+
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_escaping() {
+        assert_eq!(escape_csv_field("note"), "note");
+        assert_eq!(escape_csv_field("a, b"), "\"a, b\"");
+        assert_eq!(escape_csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::payload::transaction::TransactionId,
+    wallet::account::{types::Transaction, Account},
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Sets or removes the note of a sent or incoming transaction. Notes are only stored locally, if storage is
+    /// enabled, they will persist during restarts.
+    pub async fn set_transaction_note(
+        &self,
+        transaction_id: &TransactionId,
+        note: Option<String>,
+    ) -> crate::wallet::Result<Transaction> {
+        self.update_transaction(transaction_id, |transaction| transaction.note = note.clone())
+            .await
+    }
+
+    /// Adds a local tag to a sent or incoming transaction.
+    pub async fn add_transaction_tag(
+        &self,
+        transaction_id: &TransactionId,
+        tag: impl Into<String> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let tag = tag.into();
+        self.update_transaction(transaction_id, |transaction| {
+            transaction.tags.insert(tag.clone());
+        })
+        .await
+    }
+
+    /// Removes a local tag from a sent or incoming transaction.
+    pub async fn remove_transaction_tag(
+        &self,
+        transaction_id: &TransactionId,
+        tag: &str,
+    ) -> crate::wallet::Result<Transaction> {
+        self.update_transaction(transaction_id, |transaction| {
+            transaction.tags.remove(tag);
+        })
+        .await
+    }
+
+    /// Replaces the local tags of a sent or incoming transaction.
+    pub async fn set_transaction_tags(
+        &self,
+        transaction_id: &TransactionId,
+        tags: BTreeSet<String>,
+    ) -> crate::wallet::Result<Transaction> {
+        self.update_transaction(transaction_id, |transaction| transaction.tags = tags.clone())
+            .await
+    }
+
+    /// Returns the sent and incoming transactions that have the local tag.
+    pub async fn transactions_with_tag(&self, tag: &str) -> Vec<Transaction> {
+        let account_details = self.details().await;
+        account_details
+            .transactions
+            .values()
+            .chain(
+                account_details
+                    .incoming_transactions
+                    .iter()
+                    .filter(|(transaction_id, _)| !account_details.transactions.contains_key(transaction_id))
+                    .map(|(_, transaction)| transaction),
+            )
+            .filter(|transaction| transaction.tags.contains(tag))
+            .cloned()
+            .collect()
+    }
+
+    // Transactions to the account's own addresses are stored as sent and as incoming transaction, so both are updated.
+    async fn update_transaction(
+        &self,
+        transaction_id: &TransactionId,
+        f: impl Fn(&mut Transaction) + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let mut account_details = self.details_mut().await;
+        let details = &mut *account_details;
+
+        let mut updated = None;
+        for transaction in [
+            details.transactions.get_mut(transaction_id),
+            details.incoming_transactions.get_mut(transaction_id),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            f(transaction);
+            updated = Some(transaction.clone());
+        }
+        let transaction = updated.ok_or(crate::wallet::Error::TransactionNotFound(*transaction_id))?;
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(transaction)
+    }
+}
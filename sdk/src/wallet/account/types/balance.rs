@@ -7,7 +7,10 @@ use getset::{CopyGetters, Getters};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId};
+use crate::types::block::{
+    output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId},
+    payload::transaction::TransactionId,
+};
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
@@ -21,6 +24,10 @@ pub struct Balance {
     pub(crate) required_storage_deposit: RequiredStorageDeposit,
     /// Native tokens
     pub(crate) native_tokens: Vec<NativeTokensBalance>,
+    /// Native tokens without the ones denied by the
+    /// [`TokenFilter`](crate::wallet::account::TokenFilter) of the account
+    #[serde(default)]
+    pub(crate) filtered_native_tokens: Vec<NativeTokensBalance>,
     /// Aliases
     pub(crate) aliases: Vec<AliasId>,
     /// Foundries
@@ -32,6 +39,34 @@ pub struct Balance {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub(crate) potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Changes of pending transactions that are already applied to the balance
+    #[serde(default)]
+    pub(crate) provisional: ProvisionalBalance,
+}
+
+/// The balance of a single address of an account, returned from
+/// [`crate::wallet::account::Account::balance_per_address()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct AddressBalance {
+    /// Total and available amount of the base coin
+    pub(crate) base_coin: BaseCoinBalance,
+    /// Native tokens by their token id
+    pub(crate) native_tokens: HashMap<TokenId, NativeTokensBalance>,
+}
+
+impl From<Balance> for AddressBalance {
+    fn from(balance: Balance) -> Self {
+        Self {
+            base_coin: balance.base_coin,
+            native_tokens: balance
+                .native_tokens
+                .into_iter()
+                .map(|native_token| (native_token.token_id, native_token))
+                .collect(),
+        }
+    }
 }
 
 impl std::ops::AddAssign for Balance {
@@ -39,21 +74,26 @@ impl std::ops::AddAssign for Balance {
         self.base_coin += rhs.base_coin;
         self.required_storage_deposit += rhs.required_storage_deposit;
 
-        for rhs_native_token_balance in rhs.native_tokens.into_iter() {
-            if let Some(total_native_token_balance) = self
-                .native_tokens
-                .iter_mut()
-                .find(|lhs_native_token_balance| lhs_native_token_balance.token_id == rhs_native_token_balance.token_id)
-            {
-                *total_native_token_balance += rhs_native_token_balance;
-            } else {
-                self.native_tokens.push(rhs_native_token_balance);
-            }
-        }
+        add_native_tokens_balances(&mut self.native_tokens, rhs.native_tokens);
+        add_native_tokens_balances(&mut self.filtered_native_tokens, rhs.filtered_native_tokens);
 
         self.aliases.extend(rhs.aliases);
         self.foundries.extend(rhs.foundries);
         self.nfts.extend(rhs.nfts);
+        self.provisional += rhs.provisional;
+    }
+}
+
+fn add_native_tokens_balances(lhs: &mut Vec<NativeTokensBalance>, rhs: Vec<NativeTokensBalance>) {
+    for rhs_native_token_balance in rhs.into_iter() {
+        if let Some(total_native_token_balance) = lhs
+            .iter_mut()
+            .find(|lhs_native_token_balance| lhs_native_token_balance.token_id == rhs_native_token_balance.token_id)
+        {
+            *total_native_token_balance += rhs_native_token_balance;
+        } else {
+            lhs.push(rhs_native_token_balance);
+        }
     }
 }
 
@@ -85,6 +125,46 @@ impl std::ops::AddAssign for BaseCoinBalance {
     }
 }
 
+/// The changes of the pending transactions of an account, which are provisionally applied to [`Balance`] until the
+/// transactions are confirmed, or rolled back if they conflict.
+///
+/// The spent outputs are no longer part of the total balance, and the outputs that the account receives, like
+/// remainders, are part of the total balance, but only available once the transactions are confirmed.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvisionalBalance {
+    /// The pending transactions
+    #[getset(get = "pub")]
+    pub(crate) transaction_ids: Vec<TransactionId>,
+    /// The outputs spent by the pending transactions
+    #[getset(get = "pub")]
+    pub(crate) spent_outputs: Vec<OutputId>,
+    /// Base coin amount of the spent outputs
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) spent: u64,
+    /// Base coin amount of the outputs of the pending transactions that the account receives
+    #[getset(get_copy = "pub")]
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) received: u64,
+}
+
+impl ProvisionalBalance {
+    /// Returns whether pending transactions are applied to the balance.
+    pub fn is_empty(&self) -> bool {
+        self.transaction_ids.is_empty()
+    }
+}
+
+impl std::ops::AddAssign for ProvisionalBalance {
+    fn add_assign(&mut self, rhs: Self) {
+        self.transaction_ids.extend(rhs.transaction_ids);
+        self.spent_outputs.extend(rhs.spent_outputs);
+        self.spent += rhs.spent;
+        self.received += rhs.received;
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
 #[getset(get_copy = "pub")]
 pub struct RequiredStorageDeposit {
@@ -124,6 +204,30 @@ pub struct NativeTokensBalance {
     #[getset(get = "pub")]
     #[serde(with = "crate::utils::serde::option_string")]
     pub(crate) metadata: Option<MetadataFeature>,
+    /// Indicators that the token is spam, only computed for tokens that aren't on the allow or deny list of the
+    /// account, to suggest hiding them
+    #[getset(get = "pub")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) spam_indicators: Vec<SpamIndicator>,
+}
+
+impl NativeTokensBalance {
+    /// Returns whether there are indicators that the token is spam.
+    pub fn is_suspicious(&self) -> bool {
+        !self.spam_indicators.is_empty()
+    }
+}
+
+/// An indicator that a native token is spam, e.g. airdropped to a lot of addresses to advertise a scam.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum SpamIndicator {
+    /// The foundry of the token has no IRC-30 metadata, so it has no name or symbol.
+    MissingIrc30Metadata,
+    /// All outputs holding the token only have the minimum storage deposit, so the sender didn't give any value along
+    /// with the token.
+    ZeroValue,
 }
 
 impl Default for NativeTokensBalance {
@@ -133,6 +237,7 @@ impl Default for NativeTokensBalance {
             total: U256::from(0u8),
             available: U256::from(0u8),
             metadata: None,
+            spam_indicators: Vec::new(),
         }
     }
 }
@@ -144,6 +249,9 @@ impl std::ops::AddAssign for NativeTokensBalance {
         if self.metadata.is_none() {
             self.metadata = rhs.metadata;
         }
+        // A token is only suspicious if it's suspicious in all accounts
+        self.spam_indicators
+            .retain(|indicator| rhs.spam_indicators.contains(indicator));
     }
 }
 
@@ -196,6 +304,13 @@ impl Balance {
         let foundries = std::iter::repeat_with(|| FoundryId::from(rand_bytes_array()))
             .take(rand::thread_rng().gen_range(0..10))
             .collect::<Vec<_>>();
+        let transaction_ids = std::iter::repeat_with(|| TransactionId::from(rand_bytes_array()))
+            .take(rand::thread_rng().gen_range(0..3))
+            .collect::<Vec<_>>();
+        let spent_outputs = transaction_ids
+            .iter()
+            .map(|_| OutputId::new(TransactionId::from(rand_bytes_array()), 0).unwrap())
+            .collect::<Vec<_>>();
 
         Self {
             base_coin: BaseCoinBalance {
@@ -210,10 +325,17 @@ impl Balance {
                 foundry: total / 4,
                 nft: total / 2,
             },
+            filtered_native_tokens: native_tokens.clone(),
             native_tokens,
             aliases,
             foundries,
             nfts,
+            provisional: ProvisionalBalance {
+                transaction_ids,
+                spent_outputs,
+                spent: total / 4,
+                received: total / 8,
+            },
             ..Default::default()
         }
     }
@@ -7,17 +7,20 @@ pub(crate) mod balance;
 #[cfg(feature = "participation")]
 pub mod participation;
 
-use std::str::FromStr;
+use std::{collections::BTreeSet, str::FromStr};
 
 use crypto::keys::bip44::Bip44;
 use serde::{Deserialize, Deserializer, Serialize};
 
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs},
-    balance::{Balance, BaseCoinBalance, NativeTokensBalance, RequiredStorageDeposit},
+    balance::{
+        AddressBalance, Balance, BaseCoinBalance, NativeTokensBalance, ProvisionalBalance, RequiredStorageDeposit,
+        SpamIndicator,
+    },
 };
 use crate::{
-    client::secret::types::InputSigningData,
+    client::{explorer::ExplorerUrlTemplate, secret::types::InputSigningData},
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
@@ -168,12 +171,21 @@ pub struct Transaction {
     // set if the transaction was created by the wallet or if it was sent by someone else and is incoming
     pub incoming: bool,
     pub note: Option<String>,
+    /// Free-form tags that are only stored locally.
+    pub tags: BTreeSet<String>,
     /// Outputs that are used as input in the transaction. May not be all, because some may have already been deleted
     /// from the node.
     // serde(default) is needed so it doesn't break with old dbs
     pub inputs: Vec<OutputWithMetadataResponse>,
 }
 
+impl Transaction {
+    /// Returns the explorer url of the transaction.
+    pub fn explorer_url(&self, template: &ExplorerUrlTemplate) -> String {
+        template.transaction_url(&self.transaction_id)
+    }
+}
+
 /// Dto for a transaction with metadata
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -194,6 +206,9 @@ pub struct TransactionDto {
     pub incoming: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
+    /// Free-form tags that are only stored locally
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tags: BTreeSet<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
 }
 
@@ -208,6 +223,7 @@ impl From<&Transaction> for TransactionDto {
             network_id: value.network_id.to_string(),
             incoming: value.incoming,
             note: value.note.clone(),
+            tags: value.tags.clone(),
             inputs: value.inputs.clone(),
         }
     }
@@ -236,6 +252,7 @@ impl TryFromDto for Transaction {
                 .map_err(|_| BlockError::InvalidField("network id"))?,
             incoming: dto.incoming,
             note: dto.note,
+            tags: dto.tags,
             inputs: dto.inputs,
         })
     }
@@ -6,10 +6,13 @@ use std::collections::HashMap;
 use crate::{
     client::secret::SecretManage,
     types::block::output::{OutputId, OutputMetadata},
-    wallet::account::{
-        operations::syncing::options::SyncOptions,
-        types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
-        Account, AccountAddress,
+    wallet::{
+        account::{
+            operations::syncing::options::SyncOptions,
+            types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
+            Account, AccountAddress,
+        },
+        label::normalize_account_alias,
     },
 };
 #[cfg(feature = "events")]
@@ -27,8 +30,9 @@ where
 {
     /// Set the alias for the account
     pub async fn set_alias(&self, alias: &str) -> crate::wallet::Result<()> {
+        let alias = normalize_account_alias(alias)?;
         let mut account_details = self.details_mut().await;
-        account_details.alias = alias.to_string();
+        account_details.alias = alias;
         #[cfg(feature = "storage")]
         self.save(Some(&account_details)).await?;
         Ok(())
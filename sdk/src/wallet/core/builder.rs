@@ -12,7 +12,9 @@ use futures::{future::try_join_all, FutureExt};
 use serde::Serialize;
 use tokio::sync::RwLock;
 
-use super::operations::storage::SaveLoadWallet;
+use super::operations::{
+    spend_policy::PendingSpends, storage::SaveLoadWallet, syncing::DEFAULT_ACCOUNT_SYNC_CONCURRENCY,
+};
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
 #[cfg(all(feature = "storage", not(feature = "rocksdb")))]
@@ -206,8 +208,18 @@ where
         unlock_unused_inputs(&mut accounts)?;
         #[cfg(not(feature = "storage"))]
         let accounts = Vec::new();
+
+        #[cfg(feature = "storage")]
+        let address_book = storage_manager.get_address_book().await?.unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let address_book = Default::default();
+
         let wallet_inner = Arc::new(WalletInner {
             background_syncing_status: AtomicUsize::new(0),
+            account_sync_concurrency: AtomicUsize::new(DEFAULT_ACCOUNT_SYNC_CONCURRENCY),
+            pending_transactions_monitor_status: AtomicUsize::new(0),
+            auto_claiming_status: AtomicUsize::new(0),
+            address_book: tokio::sync::Mutex::new(address_book),
             client: self
                 .client_options
                 .clone()
@@ -218,6 +230,8 @@ where
             secret_manager: self
                 .secret_manager
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
+            spend_policy: tokio::sync::Mutex::new(None),
+            pending_spends: tokio::sync::Mutex::new(PendingSpends::new()),
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
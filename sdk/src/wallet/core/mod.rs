@@ -12,11 +12,22 @@ use std::sync::{
 use crypto::keys::bip39::{Mnemonic, MnemonicRef};
 use tokio::sync::RwLock;
 
-pub use self::builder::WalletBuilder;
+#[cfg(feature = "stronghold")]
+pub use self::operations::stronghold_backup::{BackupOptions, RestoreConflictPolicy, RestoreOptions};
+pub use self::{
+    builder::WalletBuilder,
+    operations::{
+        address_book::{AddressBook, AddressBookEntry},
+        auto_claiming::AutoClaimOptions,
+        fingerprint::WalletFingerprint,
+        spend_policy::{SpendConfirmationCallback, SpendPolicy, SpendRequest},
+        syncing::WalletSyncProgress,
+    },
+};
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
-    EventEmitter,
+    EventEmitter, TypedWalletEvent, WalletEventFilter,
 };
 #[cfg(feature = "storage")]
 use crate::wallet::storage::{StorageManager, StorageOptions};
@@ -25,7 +36,7 @@ use crate::{
         secret::{SecretManage, SecretManager},
         verify_mnemonic, Client,
     },
-    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::Balance, Account},
+    wallet::account::{builder::AccountBuilder, types::Balance, Account},
 };
 
 /// The wallet, used to create and get accounts. One wallet can hold many accounts, but they should
@@ -74,9 +85,17 @@ where
 pub struct WalletInner<S: SecretManage = SecretManager> {
     // 0 = not running, 1 = running, 2 = stopping
     pub(crate) background_syncing_status: AtomicUsize,
+    pub(crate) account_sync_concurrency: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    pub(crate) pending_transactions_monitor_status: AtomicUsize,
+    // 0 = not running, 1 = running, 2 = stopping
+    pub(crate) auto_claiming_status: AtomicUsize,
+    pub(crate) address_book: tokio::sync::Mutex<AddressBook>,
     pub(crate) client: Client,
     pub(crate) coin_type: AtomicU32,
     pub(crate) secret_manager: Arc<RwLock<S>>,
+    pub(crate) spend_policy: tokio::sync::Mutex<Option<SpendPolicy>>,
+    pub(crate) pending_spends: tokio::sync::Mutex<operations::spend_policy::PendingSpends>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: tokio::sync::RwLock<EventEmitter>,
     #[cfg(feature = "storage")]
@@ -153,17 +172,6 @@ where
 
         Ok(balance)
     }
-
-    /// Sync all accounts
-    pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
-        let mut balance = Balance::default();
-
-        for account in self.accounts.read().await.iter() {
-            balance += account.sync(options.clone()).await?;
-        }
-
-        Ok(balance)
-    }
 }
 
 impl<S: SecretManage> WalletInner<S> {
@@ -184,6 +192,30 @@ impl<S: SecretManage> WalletInner<S> {
         emitter.on(events, handler);
     }
 
+    /// Listen to the wallet events matching the `filter`
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn listen_filtered<F>(&self, filter: WalletEventFilter, handler: F)
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let mut emitter = self.event_emitter.write().await;
+        emitter.on_filtered(filter, handler);
+    }
+
+    /// Listen to the wallet events of type `E` matching the `filter`, the handler gets the account index and the typed
+    /// event
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn listen_typed<E, F>(&self, filter: WalletEventFilter, handler: F)
+    where
+        E: TypedWalletEvent,
+        F: Fn(u32, &E) + 'static + Send + Sync,
+    {
+        let mut emitter = self.event_emitter.write().await;
+        emitter.on_typed(filter, handler);
+    }
+
     /// Remove wallet event listeners, empty vec will remove all listeners
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
@@ -0,0 +1,189 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::{Address, Bech32Address},
+    wallet::{
+        label::{labels_match, normalize_address_book_label},
+        Wallet,
+    },
+};
+
+/// A labeled address of the address book, see [`Wallet::set_address_book_entry()`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookEntry {
+    /// The address.
+    pub address: Bech32Address,
+    /// The label of the address, e.g. the name of its owner.
+    pub label: String,
+    /// Whether the address belongs to a known peer, e.g. a friend, instead of e.g. an exchange.
+    #[serde(default)]
+    pub known_peer: bool,
+    /// An optional tag of the network the address is used on, e.g. `shimmer`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+impl AddressBookEntry {
+    /// Creates a new [`AddressBookEntry`].
+    pub fn new(address: Bech32Address, label: impl Into<String>) -> Self {
+        Self {
+            address,
+            label: label.into(),
+            known_peer: false,
+            network: None,
+        }
+    }
+
+    /// Sets whether the address belongs to a known peer.
+    pub fn with_known_peer(mut self, known_peer: bool) -> Self {
+        self.known_peer = known_peer;
+        self
+    }
+
+    /// Sets the network tag.
+    pub fn with_network(mut self, network: impl Into<Option<String>>) -> Self {
+        self.network = network.into();
+        self
+    }
+}
+
+/// The address book of the wallet, the entries are unique per address.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBook {
+    #[serde(default)]
+    pub(crate) entries: HashMap<Bech32Address, AddressBookEntry>,
+}
+
+impl AddressBook {
+    /// Returns the entries sorted by their label.
+    pub fn entries(&self) -> Vec<&AddressBookEntry> {
+        let mut entries = self.entries.values().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.label.cmp(&b.label).then_with(|| a.address.cmp(&b.address)));
+        entries
+    }
+
+    /// Returns the entry of a bech32 address.
+    pub fn get(&self, address: &Bech32Address) -> Option<&AddressBookEntry> {
+        self.entries.get(address)
+    }
+
+    /// Returns the entry of an address, regardless of the human readable part it was added with.
+    pub fn find(&self, address: &Address) -> Option<&AddressBookEntry> {
+        self.entries.values().find(|entry| entry.address.inner() == address)
+    }
+
+    /// Returns the entry with a label, matching labels that only differ in their Unicode encoding.
+    pub fn find_by_label(&self, label: &str) -> Option<&AddressBookEntry> {
+        self.entries.values().find(|entry| labels_match(&entry.label, label))
+    }
+
+    /// Adds an entry or replaces the entry with the same address. Returns the replaced entry.
+    pub fn insert(&mut self, entry: AddressBookEntry) -> Option<AddressBookEntry> {
+        self.entries.insert(entry.address, entry)
+    }
+
+    /// Removes the entry of an address. Returns the removed entry.
+    pub fn remove(&mut self, address: &Bech32Address) -> Option<AddressBookEntry> {
+        self.entries.remove(address)
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the address book.
+    pub async fn address_book(&self) -> AddressBook {
+        self.address_book.lock().await.clone()
+    }
+
+    /// Returns the address book entry of an address.
+    pub async fn address_book_entry(&self, address: &Bech32Address) -> Option<AddressBookEntry> {
+        self.address_book.lock().await.get(address).cloned()
+    }
+
+    /// Adds an entry to the address book or updates the existing entry of the address, if storage is enabled, it will
+    /// persist during restarts. The label is normalized to Unicode NFC and must not be empty, contain control
+    /// characters or exceed 256 characters. Returns the replaced entry.
+    pub async fn set_address_book_entry(
+        &self,
+        mut entry: AddressBookEntry,
+    ) -> crate::wallet::Result<Option<AddressBookEntry>> {
+        entry.label = normalize_address_book_label(&entry.label)?;
+
+        self.update_address_book(|address_book| address_book.insert(entry))
+            .await
+    }
+
+    /// Returns the address book entry with a label, see [`AddressBook::find_by_label()`].
+    pub async fn address_book_entry_by_label(&self, label: &str) -> Option<AddressBookEntry> {
+        self.address_book.lock().await.find_by_label(label).cloned()
+    }
+
+    /// Removes the entry of an address from the address book. Returns the removed entry.
+    pub async fn remove_address_book_entry(
+        &self,
+        address: &Bech32Address,
+    ) -> crate::wallet::Result<Option<AddressBookEntry>> {
+        self.update_address_book(|address_book| address_book.remove(address))
+            .await
+    }
+
+    async fn update_address_book<R>(&self, f: impl FnOnce(&mut AddressBook) -> R + Send) -> crate::wallet::Result<R> {
+        let mut address_book = self.address_book.lock().await;
+        let mut updated = address_book.clone();
+        let result = f(&mut updated);
+
+        #[cfg(feature = "storage")]
+        self.storage_manager.read().await.set_address_book(&updated).await?;
+
+        *address_book = updated;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::address::{Ed25519Address, Hrp};
+
+    #[test]
+    fn find_ignores_hrp() {
+        let address = Address::from(Ed25519Address::new([1; 32]));
+        let mut address_book = AddressBook::default();
+        address_book.insert(AddressBookEntry::new(
+            Bech32Address::new(Hrp::from_str_unchecked("rms"), address),
+            "alice",
+        ));
+
+        assert_eq!(address_book.find(&address).unwrap().label, "alice");
+        assert!(address_book
+            .get(&Bech32Address::new(Hrp::from_str_unchecked("smr"), address))
+            .is_none());
+        assert!(address_book
+            .find(&Address::from(Ed25519Address::new([2; 32])))
+            .is_none());
+    }
+
+    #[test]
+    fn find_by_normalized_label() {
+        let mut address_book = AddressBook::default();
+        // "é" as a single code point
+        address_book.insert(AddressBookEntry::new(
+            Bech32Address::new(Hrp::from_str_unchecked("rms"), Ed25519Address::new([1; 32])),
+            "Andr\u{e9}",
+        ));
+
+        // "é" as "e" followed by a combining acute accent
+        assert!(address_book.find_by_label("Andre\u{301}").is_some());
+        assert!(address_book.find_by_label("Andre").is_none());
+    }
+}
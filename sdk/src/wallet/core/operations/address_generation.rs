@@ -120,6 +120,70 @@ impl Wallet {
                     )
                     .await?
             }
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => {
+                pkcs11
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
+            #[cfg(feature = "encrypted_mnemonic_secret_manager")]
+            SecretManager::EncryptedMnemonic(encrypted_mnemonic) => {
+                encrypted_mnemonic
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
+            #[cfg(feature = "frost")]
+            SecretManager::Frost(frost) => {
+                frost
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
+            SecretManager::Callback(callback) => {
+                callback
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
+            #[cfg(feature = "ledger_nano_simulator")]
+            SecretManager::LedgerSimulator(simulator) => {
+                simulator
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
+            SecretManager::Router(router) => {
+                router
+                    .generate_ed25519_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
             SecretManager::Placeholder => return Err(crate::client::Error::PlaceholderSecretManager.into()),
         };
 
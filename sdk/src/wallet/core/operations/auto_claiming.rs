@@ -0,0 +1,130 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use tokio::time::sleep;
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{account::operations::syncing::SyncOptions, Wallet},
+};
+
+/// The default time before their expiration in which outputs are claimed
+pub(crate) const DEFAULT_AUTO_CLAIMING_SAFETY_MARGIN: Duration = Duration::from_secs(60 * 60);
+/// The default interval for checking for expiring outputs
+pub(crate) const DEFAULT_AUTO_CLAIMING_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Options for automatically claiming expiring outputs, see [`Wallet::start_auto_claiming()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AutoClaimOptions {
+    /// Outputs are claimed if they expire within this time. Expirations are unix timestamps, so the margin has to be
+    /// larger than the interval and the time it takes for a milestone to confirm the claiming transaction.
+    pub safety_margin: Duration,
+    /// The interval in which the accounts are synced and checked for expiring outputs.
+    pub interval: Duration,
+}
+
+impl Default for AutoClaimOptions {
+    fn default() -> Self {
+        Self {
+            safety_margin: DEFAULT_AUTO_CLAIMING_SAFETY_MARGIN,
+            interval: DEFAULT_AUTO_CLAIMING_INTERVAL,
+        }
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start automatically claiming outputs with an
+    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) of all
+    /// accounts before they return to the sender, see
+    /// [`Account::claim_expiring_outputs()`](crate::wallet::Account::claim_expiring_outputs). Emits a
+    /// [`WalletEvent::OutputsClaimed`](crate::wallet::events::types::WalletEvent::OutputsClaimed) event for each claim.
+    pub async fn start_auto_claiming(&self, options: AutoClaimOptions) -> crate::wallet::Result<()> {
+        log::debug!("[start_auto_claiming]");
+        // stop existing process if running
+        if self.auto_claiming_status.load(Ordering::Relaxed) == 1 {
+            self.auto_claiming_status.store(2, Ordering::Relaxed);
+        };
+        while self.auto_claiming_status.load(Ordering::Relaxed) == 2 {
+            log::debug!("[auto_claiming]: waiting for the old process to stop");
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        self.auto_claiming_status.store(1, Ordering::Relaxed);
+        let wallet = self.clone();
+        let _auto_claiming = std::thread::spawn(move || {
+            #[cfg(not(target_family = "wasm"))]
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            #[cfg(target_family = "wasm")]
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                'outer: loop {
+                    log::debug!("[auto_claiming]: claiming expiring outputs");
+                    let accounts = wallet.accounts.read().await.clone();
+                    for account in accounts {
+                        // Check if the process should stop before each account so it stops faster
+                        if wallet.auto_claiming_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[auto_claiming]: stopping");
+                            break 'outer;
+                        }
+                        let sync_options = SyncOptions {
+                            sync_only_most_basic_outputs: false,
+                            ..account.default_sync_options().await
+                        };
+                        let result = match account.sync(Some(sync_options)).await {
+                            Ok(_) => account.claim_expiring_outputs(options.safety_margin).await,
+                            Err(err) => Err(err),
+                        };
+                        match result {
+                            Ok(Some(transaction)) => {
+                                log::debug!("[auto_claiming]: claimed outputs in {}", transaction.transaction_id)
+                            }
+                            Ok(None) => {}
+                            Err(err) => log::debug!("[auto_claiming] error: {}", err),
+                        }
+                    }
+                    // split interval to seconds so stopping the process doesn't have to wait long
+                    for _ in 0..options.interval.as_secs() {
+                        if wallet.auto_claiming_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[auto_claiming]: stopping");
+                            break 'outer;
+                        }
+                        sleep(Duration::from_secs(1)).await;
+                    }
+                }
+                wallet.auto_claiming_status.store(0, Ordering::Relaxed);
+                log::debug!("[auto_claiming]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    /// Stop automatically claiming expiring outputs
+    pub async fn stop_auto_claiming(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_auto_claiming]");
+        // immediately return if not running
+        if self.auto_claiming_status.load(Ordering::Relaxed) == 0 {
+            return Ok(());
+        }
+        // send stop request
+        self.auto_claiming_status.store(2, Ordering::Relaxed);
+        // wait until it stopped
+        while self.auto_claiming_status.load(Ordering::Relaxed) != 0 {
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(10).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@
 
 use std::{sync::atomic::Ordering, time::Duration};
 
+use futures::StreamExt;
 use tokio::time::sleep;
 
 use crate::{
@@ -49,17 +50,22 @@ where
             runtime.block_on(async {
                 'outer: loop {
                     log::debug!("[background_syncing]: syncing accounts");
-                    for account in wallet.accounts.read().await.iter() {
-                        // Check if the process should stop before syncing each account so it stops faster
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
-                            log::debug!("[background_syncing]: stopping");
-                            break 'outer;
-                        }
-                        match account.sync(options.clone()).await {
-                            Ok(_) => {}
-                            Err(err) => log::debug!("[background_syncing] error: {}", err),
-                        };
+                    if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
+                        log::debug!("[background_syncing]: stopping");
+                        break 'outer;
                     }
+                    // Errors are only logged, so a failing account doesn't prevent the others from syncing
+                    let accounts = wallet.accounts.read().await.clone();
+                    futures::stream::iter(accounts)
+                        .for_each_concurrent(wallet.account_sync_concurrency(), |account| {
+                            let options = options.clone();
+                            async move {
+                                if let Err(err) = account.sync(options).await {
+                                    log::debug!("[background_syncing] error: {}", err);
+                                }
+                            }
+                        })
+                        .await;
                     // split interval syncing to seconds so stopping the process doesn't have to wait long
                     let seconds = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL).as_secs();
                     for _ in 0..seconds {
@@ -44,16 +44,20 @@ where
             pow_worker_count,
             #[cfg(not(target_family = "wasm"))]
             max_parallel_api_requests,
+            response_cache_size,
+            tag_protocols,
         } = client_options;
 
         // Only check bech32 if something in the node_manager_builder changed
         let change_in_node_manager = self.client_options().await.node_manager_builder != node_manager_builder;
 
         self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .update_node_manager(node_manager_builder.build(HashMap::new())?)
             .await?;
         *self.client.api_timeout.write().await = api_timeout;
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        *self.client.tag_protocols.write().await = tag_protocols;
+        self.client.set_response_cache_size(response_cache_size);
         #[cfg(not(target_family = "wasm"))]
         self.client.request_pool.resize(max_parallel_api_requests).await;
         #[cfg(not(target_family = "wasm"))]
@@ -172,7 +176,7 @@ where
         }
 
         self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
+            .update_node_manager(node_manager_builder.build(HashMap::new())?)
             .await?;
 
         for account in self.accounts.write().await.iter_mut() {
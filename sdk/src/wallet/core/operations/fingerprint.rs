@@ -0,0 +1,88 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip39::wordlist,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{client::secret::GenerateAddressOptions, utils::serde::prefix_hex_bytes, wallet::Wallet};
+
+/// Domain separator, so the fingerprint can't be confused with other hashes of the address.
+const FINGERPRINT_DOMAIN: &[u8] = b"iota-sdk-wallet-fingerprint";
+
+/// A short, deterministic and non-secret identifier of the seed of a wallet.
+///
+/// It's derived from the first address of the first account, which is the hash of its public key, so it can be shown
+/// after a restore to let users verify that they recovered the same seed, without revealing any address.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct WalletFingerprint(#[serde(with = "prefix_hex_bytes")] [u8; Self::LENGTH]);
+
+impl WalletFingerprint {
+    /// The length of a fingerprint in bytes.
+    pub const LENGTH: usize = 6;
+    /// The number of words of a fingerprint, each word encodes 11 bits.
+    pub const WORD_COUNT: usize = Self::LENGTH * 8 / 11;
+
+    /// Derives the fingerprint from the bytes of an address.
+    pub fn from_address_bytes(address: &[u8]) -> Self {
+        let hash = Blake2b256::new()
+            .chain_update(FINGERPRINT_DOMAIN)
+            .chain_update(address)
+            .finalize();
+
+        Self(hash[..Self::LENGTH].try_into().unwrap())
+    }
+
+    /// Returns the bytes of the fingerprint.
+    pub fn as_bytes(&self) -> &[u8; Self::LENGTH] {
+        &self.0
+    }
+
+    /// Returns the fingerprint as prefix hex.
+    pub fn to_hex(&self) -> String {
+        prefix_hex::encode(self.0)
+    }
+
+    /// Returns the fingerprint as words of the english BIP39 wordlist, which are easier to compare than hex.
+    pub fn words(&self) -> Vec<&'static str> {
+        let words = wordlist::ENGLISH.words();
+        let bits = self.0.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+        let unused_bits = Self::LENGTH * 8 - Self::WORD_COUNT * 11;
+
+        (0..Self::WORD_COUNT)
+            .rev()
+            .map(|i| words[((bits >> (unused_bits + i * 11)) & 0x7ff) as usize])
+            .collect()
+    }
+}
+
+impl fmt::Display for WalletFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+impl Wallet {
+    /// Returns the fingerprint of the seed of the wallet, see [`WalletFingerprint`].
+    ///
+    /// The fingerprint also depends on the coin type, so restoring the same seed with another coin type results in a
+    /// different fingerprint.
+    /// ```ignore
+    /// let fingerprint = wallet.fingerprint().await?;
+    /// println!("{fingerprint} ({})", fingerprint.words().join(" "));
+    /// ```
+    pub async fn fingerprint(&self) -> crate::wallet::Result<WalletFingerprint> {
+        // Never prompt on a ledger, the fingerprint doesn't reveal the address
+        let options = GenerateAddressOptions {
+            ledger_nano_prompt: false,
+            ..Default::default()
+        };
+        let address = self.generate_ed25519_address(0, 0, options).await?;
+
+        Ok(WalletFingerprint::from_address_bytes(address.as_ref()))
+    }
+}
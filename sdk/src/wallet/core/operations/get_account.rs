@@ -5,12 +5,13 @@ use crate::{
     client::secret::SecretManage,
     wallet::{
         account::{types::AccountIdentifier, Account},
+        label::account_aliases_match,
         Wallet,
     },
 };
 
 impl<S: SecretManage> Wallet<S> {
-    /// Get an account with an AccountIdentifier
+    /// Get an account with an AccountIdentifier, aliases are matched ignoring case and their Unicode encoding
     pub async fn get_account<I: Into<AccountIdentifier> + Send>(
         &self,
         identifier: I,
@@ -32,7 +33,7 @@ impl<S: SecretManage> Wallet<S> {
                 for account in accounts.iter() {
                     let account_details = account.details().await;
 
-                    if account_details.alias() == alias {
+                    if account_aliases_match(account_details.alias(), alias) {
                         return Ok(account.clone());
                     }
                 }
@@ -49,10 +50,18 @@ impl<S: 'static + SecretManage> Wallet<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
+    /// Get the account with an alias or create it if it doesn't exist yet. Concurrent calls with the same alias
+    /// return the same account.
     pub async fn get_or_create_account(&self, alias: impl Into<String> + Send) -> crate::wallet::Result<Account<S>> {
         let alias = alias.into();
         match self.get_account(&alias).await {
-            Err(crate::wallet::Error::AccountNotFound(_)) => self.create_account().with_alias(alias).finish().await,
+            Err(crate::wallet::Error::AccountNotFound(_)) => {
+                self.create_account()
+                    .with_alias(alias)
+                    .with_return_existing(true)
+                    .finish()
+                    .await
+            }
             res => res,
         }
     }
@@ -1,25 +1,47 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "ledger_nano")]
+use crate::client::secret::ledger_nano::LedgerSecretManager;
 use crate::{
-    client::secret::{ledger_nano::LedgerSecretManager, LedgerNanoStatus, SecretManager},
+    client::secret::{LedgerNanoStatus, SecretManager},
     wallet::Wallet,
 };
 
+#[cfg(feature = "ledger_nano")]
 impl Wallet<LedgerSecretManager> {
     /// Get the ledger nano status
     pub async fn get_ledger_nano_status(&self) -> crate::wallet::Result<LedgerNanoStatus> {
         Ok(self.secret_manager.read().await.get_ledger_nano_status().await)
     }
+
+    /// Sets whether transactions that can't be displayed on the ledger nano can be blind signed.
+    pub async fn set_allow_blind_signing(&self, allow_blind_signing: bool) {
+        self.secret_manager.write().await.allow_blind_signing = allow_blind_signing;
+    }
 }
 
 impl Wallet {
     /// Get the ledger nano status
     pub async fn get_ledger_nano_status(&self) -> crate::wallet::Result<LedgerNanoStatus> {
-        if let SecretManager::LedgerNano(ledger) = &*self.secret_manager.read().await {
-            Ok(ledger.get_ledger_nano_status().await)
-        } else {
-            Err(crate::client::Error::SecretManagerMismatch.into())
+        match &*self.secret_manager.read().await {
+            #[cfg(feature = "ledger_nano")]
+            SecretManager::LedgerNano(ledger) => Ok(ledger.get_ledger_nano_status().await),
+            #[cfg(feature = "ledger_nano_simulator")]
+            SecretManager::LedgerSimulator(simulator) => Ok(simulator.get_ledger_nano_status().await),
+            _ => Err(crate::client::Error::SecretManagerMismatch.into()),
+        }
+    }
+
+    /// Sets whether transactions that can't be displayed on the ledger nano can be blind signed.
+    pub async fn set_allow_blind_signing(&self, allow_blind_signing: bool) -> crate::wallet::Result<()> {
+        match &mut *self.secret_manager.write().await {
+            #[cfg(feature = "ledger_nano")]
+            SecretManager::LedgerNano(ledger) => ledger.allow_blind_signing = allow_blind_signing,
+            #[cfg(feature = "ledger_nano_simulator")]
+            SecretManager::LedgerSimulator(simulator) => simulator.allow_blind_signing = allow_blind_signing,
+            _ => return Err(crate::client::Error::SecretManagerMismatch.into()),
         }
+        Ok(())
     }
 }
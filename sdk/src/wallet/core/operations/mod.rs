@@ -2,16 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod account_recovery;
+pub(crate) mod address_book;
 pub(crate) mod address_generation;
+pub(crate) mod auto_claiming;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
+pub(crate) mod fingerprint;
 pub(crate) mod get_account;
-#[cfg(feature = "ledger_nano")]
+#[cfg(any(feature = "ledger_nano", feature = "ledger_nano_simulator"))]
 pub(crate) mod ledger_nano;
+pub(crate) mod pending_transactions_monitor;
+pub(crate) mod spend_policy;
 pub(crate) mod storage;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold_backup;
+pub(crate) mod sweep;
+pub(crate) mod syncing;
 #[cfg(debug_assertions)]
 pub(crate) mod verify_integrity;
@@ -0,0 +1,95 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use tokio::time::sleep;
+
+use crate::{client::secret::SecretManage, wallet::Wallet};
+
+/// The default interval for monitoring the pending transactions
+pub(crate) const DEFAULT_PENDING_TRANSACTIONS_MONITOR_INTERVAL: Duration = Duration::from_secs(5);
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start monitoring the pending transactions of all accounts, default interval is 5 seconds. Each interval the
+    /// pending transactions are promoted or reattached until they're included or conflicting, see
+    /// [`Account::retry_pending_transactions()`](crate::wallet::Account::retry_pending_transactions).
+    pub async fn start_pending_transactions_monitor(&self, interval: Option<Duration>) -> crate::wallet::Result<()> {
+        log::debug!("[start_pending_transactions_monitor]");
+        // stop existing process if running
+        if self.pending_transactions_monitor_status.load(Ordering::Relaxed) == 1 {
+            self.pending_transactions_monitor_status.store(2, Ordering::Relaxed);
+        };
+        while self.pending_transactions_monitor_status.load(Ordering::Relaxed) == 2 {
+            log::debug!("[pending_transactions_monitor]: waiting for the old process to stop");
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        self.pending_transactions_monitor_status.store(1, Ordering::Relaxed);
+        let wallet = self.clone();
+        let _pending_transactions_monitor = std::thread::spawn(move || {
+            #[cfg(not(target_family = "wasm"))]
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            #[cfg(target_family = "wasm")]
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                'outer: loop {
+                    log::debug!("[pending_transactions_monitor]: retrying pending transactions");
+                    let accounts = wallet.accounts.read().await.clone();
+                    for account in accounts {
+                        // Check if the process should stop before each account so it stops faster
+                        if wallet.pending_transactions_monitor_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[pending_transactions_monitor]: stopping");
+                            break 'outer;
+                        }
+                        if let Err(err) = account.retry_pending_transactions().await {
+                            log::debug!("[pending_transactions_monitor] error: {}", err);
+                        }
+                    }
+                    // split interval to seconds so stopping the process doesn't have to wait long
+                    let seconds = interval
+                        .unwrap_or(DEFAULT_PENDING_TRANSACTIONS_MONITOR_INTERVAL)
+                        .as_secs();
+                    for _ in 0..seconds {
+                        if wallet.pending_transactions_monitor_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[pending_transactions_monitor]: stopping");
+                            break 'outer;
+                        }
+                        sleep(Duration::from_secs(1)).await;
+                    }
+                }
+                wallet.pending_transactions_monitor_status.store(0, Ordering::Relaxed);
+                log::debug!("[pending_transactions_monitor]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    /// Stop monitoring the pending transactions
+    pub async fn stop_pending_transactions_monitor(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_pending_transactions_monitor]");
+        // immediately return if not running
+        if self.pending_transactions_monitor_status.load(Ordering::Relaxed) == 0 {
+            return Ok(());
+        }
+        // send stop request
+        self.pending_transactions_monitor_status.store(2, Ordering::Relaxed);
+        // wait until it stopped
+        while self.pending_transactions_monitor_status.load(Ordering::Relaxed) != 0 {
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(10).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        Ok(())
+    }
+}
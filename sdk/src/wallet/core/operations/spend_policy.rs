@@ -0,0 +1,346 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::{types::InputSigningData, SecretManage},
+    types::block::{
+        address::{Address, Bech32Address},
+        output::Output,
+        payload::transaction::TransactionEssence,
+    },
+    wallet::{
+        account::{operations::transaction_export::TransactionDirection, types::InclusionState, Account},
+        Wallet,
+    },
+};
+
+const DAY_IN_MILLIS: u128 = 24 * 60 * 60 * 1000;
+
+/// A transaction that is checked by a [`SpendPolicy`], passed to its confirmation callback.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpendRequest {
+    /// The index of the account that sends the transaction.
+    pub account_index: u32,
+    /// The addresses outside of the account that receive outputs of the transaction.
+    pub destinations: Vec<Bech32Address>,
+    /// The base coin amount sent to the destinations.
+    pub amount: u64,
+    /// The base coin amount the account sent to other addresses in the last 24 hours, without this transaction.
+    pub spent_last_day: u64,
+}
+
+/// A callback that gets called for each [`SpendRequest`] that passed the other rules of a [`SpendPolicy`], the
+/// transaction is only prepared, signed or submitted if it returns `true`.
+#[derive(Clone)]
+pub struct SpendConfirmationCallback(Arc<dyn Fn(&SpendRequest) -> bool + Send + Sync>);
+
+impl SpendConfirmationCallback {
+    /// Creates a new [`SpendConfirmationCallback`].
+    pub fn new(callback: impl Fn(&SpendRequest) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    fn confirm(&self, request: &SpendRequest) -> bool {
+        (self.0)(request)
+    }
+}
+
+impl fmt::Debug for SpendConfirmationCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SpendConfirmationCallback")
+    }
+}
+
+/// Rules that every transaction prepared, signed or submitted by an account of the wallet must follow, e.g. for
+/// custodial and treasury deployments, see [`Wallet::set_spend_policy()`].
+///
+/// Outputs count as spent when their address, governor address or immutable alias address doesn't belong to the
+/// account or to an alias or nft output the transaction consumes. A verified transaction counts against the daily
+/// limit until it's submitted, or for 24 hours if it never is.
+#[derive(Clone, Debug, Default)]
+pub struct SpendPolicy {
+    /// The only addresses outputs can be sent to, any address if `None`. The human readable part is ignored.
+    pub allowed_destinations: Option<HashSet<Address>>,
+    /// The max base coin amount an account can send to other addresses in 24 hours.
+    pub daily_limit: Option<u64>,
+    /// A callback that has to confirm every transaction that sends outputs to other addresses.
+    pub confirmation: Option<SpendConfirmationCallback>,
+}
+
+impl SpendPolicy {
+    /// Creates a new [`SpendPolicy`] without rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only allows sending outputs to these addresses.
+    pub fn with_allowed_destinations(mut self, destinations: impl IntoIterator<Item = Bech32Address>) -> Self {
+        self.allowed_destinations = Some(destinations.into_iter().map(|address| *address.inner()).collect());
+        self
+    }
+
+    /// Sets the max base coin amount an account can send to other addresses in 24 hours.
+    pub fn with_daily_limit(mut self, daily_limit: impl Into<Option<u64>>) -> Self {
+        self.daily_limit = daily_limit.into();
+        self
+    }
+
+    /// Sets a callback that has to confirm every transaction that sends outputs to other addresses.
+    pub fn with_confirmation(mut self, callback: impl Fn(&SpendRequest) -> bool + Send + Sync + 'static) -> Self {
+        self.confirmation = Some(SpendConfirmationCallback::new(callback));
+        self
+    }
+
+    fn verify(&self, request: &SpendRequest) -> crate::wallet::Result<()> {
+        if let Some(allowed_destinations) = &self.allowed_destinations {
+            if let Some(destination) = request
+                .destinations
+                .iter()
+                .find(|destination| !allowed_destinations.contains(destination.inner()))
+            {
+                return Err(crate::wallet::Error::SpendDestinationNotAllowed(*destination));
+            }
+        }
+
+        if let Some(daily_limit) = self.daily_limit {
+            if request.spent_last_day.saturating_add(request.amount) > daily_limit {
+                return Err(crate::wallet::Error::SpendLimitExceeded {
+                    limit: daily_limit,
+                    spent: request.spent_last_day,
+                    amount: request.amount,
+                });
+            }
+        }
+
+        if let Some(confirmation) = &self.confirmation {
+            if !confirmation.confirm(request) {
+                return Err(crate::wallet::Error::SpendNotConfirmed);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A verified transaction that isn't submitted yet, so it isn't part of the transactions of the account.
+#[derive(Debug)]
+pub(crate) struct PendingSpend {
+    account_index: u32,
+    amount: u64,
+    timestamp: u128,
+}
+
+/// The pending spends of the wallet by the hash of their transaction essence.
+pub(crate) type PendingSpends = HashMap<[u8; 32], PendingSpend>;
+
+fn output_destination(output: &Output) -> Option<&Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+    unlock_conditions
+        .address()
+        .map(|unlock_condition| unlock_condition.address())
+        .or_else(|| {
+            unlock_conditions
+                .governor_address()
+                .map(|unlock_condition| unlock_condition.address())
+        })
+        .or_else(|| {
+            unlock_conditions
+                .immutable_alias_address()
+                .map(|unlock_condition| unlock_condition.address())
+        })
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the spend policy of the wallet.
+    pub async fn spend_policy(&self) -> Option<SpendPolicy> {
+        self.spend_policy.lock().await.clone()
+    }
+
+    /// Sets or removes the spend policy that every transaction prepared, signed or submitted by an account of the
+    /// wallet must follow. The policy isn't stored and needs to be set again after a restart.
+    pub async fn set_spend_policy(&self, spend_policy: impl Into<Option<SpendPolicy>> + Send) {
+        *self.spend_policy.lock().await = spend_policy.into();
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Verifies a transaction against the spend policy of the wallet, if one is set, and reserves its amount until
+    /// it's submitted. Transactions that were already verified aren't verified again.
+    pub(crate) async fn verify_spend_policy(
+        &self,
+        essence: &TransactionEssence,
+        inputs_data: &[InputSigningData],
+    ) -> crate::wallet::Result<()> {
+        // Held until the amount is reserved, so concurrent transactions can't exceed the daily limit together
+        let spend_policy = self.wallet.spend_policy.lock().await;
+        let Some(spend_policy) = spend_policy.as_ref() else {
+            return Ok(());
+        };
+
+        let essence_hash = essence.hash();
+        let now = crate::utils::unix_timestamp_now().as_millis();
+        let mut pending_spends = self.wallet.pending_spends.lock().await;
+        pending_spends.retain(|_, pending_spend| now.saturating_sub(pending_spend.timestamp) < DAY_IN_MILLIS);
+        if pending_spends.contains_key(&essence_hash) {
+            return Ok(());
+        }
+
+        let (account_index, mut own_addresses) = {
+            let account_details = self.details().await;
+            (
+                *account_details.index(),
+                account_details
+                    .addresses()
+                    .into_iter()
+                    .map(|address| address.address.inner)
+                    .collect::<HashSet<_>>(),
+            )
+        };
+        for input in inputs_data {
+            match &input.output {
+                Output::Alias(alias_output) => {
+                    own_addresses.insert(Address::Alias(alias_output.alias_address(input.output_id())));
+                }
+                Output::Nft(nft_output) => {
+                    own_addresses.insert(Address::Nft(nft_output.nft_address(input.output_id())));
+                }
+                _ => {}
+            }
+        }
+
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let TransactionEssence::Regular(essence) = essence;
+        let mut destinations = Vec::new();
+        let mut amount = 0;
+        for output in essence.outputs() {
+            match output_destination(output) {
+                Some(address) if own_addresses.contains(address) => {}
+                destination => {
+                    amount += output.amount();
+                    if let Some(address) = destination {
+                        let destination = Bech32Address::new(bech32_hrp, *address);
+                        if !destinations.contains(&destination) {
+                            destinations.push(destination);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Transactions that only move funds between addresses of the account don't spend anything
+        if amount == 0 && destinations.is_empty() {
+            return Ok(());
+        }
+
+        let spent_last_day = if spend_policy.daily_limit.is_some() || spend_policy.confirmation.is_some() {
+            let submitted = self
+                .transaction_export_records(now.saturating_sub(DAY_IN_MILLIS)..)
+                .await?
+                .iter()
+                .filter(|record| {
+                    record.direction == TransactionDirection::Outgoing
+                        && record.inclusion_state != InclusionState::Conflicting
+                })
+                .map(|record| record.amount)
+                .sum::<u64>();
+            let pending = pending_spends
+                .values()
+                .filter(|pending_spend| pending_spend.account_index == account_index)
+                .map(|pending_spend| pending_spend.amount)
+                .sum::<u64>();
+            submitted.saturating_add(pending)
+        } else {
+            0
+        };
+
+        spend_policy.verify(&SpendRequest {
+            account_index,
+            destinations,
+            amount,
+            spent_last_day,
+        })?;
+
+        pending_spends.insert(
+            essence_hash,
+            PendingSpend {
+                account_index,
+                amount,
+                timestamp: now,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Releases the reserved amount of a transaction, after it's stored in the account or if it can't be sent.
+    pub(crate) async fn release_pending_spend(&self, essence: &TransactionEssence) {
+        self.wallet.pending_spends.lock().await.remove(&essence.hash());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::address::{Ed25519Address, Hrp};
+
+    fn request(destination: Bech32Address, amount: u64, spent_last_day: u64) -> SpendRequest {
+        SpendRequest {
+            account_index: 0,
+            destinations: vec![destination],
+            amount,
+            spent_last_day,
+        }
+    }
+
+    #[test]
+    fn spend_policy_rules() {
+        let allowed = Bech32Address::new(
+            Hrp::from_str_unchecked("rms"),
+            Address::from(Ed25519Address::new([1; 32])),
+        );
+        let other = Bech32Address::new(
+            Hrp::from_str_unchecked("rms"),
+            Address::from(Ed25519Address::new([2; 32])),
+        );
+
+        assert!(SpendPolicy::new().verify(&request(other, u64::MAX, 0)).is_ok());
+
+        let policy = SpendPolicy::new()
+            .with_allowed_destinations([allowed])
+            .with_daily_limit(1_000)
+            .with_confirmation(|request| request.amount != 42);
+
+        assert!(policy.verify(&request(allowed, 400, 600)).is_ok());
+        assert!(matches!(
+            policy.verify(&request(other, 1, 0)),
+            Err(crate::wallet::Error::SpendDestinationNotAllowed(address)) if address == other
+        ));
+        assert!(matches!(
+            policy.verify(&request(allowed, 401, 600)),
+            Err(crate::wallet::Error::SpendLimitExceeded {
+                limit: 1_000,
+                spent: 600,
+                amount: 401
+            })
+        ));
+        assert!(matches!(
+            policy.verify(&request(allowed, 42, 0)),
+            Err(crate::wallet::Error::SpendNotConfirmed)
+        ));
+    }
+}
@@ -4,6 +4,8 @@
 use std::time::Duration;
 
 use crypto::keys::bip39::Mnemonic;
+#[cfg(feature = "storage")]
+use zeroize::Zeroizing;
 
 use crate::{
     client::{secret::SecretManager, stronghold::StrongholdAdapter, utils::Password},
@@ -33,14 +35,33 @@ impl Wallet {
         let new_password = new_password.into();
 
         if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
-            stronghold.set_password(current_password).await?;
-            stronghold.change_password(new_password).await?;
+            stronghold.rotate_password(current_password, new_password).await?;
             Ok(())
         } else {
             Err(crate::client::Error::SecretManagerMismatch.into())
         }
     }
 
+    /// Changes the Stronghold password and re-encrypts the wallet storage with a new encryption key, or stores it
+    /// unencrypted if `None`. The Stronghold password is changed first, so if re-encrypting the storage fails, only the
+    /// Stronghold password has been changed. The wallet needs to be built with the new encryption key afterwards.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn rotate_passwords(
+        &self,
+        current_password: impl Into<Password> + Send,
+        new_password: impl Into<Password> + Send,
+        storage_encryption_key: impl Into<Option<[u8; 32]>> + Send,
+    ) -> crate::wallet::Result<()> {
+        let storage_encryption_key = storage_encryption_key.into().map(Zeroizing::new);
+        self.change_stronghold_password(current_password, new_password).await?;
+        self.storage_manager
+            .write()
+            .await
+            .change_encryption_key(storage_encryption_key)
+            .await
+    }
+
     /// Sets the Stronghold password clear interval
     pub async fn set_stronghold_password_clear_interval(&self, timeout: Option<Duration>) -> crate::wallet::Result<()> {
         if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
@@ -95,10 +116,32 @@ impl Wallet<StrongholdAdapter> {
         current_password: impl Into<Password> + Send,
         new_password: impl Into<Password> + Send,
     ) -> crate::wallet::Result<()> {
-        let stronghold = &mut *self.secret_manager.write().await;
-        stronghold.set_password(current_password).await?;
-        stronghold.change_password(new_password).await?;
-        Ok(())
+        Ok(self
+            .secret_manager
+            .write()
+            .await
+            .rotate_password(current_password, new_password)
+            .await?)
+    }
+
+    /// Changes the Stronghold password and re-encrypts the wallet storage with a new encryption key, or stores it
+    /// unencrypted if `None`. The Stronghold password is changed first, so if re-encrypting the storage fails, only the
+    /// Stronghold password has been changed. The wallet needs to be built with the new encryption key afterwards.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub async fn rotate_passwords(
+        &self,
+        current_password: impl Into<Password> + Send,
+        new_password: impl Into<Password> + Send,
+        storage_encryption_key: impl Into<Option<[u8; 32]>> + Send,
+    ) -> crate::wallet::Result<()> {
+        let storage_encryption_key = storage_encryption_key.into().map(Zeroizing::new);
+        self.change_stronghold_password(current_password, new_password).await?;
+        self.storage_manager
+            .write()
+            .await
+            .change_encryption_key(storage_encryption_key)
+            .await
     }
 
     /// Sets the Stronghold password clear interval
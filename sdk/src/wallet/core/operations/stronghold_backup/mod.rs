@@ -1,12 +1,12 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod options;
 pub(crate) mod stronghold_snapshot;
 
-use std::{fs, path::PathBuf, sync::atomic::Ordering};
-
-use futures::{future::try_join_all, FutureExt};
+use std::{fs, path::PathBuf, str::FromStr, sync::atomic::Ordering};
 
+pub use self::options::{BackupOptions, RestoreConflictPolicy, RestoreOptions};
 use self::stronghold_snapshot::read_data_from_stronghold_snapshot;
 #[cfg(feature = "storage")]
 use crate::{
@@ -15,11 +15,13 @@ use crate::{
 };
 use crate::{
     client::{
-        secret::{stronghold::StrongholdSecretManager, SecretManager, SecretManagerConfig, SecretManagerDto},
+        secret::{
+            stronghold::StrongholdSecretManager, SecretManage, SecretManager, SecretManagerConfig, SecretManagerDto,
+        },
         utils::Password,
     },
-    types::block::address::Hrp,
-    wallet::{Account, Wallet},
+    types::block::address::{Address, Hrp},
+    wallet::{account::AccountDetails, label::account_aliases_match, Account, Wallet},
 };
 
 impl Wallet {
@@ -29,6 +31,18 @@ impl Wallet {
         &self,
         backup_path: PathBuf,
         stronghold_password: impl Into<Password> + Send,
+    ) -> crate::wallet::Result<()> {
+        self.backup_with_options(backup_path, stronghold_password, BackupOptions::default())
+            .await
+    }
+
+    /// Backup the wallet data in a Stronghold file, only including the accounts and client options selected by the
+    /// options. `stronghold_password` must be the current one when Stronghold is used as SecretManager.
+    pub async fn backup_with_options(
+        &self,
+        backup_path: PathBuf,
+        stronghold_password: impl Into<Password> + Send,
+        options: BackupOptions,
     ) -> crate::wallet::Result<()> {
         let stronghold_password = stronghold_password.into();
 
@@ -39,7 +53,7 @@ impl Wallet {
             // Backup with existing stronghold
             SecretManager::Stronghold(stronghold) => {
                 stronghold.set_password(stronghold_password).await?;
-                self.store_data_to_stronghold(stronghold).await?;
+                self.store_data_to_stronghold(stronghold, &options).await?;
                 // Write snapshot to backup path
                 stronghold.write_stronghold_snapshot(Some(&backup_path)).await?;
             }
@@ -50,7 +64,7 @@ impl Wallet {
                     .password(stronghold_password)
                     .build(backup_path)?;
 
-                self.store_data_to_stronghold(&backup_stronghold).await?;
+                self.store_data_to_stronghold(&backup_stronghold, &options).await?;
 
                 // Write snapshot to backup path
                 backup_stronghold.write_stronghold_snapshot(None).await?;
@@ -75,6 +89,25 @@ impl Wallet {
         stronghold_password: impl Into<Password> + Send,
         ignore_if_coin_type_mismatch: Option<bool>,
         ignore_if_bech32_hrp_mismatch: Option<Hrp>,
+    ) -> crate::wallet::Result<()> {
+        self.restore_backup_with_options(
+            backup_path,
+            stronghold_password,
+            RestoreOptions::default()
+                .with_ignore_if_coin_type_mismatch(ignore_if_coin_type_mismatch)
+                .with_ignore_if_bech32_hrp_mismatch(ignore_if_bech32_hrp_mismatch),
+        )
+        .await
+    }
+
+    /// Restore the wallet from a Stronghold backup file, only restoring the accounts and client options selected by
+    /// the options. If the wallet already has accounts, the backup is merged into it according to the
+    /// [`RestoreConflictPolicy`], keeping the secret manager and coin type of the wallet.
+    pub async fn restore_backup_with_options(
+        &self,
+        backup_path: PathBuf,
+        stronghold_password: impl Into<Password> + Send,
+        options: RestoreOptions,
     ) -> crate::wallet::Result<()> {
         let stronghold_password = stronghold_password.into();
 
@@ -85,15 +118,17 @@ impl Wallet {
         }
 
         // We don't want to overwrite possible existing accounts
-        if !self.accounts.read().await.is_empty() {
+        let account_count = self.accounts.read().await.len();
+        let merge = account_count > 0;
+        if merge && options.conflict_policy == RestoreConflictPolicy::Fail {
             return Err(crate::wallet::Error::Backup(
                 "can't restore backup when there are already accounts",
             ));
         }
+        options.verify_account_indexes(account_count as u32)?;
 
-        let mut secret_manager = self.secret_manager.as_ref().write().await;
         // Get the current snapshot path if set
-        let new_snapshot_path = if let SecretManager::Stronghold(stronghold) = &mut *secret_manager {
+        let new_snapshot_path = if let SecretManager::Stronghold(stronghold) = &*self.secret_manager.read().await {
             stronghold.snapshot_path.clone()
         } else {
             PathBuf::from("wallet.stronghold")
@@ -112,49 +147,60 @@ impl Wallet {
 
         // If the coin type is not matching the current one, then the addresses in the accounts will also not be
         // correct, so we will not restore them
-        let ignore_backup_values = ignore_if_coin_type_mismatch.map_or(false, |ignore| {
-            if ignore {
-                read_coin_type.map_or(true, |read_coin_type| {
-                    self.coin_type.load(Ordering::Relaxed) != read_coin_type
-                })
-            } else {
-                false
-            }
-        });
+        let ignore_backup_values = self.ignore_backup_values(&options, read_coin_type);
+
+        // Validate everything before the state of the wallet is changed, so a failed restore doesn't leave it half
+        // restored.
+        if merge && !ignore_backup_values {
+            // Keep the secret manager and coin type of the wallet, the accounts of the backup need to be derived from
+            // the same seed
+            self.verify_mergeable_backup(read_coin_type, read_accounts.as_deref())
+                .await?;
+        }
 
-        if !ignore_backup_values {
-            if let Some(read_coin_type) = read_coin_type {
-                self.coin_type.store(read_coin_type, Ordering::Relaxed);
+        let restored_accounts = match read_accounts {
+            Some(read_accounts) if !ignore_backup_values => {
+                self.merge_read_accounts(&self.accounts.read().await, read_accounts, &options)
+                    .await?
             }
-        }
+            _ => None,
+        };
+
+        let restored_secret_manager = match read_secret_manager {
+            Some(mut read_secret_manager) if !merge => {
+                // We have to replace the snapshot path with the current one, when building stronghold
+                if let SecretManagerDto::Stronghold(stronghold_dto) = &mut read_secret_manager {
+                    stronghold_dto.snapshot_path = new_snapshot_path.display().to_string();
+                }
 
-        if let Some(mut read_secret_manager) = read_secret_manager {
-            // We have to replace the snapshot path with the current one, when building stronghold
-            if let SecretManagerDto::Stronghold(stronghold_dto) = &mut read_secret_manager {
-                stronghold_dto.snapshot_path = new_snapshot_path.display().to_string();
+                Some(
+                    SecretManager::from_config(&read_secret_manager)
+                        .map_err(|_| crate::wallet::Error::Backup("invalid secret_manager"))?,
+                )
             }
+            _ => None,
+        };
 
-            let restored_secret_manager = SecretManager::from_config(&read_secret_manager)
-                .map_err(|_| crate::wallet::Error::Backup("invalid secret_manager"))?;
+        if !merge {
+            if !ignore_backup_values {
+                if let Some(read_coin_type) = read_coin_type {
+                    self.coin_type.store(read_coin_type, Ordering::Relaxed);
+                }
+            }
 
             // Copy Stronghold file so the seed is available in the new location
             fs::copy(backup_path, new_snapshot_path)?;
 
-            if let SecretManager::Stronghold(stronghold) = &restored_secret_manager {
-                // Set password to restored secret manager
-                stronghold.set_password(stronghold_password).await?;
+            if let Some(restored_secret_manager) = restored_secret_manager {
+                if let SecretManager::Stronghold(stronghold) = &restored_secret_manager {
+                    // Set password to restored secret manager
+                    stronghold.set_password(stronghold_password).await?;
+                }
+                *self.secret_manager.as_ref().write().await = restored_secret_manager;
             }
-            *secret_manager = restored_secret_manager;
-        } else {
-            // If no secret manager data was in the backup, just copy the Stronghold file so the seed is available in
-            // the new location.
-            fs::copy(backup_path, new_snapshot_path)?;
         }
 
-        // drop secret manager, otherwise we get a deadlock in set_client_options() (there inside of save_wallet_data())
-        drop(secret_manager);
-
-        if ignore_if_coin_type_mismatch.is_none() {
+        if options.restores_client_options() {
             if let Some(read_client_options) = read_client_options {
                 self.set_client_options(read_client_options).await?;
             }
@@ -162,31 +208,8 @@ impl Wallet {
 
         let mut accounts = self.accounts.write().await;
 
-        if !ignore_backup_values {
-            if let Some(read_accounts) = read_accounts {
-                let restore_accounts = ignore_if_bech32_hrp_mismatch.map_or(true, |expected_bech32_hrp| {
-                    // Only restore if bech32 hrps match
-                    read_accounts.first().map_or(true, |account| {
-                        account
-                            .public_addresses
-                            .first()
-                            .expect("account needs to have a public address")
-                            .address()
-                            .hrp()
-                            == &expected_bech32_hrp
-                    })
-                });
-
-                if restore_accounts {
-                    let restored_account = try_join_all(
-                        read_accounts
-                            .into_iter()
-                            .map(|a| Account::new(a, self.inner.clone()).boxed()),
-                    )
-                    .await?;
-                    *accounts = restored_account;
-                }
-            }
+        if let Some(restored_accounts) = restored_accounts {
+            *accounts = restored_accounts;
         }
 
         // store new data
@@ -231,13 +254,25 @@ impl Wallet<StrongholdSecretManager> {
         &self,
         backup_path: PathBuf,
         stronghold_password: impl Into<Password> + Send,
+    ) -> crate::wallet::Result<()> {
+        self.backup_with_options(backup_path, stronghold_password, BackupOptions::default())
+            .await
+    }
+
+    /// Backup the wallet data in a Stronghold file, only including the accounts and client options selected by the
+    /// options. `stronghold_password` must be the current one.
+    pub async fn backup_with_options(
+        &self,
+        backup_path: PathBuf,
+        stronghold_password: impl Into<Password> + Send,
+        options: BackupOptions,
     ) -> crate::wallet::Result<()> {
         log::debug!("[backup] creating a stronghold backup");
         let secret_manager = self.secret_manager.read().await;
 
         secret_manager.set_password(stronghold_password).await?;
 
-        self.store_data_to_stronghold(&secret_manager).await?;
+        self.store_data_to_stronghold(&secret_manager, &options).await?;
 
         // Write snapshot to backup path
         secret_manager.write_stronghold_snapshot(Some(&backup_path)).await?;
@@ -260,6 +295,25 @@ impl Wallet<StrongholdSecretManager> {
         stronghold_password: impl Into<Password> + Send,
         ignore_if_coin_type_mismatch: Option<bool>,
         ignore_if_bech32_hrp_mismatch: Option<&str>,
+    ) -> crate::wallet::Result<()> {
+        self.restore_backup_with_options(
+            backup_path,
+            stronghold_password,
+            RestoreOptions::default()
+                .with_ignore_if_coin_type_mismatch(ignore_if_coin_type_mismatch)
+                .with_ignore_if_bech32_hrp_mismatch(ignore_if_bech32_hrp_mismatch.map(Hrp::from_str).transpose()?),
+        )
+        .await
+    }
+
+    /// Restore the wallet from a Stronghold backup file, only restoring the accounts and client options selected by
+    /// the options. If the wallet already has accounts, the backup is merged into it according to the
+    /// [`RestoreConflictPolicy`], keeping the secret manager and coin type of the wallet.
+    pub async fn restore_backup_with_options(
+        &self,
+        backup_path: PathBuf,
+        stronghold_password: impl Into<Password> + Send,
+        options: RestoreOptions,
     ) -> crate::wallet::Result<()> {
         let stronghold_password = stronghold_password.into();
 
@@ -269,17 +323,18 @@ impl Wallet<StrongholdSecretManager> {
             return Err(crate::wallet::Error::Backup("backup path doesn't exist"));
         }
 
-        let mut accounts = self.accounts.write().await;
         // We don't want to overwrite possible existing accounts
-        if !accounts.is_empty() {
+        let account_count = self.accounts.read().await.len();
+        let merge = account_count > 0;
+        if merge && options.conflict_policy == RestoreConflictPolicy::Fail {
             return Err(crate::wallet::Error::Backup(
                 "can't restore backup when there are already accounts",
             ));
         }
+        options.verify_account_indexes(account_count as u32)?;
 
-        let mut secret_manager = self.secret_manager.as_ref().write().await;
         // Get the current snapshot path if set
-        let new_snapshot_path = secret_manager.snapshot_path.clone();
+        let new_snapshot_path = self.secret_manager.read().await.snapshot_path.clone();
 
         // We'll create a new stronghold to load the backup
         let new_stronghold = StrongholdSecretManager::builder()
@@ -294,72 +349,66 @@ impl Wallet<StrongholdSecretManager> {
 
         // If the coin type is not matching the current one, then the addresses in the accounts will also not be
         // correct, so we will not restore them
-        let ignore_backup_values = ignore_if_coin_type_mismatch.map_or(false, |ignore| {
-            if ignore {
-                read_coin_type.map_or(true, |read_coin_type| {
-                    self.coin_type.load(Ordering::Relaxed) != read_coin_type
-                })
-            } else {
-                false
-            }
-        });
+        let ignore_backup_values = self.ignore_backup_values(&options, read_coin_type);
+
+        // Validate everything before the state of the wallet is changed, so a failed restore doesn't leave it half
+        // restored.
+        if merge && !ignore_backup_values {
+            // Keep the secret manager and coin type of the wallet, the accounts of the backup need to be derived from
+            // the same seed
+            self.verify_mergeable_backup(read_coin_type, read_accounts.as_deref())
+                .await?;
+        }
 
-        if !ignore_backup_values {
-            if let Some(read_coin_type) = read_coin_type {
-                self.coin_type.store(read_coin_type, Ordering::Relaxed);
+        let restored_accounts = match read_accounts {
+            Some(read_accounts) if !ignore_backup_values => {
+                self.merge_read_accounts(&self.accounts.read().await, read_accounts, &options)
+                    .await?
             }
-        }
+            _ => None,
+        };
 
-        if let Some(mut read_secret_manager) = read_secret_manager {
-            read_secret_manager.snapshot_path = new_snapshot_path.to_string_lossy().into_owned();
+        let restored_secret_manager = match read_secret_manager {
+            Some(mut read_secret_manager) if !merge => {
+                read_secret_manager.snapshot_path = new_snapshot_path.to_string_lossy().into_owned();
 
-            let restored_secret_manager = StrongholdSecretManager::from_config(&read_secret_manager)
-                .map_err(|_| crate::wallet::Error::Backup("invalid secret_manager"))?;
+                Some(
+                    StrongholdSecretManager::from_config(&read_secret_manager)
+                        .map_err(|_| crate::wallet::Error::Backup("invalid secret_manager"))?,
+                )
+            }
+            _ => None,
+        };
 
-            // Copy Stronghold file so the seed is available in the new location
-            fs::copy(backup_path, new_snapshot_path)?;
+        if !merge {
+            if !ignore_backup_values {
+                if let Some(read_coin_type) = read_coin_type {
+                    self.coin_type.store(read_coin_type, Ordering::Relaxed);
+                }
+            }
 
-            // Set password to restored secret manager
-            restored_secret_manager.set_password(stronghold_password).await?;
-            *secret_manager = restored_secret_manager;
-        }
+            if let Some(restored_secret_manager) = restored_secret_manager {
+                // Copy Stronghold file so the seed is available in the new location
+                fs::copy(backup_path, new_snapshot_path)?;
 
-        // drop secret manager, otherwise we get a deadlock in set_client_options()
-        drop(secret_manager);
+                // Set password to restored secret manager
+                restored_secret_manager.set_password(stronghold_password).await?;
+                *self.secret_manager.as_ref().write().await = restored_secret_manager;
+            }
+        }
 
         // Update Wallet with read data
-        if ignore_if_coin_type_mismatch.is_none() {
+        if options.restores_client_options() {
             if let Some(read_client_options) = read_client_options {
                 // If the nodes are from the same network as the current client options, then extend it
                 self.set_client_options(read_client_options).await?;
             }
         }
 
-        if !ignore_backup_values {
-            if let Some(read_accounts) = read_accounts {
-                let restore_accounts = ignore_if_bech32_hrp_mismatch.map_or(true, |expected_bech32_hrp| {
-                    // Only restore if bech32 hrps match
-                    read_accounts.first().map_or(true, |account| {
-                        account
-                            .public_addresses
-                            .first()
-                            .expect("account needs to have a public address")
-                            .address()
-                            .hrp()
-                            == expected_bech32_hrp
-                    })
-                });
-
-                if restore_accounts {
-                    let restored_account = try_join_all(
-                        read_accounts
-                            .into_iter()
-                            .map(|a| Account::new(a, self.inner.clone()).boxed()),
-                    )
-                    .await?;
-                    *accounts = restored_account;
-                }
-            }
+        let mut accounts = self.accounts.write().await;
+
+        if let Some(restored_accounts) = restored_accounts {
+            *accounts = restored_accounts;
         }
 
         // store new data
@@ -396,3 +445,133 @@ impl Wallet<StrongholdSecretManager> {
         Ok(())
     }
 }
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    // If the coin type is not matching the current one, then the addresses in the accounts will also not be correct,
+    // so we will not restore them
+    fn ignore_backup_values(&self, options: &RestoreOptions, read_coin_type: Option<u32>) -> bool {
+        options.ignore_if_coin_type_mismatch.is_some_and(|ignore| {
+            if ignore {
+                read_coin_type.is_none_or(|read_coin_type| self.coin_type.load(Ordering::Relaxed) != read_coin_type)
+            } else {
+                false
+            }
+        })
+    }
+
+    // The secret manager of the wallet is kept when merging, so the accounts of the backup must have been derived with
+    // the same coin type and seed.
+    async fn verify_mergeable_backup(
+        &self,
+        read_coin_type: Option<u32>,
+        read_accounts: Option<&[AccountDetails]>,
+    ) -> crate::wallet::Result<()> {
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        if read_coin_type.is_some_and(|read_coin_type| read_coin_type != coin_type) {
+            return Err(crate::wallet::Error::Backup(
+                "can't merge a backup with a different coin type",
+            ));
+        }
+
+        let Some(account) = read_accounts.and_then(|read_accounts| read_accounts.first()) else {
+            return Ok(());
+        };
+        if let Some(address) = account.public_addresses().first() {
+            let generated_address = self
+                .secret_manager
+                .read()
+                .await
+                .generate_ed25519_addresses(
+                    coin_type,
+                    *account.index(),
+                    address.key_index..address.key_index + 1,
+                    None,
+                )
+                .await?;
+            if generated_address.first().map(|address| Address::Ed25519(*address)) != Some(*address.address().inner()) {
+                return Err(crate::wallet::Error::Backup("can't merge a backup of a different seed"));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns the accounts of the wallet with the selected accounts of the backup merged into them, or `None` if the
+    // backup accounts are ignored because of their bech32 hrp.
+    async fn merge_read_accounts(
+        &self,
+        accounts: &[Account<S>],
+        read_accounts: Vec<AccountDetails>,
+        options: &RestoreOptions,
+    ) -> crate::wallet::Result<Option<Vec<Account<S>>>> {
+        let restore_accounts = options.ignore_if_bech32_hrp_mismatch.is_none_or(|expected_bech32_hrp| {
+            // Only restore if bech32 hrps match
+            read_accounts.first().is_none_or(|account| {
+                account
+                    .public_addresses
+                    .first()
+                    .expect("account needs to have a public address")
+                    .address()
+                    .hrp()
+                    == &expected_bech32_hrp
+            })
+        });
+        if !restore_accounts {
+            return Ok(None);
+        }
+
+        let mut existing_accounts = Vec::with_capacity(accounts.len());
+        for account in accounts.iter() {
+            let details = account.details().await;
+            existing_accounts.push((*details.index(), details.alias().clone(), account.clone()));
+        }
+
+        for read_account in read_accounts
+            .into_iter()
+            .filter(|account| options.includes_account(*account.index()))
+        {
+            let existing_position = existing_accounts
+                .iter()
+                .position(|(index, _, _)| index == read_account.index());
+            if existing_position.is_some() && options.conflict_policy == RestoreConflictPolicy::KeepExisting {
+                continue;
+            }
+            if existing_accounts.iter().any(|(index, alias, _)| {
+                index != read_account.index() && account_aliases_match(alias, read_account.alias())
+            }) {
+                return Err(crate::wallet::Error::AccountAliasAlreadyExists(
+                    read_account.alias().clone(),
+                ));
+            }
+
+            let entry = (
+                *read_account.index(),
+                read_account.alias().clone(),
+                Account::new(read_account, self.inner.clone()).await?,
+            );
+            match existing_position {
+                Some(position) => existing_accounts[position] = entry,
+                None => existing_accounts.push(entry),
+            }
+        }
+
+        // New accounts get the number of accounts as index, so there can't be gaps
+        existing_accounts.sort_by_key(|(index, _, _)| *index);
+        if existing_accounts
+            .iter()
+            .enumerate()
+            .any(|(position, (index, _, _))| position as u32 != *index)
+        {
+            return Err(crate::wallet::Error::Backup(
+                "restoring the accounts would leave a gap in the account indexes",
+            ));
+        }
+
+        Ok(Some(
+            existing_accounts.into_iter().map(|(_, _, account)| account).collect(),
+        ))
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::block::address::Hrp;
+
+/// Options for [`Wallet::backup_with_options()`](crate::wallet::Wallet::backup_with_options).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupOptions {
+    /// Only back up the accounts with these indexes, all accounts are backed up if not set.
+    #[serde(default)]
+    pub account_indexes: Option<HashSet<u32>>,
+    /// Whether the client options are backed up.
+    #[serde(default = "default_true")]
+    pub include_client_options: bool,
+}
+
+impl Default for BackupOptions {
+    fn default() -> Self {
+        Self {
+            account_indexes: None,
+            include_client_options: true,
+        }
+    }
+}
+
+impl BackupOptions {
+    /// Only back up the accounts with the given indexes.
+    pub fn with_account_indexes(mut self, account_indexes: impl IntoIterator<Item = u32>) -> Self {
+        self.account_indexes = Some(account_indexes.into_iter().collect());
+        self
+    }
+
+    /// Sets whether the client options are backed up.
+    pub fn with_include_client_options(mut self, include_client_options: bool) -> Self {
+        self.include_client_options = include_client_options;
+        self
+    }
+
+    pub(crate) fn includes_account(&self, account_index: u32) -> bool {
+        self.account_indexes
+            .as_ref()
+            .is_none_or(|account_indexes| account_indexes.contains(&account_index))
+    }
+}
+
+/// How to handle accounts of a backup that have the same index as an account of the wallet.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RestoreConflictPolicy {
+    /// Don't restore the backup if the wallet already has accounts.
+    #[default]
+    Fail,
+    /// Merge the accounts of the backup into the wallet, keeping the existing accounts with the same index.
+    KeepExisting,
+    /// Merge the accounts of the backup into the wallet, replacing the existing accounts with the same index.
+    ReplaceExisting,
+}
+
+/// Options for [`Wallet::restore_backup_with_options()`](crate::wallet::Wallet::restore_backup_with_options).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreOptions {
+    /// Only restore the accounts with these indexes, all accounts are restored if not set. Account indexes can't have
+    /// gaps, so together with the accounts of the wallet they need to form a range starting at 0, e.g. only restoring
+    /// account 1 into a wallet without accounts is rejected.
+    #[serde(default)]
+    pub account_indexes: Option<HashSet<u32>>,
+    /// Don't restore the client options of the backup.
+    #[serde(default)]
+    pub skip_client_options: bool,
+    /// How to handle existing accounts. When merging into a wallet with accounts, the secret manager and coin type of
+    /// the wallet are kept and need to match the ones of the backup.
+    #[serde(default)]
+    pub conflict_policy: RestoreConflictPolicy,
+    /// If set, client options will not be restored. If `true`, the coin type and accounts will also not be restored if
+    /// the coin type of the backup doesn't match the one of the wallet.
+    #[serde(default)]
+    pub ignore_if_coin_type_mismatch: Option<bool>,
+    /// If set, no accounts will be restored if their addresses have a different bech32 hrp.
+    #[serde(default)]
+    pub ignore_if_bech32_hrp_mismatch: Option<Hrp>,
+}
+
+impl RestoreOptions {
+    /// Only restore the accounts with the given indexes.
+    pub fn with_account_indexes(mut self, account_indexes: impl IntoIterator<Item = u32>) -> Self {
+        self.account_indexes = Some(account_indexes.into_iter().collect());
+        self
+    }
+
+    /// Sets whether restoring the client options is skipped.
+    pub fn with_skip_client_options(mut self, skip_client_options: bool) -> Self {
+        self.skip_client_options = skip_client_options;
+        self
+    }
+
+    /// Sets how existing accounts are handled.
+    pub fn with_conflict_policy(mut self, conflict_policy: RestoreConflictPolicy) -> Self {
+        self.conflict_policy = conflict_policy;
+        self
+    }
+
+    /// Sets whether the backup values are ignored if the coin type doesn't match.
+    pub fn with_ignore_if_coin_type_mismatch(mut self, ignore_if_coin_type_mismatch: impl Into<Option<bool>>) -> Self {
+        self.ignore_if_coin_type_mismatch = ignore_if_coin_type_mismatch.into();
+        self
+    }
+
+    /// Sets the expected bech32 hrp of the restored accounts.
+    pub fn with_ignore_if_bech32_hrp_mismatch(mut self, ignore_if_bech32_hrp_mismatch: impl Into<Option<Hrp>>) -> Self {
+        self.ignore_if_bech32_hrp_mismatch = ignore_if_bech32_hrp_mismatch.into();
+        self
+    }
+
+    pub(crate) fn includes_account(&self, account_index: u32) -> bool {
+        self.account_indexes
+            .as_ref()
+            .is_none_or(|account_indexes| account_indexes.contains(&account_index))
+    }
+
+    // Rejects selected accounts that would leave a gap in the account indexes of a wallet with `account_count`
+    // accounts.
+    pub(crate) fn verify_account_indexes(&self, account_count: u32) -> crate::wallet::Result<()> {
+        if let Some(account_indexes) = &self.account_indexes {
+            let new_indexes = account_indexes.iter().filter(|index| **index >= account_count);
+            if let Some(max_index) = new_indexes.max() {
+                if (account_count..*max_index).any(|index| !account_indexes.contains(&index)) {
+                    return Err(crate::wallet::Error::Backup(
+                        "the selected accounts would leave a gap in the account indexes",
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn restores_client_options(&self) -> bool {
+        !self.skip_client_options && self.ignore_if_coin_type_mismatch.is_none()
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
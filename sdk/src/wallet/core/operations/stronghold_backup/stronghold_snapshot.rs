@@ -3,6 +3,7 @@
 
 use std::{collections::HashMap, path::Path, sync::atomic::Ordering};
 
+use super::BackupOptions;
 use crate::{
     client::{
         constants::IOTA_COIN_TYPE, secret::SecretManagerConfig, storage::StorageAdapter, stronghold::StrongholdAdapter,
@@ -26,14 +27,23 @@ pub(crate) const SECRET_MANAGER_KEY: &str = "secret_manager";
 pub(crate) const ACCOUNTS_KEY: &str = "accounts";
 
 impl<S: 'static + SecretManagerConfig> Wallet<S> {
-    pub(crate) async fn store_data_to_stronghold(&self, stronghold: &StrongholdAdapter) -> crate::wallet::Result<()> {
+    pub(crate) async fn store_data_to_stronghold(
+        &self,
+        stronghold: &StrongholdAdapter,
+        options: &BackupOptions,
+    ) -> crate::wallet::Result<()> {
         // Set migration version
         stronghold
             .set(MIGRATION_VERSION_KEY, &latest_backup_migration_version())
             .await?;
 
-        let client_options = self.client_options().await;
-        stronghold.set(CLIENT_OPTIONS_KEY, &client_options).await?;
+        if options.include_client_options {
+            let client_options = self.client_options().await;
+            stronghold.set(CLIENT_OPTIONS_KEY, &client_options).await?;
+        } else {
+            // The stronghold could contain client options of a previous backup
+            stronghold.delete(CLIENT_OPTIONS_KEY).await?;
+        }
 
         let coin_type = self.coin_type.load(Ordering::Relaxed);
         stronghold.set_bytes(COIN_TYPE_KEY, &coin_type.to_le_bytes()).await?;
@@ -44,9 +54,10 @@ impl<S: 'static + SecretManagerConfig> Wallet<S> {
 
         let mut serialized_accounts = Vec::new();
         for account in self.accounts.read().await.iter() {
-            serialized_accounts.push(serde_json::to_value(&AccountDetailsDto::from(
-                &*account.details().await,
-            ))?);
+            let account_details = account.details().await;
+            if options.includes_account(*account_details.index()) {
+                serialized_accounts.push(serde_json::to_value(AccountDetailsDto::from(&*account_details))?);
+            }
         }
 
         stronghold.set(ACCOUNTS_KEY, &serialized_accounts).await?;
@@ -0,0 +1,70 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::Ordering;
+
+use crypto::keys::bip44::Bip44;
+
+use crate::{
+    client::{api::GetAddressesOptions, secret::SecretManager},
+    types::block::{address::Bech32Address, BlockId},
+    wallet::{core::Wallet, Result},
+};
+
+impl<S: 'static + crate::client::secret::SecretManage> Wallet<S> {
+    /// Sweeps all funds of the addresses of a foreign secret manager, e.g. of a mnemonic from another wallet, to the
+    /// `destination` address. The addresses are generated with the `options`, which default to the coin type of the
+    /// wallet and the first account if not set otherwise.
+    ///
+    /// Only basic outputs without expiration, timelock or storage deposit return unlock conditions are swept. The base
+    /// coins and native tokens of up to [`INPUT_COUNT_MAX`](crate::types::block::input::INPUT_COUNT_MAX) outputs are
+    /// moved in a single transaction. If there are more native tokens than fit into a single output, additional
+    /// outputs with the minimum storage deposit are created. Returns the IDs of the sent blocks.
+    pub async fn sweep_from(
+        &self,
+        secret_manager: &SecretManager,
+        options: impl Into<Option<GetAddressesOptions>> + Send,
+        destination: Bech32Address,
+    ) -> Result<Vec<BlockId>> {
+        log::debug!("[sweep_from]");
+        let client = self.client();
+        client.bech32_hrp_matches(destination.hrp()).await?;
+
+        let options = match options.into() {
+            Some(options) => options,
+            None => GetAddressesOptions::from_client(client)
+                .await?
+                .with_coin_type(self.coin_type.load(Ordering::Relaxed)),
+        };
+        let offset = options.range.start;
+        let (coin_type, account_index) = (options.coin_type, options.account_index);
+        let addresses = secret_manager.generate_ed25519_addresses(options).await?;
+
+        let mut block_ids = Vec::new();
+
+        for (index, address) in addresses.iter().enumerate() {
+            // add the offset so the index matches the address index also for higher start indexes
+            let index = index as u32 + offset;
+
+            let outputs = client.unconstrained_basic_outputs(*address).await?;
+            let address_block_ids = client
+                .send_outputs_to(
+                    secret_manager,
+                    Bip44::new(coin_type)
+                        .with_account(account_index)
+                        .with_address_index(index),
+                    &outputs,
+                    destination,
+                )
+                .await?;
+            log::debug!(
+                "[sweep_from] swept {} outputs of {address} in {} blocks",
+                outputs.len(),
+                address_block_ids.len()
+            );
+            block_ids.extend(address_block_ids);
+        }
+
+        Ok(block_ids)
+    }
+}
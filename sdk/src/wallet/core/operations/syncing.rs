@@ -0,0 +1,92 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::Ordering;
+
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        account::{operations::syncing::SyncOptions, types::Balance},
+        Wallet,
+    },
+};
+
+/// The default amount of accounts that are synced at the same time
+pub(crate) const DEFAULT_ACCOUNT_SYNC_CONCURRENCY: usize = 4;
+
+/// The progress of syncing all accounts of a wallet, reported after each synced account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletSyncProgress {
+    /// The index of the account that finished syncing.
+    pub account_index: u32,
+    /// The balance of the account that finished syncing.
+    pub balance: Balance,
+    /// The amount of accounts that finished syncing.
+    pub synced_accounts: usize,
+    /// The amount of accounts that are synced.
+    pub total_accounts: usize,
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Get the max amount of accounts that are synced at the same time.
+    pub fn account_sync_concurrency(&self) -> usize {
+        self.account_sync_concurrency.load(Ordering::Relaxed)
+    }
+
+    /// Set the max amount of accounts that are synced at the same time, default is 4. All accounts share the client,
+    /// so a higher value also increases the load on the nodes.
+    pub fn set_account_sync_concurrency(&self, concurrency: usize) {
+        self.account_sync_concurrency
+            .store(concurrency.max(1), Ordering::Relaxed);
+    }
+
+    /// Sync all accounts
+    pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Balance> {
+        self.sync_with_progress(options, |_| {}).await
+    }
+
+    /// Sync all accounts concurrently, see [`Wallet::set_account_sync_concurrency()`], and call `on_progress` each time
+    /// an account finished syncing. Returns the balance of all accounts added together.
+    pub async fn sync_with_progress(
+        &self,
+        options: Option<SyncOptions>,
+        mut on_progress: impl FnMut(WalletSyncProgress) + Send,
+    ) -> crate::wallet::Result<Balance> {
+        // Clone the accounts so creating an account doesn't have to wait until the syncing finished
+        let accounts = self.accounts.read().await.clone();
+        let total_accounts = accounts.len();
+        log::debug!("[SYNC] syncing {total_accounts} accounts");
+
+        let mut account_syncs = futures::stream::iter(accounts.into_iter().map(|account| {
+            let options = options.clone();
+            async move {
+                let balance = account.sync(options).await?;
+                let account_index = *account.details().await.index();
+                crate::wallet::Result::Ok((account_index, balance))
+            }
+        }))
+        .buffer_unordered(self.account_sync_concurrency());
+
+        let mut total_balance = Balance::default();
+        let mut synced_accounts = 0;
+        while let Some((account_index, balance)) = account_syncs.try_next().await? {
+            synced_accounts += 1;
+            total_balance += balance.clone();
+            on_progress(WalletSyncProgress {
+                account_index,
+                balance,
+                synced_accounts,
+                total_accounts,
+            });
+        }
+
+        Ok(total_balance)
+    }
+}
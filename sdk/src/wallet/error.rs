@@ -8,7 +8,11 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{
+    address::Bech32Address,
+    output::{AliasId, FoundryId, OutputId},
+    payload::transaction::TransactionId,
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -19,9 +23,17 @@ pub enum Error {
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
+    /// The user didn't confirm an address on the ledger nano
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    #[error("address {0} wasn't confirmed on the ledger nano")]
+    AddressNotConfirmed(Bech32Address),
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
+    /// The account doesn't have state control over an alias
+    #[error("account doesn't have state control over alias {0}")]
+    AliasNotControlled(AliasId),
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
@@ -34,6 +46,9 @@ pub enum Error {
     /// Client error.
     #[error("`{0}`")]
     Client(Box<crate::client::Error>),
+    /// The output isn't a conditional transfer that can be claimed
+    #[error("output {0} isn't a claimable conditional transfer")]
+    ConditionalTransferNotClaimable(OutputId),
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
@@ -46,6 +61,9 @@ pub enum Error {
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
+    /// The account doesn't have the foundry output in its unspent outputs
+    #[error("account doesn't control foundry {0}")]
+    FoundryNotControlled(FoundryId),
     /// Insufficient funds to send transaction.
     #[error("address owns insufficient funds: {required} base unit required, but {available} base unit available")]
     InsufficientFunds { available: u64, required: u64 },
@@ -55,6 +73,9 @@ pub enum Error {
         new_coin_type: u32,
         existing_coin_type: u32,
     },
+    /// Invalid label (account alias, storage key, ...)
+    #[error("invalid {kind}: {reason}")]
+    InvalidLabel { kind: &'static str, reason: String },
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
@@ -76,6 +97,9 @@ pub enum Error {
     /// Missing parameter.
     #[error("missing parameter: {0}")]
     MissingParameter(&'static str),
+    /// An externally signed transaction is missing the signature of an address
+    #[error("missing signature of address {0}")]
+    MissingSignature(Bech32Address),
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
@@ -97,6 +121,27 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("participation error {0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
+    /// A transaction sends outputs to an address that isn't allowed by the spend policy
+    #[error("spend policy doesn't allow sending to {0}")]
+    SpendDestinationNotAllowed(Bech32Address),
+    /// A transaction exceeds the daily limit of the spend policy
+    #[error("spend policy daily limit of {limit} exceeded: {spent} already spent, {amount} requested")]
+    SpendLimitExceeded { limit: u64, spent: u64, amount: u64 },
+    /// The confirmation callback of the spend policy rejected a transaction
+    #[error("transaction was rejected by the spend policy confirmation")]
+    SpendNotConfirmed,
+    /// A transaction of a split send failed after others were already sent
+    #[error(
+        "split transaction failed after sending {transaction_ids:?}, consolidations {consolidation_ids:?}: {error}"
+    )]
+    SplitTransactionFailed {
+        /// The IDs of the sent transactions with outputs of the plan.
+        transaction_ids: Vec<TransactionId>,
+        /// The IDs of the sent consolidation transactions.
+        consolidation_ids: Vec<TransactionId>,
+        /// The error of the failed transaction.
+        error: Box<Self>,
+    },
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -119,6 +164,9 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("invalid voting power")]
     InvalidVotingPower,
+    /// The account is watch-only
+    #[error("account {0} is watch-only, it can't generate addresses or sign transactions")]
+    WatchOnlyAccount(String),
 }
 
 // Serialize type with Display error
@@ -176,9 +224,9 @@ impl From<crate::client::stronghold::Error> for Error {
     }
 }
 
-#[cfg(feature = "ledger_nano")]
-impl From<crate::client::secret::ledger_nano::Error> for Error {
-    fn from(error: crate::client::secret::ledger_nano::Error) -> Self {
+#[cfg(any(feature = "ledger_nano", feature = "ledger_nano_simulator"))]
+impl From<crate::client::secret::ledger_common::Error> for Error {
+    fn from(error: crate::client::secret::ledger_common::Error) -> Self {
         Self::Client(Box::new(crate::client::Error::Ledger(error)))
     }
 }
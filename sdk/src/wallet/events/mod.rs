@@ -5,16 +5,171 @@ pub mod types;
 
 use alloc::sync::Arc;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Formatter, Result},
 };
 
+#[cfg(feature = "ledger_nano")]
+use self::types::AddressData;
 pub use self::types::{Event, WalletEvent, WalletEventType};
+use self::types::{
+    NativeTokenCreationProgressEvent, NewOutputEvent, OutputsClaimedEvent, SpentOutputEvent,
+    SplitTransactionProgressEvent, TransactionInclusionEvent, TransactionProgressEvent, TransactionRetryEvent,
+};
+use crate::types::block::output::dto::OutputDto;
 
 type Handler<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
 
+// we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
+const ALL_EVENT_TYPES: &[WalletEventType] = &[
+    WalletEventType::NativeTokenCreationProgress,
+    WalletEventType::NewOutput,
+    WalletEventType::OutputsClaimed,
+    WalletEventType::SpentOutput,
+    WalletEventType::SplitTransactionProgress,
+    WalletEventType::TransactionInclusion,
+    WalletEventType::TransactionProgress,
+    WalletEventType::TransactionRetry,
+    WalletEventType::ConsolidationRequired,
+    #[cfg(feature = "ledger_nano")]
+    WalletEventType::LedgerAddressGeneration,
+];
+
+/// A filter for wallet events. Each condition that is set needs to match, an empty filter matches all events.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WalletEventFilter {
+    account_indexes: HashSet<u32>,
+    event_types: HashSet<WalletEventType>,
+    output_kinds: HashSet<u8>,
+    min_amount: Option<u64>,
+}
+
+impl WalletEventFilter {
+    /// Creates a new [`WalletEventFilter`] that matches all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events of the given accounts.
+    pub fn with_account_indexes(mut self, account_indexes: impl IntoIterator<Item = u32>) -> Self {
+        self.account_indexes = account_indexes.into_iter().collect();
+        self
+    }
+
+    /// Only match events of the given types.
+    pub fn with_event_types(mut self, event_types: impl IntoIterator<Item = WalletEventType>) -> Self {
+        self.event_types = event_types.into_iter().collect();
+        self
+    }
+
+    /// Only match [`WalletEvent::NewOutput`] and [`WalletEvent::SpentOutput`] events of outputs of the given kinds,
+    /// e.g. [`BasicOutput::KIND`](crate::types::block::output::BasicOutput::KIND).
+    pub fn with_output_kinds(mut self, output_kinds: impl IntoIterator<Item = u8>) -> Self {
+        self.output_kinds = output_kinds.into_iter().collect();
+        self
+    }
+
+    /// Only match [`WalletEvent::NewOutput`] and [`WalletEvent::SpentOutput`] events of outputs with at least the
+    /// given base coin amount.
+    pub fn with_min_amount(mut self, min_amount: impl Into<Option<u64>>) -> Self {
+        self.min_amount = min_amount.into();
+        self
+    }
+
+    /// Returns the event types the filter matches.
+    pub fn event_types(&self) -> Vec<WalletEventType> {
+        if self.event_types.is_empty() {
+            ALL_EVENT_TYPES.to_vec()
+        } else {
+            self.event_types.iter().copied().collect()
+        }
+    }
+
+    /// Checks whether an event matches the filter. Events without an output don't match if an output kind or minimum
+    /// amount is set.
+    pub fn matches(&self, event: &Event) -> bool {
+        if !self.account_indexes.is_empty() && !self.account_indexes.contains(&event.account_index) {
+            return false;
+        }
+        if !self.event_types.is_empty() && !self.event_types.contains(&event.event.event_type()) {
+            return false;
+        }
+        if self.output_kinds.is_empty() && self.min_amount.is_none() {
+            return true;
+        }
+
+        let output = match &event.event {
+            WalletEvent::NewOutput(new_output) => &new_output.output.output,
+            WalletEvent::SpentOutput(spent_output) => &spent_output.output.output,
+            _ => return false,
+        };
+        let (kind, amount) = output_kind_and_amount(output);
+
+        let matches_amount = self
+            .min_amount
+            .is_none_or(|min_amount| amount.is_some_and(|amount| amount >= min_amount));
+
+        (self.output_kinds.is_empty() || self.output_kinds.contains(&kind)) && matches_amount
+    }
+}
+
+fn output_kind_and_amount(output: &OutputDto) -> (u8, Option<u64>) {
+    let (kind, amount) = match output {
+        OutputDto::Treasury(output) => (output.kind, &output.amount),
+        OutputDto::Basic(output) => (output.kind, &output.amount),
+        OutputDto::Alias(output) => (output.kind, &output.amount),
+        OutputDto::Foundry(output) => (output.kind, &output.amount),
+        OutputDto::Nft(output) => (output.kind, &output.amount),
+    };
+    (kind, amount.parse().ok())
+}
+
+/// A wallet event with data that can be listened to on its own, see [`EventEmitter::on_typed()`].
+pub trait TypedWalletEvent: Sized {
+    /// The type of the wallet event.
+    const EVENT_TYPE: WalletEventType;
+
+    /// Returns the typed event if the wallet event is of this type.
+    fn from_wallet_event(event: &WalletEvent) -> Option<&Self>;
+}
+
+macro_rules! impl_typed_wallet_event {
+    ($type:ty, $variant:ident) => {
+        impl TypedWalletEvent for $type {
+            const EVENT_TYPE: WalletEventType = WalletEventType::$variant;
+
+            fn from_wallet_event(event: &WalletEvent) -> Option<&Self> {
+                match event {
+                    WalletEvent::$variant(event) => {
+                        // some events are boxed
+                        let event: &Self = event;
+                        Some(event)
+                    }
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ledger_nano")]
+impl_typed_wallet_event!(AddressData, LedgerAddressGeneration);
+impl_typed_wallet_event!(NativeTokenCreationProgressEvent, NativeTokenCreationProgress);
+impl_typed_wallet_event!(NewOutputEvent, NewOutput);
+impl_typed_wallet_event!(OutputsClaimedEvent, OutputsClaimed);
+impl_typed_wallet_event!(SpentOutputEvent, SpentOutput);
+impl_typed_wallet_event!(SplitTransactionProgressEvent, SplitTransactionProgress);
+impl_typed_wallet_event!(TransactionInclusionEvent, TransactionInclusion);
+impl_typed_wallet_event!(TransactionProgressEvent, TransactionProgress);
+impl_typed_wallet_event!(TransactionRetryEvent, TransactionRetry);
+
+struct Listener {
+    filter: WalletEventFilter,
+    handler: Handler<Event>,
+}
+
 pub struct EventEmitter {
-    handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    handlers: HashMap<WalletEventType, Vec<Arc<Listener>>>,
 }
 
 impl EventEmitter {
@@ -31,28 +186,39 @@ impl EventEmitter {
     where
         F: Fn(&Event) + 'static + Send + Sync,
     {
-        let mut events = events.into_iter().peekable();
-        let handler = Arc::new(handler);
         // if no event is provided the handler is registered for all event types
-        if events.peek().is_none() {
-            // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
-            for event_type in [
-                WalletEventType::NewOutput,
-                WalletEventType::SpentOutput,
-                WalletEventType::TransactionInclusion,
-                WalletEventType::TransactionProgress,
-                WalletEventType::ConsolidationRequired,
-                #[cfg(feature = "ledger_nano")]
-                WalletEventType::LedgerAddressGeneration,
-            ] {
-                self.handlers.entry(event_type).or_default().push(handler.clone());
-            }
-        }
-        for event in events {
-            self.handlers.entry(event).or_default().push(handler.clone());
+        self.on_filtered(WalletEventFilter::new().with_event_types(events), handler)
+    }
+
+    /// Registers function `handler` as a listener for the events matching the `filter`.
+    pub fn on_filtered<F>(&mut self, filter: WalletEventFilter, handler: F)
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let event_types = filter.event_types();
+        let listener = Arc::new(Listener {
+            filter,
+            handler: Arc::new(handler),
+        });
+        for event_type in event_types {
+            self.handlers.entry(event_type).or_default().push(listener.clone());
         }
     }
 
+    /// Registers function `handler` as a listener for the events of type `E` matching the `filter`. The handler gets
+    /// the account index and the typed event. The event types of the filter are ignored.
+    pub fn on_typed<E, F>(&mut self, filter: WalletEventFilter, handler: F)
+    where
+        E: TypedWalletEvent,
+        F: Fn(u32, &E) + 'static + Send + Sync,
+    {
+        self.on_filtered(filter.with_event_types([E::EVENT_TYPE]), move |event| {
+            if let Some(typed_event) = E::from_wallet_event(&event.event) {
+                handler(event.account_index, typed_event);
+            }
+        })
+    }
+
     /// Removes handlers for each given `WalletEventType`.
     /// If no `WalletEventType` is given, handlers will be removed for all event types.
     pub fn clear(&mut self, events: impl IntoIterator<Item = WalletEventType>) {
@@ -66,22 +232,16 @@ impl EventEmitter {
         }
     }
 
-    /// Invokes all listeners of `event`, passing a reference to `payload` as an
+    /// Invokes all listeners of `event` whose filter matches, passing a reference to `payload` as an
     /// argument to each of them.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
-        };
+        let event_type = event.event_type();
         let event = Event { account_index, event };
-        if let Some(handlers) = self.handlers.get(&event_type) {
-            for handler in handlers {
-                handler(&event);
+        if let Some(listeners) = self.handlers.get(&event_type) {
+            for listener in listeners {
+                if listener.filter.matches(&event) {
+                    (listener.handler)(&event);
+                }
             }
         }
     }
@@ -116,8 +276,8 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::{
-        types::{TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
-        EventEmitter,
+        types::{Event, TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
+        EventEmitter, WalletEventFilter,
     };
     use crate::{types::block::payload::transaction::TransactionId, wallet::account::types::InclusionState};
 
@@ -206,4 +366,66 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn filtered_events() {
+        let mut emitter = EventEmitter::new();
+        let event_counter = Arc::new(AtomicUsize::new(0));
+
+        let event_counter_clone = Arc::clone(&event_counter);
+        emitter.on_filtered(
+            WalletEventFilter::new()
+                .with_account_indexes([1])
+                .with_event_types([WalletEventType::TransactionProgress]),
+            move |_event| {
+                event_counter_clone.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        emitter.emit(
+            0,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+        emitter.emit(1, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            1,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+
+        assert_eq!(1, event_counter.load(Ordering::SeqCst));
+
+        // events without an output don't match output conditions
+        let filter = WalletEventFilter::new().with_min_amount(1);
+        assert!(!filter.matches(&Event {
+            account_index: 0,
+            event: WalletEvent::ConsolidationRequired,
+        }));
+    }
+
+    #[test]
+    fn typed_events() {
+        let mut emitter = EventEmitter::new();
+        let event_counter = Arc::new(AtomicUsize::new(0));
+
+        let event_counter_clone = Arc::clone(&event_counter);
+        emitter.on_typed(
+            WalletEventFilter::new(),
+            move |account_index, event: &TransactionInclusionEvent| {
+                assert_eq!(account_index, 2);
+                assert_eq!(event.inclusion_state, InclusionState::Confirmed);
+                event_counter_clone.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        emitter.emit(2, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            2,
+            WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                transaction_id: TransactionId::null(),
+                inclusion_state: InclusionState::Confirmed,
+            }),
+        );
+
+        assert_eq!(1, event_counter.load(Ordering::SeqCst));
+    }
 }
@@ -10,7 +10,9 @@ use crate::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             address::Bech32Address,
+            output::{AliasId, OutputId, TokenId},
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
+            BlockId,
         },
     },
     wallet::account::types::{InclusionState, OutputDataDto},
@@ -32,10 +34,33 @@ pub enum WalletEvent {
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration(AddressData),
+    NativeTokenCreationProgress(NativeTokenCreationProgressEvent),
     NewOutput(Box<NewOutputEvent>),
+    OutputsClaimed(OutputsClaimedEvent),
     SpentOutput(Box<SpentOutputEvent>),
+    SplitTransactionProgress(SplitTransactionProgressEvent),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    TransactionRetry(TransactionRetryEvent),
+}
+
+impl WalletEvent {
+    /// Returns the type of the event.
+    pub fn event_type(&self) -> WalletEventType {
+        match self {
+            Self::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
+            Self::NativeTokenCreationProgress(_) => WalletEventType::NativeTokenCreationProgress,
+            Self::NewOutput(_) => WalletEventType::NewOutput,
+            Self::OutputsClaimed(_) => WalletEventType::OutputsClaimed,
+            Self::SpentOutput(_) => WalletEventType::SpentOutput,
+            Self::SplitTransactionProgress(_) => WalletEventType::SplitTransactionProgress,
+            Self::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
+            Self::TransactionProgress(_) => WalletEventType::TransactionProgress,
+            Self::TransactionRetry(_) => WalletEventType::TransactionRetry,
+        }
+    }
 }
 
 impl Serialize for WalletEvent {
@@ -58,6 +83,10 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a TransactionRetryEvent),
+            T7(&'a OutputsClaimedEvent),
+            T8(&'a NativeTokenCreationProgressEvent),
+            T9(&'a SplitTransactionProgressEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -92,6 +121,22 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::TransactionRetry(e) => TypedWalletEvent_ {
+                kind: WalletEventType::TransactionRetry as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::OutputsClaimed(e) => TypedWalletEvent_ {
+                kind: WalletEventType::OutputsClaimed as u8,
+                event: WalletEvent_::T7(e),
+            },
+            Self::NativeTokenCreationProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::NativeTokenCreationProgress as u8,
+                event: WalletEvent_::T8(e),
+            },
+            Self::SplitTransactionProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SplitTransactionProgress as u8,
+                event: WalletEvent_::T9(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -143,6 +188,24 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::TransactionRetry => Self::TransactionRetry(
+                    TransactionRetryEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize TransactionRetry: {e}")))?,
+                ),
+                WalletEventType::OutputsClaimed => Self::OutputsClaimed(
+                    OutputsClaimedEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize OutputsClaimed: {e}")))?,
+                ),
+                WalletEventType::NativeTokenCreationProgress => {
+                    Self::NativeTokenCreationProgress(NativeTokenCreationProgressEvent::deserialize(value).map_err(
+                        |e| serde::de::Error::custom(format!("cannot deserialize NativeTokenCreationProgress: {e}")),
+                    )?)
+                }
+                WalletEventType::SplitTransactionProgress => {
+                    Self::SplitTransactionProgress(SplitTransactionProgressEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize SplitTransactionProgress: {e}"))
+                    })?)
+                }
             },
         )
     }
@@ -160,6 +223,10 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    TransactionRetry = 6,
+    OutputsClaimed = 7,
+    NativeTokenCreationProgress = 8,
+    SplitTransactionProgress = 9,
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -174,6 +241,10 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::TransactionRetry,
+            7 => Self::OutputsClaimed,
+            8 => Self::NativeTokenCreationProgress,
+            9 => Self::SplitTransactionProgress,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -193,6 +264,15 @@ pub struct NewOutputEvent {
     pub transaction_inputs: Option<Vec<OutputWithMetadataResponse>>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputsClaimedEvent {
+    /// The transaction that claimed the outputs.
+    pub transaction_id: TransactionId,
+    /// The claimed outputs.
+    pub output_ids: Vec<OutputId>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SpentOutputEvent {
     /// The spent output.
@@ -206,6 +286,63 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRetryEvent {
+    pub transaction_id: TransactionId,
+    /// The block that was issued to get the transaction included.
+    pub block_id: BlockId,
+    pub kind: TransactionRetryKind,
+}
+
+/// How a pending transaction was retried.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionRetryKind {
+    /// The transaction was reattached in a new block.
+    Reattached,
+    /// The block of the transaction was promoted by a new block.
+    Promoted,
+}
+
+/// A step of [`Account::create_native_token_with_alias()`](crate::wallet::Account::create_native_token_with_alias).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "camelCase")]
+pub enum NativeTokenCreationProgressEvent {
+    /// Sent the transaction that creates the alias controlling the foundry.
+    #[serde(rename_all = "camelCase")]
+    CreatingAlias { transaction_id: TransactionId },
+    /// The alias was created.
+    #[serde(rename_all = "camelCase")]
+    AliasCreated { alias_id: AliasId },
+    /// Sent the transaction that creates the foundry and mints the native tokens.
+    #[serde(rename_all = "camelCase")]
+    CreatingFoundry {
+        transaction_id: TransactionId,
+        token_id: TokenId,
+    },
+    /// The foundry was created and the native tokens were minted.
+    #[serde(rename_all = "camelCase")]
+    FoundryCreated { token_id: TokenId },
+}
+
+/// A step of [`Account::send_split_plan()`](crate::wallet::Account::send_split_plan).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "camelCase")]
+pub enum SplitTransactionProgressEvent {
+    /// Sent a transaction that consolidates outputs, because the funds are spread over more outputs than a
+    /// transaction can consume.
+    #[serde(rename_all = "camelCase")]
+    Consolidating { transaction_id: TransactionId },
+    /// Sent the transaction at `index` of the `count` transactions of the plan.
+    #[serde(rename_all = "camelCase")]
+    Sending {
+        transaction_id: TransactionId,
+        index: usize,
+        count: usize,
+    },
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TransactionProgressEvent {
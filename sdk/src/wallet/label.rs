@@ -0,0 +1,112 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::wallet::Error;
+
+/// Max amount of characters of an account alias.
+pub(crate) const MAX_ACCOUNT_ALIAS_LENGTH: usize = 256;
+/// Max amount of characters of an address book label.
+pub(crate) const MAX_ADDRESS_BOOK_LABEL_LENGTH: usize = 256;
+/// Max amount of characters of a storage key.
+#[cfg(feature = "storage")]
+pub(crate) const MAX_STORAGE_KEY_LENGTH: usize = 1024;
+
+/// Normalizes a user provided label (account alias, storage key, ...) to Unicode NFC, so visually identical strings
+/// with different encodings map to the same value, and rejects empty labels, control characters and labels longer than
+/// `max_length` characters.
+pub(crate) fn normalize_label(kind: &'static str, label: &str, max_length: usize) -> crate::wallet::Result<String> {
+    let normalized = label.nfc().collect::<String>();
+
+    if normalized.is_empty() {
+        return Err(Error::InvalidLabel {
+            kind,
+            reason: "must not be empty".to_string(),
+        });
+    }
+
+    if let Some(c) = normalized.chars().find(|c| c.is_control()) {
+        return Err(Error::InvalidLabel {
+            kind,
+            reason: format!("contains control character {c:?}"),
+        });
+    }
+
+    let length = normalized.chars().count();
+    if length > max_length {
+        return Err(Error::InvalidLabel {
+            kind,
+            reason: format!("length {length} exceeds the max length {max_length}"),
+        });
+    }
+
+    Ok(normalized)
+}
+
+/// Normalizes and validates an account alias.
+pub(crate) fn normalize_account_alias(alias: &str) -> crate::wallet::Result<String> {
+    normalize_label("account alias", alias, MAX_ACCOUNT_ALIAS_LENGTH)
+}
+
+/// Normalizes and validates an address book label.
+pub(crate) fn normalize_address_book_label(label: &str) -> crate::wallet::Result<String> {
+    normalize_label("address book label", label, MAX_ADDRESS_BOOK_LABEL_LENGTH)
+}
+
+/// Compares two labels after normalizing both to Unicode NFC, so already stored, not yet normalized values still match.
+pub(crate) fn labels_match(a: &str, b: &str) -> bool {
+    a.nfc().eq(b.nfc())
+}
+
+/// Compares two account aliases like [`labels_match()`], but ignoring case, which is the rule for the uniqueness of
+/// aliases and for looking accounts up by alias.
+pub(crate) fn account_aliases_match(a: &str, b: &str) -> bool {
+    a.nfc()
+        .flat_map(char::to_lowercase)
+        .eq(b.nfc().flat_map(char::to_lowercase))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        // "é" as a single code point and as "e" followed by a combining acute accent
+        let composed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+
+        assert_eq!(normalize_account_alias(decomposed).unwrap(), composed);
+        assert!(labels_match(composed, decomposed));
+        assert!(!labels_match(composed, "cafe"));
+        assert!(account_aliases_match("CAFE\u{301}", composed));
+        assert!(!labels_match("Cafe", "cafe"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            normalize_account_alias(""),
+            Err(Error::InvalidLabel {
+                kind: "account alias",
+                ..
+            })
+        ));
+        assert!(matches!(
+            normalize_account_alias("Alice\u{0}"),
+            Err(Error::InvalidLabel { .. })
+        ));
+        assert!(matches!(
+            normalize_account_alias("Alice\nBob"),
+            Err(Error::InvalidLabel { .. })
+        ));
+        assert!(normalize_account_alias(&"a".repeat(MAX_ACCOUNT_ALIAS_LENGTH)).is_ok());
+        assert!(matches!(
+            normalize_account_alias(&"a".repeat(MAX_ACCOUNT_ALIAS_LENGTH + 1)),
+            Err(Error::InvalidLabel { .. })
+        ));
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{storage::adapter::DynStorageAdapter, Error, Result},
+};
+
+// The persisted backup of the records changed by the running migrations.
+pub(crate) const MIGRATION_BACKUP_KEY: &str = "migration-backup";
+
+/// Wraps the storage during migrations. The records are backed up before their first change, to restore them if a
+/// migration fails, so the storage is never left partially migrated. The backup is persisted in the storage before a
+/// record is changed and removed when the migrations are finished, so the records of migrations that were interrupted,
+/// e.g. by a crash, are restored by the next journal. In a dry run the changes are only kept in memory.
+#[derive(Clone, Debug)]
+pub(crate) struct MigrationJournal(Arc<JournalInner>);
+
+#[derive(Debug)]
+struct JournalInner {
+    storage: Box<dyn DynStorageAdapter>,
+    dry_run: bool,
+    // The records before their first change, `None` if they didn't exist.
+    backup: Mutex<HashMap<String, Option<Vec<u8>>>>,
+    // The changed records of a dry run, `None` if they were deleted.
+    changes: Mutex<HashMap<String, Option<Vec<u8>>>>,
+}
+
+impl MigrationJournal {
+    /// Creates a journal, restoring the records of interrupted migrations first. In a dry run they are only restored
+    /// in memory.
+    pub(crate) async fn new(storage: Box<dyn DynStorageAdapter>, dry_run: bool) -> Result<Self> {
+        let mut changes = HashMap::new();
+
+        if let Some(backup) = storage.dyn_get_bytes(MIGRATION_BACKUP_KEY).await? {
+            let backup = serde_json::from_slice::<HashMap<String, Option<Vec<u8>>>>(&backup)?;
+            log::warn!("Restoring {} records of interrupted migrations", backup.len());
+
+            if dry_run {
+                changes = backup;
+                changes.insert(MIGRATION_BACKUP_KEY.to_string(), None);
+            } else {
+                restore(storage.as_ref(), backup).await?;
+                storage.dyn_delete(MIGRATION_BACKUP_KEY).await?;
+            }
+        }
+
+        Ok(Self(Arc::new(JournalInner {
+            storage,
+            dry_run,
+            backup: Default::default(),
+            changes: Mutex::new(changes),
+        })))
+    }
+
+    /// Returns the wrapped storage, after restoring the backed up records if the migrations weren't successful.
+    /// All other clones of the journal must have been dropped.
+    pub(crate) async fn finish(self, success: bool) -> Result<Box<dyn DynStorageAdapter>> {
+        let inner = Arc::try_unwrap(self.0)
+            .map_err(|_| Error::Migration("the migration journal is still in use".to_string()))?;
+        let backup = inner.backup.into_inner();
+
+        if !inner.dry_run && !backup.is_empty() {
+            if !success {
+                restore(inner.storage.as_ref(), backup).await?;
+            }
+            inner.storage.dyn_delete(MIGRATION_BACKUP_KEY).await?;
+        }
+
+        Ok(inner.storage)
+    }
+
+    async fn change(&self, key: &str, record: Option<&[u8]>) -> Result<()> {
+        if self.0.dry_run {
+            self.0
+                .changes
+                .lock()
+                .await
+                .insert(key.to_string(), record.map(<[u8]>::to_vec));
+            return Ok(());
+        }
+
+        let mut backup = self.0.backup.lock().await;
+        if !backup.contains_key(key) {
+            backup.insert(key.to_string(), self.0.storage.dyn_get_bytes(key).await?);
+            self.0
+                .storage
+                .dyn_set_bytes(MIGRATION_BACKUP_KEY, &serde_json::to_vec(&*backup)?)
+                .await?;
+        }
+        drop(backup);
+
+        match record {
+            Some(record) => self.0.storage.dyn_set_bytes(key, record).await,
+            None => self.0.storage.dyn_delete(key).await,
+        }
+    }
+}
+
+async fn restore(storage: &dyn DynStorageAdapter, backup: HashMap<String, Option<Vec<u8>>>) -> Result<()> {
+    for (key, record) in backup {
+        match record {
+            Some(record) => storage.dyn_set_bytes(&key, &record).await?,
+            None => storage.dyn_delete(&key).await?,
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl StorageAdapter for MigrationJournal {
+    type Error = crate::wallet::Error;
+
+    async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>> {
+        if let Some(record) = self.0.changes.lock().await.get(key) {
+            return Ok(record.clone());
+        }
+        self.0.storage.dyn_get_bytes(key).await
+    }
+
+    async fn set_bytes(&self, key: &str, record: &[u8]) -> Result<()> {
+        self.change(key, Some(record)).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.change(key, None).await
+    }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>> {
+        let Some(keys) = self.0.storage.dyn_keys().await? else {
+            return Ok(None);
+        };
+        let mut keys = keys.into_iter().collect::<BTreeSet<_>>();
+        for (key, record) in self.0.changes.lock().await.iter() {
+            if record.is_some() {
+                keys.insert(key.clone());
+            } else {
+                keys.remove(key);
+            }
+        }
+        Ok(Some(keys.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use pretty_assertions::assert_eq;
+
+    use super::MIGRATION_BACKUP_KEY;
+    use crate::{
+        client::storage::StorageAdapter,
+        wallet::{
+            migration::{dry_run_migrations, migrate_storage, MigrationVersion, MIGRATION_VERSION_KEY},
+            storage::{adapter::memory::Memory, Storage},
+            Error,
+        },
+    };
+
+    fn memory_storage() -> Storage {
+        Storage {
+            inner: Box::<Memory>::default(),
+            encryption_key: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn dry_run() {
+        let (storage, applied) = migrate_storage(memory_storage(), true).await.unwrap();
+        assert_eq!(applied.first().unwrap().id, 0);
+        assert_eq!(
+            storage.get::<MigrationVersion>(MIGRATION_VERSION_KEY).await.unwrap(),
+            None
+        );
+
+        let (storage, applied_for_real) = migrate_storage(storage, false).await.unwrap();
+        assert_eq!(applied_for_real, applied);
+        assert_eq!(
+            storage.get::<MigrationVersion>(MIGRATION_VERSION_KEY).await.unwrap(),
+            applied.last().cloned()
+        );
+
+        let (_, applied) = migrate_storage(storage, true).await.unwrap();
+        assert!(applied.is_empty());
+
+        assert_eq!(
+            dry_run_migrations(Memory::default(), None).await.unwrap(),
+            applied_for_real
+        );
+    }
+
+    #[tokio::test]
+    async fn restore_on_failure() {
+        use crate::wallet::{
+            migration::journal::MigrationJournal,
+            storage::constants::{ACCOUNTS_INDEXATION_KEY, ACCOUNT_INDEXATION_KEY},
+        };
+
+        let storage = memory_storage();
+        storage.set(ACCOUNTS_INDEXATION_KEY, &[0]).await.unwrap();
+        storage
+            .set(&format!("{ACCOUNT_INDEXATION_KEY}0"), &serde_json::json!({}))
+            .await
+            .unwrap();
+        // The account can't be migrated.
+        assert!(migrate_storage(storage, false).await.is_err());
+
+        let storage = memory_storage();
+        storage.set(ACCOUNTS_INDEXATION_KEY, &[0]).await.unwrap();
+        let journal = MigrationJournal::new(storage.inner, false).await.unwrap();
+        journal.set_bytes("changed", b"new").await.unwrap();
+        journal.set_bytes(ACCOUNTS_INDEXATION_KEY, b"[1]").await.unwrap();
+        journal.delete(ACCOUNTS_INDEXATION_KEY).await.unwrap();
+        assert_eq!(journal.get_bytes("changed").await.unwrap(), Some(b"new".to_vec()));
+
+        let inner = journal.finish(false).await.unwrap();
+        assert_eq!(inner.dyn_get_bytes("changed").await.unwrap(), None);
+        assert_eq!(
+            inner.dyn_get_bytes(ACCOUNTS_INDEXATION_KEY).await.unwrap(),
+            Some(b"[0]".to_vec())
+        );
+        assert_eq!(inner.dyn_get_bytes(MIGRATION_BACKUP_KEY).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn restore_interrupted() {
+        use crate::wallet::migration::journal::MigrationJournal;
+
+        // The records of a migration that was interrupted after changing them.
+        let storage = Memory::default();
+        storage.set_bytes("changed", b"new").await.unwrap();
+        storage.set_bytes("deleted", b"new").await.unwrap();
+        storage
+            .set_bytes(
+                MIGRATION_BACKUP_KEY,
+                &serde_json::to_vec(&HashMap::from([
+                    ("changed".to_string(), Some(b"old".to_vec())),
+                    ("deleted".to_string(), None),
+                ]))
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let journal = MigrationJournal::new(Box::new(storage), true).await.unwrap();
+        assert_eq!(journal.get_bytes("changed").await.unwrap(), Some(b"old".to_vec()));
+        assert_eq!(journal.get_bytes("deleted").await.unwrap(), None);
+        let storage = journal.finish(true).await.unwrap();
+        assert!(storage.dyn_get_bytes(MIGRATION_BACKUP_KEY).await.unwrap().is_some());
+
+        let journal = MigrationJournal::new(storage, false).await.unwrap();
+        let storage = journal.finish(true).await.unwrap();
+        assert_eq!(storage.dyn_get_bytes("changed").await.unwrap(), Some(b"old".to_vec()));
+        assert_eq!(storage.dyn_get_bytes("deleted").await.unwrap(), None);
+        assert_eq!(storage.dyn_get_bytes(MIGRATION_BACKUP_KEY).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn newer_version() {
+        let storage = memory_storage();
+        let version = MigrationVersion {
+            id: usize::MAX,
+            sdk_version: "99.0.0".to_string(),
+            date: time::macros::date!(2099 - 01 - 01),
+        };
+        storage.set(MIGRATION_VERSION_KEY, &version).await.unwrap();
+
+        assert!(matches!(
+            migrate_storage(storage, false).await,
+            Err(Error::Migration(_))
+        ));
+    }
+}
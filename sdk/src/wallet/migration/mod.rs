@@ -3,6 +3,8 @@
 
 #[cfg(any(feature = "storage", feature = "stronghold"))]
 pub(crate) mod chrysalis;
+#[cfg(feature = "storage")]
+pub(crate) mod journal;
 mod migrate_0;
 mod migrate_1;
 mod migrate_2;
@@ -19,6 +21,8 @@ pub use chrysalis::rocksdb::migrate_db_chrysalis_to_stardust;
 use once_cell::sync::Lazy;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+#[cfg(feature = "storage")]
+use crate::wallet::storage::Storage;
 use crate::{
     client::storage::StorageAdapter,
     wallet::{Error, Result},
@@ -33,7 +37,6 @@ static MIGRATIONS: Lazy<Map<dyn anymap::any::Any + Send + Sync>> = Lazy::new(||
     let mut migrations = Map::new();
     #[cfg(feature = "storage")]
     {
-        use super::storage::Storage;
         const STORAGE_MIGRATIONS: [(Option<usize>, &'static dyn DynMigration<Storage>); 5] = [
             // In order to add a new storage migration, add an entry at the bottom of this list
             // and change the list length above.
@@ -126,15 +129,68 @@ where
 }
 
 pub async fn migrate<S: 'static + StorageAdapter>(storage: &S) -> Result<()>
+where
+    crate::wallet::Error: From<S::Error>,
+    S::Error: From<serde_json::Error>,
+{
+    apply_migrations(storage).await.map(|_| ())
+}
+
+/// Applies the pending migrations in order and returns their versions.
+async fn apply_migrations<S: 'static + StorageAdapter>(storage: &S) -> Result<Vec<MigrationVersion>>
 where
     crate::wallet::Error: From<S::Error>,
     S::Error: From<serde_json::Error>,
 {
     let last_migration = storage.get::<MigrationVersion>(MIGRATION_VERSION_KEY).await?;
+    let mut applied = Vec::new();
     for migration in migrations(last_migration)? {
         migration.migrate(storage).await?;
+        applied.push(migration.version());
     }
-    Ok(())
+    Ok(applied)
+}
+
+/// Applies the pending migrations of the wallet storage.
+///
+/// The changed records are backed up and restored if a migration fails, and in a dry run the changes are discarded.
+#[cfg(feature = "storage")]
+pub(crate) async fn migrate_storage(storage: Storage, dry_run: bool) -> Result<(Storage, Vec<MigrationVersion>)> {
+    let Storage { inner, encryption_key } = storage;
+    let journal = journal::MigrationJournal::new(inner, dry_run).await?;
+    let journal_storage = Storage {
+        inner: Box::new(journal.clone()),
+        encryption_key: encryption_key.clone(),
+    };
+
+    let result = apply_migrations(&journal_storage).await;
+    drop(journal_storage);
+    if let Err(e) = &result {
+        log::warn!("Migration failed, restoring the previous records: {e}");
+    }
+    let inner = journal.finish(result.is_ok()).await?;
+
+    Ok((Storage { inner, encryption_key }, result?))
+}
+
+/// Runs the pending migrations of a wallet storage without persisting any change.
+///
+/// Checks if the storage can be upgraded by this SDK version and returns the versions of the migrations that would be
+/// applied.
+#[cfg(feature = "storage")]
+pub async fn dry_run_migrations<S: StorageAdapter + 'static>(
+    storage: S,
+    encryption_key: impl Into<Option<[u8; 32]>> + Send,
+) -> Result<Vec<MigrationVersion>>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    let storage = Storage {
+        inner: Box::new(storage),
+        encryption_key: encryption_key.into().map(zeroize::Zeroizing::new),
+    };
+
+    migrate_storage(storage, true).await.map(|(_, applied)| applied)
 }
 
 fn migrations<S: 'static + StorageAdapter>(
@@ -148,6 +204,16 @@ fn migrations<S: 'static + StorageAdapter>(
                 std::any::type_name::<S>()
             ))
         })?;
+    if let Some(last_migration) = &last_migration {
+        if !migrations
+            .values()
+            .any(|migration| migration.version().id == last_migration.id)
+        {
+            return Err(Error::Migration(format!(
+                "unknown migration version {last_migration}, the storage was migrated by a newer sdk version"
+            )));
+        }
+    }
     let mut res = Vec::new();
     while let Some(next) = migrations.get(&last_migration.as_ref().map(|m| m.id)) {
         last_migration = Some(next.version());
@@ -20,6 +20,8 @@ pub mod error;
 #[cfg(feature = "events")]
 #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
 pub mod events;
+/// The module for validating and normalizing user provided labels.
+pub(crate) mod label;
 /// The storage module.
 #[cfg(feature = "storage")]
 #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
@@ -27,6 +29,8 @@ pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
 
+#[cfg(feature = "stronghold")]
+pub use self::core::{BackupOptions, RestoreConflictPolicy, RestoreOptions};
 pub use self::{
     account::{
         operations::transaction::high_level::{
@@ -37,7 +41,10 @@ pub use self::{
         },
         Account,
     },
-    core::{Wallet, WalletBuilder},
+    core::{
+        AddressBook, AddressBookEntry, AutoClaimOptions, SpendConfirmationCallback, SpendPolicy, SpendRequest, Wallet,
+        WalletBuilder, WalletFingerprint, WalletSyncProgress,
+    },
     error::Error,
 };
 
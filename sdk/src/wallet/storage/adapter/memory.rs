@@ -29,4 +29,8 @@ impl StorageAdapter for Memory {
         self.0.write().await.remove(key);
         Ok(())
     }
+
+    async fn keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        Ok(Some(self.0.read().await.keys().cloned().collect()))
+    }
 }
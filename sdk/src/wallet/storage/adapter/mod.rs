@@ -19,6 +19,8 @@ pub(crate) trait DynStorageAdapter: std::fmt::Debug + Send + Sync {
 
     /// Removes a record from the storage.
     async fn dyn_delete(&self, key: &str) -> crate::wallet::Result<()>;
+
+    async fn dyn_keys(&self) -> crate::wallet::Result<Option<Vec<String>>>;
 }
 
 #[async_trait]
@@ -37,6 +39,10 @@ where
     async fn dyn_delete(&self, key: &str) -> crate::wallet::Result<()> {
         Ok(self.delete(key).await?)
     }
+
+    async fn dyn_keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        Ok(self.keys().await?)
+    }
 }
 
 #[async_trait]
@@ -54,4 +60,8 @@ impl StorageAdapter for dyn DynStorageAdapter {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.dyn_delete(key).await
     }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        self.dyn_keys().await
+    }
 }
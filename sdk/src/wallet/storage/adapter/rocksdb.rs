@@ -3,7 +3,7 @@
 
 use std::{path::Path, sync::Arc};
 
-use rocksdb::{DBCompressionType, Options, DB};
+use rocksdb::{DBCompressionType, IteratorMode, Options, DB};
 use tokio::sync::Mutex;
 
 use crate::client::storage::StorageAdapter;
@@ -45,4 +45,16 @@ impl StorageAdapter for RocksdbStorageAdapter {
         self.db.lock().await.delete(key)?;
         Ok(())
     }
+
+    async fn keys(&self) -> crate::wallet::Result<Option<Vec<String>>> {
+        let mut keys = Vec::new();
+        for record in self.db.lock().await.iterator(IteratorMode::Start) {
+            let (key, _) = record?;
+            // The wallet only stores records with string keys
+            if let Ok(key) = String::from_utf8(key.into_vec()) {
+                keys.push(key);
+            }
+        }
+        Ok(Some(keys))
+    }
 }
@@ -19,10 +19,14 @@ pub(crate) const WALLET_INDEXATION_KEY: &str = "iota-wallet-account-manager";
 
 pub(crate) const SECRET_MANAGER_KEY: &str = "secret_manager";
 
+pub(crate) const ADDRESS_BOOK_KEY: &str = "iota-wallet-address-book";
+
 pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
+pub(crate) const ACCOUNT_SYNC_CHECKPOINT: &str = "sync-checkpoint";
+pub(crate) const ACCOUNT_TOKEN_FILTER: &str = "token-filter";
 
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
@@ -1,19 +1,33 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "stronghold")]
+use std::collections::HashMap;
+
 use futures::{StreamExt, TryStreamExt};
 use zeroize::Zeroizing;
 
+#[cfg(feature = "stronghold")]
+use crate::wallet::migration::journal::MIGRATION_BACKUP_KEY;
 use crate::{
     client::storage::StorageAdapter,
     types::TryFromDto,
     wallet::{
-        account::{AccountDetails, AccountDetailsDto, SyncOptions},
-        migration::migrate,
+        account::{AccountDetails, AccountDetailsDto, SyncCheckpoint, SyncOptions, TokenFilter},
+        core::AddressBook,
+        migration::migrate_storage,
         storage::{constants::*, DynStorageAdapter, Storage},
     },
 };
 
+// A record of the wallet storage, decrypted to change the encryption key.
+#[cfg(feature = "stronghold")]
+enum PlainRecord {
+    Record(Zeroizing<Vec<u8>>),
+    // The backup of an interrupted migration, with its records decrypted.
+    MigrationBackup(HashMap<String, Option<Zeroizing<Vec<u8>>>>),
+}
+
 /// Storage manager
 #[derive(Debug)]
 pub(crate) struct StorageManager {
@@ -31,7 +45,7 @@ impl StorageManager {
             inner: Box::new(storage) as _,
             encryption_key: encryption_key.into(),
         };
-        migrate(&storage).await?;
+        let (storage, _) = migrate_storage(storage, false).await?;
 
         // Get the db version or set it
         if let Some(db_schema_version) = storage.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await? {
@@ -92,6 +106,14 @@ impl StorageManager {
 
     pub(crate) async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
         self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}")).await?;
+        self.delete(&format!(
+            "{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CHECKPOINT}"
+        ))
+        .await?;
+        self.delete(&format!(
+            "{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_TOKEN_FILTER}"
+        ))
+        .await?;
         self.account_indexes.retain(|a| a != &account_index);
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
     }
@@ -112,6 +134,140 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.get(&key).await
     }
+
+    pub(crate) async fn set_sync_checkpoint(
+        &self,
+        account_index: u32,
+        sync_checkpoint: &SyncCheckpoint,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CHECKPOINT}");
+        self.set(&key, &sync_checkpoint).await
+    }
+
+    pub(crate) async fn get_sync_checkpoint(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<SyncCheckpoint>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CHECKPOINT}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_token_filter(
+        &self,
+        account_index: u32,
+        token_filter: &TokenFilter,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_TOKEN_FILTER}");
+        self.set(&key, &token_filter).await
+    }
+
+    pub(crate) async fn get_token_filter(&self, account_index: u32) -> crate::wallet::Result<Option<TokenFilter>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_TOKEN_FILTER}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_address_book(&self, address_book: &AddressBook) -> crate::wallet::Result<()> {
+        self.set(ADDRESS_BOOK_KEY, address_book).await
+    }
+
+    /// Re-encrypts all records of the wallet with a new encryption key, or stores them unencrypted if `None`. If
+    /// writing a record fails, the records are restored with the previous key.
+    #[cfg(feature = "stronghold")]
+    pub(crate) async fn change_encryption_key(
+        &mut self,
+        encryption_key: Option<Zeroizing<[u8; 32]>>,
+    ) -> crate::wallet::Result<()> {
+        let keys = self
+            .storage
+            .keys()
+            .await?
+            .ok_or_else(|| crate::wallet::Error::Storage("can't list the records of the storage".to_string()))?;
+
+        // Read everything with the old key first, so a failure doesn't leave records with mixed keys
+        let mut records = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(record) = self.read_plain_record(&key).await? {
+                records.push((key, record));
+            }
+        }
+
+        let old_encryption_key = core::mem::replace(&mut self.storage.encryption_key, encryption_key);
+        for (index, (key, record)) in records.iter().enumerate() {
+            if let Err(err) = self.write_plain_record(key, record).await {
+                log::error!("re-encrypting the wallet storage failed: {err}");
+                self.storage.encryption_key = old_encryption_key;
+
+                // Keep restoring after a failure, so as few records as possible are left with the new key
+                let mut restore_errors = Vec::new();
+                for (key, record) in &records[..=index] {
+                    if let Err(err) = self.write_plain_record(key, record).await {
+                        restore_errors.push(format!("{key}: {err}"));
+                    }
+                }
+                if restore_errors.is_empty() {
+                    return Err(err);
+                }
+                return Err(crate::wallet::Error::Storage(format!(
+                    "re-encrypting the wallet storage failed: {err}, restoring records with the previous key failed: {}",
+                    restore_errors.join(", ")
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Reads a record and decrypts it with the current encryption key.
+    #[cfg(feature = "stronghold")]
+    async fn read_plain_record(&self, key: &str) -> crate::wallet::Result<Option<PlainRecord>> {
+        if key != MIGRATION_BACKUP_KEY {
+            return Ok(self
+                .storage
+                .get_bytes(key)
+                .await?
+                .map(|record| PlainRecord::Record(record.into())));
+        }
+
+        // The backup of an interrupted migration is stored unencrypted and contains the records as they are stored
+        let Some(backup) = self.storage.inner.as_ref().get_bytes(key).await? else {
+            return Ok(None);
+        };
+        let backup = serde_json::from_slice::<HashMap<String, Option<Vec<u8>>>>(&backup)?
+            .into_iter()
+            .map(|(key, record)| {
+                let record = record.map(|record| self.storage.decrypt(&record)).transpose()?;
+                Ok((key, record.map(Zeroizing::new)))
+            })
+            .collect::<crate::wallet::Result<_>>()?;
+
+        Ok(Some(PlainRecord::MigrationBackup(backup)))
+    }
+
+    // Encrypts a record with the current encryption key and writes it.
+    #[cfg(feature = "stronghold")]
+    async fn write_plain_record(&self, key: &str, record: &PlainRecord) -> crate::wallet::Result<()> {
+        match record {
+            PlainRecord::Record(record) => self.storage.set_bytes(key, record).await,
+            PlainRecord::MigrationBackup(backup) => {
+                let backup = backup
+                    .iter()
+                    .map(|(key, record)| {
+                        let record = record.as_ref().map(|record| self.storage.encrypt(record)).transpose()?;
+                        Ok((key, record))
+                    })
+                    .collect::<crate::wallet::Result<HashMap<_, _>>>()?;
+                self.storage
+                    .inner
+                    .as_ref()
+                    .set_bytes(key, &serde_json::to_vec(&backup)?)
+                    .await
+            }
+        }
+    }
+
+    pub(crate) async fn get_address_book(&self) -> crate::wallet::Result<Option<AddressBook>> {
+        self.get(ADDRESS_BOOK_KEY).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -129,6 +285,10 @@ impl StorageAdapter for StorageManager {
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
         self.storage.delete(key).await
     }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        self.storage.keys().await
+    }
 }
 
 #[cfg(test)]
@@ -179,24 +339,83 @@ mod tests {
         assert!(storage_manager.get_accounts().await.unwrap().is_empty());
     }
 
+    #[cfg(feature = "stronghold")]
     #[tokio::test]
-    async fn save_get_wallet_data() {
-        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
-        assert!(
-            WalletBuilder::<SecretManager>::load(&storage_manager)
+    async fn change_encryption_key() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        storage_manager.set("record", &42u32).await.unwrap();
+        let backup = HashMap::from([("backed-up-record".to_string(), Some(b"1".to_vec()))]);
+        storage_manager
+            .storage
+            .inner
+            .as_ref()
+            .set_bytes(MIGRATION_BACKUP_KEY, &serde_json::to_vec(&backup).unwrap())
+            .await
+            .unwrap();
+
+        storage_manager
+            .change_encryption_key(Some(Zeroizing::new([1; 32])))
+            .await
+            .unwrap();
+        assert_eq!(storage_manager.get::<u32>("record").await.unwrap(), Some(42));
+        assert_ne!(
+            storage_manager
+                .storage
+                .inner
+                .as_ref()
+                .get_bytes("record")
+                .await
+                .unwrap(),
+            Some(b"42".to_vec())
+        );
+        let Some(PlainRecord::MigrationBackup(encrypted_backup)) =
+            storage_manager.read_plain_record(MIGRATION_BACKUP_KEY).await.unwrap()
+        else {
+            panic!("expected the migration backup");
+        };
+        assert_eq!(
+            encrypted_backup["backed-up-record"].as_deref().map(Vec::as_slice),
+            Some(b"1".as_slice())
+        );
+
+        storage_manager.change_encryption_key(None).await.unwrap();
+        assert_eq!(
+            storage_manager
+                .storage
+                .inner
+                .as_ref()
+                .get_bytes("record")
+                .await
+                .unwrap(),
+            Some(b"42".to_vec())
+        );
+        assert_eq!(
+            storage_manager
+                .storage
+                .inner
+                .as_ref()
+                .get_bytes(MIGRATION_BACKUP_KEY)
                 .await
                 .unwrap()
-                .is_none()
+                .map(|backup| serde_json::from_slice::<HashMap<String, Option<Vec<u8>>>>(&backup).unwrap()),
+            Some(backup)
         );
+    }
+
+    #[tokio::test]
+    async fn save_get_wallet_data() {
+        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        assert!(WalletBuilder::<SecretManager>::load(&storage_manager)
+            .await
+            .unwrap()
+            .is_none());
 
         let wallet_builder = WalletBuilder::<SecretManager>::new();
         wallet_builder.save(&storage_manager).await.unwrap();
 
-        assert!(
-            WalletBuilder::<SecretManager>::load(&storage_manager)
-                .await
-                .unwrap()
-                .is_some()
-        );
+        assert!(WalletBuilder::<SecretManager>::load(&storage_manager)
+            .await
+            .unwrap()
+            .is_some());
     }
 }
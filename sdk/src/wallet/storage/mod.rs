@@ -23,12 +23,33 @@ use zeroize::Zeroizing;
 use self::adapter::DynStorageAdapter;
 pub(crate) use self::manager::StorageManager;
 pub use self::{kind::StorageKind, options::StorageOptions};
-use crate::client::storage::StorageAdapter;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::label::{normalize_label, MAX_STORAGE_KEY_LENGTH},
+};
 
 #[derive(Debug)]
 pub struct Storage {
     pub(crate) inner: Box<dyn DynStorageAdapter>,
-    encryption_key: Option<Zeroizing<[u8; 32]>>,
+    pub(crate) encryption_key: Option<Zeroizing<[u8; 32]>>,
+}
+
+impl Storage {
+    // Encrypts a record with the encryption key, if one is set.
+    pub(crate) fn encrypt(&self, record: &[u8]) -> crate::wallet::Result<Vec<u8>> {
+        match &self.encryption_key {
+            Some(encryption_key) => Ok(chacha::aead_encrypt(encryption_key.as_ref(), record)?),
+            None => Ok(record.to_vec()),
+        }
+    }
+
+    // Decrypts a record with the encryption key, if one is set.
+    pub(crate) fn decrypt(&self, record: &[u8]) -> crate::wallet::Result<Vec<u8>> {
+        match &self.encryption_key {
+            Some(encryption_key) => Ok(chacha::aead_decrypt(encryption_key.as_ref(), record)?),
+            None => Ok(record.to_vec()),
+        }
+    }
 }
 
 #[async_trait]
@@ -36,33 +57,54 @@ impl StorageAdapter for Storage {
     type Error = crate::wallet::Error;
 
     async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
-        match self.inner.as_ref().get_bytes(key).await? {
-            Some(record) => {
-                if let Some(encryption_key) = &self.encryption_key {
-                    return Ok(Some(chacha::aead_decrypt(encryption_key.as_ref(), &record)?));
-                }
-
-                Ok(Some(record))
-            }
-            None => Ok(None),
+        let normalized_key = normalize_storage_key(key)?;
+        let mut record = self.inner.as_ref().get_bytes(&normalized_key).await?;
+        // Records written before keys were normalized are still stored under the raw key.
+        if record.is_none() && normalized_key != key {
+            record = self.inner.as_ref().get_bytes(key).await?;
+        }
+
+        match record {
+            Some(record) if self.encryption_key.is_some() => Ok(Some(self.decrypt(&record)?)),
+            record => Ok(record),
         }
     }
 
     async fn set_bytes(&self, key: &str, record: &[u8]) -> Result<(), Self::Error> {
-        if let Some(encryption_key) = &self.encryption_key {
-            let encrypted_bytes = chacha::aead_encrypt(encryption_key.as_ref(), record)?;
-            self.inner.as_ref().set_bytes(key, &encrypted_bytes).await?
+        let normalized_key = normalize_storage_key(key)?;
+        if self.encryption_key.is_some() {
+            self.inner
+                .as_ref()
+                .set_bytes(&normalized_key, &self.encrypt(record)?)
+                .await?
         } else {
-            self.inner.as_ref().set_bytes(key, record).await?
+            self.inner.as_ref().set_bytes(&normalized_key, record).await?
+        }
+        // Migrate the record away from the raw key, so an outdated record can't be read anymore.
+        if normalized_key != key {
+            self.inner.as_ref().delete(key).await?;
         }
         Ok(())
     }
 
     async fn delete(&self, key: &str) -> Result<(), Self::Error> {
-        self.inner.as_ref().delete(key).await
+        let normalized_key = normalize_storage_key(key)?;
+        self.inner.as_ref().delete(&normalized_key).await?;
+        if normalized_key != key {
+            self.inner.as_ref().delete(key).await?;
+        }
+        Ok(())
+    }
+
+    async fn keys(&self) -> Result<Option<Vec<String>>, Self::Error> {
+        self.inner.as_ref().keys().await
     }
 }
 
+fn normalize_storage_key(key: &str) -> crate::wallet::Result<String> {
+    normalize_label("storage key", key, MAX_STORAGE_KEY_LENGTH)
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -97,6 +139,28 @@ mod tests {
         assert_eq!(None, storage.get::<Record>("key").await.unwrap());
     }
 
+    #[tokio::test]
+    async fn not_normalized_key() {
+        // "é" as "e" followed by a combining acute accent, as it could have been stored before keys were normalized
+        let raw_key = "cafe\u{301}";
+        let storage = Storage {
+            inner: Box::<Memory>::default(),
+            encryption_key: None,
+        };
+        storage.inner.as_ref().set_bytes(raw_key, b"old").await.unwrap();
+
+        // The raw key is the fallback
+        assert_eq!(storage.get_bytes(raw_key).await.unwrap().as_deref(), Some(&b"old"[..]));
+
+        // Writing migrates the record to the normalized key
+        storage.set_bytes(raw_key, b"new").await.unwrap();
+        assert_eq!(storage.inner.as_ref().get_bytes(raw_key).await.unwrap(), None);
+        assert_eq!(storage.get_bytes(raw_key).await.unwrap().as_deref(), Some(&b"new"[..]));
+
+        storage.delete(raw_key).await.unwrap();
+        assert_eq!(storage.get_bytes(raw_key).await.unwrap(), None);
+    }
+
     #[cfg(feature = "rand")]
     #[tokio::test]
     async fn get_set_encrypted() {
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    client::{api::input_selection::Error as IsaError, Error},
-    types::block::Error as BlockError,
+    client::{api::input_selection::Error as IsaError, Client, Error},
+    types::block::{output::OutputId, Error as BlockError},
 };
 use pretty_assertions::assert_eq;
 
@@ -44,4 +44,39 @@ fn stringified_error() {
         &serde_json::to_string(&error).unwrap(),
         "{\"type\":\"inputSelection\",\"error\":\"invalid address provided\"}"
     );
+
+    let error = Error::Bech32HrpMismatch {
+        provided: "smr".to_string(),
+        expected: "rms".to_string(),
+    };
+    assert_eq!(
+        &serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"bech32HrpMismatch\",\"error\":\"invalid bech32 hrp for the connected network: smr, expected: rms, this looks like a Shimmer address\"}"
+    );
+}
+
+#[tokio::test]
+async fn output_requests_failed() {
+    // Nothing listens on the port, so every request fails
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap();
+    let output_ids = [
+        OutputId::null(),
+        "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000"
+            .parse()
+            .unwrap(),
+    ];
+
+    let Err(Error::OutputRequestsFailed(failed)) = client.get_outputs_with_concurrency(&output_ids, 1).await else {
+        panic!("expected OutputRequestsFailed error variant");
+    };
+    assert_eq!(
+        failed.iter().map(|(output_id, _)| *output_id).collect::<Vec<_>>(),
+        output_ids
+    );
 }
@@ -0,0 +1,66 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{explorer::ExplorerUrlTemplate, Client, ClientBuilder},
+    types::block::{address::Bech32Address, payload::transaction::TransactionId, rand::block::rand_block},
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn explorer_urls() {
+    let template = ExplorerUrlTemplate::new("https://explorer.shimmer.network/testnet/{kind}/{id}").unwrap();
+
+    let block = rand_block();
+    assert_eq!(
+        block.explorer_url(&template),
+        format!("https://explorer.shimmer.network/testnet/block/{}", block.id())
+    );
+
+    let transaction_id = TransactionId::new([1; 32]);
+    assert_eq!(
+        template.transaction_url(&transaction_id),
+        format!("https://explorer.shimmer.network/testnet/transaction/{transaction_id}")
+    );
+
+    let bech32_address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"
+        .parse::<Bech32Address>()
+        .unwrap();
+    let expected =
+        "https://explorer.shimmer.network/testnet/addr/rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+    assert_eq!(bech32_address.explorer_url(&template), expected);
+    assert_eq!(
+        bech32_address.inner().explorer_url(&template, *bech32_address.hrp()),
+        expected
+    );
+
+    // Templates need an id placeholder and to be valid urls
+    assert!(ExplorerUrlTemplate::new("https://explorer.shimmer.network/testnet").is_err());
+    assert!(ExplorerUrlTemplate::new("explorer/{kind}/{id}").is_err());
+    assert!(serde_json::from_str::<ExplorerUrlTemplate>(r#""https://explorer.shimmer.network""#).is_err());
+}
+
+#[tokio::test]
+async fn explorer_url_template_option() {
+    let template = ExplorerUrlTemplate::new("https://explorer.shimmer.network/testnet/{kind}/{id}").unwrap();
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_explorer_url_template(template.clone())
+        .finish()
+        .await
+        .unwrap();
+
+    assert_eq!(client.get_explorer_url_template().await, Some(template.clone()));
+
+    let json = serde_json::to_value(ClientBuilder::from_client(&client).await).unwrap();
+    assert_eq!(json["explorerUrlTemplate"], template.as_str());
+    assert_eq!(
+        serde_json::from_value::<ClientBuilder>(json)
+            .unwrap()
+            .network_info
+            .explorer_url_template,
+        Some(template)
+    );
+}
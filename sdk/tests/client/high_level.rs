@@ -1,9 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::types::block::{
-    address::ToBech32Ext,
-    payload::{transaction::TransactionEssence, Payload},
+use std::sync::Mutex;
+
+use iota_sdk::{
+    client::api::RetryProgressEvent,
+    types::block::{
+        address::ToBech32Ext,
+        payload::{transaction::TransactionEssence, Payload},
+    },
 };
 use pretty_assertions::assert_eq;
 
@@ -59,3 +64,30 @@ async fn test_find_inputs() {
         unreachable!();
     }
 }
+
+#[ignore]
+#[tokio::test]
+async fn test_retry_until_included_with_progress() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (block_id, _transaction_id) = setup_transaction_block(&client).await;
+    let events = Mutex::new(Vec::new());
+
+    let blocks = client
+        .retry_until_included_with_progress(&block_id, None, None, |event| events.lock().unwrap().push(event))
+        .await
+        .unwrap();
+
+    // Every reported reattachment is returned.
+    let reattached = events
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .filter_map(|event| match event {
+            RetryProgressEvent::Reattached(block_id) => Some(block_id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert!(reattached
+        .iter()
+        .all(|id| blocks.iter().any(|(block_id, _)| block_id == id)));
+}
@@ -4,7 +4,7 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    client::api::input_selection::{Error, InputSelection, Requirement},
+    client::api::input_selection::{Error, InputSelection, Requirement, UnusableInputReason},
     types::block::{
         address::{Address, AliasAddress, Bech32Address, NftAddress},
         output::{AliasId, NftId},
@@ -494,12 +494,10 @@ fn ed25519_sender() {
 
     // Sender + another for amount
     assert_eq!(selected.inputs.len(), 2);
-    assert!(
-        selected
-            .inputs
-            .iter()
-            .any(|input| *input.output.as_basic().address() == sender)
-    );
+    assert!(selected
+        .inputs
+        .iter()
+        .any(|input| *input.output.as_basic().address() == sender));
     // Provided output + remainder
     assert_eq!(selected.outputs.len(), 2);
 }
@@ -588,12 +586,10 @@ fn alias_sender() {
 
     // Sender + another for amount
     assert_eq!(selected.inputs.len(), 2);
-    assert!(
-        selected
-            .inputs
-            .iter()
-            .any(|input| input.output.is_alias() && *input.output.as_alias().alias_id() == alias_id_1)
-    );
+    assert!(selected
+        .inputs
+        .iter()
+        .any(|input| input.output.is_alias() && *input.output.as_alias().alias_id() == alias_id_1));
     // Provided output + alias
     assert_eq!(selected.outputs.len(), 2);
     assert!(selected.outputs.contains(&outputs[0]));
@@ -645,12 +641,10 @@ fn alias_sender_zero_id() {
 
     assert!(unsorted_eq(&selected.inputs, &inputs));
     assert_eq!(selected.outputs.len(), 2);
-    assert!(
-        selected
-            .outputs
-            .iter()
-            .any(|output| output.is_alias() && *output.as_alias().alias_id() == alias_id)
-    );
+    assert!(selected
+        .outputs
+        .iter()
+        .any(|output| output.is_alias() && *output.as_alias().alias_id() == alias_id));
 }
 
 #[test]
@@ -736,12 +730,10 @@ fn nft_sender() {
 
     // Sender + another for amount
     assert_eq!(selected.inputs.len(), 2);
-    assert!(
-        selected
-            .inputs
-            .iter()
-            .any(|input| input.output.is_nft() && *input.output.as_nft().nft_id() == nft_id_1)
-    );
+    assert!(selected
+        .inputs
+        .iter()
+        .any(|input| input.output.is_nft() && *input.output.as_nft().nft_id() == nft_id_1));
     // Provided output + nft
     assert_eq!(selected.outputs.len(), 2);
     assert!(selected.outputs.contains(&inputs[2].output));
@@ -794,12 +786,10 @@ fn nft_sender_zero_id() {
 
     assert!(unsorted_eq(&selected.inputs, &inputs));
     assert_eq!(selected.outputs.len(), 2);
-    assert!(
-        selected
-            .outputs
-            .iter()
-            .any(|output| output.is_nft() && *output.as_nft().nft_id() == nft_id)
-    );
+    assert!(selected
+        .outputs
+        .iter()
+        .any(|output| output.is_nft() && *output.as_nft().nft_id() == nft_id));
 }
 
 #[test]
@@ -1391,3 +1381,145 @@ fn too_many_outputs_with_remainder() {
         iota_sdk::client::api::input_selection::Error::InvalidOutputCount(129)
     )
 }
+
+#[test]
+fn mandatory_inputs_and_optional_pool() {
+    let protocol_parameters = protocol_parameters();
+
+    let mandatory_inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let optional_inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        optional_inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .mandatory_inputs(mandatory_inputs.clone())
+    .select()
+    .unwrap();
+
+    // The mandatory input is consumed, the optional ones only as needed.
+    assert!(selected.inputs.contains(&mandatory_inputs[0]));
+    assert_eq!(selected.inputs.len(), 2);
+    assert!(selected.inputs.contains(&optional_inputs[0]));
+    assert!(selected.remainder.is_none());
+}
+
+#[test]
+fn mandatory_input_not_controlled() {
+    let protocol_parameters = protocol_parameters();
+
+    let mandatory_inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let optional_inputs = build_inputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        optional_inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .mandatory_inputs(mandatory_inputs.clone())
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        Error::RequiredInputIsUnusable {
+            output_id: *mandatory_inputs[0].output_id(),
+            reason: UnusableInputReason::NotControlled,
+        }
+    );
+}
+
+#[test]
+fn mandatory_input_insufficient_amount() {
+    let protocol_parameters = protocol_parameters();
+
+    let mandatory_inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        Vec::new(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .mandatory_inputs(mandatory_inputs)
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        Error::InsufficientAmount {
+            found: 1_000_000,
+            required: 2_000_000,
+        }
+    );
+}
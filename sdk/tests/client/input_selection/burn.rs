@@ -7,10 +7,10 @@ use std::{
 };
 
 use iota_sdk::{
-    client::api::input_selection::{Burn, Error, InputSelection, Requirement},
+    client::api::input_selection::{Burn, Error, InputSelection, InvalidBurnReason, Requirement},
     types::block::{
         address::Address,
-        output::{AliasId, AliasTransition, ChainId, NftId, SimpleTokenScheme, TokenId},
+        output::{AliasId, AliasTransition, ChainId, FoundryId, NftId, SimpleTokenScheme, TokenId},
         protocol::protocol_parameters,
     },
 };
@@ -941,3 +941,52 @@ fn burn_foundry_and_its_alias() {
         Err(Error::UnfulfillableRequirement(Requirement::Alias(alias_id, AliasTransition::State))) if alias_id == alias_id_1
     ));
 }
+
+#[test]
+fn burn_builder_consistency() {
+    let alias_id_2 = AliasId::from_str(ALIAS_ID_2).unwrap();
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1).unwrap();
+    let foundry_id = FoundryId::from(token_id_1);
+
+    let burn = Burn::builder()
+        .burn_native_token(token_id_1, 10)
+        .burn_native_token(token_id_1, 20)
+        .melt_native_token(token_id_1, 5)
+        .burn_nft(NftId::from_str(NFT_ID_1).unwrap())
+        .finish()
+        .unwrap();
+    assert_eq!(burn.native_tokens().get(&token_id_1).unwrap().as_u32(), 30);
+    assert_eq!(burn.melted_native_tokens().get(&token_id_1).unwrap().as_u32(), 5);
+
+    assert_eq!(
+        Burn::builder().burn_native_token(token_id_1, 0).finish().unwrap_err(),
+        Error::InvalidBurn(InvalidBurnReason::ZeroAmount(token_id_1))
+    );
+    assert_eq!(
+        Burn::builder()
+            .melt_native_token(token_id_1, 5)
+            .destroy_foundry(foundry_id)
+            .finish()
+            .unwrap_err(),
+        Error::InvalidBurn(InvalidBurnReason::MeltAndDestroyFoundry(foundry_id))
+    );
+
+    let foundry_alias_id = *foundry_id.alias_address().alias_id();
+    assert_eq!(
+        Burn::builder()
+            .destroy_foundry(foundry_id)
+            .destroy_alias(foundry_alias_id)
+            .finish()
+            .unwrap_err(),
+        Error::InvalidBurn(InvalidBurnReason::FoundryOfDestroyedAlias {
+            foundry_id,
+            alias_id: foundry_alias_id,
+        })
+    );
+    // Destroying an unrelated alias is fine.
+    assert!(Burn::builder()
+        .destroy_foundry(foundry_id)
+        .destroy_alias(alias_id_2)
+        .finish()
+        .is_ok());
+}
@@ -4,7 +4,7 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    client::api::input_selection::{Error, InputSelection},
+    client::api::input_selection::{Error, InputSelection, UnusableInputReason},
     types::block::{
         output::{AliasId, NftId},
         protocol::protocol_parameters,
@@ -95,6 +95,79 @@ fn expiration_equal_timestamp() {
     assert!(unsorted_eq(&selected.outputs, &outputs));
 }
 
+#[test]
+fn expiration_within_safety_margin() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            Some((BECH32_ADDRESS_ED25519_1, 150)),
+            None,
+        ),
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            Some((BECH32_ADDRESS_ED25519_1, 200)),
+            None,
+        ),
+    ]);
+    let outputs = build_outputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters.clone(),
+    )
+    .timestamp(100)
+    .expiration_safety_margin(60)
+    .select()
+    .unwrap();
+
+    // The input that expires within the margin is skipped.
+    assert_eq!(selected.inputs.len(), 1);
+    assert_eq!(selected.inputs[0], inputs[1]);
+    assert!(unsorted_eq(&selected.outputs, &outputs));
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs([*inputs[0].output_id()])
+    .timestamp(100)
+    .expiration_safety_margin(60)
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        Error::RequiredInputIsUnusable {
+            output_id: *inputs[0].output_id(),
+            reason: UnusableInputReason::ExpiresSoon,
+        }
+    );
+}
+
 #[test]
 fn one_output_expiration_expired() {
     let protocol_parameters = protocol_parameters();
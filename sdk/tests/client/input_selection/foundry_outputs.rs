@@ -285,6 +285,85 @@ fn melt_native_tokens() {
     });
 }
 
+#[test]
+fn melt_native_tokens_with_burn() {
+    let protocol_parameters = protocol_parameters();
+    let alias_id_1 = AliasId::from_str(ALIAS_ID_1).unwrap();
+    let token_id = "0x0811111111111111111111111111111111111111111111111111111111111111110100000000";
+
+    let mut inputs = build_inputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Foundry(
+            1_000_000,
+            alias_id_1,
+            1,
+            SimpleTokenScheme::new(10, 0, 10).unwrap(),
+            Some(vec![(token_id, 10)]),
+        ),
+    ]);
+    let alias_output = AliasOutputBuilder::new_with_amount(1_000_000, alias_id_1)
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(
+            Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap(),
+        ))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(
+            Address::try_from_bech32(BECH32_ADDRESS_ED25519_0).unwrap(),
+        ))
+        .with_foundry_counter(1)
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    inputs.push(InputSigningData {
+        output: alias_output,
+        output_metadata: OutputMetadata::new(rand_block_id(), rand_output_id(), false, None, None, None, 0, 0, 0),
+        chain: None,
+    });
+    let burn = Burn::builder()
+        .melt_native_token(TokenId::from_str(token_id).unwrap(), 5)
+        .finish()
+        .unwrap();
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        Vec::new(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters.clone(),
+    )
+    .burn(burn.clone())
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    // Alias next state + foundry + basic output with native tokens
+    assert_eq!(selected.outputs.len(), 3);
+    selected.outputs.iter().for_each(|output| {
+        if let Output::Foundry(foundry_output) = &output {
+            assert_eq!(foundry_output.token_scheme().as_simple().melted_tokens().as_u32(), 5);
+            assert!(foundry_output.native_tokens().is_empty());
+        }
+        if !output.is_alias() && !output.is_foundry() {
+            assert!(is_remainder_or_return(
+                output,
+                1_000_000,
+                BECH32_ADDRESS_ED25519_0,
+                Some(vec![(token_id, 5)])
+            ));
+        }
+    });
+
+    let dry_run = burn
+        .dry_run(InputSelection::new(
+            inputs,
+            Vec::new(),
+            addresses([BECH32_ADDRESS_ED25519_0]),
+            protocol_parameters,
+        ))
+        .unwrap();
+
+    assert_eq!(dry_run.inputs.len(), 3);
+    assert_eq!(dry_run.input_amount, 3_000_000);
+    assert_eq!(dry_run.output_amount, 3_000_000);
+    assert_eq!(dry_run.remainder_amount, 1_000_000);
+}
+
 #[test]
 fn destroy_foundry_with_alias_state_transition() {
     let protocol_parameters = protocol_parameters();
@@ -5,14 +5,19 @@ use std::str::FromStr;
 
 use iota_sdk::{
     client::api::input_selection::{Burn, Error, InputSelection},
-    types::block::{output::TokenId, protocol::protocol_parameters, rand::bytes::rand_bytes_array},
+    types::block::{
+        address::Address,
+        output::{Rent, TokenId},
+        protocol::protocol_parameters,
+        rand::bytes::rand_bytes_array,
+    },
 };
 use pretty_assertions::assert_eq;
 use primitive_types::U256;
 
 use crate::client::{
     addresses, build_inputs, build_outputs, is_remainder_or_return, unsorted_eq, Build::Basic,
-    BECH32_ADDRESS_ED25519_0, TOKEN_ID_1, TOKEN_ID_2,
+    BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1, TOKEN_ID_1, TOKEN_ID_2,
 };
 
 #[test]
@@ -1535,6 +1540,169 @@ fn higher_nts_count_but_below_max_native_tokens() {
     ));
 }
 
+#[test]
+fn max_native_token_count_alternative_input() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100), (TOKEN_ID_2, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .max_native_token_count(1)
+    .select()
+    .unwrap();
+
+    assert_eq!(selected.inputs.len(), 1);
+    assert!(selected.inputs.contains(&inputs[1]));
+    assert_eq!(selected.outputs, outputs);
+}
+
+#[test]
+fn max_native_token_count_exceeded() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100), (TOKEN_ID_2, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters.clone(),
+    )
+    .max_native_token_count(1)
+    .select();
+
+    assert_eq!(selected.unwrap_err(), Error::TooManyNativeTokens { found: 2, max: 1 });
+
+    // Fits under the default max.
+    assert!(InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .select()
+    .is_ok());
+}
+
+#[test]
+fn token_remainder_address() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100), (TOKEN_ID_2, 100)]),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs([Basic(
+        500_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs.clone(),
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters.clone(),
+    )
+    .token_remainder_address(
+        TokenId::from_str(TOKEN_ID_1).unwrap(),
+        Address::try_from_bech32(BECH32_ADDRESS_ED25519_1).unwrap(),
+    )
+    .select()
+    .unwrap();
+
+    assert!(unsorted_eq(&selected.inputs, &inputs));
+    assert_eq!(selected.outputs.len(), 3);
+    assert!(selected.outputs.contains(&outputs[0]));
+
+    // The token with its own remainder address is sent there with the minimum storage deposit.
+    let token_remainder = selected
+        .outputs
+        .iter()
+        .find(|output| output.as_basic().address() == &Address::try_from_bech32(BECH32_ADDRESS_ED25519_1).unwrap())
+        .unwrap();
+    let token_remainder_amount = token_remainder.rent_cost(protocol_parameters.rent_structure());
+    assert!(is_remainder_or_return(
+        token_remainder,
+        token_remainder_amount,
+        BECH32_ADDRESS_ED25519_1,
+        Some(vec![(TOKEN_ID_1, 100)])
+    ));
+    // The other token stays in the base coin remainder.
+    assert!(is_remainder_or_return(
+        &selected.remainder.unwrap().output,
+        1_500_000 - token_remainder_amount,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_2, 100)])
+    ));
+}
+
 // T27: :wavy_dash:
 // inputs: [basic{ amount: 1_000_000, native_tokens: [{‘a’: 100}] }, basic{ amount: 1_000_000, native_tokens: [{‘a’:
 // 200}] }] }] outputs: [basic{ amount: 500_000, native_tokens: [{‘a’: 150}] }]
@@ -0,0 +1,156 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::api::input_selection::{ChangeStrategy, CoinSelectionStrategy, InputSelection},
+    types::block::protocol::protocol_parameters,
+};
+use pretty_assertions::assert_eq;
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1,
+};
+
+fn select_with_strategy(strategy: CoinSelectionStrategy) -> (Vec<u64>, Option<u64>) {
+    let inputs = build_inputs(
+        [1_000_000, 2_000_000, 3_000_000, 5_000_000]
+            .map(|amount| Basic(amount, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None)),
+    );
+    let outputs = build_outputs([Basic(
+        4_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters(),
+    )
+    .strategy(strategy)
+    .select()
+    .unwrap();
+
+    let mut input_amounts = selected
+        .inputs
+        .iter()
+        .map(|input| input.output.amount())
+        .collect::<Vec<_>>();
+    input_amounts.sort_unstable();
+
+    (
+        input_amounts,
+        selected.remainder.map(|remainder| remainder.output.amount()),
+    )
+}
+
+#[test]
+fn largest_first() {
+    assert_eq!(
+        select_with_strategy(CoinSelectionStrategy::LargestFirst),
+        (vec![5_000_000], Some(1_000_000))
+    );
+}
+
+#[test]
+fn smallest_first() {
+    assert_eq!(
+        select_with_strategy(CoinSelectionStrategy::SmallestFirst),
+        (vec![1_000_000, 2_000_000, 3_000_000], Some(2_000_000))
+    );
+}
+
+#[test]
+fn branch_and_bound_exact_match() {
+    assert_eq!(
+        select_with_strategy(CoinSelectionStrategy::BranchAndBound),
+        (vec![1_000_000, 3_000_000], None)
+    );
+}
+
+#[test]
+fn random_covers_amount() {
+    let (input_amounts, remainder) = select_with_strategy(CoinSelectionStrategy::Random);
+
+    assert_eq!(
+        input_amounts.iter().sum::<u64>(),
+        4_000_000 + remainder.unwrap_or_default()
+    );
+}
+
+fn select_with_change_strategy(change_strategy: ChangeStrategy) -> (Vec<u64>, Vec<u64>) {
+    let inputs = build_inputs(
+        [1_000_000, 2_000_000, 10_000_000]
+            .map(|amount| Basic(amount, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None)),
+    );
+    let outputs = build_outputs([Basic(
+        4_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters(),
+    )
+    .strategy(CoinSelectionStrategy::LargestFirst)
+    .change_strategy(change_strategy)
+    .select()
+    .unwrap();
+
+    let mut input_amounts = selected
+        .inputs
+        .iter()
+        .map(|input| input.output.amount())
+        .collect::<Vec<_>>();
+    input_amounts.sort_unstable();
+
+    (
+        input_amounts,
+        selected.outputs.iter().map(|output| output.amount()).collect(),
+    )
+}
+
+#[test]
+fn single_remainder() {
+    assert_eq!(
+        select_with_change_strategy(ChangeStrategy::Single),
+        (vec![10_000_000], vec![4_000_000, 6_000_000])
+    );
+}
+
+#[test]
+fn split_remainder() {
+    assert_eq!(
+        select_with_change_strategy(ChangeStrategy::Split(4)),
+        (
+            vec![10_000_000],
+            vec![4_000_000, 1_500_000, 1_500_000, 1_500_000, 1_500_000]
+        )
+    );
+    // The remainder isn't split into outputs holding less than twice their minimum storage deposit.
+    let (_, outputs) = select_with_change_strategy(ChangeStrategy::Split(u8::MAX));
+    assert_eq!(outputs[0], 4_000_000);
+    assert_eq!(outputs[1..], [500_000; 12]);
+}
+
+#[test]
+fn merge_remainder_into_existing() {
+    assert_eq!(
+        select_with_change_strategy(ChangeStrategy::MergeIntoExisting),
+        (vec![1_000_000, 10_000_000], vec![4_000_000, 7_000_000])
+    );
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    client::api::input_selection::{Error, InputSelection},
+    client::api::input_selection::{Error, InputSelection, UnusableInputReason},
     types::block::protocol::protocol_parameters,
 };
 use pretty_assertions::assert_eq;
@@ -221,3 +221,50 @@ fn one_output_timelock_expired() {
     assert!(unsorted_eq(&selected.inputs, &inputs));
     assert!(unsorted_eq(&selected.outputs, &outputs));
 }
+
+#[test]
+fn required_input_timelock_not_expired() {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            Some(200),
+            None,
+            None,
+        ),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs.clone(),
+        outputs,
+        addresses([BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .required_inputs([*inputs[0].output_id()])
+    .timestamp(100)
+    .select();
+
+    assert_eq!(
+        selected.unwrap_err(),
+        Error::RequiredInputIsUnusable {
+            output_id: *inputs[0].output_id(),
+            reason: UnusableInputReason::TimeLocked,
+        }
+    );
+}
@@ -6,6 +6,7 @@ mod client_builder;
 mod common;
 mod consolidation;
 mod error;
+mod explorer;
 mod high_level;
 mod input_selection;
 mod input_signing_data;
@@ -13,8 +14,16 @@ mod mnemonic;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+mod pow;
+mod protocol_parameters;
+mod provenance;
 mod secret_manager;
+#[cfg(feature = "service")]
+mod service;
 mod signing;
+mod tag_protocol;
+mod transaction_envelope;
+mod transaction_summary;
 mod transactions;
 
 use std::{
@@ -1,4 +1,6 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod broker_options;
 mod topic;
+mod utxo_changes;
@@ -0,0 +1,31 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::StreamExt;
+use pretty_assertions::assert_eq;
+
+use crate::client::common::setup_client_with_node_health_ignored;
+
+#[ignore]
+#[tokio::test]
+async fn utxo_changes_stream() {
+    let client = setup_client_with_node_health_ignored().await;
+    let confirmed_index = client
+        .get_info()
+        .await
+        .unwrap()
+        .node_info
+        .status
+        .confirmed_milestone
+        .index;
+    let start_index = confirmed_index.saturating_sub(2).max(1);
+
+    let stream = client.utxo_changes_stream(start_index).await.unwrap();
+    futures::pin_mut!(stream);
+
+    // Backfilled past milestones and at least one new milestone, in order
+    for index in start_index..=confirmed_index + 1 {
+        let utxo_changes = stream.next().await.unwrap().unwrap();
+        assert_eq!(utxo_changes.index, index);
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{node_api::chronicle::LedgerUpdatesQuery, Client, Error},
+    types::{api::chronicle::SortOrder, block::address::Bech32Address},
+};
+
+#[tokio::test]
+async fn chronicle_routes_require_a_permanode() {
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap();
+    let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"
+        .parse::<Bech32Address>()
+        .unwrap();
+
+    assert!(matches!(
+        client
+            .get_ledger_updates_by_address(&address, &LedgerUpdatesQuery::default())
+            .await,
+        Err(Error::MissingPermanode)
+    ));
+    assert!(matches!(
+        client.get_address_history(&address, SortOrder::Oldest).await,
+        Err(Error::MissingPermanode)
+    ));
+}
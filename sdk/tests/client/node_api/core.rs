@@ -139,6 +139,24 @@ async fn test_get_output() {
     println!("{r:#?}");
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_get_outputs_with_concurrency() {
+    let client = setup_client_with_node_health_ignored().await;
+    let (_block_id, transaction_id) = setup_transaction_block(&client).await;
+    let output_ids = [
+        OutputId::new(transaction_id, 0).unwrap(),
+        OutputId::new(transaction_id, 1).unwrap(),
+    ];
+
+    let outputs = client.get_outputs_with_concurrency(&output_ids, 1).await.unwrap();
+
+    assert_eq!(
+        outputs.iter().map(|output| *output.metadata().output_id()).collect::<Vec<_>>(),
+        output_ids
+    );
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_output_raw() {
@@ -1,8 +1,9 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::TryStreamExt;
 use iota_sdk::{
-    client::{api::GetAddressesOptions, Result},
+    client::{api::GetAddressesOptions, node_api::indexer::query_parameters::QueryParameter, Result},
     types::block::{
         address::AliasAddress,
         output::{
@@ -150,3 +151,27 @@ async fn get_foundry_output_id_test() -> Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn basic_output_ids_stream_test() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+
+    let address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(0..1))
+        .await?[0];
+
+    // A page size of 1 makes sure that the cursor has to be followed
+    let query_parameters = [QueryParameter::Address(address), QueryParameter::PageSize(1)];
+
+    let output_ids = client.basic_output_ids(query_parameters.clone()).await?.items;
+    let streamed_output_ids = client
+        .basic_output_ids_stream(query_parameters)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert!(!output_ids.is_empty());
+    assert_eq!(output_ids, streamed_output_ids);
+
+    Ok(())
+}
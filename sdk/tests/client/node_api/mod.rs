@@ -1,6 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod chronicle;
 mod core;
 mod indexer;
 #[cfg(feature = "mqtt")]
@@ -0,0 +1,143 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::{
+    client::{
+        api::{PowAbortHandle, PowEvent, PowPolicy},
+        Client, ClientBuilder, Error,
+    },
+    types::block::{protocol::ProtocolParameters, rand::parents::rand_parents},
+    utils::unix_timestamp_now,
+};
+use pretty_assertions::assert_eq;
+
+async fn local_pow_client() -> Client {
+    Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(true)
+        .with_pow_worker_count(2)
+        // Don't restart the PoW, which can take a while in debug builds.
+        .with_tips_interval(3600)
+        .finish()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn local_pow_worker_pool() {
+    let client = local_pow_client().await;
+    let min_pow_score = client.get_min_pow_score().await.unwrap();
+
+    let block = client.finish_block_builder(Some(rand_parents()), None).await.unwrap();
+    assert!(block.verify_nonce(min_pow_score).is_ok());
+}
+
+#[tokio::test]
+async fn abort_local_pow() {
+    let client = local_pow_client().await;
+
+    let abort_handle = PowAbortHandle::new();
+    abort_handle.abort();
+    assert!(abort_handle.is_aborted());
+    assert!(matches!(
+        client
+            .finish_block_builder_with_abort_handle(Some(rand_parents()), None, &abort_handle)
+            .await,
+        Err(Error::PowAborted)
+    ));
+
+    // The background task doesn't start before the test yields, so it's aborted before the PoW.
+    let submission = client.submit_block(Some(rand_parents()), None);
+    submission.abort();
+    assert!(submission.abort_handle().is_aborted());
+    assert!(matches!(submission.finish().await, Err(Error::PowAborted)));
+}
+
+#[tokio::test]
+async fn local_then_remote_pow_policy() {
+    let local_timeout = Duration::from_millis(1);
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(true)
+        .with_pow_policy(PowPolicy::LocalThenRemote { local_timeout })
+        .with_tips_interval(3600)
+        .finish()
+        .await
+        .unwrap();
+    let mut pow_events = client.subscribe_pow_events();
+
+    // The timeout is too short for local PoW, so the block is left for a node with remote PoW.
+    let block = client.finish_block_builder(Some(rand_parents()), None).await.unwrap();
+    assert_eq!(block.nonce(), 0);
+    assert_eq!(
+        pow_events.try_recv().unwrap(),
+        PowEvent::FallbackToRemotePow { local_timeout }
+    );
+}
+
+#[test]
+fn pow_policy_serialization() {
+    let client_builder = serde_json::from_str::<ClientBuilder>(
+        r#"{"powPolicy":{"type":"localThenRemote","localTimeout":{"secs":30,"nanos":0}}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        client_builder.network_info.pow_policy,
+        PowPolicy::LocalThenRemote {
+            local_timeout: Duration::from_secs(30)
+        }
+    );
+
+    let client_builder = serde_json::from_str::<ClientBuilder>("{}").unwrap();
+    assert_eq!(client_builder.network_info.pow_policy, PowPolicy::Static);
+}
+
+#[tokio::test]
+async fn prepare_block() {
+    let client = local_pow_client().await;
+    let min_pow_score = client.get_min_pow_score().await.unwrap();
+    let below_max_depth = client.get_below_max_depth().await.unwrap() as u32;
+    let now = unix_timestamp_now().as_secs() as u32;
+
+    let prepared_block = client.prepare_block(Some(rand_parents()), None).await.unwrap();
+    assert!(prepared_block.block().verify_nonce(min_pow_score).is_ok());
+    assert!(prepared_block.valid_until() >= now + below_max_depth * 5);
+    assert!(!prepared_block.is_expired(now));
+    assert!(prepared_block.is_expired(prepared_block.valid_until()));
+}
+
+#[tokio::test]
+async fn submit_expired_prepared_block() {
+    let defaults = ProtocolParameters::default();
+    let protocol_parameters = ProtocolParameters::new(
+        defaults.protocol_version(),
+        defaults.network_name().to_string(),
+        *defaults.bech32_hrp(),
+        defaults.min_pow_score(),
+        0,
+        *defaults.rent_structure(),
+        defaults.token_supply(),
+    )
+    .unwrap();
+    let mut client_builder = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_local_pow(true)
+        .with_tips_interval(3600);
+    client_builder.network_info.protocol_parameters = protocol_parameters;
+    let client = client_builder.finish().await.unwrap();
+
+    // Without a below max depth the block is expired right away, so it's not posted to the node.
+    let prepared_block = client.prepare_block(Some(rand_parents()), None).await.unwrap();
+    assert!(matches!(
+        client.submit_prepared(&prepared_block).await,
+        Err(Error::PreparedBlockExpired { valid_until }) if valid_until == prepared_block.valid_until()
+    ));
+}
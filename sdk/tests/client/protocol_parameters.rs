@@ -0,0 +1,87 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use iota_sdk::client::{
+    node_manager::interceptor::{Request, RequestInterceptor},
+    protocol_parameters::ProtocolParametersRefresh,
+    Client, ClientBuilder,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Default)]
+struct PathRecorder(Mutex<Vec<String>>);
+
+impl RequestInterceptor for PathRecorder {
+    fn on_request(&self, request: &mut Request) {
+        self.0.lock().unwrap().push(request.url().path().to_string());
+    }
+}
+
+#[tokio::test]
+async fn interval_refresh() {
+    let recorder = Arc::new(PathRecorder::default());
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_node_sync_interval(Duration::from_secs(3600))
+        .with_request_interceptor(recorder.clone())
+        .with_protocol_parameters_refresh(ProtocolParametersRefresh::Interval {
+            interval: Duration::from_millis(10),
+        })
+        .finish()
+        .await
+        .unwrap();
+    let mut updates = client.subscribe_protocol_parameters_updates();
+    let synced = recorder.0.lock().unwrap().len();
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    // Nothing listens on the port, so the refreshes fail and the protocol parameters stay the same.
+    {
+        let requests = recorder.0.lock().unwrap();
+        assert!(requests.len() > synced + 1);
+        assert!(requests[synced..].iter().all(|path| path == "/api/core/v2/info"));
+    }
+    assert!(updates.try_recv().is_err());
+
+    // The refresh is stopped with the client.
+    drop(client);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let stopped = recorder.0.lock().unwrap().len();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(recorder.0.lock().unwrap().len(), stopped);
+}
+
+#[test]
+fn refresh_serialization() {
+    let client_builder = serde_json::from_str::<ClientBuilder>(
+        r#"{"protocolParametersRefresh":{"type":"interval","interval":{"secs":30,"nanos":0}}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        client_builder.network_info.protocol_parameters_refresh,
+        Some(ProtocolParametersRefresh::Interval {
+            interval: Duration::from_secs(30)
+        })
+    );
+
+    let client_builder = serde_json::from_str::<ClientBuilder>("{}").unwrap();
+    assert_eq!(client_builder.network_info.protocol_parameters_refresh, None);
+}
+
+#[cfg(feature = "mqtt")]
+#[test]
+fn on_milestone_serialization() {
+    let client_builder =
+        serde_json::from_str::<ClientBuilder>(r#"{"protocolParametersRefresh":{"type":"onMilestone"}}"#).unwrap();
+    assert_eq!(
+        client_builder.network_info.protocol_parameters_refresh,
+        Some(ProtocolParametersRefresh::OnMilestone)
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::client::{
+    node_api::provenance::{Provenance, WithProvenance},
+    Client,
+};
+use pretty_assertions::assert_eq;
+
+fn provenance() -> Provenance {
+    Provenance {
+        url: "https://node.example/api/core/v2/info".parse().unwrap(),
+        status: 200,
+        latency: Duration::from_millis(42),
+        ledger_index: Some(1234),
+    }
+}
+
+#[test]
+fn with_provenance() {
+    let result = WithProvenance {
+        data: 21u32,
+        provenance: provenance(),
+    };
+
+    let doubled = result.clone().map(|data| data * 2);
+    assert_eq!(doubled.data, 42);
+    assert_eq!(doubled.provenance, provenance());
+
+    let converted = result.clone().try_map(u8::try_from).unwrap();
+    assert_eq!(converted.into_inner(), 21u8);
+    assert!(result.map(|data| data + 255).try_map(u8::try_from).is_err());
+}
+
+#[test]
+fn provenance_serialization() {
+    let json = serde_json::to_value(provenance()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "url": "https://node.example/api/core/v2/info",
+            "status": 200,
+            "latency": { "secs": 0, "nanos": 42_000_000 },
+            "ledgerIndex": 1234
+        })
+    );
+    assert_eq!(serde_json::from_value::<Provenance>(json).unwrap(), provenance());
+}
+
+#[tokio::test]
+async fn unreachable_node() {
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap();
+
+    assert!(client.get_info_with_provenance().await.is_err());
+}
@@ -0,0 +1,110 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{
+        service::{ClientService, HealthReport},
+        Client, NodeInfoWrapper,
+    },
+    types::{
+        api::core::response::{
+            BaseTokenResponse, ConfirmedMilestoneResponse, InfoResponse, LatestMilestoneResponse, MetricsResponse,
+            StatusResponse,
+        },
+        block::protocol::ProtocolParameters,
+    },
+};
+use pretty_assertions::assert_eq;
+
+fn node_info(is_healthy: bool, latest_milestone_index: u32, confirmed_milestone_index: u32) -> NodeInfoWrapper {
+    NodeInfoWrapper {
+        node_info: InfoResponse {
+            name: "HORNET".to_string(),
+            version: "2.0.0".to_string(),
+            status: StatusResponse {
+                is_healthy,
+                latest_milestone: LatestMilestoneResponse {
+                    index: latest_milestone_index,
+                    timestamp: None,
+                    milestone_id: None,
+                },
+                confirmed_milestone: ConfirmedMilestoneResponse {
+                    index: confirmed_milestone_index,
+                    timestamp: None,
+                    milestone_id: None,
+                },
+                pruning_index: 0,
+            },
+            supported_protocol_versions: vec![2],
+            protocol: ProtocolParameters::default(),
+            pending_protocol_parameters: Vec::new(),
+            base_token: BaseTokenResponse {
+                name: "Shimmer".to_string(),
+                ticker_symbol: "SMR".to_string(),
+                unit: "SMR".to_string(),
+                subunit: Some("glow".to_string()),
+                decimals: 6,
+                use_metric_prefix: false,
+            },
+            metrics: MetricsResponse {
+                blocks_per_second: 0.0,
+                referenced_blocks_per_second: 0.0,
+                referenced_rate: 0.0,
+            },
+            features: Vec::new(),
+        },
+        url: "http://localhost:14265".to_string(),
+    }
+}
+
+#[test]
+fn readiness_from_node_info() {
+    let report = HealthReport::from_node_info(&node_info(true, 10, 9), 2);
+    assert_eq!(
+        report,
+        HealthReport {
+            healthy: true,
+            node_url: Some("http://localhost:14265".to_string()),
+            synced: Some(true),
+            milestone_lag: Some(1),
+            error: None,
+        }
+    );
+    assert_eq!(report.status_code(), 200);
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        r#"{"healthy":true,"nodeUrl":"http://localhost:14265","synced":true,"milestoneLag":1}"#
+    );
+
+    let report = HealthReport::from_node_info(&node_info(false, 10, 10), 2);
+    assert!(!report.healthy);
+    assert_eq!(report.error.as_deref(), Some("node is not synced"));
+    assert_eq!(report.status_code(), 503);
+
+    let report = HealthReport::from_node_info(&node_info(true, 10, 7), 2);
+    assert!(!report.healthy);
+    assert_eq!(report.milestone_lag, Some(3));
+    assert_eq!(report.error.as_deref(), Some("milestone lag 3 exceeds the max of 2"));
+}
+
+#[tokio::test]
+async fn liveness_and_readiness() {
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .finish()
+        .await
+        .unwrap();
+    let service = ClientService::new(client).with_max_milestone_lag(5);
+
+    // The client works without a reachable node, but a service depending on it isn't ready.
+    let liveness = service.liveness().await;
+    assert!(liveness.healthy);
+    assert_eq!(liveness.status_code(), 200);
+
+    let readiness = service.readiness().await;
+    assert!(!readiness.healthy);
+    assert!(readiness.error.is_some());
+    assert_eq!(readiness.status_code(), 503);
+}
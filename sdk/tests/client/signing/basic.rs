@@ -27,7 +27,7 @@ use iota_sdk::{
 };
 use pretty_assertions::assert_eq;
 
-use crate::client::{build_inputs, build_outputs, Build::Basic};
+use crate::client::{build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0};
 
 #[tokio::test]
 async fn single_ed25519_unlock() -> Result<()> {
@@ -323,3 +323,69 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn validate_before_signing() -> Result<()> {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE)),
+    )]);
+
+    let prepare = |amount| -> Result<PreparedTransactionData> {
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(
+                protocol_parameters.network_id(),
+                InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+            )
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                    .collect::<Vec<_>>(),
+            )
+            .with_outputs(build_outputs([Basic(
+                amount,
+                BECH32_ADDRESS_ED25519_0,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )]))
+            .finish_with_params(protocol_parameters.clone())?,
+        );
+
+        Ok(PreparedTransactionData {
+            essence,
+            inputs_data: inputs.clone(),
+            remainder: None,
+        })
+    };
+
+    assert_eq!(
+        prepare(1_000_000)?.validate(&protocol_parameters)?,
+        ConflictReason::None
+    );
+    assert_eq!(
+        prepare(900_000)?.validate(&protocol_parameters)?,
+        ConflictReason::CreatedConsumedAmountMismatch
+    );
+
+    let mut prepared_transaction_data = prepare(1_000_000)?;
+    prepared_transaction_data.inputs_data.clear();
+    assert_eq!(
+        prepared_transaction_data.validate(&protocol_parameters)?,
+        ConflictReason::InputUtxoNotFound
+    );
+
+    Ok(())
+}
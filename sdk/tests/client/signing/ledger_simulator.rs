@@ -0,0 +1,142 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{verify_semantic, PreparedTransactionData},
+        constants::{SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{
+            ledger_common::{BlindSigningReason, Error as LedgerError},
+            ledger_nano_simulator::LedgerSimulatorSecretManager,
+            SecretManage,
+        },
+        Client, Error, Result,
+    },
+    types::block::{
+        address::ToBech32Ext,
+        input::{Input, UtxoInput},
+        output::{InputsCommitment, NftId},
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            TransactionPayload,
+        },
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+    },
+};
+use pretty_assertions::assert_eq;
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{Basic, Nft},
+};
+
+async fn prepared_transaction_data(
+    secret_manager: &LedgerSimulatorSecretManager,
+    nft_output: bool,
+) -> Result<PreparedTransactionData> {
+    let protocol_parameters = protocol_parameters();
+    let bech32_address_0 = secret_manager
+        .generate_ed25519_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP)
+        .to_string();
+    let chain = Some(Bip44::new(SHIMMER_COIN_TYPE));
+
+    let inputs = build_inputs([
+        Basic(1_000_000, &bech32_address_0, None, None, None, None, None, chain),
+        Basic(1_000_000, &bech32_address_0, None, None, None, None, None, chain),
+    ]);
+    let outputs = build_outputs([if nft_output {
+        Nft(
+            2_000_000,
+            NftId::null(),
+            &bech32_address_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    } else {
+        Basic(2_000_000, &bech32_address_0, None, None, None, None, None, None)
+    }]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs)
+        .finish_with_params(protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
+
+#[tokio::test]
+async fn ledger_simulator_displayed_transaction() -> Result<()> {
+    let mut secret_manager = LedgerSimulatorSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    secret_manager.app_coin_type = SHIMMER_COIN_TYPE;
+
+    let prepared_transaction_data = prepared_transaction_data(&secret_manager, false).await?;
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+
+    assert_eq!(unlocks.len(), 2);
+
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, 100)?;
+    assert_eq!(conflict, ConflictReason::None);
+
+    secret_manager.user_approves = false;
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(0))
+            .await,
+        Err(Error::Ledger(LedgerError::DeniedByUser))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ledger_simulator_blind_signing() -> Result<()> {
+    let mut secret_manager = LedgerSimulatorSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?;
+    secret_manager.app_coin_type = SHIMMER_COIN_TYPE;
+
+    let prepared_transaction_data = prepared_transaction_data(&secret_manager, true).await?;
+
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(0))
+            .await,
+        Err(Error::Ledger(LedgerError::BlindSigningRequired(
+            BlindSigningReason::UnsupportedOutput { index: 0 }
+        )))
+    ));
+
+    secret_manager.blind_signing_enabled = true;
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, 100)?;
+    assert_eq!(conflict, ConflictReason::None);
+
+    Ok(())
+}
@@ -3,6 +3,8 @@
 
 mod alias;
 mod basic;
+#[cfg(feature = "ledger_nano_simulator")]
+mod ledger_simulator;
 mod nft;
 
 use std::str::FromStr;
@@ -0,0 +1,106 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{tag_protocol::TagProtocol, Client, Error},
+    types::block::{
+        output::{
+            feature::{MetadataFeature, TagFeature},
+            unlock_condition::AddressUnlockCondition,
+            BasicOutputBuilder,
+        },
+        payload::{Payload, TaggedDataPayload},
+        rand::address::rand_address,
+    },
+};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+use crate::client::TOKEN_SUPPLY;
+
+struct JsonProtocol {
+    name: &'static str,
+    tag_prefix: &'static [u8],
+}
+
+impl TagProtocol for JsonProtocol {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn tag_prefix(&self) -> &[u8] {
+        self.tag_prefix
+    }
+
+    fn decode(&self, _tag: &[u8], data: &[u8]) -> Result<Value, String> {
+        serde_json::from_slice(data).map_err(|e| e.to_string())
+    }
+}
+
+async fn client() -> Client {
+    Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_tag_protocol(JsonProtocol {
+            name: "chat",
+            tag_prefix: b"chat",
+        })
+        .with_tag_protocol(JsonProtocol {
+            name: "chat-v2",
+            tag_prefix: b"chat/v2",
+        })
+        .finish()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn decode_tagged_data() {
+    let client = client().await;
+
+    let tagged_data = TaggedDataPayload::new(b"chat/v1".to_vec(), br#"{"text":"hi"}"#.to_vec()).unwrap();
+    let payload = client.decode_tagged_data(&tagged_data).await.unwrap().unwrap();
+    assert_eq!(payload.protocol, "chat");
+    assert_eq!(payload.tag, b"chat/v1");
+    assert_eq!(payload.data, json!({ "text": "hi" }));
+
+    // The protocol with the longest matching prefix is used.
+    let tagged_data = TaggedDataPayload::new(b"chat/v2".to_vec(), b"1".to_vec()).unwrap();
+    let payload = client
+        .decode_payload(&Payload::from(tagged_data))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(payload.protocol, "chat-v2");
+
+    let tagged_data = TaggedDataPayload::new(b"other".to_vec(), b"1".to_vec()).unwrap();
+    assert!(client.decode_tagged_data(&tagged_data).await.unwrap().is_none());
+
+    let tagged_data = TaggedDataPayload::new(b"chat".to_vec(), b"not json".to_vec()).unwrap();
+    assert!(matches!(
+        client.decode_tagged_data(&tagged_data).await,
+        Err(Error::TagProtocolDecode { protocol, .. }) if protocol == "chat"
+    ));
+}
+
+#[tokio::test]
+async fn decode_output() {
+    let client = client().await;
+
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+        .add_feature(TagFeature::new(b"chat".to_vec()).unwrap())
+        .add_feature(MetadataFeature::new(br#"["hi"]"#.to_vec()).unwrap())
+        .finish_output(TOKEN_SUPPLY)
+        .unwrap();
+    let payload = client.decode_output(&output).await.unwrap().unwrap();
+    assert_eq!(payload.protocol, "chat");
+    assert_eq!(payload.data, json!(["hi"]));
+
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+        .finish_output(TOKEN_SUPPLY)
+        .unwrap();
+    assert!(client.decode_output(&output).await.unwrap().is_none());
+}
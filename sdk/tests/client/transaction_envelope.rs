@@ -0,0 +1,118 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip44::Bip44;
+use iota_sdk::{
+    client::{
+        api::{envelope, PreparedTransactionData, RemainderData, SignedTransactionData},
+        constants::SHIMMER_COIN_TYPE,
+        Error, Result,
+    },
+    types::block::{
+        address::Bech32Address,
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::{protocol_parameters, ProtocolParameters},
+    },
+};
+use pretty_assertions::assert_eq;
+
+use crate::client::{
+    build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_REMAINDER, TOKEN_ID_1,
+};
+
+#[test]
+fn envelope_round_trip() -> Result<()> {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs([Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        Some(vec![(TOKEN_ID_1, 100)]),
+        None,
+        None,
+        None,
+        None,
+        Some(Bip44::new(SHIMMER_COIN_TYPE).with_address_index(3)),
+    )]);
+    let outputs = build_outputs([
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_REMAINDER,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs.clone())
+        .finish_with_params(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: Some(RemainderData {
+            output: outputs[1].clone(),
+            chain: Some(Bip44::new(SHIMMER_COIN_TYPE).with_change(1)),
+            address: *Bech32Address::try_from_str(BECH32_ADDRESS_REMAINDER)?.inner(),
+        }),
+    };
+
+    let bytes = prepared_transaction_data.to_envelope_bytes(&protocol_parameters);
+    assert_eq!(bytes[0], envelope::TRANSACTION_DATA_ENVELOPE_VERSION);
+    assert_eq!(bytes[1], envelope::PREPARED_TRANSACTION_DATA_KIND);
+    assert_eq!(
+        PreparedTransactionData::from_envelope_bytes(&bytes, &protocol_parameters)?,
+        prepared_transaction_data
+    );
+
+    let json = prepared_transaction_data.to_envelope_json(&protocol_parameters)?;
+    assert_eq!(
+        PreparedTransactionData::from_envelope_json(&json, &protocol_parameters)?,
+        prepared_transaction_data
+    );
+
+    // Other protocol parameters, kinds, versions and truncated data are rejected.
+    assert!(matches!(
+        PreparedTransactionData::from_envelope_bytes(&bytes, &ProtocolParameters::default()),
+        Err(Error::TransactionDataEnvelope(
+            envelope::Error::ProtocolParametersMismatch { .. }
+        ))
+    ));
+    assert!(matches!(
+        SignedTransactionData::from_envelope_json(&json, &protocol_parameters),
+        Err(Error::TransactionDataEnvelope(envelope::Error::InvalidKind {
+            expected: envelope::SIGNED_TRANSACTION_DATA_KIND,
+            found: envelope::PREPARED_TRANSACTION_DATA_KIND,
+        }))
+    ));
+    let mut unsupported = bytes.clone();
+    unsupported[0] = 0;
+    assert!(matches!(
+        PreparedTransactionData::from_envelope_bytes(&unsupported, &protocol_parameters),
+        Err(Error::TransactionDataEnvelope(envelope::Error::UnsupportedVersion(0)))
+    ));
+    assert!(matches!(
+        PreparedTransactionData::from_envelope_bytes(&bytes[..bytes.len() - 1], &protocol_parameters),
+        Err(Error::Unpack(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    client::{
+        api::{PreparedTransactionData, RemainderData},
+        Result,
+    },
+    types::block::{
+        address::Bech32Address,
+        input::{Input, UtxoInput},
+        output::{InputsCommitment, NativeToken, NftId, TokenId},
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+    },
+};
+use pretty_assertions::assert_eq;
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{Basic, Nft},
+    BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1, BECH32_ADDRESS_REMAINDER, NFT_ID_1, TOKEN_ID_1,
+};
+
+#[test]
+fn summary() -> Result<()> {
+    let protocol_parameters = protocol_parameters();
+    let nft_id = NftId::from_str(NFT_ID_1)?;
+
+    let inputs = build_inputs([
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            nft_id,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let outputs = build_outputs([
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_1,
+            None,
+            None,
+            Some((BECH32_ADDRESS_ED25519_0, 300_000)),
+            None,
+            Some((BECH32_ADDRESS_ED25519_0, 100)),
+            None,
+        ),
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_REMAINDER,
+            Some(vec![(TOKEN_ID_1, 60)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect::<Vec<_>>(),
+        )
+        .with_outputs(outputs.clone())
+        .finish_with_params(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: Some(RemainderData {
+            output: outputs[1].clone(),
+            chain: None,
+            address: *Bech32Address::try_from_str(BECH32_ADDRESS_REMAINDER)?.inner(),
+        }),
+    };
+
+    let summary = prepared_transaction_data.summary(&protocol_parameters)?;
+
+    assert_eq!(summary.recipients.len(), 1);
+    assert_eq!(summary.recipients[0].amount, 1_000_000);
+    assert_eq!(
+        summary.recipients[0].address,
+        Some(Bech32Address::try_from_str(BECH32_ADDRESS_ED25519_1)?)
+    );
+    assert_eq!(summary.recipients[0].storage_deposit_return, Some(300_000));
+    assert_eq!(summary.recipients[0].expiration, Some(100));
+    assert_eq!(summary.remainder.as_ref().unwrap().amount, 2_000_000);
+    assert_eq!(summary.input_amount, 3_000_000);
+    assert_eq!(summary.output_amount, 3_000_000);
+    assert!(summary.minted_native_tokens.is_empty());
+    assert_eq!(
+        summary.burned_native_tokens,
+        [NativeToken::new(TokenId::from_str(TOKEN_ID_1)?, 40)?]
+    );
+    assert_eq!(summary.burned_nfts, [nft_id]);
+    assert!(summary.burned_aliases.is_empty());
+    assert!(summary.burned_foundries.is_empty());
+    assert!(summary.output_storage_deposit > 0);
+
+    Ok(())
+}
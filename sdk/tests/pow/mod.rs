@@ -2,4 +2,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod miner;
+mod pool;
 mod score;
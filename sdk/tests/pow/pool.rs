@@ -0,0 +1,68 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    pow::{miner::MinerCancel, pool::PowWorkerPool, score::PowScorer},
+    types::block::rand::bytes::rand_bytes,
+};
+
+#[test]
+fn pool_nonce_score() {
+    let pool = PowWorkerPool::new(4);
+    assert_eq!(pool.num_workers(), 4);
+
+    // The pool is reused for multiple searches.
+    for _ in 0..3 {
+        let mut bytes = rand_bytes(256);
+
+        let nonce = pool.nonce(&bytes[0..248], 100, &MinerCancel::new()).unwrap();
+        bytes[248..].copy_from_slice(&nonce.to_le_bytes());
+
+        assert!(PowScorer::new().score(&bytes) >= 100f64);
+    }
+}
+
+#[test]
+fn pool_concurrent_nonces() {
+    let pool = std::sync::Arc::new(PowWorkerPool::new(2));
+
+    let handles = (0..4)
+        .map(|_| {
+            let pool = pool.clone();
+            std::thread::spawn(move || {
+                let mut bytes = rand_bytes(256);
+                let nonce = pool.nonce(&bytes[0..248], 100, &MinerCancel::new()).unwrap();
+                bytes[248..].copy_from_slice(&nonce.to_le_bytes());
+                PowScorer::new().score(&bytes)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for handle in handles {
+        assert!(handle.join().unwrap() >= 100f64);
+    }
+}
+
+#[test]
+fn pool_cancel() {
+    let pool = std::sync::Arc::new(PowWorkerPool::new(4));
+    let cancel = MinerCancel::new();
+    let bytes = rand_bytes(248);
+
+    let now = std::time::Instant::now();
+    let handle = {
+        let pool = pool.clone();
+        let cancel = cancel.clone();
+        std::thread::spawn(move || pool.nonce(&bytes, u32::MAX, &cancel))
+    };
+
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    cancel.trigger();
+
+    assert!(handle.join().unwrap().is_none());
+    assert!(now.elapsed().as_secs() < 2);
+
+    // An already cancelled search doesn't start.
+    assert!(pool.nonce(&rand_bytes(248), 100, &cancel).is_none());
+}
@@ -4,7 +4,7 @@
 use core::str::FromStr;
 
 use iota_sdk::types::block::{
-    address::{Address, Bech32Address, Ed25519Address, Hrp},
+    address::{Address, Bech32Address, Bech32Network, Ed25519Address, Hrp},
     Error,
 };
 use packable::PackableExt;
@@ -69,3 +69,44 @@ fn bech32_into_inner() {
 
     assert_eq!(address, bech32_address.into_inner());
 }
+
+#[test]
+fn guess_network() {
+    let bech32_address = Bech32Address::from_str(ED25519_BECH32).unwrap();
+    assert_eq!(bech32_address.guess_network(), Some(Bech32Network::ShimmerTestnet));
+    assert_eq!(
+        Hrp::from_str("smr").unwrap().guess_network(),
+        Some(Bech32Network::Shimmer)
+    );
+    assert_eq!(
+        Hrp::from_str("iota").unwrap().guess_network(),
+        Some(Bech32Network::Iota)
+    );
+    assert_eq!(Hrp::from_str("tst").unwrap().guess_network(), None);
+    assert_eq!(Bech32Network::IotaTestnet.hrp(), "atoi");
+}
+
+#[test]
+fn hrp_mismatch() {
+    assert!(Bech32Address::try_from_str_with_hrp(ED25519_BECH32, "rms").is_ok());
+
+    let error = Bech32Address::try_from_str_with_hrp(ED25519_BECH32, "smr").unwrap_err();
+    assert_eq!(
+        error,
+        Error::Bech32HrpMismatch {
+            expected: "smr".to_string(),
+            actual: "rms".to_string(),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "bech32 hrp mismatch: expected smr but got rms, this looks like a Shimmer testnet address"
+    );
+
+    let bech32_address = Bech32Address::from_str(ED25519_BECH32).unwrap();
+    let bech32_address = Bech32Address::new(Hrp::from_str("tst").unwrap(), bech32_address.into_inner());
+    assert_eq!(
+        bech32_address.check_hrp("rms").unwrap_err().to_string(),
+        "bech32 hrp mismatch: expected rms but got tst"
+    );
+}
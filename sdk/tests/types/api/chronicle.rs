@@ -0,0 +1,60 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::{
+    api::chronicle::{LedgerUpdatesByAddressResponse, LedgerUpdatesByMilestoneResponse},
+    block::address::Address,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn ledger_updates_by_address() {
+    let json = r#"{
+        "address": "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+        "items": [{
+            "milestoneIndex": 10,
+            "milestoneTimestamp": 1690000000,
+            "outputId": "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000",
+            "isSpent": true
+        }],
+        "cursor": "1690000000.0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000.true.100"
+    }"#;
+
+    let response = serde_json::from_str::<LedgerUpdatesByAddressResponse>(json).unwrap();
+    assert_eq!(response.items.len(), 1);
+    assert_eq!(response.items[0].milestone_index, 10);
+    assert!(response.items[0].is_spent);
+    assert!(response.cursor.is_some());
+
+    let response = serde_json::to_value(&response).unwrap();
+    assert_eq!(
+        serde_json::from_value::<LedgerUpdatesByAddressResponse>(response.clone()).unwrap(),
+        serde_json::from_str::<LedgerUpdatesByAddressResponse>(json).unwrap()
+    );
+}
+
+#[test]
+fn ledger_updates_by_milestone() {
+    let json = r#"{
+        "milestoneIndex": 10,
+        "items": [{
+            "address": {
+                "type": 0,
+                "pubKeyHash": "0x7ffec9e1233204d9c6dce6812b1539ee96af691ca2e4d9065daa85907d33e5d3"
+            },
+            "outputId": "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000",
+            "isSpent": false
+        }]
+    }"#;
+
+    let response = serde_json::from_str::<LedgerUpdatesByMilestoneResponse>(json).unwrap();
+    assert_eq!(response.milestone_index, 10);
+    assert!(matches!(response.items[0].address, Address::Ed25519(_)));
+    assert!(!response.items[0].is_spent);
+    assert_eq!(response.cursor, None);
+
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+}
@@ -1,4 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod chronicle;
 mod participation;
+mod pending_protocol_parameters;
+mod poi;
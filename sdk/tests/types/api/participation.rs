@@ -3,7 +3,10 @@
 
 use std::str::FromStr;
 
-use iota_sdk::types::api::plugins::participation::types::{Participation, ParticipationEventId, Participations};
+use iota_sdk::types::api::plugins::participation::{
+    responses::RewardsResponse,
+    types::{Participation, ParticipationEventId, Participations},
+};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -101,3 +104,23 @@ fn mutate() {
         }
     );
 }
+
+#[test]
+fn rewards_response() {
+    let json = serde_json::json!({
+        "symbol": "SMR",
+        "milestoneIndex": 1000,
+        "totalRewards": 300,
+        "checksum": "0x01",
+        "rewards": {
+            "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy": 100,
+            "rms1qqdtnxhkwhlmyw7zaaxarfshf0pjtnfp68cq7dpgk5tzqcyy68zd6ksdkj7": 200
+        }
+    });
+
+    let response = serde_json::from_value::<RewardsResponse>(json.clone()).unwrap();
+
+    assert_eq!(response.milestone_index, 1000);
+    assert_eq!(response.total_rewards, response.rewards.values().sum::<u64>());
+    assert_eq!(serde_json::to_value(&response).unwrap(), json);
+}
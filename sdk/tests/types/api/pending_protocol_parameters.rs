@@ -0,0 +1,66 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::{
+    api::core::response::PendingProtocolParameter,
+    block::{output::RentStructure, protocol::ProtocolParameters, Error},
+};
+use packable::PackableExt;
+use pretty_assertions::assert_eq;
+
+fn pending_protocol_parameter(protocol_version: u8, params: &[u8]) -> PendingProtocolParameter {
+    serde_json::from_value(serde_json::json!({
+        "type": 1,
+        "targetMilestoneIndex": 1000,
+        "protocolVersion": protocol_version,
+        "params": prefix_hex::encode(params),
+    }))
+    .unwrap()
+}
+
+fn upcoming_protocol_parameters() -> ProtocolParameters {
+    ProtocolParameters::new(
+        3,
+        String::from("testnet"),
+        "rms",
+        1000,
+        15,
+        RentStructure::new(250, 10, 1),
+        1_813_620_509_061_365,
+    )
+    .unwrap()
+}
+
+#[test]
+fn try_into_parameters() {
+    let protocol_parameters = upcoming_protocol_parameters();
+    let pending = pending_protocol_parameter(3, &protocol_parameters.pack_to_vec());
+
+    assert_eq!(pending.try_into_parameters().unwrap(), protocol_parameters);
+}
+
+#[test]
+fn try_into_parameters_version_mismatch() {
+    let pending = pending_protocol_parameter(4, &upcoming_protocol_parameters().pack_to_vec());
+
+    assert!(matches!(
+        pending.try_into_parameters(),
+        Err(Error::ProtocolVersionMismatch { expected: 4, actual: 3 })
+    ));
+}
+
+#[test]
+fn try_into_parameters_invalid() {
+    let bytes = upcoming_protocol_parameters().pack_to_vec();
+    let truncated = pending_protocol_parameter(3, &bytes[..bytes.len() - 1]);
+
+    assert!(matches!(
+        truncated.try_into_parameters(),
+        Err(Error::InvalidField("params"))
+    ));
+
+    let mut not_hex = pending_protocol_parameter(3, &bytes);
+    not_hex.params = String::from("0xzz");
+
+    assert!(matches!(not_hex.try_into_parameters(), Err(Error::Hex(_))));
+}
@@ -0,0 +1,94 @@
+// Copyright 2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use iota_sdk::types::{
+    api::plugins::poi::{verify_poi, MerkleProof},
+    block::{
+        payload::milestone::{MerkleRoot, MilestoneEssence, MilestoneIndex, MilestoneOptions, MilestonePayload},
+        protocol::protocol_parameters,
+        rand::{
+            block::{rand_block, rand_block_id},
+            milestone::{rand_merkle_root, rand_milestone_id},
+            parents::rand_parents,
+            signature::rand_signature,
+        },
+        BlockId,
+    },
+};
+use pretty_assertions::assert_eq;
+
+fn leaf_hash(block_id: &BlockId) -> [u8; 32] {
+    Blake2b256::new()
+        .chain_update([0])
+        .chain_update(block_id)
+        .finalize()
+        .into()
+}
+
+fn node_hash(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+    Blake2b256::new()
+        .chain_update([1])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+fn milestone(inclusion_merkle_root: [u8; 32]) -> MilestonePayload {
+    MilestonePayload::new(
+        MilestoneEssence::new(
+            MilestoneIndex(1),
+            0,
+            protocol_parameters().protocol_version(),
+            rand_milestone_id(),
+            rand_parents(),
+            MerkleRoot::new(inclusion_merkle_root),
+            rand_merkle_root(),
+            [],
+            MilestoneOptions::from_vec(vec![]).unwrap(),
+        )
+        .unwrap(),
+        [rand_signature()],
+    )
+    .unwrap()
+}
+
+#[test]
+fn verify() {
+    let block = rand_block();
+    let sibling = rand_block_id();
+    let proof = MerkleProof::Node {
+        left: Box::new(MerkleProof::Hash {
+            hash: leaf_hash(&sibling),
+        }),
+        right: Box::new(MerkleProof::Value { value: block.id() }),
+    };
+    let root = node_hash(leaf_hash(&sibling), leaf_hash(&block.id()));
+
+    assert_eq!(proof.hash(), root);
+    assert!(verify_poi(&proof, &block, &milestone(root)));
+    assert!(!verify_poi(&proof, &block, &milestone(*rand_merkle_root())));
+    assert!(!verify_poi(&proof, &rand_block(), &milestone(root)));
+}
+
+#[test]
+fn proof_serde() {
+    let block_id = rand_block_id();
+    let hash = leaf_hash(&rand_block_id());
+    let json = serde_json::json!({
+        "l": { "h": prefix_hex::encode(hash) },
+        "r": { "value": block_id.to_string() },
+    });
+
+    let proof = serde_json::from_value::<MerkleProof>(json.clone()).unwrap();
+
+    assert_eq!(
+        proof,
+        MerkleProof::Node {
+            left: Box::new(MerkleProof::Hash { hash }),
+            right: Box::new(MerkleProof::Value { value: block_id }),
+        }
+    );
+    assert_eq!(serde_json::to_value(&proof).unwrap(), json);
+}
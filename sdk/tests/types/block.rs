@@ -37,6 +37,45 @@ fn pow_provider() {
     let score = PowScorer::new().score(&block_bytes);
 
     assert!(score >= min_pow_score as f64);
+    assert!(block.verify_nonce(min_pow_score).is_ok());
+}
+
+#[test]
+fn pow_score_and_verify_nonce() {
+    let mut bytes = vec![
+        2, 2, 140, 28, 186, 52, 147, 145, 96, 9, 105, 89, 78, 139, 3, 71, 249, 97, 149, 190, 63, 238, 168, 202, 82,
+        140, 227, 66, 173, 19, 110, 93, 117, 34, 225, 202, 251, 10, 156, 58, 144, 225, 54, 79, 62, 38, 20, 121, 95, 90,
+        112, 109, 6, 166, 126, 145, 13, 62, 52, 68, 248, 135, 223, 119, 137, 13, 0, 0, 0, 0, 21, 205, 91, 7, 0, 0, 0,
+        0,
+    ];
+    let min_pow_score = protocol_parameters().min_pow_score();
+
+    // Nonce 123456789, no trailing zeros.
+    let block = Block::unpack_strict(bytes.as_slice(), &protocol_parameters()).unwrap();
+    assert!((block.pow_score() - 1f64 / 78f64).abs() < f64::EPSILON);
+    assert_eq!(
+        block.verify_nonce(min_pow_score),
+        Err(Error::InsufficientPowScore {
+            min_pow_score,
+            score: 0
+        })
+    );
+    assert!(block.verify_nonce(0).is_ok());
+
+    // Nonce 25280, 12 trailing zeros.
+    let len = bytes.len();
+    bytes[len - 8..].copy_from_slice(&25280u64.to_le_bytes());
+    let block = Block::unpack_strict(bytes.as_slice(), &protocol_parameters()).unwrap();
+    assert!((block.pow_score() - 3u128.pow(12) as f64 / 78f64).abs() < f64::EPSILON);
+    assert_eq!(block.pow_score(), PowScorer::new().score(&bytes));
+    assert!(block.verify_nonce(min_pow_score).is_ok());
+    assert_eq!(
+        block.verify_nonce(7000),
+        Err(Error::InsufficientPowScore {
+            min_pow_score: 7000,
+            score: 6813
+        })
+    );
 }
 
 #[test]
@@ -61,19 +100,17 @@ fn invalid_payload_kind() {
 
 #[test]
 fn unpack_valid_no_remaining_bytes() {
-    assert!(
-        Block::unpack_strict(
-            vec![
-                2, 2, 140, 28, 186, 52, 147, 145, 96, 9, 105, 89, 78, 139, 3, 71, 249, 97, 149, 190, 63, 238, 168, 202,
-                82, 140, 227, 66, 173, 19, 110, 93, 117, 34, 225, 202, 251, 10, 156, 58, 144, 225, 54, 79, 62, 38, 20,
-                121, 95, 90, 112, 109, 6, 166, 126, 145, 13, 62, 52, 68, 248, 135, 223, 119, 137, 13, 0, 0, 0, 0, 21,
-                205, 91, 7, 0, 0, 0, 0,
-            ]
-            .as_slice(),
-            &protocol_parameters()
-        )
-        .is_ok()
+    assert!(Block::unpack_strict(
+        vec![
+            2, 2, 140, 28, 186, 52, 147, 145, 96, 9, 105, 89, 78, 139, 3, 71, 249, 97, 149, 190, 63, 238, 168, 202, 82,
+            140, 227, 66, 173, 19, 110, 93, 117, 34, 225, 202, 251, 10, 156, 58, 144, 225, 54, 79, 62, 38, 20, 121, 95,
+            90, 112, 109, 6, 166, 126, 145, 13, 62, 52, 68, 248, 135, 223, 119, 137, 13, 0, 0, 0, 0, 21, 205, 91, 7, 0,
+            0, 0, 0,
+        ]
+        .as_slice(),
+        &protocol_parameters()
     )
+    .is_ok())
 }
 
 #[test]
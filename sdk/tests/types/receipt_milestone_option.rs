@@ -9,7 +9,7 @@ use iota_sdk::types::block::{
     output::TreasuryOutput,
     payload::{
         milestone::{
-            option::{MigratedFundsEntry, ReceiptMilestoneOption, TailTransactionHash},
+            option::{MigratedFundsEntry, ReceiptMilestoneOption, ReceiptMilestoneOptionBuilder, TailTransactionHash},
             MilestoneId, MilestoneIndex,
         },
         TreasuryTransactionPayload,
@@ -232,3 +232,59 @@ fn getters() {
     assert_eq!(receipt.transaction(), &transaction);
     assert_eq!(receipt.amount(), AMOUNT);
 }
+
+#[test]
+fn builder() {
+    let token_supply = protocol_parameters().token_supply();
+    let treasury_input = TreasuryInput::new(MilestoneId::from_str(MILESTONE_ID).unwrap());
+    let mut new_tail_transaction_hash = TAIL_TRANSACTION_HASH_BYTES;
+    new_tail_transaction_hash[0] = 223;
+    let new_funds = |tail_transaction_hash| {
+        MigratedFundsEntry::new(
+            TailTransactionHash::new(tail_transaction_hash).unwrap(),
+            Address::from(Ed25519Address::from_str(ED25519_ADDRESS).unwrap()),
+            AMOUNT,
+            token_supply,
+        )
+        .unwrap()
+    };
+
+    // Funds are added unsorted on purpose
+    let receipt = ReceiptMilestoneOptionBuilder::new(MilestoneIndex::new(1), treasury_input, 3 * AMOUNT)
+        .with_last(true)
+        .add_funds(new_funds(new_tail_transaction_hash))
+        .add_funds(new_funds(TAIL_TRANSACTION_HASH_BYTES))
+        .finish(token_supply)
+        .unwrap();
+
+    assert_eq!(receipt.migrated_at(), MilestoneIndex::new(1));
+    assert!(receipt.last());
+    assert_eq!(
+        receipt.funds(),
+        [
+            new_funds(TAIL_TRANSACTION_HASH_BYTES),
+            new_funds(new_tail_transaction_hash)
+        ]
+    );
+    assert_eq!(receipt.amount(), 2 * AMOUNT);
+    assert_eq!(receipt.transaction().output().amount(), AMOUNT);
+    assert!(receipt.verify_treasury(&treasury_input, 3 * AMOUNT).is_ok());
+    assert!(matches!(
+        receipt.verify_treasury(&treasury_input, 4 * AMOUNT),
+        Err(Error::TreasuryAmountMismatch { .. })
+    ));
+    assert!(matches!(
+        receipt.verify_treasury(&TreasuryInput::new(MilestoneId::null()), 3 * AMOUNT),
+        Err(Error::TreasuryInputMismatch { .. })
+    ));
+
+    assert!(matches!(
+        ReceiptMilestoneOptionBuilder::new(MilestoneIndex::new(1), treasury_input, AMOUNT)
+            .with_funds([
+                new_funds(TAIL_TRANSACTION_HASH_BYTES),
+                new_funds(new_tail_transaction_hash)
+            ])
+            .finish(token_supply),
+        Err(Error::InsufficientTreasuryAmount { .. })
+    ));
+}
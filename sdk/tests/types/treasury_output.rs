@@ -1,7 +1,11 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::types::block::{output::TreasuryOutput, protocol::protocol_parameters, Error};
+use iota_sdk::types::block::{
+    output::{Output, TreasuryOutput, TreasuryOutputBuilder},
+    protocol::protocol_parameters,
+    Error,
+};
 use packable::{error::UnpackError, PackableExt};
 use pretty_assertions::assert_eq;
 
@@ -65,3 +69,22 @@ fn pack_unpack_invalid() {
         )))
     ));
 }
+
+#[test]
+fn builder() {
+    let token_supply = protocol_parameters().token_supply();
+    let output = TreasuryOutput::build_with_amount(1_000).finish(token_supply).unwrap();
+
+    assert_eq!(output.amount(), 1_000);
+    assert_eq!(
+        TreasuryOutputBuilder::from(&output)
+            .with_amount(2_000)
+            .finish_output(token_supply)
+            .unwrap(),
+        Output::Treasury(TreasuryOutput::new(2_000, token_supply).unwrap())
+    );
+    assert!(matches!(
+        TreasuryOutputBuilder::new_with_amount(token_supply + 1).finish(token_supply),
+        Err(Error::InvalidTreasuryOutputAmount(_))
+    ));
+}
@@ -4,7 +4,11 @@
 use core::str::FromStr;
 
 use iota_sdk::types::block::{
-    input::TreasuryInput, output::TreasuryOutput, payload::TreasuryTransactionPayload, protocol::protocol_parameters,
+    input::TreasuryInput,
+    output::TreasuryOutput,
+    payload::{TreasuryTransactionPayload, TreasuryTransactionPayloadBuilder},
+    protocol::protocol_parameters,
+    Error,
 };
 use packable::PackableExt;
 use pretty_assertions::assert_eq;
@@ -65,3 +69,36 @@ fn pack_unpack_invalid() {
     // `InvalidOutputKind` but something related to an invalid address, so not really relevant for this test.
     assert!(TreasuryTransactionPayload::unpack_verified(bytes.as_slice(), &protocol_parameters()).is_err());
 }
+
+#[test]
+fn builder() {
+    let token_supply = protocol_parameters().token_supply();
+    let input = TreasuryInput::from_str(BLOCK_ID).unwrap();
+    let transaction = TreasuryTransactionPayloadBuilder::new(input, 1_000)
+        .add_spent_amount(300)
+        .add_spent_amount(200)
+        .finish(token_supply)
+        .unwrap();
+
+    assert_eq!(*transaction.input(), input);
+    assert_eq!(transaction.output().amount(), 500);
+    assert!(transaction.verify_transition(&input, 1_000, 500).is_ok());
+    assert!(matches!(
+        transaction.verify_transition(&input, 1_000, 400),
+        Err(Error::TreasuryAmountMismatch {
+            input: 1_000,
+            output: 500,
+            spent: 400
+        })
+    ));
+
+    assert!(matches!(
+        TreasuryTransactionPayloadBuilder::new(input, 1_000)
+            .add_spent_amount(1_001)
+            .finish(token_supply),
+        Err(Error::InsufficientTreasuryAmount {
+            available: 1_000,
+            required: 1_001
+        })
+    ));
+}
@@ -120,6 +120,25 @@ async fn account_alias_already_exists() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn get_or_create_account_concurrently() -> Result<()> {
+    let storage_path = "test-storage/get_or_create_account_concurrently";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = futures::future::try_join_all((0..5).map(|_| wallet.get_or_create_account("Alice"))).await?;
+
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+    for account in accounts {
+        assert_eq!(*account.details().await.index(), 0);
+    }
+    // The alias is compared case insensitive like for new accounts
+    assert_eq!(*wallet.get_or_create_account("alice").await?.details().await.index(), 0);
+    assert_eq!(*wallet.get_or_create_account("Bob").await?.details().await.index(), 1);
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_rename_alias() -> Result<()> {
     let storage_path = "test-storage/account_rename_alias";
@@ -190,3 +209,50 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn watch_only_account() -> Result<()> {
+    use iota_sdk::{
+        client::{constants::SHIMMER_COIN_TYPE, secret::SecretManager},
+        types::block::address::Bech32Address,
+        wallet::{ClientOptions, Error, Wallet},
+    };
+
+    let storage_path = "test-storage/watch_only_account";
+    setup(storage_path)?;
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Placeholder)
+        .with_client_options(ClientOptions::new().with_node(crate::wallet::common::NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    let wallet = wallet_builder.finish().await?;
+
+    assert!(matches!(
+        wallet.create_account().with_watch_only_addresses([]).finish().await,
+        Err(Error::MissingParameter("addresses"))
+    ));
+
+    let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"
+        .parse::<Bech32Address>()
+        .unwrap();
+    let account = wallet
+        .create_account()
+        .with_alias("watch")
+        .with_watch_only_addresses([address])
+        .finish()
+        .await?;
+
+    assert!(*account.details().await.watch_only());
+    assert_eq!(account.addresses().await?[0].address(), &address);
+    assert!(matches!(
+        account.generate_ed25519_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(alias)) if alias == "watch"
+    ));
+
+    tear_down(storage_path)
+}
@@ -10,7 +10,7 @@ use iota_sdk::{
         node_manager::node::{Node, NodeDto},
         secret::{mnemonic::MnemonicSecretManager, stronghold::StrongholdSecretManager, SecretManager},
     },
-    wallet::{ClientOptions, Result, Wallet},
+    wallet::{BackupOptions, ClientOptions, Error, RestoreConflictPolicy, RestoreOptions, Result, Wallet},
     Url,
 };
 use pretty_assertions::assert_eq;
@@ -569,3 +569,119 @@ async fn restore_no_secret_manager_data() -> Result<()> {
     );
     tear_down(storage_path)
 }
+
+// Partial backup and merging it into a wallet with accounts
+#[tokio::test]
+async fn partial_backup_and_merge_restore() -> Result<()> {
+    iota_stronghold::engine::snapshot::try_set_encrypt_work_factor(0).unwrap();
+
+    let storage_path = "test-storage/partial_backup_and_merge_restore";
+    setup(storage_path)?;
+
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic.to_owned(),
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/partial_backup_and_merge_restore/1")
+        .finish()
+        .await?;
+
+    let alice = wallet.create_account().with_alias("Alice").finish().await?;
+    let bob = wallet.create_account().with_alias("Bob").finish().await?;
+
+    let stronghold_password = "some_hopefully_secure_password".to_owned();
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    wallet
+        .backup_with_options(
+            PathBuf::from("test-storage/partial_backup_and_merge_restore/backup.stronghold"),
+            stronghold_password.clone(),
+            BackupOptions::default().with_include_client_options(false),
+        )
+        .await?;
+
+    // restore into a wallet with the same mnemonic that already has an account
+
+    let restore_wallet = Wallet::builder()
+        .with_storage_path("test-storage/partial_backup_and_merge_restore/2")
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic.to_owned(),
+        )?))
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .finish()
+        .await?;
+    restore_wallet.create_account().with_alias("Local").finish().await?;
+
+    // The default conflict policy doesn't merge
+    restore_wallet
+        .restore_backup_with_options(
+            PathBuf::from("test-storage/partial_backup_and_merge_restore/backup.stronghold"),
+            stronghold_password.clone(),
+            RestoreOptions::default(),
+        )
+        .await
+        .unwrap_err();
+
+    restore_wallet
+        .restore_backup_with_options(
+            PathBuf::from("test-storage/partial_backup_and_merge_restore/backup.stronghold"),
+            stronghold_password.clone(),
+            RestoreOptions::default().with_conflict_policy(RestoreConflictPolicy::KeepExisting),
+        )
+        .await?;
+
+    // The existing account is kept and the other one is added
+    assert_eq!(restore_wallet.get_account(0).await?.alias().await, "Local");
+    let recovered_bob = restore_wallet.get_account("Bob").await?;
+    assert_eq!(bob.addresses().await?, recovered_bob.addresses().await?);
+
+    // The client options weren't in the backup
+    let client_options = restore_wallet.client_options().await;
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_OTHER).unwrap()));
+    assert!(client_options.node_manager_builder.nodes.contains(&node_dto));
+
+    restore_wallet
+        .restore_backup_with_options(
+            PathBuf::from("test-storage/partial_backup_and_merge_restore/backup.stronghold"),
+            stronghold_password.clone(),
+            RestoreOptions::default()
+                .with_account_indexes([0])
+                .with_conflict_policy(RestoreConflictPolicy::ReplaceExisting),
+        )
+        .await?;
+
+    let recovered_alice = restore_wallet.get_account(0).await?;
+    assert_eq!(recovered_alice.alias().await, "Alice");
+    assert_eq!(alice.addresses().await?, recovered_alice.addresses().await?);
+    assert_eq!(restore_wallet.get_accounts().await?.len(), 2);
+
+    // Only restoring the second account into a wallet without accounts would leave a gap in the account indexes
+    let empty_wallet = Wallet::builder()
+        .with_storage_path("test-storage/partial_backup_and_merge_restore/3")
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic.to_owned(),
+        )?))
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .finish()
+        .await?;
+    assert!(matches!(
+        empty_wallet
+            .restore_backup_with_options(
+                PathBuf::from("test-storage/partial_backup_and_merge_restore/backup.stronghold"),
+                stronghold_password,
+                RestoreOptions::default().with_account_indexes([1]),
+            )
+            .await,
+        Err(Error::Backup(_))
+    ));
+    assert!(empty_wallet.get_accounts().await?.is_empty());
+
+    tear_down(storage_path)
+}
@@ -5,9 +5,15 @@ use iota_sdk::{
     types::block::output::{
         feature::SenderFeature,
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
-        BasicOutputBuilder, UnlockCondition,
+        BasicOutputBuilder, TokenId, UnlockCondition,
+    },
+    wallet::{
+        account::{
+            types::{AddressBalance, Balance},
+            TokenFilter,
+        },
+        Result,
     },
-    wallet::{account::types::Balance, Result},
 };
 use pretty_assertions::assert_eq;
 
@@ -32,6 +38,7 @@ fn balance_add_assign() {
     let num_aliases1 = balance1.aliases().len();
     let num_foundries1 = balance1.foundries().len();
     let num_nfts1 = balance1.nfts().len();
+    let provisional1 = balance1.provisional().clone();
 
     let balance2 = Balance::rand_mock();
     let total2 = balance2.base_coin().total();
@@ -48,6 +55,7 @@ fn balance_add_assign() {
     let num_aliases2 = balance2.aliases().len();
     let num_foundries2 = balance2.foundries().len();
     let num_nfts2 = balance2.nfts().len();
+    let provisional2 = balance2.provisional().clone();
 
     balance1 += balance2;
 
@@ -68,6 +76,23 @@ fn balance_add_assign() {
     assert_eq!(balance1.foundries().len(), num_foundries1 + num_foundries2);
     assert_eq!(balance1.nfts().len(), num_nfts1 + num_nfts2);
 
+    assert_eq!(
+        balance1.provisional().transaction_ids().len(),
+        provisional1.transaction_ids().len() + provisional2.transaction_ids().len()
+    );
+    assert_eq!(
+        balance1.provisional().spent_outputs().len(),
+        provisional1.spent_outputs().len() + provisional2.spent_outputs().len()
+    );
+    assert_eq!(
+        balance1.provisional().spent(),
+        provisional1.spent() + provisional2.spent()
+    );
+    assert_eq!(
+        balance1.provisional().received(),
+        provisional1.received() + provisional2.received()
+    );
+
     let mut expected = std::collections::HashMap::new();
     for nt in native_tokens1.iter().chain(native_tokens2.iter()) {
         let v = expected
@@ -84,6 +109,80 @@ fn balance_add_assign() {
     }
 }
 
+#[test]
+fn address_balance_from_balance() {
+    let balance = Balance::rand_mock();
+    let address_balance = AddressBalance::from(balance.clone());
+
+    assert_eq!(address_balance.base_coin(), balance.base_coin());
+    assert_eq!(address_balance.native_tokens().len(), balance.native_tokens().len());
+    for native_token in balance.native_tokens() {
+        assert_eq!(&address_balance.native_tokens()[native_token.token_id()], native_token);
+    }
+}
+
+#[test]
+fn token_filter_lists() {
+    let token_a = TokenId::new([1; TokenId::LENGTH]);
+    let token_b = TokenId::new([2; TokenId::LENGTH]);
+
+    let mut token_filter = TokenFilter::default();
+    assert!(token_filter.is_shown(&token_a));
+    assert!(!token_filter.is_listed(&token_a));
+
+    token_filter.deny(token_a);
+    token_filter.allow(token_b);
+    assert!(!token_filter.is_shown(&token_a));
+    assert!(token_filter.is_shown(&token_b));
+    assert!(token_filter.is_listed(&token_a) && token_filter.is_listed(&token_b));
+
+    // A token can only be on one list.
+    token_filter.allow(token_a);
+    assert!(token_filter.is_shown(&token_a));
+    assert!(token_filter.denied().is_empty());
+    assert_eq!(token_filter.allowed().len(), 2);
+
+    token_filter.reset(&token_a);
+    assert!(!token_filter.is_listed(&token_a));
+    assert!(token_filter.is_shown(&token_a));
+}
+
+#[tokio::test]
+async fn account_token_filter() -> Result<()> {
+    let storage_path = "test-storage/account_token_filter";
+    setup(storage_path)?;
+
+    let token_a = TokenId::new([1; TokenId::LENGTH]);
+    let token_b = TokenId::new([2; TokenId::LENGTH]);
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+        let account = wallet.create_account().finish().await?;
+        assert_eq!(account.token_filter().await, TokenFilter::default());
+
+        account.deny_native_token(token_a).await?;
+        account.allow_native_token(token_b).await?;
+        let token_filter = account.token_filter().await;
+        assert!(!token_filter.is_shown(&token_a));
+        assert!(token_filter.allowed().contains(&token_b));
+
+        account.reset_native_token(&token_b).await?;
+        assert!(!account.token_filter().await.is_listed(&token_b));
+    }
+
+    #[cfg(feature = "storage")]
+    {
+        // The token filter is restored from the storage.
+        let wallet = make_wallet(storage_path, None, None).await?;
+        let account = wallet.get_account(0).await?;
+        let token_filter = account.token_filter().await;
+        assert!(token_filter.denied().contains(&token_a));
+        assert!(!token_filter.is_listed(&token_b));
+    }
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn balance_expiration() -> Result<()> {
@@ -116,17 +215,27 @@ async fn balance_expiration() -> Result<()> {
     let balance_before_tx = account_0.balance().await?;
     let tx = account_0.send_outputs(outputs, None).await?;
     let balance_after_tx = account_0.balance().await?;
-    // Total doesn't change before syncing after tx got confirmed
+    // The pending transaction is provisionally applied to the total, the remainder is available once it's confirmed
     assert_eq!(
-        balance_before_tx.base_coin().total(),
+        balance_before_tx.base_coin().total() - 1_000_000,
         balance_after_tx.base_coin().total()
     );
     assert_eq!(balance_after_tx.base_coin().available(), 0);
+    assert_eq!(balance_after_tx.provisional().transaction_ids(), &[tx.transaction_id]);
+    assert_eq!(
+        balance_after_tx.provisional().spent() - balance_after_tx.provisional().received(),
+        1_000_000
+    );
 
     account_0
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
 
+    let balance = account_0.sync(None).await?;
+    assert!(balance.provisional().is_empty());
+    assert_eq!(balance.base_coin().total(), balance_after_tx.base_coin().total());
+    assert_eq!(balance.base_coin().available(), balance.base_coin().total());
+
     // Account 1 balance before expiration
     let balance = account_1.sync(None).await?;
     assert_eq!(balance.potentially_locked_outputs().len(), 1);
@@ -278,3 +387,26 @@ async fn balance_voting_power() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn balance_per_address() -> Result<()> {
+    let storage_path = "test-storage/balance_per_address";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let funded_address = *account.addresses().await?[0].address();
+    let empty_address = *account.generate_ed25519_addresses(1, None).await?[0].address();
+
+    let balance = account.balance().await?;
+    let balances = account.balance_per_address().await?;
+
+    assert_eq!(balances.len(), 2);
+    assert_eq!(balances[&funded_address].base_coin(), balance.base_coin());
+    assert_eq!(balances[&empty_address].base_coin().total(), 0);
+    assert!(balances[&empty_address].native_tokens().is_empty());
+
+    tear_down(storage_path)
+}
@@ -5,9 +5,12 @@ use iota_sdk::{
     client::api::input_selection::Burn,
     types::block::output::{
         unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
-        NativeToken, NftId, NftOutputBuilder, OutputId, UnlockCondition,
+        FoundryId, NativeToken, NftId, NftOutputBuilder, OutputId, UnlockCondition,
+    },
+    wallet::{
+        account::{AliasDestructionBlocker, AliasDestructionStep, DestroyAliasOptions},
+        Account, CreateNativeTokenParams, MintNftParams, Result,
     },
-    wallet::{Account, CreateNativeTokenParams, MintNftParams, Result},
     U256,
 };
 use pretty_assertions::assert_eq;
@@ -229,6 +232,78 @@ async fn destroy_alias(account: &Account) -> Result<()> {
     Ok(())
 }
 
+#[ignore]
+#[tokio::test]
+async fn destroy_alias_with_dependencies() -> Result<()> {
+    let storage_path = "test-storage/destroy_alias_with_dependencies";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let transaction = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let params = CreateNativeTokenParams {
+        alias_id: None,
+        circulating_supply: U256::from(60i32),
+        maximum_supply: U256::from(100i32),
+        foundry_metadata: None,
+    };
+    let create_transaction = account.create_native_token(params, None).await?;
+    account
+        .retry_transaction_until_included(&create_transaction.transaction.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(None).await?;
+    let alias_id = *balance.aliases().first().unwrap();
+
+    // Without melting, the circulating native tokens block the destruction
+    let plan = account
+        .plan_alias_destruction(alias_id, &DestroyAliasOptions::default())
+        .await?;
+    assert_eq!(
+        plan.blockers,
+        [AliasDestructionBlocker::CirculatingNativeTokens {
+            token_id: create_transaction.token_id,
+            circulating_supply: U256::from(60i32),
+            held_by_account: U256::from(60i32),
+        }]
+    );
+
+    let options = DestroyAliasOptions {
+        melt_native_tokens: true,
+    };
+    let plan = account.plan_alias_destruction(alias_id, &options).await?;
+    assert!(plan.is_executable());
+    assert_eq!(
+        plan.steps,
+        [
+            AliasDestructionStep::MeltNativeToken {
+                token_id: create_transaction.token_id,
+                amount: U256::from(60i32),
+            },
+            AliasDestructionStep::DestroyFoundries {
+                foundry_ids: vec![FoundryId::from(create_transaction.token_id)],
+            },
+            AliasDestructionStep::DestroyAlias { alias_id },
+        ]
+    );
+
+    let transactions = account
+        .destroy_alias_with_dependencies(alias_id, &options, None)
+        .await?;
+    assert_eq!(transactions.len(), 3);
+
+    let balance = account.balance().await?;
+    assert!(balance.aliases().is_empty());
+    assert!(balance.foundries().is_empty());
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn create_and_burn_native_tokens() -> Result<()> {
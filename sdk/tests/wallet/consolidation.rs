@@ -1,7 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::ConsolidationParams, Result, SendParams};
+use iota_sdk::{
+    client::{
+        api::GetAddressesOptions,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client,
+    },
+    wallet::{account::ConsolidationParams, Result, SendParams},
+};
 use pretty_assertions::assert_eq;
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -49,3 +56,68 @@ async fn consolidation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn sweep_from_mnemonic() -> Result<()> {
+    let storage_path = "test-storage/sweep_from_mnemonic";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let foreign_secret_manager =
+        SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(Client::generate_mnemonic()?)?);
+    let foreign_addresses = foreign_secret_manager
+        .generate_ed25519_addresses(
+            GetAddressesOptions::from_client(wallet.client())
+                .await?
+                .with_coin_type(*account_0.details().await.coin_type())
+                .with_range(0..2),
+        )
+        .await?;
+
+    // Send 2 outputs to each foreign address
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_with_params(
+            foreign_addresses
+                .iter()
+                .flat_map(|address| {
+                    [
+                        SendParams::new(amount, *address).unwrap(),
+                        SendParams::new(amount, *address).unwrap(),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let block_ids = wallet
+        .sweep_from(
+            &foreign_secret_manager,
+            GetAddressesOptions::from_client(wallet.client())
+                .await?
+                .with_coin_type(*account_1.details().await.coin_type())
+                .with_range(0..2),
+            *account_1.addresses().await?[0].address(),
+        )
+        .await?;
+    // One transaction per address
+    assert_eq!(block_ids.len(), 2);
+    for block_id in block_ids {
+        wallet.client().retry_until_included(&block_id, None, None).await?;
+    }
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 4 * amount);
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 2);
+
+    tear_down(storage_path)
+}
@@ -6,7 +6,7 @@ use crypto::keys::bip39::Mnemonic;
 use iota_sdk::{
     client::constants::SHIMMER_COIN_TYPE,
     client::node_manager::node::{Node, NodeDto},
-    wallet::Error,
+    wallet::{AddressBookEntry, Error},
     Url,
 };
 use iota_sdk::{
@@ -15,7 +15,7 @@ use iota_sdk::{
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
     },
     types::block::address::Bech32Address,
-    wallet::{ClientOptions, Result, Wallet},
+    wallet::{ClientOptions, Result, Wallet, WalletFingerprint},
 };
 use pretty_assertions::assert_eq;
 
@@ -77,6 +77,91 @@ async fn different_seed() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn address_book() -> Result<()> {
+    let storage_path = "test-storage/address_book";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let address = Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?;
+
+    let entry = AddressBookEntry::new(address, "Alice")
+        .with_known_peer(true)
+        .with_network("testnet".to_string());
+    assert!(wallet.set_address_book_entry(entry.clone()).await?.is_none());
+    assert_eq!(wallet.address_book_entry(&address).await, Some(entry.clone()));
+
+    // Updating the label replaces the entry
+    let updated_entry = AddressBookEntry {
+        label: "Alice B.".to_string(),
+        ..entry.clone()
+    };
+    assert_eq!(wallet.set_address_book_entry(updated_entry.clone()).await?, Some(entry));
+    drop(wallet);
+
+    // The address book is persisted
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert_eq!(wallet.address_book().await.entries(), [&updated_entry]);
+
+    assert_eq!(wallet.remove_address_book_entry(&address).await?, Some(updated_entry));
+    assert!(wallet.address_book().await.entries().is_empty());
+
+    // Labels are validated
+    assert!(matches!(
+        wallet
+            .set_address_book_entry(AddressBookEntry::new(address, "Alice\n"))
+            .await,
+        Err(Error::InvalidLabel { .. })
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn fingerprint() -> Result<()> {
+    let storage_path_0 = "test-storage/fingerprint_0";
+    let storage_path_1 = "test-storage/fingerprint_1";
+    let storage_path_2 = "test-storage/fingerprint_2";
+    setup(storage_path_0)?;
+    setup(storage_path_1)?;
+    setup(storage_path_2)?;
+
+    let mnemonic = Mnemonic::from(DEFAULT_MNEMONIC.to_owned());
+    let wallet = make_wallet(storage_path_0, Some(mnemonic.clone()), None).await?;
+    let fingerprint = wallet.fingerprint().await?;
+
+    assert_eq!(fingerprint.words().len(), WalletFingerprint::WORD_COUNT);
+    assert_eq!(fingerprint.to_string(), fingerprint.to_hex());
+    assert_eq!(
+        serde_json::to_string(&fingerprint).unwrap(),
+        format!("\"{}\"", fingerprint.to_hex())
+    );
+
+    // The fingerprint doesn't depend on the accounts of the wallet
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+    assert_eq!(
+        WalletFingerprint::from_address_bytes(address.as_ed25519().as_ref()),
+        fingerprint
+    );
+    assert_eq!(wallet.fingerprint().await?, fingerprint);
+
+    // The same seed results in the same fingerprint
+    let restored_wallet = make_wallet(storage_path_1, Some(mnemonic), None).await?;
+    assert_eq!(restored_wallet.fingerprint().await?, fingerprint);
+
+    // A different seed results in a different fingerprint
+    let other_wallet = make_wallet(storage_path_2, None, None).await?;
+    let other_fingerprint = other_wallet.fingerprint().await?;
+    assert_ne!(other_fingerprint, fingerprint);
+    assert_ne!(other_fingerprint.words(), fingerprint.words());
+
+    tear_down(storage_path_0)?;
+    tear_down(storage_path_1)?;
+    tear_down(storage_path_2)
+}
+
 #[cfg(feature = "storage")]
 #[tokio::test]
 async fn changed_coin_type() -> Result<()> {
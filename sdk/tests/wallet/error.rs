@@ -1,7 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::Error;
+use iota_sdk::{types::block::output::AliasId, wallet::Error};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -26,4 +26,10 @@ fn stringified_error() {
         &serde_json::to_string(&error).unwrap(),
         "{\"type\":\"failedToGetRemainder\",\"error\":\"failed to get remainder address\"}"
     );
+
+    let error = Error::AliasNotControlled(AliasId::null());
+    assert_eq!(
+        &serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"aliasNotControlled\",\"error\":\"account doesn't have state control over alias 0x0000000000000000000000000000000000000000000000000000000000000000\"}"
+    );
 }
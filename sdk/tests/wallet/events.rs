@@ -6,16 +6,18 @@ use iota_sdk::{
     types::block::{
         address::{Address, Bech32Address, Ed25519Address},
         input::{Input, UtxoInput},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
+        output::{unlock_condition::AddressUnlockCondition, AliasId, BasicOutput, Output, OutputId},
         payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
         protocol::protocol_parameters,
         rand::output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
+        BlockId,
     },
     wallet::{
         account::types::{InclusionState, OutputData, OutputDataDto},
         events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+            AddressData, NativeTokenCreationProgressEvent, NewOutputEvent, OutputsClaimedEvent, SpentOutputEvent,
+            SplitTransactionProgressEvent, TransactionInclusionEvent, TransactionProgressEvent, TransactionRetryEvent,
+            TransactionRetryKind, WalletEvent,
         },
     },
 };
@@ -122,4 +124,39 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    assert_serde_eq(WalletEvent::TransactionRetry(TransactionRetryEvent {
+        transaction_id: TransactionId::null(),
+        block_id: BlockId::null(),
+        kind: TransactionRetryKind::Promoted,
+    }));
+
+    assert_serde_eq(WalletEvent::OutputsClaimed(OutputsClaimedEvent {
+        transaction_id: TransactionId::null(),
+        output_ids: vec![OutputId::new(TransactionId::null(), 0).unwrap()],
+    }));
+
+    assert_serde_eq(WalletEvent::NativeTokenCreationProgress(
+        NativeTokenCreationProgressEvent::CreatingAlias {
+            transaction_id: TransactionId::null(),
+        },
+    ));
+    assert_serde_eq(WalletEvent::NativeTokenCreationProgress(
+        NativeTokenCreationProgressEvent::AliasCreated {
+            alias_id: AliasId::null(),
+        },
+    ));
+
+    assert_serde_eq(WalletEvent::SplitTransactionProgress(
+        SplitTransactionProgressEvent::Consolidating {
+            transaction_id: TransactionId::null(),
+        },
+    ));
+    assert_serde_eq(WalletEvent::SplitTransactionProgress(
+        SplitTransactionProgressEvent::Sending {
+            transaction_id: TransactionId::null(),
+            index: 1,
+            count: 3,
+        },
+    ));
 }
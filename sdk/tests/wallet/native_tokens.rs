@@ -70,6 +70,60 @@ async fn create_and_mint_native_token() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn create_native_token_with_alias() -> Result<()> {
+    let storage_path = "test-storage/create_native_token_with_alias";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    // No alias exists yet, so it's created first
+    let create_txs = account
+        .create_native_token_with_alias(
+            CreateNativeTokenParams {
+                alias_id: None,
+                circulating_supply: U256::from(50),
+                maximum_supply: U256::from(100),
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    assert!(create_txs.alias_transaction.is_some());
+
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.aliases(), &[create_txs.alias_id]);
+    assert_eq!(
+        balance
+            .native_tokens()
+            .iter()
+            .find(|t| t.token_id() == &create_txs.token_id)
+            .unwrap()
+            .available(),
+        U256::from(50)
+    );
+
+    // The existing alias is used for the next one
+    let create_txs = account
+        .create_native_token_with_alias(
+            CreateNativeTokenParams {
+                alias_id: None,
+                circulating_supply: U256::from(50),
+                maximum_supply: U256::from(100),
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    assert!(create_txs.alias_transaction.is_none());
+    assert_eq!(account.sync(None).await?.native_tokens().len(), 2);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn native_token_foundry_metadata() -> Result<()> {
@@ -234,3 +234,40 @@ async fn background_syncing() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn sync_accounts_concurrently() -> Result<()> {
+    let storage_path = "test-storage/sync_accounts_concurrently";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    for _ in 0..3 {
+        wallet.create_account().finish().await?;
+    }
+
+    wallet.set_account_sync_concurrency(0);
+    assert_eq!(wallet.account_sync_concurrency(), 1);
+    wallet.set_account_sync_concurrency(2);
+
+    let mut progress = Vec::new();
+    let balance = wallet
+        .sync_with_progress(
+            Some(SyncOptions {
+                force_syncing: true,
+                ..Default::default()
+            }),
+            |p| progress.push(p),
+        )
+        .await?;
+
+    assert_eq!(balance.base_coin().total(), 0);
+    assert_eq!(progress.len(), 3);
+    assert_eq!(progress.last().unwrap().synced_accounts, 3);
+    assert!(progress.iter().all(|p| p.total_accounts == 3));
+    let mut account_indexes = progress.iter().map(|p| p.account_index).collect::<Vec<_>>();
+    account_indexes.sort_unstable();
+    assert_eq!(account_indexes, [0, 1, 2]);
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+use iota_sdk::{
+    client::secret::SecretManage,
+    wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams},
+};
 use pretty_assertions::assert_eq;
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -35,6 +38,130 @@ async fn send_amount() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_split_to_many_recipients() -> Result<()> {
+    let storage_path = "test-storage/send_split_to_many_recipients";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+
+    // More outputs than fit into a single transaction.
+    let amount = 100_000;
+    let params = (0..150)
+        .map(|_| SendParams::new(amount, address))
+        .collect::<Result<Vec<_>>>()?;
+
+    let plan = account_0.plan_split_send(params.clone(), None).await?;
+    assert_eq!(plan.transactions.iter().map(Vec::len).collect::<Vec<_>>(), [127, 23]);
+
+    let transactions = account_0.send_split(params, None).await?;
+    assert_eq!(transactions.len(), 2);
+    assert!(transactions.iter().all(|transaction| !transaction.consolidation));
+
+    account_0
+        .retry_transaction_until_included(&transactions[1].transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), 150 * amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn transaction_notes_and_tags() -> Result<()> {
+    let storage_path = "test-storage/transaction_notes_and_tags";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0
+        .send_with_params(
+            [SendParams::new(1_000_000, *account_1.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+
+    account_0
+        .set_transaction_note(&tx.transaction_id, Some("rent".to_string()))
+        .await?;
+    account_0.add_transaction_tag(&tx.transaction_id, "household").await?;
+    account_0.add_transaction_tag(&tx.transaction_id, "monthly").await?;
+    let updated = account_0.remove_transaction_tag(&tx.transaction_id, "monthly").await?;
+    assert_eq!(updated.note.as_deref(), Some("rent"));
+    assert_eq!(updated.tags.iter().collect::<Vec<_>>(), ["household"]);
+
+    assert_eq!(account_0.transactions_with_tag("household").await, [updated.clone()]);
+    assert!(account_0.transactions_with_tag("monthly").await.is_empty());
+    assert_eq!(account_0.get_transaction(&tx.transaction_id).await, Some(updated));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_amount_external_signing() -> Result<()> {
+    let storage_path = "test-storage/send_amount_external_signing";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let prepared_transaction = account_0
+        .prepare_send(
+            [SendParams::new(amount, *account_1.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+
+    let request = account_0.export_for_external_signing(&prepared_transaction).await?;
+    assert_eq!(
+        request.required_signers,
+        vec![*account_0.addresses().await?[0].address()]
+    );
+
+    // Sign with the secret manager as if it was on another machine
+    let unlocks = wallet
+        .get_secret_manager()
+        .read()
+        .await
+        .sign_transaction_essence(&prepared_transaction, None)
+        .await?;
+
+    // Without signatures the transaction can't be unlocked
+    assert!(matches!(
+        account_0
+            .combine_external_signatures(&prepared_transaction, Vec::new())
+            .await,
+        Err(iota_sdk::wallet::Error::MissingSignature(_))
+    ));
+
+    let tx = account_0
+        .submit_externally_signed_transaction(&prepared_transaction, unlocks.to_vec(), None)
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_127_outputs() -> Result<()> {
@@ -158,6 +285,42 @@ async fn send_nft() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn mint_nft_collection() -> Result<()> {
+    let storage_path = "test-storage/mint_nft_collection";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let transaction = account
+        .mint_nfts(
+            [MintNftParams::new().with_immutable_metadata(b"collection".to_vec())],
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let collection_nft_id = *account.sync(None).await?.nfts().first().unwrap();
+
+    // More items than fit into a single transaction
+    let params = (0..60)
+        .map(|index| MintNftParams::new().with_immutable_metadata(format!("item {index}").into_bytes()))
+        .collect::<Vec<_>>();
+    let result = account.mint_nft_collection(collection_nft_id, params, None).await?;
+
+    assert!(result.is_complete());
+    let nft_ids = result.nft_ids();
+    assert_eq!(nft_ids.len(), 60);
+
+    let balance = account.sync(None).await?;
+    assert!(nft_ids.iter().all(|nft_id| balance.nfts().contains(nft_id)));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_with_note() -> Result<()> {